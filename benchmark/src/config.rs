@@ -1,18 +1,45 @@
 //! @ai:module:intent Configuration structs for benchmark system
 //! @ai:module:layer infrastructure
-//! @ai:module:public_api BenchmarkConfig, ApiConfig, RunConfig, FilterConfig
+//! @ai:module:public_api BenchmarkConfig, ApiConfig, BackendConfig, BackendKind, OllamaConfig, BedrockConfig,
+//!                        VertexConfig, ClaudeCodeConfig, PermissionProfile, McpServerConfig, RunConfig,
+//!                        SamplingSetting, SkillVariant, ModeSelection,
+//!                        RetryConfig, FilterConfig, PromptConfig, SystemPromptSource, CompareConfig,
+//!                        RankingStrategy, JudgeBackend,
+//!                        ModelPricing, SandboxConfig, SandboxKind, RustBuildCache, VendorConfig,
+//!                        EvaluationConfig, PythonTypeChecker, AnnotationMatchingConfig,
+//!                        AnnotationJudgeKind
 //! @ai:module:stateless true
 
+use aicms_parser::FailOn;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
 /// @ai:intent Main configuration for the benchmark system
 /// @ai:effects pure
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BenchmarkConfig {
     pub api: ApiConfig,
+    #[serde(default)]
+    pub backend: BackendConfig,
     pub run: RunConfig,
     pub paths: PathConfig,
+    #[serde(default)]
+    pub prompts: PromptConfig,
+    #[serde(default)]
+    pub compare: CompareConfig,
+    #[serde(default)]
+    pub sandbox: SandboxConfig,
+    #[serde(default)]
+    pub evaluation: EvaluationConfig,
+    #[serde(default)]
+    pub statistics: StatisticsConfig,
+    #[serde(default)]
+    pub export: ExportConfig,
+    /// Per-model prices, keyed by a model name fragment (e.g. "claude-sonnet-4");
+    /// entries override the built-in table
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pricing: BTreeMap<String, ModelPricing>,
 }
 
 /// @ai:intent API configuration for Claude client
@@ -21,12 +48,325 @@ pub struct BenchmarkConfig {
 pub struct ApiConfig {
     #[serde(default = "default_model")]
     pub model: String,
+    /// Run the full task x mode matrix once per model (overrides the selected backend's model)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub models: Vec<String>,
     #[serde(default = "default_max_tokens")]
     pub max_tokens: u32,
     #[serde(default)]
     pub temperature: f32,
+    /// Run the full task x mode matrix once per temperature (overrides temperature)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub temperatures: Vec<f32>,
+    /// Sampling seed; only Ollama honours it (the Claude APIs have no seed parameter)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    /// Run the matrix once per seed (combined with temperatures; overrides seed)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub seeds: Vec<u64>,
     #[serde(default = "default_rate_limit")]
     pub requests_per_minute: u32,
+    /// Input+output token budget per minute (API backends); None = unlimited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tokens_per_minute: Option<u32>,
+    /// Stream responses over SSE (direct API only), logging progress while generating
+    #[serde(default = "default_stream")]
+    pub stream: bool,
+    /// Mark the system prompt for prompt caching (direct API only)
+    #[serde(default = "default_prompt_caching")]
+    pub prompt_caching: bool,
+}
+
+/// @ai:intent One point of a temperature/seed sweep
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SamplingSetting {
+    pub temperature: f32,
+    pub seed: Option<u64>,
+}
+
+impl SamplingSetting {
+    /// @ai:intent Human-readable label used in reports
+    /// @ai:effects pure
+    /// @ai:example (temperature 0.3, seed 7) -> "t=0.3 seed=7"
+    pub fn label(&self) -> String {
+        match self.seed {
+            Some(seed) => format!("t={} seed={}", self.temperature, seed),
+            None => format!("t={}", self.temperature),
+        }
+    }
+
+    /// @ai:intent Directory name for this setting's results
+    /// @ai:effects pure
+    /// @ai:example (temperature 0.3, seed 7) -> "t0.3-seed7"
+    pub fn dir_name(&self) -> String {
+        match self.seed {
+            Some(seed) => format!("t{}-seed{}", self.temperature, seed),
+            None => format!("t{}", self.temperature),
+        }
+    }
+}
+
+/// @ai:intent One SKILL.md variant of a skill matrix run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillVariant {
+    pub label: String,
+    pub path: PathBuf,
+}
+
+impl SkillVariant {
+    /// @ai:intent Variant labelled by its file stem, or by its directory for SKILL.md files
+    /// @ai:effects pure
+    /// @ai:example ("variants/terse.md") -> label "terse"
+    /// @ai:example ("skills/v2/SKILL.md") -> label "v2"
+    pub fn from_path(path: PathBuf) -> Self {
+        let stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let label = if stem.eq_ignore_ascii_case("skill") {
+            path.parent()
+                .and_then(|p| p.file_name())
+                .map(|d| d.to_string_lossy().into_owned())
+                .unwrap_or(stem)
+        } else {
+            stem
+        };
+        Self { label, path }
+    }
+
+    /// @ai:intent Directory name for this variant's results
+    /// @ai:effects pure
+    /// @ai:example (label "terse") -> "skill-terse"
+    pub fn dir_name(&self) -> String {
+        format!("skill-{}", self.label)
+    }
+}
+
+/// @ai:intent Which client generates code, plus per-backend settings
+/// @ai:effects pure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BackendConfig {
+    #[serde(default)]
+    pub kind: BackendKind,
+    #[serde(default)]
+    pub ollama: OllamaConfig,
+    #[serde(default)]
+    pub bedrock: BedrockConfig,
+    #[serde(default)]
+    pub vertex: VertexConfig,
+    #[serde(default)]
+    pub claude_code: ClaudeCodeConfig,
+}
+
+/// @ai:intent Available code generation backends
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BackendKind {
+    /// Claude Code CLI (default)
+    #[default]
+    ClaudeCode,
+    /// Anthropic Messages API (requires ANTHROPIC_API_KEY)
+    Api,
+    /// Local Ollama server
+    Ollama,
+    /// Claude on AWS Bedrock (SigV4, credentials from AWS_* environment variables)
+    Bedrock,
+    /// Claude on Google Cloud Vertex AI (Application Default Credentials)
+    Vertex,
+}
+
+impl BackendKind {
+    /// @ai:intent Convert backend kind to string representation
+    /// @ai:effects pure
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BackendKind::ClaudeCode => "claude-code",
+            BackendKind::Api => "api",
+            BackendKind::Ollama => "ollama",
+            BackendKind::Bedrock => "bedrock",
+            BackendKind::Vertex => "vertex",
+        }
+    }
+
+    /// @ai:intent Whether requests count against Anthropic rate limits (everything but Ollama)
+    /// @ai:effects pure
+    pub fn is_rate_limited(&self) -> bool {
+        !matches!(self, BackendKind::Ollama)
+    }
+}
+
+impl std::str::FromStr for BackendKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "claude-code" => Ok(BackendKind::ClaudeCode),
+            "api" => Ok(BackendKind::Api),
+            "ollama" => Ok(BackendKind::Ollama),
+            "bedrock" => Ok(BackendKind::Bedrock),
+            "vertex" => Ok(BackendKind::Vertex),
+            other => Err(format!(
+                "unknown backend '{}' (expected claude-code, api, ollama, bedrock, or vertex)",
+                other
+            )),
+        }
+    }
+}
+
+/// @ai:intent Which prompt modes (arms) a run executes
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModeSelection {
+    /// Baseline and AICMS (default)
+    #[default]
+    Both,
+    Baseline,
+    Aicms,
+}
+
+impl ModeSelection {
+    /// @ai:intent Convert mode selection to string representation
+    /// @ai:effects pure
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModeSelection::Both => "both",
+            ModeSelection::Baseline => "baseline",
+            ModeSelection::Aicms => "aicms",
+        }
+    }
+}
+
+impl std::str::FromStr for ModeSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "both" => Ok(ModeSelection::Both),
+            "baseline" => Ok(ModeSelection::Baseline),
+            "aicms" => Ok(ModeSelection::Aicms),
+            other => Err(format!(
+                "unknown modes '{}' (expected baseline, aicms, or both)",
+                other
+            )),
+        }
+    }
+}
+
+/// @ai:intent Settings for a local Ollama server
+///            max_tokens and temperature are taken from [api]
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OllamaConfig {
+    #[serde(default = "default_ollama_endpoint")]
+    pub endpoint: String,
+    #[serde(default = "default_ollama_model")]
+    pub model: String,
+    /// Per-request timeout; local models can be slow on long outputs
+    #[serde(default = "default_ollama_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+/// @ai:intent Settings for Claude on AWS Bedrock
+///            max_tokens, temperature, and rate limits are taken from [api]
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BedrockConfig {
+    /// AWS region; falls back to AWS_REGION when unset
+    #[serde(default)]
+    pub region: Option<String>,
+    /// Bedrock model ID or inference profile ID
+    #[serde(default = "default_bedrock_model_id")]
+    pub model_id: String,
+}
+
+/// @ai:intent Settings for Claude on Google Cloud Vertex AI
+///            max_tokens, temperature, and rate limits are taken from [api]
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VertexConfig {
+    /// GCP project; falls back to GOOGLE_CLOUD_PROJECT when unset
+    #[serde(default)]
+    pub project_id: Option<String>,
+    /// Vertex region hosting the model ("global" for the global endpoint)
+    #[serde(default = "default_vertex_region")]
+    pub region: String,
+    /// Vertex model name (publisher model ID with version)
+    #[serde(default = "default_vertex_model")]
+    pub model: String,
+}
+
+/// @ai:intent Settings of the Claude Code CLI backend: tool permissions, MCP servers, logging
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClaudeCodeConfig {
+    #[serde(default)]
+    pub permission_profile: PermissionProfile,
+    /// Tools allowed without prompting, e.g. "Edit", "Bash(cargo test:*)" (--allowedTools)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_tools: Vec<String>,
+    /// Tools that are always denied (--disallowedTools)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disallowed_tools: Vec<String>,
+    /// MCP servers available to Claude, by name (passed with --mcp-config)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub mcp_servers: BTreeMap<String, McpServerConfig>,
+    /// Append CLI output to the interaction log as it arrives (instead of once at exit),
+    /// so a hung run can be inspected while it is still going
+    #[serde(default)]
+    pub stream_log: bool,
+    /// Wall-clock limit per CLI invocation; on expiry the CLI and everything it started
+    /// (cargo, npm, test binaries) are killed (0 = no limit)
+    #[serde(default = "default_claude_code_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+impl Default for ClaudeCodeConfig {
+    fn default() -> Self {
+        Self {
+            permission_profile: PermissionProfile::default(),
+            allowed_tools: Vec::new(),
+            disallowed_tools: Vec::new(),
+            mcp_servers: BTreeMap::new(),
+            stream_log: false,
+            timeout_secs: default_claude_code_timeout_secs(),
+        }
+    }
+}
+
+/// @ai:intent One MCP server, in the format of Claude Code's .mcp.json
+/// @ai:effects pure
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct McpServerConfig {
+    /// Transport: "stdio" (default for command servers), "http", or "sse"
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub transport: Option<String>,
+    /// Command that starts a stdio server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Endpoint of an http or sse server
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// @ai:intent How much the Claude Code CLI may do without asking
+///            Non-interactive runs deny anything that would need a prompt
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PermissionProfile {
+    /// Skip all permission checks (--dangerously-skip-permissions)
+    #[default]
+    Bypass,
+    /// Allow file edits; other tools only when listed in allowed_tools
+    AcceptEdits,
+    /// Only tools listed in allowed_tools
+    Restricted,
 }
 
 /// @ai:intent Run configuration for benchmark execution
@@ -37,8 +377,44 @@ pub struct RunConfig {
     pub repetitions: u32,
     #[serde(default)]
     pub dry_run: bool,
+    /// Prompt modes to execute; a single mode re-runs one arm without its counterpart
+    #[serde(default)]
+    pub modes: ModeSelection,
+    #[serde(default = "default_concurrency")]
+    pub concurrency: usize,
+    #[serde(default)]
+    pub retry: RetryConfig,
     #[serde(default)]
     pub filter: FilterConfig,
+    /// Save every prompt/response pair as a fixture in this directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_dir: Option<PathBuf>,
+    /// Answer from fixtures in this directory instead of calling a model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replay_dir: Option<PathBuf>,
+    /// Feed compile/test failures back to the model for up to this many extra turns
+    #[serde(default)]
+    pub repair_turns: u32,
+    /// Stop starting executions once the run has cost this much (USD, see [pricing])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cost_usd: Option<f64>,
+    /// Stop starting executions once the run has used this many input+output tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_tokens: Option<u64>,
+}
+
+/// @ai:intent Retry policy for failed client requests
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryConfig {
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    #[serde(default = "default_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+    #[serde(default = "default_jitter")]
+    pub jitter: f64,
 }
 
 /// @ai:intent Path configuration for input/output directories
@@ -49,8 +425,23 @@ pub struct PathConfig {
     pub prompts_dir: PathBuf,
     pub results_dir: PathBuf,
     pub skill_file: PathBuf,
+    /// Skill files (or directories of *.md variants) to A/B test; the AICMS arm runs
+    /// once per variant and the baseline arm once
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skill_variants: Vec<PathBuf>,
     #[serde(default = "default_comparison_prompt")]
     pub comparison_prompt_file: PathBuf,
+    /// Aspects, descriptions and weights of the comparison rubric (TOML, or JSON by
+    /// extension). The built-in rubric is used if the file does not exist
+    #[serde(default = "default_comparison_rubric")]
+    pub comparison_rubric_file: PathBuf,
+    /// Prompt for ranking more than two implementations at once ([compare] ranking = "ranked")
+    #[serde(default = "default_ranking_prompt")]
+    pub ranking_prompt_file: PathBuf,
+    /// CLAUDE.md written in AICMS mode (Claude Code); {{SKILL_PATH}} is replaced with
+    /// the skill file. The built-in template is used if the file does not exist
+    #[serde(default = "default_claude_md_template")]
+    pub claude_md_template: PathBuf,
 }
 
 /// @ai:intent Filter configuration for selecting tasks
@@ -63,23 +454,49 @@ pub struct FilterConfig {
     pub task_ids: Option<Vec<String>>,
 }
 
-impl Default for BenchmarkConfig {
+impl Default for ApiConfig {
     fn default() -> Self {
         Self {
-            api: ApiConfig::default(),
-            run: RunConfig::default(),
-            paths: PathConfig::default(),
+            model: default_model(),
+            models: Vec::new(),
+            max_tokens: default_max_tokens(),
+            temperature: 0.0,
+            temperatures: Vec::new(),
+            seed: None,
+            seeds: Vec::new(),
+            requests_per_minute: default_rate_limit(),
+            tokens_per_minute: None,
+            stream: default_stream(),
+            prompt_caching: default_prompt_caching(),
         }
     }
 }
 
-impl Default for ApiConfig {
+impl Default for OllamaConfig {
     fn default() -> Self {
         Self {
-            model: default_model(),
-            max_tokens: default_max_tokens(),
-            temperature: 0.0,
-            requests_per_minute: default_rate_limit(),
+            endpoint: default_ollama_endpoint(),
+            model: default_ollama_model(),
+            timeout_secs: default_ollama_timeout_secs(),
+        }
+    }
+}
+
+impl Default for BedrockConfig {
+    fn default() -> Self {
+        Self {
+            region: None,
+            model_id: default_bedrock_model_id(),
+        }
+    }
+}
+
+impl Default for VertexConfig {
+    fn default() -> Self {
+        Self {
+            project_id: None,
+            region: default_vertex_region(),
+            model: default_vertex_model(),
         }
     }
 }
@@ -89,7 +506,741 @@ impl Default for RunConfig {
         Self {
             repetitions: default_repetitions(),
             dry_run: false,
+            modes: ModeSelection::default(),
+            concurrency: default_concurrency(),
+            retry: RetryConfig::default(),
             filter: FilterConfig::default(),
+            record_dir: None,
+            replay_dir: None,
+            repair_turns: 0,
+            max_cost_usd: None,
+            max_total_tokens: None,
+        }
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_max_attempts(),
+            initial_backoff_ms: default_initial_backoff_ms(),
+            max_backoff_ms: default_max_backoff_ms(),
+            jitter: default_jitter(),
+        }
+    }
+}
+
+/// @ai:intent Settings for Claude-based comparison scoring
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompareConfig {
+    /// Comparisons scored in one Claude session before starting a fresh one (1 = no reuse)
+    #[serde(default = "default_comparisons_per_session")]
+    pub comparisons_per_session: u32,
+    /// Wall-clock limit per scoring call; the scorer's process tree is killed on expiry (0 = no limit)
+    #[serde(default = "default_compare_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Independent judge passes per comparison, combined by mean score and majority winner
+    #[serde(default = "default_judge_passes")]
+    pub judge_passes: u32,
+    /// Models used by the passes in turn (empty = the CLI's default model)
+    #[serde(default)]
+    pub judge_models: Vec<String>,
+    /// How runs with more than two implementations per task are ranked
+    #[serde(default)]
+    pub ranking: RankingStrategy,
+    /// Re-prompts asking the judge to restate an answer that cannot be parsed (0 = none)
+    #[serde(default = "default_parse_retries")]
+    pub parse_retries: u32,
+    /// Where the judge runs; None follows the run: the API for api runs, else the CLI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub judge_backend: Option<JudgeBackend>,
+    /// API judge: bytes of one file inlined into the prompt before it is truncated
+    #[serde(default = "default_judge_max_file_bytes")]
+    pub judge_max_file_bytes: usize,
+    /// API judge: bytes of source inlined per judgment, shared evenly by the implementations
+    #[serde(default = "default_judge_max_source_bytes")]
+    pub judge_max_source_bytes: usize,
+    /// Seed of the judges' random A/B orders; None draws one from the clock per run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+/// @ai:intent How the comparison judge reads the implementations
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum JudgeBackend {
+    /// Claude Code CLI in agentic mode, reading the files itself
+    ClaudeCode,
+    /// Messages API (requires ANTHROPIC_API_KEY), with the source files inlined in the prompt
+    Api,
+}
+
+impl JudgeBackend {
+    /// @ai:intent Config name of the judge backend
+    /// @ai:effects pure
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ClaudeCode => "claude-code",
+            Self::Api => "api",
+        }
+    }
+}
+
+/// @ai:intent How the judge compares more than two implementations of a task
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RankingStrategy {
+    /// Compare every pair of implementations and rank by net wins (default)
+    #[default]
+    Pairwise,
+    /// Show all implementations to the judge at once and let it rank them
+    Ranked,
+}
+
+impl RankingStrategy {
+    /// @ai:intent Config name of the strategy
+    /// @ai:effects pure
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pairwise => "pairwise",
+            Self::Ranked => "ranked",
+        }
+    }
+}
+
+impl Default for CompareConfig {
+    fn default() -> Self {
+        Self {
+            comparisons_per_session: default_comparisons_per_session(),
+            timeout_secs: default_compare_timeout_secs(),
+            judge_passes: default_judge_passes(),
+            judge_models: Vec::new(),
+            ranking: RankingStrategy::default(),
+            parse_retries: default_parse_retries(),
+            judge_backend: None,
+            judge_max_file_bytes: default_judge_max_file_bytes(),
+            judge_max_source_bytes: default_judge_max_source_bytes(),
+            seed: None,
+        }
+    }
+}
+
+fn default_comparisons_per_session() -> u32 {
+    1
+}
+
+fn default_judge_passes() -> u32 {
+    1
+}
+
+fn default_parse_retries() -> u32 {
+    1
+}
+
+fn default_judge_max_file_bytes() -> usize {
+    64 * 1024
+}
+
+fn default_judge_max_source_bytes() -> usize {
+    // Roughly 100k tokens of code, leaving room for the rubric and the answer
+    400 * 1024
+}
+
+/// @ai:intent Where generated code is compiled and tested
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SandboxConfig {
+    #[serde(default)]
+    pub kind: SandboxKind,
+    /// Images used with kind = "docker"; they must contain the language's toolchain
+    /// (and pytest for Python tests, vitest, jest or ts-node for TypeScript tests)
+    #[serde(default = "default_sandbox_rust_image")]
+    pub rust_image: String,
+    #[serde(default = "default_sandbox_python_image")]
+    pub python_image: String,
+    #[serde(default = "default_sandbox_typescript_image")]
+    pub typescript_image: String,
+    #[serde(default = "default_sandbox_go_image")]
+    pub go_image: String,
+    #[serde(default = "default_sandbox_java_image")]
+    pub java_image: String,
+    /// JUnit console launcher (junit-platform-console-standalone jar) as seen where tests
+    /// run, i.e. inside the image with kind = "docker"; Java tests need it
+    #[serde(default)]
+    pub junit_jar: Option<String>,
+    /// CPU limit per container (docker --cpus)
+    #[serde(default = "default_sandbox_cpus")]
+    pub cpus: f64,
+    /// Memory limit per container (docker --memory), e.g. "2g"
+    #[serde(default = "default_sandbox_memory")]
+    pub memory: String,
+    /// Give containers network access (off by default; dependencies cannot be downloaded)
+    #[serde(default)]
+    pub network: bool,
+    /// Wall-clock limit per compiler/test command; the command and its subprocesses are
+    /// killed on expiry (0 = no limit)
+    #[serde(default = "default_sandbox_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Bytes of stdout and of stderr kept per command; the rest is read and dropped
+    #[serde(default = "default_sandbox_max_output_bytes")]
+    pub max_output_bytes: usize,
+    /// CPU seconds each process may use (RLIMIT_CPU on Unix hosts, --ulimit in Docker; 0 = no limit)
+    #[serde(default = "default_sandbox_cpu_limit_secs")]
+    pub cpu_limit_secs: u64,
+    /// Address space per process in MiB (RLIMIT_AS, Unix hosts only; Docker uses memory)
+    /// Off by default: V8 and some allocators reserve far more than they use (0 = no limit)
+    #[serde(default)]
+    pub memory_limit_mb: u64,
+    /// How Rust builds of different executions share compiled artifacts
+    #[serde(default)]
+    pub rust_build_cache: RustBuildCache,
+    /// Pre-downloaded third-party packages for tasks with dependencies
+    #[serde(default)]
+    pub vendor: VendorConfig,
+}
+
+impl Default for SandboxConfig {
+    fn default() -> Self {
+        Self {
+            kind: SandboxKind::default(),
+            rust_image: default_sandbox_rust_image(),
+            python_image: default_sandbox_python_image(),
+            typescript_image: default_sandbox_typescript_image(),
+            go_image: default_sandbox_go_image(),
+            java_image: default_sandbox_java_image(),
+            junit_jar: None,
+            cpus: default_sandbox_cpus(),
+            memory: default_sandbox_memory(),
+            network: false,
+            timeout_secs: default_sandbox_timeout_secs(),
+            max_output_bytes: default_sandbox_max_output_bytes(),
+            cpu_limit_secs: default_sandbox_cpu_limit_secs(),
+            memory_limit_mb: 0,
+            rust_build_cache: RustBuildCache::default(),
+            vendor: VendorConfig::default(),
+        }
+    }
+}
+
+/// @ai:intent Host directories of pre-downloaded packages, so generated projects with
+///            dependencies build and test without network access (mounted read-only
+///            with kind = "docker")
+/// @ai:effects pure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VendorConfig {
+    /// Crate sources from `cargo vendor`; cargo then builds offline, from them only
+    #[serde(default)]
+    pub cargo: Option<PathBuf>,
+    /// Wheels from `pip download -d`; a requirements.txt is installed from them
+    #[serde(default)]
+    pub wheels: Option<PathBuf>,
+    /// npm cache holding the packages (e.g. filled by `npm cache add`); a package.json is
+    /// installed from it
+    #[serde(default)]
+    pub npm_cache: Option<PathBuf>,
+}
+
+/// @ai:intent Available evaluation sandboxes
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SandboxKind {
+    /// Run compilers and tests directly on the host (default)
+    #[default]
+    Host,
+    /// Run each compiler/test command in a throwaway Docker container
+    Docker,
+}
+
+/// @ai:intent Build caches for Rust evaluation
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RustBuildCache {
+    /// Cold build in every project's own target/ directory
+    Off,
+    /// One CARGO_TARGET_DIR per run; dependencies are compiled once, and the
+    /// project's own packages are always rebuilt (default)
+    #[default]
+    SharedTarget,
+    /// RUSTC_WRAPPER=sccache; sccache must be installed where builds run
+    Sccache,
+}
+
+/// @ai:intent Evaluation parallelism and optional (slow) evaluation stages
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluationConfig {
+    /// Run cargo-mutants (Rust) or mutmut (Python) against generated code whose own tests pass
+    #[serde(default)]
+    pub mutation_testing: bool,
+    /// Seconds each mutant's test run may take before it counts as caught by timeout
+    #[serde(default = "default_mutation_timeout_secs")]
+    pub mutation_timeout_secs: u64,
+    /// Run the generated tests a second time and flag executions whose runs disagree
+    #[serde(default)]
+    pub flaky_test_detection: bool,
+    /// Executions evaluated at once, while further executions run (0 = one per CPU core)
+    #[serde(default)]
+    pub workers: usize,
+    /// Type-check generated Python code with mypy or pyright (None = not type checked)
+    #[serde(default)]
+    pub python_type_checker: Option<PythonTypeChecker>,
+    /// How generated annotations are matched against expected ones
+    #[serde(default)]
+    pub annotation_matching: AnnotationMatchingConfig,
+    /// Share (0.0-1.0) of an output's token n-grams found in a reference solution or another
+    /// task's output from which it is flagged as contaminated
+    #[serde(default = "default_contamination_threshold")]
+    pub contamination_threshold: f64,
+    /// Audit dependencies with cargo-audit (Rust), pip-audit (Python) or npm audit
+    /// (TypeScript); dangerous code patterns are always checked
+    #[serde(default)]
+    pub security_audit: bool,
+    /// Names of evaluation stages not to run (see Evaluator::stage_names)
+    #[serde(default)]
+    pub disabled_stages: Vec<String>,
+    /// Lowest annotation lint severity that fails an execution's lint check, as `aicms lint
+    /// --fail-on`
+    #[serde(default)]
+    pub lint_fail_on: FailOn,
+    /// Annotation lint warnings an execution may have before its lint check fails, as
+    /// `aicms lint --max-warnings` (None = no limit)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lint_max_warnings: Option<usize>,
+}
+
+impl Default for EvaluationConfig {
+    fn default() -> Self {
+        Self {
+            mutation_testing: false,
+            mutation_timeout_secs: default_mutation_timeout_secs(),
+            flaky_test_detection: false,
+            workers: 0,
+            python_type_checker: None,
+            annotation_matching: AnnotationMatchingConfig::default(),
+            contamination_threshold: default_contamination_threshold(),
+            security_audit: false,
+            disabled_stages: vec![],
+            lint_fail_on: FailOn::default(),
+            lint_max_warnings: None,
+        }
+    }
+}
+
+impl EvaluationConfig {
+    /// @ai:intent Number of evaluation workers, resolving 0 to the available cores
+    /// @ai:post result >= 1
+    /// @ai:effects env
+    pub fn worker_count(&self) -> usize {
+        match self.workers {
+            0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+            n => n,
+        }
+    }
+}
+
+/// @ai:intent How aggregate results are summarized statistically
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatisticsConfig {
+    /// Bootstrap resamples behind the confidence intervals (0 = no intervals)
+    #[serde(default = "default_bootstrap_resamples")]
+    pub bootstrap_resamples: u32,
+    /// How judge ties count in the binomial test of the comparison win rate
+    #[serde(default)]
+    pub ties: TieHandling,
+    /// How custom metrics recorded by evaluation stages combine over executions, by name
+    /// (metrics not listed are averaged)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom_metrics: BTreeMap<String, CustomAggregation>,
+    /// Weight of each component of the composite score, by metric name (empty = no composite)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub composite: BTreeMap<String, f64>,
+    /// Weight of each difficulty ("easy", "medium", "hard") in the difficulty-normalized
+    /// rates (empty = not reported)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub difficulty_weights: BTreeMap<String, f64>,
+    /// How executions whose code could not be extracted enter the aggregates
+    #[serde(default)]
+    pub missing_data: MissingDataPolicy,
+}
+
+impl Default for StatisticsConfig {
+    fn default() -> Self {
+        Self {
+            bootstrap_resamples: default_bootstrap_resamples(),
+            ties: TieHandling::default(),
+            custom_metrics: BTreeMap::new(),
+            composite: BTreeMap::new(),
+            difficulty_weights: BTreeMap::new(),
+            missing_data: MissingDataPolicy::default(),
+        }
+    }
+}
+
+/// @ai:intent How judge ties count when testing AICMS wins against a 50% win rate
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TieHandling {
+    /// Leave ties out and test AICMS wins against baseline wins (the sign test; default)
+    #[default]
+    Exclude,
+    /// Count each pair of ties as one win for each side (an odd tie is left out)
+    Split,
+    /// Count ties against AICMS, so only outright wins support it
+    Loss,
+}
+
+impl TieHandling {
+    /// @ai:intent Config name of the tie handling
+    /// @ai:effects pure
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Exclude => "exclude",
+            Self::Split => "split",
+            Self::Loss => "loss",
+        }
+    }
+}
+
+/// @ai:intent How executions without extracted code count in the aggregated metrics
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MissingDataPolicy {
+    /// Count them as failed executions with every rate at 0% (default)
+    #[default]
+    Zero,
+    /// Leave them out, as if they had not run
+    Exclude,
+    /// Give them the average rates of the task's repetitions in the same mode that produced
+    /// code; left out when none did
+    Impute,
+}
+
+impl MissingDataPolicy {
+    /// @ai:intent Config name of the policy
+    /// @ai:effects pure
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::Exclude => "exclude",
+            Self::Impute => "impute",
+        }
+    }
+}
+
+/// @ai:intent How the values of a custom metric combine over the executions that recorded it
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CustomAggregation {
+    /// Average over the executions (default)
+    #[default]
+    Mean,
+    /// Total over the executions (counts, e.g. findings)
+    Sum,
+    /// Highest value of any execution (worst cases, e.g. peak memory)
+    Max,
+}
+
+impl CustomAggregation {
+    /// @ai:intent Config name of the aggregation
+    /// @ai:effects pure
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mean => "mean",
+            Self::Sum => "sum",
+            Self::Max => "max",
+        }
+    }
+
+    /// @ai:intent Combine the values (None if there are none)
+    /// @ai:effects pure
+    /// @ai:example (Sum, [1.0, 2.0]) -> Some(3.0)
+    pub fn apply(&self, values: &[f64]) -> Option<f64> {
+        if values.is_empty() {
+            return None;
+        }
+        Some(match self {
+            Self::Mean => values.iter().sum::<f64>() / values.len() as f64,
+            Self::Sum => values.iter().sum(),
+            Self::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        })
+    }
+}
+
+/// @ai:intent Where run metrics are published besides the reports
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportConfig {
+    /// Prometheus Pushgateway the metrics of each run are pushed to (None = no push)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pushgateway_url: Option<String>,
+    /// Job label of the pushed metrics; each push replaces the job's previous metrics
+    #[serde(default = "default_export_job")]
+    pub job: String,
+}
+
+impl Default for ExportConfig {
+    fn default() -> Self {
+        Self {
+            pushgateway_url: None,
+            job: default_export_job(),
+        }
+    }
+}
+
+/// @ai:intent Static type checkers for generated Python code
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PythonTypeChecker {
+    /// `python -m mypy`, with missing imports ignored
+    Mypy,
+    /// The `pyright` CLI, with missing imports ignored
+    Pyright,
+}
+
+/// @ai:intent Settings for matching generated annotations against expected ones (the gold
+///            annotations of inference tasks)
+/// @ai:effects pure
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnotationMatchingConfig {
+    /// Token overlap (0.0-1.0) from which two annotations match
+    #[serde(default = "default_token_overlap_threshold")]
+    pub threshold: f64,
+    /// Judge for expected annotations without a token-overlap match (None = token overlap only)
+    #[serde(default)]
+    pub judge: Option<AnnotationJudgeKind>,
+    /// Ollama server computing embeddings for the embedding judge
+    #[serde(default = "default_ollama_endpoint")]
+    pub embedding_endpoint: String,
+    #[serde(default = "default_embedding_model")]
+    pub embedding_model: String,
+    /// Cosine similarity (0.0-1.0) from which the embedding judge matches two annotations
+    #[serde(default = "default_embedding_threshold")]
+    pub embedding_threshold: f64,
+}
+
+impl Default for AnnotationMatchingConfig {
+    fn default() -> Self {
+        Self {
+            threshold: default_token_overlap_threshold(),
+            judge: None,
+            embedding_endpoint: default_ollama_endpoint(),
+            embedding_model: default_embedding_model(),
+            embedding_threshold: default_embedding_threshold(),
+        }
+    }
+}
+
+/// @ai:intent Judges deciding whether two differently worded annotations mean the same
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AnnotationJudgeKind {
+    /// Cosine similarity of embeddings from an Ollama server
+    Embedding,
+    /// Equivalence judgment by the Claude Code CLI
+    Claude,
+}
+
+fn default_token_overlap_threshold() -> f64 {
+    0.5
+}
+
+fn default_embedding_model() -> String {
+    "nomic-embed-text".to_string()
+}
+
+fn default_embedding_threshold() -> f64 {
+    0.8
+}
+
+fn default_mutation_timeout_secs() -> u64 {
+    60
+}
+
+fn default_contamination_threshold() -> f64 {
+    0.5
+}
+
+fn default_bootstrap_resamples() -> u32 {
+    2000
+}
+
+fn default_export_job() -> String {
+    "aicms_bench".to_string()
+}
+
+fn default_sandbox_rust_image() -> String {
+    "rust:1-slim".to_string()
+}
+
+fn default_sandbox_python_image() -> String {
+    "python:3-slim".to_string()
+}
+
+fn default_sandbox_typescript_image() -> String {
+    "node:20-slim".to_string()
+}
+
+fn default_sandbox_go_image() -> String {
+    "golang:1".to_string()
+}
+
+fn default_sandbox_java_image() -> String {
+    "eclipse-temurin:21-jdk".to_string()
+}
+
+fn default_sandbox_cpus() -> f64 {
+    2.0
+}
+
+fn default_sandbox_memory() -> String {
+    "2g".to_string()
+}
+
+fn default_sandbox_timeout_secs() -> u64 {
+    600
+}
+
+fn default_sandbox_max_output_bytes() -> usize {
+    1024 * 1024
+}
+
+fn default_sandbox_cpu_limit_secs() -> u64 {
+    600
+}
+
+fn default_compare_timeout_secs() -> u64 {
+    600
+}
+
+/// @ai:intent Price of a model in USD per million tokens
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPricing {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+    /// Prompt cache writes; defaults to 125% of the input price
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_write_per_mtok: Option<f64>,
+    /// Prompt cache reads; defaults to 10% of the input price
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_read_per_mtok: Option<f64>,
+}
+
+impl ModelPricing {
+    /// @ai:intent Price with the default cache multipliers
+    /// @ai:effects pure
+    pub const fn new(input_per_mtok: f64, output_per_mtok: f64) -> Self {
+        Self {
+            input_per_mtok,
+            output_per_mtok,
+            cache_write_per_mtok: None,
+            cache_read_per_mtok: None,
+        }
+    }
+
+    /// @ai:intent Cost of one execution in USD
+    ///            input_tokens includes the cached tokens, which are billed at the cache prices
+    /// @ai:pre cache_creation_tokens + cache_read_tokens <= input_tokens
+    /// @ai:effects pure
+    /// @ai:example ($3/$15, 1_000_000 input, 100_000 output, no cache) -> 4.5
+    pub fn cost(
+        &self,
+        input_tokens: u32,
+        output_tokens: u32,
+        cache_creation_tokens: u32,
+        cache_read_tokens: u32,
+    ) -> f64 {
+        let uncached = input_tokens.saturating_sub(cache_creation_tokens + cache_read_tokens);
+        let cache_write = self.cache_write_per_mtok.unwrap_or(self.input_per_mtok * 1.25);
+        let cache_read = self.cache_read_per_mtok.unwrap_or(self.input_per_mtok * 0.1);
+
+        (uncached as f64 * self.input_per_mtok
+            + cache_creation_tokens as f64 * cache_write
+            + cache_read_tokens as f64 * cache_read
+            + output_tokens as f64 * self.output_per_mtok)
+            / 1_000_000.0
+    }
+}
+
+/// Built-in list prices (USD per million tokens) by model name fragment
+const DEFAULT_PRICING: &[(&str, ModelPricing)] = &[
+    ("claude-opus-4", ModelPricing::new(15.0, 75.0)),
+    ("claude-opus-4-5", ModelPricing::new(5.0, 25.0)),
+    ("claude-sonnet-4", ModelPricing::new(3.0, 15.0)),
+    ("claude-haiku-4-5", ModelPricing::new(1.0, 5.0)),
+    ("claude-3-7-sonnet", ModelPricing::new(3.0, 15.0)),
+    ("claude-3-5-sonnet", ModelPricing::new(3.0, 15.0)),
+    ("claude-3-5-haiku", ModelPricing::new(0.8, 4.0)),
+];
+
+/// @ai:intent System prompt sent in each mode (API backends; Claude Code uses CLAUDE.md)
+/// @ai:effects pure
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PromptConfig {
+    #[serde(default)]
+    pub baseline_system: SystemPromptSource,
+    #[serde(default)]
+    pub aicms_system: SystemPromptSource,
+}
+
+/// @ai:intent Where a mode's system prompt comes from
+///            Written in TOML as "default", "skill", "none", or a file path
+/// @ai:effects pure
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum SystemPromptSource {
+    /// prompts_dir/baseline.md
+    #[default]
+    Default,
+    /// The AICMS skill file (paths.skill_file)
+    Skill,
+    /// No system prompt at all
+    None,
+    File(PathBuf),
+}
+
+impl SystemPromptSource {
+    /// @ai:intent File to read the system prompt from, or None for no system prompt
+    /// @ai:effects pure
+    pub fn path(&self, paths: &PathConfig) -> Option<PathBuf> {
+        match self {
+            SystemPromptSource::Default => Some(paths.prompts_dir.join("baseline.md")),
+            SystemPromptSource::Skill => Some(paths.skill_file.clone()),
+            SystemPromptSource::None => None,
+            SystemPromptSource::File(path) => Some(path.clone()),
+        }
+    }
+}
+
+impl From<String> for SystemPromptSource {
+    fn from(value: String) -> Self {
+        match value.as_str() {
+            "default" => SystemPromptSource::Default,
+            "skill" => SystemPromptSource::Skill,
+            "none" => SystemPromptSource::None,
+            _ => SystemPromptSource::File(PathBuf::from(value)),
+        }
+    }
+}
+
+impl From<SystemPromptSource> for String {
+    fn from(source: SystemPromptSource) -> Self {
+        match source {
+            SystemPromptSource::Default => "default".to_string(),
+            SystemPromptSource::Skill => "skill".to_string(),
+            SystemPromptSource::None => "none".to_string(),
+            SystemPromptSource::File(path) => path.to_string_lossy().to_string(),
         }
     }
 }
@@ -101,7 +1252,11 @@ impl Default for PathConfig {
             prompts_dir: PathBuf::from("prompts"),
             results_dir: PathBuf::from("results"),
             skill_file: PathBuf::from("../skills/aicms/SKILL.md"),
+            skill_variants: vec![],
             comparison_prompt_file: default_comparison_prompt(),
+            comparison_rubric_file: default_comparison_rubric(),
+            ranking_prompt_file: default_ranking_prompt(),
+            claude_md_template: default_claude_md_template(),
         }
     }
 }
@@ -110,6 +1265,18 @@ fn default_comparison_prompt() -> PathBuf {
     PathBuf::from("prompts/comparison.md")
 }
 
+fn default_comparison_rubric() -> PathBuf {
+    PathBuf::from("prompts/rubric.toml")
+}
+
+fn default_ranking_prompt() -> PathBuf {
+    PathBuf::from("prompts/ranking.md")
+}
+
+fn default_claude_md_template() -> PathBuf {
+    PathBuf::from("prompts/claude_md.md")
+}
+
 fn default_model() -> String {
     "claude-sonnet-4-20250514".to_string()
 }
@@ -122,10 +1289,66 @@ fn default_rate_limit() -> u32 {
     60
 }
 
+fn default_stream() -> bool {
+    true
+}
+
+fn default_prompt_caching() -> bool {
+    true
+}
+
+fn default_ollama_endpoint() -> String {
+    "http://localhost:11434".to_string()
+}
+
+fn default_ollama_model() -> String {
+    "qwen2.5-coder:7b".to_string()
+}
+
+fn default_ollama_timeout_secs() -> u64 {
+    600
+}
+
+fn default_claude_code_timeout_secs() -> u64 {
+    1800
+}
+
+fn default_bedrock_model_id() -> String {
+    "anthropic.claude-sonnet-4-20250514-v1:0".to_string()
+}
+
+fn default_vertex_region() -> String {
+    "us-east5".to_string()
+}
+
+fn default_vertex_model() -> String {
+    "claude-sonnet-4@20250514".to_string()
+}
+
 fn default_repetitions() -> u32 {
     1
 }
 
+fn default_concurrency() -> usize {
+    1
+}
+
+fn default_max_attempts() -> u32 {
+    3
+}
+
+fn default_initial_backoff_ms() -> u64 {
+    1000
+}
+
+fn default_max_backoff_ms() -> u64 {
+    60_000
+}
+
+fn default_jitter() -> f64 {
+    0.2
+}
+
 impl BenchmarkConfig {
     /// @ai:intent Load configuration from a TOML file
     /// @ai:pre path exists and is readable
@@ -136,6 +1359,191 @@ impl BenchmarkConfig {
         Ok(config)
     }
 
+    /// @ai:intent Backend of the comparison judge: [compare] judge_backend, else the API for
+    ///            api runs and the Claude Code CLI for all others
+    /// @ai:effects pure
+    pub fn judge_backend(&self) -> JudgeBackend {
+        let following_run = match self.backend.kind {
+            BackendKind::Api => JudgeBackend::Api,
+            _ => JudgeBackend::ClaudeCode,
+        };
+        self.compare.judge_backend.unwrap_or(following_run)
+    }
+
+    /// @ai:intent Name of the model that generates code for the selected backend
+    /// @ai:effects pure
+    pub fn model_name(&self) -> &str {
+        match self.backend.kind {
+            BackendKind::Ollama => &self.backend.ollama.model,
+            BackendKind::Bedrock => &self.backend.bedrock.model_id,
+            BackendKind::Vertex => &self.backend.vertex.model,
+            BackendKind::ClaudeCode | BackendKind::Api => &self.api.model,
+        }
+    }
+
+    /// @ai:intent Price of a model: the longest [pricing] key contained in its name,
+    ///            else the longest built-in key (Bedrock and Vertex IDs embed the Anthropic name)
+    /// @ai:effects pure
+    /// @ai:example ("anthropic.claude-sonnet-4-20250514-v1:0") -> Some($3/$15)
+    pub fn pricing_for(&self, model: &str) -> Option<ModelPricing> {
+        fn longest_match<'a>(
+            entries: impl Iterator<Item = (&'a str, &'a ModelPricing)>,
+            model: &str,
+        ) -> Option<ModelPricing> {
+            entries
+                .filter(|(key, _)| model.contains(key))
+                .max_by_key(|(key, _)| key.len())
+                .map(|(_, pricing)| *pricing)
+        }
+
+        longest_match(self.pricing.iter().map(|(k, p)| (k.as_str(), p)), model).or_else(|| {
+            longest_match(DEFAULT_PRICING.iter().map(|(k, p)| (*k, p)), model)
+        })
+    }
+
+    /// @ai:intent Models to benchmark: the [api] models list, or the backend's single model
+    /// @ai:post result is non-empty
+    /// @ai:effects pure
+    pub fn models(&self) -> Vec<String> {
+        if self.api.models.is_empty() {
+            vec![self.model_name().to_string()]
+        } else {
+            self.api.models.clone()
+        }
+    }
+
+    /// @ai:intent Copy of this config with the selected backend's model replaced
+    /// @ai:post result.model_name() == model
+    /// @ai:effects pure
+    pub fn for_model(&self, model: &str) -> Self {
+        let mut config = self.clone();
+        match config.backend.kind {
+            BackendKind::Ollama => config.backend.ollama.model = model.to_string(),
+            BackendKind::Bedrock => config.backend.bedrock.model_id = model.to_string(),
+            BackendKind::Vertex => config.backend.vertex.model = model.to_string(),
+            BackendKind::ClaudeCode | BackendKind::Api => config.api.model = model.to_string(),
+        }
+        config
+    }
+
+    /// @ai:intent Check if the run sweeps temperatures or seeds
+    /// @ai:effects pure
+    pub fn is_sweep(&self) -> bool {
+        !self.api.temperatures.is_empty() || !self.api.seeds.is_empty()
+    }
+
+    /// @ai:intent Sampling settings to run: every temperature x seed combination,
+    ///            falling back to the single configured temperature and seed
+    /// @ai:post result is non-empty
+    /// @ai:effects pure
+    pub fn sampling_settings(&self) -> Vec<SamplingSetting> {
+        let temperatures = if self.api.temperatures.is_empty() {
+            vec![self.api.temperature]
+        } else {
+            self.api.temperatures.clone()
+        };
+        let seeds: Vec<Option<u64>> = if self.api.seeds.is_empty() {
+            vec![self.api.seed]
+        } else {
+            self.api.seeds.iter().copied().map(Some).collect()
+        };
+
+        temperatures
+            .iter()
+            .flat_map(|&temperature| {
+                seeds
+                    .iter()
+                    .map(move |&seed| SamplingSetting { temperature, seed })
+            })
+            .collect()
+    }
+
+    /// @ai:intent Copy of this config with the temperature and seed replaced
+    /// @ai:post result.sampling_label() == Some(setting.label()) when sweeping
+    /// @ai:effects pure
+    pub fn for_sampling(&self, setting: SamplingSetting) -> Self {
+        let mut config = self.clone();
+        config.api.temperature = setting.temperature;
+        config.api.seed = setting.seed;
+        config
+    }
+
+    /// @ai:intent Label of the current sampling setting, or None outside a sweep
+    /// @ai:effects pure
+    pub fn sampling_label(&self) -> Option<String> {
+        self.is_sweep().then(|| {
+            SamplingSetting {
+                temperature: self.api.temperature,
+                seed: self.api.seed,
+            }
+            .label()
+        })
+    }
+
+    /// @ai:intent Check if the run compares several skill files
+    /// @ai:effects pure
+    pub fn is_skill_matrix(&self) -> bool {
+        !self.paths.skill_variants.is_empty()
+    }
+
+    /// @ai:intent Skill variants to run, with directories expanded to their *.md files
+    /// @ai:post labels are unique
+    /// @ai:effects fs:read
+    pub fn skill_variants(&self) -> anyhow::Result<Vec<SkillVariant>> {
+        let mut variants: Vec<SkillVariant> = Vec::new();
+        for entry in &self.paths.skill_variants {
+            let mut files = Vec::new();
+            if entry.is_dir() {
+                for file in std::fs::read_dir(entry)? {
+                    let path = file?.path();
+                    if path.extension().is_some_and(|e| e == "md") {
+                        files.push(path);
+                    }
+                }
+                files.sort();
+                if files.is_empty() {
+                    anyhow::bail!("No *.md skill variants in {}", entry.display());
+                }
+            } else if entry.is_file() {
+                files.push(entry.clone());
+            } else {
+                anyhow::bail!("Skill variant {} does not exist", entry.display());
+            }
+
+            for file in files {
+                let variant = SkillVariant::from_path(file);
+                if let Some(other) = variants.iter().find(|v| v.label == variant.label) {
+                    anyhow::bail!(
+                        "Skill variants {} and {} share the label '{}'; rename one",
+                        other.path.display(),
+                        variant.path.display(),
+                        variant.label
+                    );
+                }
+                variants.push(variant);
+            }
+        }
+        Ok(variants)
+    }
+
+    /// @ai:intent Copy of this config running only the AICMS arm with a skill variant
+    /// @ai:post result.skill_label() == Some(variant.label) in a skill matrix
+    /// @ai:effects pure
+    pub fn for_skill(&self, variant: &SkillVariant) -> Self {
+        let mut config = self.clone();
+        config.paths.skill_file = variant.path.clone();
+        config.run.modes = ModeSelection::Aicms;
+        config
+    }
+
+    /// @ai:intent Label of the current skill variant, or None outside a skill matrix
+    ///            and for its baseline arm
+    /// @ai:effects pure
+    pub fn skill_label(&self) -> Option<String> {
+        (self.is_skill_matrix() && self.run.modes == ModeSelection::Aicms)
+            .then(|| SkillVariant::from_path(self.paths.skill_file.clone()).label)
+    }
+
     /// @ai:intent Save configuration to a TOML file
     /// @ai:effects fs:write
     pub fn save(&self, path: &std::path::Path) -> anyhow::Result<()> {
@@ -208,4 +1616,162 @@ mod tests {
         assert!(filter.matches("implement", "python", "medium", "other-task"));
         assert!(!filter.matches("implement", "typescript", "easy", "test-task"));
     }
+
+    #[test]
+    fn test_backend_section_parses() {
+        let config: BenchmarkConfig = toml::from_str(
+            r#"
+            [api]
+            [run]
+            [paths]
+            corpus_dir = "corpus"
+            prompts_dir = "prompts"
+            results_dir = "results"
+            skill_file = "SKILL.md"
+
+            [backend]
+            kind = "ollama"
+
+            [backend.ollama]
+            model = "llama3.1:8b"
+
+            [backend.claude_code.mcp_servers.docs]
+            command = "npx"
+            args = ["-y", "docs-mcp-server"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.backend.kind, BackendKind::Ollama);
+        assert_eq!(config.backend.ollama.endpoint, "http://localhost:11434");
+        assert_eq!(config.model_name(), "llama3.1:8b");
+        assert_eq!(config.backend.vertex.region, "us-east5");
+        assert_eq!("vertex".parse::<BackendKind>(), Ok(BackendKind::Vertex));
+        assert_eq!(config.backend.claude_code.permission_profile, PermissionProfile::Bypass);
+        assert_eq!(config.backend.claude_code.mcp_servers["docs"].args.len(), 2);
+        assert_eq!(config.judge_backend(), JudgeBackend::ClaudeCode);
+    }
+
+    #[test]
+    fn test_judge_backend_follows_run() {
+        let mut config = BenchmarkConfig::default();
+        config.backend.kind = BackendKind::Api;
+        assert_eq!(config.judge_backend(), JudgeBackend::Api);
+
+        config.compare = toml::from_str(r#"judge_backend = "claude-code""#).unwrap();
+        assert_eq!(config.judge_backend(), JudgeBackend::ClaudeCode);
+        assert_eq!(config.compare.judge_max_file_bytes, 64 * 1024);
+    }
+
+    #[test]
+    fn test_models_matrix() {
+        let mut config = BenchmarkConfig::default();
+        assert_eq!(config.models(), vec!["claude-sonnet-4-20250514"]);
+
+        config.api.models = vec!["qwen2.5-coder:7b".to_string(), "llama3.1:8b".to_string()];
+        config.backend.kind = BackendKind::Ollama;
+
+        assert_eq!(config.models().len(), 2);
+        let second = config.for_model(&config.models()[1]);
+        assert_eq!(second.model_name(), "llama3.1:8b");
+        assert_eq!(second.api.model, config.api.model);
+    }
+
+    #[test]
+    fn test_sampling_sweep() {
+        let mut config = BenchmarkConfig::default();
+        assert!(!config.is_sweep());
+        assert_eq!(config.sampling_settings().len(), 1);
+        assert_eq!(config.sampling_label(), None);
+
+        config.api.temperatures = vec![0.0, 0.7];
+        config.api.seeds = vec![1, 2];
+
+        let settings = config.sampling_settings();
+        assert_eq!(settings.len(), 4);
+        assert_eq!(settings[3].dir_name(), "t0.7-seed2");
+
+        let last = config.for_sampling(settings[3]);
+        assert_eq!(last.api.temperature, 0.7);
+        assert_eq!(last.sampling_label().as_deref(), Some("t=0.7 seed=2"));
+    }
+
+    #[test]
+    fn test_skill_variants() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let variants_dir = temp.path().join("variants");
+        std::fs::create_dir_all(variants_dir.join("v2")).unwrap();
+        std::fs::write(variants_dir.join("terse.md"), "terse").unwrap();
+        std::fs::write(variants_dir.join("notes.txt"), "ignored").unwrap();
+        std::fs::write(variants_dir.join("v2").join("SKILL.md"), "v2").unwrap();
+
+        let mut config = BenchmarkConfig::default();
+        assert!(!config.is_skill_matrix());
+        assert_eq!(config.skill_label(), None);
+
+        config.paths.skill_variants = vec![variants_dir.clone(), variants_dir.join("v2/SKILL.md")];
+        let variants = config.skill_variants().unwrap();
+        let labels: Vec<&str> = variants.iter().map(|v| v.label.as_str()).collect();
+        assert_eq!(labels, ["terse", "v2"]);
+        assert_eq!(variants[1].dir_name(), "skill-v2");
+
+        let terse = config.for_skill(&variants[0]);
+        assert_eq!(terse.run.modes, ModeSelection::Aicms);
+        assert_eq!(terse.skill_label().as_deref(), Some("terse"));
+
+        config.paths.skill_variants.push(variants_dir.join("terse.md"));
+        assert!(config.skill_variants().is_err());
+    }
+
+    #[test]
+    fn test_pricing_lookup() {
+        let mut config = BenchmarkConfig::default();
+        let sonnet = config
+            .pricing_for("anthropic.claude-sonnet-4-20250514-v1:0")
+            .unwrap();
+        assert!((sonnet.cost(1_000_000, 100_000, 0, 0) - 4.5).abs() < 1e-9);
+        // 100k cache reads at 10% of $3 plus 900k uncached at $3
+        assert!((sonnet.cost(1_000_000, 0, 0, 100_000) - 2.73).abs() < 1e-9);
+        assert_eq!(
+            config.pricing_for("claude-opus-4-5-20251101"),
+            Some(ModelPricing::new(5.0, 25.0))
+        );
+        assert_eq!(config.pricing_for("llama3.1:8b"), None);
+
+        config
+            .pricing
+            .insert("llama3.1".to_string(), ModelPricing::new(0.1, 0.1));
+        assert!(config.pricing_for("llama3.1:8b").is_some());
+    }
+
+    #[test]
+    fn test_prompts_section_parses() {
+        let config: BenchmarkConfig = toml::from_str(
+            r#"
+            [api]
+            [run]
+            [paths]
+            corpus_dir = "corpus"
+            prompts_dir = "prompts"
+            results_dir = "results"
+            skill_file = "SKILL.md"
+
+            [prompts]
+            baseline_system = "none"
+            aicms_system = "skill"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.prompts.baseline_system, SystemPromptSource::None);
+        assert_eq!(config.prompts.baseline_system.path(&config.paths), None);
+        assert_eq!(
+            config.prompts.aicms_system.path(&config.paths),
+            Some(PathBuf::from("SKILL.md"))
+        );
+        assert_eq!(
+            SystemPromptSource::from("prompts/terse.md".to_string()),
+            SystemPromptSource::File(PathBuf::from("prompts/terse.md"))
+        );
+    }
 }
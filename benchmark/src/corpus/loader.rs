@@ -42,7 +42,22 @@ impl CorpusLoader {
         let task_file: TaskFile = toml::from_str(&content)
             .with_context(|| format!("Failed to parse task file: {}", path.display()))?;
 
-        Ok(task_file.into())
+        let template_path = task_file
+            .task
+            .prompt_template
+            .as_ref()
+            .map(|template| path.parent().unwrap_or(Path::new(".")).join(template));
+
+        let mut task: Task = task_file.into();
+
+        if let Some(template_path) = template_path {
+            let template = std::fs::read_to_string(&template_path).with_context(|| {
+                format!("Failed to read prompt template: {}", template_path.display())
+            })?;
+            task.prompt_template = Some(template);
+        }
+
+        Ok(task)
     }
 
     /// @ai:intent Find all TOML files in directory
@@ -119,6 +134,7 @@ impl CorpusLoaderTrait for CorpusLoader {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::corpus::ApiKind;
     use std::io::Write;
     use tempfile::TempDir;
 
@@ -189,4 +205,146 @@ description = "A python task"
         assert_eq!(tasks.len(), 1);
         assert_eq!(tasks[0].id, "rust-task");
     }
+
+    #[test]
+    fn test_load_prompt_template_relative_to_task_file() {
+        let temp = TempDir::new().unwrap();
+        let content = r#"
+[task]
+id = "templated-task"
+name = "Templated Task"
+category = "implement"
+language = "rust"
+difficulty = "easy"
+description = "A task with its own prompt"
+prompt_template = "templates/templated.md"
+"#;
+        create_test_task(temp.path(), "rust/templated.toml", content);
+        create_test_task(temp.path(), "rust/templates/templated.md", "# {{TASK_NAME}}");
+
+        let tasks = CorpusLoader::new().load_all(temp.path()).unwrap();
+        assert_eq!(tasks[0].prompt_template.as_deref(), Some("# {{TASK_NAME}}"));
+    }
+
+    #[test]
+    fn test_load_hidden_tests() {
+        let temp = TempDir::new().unwrap();
+        let content = r#"
+[task]
+id = "hidden-task"
+name = "Hidden Task"
+category = "implement"
+language = "python"
+difficulty = "easy"
+description = "A task with reference tests"
+
+[[hidden_tests]]
+path = "tests/test_hidden.py"
+code = "def test_ok():\n    assert True\n"
+"#;
+        create_test_task(temp.path(), "hidden.toml", content);
+
+        let tasks = CorpusLoader::new().load_all(temp.path()).unwrap();
+        assert_eq!(tasks[0].hidden_tests.len(), 1);
+        assert_eq!(tasks[0].hidden_tests[0].path, "tests/test_hidden.py");
+        assert!(tasks[0].contract_traps.is_empty());
+    }
+
+    #[test]
+    fn test_load_io_cases() {
+        let temp = TempDir::new().unwrap();
+        let content = r#"
+[task]
+id = "io-task"
+name = "IO Task"
+category = "implement"
+language = "python"
+difficulty = "easy"
+description = "A command-line task"
+
+[io]
+entry = "wc.py"
+
+[[io.cases]]
+name = "counts lines"
+args = ["-l"]
+stdin = "a\nb\n"
+stdout = "2\n"
+
+[[io.cases]]
+name = "rejects unknown flags"
+args = ["--bogus"]
+stdout = ""
+exit_code = 2
+timeout_secs = 5
+"#;
+        create_test_task(temp.path(), "io.toml", content);
+
+        let tasks = CorpusLoader::new().load_all(temp.path()).unwrap();
+        let io = tasks[0].io.as_ref().unwrap();
+        assert_eq!(io.entry.as_deref(), Some("wc.py"));
+        assert_eq!(io.cases[0].stdin, "a\nb\n");
+        assert_eq!((io.cases[0].exit_code, io.cases[0].timeout_secs), (0, 10));
+        assert_eq!((io.cases[1].exit_code, io.cases[1].timeout_secs), (2, 5));
+    }
+
+    #[test]
+    fn test_load_edge_cases() {
+        let temp = TempDir::new().unwrap();
+        let content = r#"
+[task]
+id = "edge-task"
+name = "Edge Task"
+category = "implement"
+language = "rust"
+difficulty = "easy"
+description = "A task with edge cases"
+
+[[edge_cases]]
+name = "empty input"
+
+[[edge_cases]]
+name = "overflow"
+patterns = ['u64::MAX']
+"#;
+        create_test_task(temp.path(), "edge.toml", content);
+
+        let tasks = CorpusLoader::new().load_all(temp.path()).unwrap();
+        assert_eq!(tasks[0].edge_cases.len(), 2);
+        assert!(tasks[0].edge_cases[0].patterns.is_empty());
+        assert_eq!(tasks[0].edge_cases[1].patterns, vec!["u64::MAX".to_string()]);
+    }
+
+    #[test]
+    fn test_load_required_api() {
+        let temp = TempDir::new().unwrap();
+        let content = r#"
+[task]
+id = "api-task"
+name = "API Task"
+category = "implement"
+language = "rust"
+difficulty = "easy"
+description = "A task with a required API"
+
+[[api]]
+name = "Bank"
+kind = "type"
+
+[[api]]
+name = "transfer"
+signature = "fn transfer(&mut self, amount: u64)"
+"#;
+        create_test_task(temp.path(), "api.toml", content);
+
+        let tasks = CorpusLoader::new().load_all(temp.path()).unwrap();
+        let api = &tasks[0].api;
+        assert_eq!(api[0].kind, ApiKind::Type);
+        assert_eq!(api[0].signature, None);
+        assert_eq!(api[1].kind, ApiKind::Function);
+        assert_eq!(
+            api[1].signature.as_deref(),
+            Some("fn transfer(&mut self, amount: u64)")
+        );
+    }
 }
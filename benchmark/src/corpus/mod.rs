@@ -1,9 +1,15 @@
 //! @ai:module:intent Task corpus definitions and loading
 //! @ai:module:layer domain
-//! @ai:module:public_api Task, TaskCategory, Language, Difficulty, CorpusLoader
+//! @ai:module:public_api Task, TaskCategory, Language, Difficulty, CorpusLoader,
+//!                       ParityReport, analyze_parity
 
 pub mod loader;
+pub mod parity;
 pub mod task;
 
 pub use loader::{CorpusLoader, CorpusLoaderTrait};
-pub use task::{Difficulty, Language, Task, TaskCategory};
+pub use parity::{analyze_parity, FamilyCoverage, ParityReport};
+pub use task::{
+    ApiKind, ContractTrap, Dependency, Difficulty, EdgeCase, HiddenTest, IoCase, IoSpec, Language,
+    ReferenceSolution, RequiredApi, StarterFile, Task, TaskCategory,
+};
@@ -0,0 +1,200 @@
+//! @ai:module:intent Cross-language parity analysis of the task corpus
+//! @ai:module:layer domain
+//! @ai:module:public_api ParityReport, FamilyCoverage, analyze_parity
+//! @ai:module:stateless true
+
+use crate::corpus::task::{Language, Task, TaskCategory};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// @ai:intent Which languages implement one conceptual task family
+#[derive(Debug, Clone, Serialize)]
+pub struct FamilyCoverage {
+    pub family: String,
+    pub category: TaskCategory,
+    /// Task ids per language (a language may have several variants)
+    pub tasks: BTreeMap<String, Vec<String>>,
+    /// Languages with no task in this family
+    pub missing: Vec<Language>,
+}
+
+impl FamilyCoverage {
+    /// @ai:intent Check if every language has at least one task in the family
+    /// @ai:effects pure
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// @ai:intent Corpus-wide parity summary
+#[derive(Debug, Clone, Serialize)]
+pub struct ParityReport {
+    pub languages: Vec<Language>,
+    pub families: Vec<FamilyCoverage>,
+    /// Number of tasks per language
+    pub task_counts: BTreeMap<String, usize>,
+}
+
+impl ParityReport {
+    /// @ai:intent Families missing at least one language
+    /// @ai:effects pure
+    pub fn incomplete(&self) -> impl Iterator<Item = &FamilyCoverage> {
+        self.families.iter().filter(|f| !f.is_complete())
+    }
+
+    /// @ai:intent Number of families each language lacks
+    /// @ai:effects pure
+    pub fn missing_counts(&self) -> BTreeMap<String, usize> {
+        let mut counts: BTreeMap<String, usize> = self
+            .languages
+            .iter()
+            .map(|l| (l.as_str().to_string(), 0))
+            .collect();
+
+        for language in self.families.iter().flat_map(|f| &f.missing) {
+            *counts.entry(language.as_str().to_string()).or_default() += 1;
+        }
+
+        counts
+    }
+
+    /// @ai:intent Render the coverage matrix as a markdown table
+    /// @ai:effects pure
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("| Family | Category |");
+        for language in &self.languages {
+            out.push_str(&format!(" {} |", language));
+        }
+        out.push_str("\n|---|---|");
+        out.push_str(&"---|".repeat(self.languages.len()));
+        out.push('\n');
+
+        for family in &self.families {
+            out.push_str(&format!("| {} | {} |", family.family, family.category));
+            for language in &self.languages {
+                let cell = match family.tasks.get(language.as_str()) {
+                    Some(ids) => ids.join(", "),
+                    None => "**missing**".to_string(),
+                };
+                out.push_str(&format!(" {} |", cell));
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+/// @ai:intent Group tasks into families and record which languages each family lacks
+/// @ai:post every family lists each language either in tasks or in missing
+/// @ai:effects pure
+pub fn analyze_parity(tasks: &[Task]) -> ParityReport {
+    let languages = Language::ALL.to_vec();
+    let mut grouped: BTreeMap<String, (TaskCategory, BTreeMap<String, Vec<String>>)> =
+        BTreeMap::new();
+    let mut task_counts: BTreeMap<String, usize> = languages
+        .iter()
+        .map(|l| (l.as_str().to_string(), 0))
+        .collect();
+
+    for task in tasks {
+        let (_, by_language) = grouped
+            .entry(task.family())
+            .or_insert_with(|| (task.category, BTreeMap::new()));
+        by_language
+            .entry(task.language.as_str().to_string())
+            .or_default()
+            .push(task.id.clone());
+        *task_counts
+            .entry(task.language.as_str().to_string())
+            .or_default() += 1;
+    }
+
+    let families = grouped
+        .into_iter()
+        .map(|(family, (category, tasks))| {
+            let missing = languages
+                .iter()
+                .filter(|l| !tasks.contains_key(l.as_str()))
+                .copied()
+                .collect();
+            FamilyCoverage {
+                family,
+                category,
+                tasks,
+                missing,
+            }
+        })
+        .collect();
+
+    ParityReport {
+        languages,
+        families,
+        task_counts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::corpus::task::Difficulty;
+
+    fn task(id: &str, language: Language) -> Task {
+        Task {
+            id: id.to_string(),
+            name: id.to_string(),
+            language,
+            difficulty: Difficulty::Medium,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_analyze_parity_finds_missing_languages() {
+        let tasks = vec![
+            task("impl-rust-user-crud", Language::Rust),
+            task("impl-python-user-crud", Language::Python),
+            task("impl-typescript-user-crud", Language::TypeScript),
+            task("impl-go-user-crud", Language::Go),
+            task("impl-java-user-crud", Language::Java),
+            task("impl-python-order-service", Language::Python),
+        ];
+
+        let report = analyze_parity(&tasks);
+
+        assert_eq!(report.families.len(), 2);
+        let orders = &report.families[0];
+        assert_eq!(orders.family, "impl-order-service");
+        assert_eq!(
+            orders.missing,
+            vec![
+                Language::Rust,
+                Language::TypeScript,
+                Language::Go,
+                Language::Java
+            ]
+        );
+        assert!(report.families[1].is_complete());
+        assert_eq!(report.incomplete().count(), 1);
+        assert_eq!(report.missing_counts()["rust"], 1);
+        assert_eq!(report.missing_counts()["python"], 0);
+        assert_eq!(report.task_counts["python"], 2);
+    }
+
+    #[test]
+    fn test_explicit_family_groups_differently_named_tasks() {
+        let mut rust = task("impl-rust-user-crud", Language::Rust);
+        let mut python = task("impl-python-users", Language::Python);
+        rust.family = Some("user-crud".to_string());
+        python.family = Some("user-crud".to_string());
+
+        let report = analyze_parity(&[rust, python]);
+
+        assert_eq!(report.families.len(), 1);
+        assert_eq!(
+            report.families[0].missing,
+            vec![Language::TypeScript, Language::Go, Language::Java]
+        );
+        assert!(report.to_markdown().contains("| user-crud | implement |"));
+    }
+}
@@ -1,19 +1,25 @@
 //! @ai:module:intent Task definitions for benchmark corpus
 //! @ai:module:layer domain
-//! @ai:module:public_api Task, TaskCategory, Language, Difficulty
+//! @ai:module:public_api Task, TaskCategory, Language, Difficulty, StarterFile, ContractTrap,
+//!                        HiddenTest, ReferenceSolution, EdgeCase, Dependency, IoSpec, IoCase,
+//!                        RequiredApi, ApiKind
 //! @ai:module:stateless true
 
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
-/// @ai:intent Category of benchmark task
+/// @ai:intent Category of benchmark task (ordered as declared, which is the report order)
 /// @ai:effects pure
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum TaskCategory {
     Implement,
     Bugfix,
     Refactor,
+    /// Annotate unannotated starter code; scored against annotated gold files
     Inference,
+    /// Change annotated starter code without breaking its documented contracts
+    Modify,
 }
 
 impl TaskCategory {
@@ -25,6 +31,7 @@ impl TaskCategory {
             TaskCategory::Bugfix => "bugfix",
             TaskCategory::Refactor => "refactor",
             TaskCategory::Inference => "inference",
+            TaskCategory::Modify => "modify",
         }
     }
 }
@@ -35,17 +42,28 @@ impl std::fmt::Display for TaskCategory {
     }
 }
 
-/// @ai:intent Programming language for the task
+/// @ai:intent Programming language for the task (ordered as declared, which is the report order)
 /// @ai:effects pure
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Language {
     Rust,
     Python,
     TypeScript,
+    Go,
+    Java,
 }
 
 impl Language {
+    /// @ai:intent Every supported language, in display order
+    pub const ALL: &'static [Language] = &[
+        Language::Rust,
+        Language::Python,
+        Language::TypeScript,
+        Language::Go,
+        Language::Java,
+    ];
+
     /// @ai:intent Convert language to string representation
     /// @ai:effects pure
     pub fn as_str(&self) -> &'static str {
@@ -53,6 +71,8 @@ impl Language {
             Language::Rust => "rust",
             Language::Python => "python",
             Language::TypeScript => "typescript",
+            Language::Go => "go",
+            Language::Java => "java",
         }
     }
 
@@ -63,6 +83,8 @@ impl Language {
             Language::Rust => "rs",
             Language::Python => "py",
             Language::TypeScript => "ts",
+            Language::Go => "go",
+            Language::Java => "java",
         }
     }
 }
@@ -73,9 +95,9 @@ impl std::fmt::Display for Language {
     }
 }
 
-/// @ai:intent Difficulty level of the task
+/// @ai:intent Difficulty level of the task (ordered as declared, which is the report order)
 /// @ai:effects pure
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Difficulty {
     Easy,
@@ -101,6 +123,128 @@ impl std::fmt::Display for Difficulty {
     }
 }
 
+/// @ai:intent A project file: starter code shown to the model (modify tasks) or part of a
+///            reference solution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StarterFile {
+    pub path: String,
+    pub content: String,
+}
+
+/// @ai:intent A documented contract plus a hidden test that fails if it is violated
+///            In Rust tests, `{{crate}}` is replaced with the generated crate's name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractTrap {
+    pub id: String,
+    /// The annotation that documents the constraint (e.g. "@ai:invariant balance >= 0")
+    pub contract: String,
+    /// Where the hidden test is written in the project (e.g. tests/trap_balance.rs)
+    pub path: String,
+    pub code: String,
+}
+
+/// @ai:intent A reference test file run against the generated code but never shown to the model
+///            The path is relative to the project (Rust tests go under tests/); in Rust
+///            tests, `{{crate}}` is replaced with the generated crate's name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HiddenTest {
+    pub path: String,
+    pub code: String,
+}
+
+/// @ai:intent Behavioral cases for CLI-style tasks: the program is built once and run per case
+///            `entry` is what runs: a Python script (main.py), a TypeScript file run with tsx
+///            (src/main.ts), a Go package (.), a Java main class (Main) or a Rust binary target
+///            (the package's only binary)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IoSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entry: Option<String>,
+    pub cases: Vec<IoCase>,
+}
+
+/// @ai:intent One run of the program: arguments and stdin in, exit code and stdout checked
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IoCase {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub stdin: String,
+    /// Expected standard output; trailing whitespace of lines and trailing blank lines
+    /// are ignored
+    pub stdout: String,
+    #[serde(default)]
+    pub exit_code: i32,
+    /// Seconds the run may take before it is killed and fails
+    #[serde(default = "default_io_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_io_timeout_secs() -> u64 {
+    10
+}
+
+/// @ai:intent A boundary case named in the task description that the model's tests should cover
+///            Covered if a pattern (regex) matches the generated test code; without patterns,
+///            if one test mentions every word of the name (e.g. `transfer_to_same_account`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EdgeCase {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<String>,
+}
+
+/// @ai:intent A public function or type the generated code must define, as the description
+///            asks for it. With a signature, the declaration must also match it: written in
+///            the task's language, compared without whitespace and leading modifiers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequiredApi {
+    /// Function, method or type name (a method of any type matches)
+    pub name: String,
+    #[serde(default)]
+    pub kind: ApiKind,
+    /// e.g. "fn transfer(&mut self, from: u32, to: u32, amount: u64) -> Result<(), BankError>"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+/// @ai:intent What a required API item is
+/// @ai:effects pure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApiKind {
+    /// A function or method
+    #[default]
+    Function,
+    /// A struct, class, interface, trait, enum or type alias
+    Type,
+}
+
+/// @ai:intent A third-party package the model may use (a crate, a PyPI or an npm package)
+///            Evaluation is offline, so it must be in the vendored registry ([sandbox.vendor])
+///            at a version that matches `version`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dependency {
+    pub name: String,
+    /// Version requirement in the package manager's syntax (e.g. "1", ">=2.31", "^4.17")
+    pub version: String,
+    /// Cargo features to enable (Rust only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub features: Vec<String>,
+}
+
+/// @ai:intent A gold implementation of the task, never shown to the model
+///            Test files among `files` (tests/*.rs, test_*.py, *.test.ts) are run against
+///            the generated code; the rest define the expected API surface
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReferenceSolution {
+    pub files: Vec<StarterFile>,
+    /// Also score textual similarity (off by default: correct solutions may look different)
+    #[serde(default)]
+    pub similarity: bool,
+}
+
 /// @ai:intent A benchmark task definition
 /// @ai:effects pure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -112,6 +256,90 @@ pub struct Task {
     pub difficulty: Difficulty,
     /// Description shown to Claude - the only input for implement tasks
     pub description: String,
+    /// Conceptual task shared by the per-language variants (derived from the id if unset)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
+    /// Free-form labels (e.g. "concurrency", "parsing") the results are also broken down by
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Starter code the model must modify (shown in the prompt)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub starter: Vec<StarterFile>,
+    /// Hidden tests for the contracts documented in the starter code (never shown)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contract_traps: Vec<ContractTrap>,
+    /// Reference tests for the required behavior, run separately from the model's own tests
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_tests: Vec<HiddenTest>,
+    /// Runs of the built program checked against expected output (CLI-style tasks)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io: Option<IoSpec>,
+    /// Boundary cases from the description, checked against the model's own tests
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub edge_cases: Vec<EdgeCase>,
+    /// Public functions and types the description asks for, checked in the generated code
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub api: Vec<RequiredApi>,
+    /// Third-party packages the solution may use; when set, the project may declare no
+    /// other runtime dependencies (empty: not restricted)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<Dependency>,
+    /// Gold solution the generated code is compared with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<ReferenceSolution>,
+    /// Annotated versions of the starter files, scored against inferred annotations
+    /// (inference tasks)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub gold: Vec<StarterFile>,
+    /// Task-specific prompt template text (replaces the generic task prompt)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt_template: Option<String>,
+}
+
+/// @ai:intent Empty Rust implement task for test fixtures, which set the fields they use
+#[cfg(test)]
+impl Default for Task {
+    fn default() -> Self {
+        Task {
+            id: String::new(),
+            name: String::new(),
+            category: TaskCategory::Implement,
+            language: Language::Rust,
+            difficulty: Difficulty::Easy,
+            description: String::new(),
+            family: None,
+            tags: vec![],
+            starter: vec![],
+            contract_traps: vec![],
+            hidden_tests: vec![],
+            io: None,
+            edge_cases: vec![],
+            api: vec![],
+            dependencies: vec![],
+            reference: None,
+            gold: vec![],
+            prompt_template: None,
+        }
+    }
+}
+
+impl Task {
+    /// @ai:intent Conceptual task family used to match equivalent tasks across languages
+    ///            Explicit `family` wins; otherwise the language segment is dropped from the id
+    /// @ai:effects pure
+    /// @ai:example (id "impl-rust-user-crud") -> "impl-user-crud"
+    pub fn family(&self) -> String {
+        if let Some(family) = &self.family {
+            return family.clone();
+        }
+
+        let language = self.language.as_str();
+        self.id
+            .split('-')
+            .filter(|segment| *segment != language)
+            .collect::<Vec<_>>()
+            .join("-")
+    }
 }
 
 /// @ai:intent Raw task structure from TOML file
@@ -119,6 +347,24 @@ pub struct Task {
 #[derive(Debug, Deserialize)]
 pub struct TaskFile {
     pub task: TaskMetadata,
+    #[serde(default)]
+    pub starter: Vec<StarterFile>,
+    #[serde(default)]
+    pub traps: Vec<ContractTrap>,
+    #[serde(default)]
+    pub hidden_tests: Vec<HiddenTest>,
+    #[serde(default)]
+    pub io: Option<IoSpec>,
+    #[serde(default)]
+    pub edge_cases: Vec<EdgeCase>,
+    #[serde(default)]
+    pub api: Vec<RequiredApi>,
+    #[serde(default)]
+    pub dependencies: Vec<Dependency>,
+    #[serde(default)]
+    pub reference: Option<ReferenceSolution>,
+    #[serde(default)]
+    pub gold: Vec<StarterFile>,
 }
 
 /// @ai:intent Task metadata from TOML file
@@ -131,6 +377,13 @@ pub struct TaskMetadata {
     pub language: Language,
     pub difficulty: Difficulty,
     pub description: String,
+    #[serde(default)]
+    pub family: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Prompt template file, relative to the task file (read by the corpus loader)
+    #[serde(default)]
+    pub prompt_template: Option<PathBuf>,
 }
 
 impl From<TaskFile> for Task {
@@ -142,6 +395,18 @@ impl From<TaskFile> for Task {
             language: file.task.language,
             difficulty: file.task.difficulty,
             description: file.task.description,
+            family: file.task.family,
+            tags: file.task.tags,
+            starter: file.starter,
+            contract_traps: file.traps,
+            hidden_tests: file.hidden_tests,
+            io: file.io,
+            edge_cases: file.edge_cases,
+            api: file.api,
+            dependencies: file.dependencies,
+            reference: file.reference,
+            gold: file.gold,
+            prompt_template: None,
         }
     }
 }
@@ -162,4 +427,19 @@ mod tests {
         assert_eq!(TaskCategory::Implement.as_str(), "implement");
         assert_eq!(TaskCategory::Bugfix.as_str(), "bugfix");
     }
+
+    #[test]
+    fn test_family_derived_from_id() {
+        let mut task = Task {
+            id: "impl-typescript-user-crud".to_string(),
+            name: "User CRUD".to_string(),
+            language: Language::TypeScript,
+            difficulty: Difficulty::Medium,
+            ..Default::default()
+        };
+        assert_eq!(task.family(), "impl-user-crud");
+
+        task.family = Some("crud".to_string());
+        assert_eq!(task.family(), "crud");
+    }
 }
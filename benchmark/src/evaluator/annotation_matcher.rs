@@ -0,0 +1,537 @@
+//! @ai:module:intent Semantic matching of generated annotations against expected ones
+//! @ai:module:layer application
+//! @ai:module:public_api AnnotationMatcher, AnnotationMatch, MatchMethod, AnnotationJudge,
+//!                        EmbeddingJudge, ClaudeJudge, token_similarity
+//! @ai:module:depends_on aicms_parser, config, platform, runner::process
+//! @ai:module:stateless true
+
+use crate::config::{AnnotationJudgeKind, AnnotationMatchingConfig};
+use crate::platform::resolve_executable;
+use crate::runner::process::ProcessTree;
+use aicms_parser::Annotation;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::future::Future;
+use std::process::Command;
+use std::time::Duration;
+
+/// @ai:intent Token overlap from which two annotations match by default
+const DEFAULT_THRESHOLD: f64 = 0.5;
+
+/// @ai:intent Closest candidates (by token overlap) shown to a judge per expected annotation
+const MAX_JUDGED_CANDIDATES: usize = 10;
+
+/// @ai:intent Seconds per Claude CLI call of the Claude judge
+const CLAUDE_JUDGE_TIMEOUT_SECS: u64 = 120;
+
+/// @ai:intent Words that carry no meaning of their own in an annotation
+const STOPWORDS: &[&str] = &[
+    "a", "an", "the", "of", "to", "and", "or", "is", "are", "be", "in", "on", "for", "with", "by",
+    "it", "its", "that", "this", "as", "from", "given",
+];
+
+/// @ai:intent How an expected annotation was matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MatchMethod {
+    TokenOverlap,
+    Embedding,
+    Claude,
+}
+
+/// @ai:intent Outcome of matching one expected annotation against the generated ones
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnnotationMatch {
+    pub expected: String,
+    /// Closest generated annotation with the same tag (the judge's pick when it matched)
+    pub closest: Option<String>,
+    /// Similarity (0.0-1.0) to the closest annotation, as measured by the method
+    pub similarity: f64,
+    pub method: MatchMethod,
+    pub matched: bool,
+}
+
+/// @ai:intent Decides whether differently worded annotations state the same fact
+pub trait AnnotationJudge: Send + Sync {
+    /// @ai:intent Method reported for the matches this judge makes
+    fn method(&self) -> MatchMethod;
+
+    /// @ai:intent Candidate equivalent to the expected annotation value, if any, and its
+    ///            similarity
+    fn best_match(&self, expected: &str, candidates: &[&str]) -> Result<Option<(usize, f64)>>;
+}
+
+/// @ai:intent Matches expected annotations by normalized token overlap, asking an optional
+///            judge about those without a match
+pub struct AnnotationMatcher {
+    threshold: f64,
+    judge: Option<Box<dyn AnnotationJudge>>,
+}
+
+impl AnnotationMatcher {
+    /// @ai:intent Create a token-overlap matcher with the default threshold and no judge
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            threshold: DEFAULT_THRESHOLD,
+            judge: None,
+        }
+    }
+
+    /// @ai:intent Create the matcher selected by the [evaluation.annotation_matching] section
+    /// @ai:effects pure
+    pub fn from_config(config: &AnnotationMatchingConfig) -> Result<Self> {
+        let judge: Option<Box<dyn AnnotationJudge>> = match config.judge {
+            Some(AnnotationJudgeKind::Embedding) => Some(Box::new(EmbeddingJudge::new(
+                &config.embedding_endpoint,
+                &config.embedding_model,
+                config.embedding_threshold,
+            )?)),
+            Some(AnnotationJudgeKind::Claude) => Some(Box::new(ClaudeJudge::new())),
+            None => None,
+        };
+        Ok(Self {
+            threshold: config.threshold,
+            judge,
+        })
+    }
+
+    /// @ai:intent Override the token overlap from which annotations match
+    /// @ai:pre 0.0 <= threshold <= 1.0
+    /// @ai:effects pure
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// @ai:intent Ask the judge about expected annotations without a token-overlap match
+    /// @ai:effects pure
+    pub fn with_judge(mut self, judge: Box<dyn AnnotationJudge>) -> Self {
+        self.judge = Some(judge);
+        self
+    }
+
+    /// @ai:intent Match one expected annotation against the generated annotations
+    ///            "@ai:<tag> <value>" only matches annotations with that tag; text without
+    ///            a tag is compared with every annotation
+    /// @ai:effects io, network
+    pub fn match_annotation(&self, expected: &str, generated: &[Annotation]) -> AnnotationMatch {
+        let (tag, value) = split_annotation(expected);
+        let mut candidates: Vec<(&Annotation, f64)> = generated
+            .iter()
+            .filter(|a| tag.is_none_or(|tag| a.tag == tag))
+            .map(|a| (a, token_similarity(value, &a.value)))
+            .collect();
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let mut result = AnnotationMatch {
+            expected: expected.to_string(),
+            closest: candidates.first().map(|(a, _)| display(a)),
+            similarity: candidates.first().map_or(0.0, |(_, s)| *s),
+            method: MatchMethod::TokenOverlap,
+            matched: false,
+        };
+        if result.similarity >= self.threshold && !candidates.is_empty() {
+            result.matched = true;
+            return result;
+        }
+
+        let Some(judge) = &self.judge else {
+            return result;
+        };
+        if candidates.is_empty() {
+            return result;
+        }
+        candidates.truncate(MAX_JUDGED_CANDIDATES);
+        let values: Vec<&str> = candidates.iter().map(|(a, _)| a.value.as_str()).collect();
+        match judge.best_match(value, &values) {
+            Ok(Some((index, similarity))) if index < candidates.len() => {
+                result.closest = Some(display(candidates[index].0));
+                result.similarity = similarity;
+                result.method = judge.method();
+                result.matched = true;
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Annotation judge failed for `{}`: {}", expected, e),
+        }
+        result
+    }
+}
+
+impl Default for AnnotationMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// @ai:intent Split "@ai:<tag> <value>" into tag and value; other text is all value
+/// @ai:effects pure
+/// @ai:example ("@ai:pre n >= 0") -> (Some("pre"), "n >= 0")
+fn split_annotation(text: &str) -> (Option<&str>, &str) {
+    let text = text.trim();
+    match text.strip_prefix("@ai:") {
+        Some(rest) => {
+            let (tag, value) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+            (Some(tag), value.trim())
+        }
+        None => (None, text),
+    }
+}
+
+/// @ai:intent An annotation as written in the source
+/// @ai:effects pure
+fn display(annotation: &Annotation) -> String {
+    format!(
+        "{}{} {}",
+        annotation.prefix, annotation.tag, annotation.value
+    )
+}
+
+/// @ai:intent Dice coefficient of the normalized tokens of two annotation values
+///            Case, punctuation, stopwords and common suffixes are ignored; comparison
+///            operators are tokens of their own
+/// @ai:post 0.0 <= result <= 1.0
+/// @ai:effects pure
+/// @ai:example ("Computes the total price", "compute total prices") -> 1.0
+pub fn token_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (tokens(a), tokens(b));
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    2.0 * a.intersection(&b).count() as f64 / (a.len() + b.len()) as f64
+}
+
+/// @ai:intent Normalized word and operator tokens of a text
+/// @ai:effects pure
+/// @ai:example ("result >= Items.len()") -> {">=", "item", "len", "result"}
+fn tokens(text: &str) -> BTreeSet<String> {
+    let mut tokens = BTreeSet::new();
+    let mut word = String::new();
+    let mut operator = String::new();
+    for c in text.chars().chain([' ']) {
+        if c.is_alphanumeric() || c == '_' {
+            word.extend(c.to_lowercase());
+        } else if !word.is_empty() {
+            tokens.insert(stem(&std::mem::take(&mut word)));
+        }
+        if "<>=!".contains(c) {
+            operator.push(c);
+        } else if !operator.is_empty() {
+            tokens.insert(std::mem::take(&mut operator));
+        }
+    }
+    tokens.retain(|token| !STOPWORDS.contains(&token.as_str()));
+    tokens
+}
+
+/// @ai:intent Strip plural and verb suffixes so inflections of a word compare equal
+/// @ai:effects pure
+/// @ai:example ("computes") -> "comput"
+fn stem(word: &str) -> String {
+    if word.len() <= 3 || !word.chars().all(|c| c.is_ascii_alphabetic()) {
+        return word.to_string();
+    }
+    let stem = ["ing", "ed", "s"]
+        .iter()
+        .find_map(|suffix| word.strip_suffix(suffix).filter(|stem| stem.len() >= 3))
+        .unwrap_or(word);
+    stem.strip_suffix('e')
+        .filter(|stem| stem.len() >= 3)
+        .unwrap_or(stem)
+        .to_string()
+}
+
+/// @ai:intent Ollama /api/embed request body
+#[derive(Debug, Serialize)]
+struct EmbedRequest<'a> {
+    model: &'a str,
+    input: &'a [&'a str],
+}
+
+/// @ai:intent Ollama /api/embed response body
+#[derive(Debug, Deserialize)]
+struct EmbedResponse {
+    embeddings: Vec<Vec<f64>>,
+}
+
+/// @ai:intent Matches annotations by the cosine similarity of their Ollama embeddings
+pub struct EmbeddingJudge {
+    client: reqwest::Client,
+    endpoint: String,
+    model: String,
+    threshold: f64,
+}
+
+impl EmbeddingJudge {
+    /// @ai:intent Create a judge using an embedding model on an Ollama server
+    /// @ai:effects pure
+    pub fn new(endpoint: &str, model: &str, threshold: f64) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(60))
+            .build()?;
+        Ok(Self {
+            client,
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            model: model.to_string(),
+            threshold,
+        })
+    }
+
+    /// @ai:intent Embed the texts in one request
+    /// @ai:effects network
+    fn embed(&self, input: &[&str]) -> Result<Vec<Vec<f64>>> {
+        let request = self
+            .client
+            .post(format!("{}/api/embed", self.endpoint))
+            .json(&EmbedRequest {
+                model: &self.model,
+                input,
+            })
+            .send();
+        let response: EmbedResponse = block_on(async {
+            request
+                .await?
+                .error_for_status()?
+                .json::<EmbedResponse>()
+                .await
+        })??;
+        Ok(response.embeddings)
+    }
+}
+
+impl AnnotationJudge for EmbeddingJudge {
+    /// @ai:intent Report embedding matches
+    /// @ai:effects pure
+    fn method(&self) -> MatchMethod {
+        MatchMethod::Embedding
+    }
+
+    /// @ai:intent Most similar candidate, if its cosine similarity reaches the threshold
+    /// @ai:effects network
+    fn best_match(&self, expected: &str, candidates: &[&str]) -> Result<Option<(usize, f64)>> {
+        let input: Vec<&str> = std::iter::once(expected)
+            .chain(candidates.iter().copied())
+            .collect();
+        let embeddings = self.embed(&input)?;
+        let (expected, candidates) = embeddings
+            .split_first()
+            .context("Ollama returned no embeddings")?;
+
+        Ok(candidates
+            .iter()
+            .map(|candidate| cosine_similarity(expected, candidate))
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .filter(|(_, similarity)| *similarity >= self.threshold))
+    }
+}
+
+/// @ai:intent Cosine similarity of two vectors, 0 if either is zero
+/// @ai:effects pure
+fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f64]| v.iter().map(|x| x * x).sum::<f64>().sqrt();
+    let norms = norm(a) * norm(b);
+    if norms == 0.0 {
+        0.0
+    } else {
+        dot / norms
+    }
+}
+
+/// @ai:intent Run a future to completion from synchronous code
+///            Evaluation runs on blocking worker threads, where the runtime's handle can
+///            block; on a runtime worker (comparisons) the worker is handed over first;
+///            outside a runtime a temporary one is started
+/// @ai:effects io
+pub(crate) fn block_on<F: Future>(future: F) -> Result<F::Output> {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+            Ok(tokio::task::block_in_place(|| handle.block_on(future)))
+        }
+        Ok(handle) => Ok(handle.block_on(future)),
+        Err(_) => Ok(tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?
+            .block_on(future)),
+    }
+}
+
+/// @ai:intent Asks the Claude Code CLI which candidate, if any, states the same fact
+pub struct ClaudeJudge {
+    timeout: Duration,
+}
+
+impl ClaudeJudge {
+    /// @ai:intent Create a judge that runs the Claude CLI once per expected annotation
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            timeout: Duration::from_secs(CLAUDE_JUDGE_TIMEOUT_SECS),
+        }
+    }
+
+    /// @ai:intent Prompt listing the numbered candidates
+    /// @ai:effects pure
+    fn build_prompt(expected: &str, candidates: &[&str]) -> String {
+        let list: String = candidates
+            .iter()
+            .enumerate()
+            .map(|(i, candidate)| format!("{}. {}\n", i + 1, candidate))
+            .collect();
+        format!(
+            "Does one of the candidate code annotations state the same fact as the expected \
+             annotation? Wording, naming and formatting may differ; the meaning may not.\n\n\
+             Expected: {}\n\nCandidates:\n{}\n\
+             Reply with only the number of the equivalent candidate, or 0 if none is.",
+            expected, list
+        )
+    }
+
+    /// @ai:intent Run the Claude CLI with the prompt on stdin and return its stdout
+    /// @ai:effects io, network
+    fn run_claude(&self, prompt: &str) -> Result<String> {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let mut cmd = Command::new(resolve_executable("claude"));
+        cmd.arg("--print")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        ProcessTree::configure(&mut cmd);
+
+        let mut child = cmd.spawn().context("Claude CLI not available")?;
+        let tree = ProcessTree::attach(child.id());
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(prompt.as_bytes())?;
+        }
+
+        let output = tree
+            .wait_with_output(child, Some(self.timeout))?
+            .with_context(|| format!("Claude judge timed out after {}s", self.timeout.as_secs()))?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Claude CLI failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+}
+
+impl Default for ClaudeJudge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AnnotationJudge for ClaudeJudge {
+    /// @ai:intent Report Claude matches
+    /// @ai:effects pure
+    fn method(&self) -> MatchMethod {
+        MatchMethod::Claude
+    }
+
+    /// @ai:intent Candidate Claude names as equivalent (similarity 1.0), if any
+    /// @ai:effects io, network
+    fn best_match(&self, expected: &str, candidates: &[&str]) -> Result<Option<(usize, f64)>> {
+        let reply = self.run_claude(&Self::build_prompt(expected, candidates))?;
+        Ok(parse_choice(&reply, candidates.len()).map(|index| (index, 1.0)))
+    }
+}
+
+/// @ai:intent Zero-based candidate index from a reply naming a 1-based candidate number
+///            0, numbers out of range and replies without a number choose no candidate
+/// @ai:effects pure
+/// @ai:example ("2", 3) -> Some(1)
+fn parse_choice(reply: &str, candidates: usize) -> Option<usize> {
+    let number: String = reply
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    number
+        .parse::<usize>()
+        .ok()
+        .filter(|n| (1..=candidates).contains(n))
+        .map(|n| n - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aicms_parser::{AnnotationLevel, Location};
+
+    fn annotation(tag: &str, value: &str) -> Annotation {
+        Annotation {
+            level: AnnotationLevel::Function,
+            tag: tag.to_string(),
+            value: value.to_string(),
+            location: Location::default(),
+            prefix: "@ai:".to_string(),
+        }
+    }
+
+    struct FixedJudge(Option<(usize, f64)>);
+
+    impl AnnotationJudge for FixedJudge {
+        fn method(&self) -> MatchMethod {
+            MatchMethod::Claude
+        }
+
+        fn best_match(&self, _: &str, _: &[&str]) -> Result<Option<(usize, f64)>> {
+            Ok(self.0)
+        }
+    }
+
+    #[test]
+    fn test_token_similarity() {
+        assert!(
+            (token_similarity("Computes the total price", "compute total prices") - 1.0).abs()
+                < 1e-9
+        );
+        assert!((token_similarity("n >= 0", "N >= 0") - 1.0).abs() < 1e-9);
+        assert!(token_similarity("n >= 0", "n > 0") < 1.0);
+        assert_eq!(token_similarity("Parse a date", "Sort the list"), 0.0);
+        assert_eq!(parse_choice("Candidate 2 is equivalent.", 3), Some(1));
+        assert_eq!(parse_choice("0", 3), None);
+    }
+
+    #[test]
+    fn test_match_annotation_by_tag_and_judge() {
+        let generated = [
+            annotation("intent", "Convert a temperature from Celsius to Fahrenheit"),
+            annotation("pre", "celsius is finite"),
+        ];
+        let matcher = AnnotationMatcher::new();
+
+        let result = matcher.match_annotation(
+            "@ai:intent Convert a Celsius temperature to Fahrenheit",
+            &generated,
+        );
+        assert!(result.matched);
+        assert_eq!(result.method, MatchMethod::TokenOverlap);
+        assert_eq!(
+            result.closest.as_deref(),
+            Some("@ai:intent Convert a temperature from Celsius to Fahrenheit")
+        );
+
+        // The value matches an intent word for word, but no annotation has the tag
+        let result = matcher.match_annotation("@ai:post celsius is finite", &generated[..1]);
+        assert!(!result.matched);
+        assert_eq!(result.closest, None);
+
+        let result = matcher.match_annotation("@ai:pre !celsius.is_nan()", &generated);
+        assert!(!result.matched);
+        assert_eq!(result.closest.as_deref(), Some("@ai:pre celsius is finite"));
+
+        let judged = AnnotationMatcher::new()
+            .with_judge(Box::new(FixedJudge(Some((0, 1.0)))))
+            .match_annotation("@ai:pre !celsius.is_nan()", &generated);
+        assert!(judged.matched);
+        assert_eq!(judged.method, MatchMethod::Claude);
+        assert!((judged.similarity - 1.0).abs() < 1e-9);
+    }
+}
@@ -1,9 +1,16 @@
 //! @ai:module:intent Score quality of inferred AICMS annotations
 //! @ai:module:layer application
-//! @ai:module:public_api AnnotationScorer, AnnotationScore
+//! @ai:module:public_api AnnotationScorer, AnnotationScore, AnnotationDensity, expected_annotations
+//! @ai:module:depends_on aicms_parser, corpus, evaluator::annotation_matcher, evaluator::inference
 //! @ai:module:stateless true
 
-use regex::Regex;
+use crate::corpus::Task;
+use crate::evaluator::annotation_matcher::{AnnotationMatch, AnnotationMatcher};
+use crate::evaluator::inference::parse_source;
+use crate::evaluator::SourceFile;
+use aicms_parser::{Annotation, AnnotationLevel, FunctionAnnotations};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// @ai:intent Score of annotation inference quality
 #[derive(Debug, Clone)]
@@ -12,6 +19,42 @@ pub struct AnnotationScore {
     pub accuracy: f64,
     pub overall: f64,
     pub details: ScoringDetails,
+    pub density: AnnotationDensity,
+}
+
+/// @ai:intent How much of the generated code carries annotations, as seen by the AICMS parser
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnnotationDensity {
+    pub functions: u32,
+    /// Functions with at least one intent, pre, post, example or effects annotation
+    pub annotated_functions: u32,
+    /// Function-level annotations
+    pub annotations: u32,
+    /// Module and function annotations by tag (e.g. "intent", "module:layer")
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tags: BTreeMap<String, u32>,
+}
+
+impl AnnotationDensity {
+    /// @ai:intent Function-level annotations per function
+    /// @ai:effects pure
+    pub fn per_function(&self) -> f64 {
+        if self.functions == 0 {
+            0.0
+        } else {
+            self.annotations as f64 / self.functions as f64
+        }
+    }
+
+    /// @ai:intent Percentage of functions with at least one annotation
+    /// @ai:effects pure
+    pub fn annotated_rate(&self) -> f64 {
+        if self.functions == 0 {
+            0.0
+        } else {
+            self.annotated_functions as f64 / self.functions as f64 * 100.0
+        }
+    }
 }
 
 /// @ai:intent Detailed breakdown of scoring
@@ -26,64 +69,50 @@ pub struct ScoringDetails {
     pub example_count: u32,
     pub matched_expected: u32,
     pub total_expected: u32,
+    /// How each expected annotation was matched, in the order given
+    pub matches: Vec<AnnotationMatch>,
 }
 
 /// @ai:intent Trait for annotation scoring
 pub trait AnnotationScorerTrait: Send + Sync {
-    /// @ai:intent Score inferred annotations
-    fn score(&self, code: &str, expected_annotations: &[String]) -> AnnotationScore;
+    /// @ai:intent Score the annotations of the generated files
+    fn score(&self, files: &[SourceFile], expected_annotations: &[String]) -> AnnotationScore;
 }
 
 /// @ai:intent Scores the quality of inferred AICMS annotations
+///            Files are parsed with the AICMS parser, so only annotations attached to
+///            functions and modules count; files of unsupported types are skipped
 pub struct AnnotationScorer {
-    intent_regex: Regex,
-    pre_regex: Regex,
-    post_regex: Regex,
-    effects_regex: Regex,
-    example_regex: Regex,
+    matcher: AnnotationMatcher,
 }
 
 impl AnnotationScorer {
-    /// @ai:intent Create a new annotation scorer
+    /// @ai:intent Create a new annotation scorer matching by token overlap
     /// @ai:effects pure
     pub fn new() -> Self {
         Self {
-            intent_regex: Regex::new(r"@ai:intent\s+(.+)").unwrap(),
-            pre_regex: Regex::new(r"@ai:pre\s+(.+)").unwrap(),
-            post_regex: Regex::new(r"@ai:post\s+(.+)").unwrap(),
-            effects_regex: Regex::new(r"@ai:effects\s+(.+)").unwrap(),
-            example_regex: Regex::new(r"@ai:example\s+\(([^)]+)\)\s*->\s*(.+)").unwrap(),
+            matcher: AnnotationMatcher::new(),
         }
     }
 
-    /// @ai:intent Check if code has a valid intent annotation
+    /// @ai:intent Match expected annotations with the given matcher
     /// @ai:effects pure
-    fn check_intent(&self, code: &str) -> (bool, f64) {
-        if let Some(cap) = self.intent_regex.captures(code) {
-            let intent = cap.get(1).map(|m| m.as_str()).unwrap_or("");
-            let quality = score_intent_quality(intent);
-            (true, quality)
-        } else {
-            (false, 0.0)
-        }
+    pub fn with_matcher(mut self, matcher: AnnotationMatcher) -> Self {
+        self.matcher = matcher;
+        self
     }
+}
 
-    /// @ai:intent Count examples in code
-    /// @ai:effects pure
-    fn count_examples(&self, code: &str) -> u32 {
-        self.example_regex.captures_iter(code).count() as u32
-    }
-
-    /// @ai:intent Count matched expected annotations
-    /// @ai:effects pure
-    fn count_matched(&self, code: &str, expected: &[String]) -> u32 {
-        let code_lower = code.to_lowercase();
-
-        expected
-            .iter()
-            .filter(|ann| code_lower.contains(&ann.to_lowercase()))
-            .count() as u32
-    }
+/// @ai:intent The task's gold annotations as "@ai:<tag> <value>", the annotations an
+///            inference task expects (empty for other tasks)
+/// @ai:effects fs:write
+pub fn expected_annotations(task: &Task) -> Vec<String> {
+    task.gold
+        .iter()
+        .filter_map(|gold| parse_source(&gold.path, &gold.content))
+        .flat_map(|parsed| parsed.raw_annotations)
+        .map(|a| format!("@ai:{} {}", a.tag, a.value).trim_end().to_string())
+        .collect()
 }
 
 impl Default for AnnotationScorer {
@@ -144,19 +173,68 @@ fn score_intent_quality(intent: &str) -> f64 {
 }
 
 impl AnnotationScorerTrait for AnnotationScorer {
-    /// @ai:intent Score inferred annotations against expectations
-    /// @ai:effects pure
-    fn score(&self, code: &str, expected_annotations: &[String]) -> AnnotationScore {
-        let (has_intent, intent_quality) = self.check_intent(code);
-        let has_pre = self.pre_regex.is_match(code);
-        let has_post = self.post_regex.is_match(code);
-        let has_effects = self.effects_regex.is_match(code);
-        let has_example = self.example_regex.is_match(code);
-        let example_count = self.count_examples(code);
+    /// @ai:intent Score the annotations of the generated files against expectations
+    /// @ai:effects fs:write
+    fn score(&self, files: &[SourceFile], expected_annotations: &[String]) -> AnnotationScore {
+        let parsed: Vec<_> = files
+            .iter()
+            .filter_map(|f| parse_source(&f.path, &f.content))
+            .collect();
+        let functions: Vec<&FunctionAnnotations> =
+            parsed.iter().flat_map(|p| &p.module.functions).collect();
+
+        let mut density = AnnotationDensity {
+            functions: functions.len() as u32,
+            annotated_functions: functions.iter().filter(|f| f.is_annotated()).count() as u32,
+            ..Default::default()
+        };
+        for annotation in parsed
+            .iter()
+            .flat_map(|p| &p.raw_annotations)
+            .filter(|a| a.level == AnnotationLevel::Module)
+        {
+            *density.tags.entry(annotation.tag.clone()).or_default() += 1;
+        }
+        // Counted from the attached annotations, since the parser's raw function-level
+        // entries can also contain the lines of the module header
+        for function in &functions {
+            let counts = [
+                ("intent", function.intent.is_some() as usize),
+                ("pre", function.pre.len()),
+                ("post", function.post.len()),
+                ("invariant", function.invariant.is_some() as usize),
+                ("example", function.examples.len()),
+                ("effects", function.effects.len()),
+            ];
+            for (tag, count) in counts.into_iter().filter(|(_, count)| *count > 0) {
+                *density.tags.entry(tag.to_string()).or_default() += count as u32;
+                density.annotations += count as u32;
+            }
+        }
+
+        let intent = functions
+            .iter()
+            .find_map(|f| f.intent.as_deref())
+            .or_else(|| parsed.iter().find_map(|p| p.module.intent.as_deref()));
+        let has_intent = intent.is_some();
+        let intent_quality = intent.map(score_intent_quality).unwrap_or(0.0);
+        let has_pre = functions.iter().any(|f| !f.pre.is_empty());
+        let has_post = functions.iter().any(|f| !f.post.is_empty());
+        let has_effects = functions.iter().any(|f| !f.effects.is_empty());
+        let example_count = functions.iter().map(|f| f.examples.len() as u32).sum::<u32>();
+        let has_example = example_count > 0;
 
         let completeness = calculate_completeness(has_intent, has_pre, has_post, has_effects, has_example);
 
-        let matched = self.count_matched(code, expected_annotations);
+        let generated: Vec<Annotation> = parsed
+            .iter()
+            .flat_map(|p| p.raw_annotations.iter().cloned())
+            .collect();
+        let matches: Vec<AnnotationMatch> = expected_annotations
+            .iter()
+            .map(|expected| self.matcher.match_annotation(expected, &generated))
+            .collect();
+        let matched = matches.iter().filter(|m| m.matched).count() as u32;
         let total = expected_annotations.len() as u32;
 
         let accuracy = if total == 0 {
@@ -181,7 +259,9 @@ impl AnnotationScorerTrait for AnnotationScorer {
                 example_count,
                 matched_expected: matched,
                 total_expected: total,
+                matches,
             },
+            density,
         }
     }
 }
@@ -224,6 +304,13 @@ fn calculate_completeness(
 mod tests {
     use super::*;
 
+    fn files(code: &str) -> Vec<SourceFile> {
+        vec![SourceFile {
+            path: "src/lib.rs".to_string(),
+            content: code.to_string(),
+        }]
+    }
+
     #[test]
     fn test_score_complete_annotations() {
         let scorer = AnnotationScorer::new();
@@ -236,7 +323,7 @@ mod tests {
 fn factorial(n: u64) -> u64 { 1 }
 "#;
 
-        let score = scorer.score(code, &[]);
+        let score = scorer.score(&files(code), &[]);
         assert!(score.completeness > 0.9);
         assert!(score.details.has_intent);
         assert!(score.details.has_example);
@@ -247,8 +334,57 @@ fn factorial(n: u64) -> u64 { 1 }
         let scorer = AnnotationScorer::new();
         let code = "fn factorial(n: u64) -> u64 { 1 }";
 
-        let score = scorer.score(code, &[]);
+        let score = scorer.score(&files(code), &[]);
         assert!(score.completeness < 0.1);
+        assert_eq!(score.density.functions, 1);
+        assert_eq!(score.density.annotated_functions, 0);
+    }
+
+    #[test]
+    fn test_density_counts_parsed_annotations_only() {
+        let code = r#"
+//! @ai:module:intent Math helpers
+//! @ai:module:layer domain
+
+/// @ai:intent Double a number
+/// @ai:effects pure
+fn double(n: u64) -> u64 { n * 2 }
+
+fn undocumented() {
+    let _text = "@ai:intent inside a string is not an annotation";
+}
+"#;
+
+        let density = AnnotationScorer::new().score(&files(code), &[]).density;
+        assert_eq!(density.functions, 2);
+        assert_eq!(density.annotated_functions, 1);
+        assert_eq!(density.annotations, 2);
+        assert!((density.per_function() - 1.0).abs() < 0.01);
+        assert!((density.annotated_rate() - 50.0).abs() < 0.01);
+        assert_eq!(density.tags.get("intent"), Some(&1));
+        assert_eq!(density.tags.get("module:layer"), Some(&1));
+    }
+
+    #[test]
+    fn test_accuracy_matches_reworded_annotations() {
+        let code = r#"
+/// @ai:intent Converts a temperature from Celsius to Fahrenheit
+/// @ai:pre celsius is finite
+/// @ai:effects pure
+fn to_fahrenheit(celsius: f64) -> f64 { celsius * 1.8 + 32.0 }
+"#;
+        let expected = [
+            "@ai:intent Convert a Celsius temperature to Fahrenheit".to_string(),
+            "@ai:effects pure".to_string(),
+            "@ai:post result >= -459.67".to_string(),
+        ];
+
+        let score = AnnotationScorer::new().score(&files(code), &expected);
+        assert_eq!(score.details.matched_expected, 2);
+        assert!((score.accuracy - 2.0 / 3.0).abs() < 0.01);
+        assert!(score.details.matches[0].matched);
+        assert!(!score.details.matches[2].matched);
+        assert_eq!(score.details.matches[2].closest, None);
     }
 
     #[test]
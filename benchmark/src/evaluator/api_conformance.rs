@@ -0,0 +1,537 @@
+//! @ai:module:intent Spec conformance of generated code: the public functions and types a task
+//!                   requires must be declared, with the required signatures, whatever the
+//!                   tests say
+//! @ai:module:layer application
+//! @ai:module:public_api ApiConformanceChecker, ApiConformanceCheckerTrait, ApiConformance
+//! @ai:module:depends_on corpus, evaluator::complexity, evaluator::reference
+//! @ai:module:stateless true
+
+use crate::corpus::{ApiKind, Language, RequiredApi, Task};
+use crate::evaluator::complexity::{matching, strip_comments_and_strings};
+use crate::evaluator::reference::is_test_file;
+use crate::evaluator::SourceFile;
+use regex::{Match, Regex};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// @ai:intent Words that function patterns capture from control flow and expressions
+const KEYWORDS: &[&str] = &[
+    "if",
+    "for",
+    "while",
+    "switch",
+    "catch",
+    "return",
+    "function",
+    "try",
+    "synchronized",
+    "new",
+    "else",
+    "throw",
+    "case",
+];
+
+/// @ai:intent Required API found in one execution
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ApiConformance {
+    pub required: u32,
+    /// Items declared publicly, with the required signature where one is given
+    pub conforming: u32,
+    /// Items with no public declaration
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing: Vec<String>,
+    /// Items declared with another signature: "transfer: found `(&self, amount: u64)`"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mismatched: Vec<String>,
+}
+
+impl ApiConformance {
+    /// @ai:intent Percentage of the required items that conform
+    /// @ai:effects pure
+    pub fn score(&self) -> f64 {
+        if self.required == 0 {
+            100.0
+        } else {
+            self.conforming as f64 / self.required as f64 * 100.0
+        }
+    }
+}
+
+/// @ai:intent Trait for API conformance checking
+pub trait ApiConformanceCheckerTrait: Send + Sync {
+    /// @ai:intent Check the generated files for the task's required API (None if it has none)
+    fn check(&self, task: &Task, files: &[SourceFile]) -> Option<ApiConformance>;
+}
+
+/// @ai:intent A function or type declared in source code
+#[derive(Debug, Clone, PartialEq)]
+struct Declaration {
+    name: String,
+    kind: ApiKind,
+    /// Parameters and return type of a function: "(a: i32, b: i32) -> i32"
+    signature: Option<String>,
+}
+
+/// @ai:intent Lexical API checker
+///            Comments and string literals are blanked out first; declarations are found by
+///            pattern and parameter lists by bracket matching
+pub struct ApiConformanceChecker {
+    functions: HashMap<Language, Vec<Regex>>,
+    types: HashMap<Language, Regex>,
+}
+
+impl ApiConformanceChecker {
+    /// @ai:intent Create a new API conformance checker
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        let regex = |pattern: &str| Regex::new(pattern).unwrap();
+        let functions = HashMap::from([
+            (
+                Language::Rust,
+                vec![regex(
+                    r"(?m)^[ \t]*(?P<vis>pub(?:\([^)]*\))?\s+)?(?:(?:const|async|unsafe)\s+)*fn\s+(?P<name>[A-Za-z_]\w*)\s*(?:<[^(]*>)?\s*\(",
+                )],
+            ),
+            (
+                Language::Python,
+                vec![regex(
+                    r"(?m)^[ \t]*(?:async\s+)?def\s+(?P<name>[A-Za-z_]\w*)\s*(?:\[[^\]]*\])?\(",
+                )],
+            ),
+            (
+                Language::TypeScript,
+                vec![
+                    // Functions and class methods
+                    regex(
+                        r"(?m)^(?P<indent>[ \t]*)(?P<vis>export\s+(?:default\s+)?|(?:public|private|protected)\s+)?(?:(?:static|async|readonly|override|abstract)\s+)*(?P<function>function\s*\*?\s*)?(?P<name>[A-Za-z_$][\w$]*)\s*(?:<[^>()]*>)?\s*\(",
+                    ),
+                    // Arrow functions
+                    regex(
+                        r"(?m)^[ \t]*(?P<vis>export\s+)?(?:const|let)\s+(?P<name>[A-Za-z_$][\w$]*)\s*=\s*(?:async\s+)?(?:<[^>()]*>)?\s*\(",
+                    ),
+                ],
+            ),
+            (
+                Language::Go,
+                vec![regex(
+                    r"(?m)^func\s+(?:\([^)]*\)\s*)?(?P<name>[A-Za-z_]\w*)\s*(?:\[[^\]]*\])?\(",
+                )],
+            ),
+            (
+                Language::Java,
+                vec![regex(
+                    r"(?m)^[ \t]*(?P<vis>(?:public|private|protected)\s+)?(?:(?:static|final|abstract|synchronized|native|default)\s+)*(?:<[^>()]*>\s*)?(?P<ret>[\w.]+(?:<[^;{}()=]*>)?(?:\[\])*)\s+(?P<name>[A-Za-z_]\w*)\s*\(",
+                )],
+            ),
+        ]);
+        let types = HashMap::from([
+            (
+                Language::Rust,
+                regex(
+                    r"(?m)^[ \t]*(?P<vis>pub(?:\([^)]*\))?\s+)?(?:struct|enum|trait|type|union)\s+(?P<name>[A-Za-z_]\w*)",
+                ),
+            ),
+            (
+                Language::Python,
+                regex(r"(?m)^[ \t]*class\s+(?P<name>[A-Za-z_]\w*)"),
+            ),
+            (
+                Language::TypeScript,
+                regex(
+                    r"(?m)^[ \t]*(?P<vis>export\s+(?:default\s+)?)?(?:declare\s+)?(?:abstract\s+)?(?:class|interface|type|enum)\s+(?P<name>[A-Za-z_$][\w$]*)",
+                ),
+            ),
+            (
+                Language::Go,
+                regex(r"(?m)^[ \t]*type\s+(?P<name>[A-Za-z_]\w*)"),
+            ),
+            (
+                Language::Java,
+                regex(
+                    r"(?m)^[ \t]*(?P<vis>(?:public|private|protected)\s+)?(?:(?:static|final|abstract|sealed|non-sealed|strictfp)\s+)*(?:class|interface|enum|record|@interface)\s+(?P<name>[A-Za-z_]\w*)",
+                ),
+            ),
+        ]);
+        Self { functions, types }
+    }
+
+    /// @ai:intent Functions and types declared in code; public ones only unless all is set
+    ///            (used to read required signatures, which may omit the modifiers)
+    /// @ai:effects pure
+    fn declarations(&self, code: &str, language: Language, all: bool) -> Vec<Declaration> {
+        let code = strip_comments_and_strings(code, language);
+        let mut declarations = Vec::new();
+
+        for cap in self.types[&language].captures_iter(&code) {
+            let name = &cap["name"];
+            if all || is_public(language, cap.name("vis"), name, false) {
+                declarations.push(Declaration {
+                    name: name.to_string(),
+                    kind: ApiKind::Type,
+                    signature: None,
+                });
+            }
+        }
+
+        for regex in &self.functions[&language] {
+            for cap in regex.captures_iter(&code) {
+                let (Some(whole), Some(name)) = (cap.get(0), cap.name("name")) else {
+                    continue;
+                };
+                let ret = cap.name("ret");
+                if KEYWORDS.contains(&name.as_str())
+                    || ret.is_some_and(|r| KEYWORDS.contains(&r.as_str()))
+                {
+                    continue;
+                }
+                // An indented TypeScript function without the keyword is a class method
+                let method = cap.name("indent").is_some_and(|i| !i.as_str().is_empty())
+                    && cap.name("function").is_none();
+                if !all && !is_public(language, cap.name("vis"), name.as_str(), method) {
+                    continue;
+                }
+
+                let open = whole.end() - 1;
+                let Some(close) = matching(&code, open, '(', ')') else {
+                    continue;
+                };
+                let Some(returns) = return_type(&code[close + 1..], language, all) else {
+                    continue;
+                };
+                let returns = ret.map_or(returns, |r| r.as_str().to_string());
+                declarations.push(Declaration {
+                    name: name.as_str().to_string(),
+                    kind: ApiKind::Function,
+                    signature: Some(format_signature(&code[open + 1..close], &returns)),
+                });
+            }
+        }
+        declarations
+    }
+
+    /// @ai:intent Signature of a required function, read with the function patterns
+    /// @ai:effects pure
+    fn required_signature(&self, required: &RequiredApi, language: Language) -> Option<String> {
+        let signature = required.signature.as_deref()?;
+        let parsed = self
+            .declarations(signature, language, true)
+            .into_iter()
+            .find(|d| d.kind == ApiKind::Function)
+            .and_then(|d| d.signature);
+        if parsed.is_none() {
+            tracing::warn!(
+                "Required signature of {} is not a {} function declaration, checking the name only: {}",
+                required.name,
+                language.as_str(),
+                signature
+            );
+        }
+        parsed
+    }
+}
+
+impl Default for ApiConformanceChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ApiConformanceCheckerTrait for ApiConformanceChecker {
+    /// @ai:intent Look up every required item among the public declarations of the non-test
+    ///            files
+    /// @ai:post result.is_some() == !task.api.is_empty()
+    /// @ai:effects pure
+    fn check(&self, task: &Task, files: &[SourceFile]) -> Option<ApiConformance> {
+        if task.api.is_empty() {
+            return None;
+        }
+        let language = task.language;
+
+        let declared: Vec<Declaration> = files
+            .iter()
+            .filter(|f| {
+                f.path.ends_with(&format!(".{}", language.extension()))
+                    && !is_test_file(&f.path, language)
+            })
+            .flat_map(|f| self.declarations(&f.content, language, false))
+            .collect();
+
+        let mut conformance = ApiConformance {
+            required: task.api.len() as u32,
+            ..Default::default()
+        };
+        for required in &task.api {
+            let candidates: Vec<&Declaration> = declared
+                .iter()
+                .filter(|d| d.name == required.name && d.kind == required.kind)
+                .collect();
+            let Some(first) = candidates.first() else {
+                conformance.missing.push(required.name.clone());
+                continue;
+            };
+
+            let expected = match required.kind {
+                ApiKind::Function => self.required_signature(required, language),
+                ApiKind::Type => None,
+            };
+            let Some(expected) = expected else {
+                conformance.conforming += 1;
+                continue;
+            };
+            if candidates
+                .iter()
+                .any(|d| d.signature.as_deref().map(compact) == Some(compact(&expected)))
+            {
+                conformance.conforming += 1;
+            } else {
+                conformance.mismatched.push(format!(
+                    "{}: found `{}`",
+                    required.name,
+                    first.signature.as_deref().unwrap_or_default()
+                ));
+            }
+        }
+        Some(conformance)
+    }
+}
+
+/// @ai:intent Whether a declaration is part of the public API
+///            Rust `pub` (not `pub(crate)`), Java `public`, TypeScript `export` or a class
+///            method that is not private, Go capitalized names, Python names without a
+///            leading underscore
+/// @ai:effects pure
+fn is_public(language: Language, visibility: Option<Match>, name: &str, method: bool) -> bool {
+    let visibility = visibility.map_or("", |v| v.as_str().trim());
+    match language {
+        Language::Rust => visibility == "pub",
+        Language::Java => visibility == "public",
+        Language::TypeScript => {
+            visibility.starts_with("export") || (method && matches!(visibility, "" | "public"))
+        }
+        Language::Go => name.starts_with(|c: char| c.is_ascii_uppercase()),
+        Language::Python => !name.starts_with('_'),
+    }
+}
+
+/// @ai:intent Return type written after a function's parameter list, up to its body
+///            None if the text is not a declaration (e.g. a call); with open_ended, the
+///            text may end without a body
+/// @ai:effects pure
+/// @ai:example (" -> Result<u32, Error> where T: Clone {", Rust, false) -> Some("Result<u32, Error>")
+fn return_type(rest: &str, language: Language, open_ended: bool) -> Option<String> {
+    let end = if language == Language::Python {
+        // The colon that opens the body, outside brackets
+        let mut depth = 0;
+        rest.char_indices()
+            .find(|&(_, c)| {
+                match c {
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' => depth -= 1,
+                    _ => {}
+                }
+                c == ':' && depth == 0
+            })
+            .map(|(i, _)| i)
+    } else {
+        let body = rest
+            .find(['{', ';'])
+            .filter(|&i| rest[i..].starts_with('{'));
+        let arrow = rest.find("=>").filter(|_| language == Language::TypeScript);
+        match (body, arrow) {
+            (Some(b), Some(a)) => Some(b.min(a)),
+            (b, a) => b.or(a),
+        }
+    };
+    let tail = match end {
+        Some(end) => &rest[..end],
+        None if open_ended && !rest.contains(['{', ';']) => rest,
+        None => return None,
+    };
+    let tail = tail.trim();
+
+    let returns = match language {
+        Language::Rust => {
+            let clause = tail
+                .find(" where ")
+                .or(tail.starts_with("where").then_some(0));
+            let tail = clause.map_or(tail, |i| tail[..i].trim());
+            if tail.is_empty() {
+                tail
+            } else {
+                tail.strip_prefix("->")?
+            }
+        }
+        Language::Python => tail.strip_prefix("->").unwrap_or(tail),
+        Language::TypeScript if tail.is_empty() => tail,
+        Language::TypeScript => tail.strip_prefix(':')?,
+        // The return type comes before the name
+        Language::Java if tail.is_empty() || tail.starts_with("throws") => "",
+        Language::Java => return None,
+        Language::Go => tail,
+    };
+    Some(returns.trim().to_string())
+}
+
+/// @ai:intent Signature shown for a function, whitespace collapsed
+/// @ai:effects pure
+/// @ai:example ("\n    a: i32,\n    b: i32,\n", "i32") -> "(a: i32, b: i32) -> i32"
+fn format_signature(params: &str, returns: &str) -> String {
+    let params = params.split_whitespace().collect::<Vec<_>>().join(" ");
+    let params = params.trim_end_matches(',').trim_end();
+    let returns = returns.split_whitespace().collect::<Vec<_>>().join(" ");
+    if returns.is_empty() {
+        format!("({})", params)
+    } else {
+        format!("({}) -> {}", params, returns)
+    }
+}
+
+/// @ai:intent Signature without whitespace, for comparison
+/// @ai:effects pure
+fn compact(signature: &str) -> String {
+    signature.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(language: Language, api: Vec<RequiredApi>) -> Task {
+        Task {
+            id: "api-task".to_string(),
+            name: "API Task".to_string(),
+            language,
+            api,
+            ..Default::default()
+        }
+    }
+
+    fn required(name: &str, kind: ApiKind, signature: Option<&str>) -> RequiredApi {
+        RequiredApi {
+            name: name.to_string(),
+            kind,
+            signature: signature.map(str::to_string),
+        }
+    }
+
+    fn file(path: &str, content: &str) -> SourceFile {
+        SourceFile {
+            path: path.to_string(),
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_rust_api_conformance() {
+        let task = task(
+            Language::Rust,
+            vec![
+                required("Bank", ApiKind::Type, None),
+                required(
+                    "transfer",
+                    ApiKind::Function,
+                    Some("fn transfer(&mut self, from: u32, to: u32, amount: u64) -> Result<(), BankError>"),
+                ),
+                required("balance", ApiKind::Function, Some("fn balance(&self, id: u32) -> u64")),
+                required("audit", ApiKind::Function, None),
+                required("close", ApiKind::Function, None),
+            ],
+        );
+        let code = "pub struct Bank;\n\
+                    impl Bank {\n\
+                    \x20   pub fn transfer(\n\
+                    \x20       &mut self,\n\
+                    \x20       from: u32,\n\
+                    \x20       to: u32,\n\
+                    \x20       amount: u64,\n\
+                    \x20   ) -> Result<(), BankError> {\n\
+                    \x20       Ok(())\n\
+                    \x20   }\n\
+                    \x20   pub fn balance(&self, id: u32) -> i64 { 0 }\n\
+                    \x20   pub(crate) fn audit(&self) {}\n\
+                    }\n\
+                    // pub fn close() {}\n";
+        let files = vec![
+            file("src/lib.rs", code),
+            file("tests/api.rs", "pub fn close() {}"),
+        ];
+
+        let conformance = ApiConformanceChecker::new().check(&task, &files).unwrap();
+        assert_eq!((conformance.required, conformance.conforming), (5, 2));
+        assert_eq!(conformance.missing, vec!["audit", "close"]);
+        assert_eq!(
+            conformance.mismatched,
+            vec!["balance: found `(&self, id: u32) -> i64`"]
+        );
+        assert!((conformance.score() - 40.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_typescript_and_python_signatures() {
+        let checker = ApiConformanceChecker::new();
+
+        let task_ts = task(
+            Language::TypeScript,
+            vec![
+                required(
+                    "add",
+                    ApiKind::Function,
+                    Some("function add(a: number, b: number): number"),
+                ),
+                required("push", ApiKind::Function, Some("push(item: T): void")),
+                required("Stack", ApiKind::Type, None),
+            ],
+        );
+        let code = "export const add = (a: number, b: number): number => a + b;\n\
+                    export class Stack<T> {\n\
+                    \x20 push(item: T): void {\n\
+                    \x20   this.items.push(item);\n\
+                    \x20 }\n\
+                    }\n";
+        let conformance = checker
+            .check(&task_ts, &[file("src/stack.ts", code)])
+            .unwrap();
+        assert_eq!(conformance.conforming, 3, "{:?}", conformance);
+
+        let task_py = task(
+            Language::Python,
+            vec![
+                required(
+                    "parse",
+                    ApiKind::Function,
+                    Some("def parse(text: str) -> dict[str, int]"),
+                ),
+                required("_helper", ApiKind::Function, None),
+            ],
+        );
+        let code = "def parse(text: str) -> dict[str, int]:\n    return {}\n\n\
+                    def _helper():\n    pass\n";
+        let conformance = checker.check(&task_py, &[file("parser.py", code)]).unwrap();
+        assert_eq!(conformance.conforming, 1);
+        assert_eq!(conformance.missing, vec!["_helper"]);
+
+        assert!(checker.check(&task(Language::Go, vec![]), &[]).is_none());
+    }
+
+    #[test]
+    fn test_return_type() {
+        assert_eq!(
+            return_type(
+                " -> Result<u32, Error> where T: Clone {",
+                Language::Rust,
+                false
+            ),
+            Some("Result<u32, Error>".to_string())
+        );
+        // A call, not a declaration
+        assert_eq!(return_type(";\n", Language::Rust, false), None);
+        assert_eq!(
+            return_type(" (int, error) {", Language::Go, false),
+            Some("(int, error)".to_string())
+        );
+        assert_eq!(
+            return_type(" -> None", Language::Python, true),
+            Some("None".to_string())
+        );
+    }
+}
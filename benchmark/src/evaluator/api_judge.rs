@@ -0,0 +1,210 @@
+//! @ai:module:intent Comparison judge on the Messages API: the implementations' source files
+//!                   are inlined into the prompt, within size limits, since the API cannot
+//!                   read directories
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api ApiJudge
+//! @ai:module:depends_on config, runner::client, evaluator::annotation_matcher
+//! @ai:module:stateless true
+
+use crate::config::{ApiConfig, CompareConfig};
+use crate::evaluator::annotation_matcher::block_on;
+use crate::platform::portable_path;
+use crate::runner::{ClaudeClient, ClaudeClientTrait, TaskContext};
+use anyhow::Result;
+use std::path::Path;
+use walkdir::WalkDir;
+
+/// @ai:intent Files that say nothing about the code and only use up the size limit
+const SKIPPED_FILES: &[&str] = &[
+    "Cargo.lock",
+    "package-lock.json",
+    "yarn.lock",
+    "pnpm-lock.yaml",
+    "poetry.lock",
+    "go.sum",
+];
+
+/// @ai:intent Extensions inlined before all other files, so code wins when space runs out
+const SOURCE_EXTENSIONS: &[&str] = &[
+    "rs", "py", "ts", "tsx", "js", "jsx", "mjs", "go", "java", "toml", "mod", "xml",
+];
+
+/// @ai:intent Judges comparisons with one Messages API call per prompt
+pub struct ApiJudge {
+    client: ClaudeClient,
+    max_file_bytes: usize,
+    max_source_bytes: usize,
+}
+
+impl ApiJudge {
+    /// @ai:intent Create a judge using the [api] settings, with the given model if set
+    /// @ai:pre ANTHROPIC_API_KEY environment variable is set
+    /// @ai:effects env
+    pub fn new(api: &ApiConfig, compare: &CompareConfig, model: Option<String>) -> Result<Self> {
+        let mut api = api.clone();
+        if let Some(model) = model {
+            api.model = model;
+        }
+        // Verdicts should not depend on the sampling settings of the code generation run
+        api.temperature = 0.0;
+
+        Ok(Self {
+            client: ClaudeClient::new(api)?,
+            max_file_bytes: compare.judge_max_file_bytes,
+            max_source_bytes: compare.judge_max_source_bytes,
+        })
+    }
+
+    /// @ai:intent Send one prompt and return the answer text
+    /// @ai:effects network
+    pub fn ask(&self, prompt: &str) -> Result<String> {
+        let context = TaskContext {
+            task_id: "comparison".to_string(),
+            mode: "judge".to_string(),
+            use_aicms_skill: false,
+            repetition: 0,
+        };
+        let response = block_on(self.client.send_message(prompt, None, &context))??;
+        tracing::debug!("Claude comparison response: {}", response.content);
+        Ok(response.content)
+    }
+
+    /// @ai:intent Source files of the implementations as a markdown section, each under
+    ///            the name it is shown with. Every implementation gets an equal share of
+    ///            max_source_bytes; longer files are truncated, files that no longer fit
+    ///            are listed as omitted
+    /// @ai:effects fs:read
+    pub fn inline_sources(&self, implementations: &[(String, &Path)]) -> Result<String> {
+        let share = self.max_source_bytes / implementations.len().max(1);
+        let mut section = String::from("## Source Files\n");
+        for (name, dir) in implementations {
+            section.push_str(&inline_directory(name, dir, self.max_file_bytes, share)?);
+        }
+        Ok(section)
+    }
+}
+
+/// @ai:intent Inline the files of one implementation, source files first, within budget bytes
+/// @ai:effects fs:read
+fn inline_directory(
+    name: &str,
+    dir: &Path,
+    max_file_bytes: usize,
+    budget: usize,
+) -> Result<String> {
+    let mut files = Vec::new();
+    let entries = WalkDir::new(dir)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.')
+        });
+    for entry in entries {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy();
+        if entry.file_type().is_file() && !SKIPPED_FILES.contains(&file_name.as_ref()) {
+            files.push(entry.path().to_path_buf());
+        }
+    }
+    let is_source = |path: &Path| {
+        path.extension()
+            .is_some_and(|ext| SOURCE_EXTENSIONS.contains(&ext.to_string_lossy().as_ref()))
+    };
+    // Stable sort: source files first, each group in path order
+    files.sort_by_key(|path| !is_source(path));
+
+    let mut section = String::new();
+    let mut omitted = Vec::new();
+    let mut remaining = budget;
+    for path in files {
+        let shown = format!("{}/{}", name, portable_path(path.strip_prefix(dir)?));
+        let Ok(content) = String::from_utf8(std::fs::read(&path)?) else {
+            omitted.push(format!("{} (binary)", shown));
+            continue;
+        };
+
+        let (content, note) = if content.len() > max_file_bytes {
+            let cut = floor_char_boundary(&content, max_file_bytes);
+            let note = format!("\n(truncated: first {} of {} bytes)", cut, content.len());
+            (&content[..cut], note)
+        } else {
+            (content.as_str(), String::new())
+        };
+        if content.len() > remaining {
+            omitted.push(format!("{} (size limit)", shown));
+            continue;
+        }
+        remaining -= content.len();
+
+        let fence = code_fence(content);
+        section.push_str(&format!(
+            "\n### {}\n{}\n{}\n{}{}\n",
+            shown,
+            fence,
+            content.trim_end(),
+            fence,
+            note
+        ));
+    }
+
+    if !omitted.is_empty() {
+        section.push_str(&format!(
+            "\nOmitted from {}: {}\n",
+            name,
+            omitted.join(", ")
+        ));
+    }
+    Ok(section)
+}
+
+/// @ai:intent Largest index <= max that falls on a character boundary of text
+/// @ai:effects pure
+fn floor_char_boundary(text: &str, max: usize) -> usize {
+    (0..=max.min(text.len()))
+        .rev()
+        .find(|&i| text.is_char_boundary(i))
+        .unwrap_or(0)
+}
+
+/// @ai:intent Backtick fence longer than any backtick run in the content (at least three)
+/// @ai:effects pure
+/// @ai:example ("let s = \"```\";") -> "````"
+fn code_fence(content: &str) -> String {
+    let longest = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    "`".repeat((longest + 1).max(3))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_directory_respects_limits() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::create_dir_all(root.join(".venv")).unwrap();
+        std::fs::write(root.join("src/lib.rs"), "pub fn f() {}\n").unwrap();
+        std::fs::write(root.join("src/big.rs"), "é".repeat(40)).unwrap();
+        std::fs::write(root.join("README.md"), "x".repeat(30)).unwrap();
+        std::fs::write(root.join("Cargo.lock"), "lock").unwrap();
+        std::fs::write(root.join(".venv/site.py"), "hidden").unwrap();
+        std::fs::write(root.join("logo.png"), [0xff, 0xfe, 0x00]).unwrap();
+
+        let section = inline_directory("implementation-a", root, 25, 50).unwrap();
+        assert!(section.contains("### implementation-a/src/lib.rs\n```\npub fn f() {}\n```"));
+        // 80 bytes of 2-byte characters, cut at 24
+        assert!(section.contains("(truncated: first 24 of 80 bytes)"));
+        assert!(section.contains("implementation-a/README.md (size limit)"));
+        assert!(section.contains("implementation-a/logo.png (binary)"));
+        assert!(!section.contains("lock") && !section.contains("hidden"));
+        // Source files come first
+        assert!(section.find("src/big.rs").unwrap() < section.find("README.md").unwrap());
+    }
+
+    #[test]
+    fn test_code_fence_outgrows_content() {
+        assert_eq!(code_fence("fn f() {}"), "```");
+        assert_eq!(code_fence("/// ```\n/// f();\n/// ````"), "`````");
+    }
+}
@@ -1,12 +1,40 @@
 //! @ai:module:intent Claude-based scoring of implementations
 //! @ai:module:layer application
-//! @ai:module:public_api ClaudeScorer, ComparisonScore, ImplementationScore
-//! @ai:module:stateless true
+//! @ai:module:public_api ClaudeScorer, JudgePanel, ComparisonScore, ImplementationScore, BlindAssignment,
+//!                        RankedVerdict, RankedImplementation, ParseAttempt, JudgeParseError
+//! @ai:module:stateless false
 
-use anyhow::Result;
+use crate::config::{ApiConfig, CompareConfig, JudgeBackend};
+use crate::evaluator::api_judge::ApiJudge;
+use crate::evaluator::json_repair::repair_json;
+use crate::evaluator::rubric::Rubric;
+use crate::metrics::statistics::SplitMix64;
+use crate::platform::resolve_executable;
+use crate::runner::claude_code_client::parse_cli_output;
+use crate::runner::process::{timeout_from_secs, ProcessTree};
+use crate::runner::retry::random_seed;
+use crate::runner::MANIFEST_FILE;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
 use std::process::Command;
+use std::sync::Mutex;
+use std::time::Duration;
+use tempfile::TempDir;
+use walkdir::WalkDir;
+
+/// @ai:intent Entries not copied for the judge: build output, logs, and files that reveal
+///            the mode (CLAUDE.md imports the skill, TASK.json names the mode)
+const REVIEW_EXCLUDED: &[&str] = &[
+    "target",
+    "node_modules",
+    ".git",
+    ".claude",
+    "CLAUDE.md",
+    MANIFEST_FILE,
+    "_claude_interaction.log",
+];
 
 /// @ai:intent Score for a single implementation aspect
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,16 +46,12 @@ pub struct AspectScore {
 /// @ai:intent Detailed score breakdown for an implementation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImplementationScore {
-    /// Overall score 0-100
+    /// Overall score 0-100: the rubric-weighted mean of the aspect scores
+    #[serde(default)]
     pub overall: u8,
-    /// Did the implementation match the specified intent?
-    pub intent_match: AspectScore,
-    /// How well were edge cases handled?
-    pub edge_cases: AspectScore,
-    /// Code quality and readability
-    pub code_quality: AspectScore,
-    /// How well were AICMS annotations used/followed?
-    pub annotation_compliance: AspectScore,
+    /// Score per rubric aspect, keyed by aspect name (stored inline, next to overall)
+    #[serde(flatten)]
+    pub aspects: BTreeMap<String, AspectScore>,
 }
 
 /// @ai:intent Comparison result between baseline and AICMS implementations
@@ -37,6 +61,115 @@ pub struct ComparisonScore {
     pub aicms: ImplementationScore,
     pub winner: String,
     pub summary: String,
+    /// Which mode the judge saw as Implementation A and B (None for unblinded scores)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignment: Option<BlindAssignment>,
+    /// Individual verdicts when several judge passes were combined into this one
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub passes: Vec<ComparisonScore>,
+    /// Attempts at parsing the judge's answer, the last one successful
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parse_attempts: Vec<ParseAttempt>,
+}
+
+/// @ai:intent One attempt at parsing a judge answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseAttempt {
+    /// 1 = the original answer; later attempts answer a re-prompt
+    pub attempt: u32,
+    /// Syntax repairs applied before parsing (e.g. "code-fence", "trailing-comma")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repairs: Vec<String>,
+    /// Why the answer was rejected; None for the attempt that parsed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// @ai:intent Context of the error returned when no attempt at parsing a judge answer
+///            succeeded, so callers can tell it from failures to run the judge
+#[derive(Debug, thiserror::Error)]
+#[error("Judge answer could not be parsed in {attempts} attempt(s)")]
+pub struct JudgeParseError {
+    pub attempts: u32,
+}
+
+/// @ai:intent Modes shown to the judge as Implementation A and Implementation B
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindAssignment {
+    pub implementation_a: String,
+    pub implementation_b: String,
+    /// Seed of the judge's random orders that drew this assignment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+/// @ai:intent The judge's verdict on two anonymous implementations
+#[derive(Debug, Clone, Deserialize)]
+struct BlindVerdict {
+    implementation_a: ImplementationScore,
+    implementation_b: ImplementationScore,
+    winner: String,
+    summary: String,
+}
+
+impl BlindVerdict {
+    /// @ai:intent Map the verdict back to the modes behind A and B
+    ///            Winners other than A or B count as a tie
+    /// @ai:effects pure
+    fn unblind(self, aicms_is_a: bool) -> ComparisonScore {
+        let (aicms, baseline) = if aicms_is_a {
+            (self.implementation_a, self.implementation_b)
+        } else {
+            (self.implementation_b, self.implementation_a)
+        };
+        let mode = |is_aicms: bool| if is_aicms { "aicms" } else { "baseline" };
+        let winner = self.winner.trim().to_lowercase();
+        let winner = match winner
+            .trim_start_matches("implementation")
+            .trim_matches(['_', ' '])
+        {
+            "a" => mode(aicms_is_a),
+            "b" => mode(!aicms_is_a),
+            _ => "tie",
+        };
+
+        ComparisonScore {
+            baseline,
+            aicms,
+            winner: winner.to_string(),
+            summary: self.summary,
+            assignment: Some(BlindAssignment {
+                implementation_a: mode(aicms_is_a).to_string(),
+                implementation_b: mode(!aicms_is_a).to_string(),
+                seed: None,
+            }),
+            passes: Vec::new(),
+            parse_attempts: Vec::new(),
+        }
+    }
+}
+
+/// @ai:intent Score and rank of one implementation in a ranked judgment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RankedImplementation {
+    /// 1 = best
+    pub rank: u32,
+    pub score: ImplementationScore,
+}
+
+/// @ai:intent Ranked judgment of several implementations, in the order they were given
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RankedVerdict {
+    pub implementations: Vec<RankedImplementation>,
+    pub summary: String,
+}
+
+/// @ai:intent The judge's answer to a ranking prompt, with implementations numbered from 1
+#[derive(Debug, Clone, Deserialize)]
+struct RankingResponse {
+    implementations: BTreeMap<String, ImplementationScore>,
+    ranking: Vec<String>,
+    summary: String,
 }
 
 /// @ai:intent Trait for scoring implementations
@@ -48,37 +181,391 @@ pub trait ClaudeScorerTrait: Send + Sync {
         baseline_dir: &Path,
         aicms_dir: &Path,
     ) -> Result<ComparisonScore>;
+
+    /// @ai:intent Score several implementations in one judgment and rank them
+    fn rank_dirs(&self, task_spec: &str, dirs: &[&Path]) -> Result<RankedVerdict>;
+}
+
+/// @ai:intent A Claude session kept open across comparisons
+#[derive(Debug, Clone)]
+struct ScorerSession {
+    id: String,
+    comparisons: u32,
 }
 
 /// @ai:intent Uses Claude Code CLI to score implementations
 pub struct ClaudeScorer {
     prompt_template: String,
+    /// Comparisons scored per session (1 = fresh process and full prompt every time)
+    comparisons_per_session: u32,
+    /// Limit per CLI call; None waits indefinitely
+    timeout: Option<Duration>,
+    /// Judge model passed to the CLI; None uses its default
+    model: Option<String>,
+    /// Aspects the judge scores and their weights in the overall score
+    rubric: Rubric,
+    /// Prompt for ranking more than two implementations at once
+    ranking_template: String,
+    /// Re-prompts asking the judge to fix an answer that cannot be parsed
+    parse_retries: u32,
+    /// Judge over the Messages API instead of the CLI, with the sources in the prompt
+    api: Option<ApiJudge>,
+    session: Mutex<Option<ScorerSession>>,
+    /// Seed of rng, recorded with every blind assignment
+    seed: u64,
+    /// Draws the A/B order of each comparison and the order of each ranking
+    rng: Mutex<SplitMix64>,
 }
 
 impl ClaudeScorer {
-    /// @ai:intent Create a new Claude scorer with a prompt template
-    /// @ai:effects pure
+    /// @ai:intent Create a new Claude scorer with a prompt template and a seed from the clock
+    /// @ai:effects time
     pub fn new(prompt_template: String) -> Self {
-        Self { prompt_template }
+        let seed = random_seed();
+        Self {
+            prompt_template,
+            comparisons_per_session: 1,
+            timeout: None,
+            model: None,
+            rubric: Rubric::default(),
+            ranking_template: default_ranking_prompt(),
+            parse_retries: 1,
+            api: None,
+            session: Mutex::new(None),
+            seed,
+            rng: Mutex::new(SplitMix64::new(seed)),
+        }
+    }
+
+    /// @ai:intent Score up to n comparisons in one session: later comparisons resume it
+    ///            with a short prompt instead of resending the rubric
+    /// @ai:effects pure
+    pub fn with_session_reuse(mut self, comparisons_per_session: u32) -> Self {
+        self.comparisons_per_session = comparisons_per_session.max(1);
+        self
+    }
+
+    /// @ai:intent Kill the CLI and its subprocesses when a comparison takes longer than this
+    /// @ai:effects pure
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// @ai:intent Judge with the given model instead of the CLI's default
+    /// @ai:effects pure
+    pub fn with_model(mut self, model: Option<String>) -> Self {
+        self.model = model;
+        self
+    }
+
+    /// @ai:intent Score the aspects of the given rubric instead of the default one
+    /// @ai:effects pure
+    pub fn with_rubric(mut self, rubric: Rubric) -> Self {
+        self.rubric = rubric;
+        self
+    }
+
+    /// @ai:intent Rank more than two implementations with the given prompt template
+    /// @ai:effects pure
+    pub fn with_ranking_prompt(mut self, ranking_template: String) -> Self {
+        self.ranking_template = ranking_template;
+        self
     }
 
-    /// @ai:intent Build the comparison prompt by substituting directory paths
+    /// @ai:intent Re-prompt up to n times when the judge's answer cannot be parsed
     /// @ai:effects pure
-    fn build_prompt(&self, task_spec: &str, baseline_dir: &Path, aicms_dir: &Path) -> String {
-        self.prompt_template
+    pub fn with_parse_retries(mut self, parse_retries: u32) -> Self {
+        self.parse_retries = parse_retries;
+        self
+    }
+
+    /// @ai:intent Draw the A/B orders from the given seed, so a run can be repeated
+    /// @ai:effects pure
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self.rng = Mutex::new(SplitMix64::new(seed));
+        self
+    }
+
+    /// @ai:intent Judge over the Messages API; every judgment is a single call, so
+    ///            sessions are not reused
+    /// @ai:effects pure
+    pub fn with_api_judge(mut self, api: ApiJudge) -> Self {
+        self.api = Some(api);
+        self.comparisons_per_session = 1;
+        self
+    }
+
+    /// @ai:intent Whether the next comparison shows AICMS as Implementation A
+    /// @ai:effects state:write
+    fn draw_aicms_is_a(&self) -> bool {
+        self.rng.lock().unwrap().below(2) == 0
+    }
+
+    /// @ai:intent Prompt for a comparison in a resumed session (rubric already in context)
+    /// @ai:effects pure
+    fn build_followup_prompt(task_spec: &str, dir_a: &Path, dir_b: &Path) -> String {
+        format!(
+            "Next comparison. Judge it on its own, ignoring the implementations you read before, \
+             with exactly the same rules, criteria, and JSON output format.\n\n\
+             ## Task Specification\n{}\n\n\
+             ## Directories to Compare\n\
+             - **Implementation A**: {}\n\
+             - **Implementation B**: {}\n\n\
+             Respond ONLY with the JSON result.",
+            task_spec,
+            dir_a.display(),
+            dir_b.display()
+        )
+    }
+
+    /// @ai:intent Session to resume for the next comparison, if reuse is on and it has room
+    /// @ai:effects pure
+    fn resumable_session(&self) -> Option<String> {
+        if self.comparisons_per_session <= 1 {
+            return None;
+        }
+
+        self.session
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|s| s.comparisons < self.comparisons_per_session)
+            .map(|s| s.id.clone())
+    }
+
+    /// @ai:intent Remember the session of a successful comparison
+    /// @ai:effects pure
+    fn record_session(&self, id: Option<String>, resumed: bool) {
+        let mut session = self.session.lock().unwrap();
+        *session = match (id, session.take()) {
+            (Some(id), Some(previous)) if resumed => Some(ScorerSession {
+                id,
+                comparisons: previous.comparisons + 1,
+            }),
+            (Some(id), _) => Some(ScorerSession { id, comparisons: 1 }),
+            (None, _) => None,
+        };
+    }
+
+    /// @ai:intent Run the Claude CLI once and return its stdout
+    ///            With session reuse, output is JSON so the session id can be read
+    /// @ai:effects io, network, fs:read
+    fn run_claude(&self, prompt: &str, resume: Option<&str>) -> Result<String> {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        if let Some(api) = &self.api {
+            return api.ask(prompt);
+        }
+
+        // Run Claude in agentic mode to let it read files from directories
+        let mut cmd = Command::new(resolve_executable("claude"));
+        cmd.arg("--print").arg("--verbose");
+        if let Some(model) = &self.model {
+            cmd.arg("--model").arg(model);
+        }
+        if self.comparisons_per_session > 1 {
+            cmd.arg("--output-format").arg("json");
+        }
+        if let Some(session_id) = resume {
+            cmd.arg("--resume").arg(session_id);
+        }
+
+        cmd.stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        ProcessTree::configure(&mut cmd);
+
+        let mut child = cmd.spawn()?;
+        let tree = ProcessTree::attach(child.id());
+
+        // Write prompt to stdin
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(prompt.as_bytes())?;
+        }
+
+        let output = tree.wait_with_output(child, self.timeout)?.with_context(|| {
+            format!(
+                "Claude scorer timed out after {}s; killed it and its subprocesses",
+                self.timeout.unwrap_or_default().as_secs()
+            )
+        })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            tracing::warn!("Claude CLI stderr: {}", stderr);
+        }
+
+        let response = String::from_utf8_lossy(&output.stdout).to_string();
+        tracing::debug!("Claude comparison response: {}", response);
+
+        Ok(response)
+    }
+
+    /// @ai:intent Answer text of a CLI call (the result message when output is JSON)
+    /// @ai:effects pure
+    fn response_text(&self, stdout: String) -> Result<String> {
+        if self.comparisons_per_session <= 1 {
+            return Ok(stdout);
+        }
+
+        Ok(parse_cli_output(&stdout)
+            .context("No result message in Claude CLI output")?
+            .result)
+    }
+
+    /// @ai:intent Parse a comparison and, with session reuse, track its session
+    ///            An unparseable answer is re-prompted in a fresh call, see parse_with_retries
+    /// @ai:effects io, network
+    fn parse_output(
+        &self,
+        stdout: &str,
+        resumed: bool,
+        aicms_is_a: bool,
+    ) -> Result<ComparisonScore> {
+        let (response, session_id) = if self.comparisons_per_session <= 1 {
+            (stdout.to_string(), None)
+        } else {
+            let result =
+                parse_cli_output(stdout).context("No result message in Claude CLI output")?;
+            (result.result, result.session_id)
+        };
+
+        let format = self.rubric.render("{{OUTPUT_FORMAT}}");
+        let (mut score, attempts) = self.parse_with_retries(response, &format, |response| {
+            self.parse_response(response, aicms_is_a)
+        })?;
+        score.parse_attempts = attempts;
+
+        if self.comparisons_per_session > 1 {
+            self.record_session(session_id, resumed);
+        }
+        Ok(score)
+    }
+
+    /// @ai:intent Parse a judge answer, repairing common JSON slips first; while it still
+    ///            fails, re-prompt with the error and the expected format, up to
+    ///            parse_retries times. Returns every attempt made
+    /// @ai:effects io, network
+    fn parse_with_retries<T>(
+        &self,
+        mut response: String,
+        format: &str,
+        parse: impl Fn(&str) -> Result<T>,
+    ) -> Result<(T, Vec<ParseAttempt>)> {
+        let mut attempts = Vec::new();
+        loop {
+            let repairs = repair_json(&response)
+                .map(|repaired| repaired.repairs.iter().map(|r| r.to_string()).collect())
+                .unwrap_or_default();
+            let attempt = attempts.len() as u32 + 1;
+
+            let error = match parse(&response) {
+                Ok(value) => {
+                    attempts.push(ParseAttempt {
+                        attempt,
+                        repairs,
+                        error: None,
+                    });
+                    return Ok((value, attempts));
+                }
+                Err(e) => e,
+            };
+            attempts.push(ParseAttempt {
+                attempt,
+                repairs,
+                error: Some(format!("{:#}", error)),
+            });
+
+            if attempt > self.parse_retries {
+                return Err(error.context(JudgeParseError { attempts: attempt }));
+            }
+            tracing::warn!(
+                "Judge answer could not be parsed ({:#}); asking for a corrected one",
+                error
+            );
+            let prompt = build_reprompt(&response, &format!("{:#}", error), format);
+            response = self.response_text(self.run_claude(&prompt, None)?)?;
+        }
+    }
+
+    /// @ai:intent Build the comparison prompt by substituting the rubric and directory paths
+    /// @ai:effects pure
+    fn build_prompt(&self, task_spec: &str, dir_a: &Path, dir_b: &Path) -> String {
+        self.rubric
+            .render(&self.prompt_template)
             .replace("{{TASK_SPEC}}", task_spec)
-            .replace("{{BASELINE_DIR}}", &baseline_dir.display().to_string())
-            .replace("{{AICMS_DIR}}", &aicms_dir.display().to_string())
+            .replace("{{DIR_A}}", &dir_a.display().to_string())
+            .replace("{{DIR_B}}", &dir_b.display().to_string())
     }
 
-    /// @ai:intent Parse Claude's JSON response
+    /// @ai:intent Parse Claude's JSON verdict, map it back to the modes, and compute the
+    ///            weighted overall scores; fails if a rubric aspect was not scored
     /// @ai:effects pure
-    fn parse_response(response: &str) -> Result<ComparisonScore> {
+    fn parse_response(&self, response: &str, aicms_is_a: bool) -> Result<ComparisonScore> {
         // Try to extract JSON from the response
-        let json_str = extract_json(response)?;
-        let score: ComparisonScore = serde_json::from_str(&json_str)?;
+        let json_str = repair_json(response)?.json;
+        let verdict: BlindVerdict = serde_json::from_str(&json_str)?;
+        let mut score = verdict.unblind(aicms_is_a);
+        if let Some(assignment) = score.assignment.as_mut() {
+            assignment.seed = Some(self.seed);
+        }
+        self.rubric.apply(&mut score.baseline)?;
+        self.rubric.apply(&mut score.aicms)?;
         Ok(score)
     }
+
+    /// @ai:intent Parse a ranking of shown implementations and map it back to input order
+    ///            order[i] is the input index shown as implementation i + 1
+    /// @ai:effects pure
+    fn parse_ranking(&self, response: &str, order: &[usize]) -> Result<RankedVerdict> {
+        let json_str = repair_json(response)?.json;
+        let mut ranking: RankingResponse = serde_json::from_str(&json_str)?;
+
+        let shown = |label: &str| -> Option<usize> {
+            let label = label.trim().to_lowercase();
+            let number = label
+                .trim_start_matches("implementation")
+                .trim_matches(['_', ' ', '-']);
+            number
+                .parse::<usize>()
+                .ok()
+                .filter(|n| (1..=order.len()).contains(n))
+        };
+        let mut ranks = vec![0u32; order.len()];
+        for (position, label) in ranking.ranking.iter().enumerate() {
+            let Some(n) = shown(label) else {
+                anyhow::bail!("Ranking names unknown implementation {:?}", label);
+            };
+            if ranks[n - 1] != 0 {
+                anyhow::bail!("Ranking names implementation {} twice", n);
+            }
+            ranks[n - 1] = position as u32 + 1;
+        }
+        if ranks.contains(&0) {
+            anyhow::bail!("Ranking does not include every implementation");
+        }
+
+        let mut implementations: Vec<Option<RankedImplementation>> = vec![None; order.len()];
+        for (n, &input) in order.iter().enumerate() {
+            let mut score = ranking
+                .implementations
+                .remove(&(n + 1).to_string())
+                .with_context(|| format!("Judge response lacks implementation {}", n + 1))?;
+            self.rubric.apply(&mut score)?;
+            implementations[input] = Some(RankedImplementation {
+                rank: ranks[n],
+                score,
+            });
+        }
+
+        Ok(RankedVerdict {
+            implementations: implementations.into_iter().flatten().collect(),
+            summary: ranking.summary,
+        })
+    }
 }
 
 impl Default for ClaudeScorer {
@@ -87,6 +574,45 @@ impl Default for ClaudeScorer {
     }
 }
 
+/// @ai:intent Default ranking prompt template for more than two implementations
+/// @ai:effects pure
+pub fn default_ranking_prompt() -> String {
+    r#"You are evaluating several implementations of the same task. Read and compare the source files.
+
+## Task Specification
+{{TASK_SPEC}}
+
+## Directories to Compare
+{{DIRECTORIES}}
+
+The implementations are numbered in random order. Judge them only by their code.
+
+## IMPORTANT: Fair Comparison Rules
+
+**IGNORE all `@ai:*` annotation comments when scoring.** Do not give any advantage or disadvantage to code based on the presence or absence of `@ai:intent`, `@ai:pre`, `@ai:post`, or any other AICMS annotations.
+
+Focus ONLY on:
+- The actual code implementation
+- The logic and algorithms used
+- Error handling and edge cases
+- Code structure and readability
+
+## Instructions
+1. Read all source files in every directory
+2. **Strip out all `@ai:*` annotations mentally** before evaluating
+3. Score each implementation on the criteria below, then rank all of them from best to worst
+4. Output ONLY the JSON result (no markdown, no explanation)
+
+## Scoring Criteria (0-100 for each)
+Weights show how much each criterion counts toward the overall verdict.
+{{CRITERIA}}
+
+## Required Output Format
+Respond ONLY with valid JSON in this exact format, with every implementation in the ranking exactly once:
+{{OUTPUT_FORMAT}}"#
+        .to_string()
+}
+
 /// @ai:intent Default comparison prompt template
 /// @ai:effects pure
 pub fn default_comparison_prompt() -> String {
@@ -96,8 +622,10 @@ pub fn default_comparison_prompt() -> String {
 {{TASK_SPEC}}
 
 ## Directories to Compare
-- **Baseline** (no AICMS context): {{BASELINE_DIR}}
-- **AICMS** (with annotation context): {{AICMS_DIR}}
+- **Implementation A**: {{DIR_A}}
+- **Implementation B**: {{DIR_B}}
+
+The implementations are presented in random order. Judge them only by their code.
 
 ## IMPORTANT: Fair Comparison Rules
 
@@ -110,98 +638,385 @@ Focus ONLY on:
 - Code structure and readability
 
 ## Instructions
-1. Read all source files in both directories (ignore _claude_interaction.log and target/)
+1. Read all source files in both directories
 2. **Strip out all `@ai:*` annotations mentally** before evaluating
 3. Compare the implementations on the criteria below
 4. Output ONLY the JSON result (no markdown, no explanation)
 
 ## Scoring Criteria (0-100 for each)
-1. **Intent Match**: Does the actual implementation correctly fulfill the specified task? (Ignore @ai:intent comments)
-2. **Edge Cases**: Are edge cases handled correctly in the code? (Ignore @ai:pre/@ai:post comments)
-3. **Code Quality**: Is the code clean, readable, and well-structured?
-4. **Error Handling**: Does the code properly handle errors and invalid inputs?
+Weights show how much each criterion counts toward the overall verdict.
+{{CRITERIA}}
 
 ## Required Output Format
 Respond ONLY with valid JSON in this exact format:
-{
-  "baseline": {
-    "overall": <0-100>,
-    "intent_match": {"score": <0-100>, "reason": "<brief reason>"},
-    "edge_cases": {"score": <0-100>, "reason": "<brief reason>"},
-    "code_quality": {"score": <0-100>, "reason": "<brief reason>"},
-    "annotation_compliance": {"score": <0-100>, "reason": "<brief reason for error handling>"}
-  },
-  "aicms": {
-    "overall": <0-100>,
-    "intent_match": {"score": <0-100>, "reason": "<brief reason>"},
-    "edge_cases": {"score": <0-100>, "reason": "<brief reason>"},
-    "code_quality": {"score": <0-100>, "reason": "<brief reason>"},
-    "annotation_compliance": {"score": <0-100>, "reason": "<brief reason for error handling>"}
-  },
-  "winner": "<baseline|aicms|tie>",
-  "summary": "<one sentence comparing the two implementations>"
-}"#
+{{OUTPUT_FORMAT}}"#
     .to_string()
 }
 
 impl ClaudeScorerTrait for ClaudeScorer {
     /// @ai:intent Compare and score two implementations using Claude agentic mode
-    /// @ai:effects io, network, fs:read
+    ///            The judge sees copies of the directories as Implementation A and B in
+    ///            random order, without files that name the mode
+    /// @ai:effects io, network, fs:read, fs:write
     fn compare_dirs(
         &self,
         task_spec: &str,
         baseline_dir: &Path,
         aicms_dir: &Path,
     ) -> Result<ComparisonScore> {
-        use std::io::Write;
-        use std::process::Stdio;
+        if !self.prompt_template.contains("{{DIR_A}}")
+            || !self.prompt_template.contains("{{DIR_B}}")
+        {
+            anyhow::bail!(
+                "Comparison prompt must reference {{{{DIR_A}}}} and {{{{DIR_B}}}} \
+                 ({{{{BASELINE_DIR}}}}/{{{{AICMS_DIR}}}} would reveal the modes)"
+            );
+        }
 
-        let prompt = self.build_prompt(task_spec, baseline_dir, aicms_dir);
+        let aicms_is_a = self.draw_aicms_is_a();
+        let staging = TempDir::new()?;
+        let dir_a = staging.path().join("implementation-a");
+        let dir_b = staging.path().join("implementation-b");
+        let (aicms_copy, baseline_copy) = if aicms_is_a {
+            (&dir_a, &dir_b)
+        } else {
+            (&dir_b, &dir_a)
+        };
+        copy_for_review(baseline_dir, baseline_copy)?;
+        copy_for_review(aicms_dir, aicms_copy)?;
 
-        // Run Claude in agentic mode to let it read files from directories
-        let mut child = Command::new("claude")
-            .arg("--print")
-            .arg("--verbose")
-            .stdin(Stdio::piped())
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        if let Some(session_id) = self.resumable_session() {
+            let prompt = Self::build_followup_prompt(task_spec, &dir_a, &dir_b);
+            match self
+                .run_claude(&prompt, Some(&session_id))
+                .and_then(|stdout| self.parse_output(&stdout, true, aicms_is_a))
+            {
+                Ok(score) => return Ok(score),
+                Err(e) => {
+                    tracing::warn!("Resumed scoring session failed ({}); starting a new one", e);
+                    self.record_session(None, true);
+                }
+            }
+        }
 
-        // Write prompt to stdin
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin.write_all(prompt.as_bytes())?;
+        let prompt = match &self.api {
+            // The API cannot read files: the sources go first, the copies are named as shown
+            Some(api) => {
+                let sources = api.inline_sources(&[
+                    ("implementation-a".to_string(), dir_a.as_path()),
+                    ("implementation-b".to_string(), dir_b.as_path()),
+                ])?;
+                let prompt = self.build_prompt(
+                    task_spec,
+                    Path::new("implementation-a"),
+                    Path::new("implementation-b"),
+                );
+                format!("{}\n\n{}", sources, prompt)
+            }
+            None => self.build_prompt(task_spec, &dir_a, &dir_b),
+        };
+        let stdout = self.run_claude(&prompt, None)?;
+        self.parse_output(&stdout, false, aicms_is_a)
+    }
+
+    /// @ai:intent Rank implementations in one fresh session, shown as numbered copies in
+    ///            random order without files that name the mode
+    /// @ai:effects io, network, fs:read, fs:write
+    fn rank_dirs(&self, task_spec: &str, dirs: &[&Path]) -> Result<RankedVerdict> {
+        if !self.ranking_template.contains("{{DIRECTORIES}}") {
+            anyhow::bail!("Ranking prompt must reference {{{{DIRECTORIES}}}}");
         }
 
-        let output = child.wait_with_output()?;
+        let order = shuffled_order(&mut self.rng.lock().unwrap(), dirs.len());
+        let staging = TempDir::new()?;
+        let mut listing = Vec::new();
+        let mut copies = Vec::new();
+        for (n, &input) in order.iter().enumerate() {
+            let name = format!("implementation-{}", n + 1);
+            let copy = staging.path().join(&name);
+            copy_for_review(dirs[input], &copy)?;
+            // The API judge sees inlined sources under the bare names
+            let shown = if self.api.is_some() {
+                name.clone()
+            } else {
+                copy.display().to_string()
+            };
+            listing.push(format!("- **Implementation {}**: {}", n + 1, shown));
+            copies.push((name, copy));
+        }
 
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            tracing::warn!("Claude CLI stderr: {}", stderr);
+        let mut prompt = self
+            .rubric
+            .render_ranking(&self.ranking_template, dirs.len())
+            .replace("{{TASK_SPEC}}", task_spec)
+            .replace("{{DIRECTORIES}}", &listing.join("\n"));
+        if let Some(api) = &self.api {
+            let copies: Vec<(String, &Path)> = copies
+                .iter()
+                .map(|(name, copy)| (name.clone(), copy.as_path()))
+                .collect();
+            prompt = format!("{}\n\n{}", api.inline_sources(&copies)?, prompt);
         }
+        let response = self.response_text(self.run_claude(&prompt, None)?)?;
+        let format = self.rubric.render_ranking("{{OUTPUT_FORMAT}}", dirs.len());
+        let (verdict, _) = self.parse_with_retries(response, &format, |response| {
+            self.parse_ranking(response, &order)
+        })?;
+        Ok(verdict)
+    }
+}
 
-        let response = String::from_utf8_lossy(&output.stdout);
-        tracing::debug!("Claude comparison response: {}", response);
+/// @ai:intent Random permutation of 0..n (Fisher-Yates), every index drawn from rng
+/// @ai:effects state:write
+fn shuffled_order(rng: &mut SplitMix64, n: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..n).collect();
+    for i in (1..n).rev() {
+        order.swap(i, rng.below(i + 1));
+    }
+    order
+}
+
+/// @ai:intent Judges each comparison several times and combines the verdicts
+///            Every pass has its own scorer, so sessions and A/B orders are independent
+pub struct JudgePanel {
+    judges: Vec<ClaudeScorer>,
+}
+
+impl JudgePanel {
+    /// @ai:intent Create a panel from the given judges
+    /// @ai:pre judges is not empty
+    /// @ai:effects pure
+    pub fn new(judges: Vec<ClaudeScorer>) -> Self {
+        Self { judges }
+    }
+
+    /// @ai:intent Create judge_passes scorers, using judge_models in turn
+    ///            API judges take their model from [api] when judge_models is empty
+    ///            Pass n draws its A/B orders from the run's seed + n
+    /// @ai:effects env, time
+    pub fn from_config(
+        prompt_template: &str,
+        ranking_template: &str,
+        rubric: &Rubric,
+        config: &CompareConfig,
+        backend: JudgeBackend,
+        api: &ApiConfig,
+    ) -> Result<Self> {
+        let seed = config.seed.unwrap_or_else(random_seed);
+        tracing::info!("Judge seed: {}", seed);
+        let judges = (0..config.judge_passes.max(1) as usize)
+            .map(|pass| {
+                let model = (!config.judge_models.is_empty())
+                    .then(|| config.judge_models[pass % config.judge_models.len()].clone());
+                let judge = ClaudeScorer::new(prompt_template.to_string())
+                    .with_session_reuse(config.comparisons_per_session)
+                    .with_timeout(timeout_from_secs(config.timeout_secs))
+                    .with_model(model.clone())
+                    .with_rubric(rubric.clone())
+                    .with_ranking_prompt(ranking_template.to_string())
+                    .with_parse_retries(config.parse_retries)
+                    .with_seed(seed.wrapping_add(pass as u64));
+                match backend {
+                    JudgeBackend::ClaudeCode => Ok(judge),
+                    JudgeBackend::Api => {
+                        Ok(judge.with_api_judge(ApiJudge::new(api, config, model)?))
+                    }
+                }
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self::new(judges))
+    }
+}
+
+impl ClaudeScorerTrait for JudgePanel {
+    /// @ai:intent Run every judge pass and combine the verdicts that succeeded
+    ///            Fails only if all passes fail
+    /// @ai:effects io, network, fs:read, fs:write
+    fn compare_dirs(
+        &self,
+        task_spec: &str,
+        baseline_dir: &Path,
+        aicms_dir: &Path,
+    ) -> Result<ComparisonScore> {
+        let mut passes = Vec::new();
+        let mut last_error = None;
+        for (i, judge) in self.judges.iter().enumerate() {
+            match judge.compare_dirs(task_spec, baseline_dir, aicms_dir) {
+                Ok(score) => passes.push(score),
+                Err(e) => {
+                    tracing::warn!("Judge pass {}/{} failed: {}", i + 1, self.judges.len(), e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match last_error {
+            Some(e) if passes.is_empty() => Err(e),
+            _ => Ok(combine_verdicts(passes)),
+        }
+    }
+
+    /// @ai:intent Run every judge pass and combine the rankings that succeeded
+    ///            Fails only if all passes fail
+    /// @ai:effects io, network, fs:read, fs:write
+    fn rank_dirs(&self, task_spec: &str, dirs: &[&Path]) -> Result<RankedVerdict> {
+        let mut passes = Vec::new();
+        let mut last_error = None;
+        for (i, judge) in self.judges.iter().enumerate() {
+            match judge.rank_dirs(task_spec, dirs) {
+                Ok(verdict) => passes.push(verdict),
+                Err(e) => {
+                    tracing::warn!("Judge pass {}/{} failed: {}", i + 1, self.judges.len(), e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match last_error {
+            Some(e) if passes.is_empty() => Err(e),
+            _ => Ok(combine_rankings(passes)),
+        }
+    }
+}
+
+/// @ai:intent Combine ranking passes: mean scores, ranked by mean rank (equal means share a rank)
+///            The summary comes from the first pass
+/// @ai:pre passes is not empty and all rank the same implementations
+/// @ai:effects pure
+fn combine_rankings(mut passes: Vec<RankedVerdict>) -> RankedVerdict {
+    if passes.len() == 1 {
+        return passes.remove(0);
+    }
+
+    let count = passes[0].implementations.len();
+    let mean_ranks: Vec<f64> = (0..count)
+        .map(|i| {
+            let total: u32 = passes.iter().map(|p| p.implementations[i].rank).sum();
+            total as f64 / passes.len() as f64
+        })
+        .collect();
+
+    let implementations = (0..count)
+        .map(|i| {
+            let scores: Vec<_> = passes.iter().map(|p| &p.implementations[i].score).collect();
+            RankedImplementation {
+                rank: 1 + mean_ranks.iter().filter(|&&r| r < mean_ranks[i]).count() as u32,
+                score: combine_implementation(&scores, scores[0]),
+            }
+        })
+        .collect();
+
+    RankedVerdict {
+        implementations,
+        summary: passes.swap_remove(0).summary,
+    }
+}
 
-        Self::parse_response(&response)
+/// @ai:intent Combine judge passes: mean scores and majority winner (no majority = tie)
+///            Reasons and summary come from the first pass that agrees with the winner
+/// @ai:pre passes is not empty
+/// @ai:effects pure
+fn combine_verdicts(mut passes: Vec<ComparisonScore>) -> ComparisonScore {
+    if passes.len() == 1 {
+        return passes.remove(0);
+    }
+
+    let votes = |winner: &str| passes.iter().filter(|p| p.winner == winner).count();
+    let winner = ["aicms", "baseline", "tie"]
+        .into_iter()
+        .find(|winner| votes(winner) * 2 > passes.len())
+        .unwrap_or("tie");
+    let representative = passes
+        .iter()
+        .find(|p| p.winner == winner)
+        .unwrap_or(&passes[0]);
+
+    let baseline: Vec<_> = passes.iter().map(|p| &p.baseline).collect();
+    let aicms: Vec<_> = passes.iter().map(|p| &p.aicms).collect();
+
+    ComparisonScore {
+        baseline: combine_implementation(&baseline, &representative.baseline),
+        aicms: combine_implementation(&aicms, &representative.aicms),
+        winner: winner.to_string(),
+        summary: representative.summary.clone(),
+        assignment: None,
+        passes,
+        parse_attempts: Vec::new(),
+    }
+}
+
+/// @ai:intent Mean overall and aspect scores of one mode over the passes that scored them
+///            Reasons come from the representative pass when it has the aspect
+/// @ai:pre scores is not empty
+/// @ai:effects pure
+fn combine_implementation(
+    scores: &[&ImplementationScore],
+    representative: &ImplementationScore,
+) -> ImplementationScore {
+    let mut aspects = BTreeMap::new();
+    for name in scores.iter().flat_map(|s| s.aspects.keys()) {
+        if aspects.contains_key(name) {
+            continue;
+        }
+        let scored: Vec<&AspectScore> = scores.iter().filter_map(|s| s.aspects.get(name)).collect();
+        let reason = representative.aspects.get(name).unwrap_or(scored[0]);
+        aspects.insert(
+            name.clone(),
+            AspectScore {
+                score: mean_score(scored.iter().map(|a| a.score)),
+                reason: reason.reason.clone(),
+            },
+        );
+    }
+
+    ImplementationScore {
+        overall: mean_score(scores.iter().map(|s| s.overall)),
+        aspects,
     }
 }
 
-/// @ai:intent Extract JSON object from response that may contain extra text
+/// @ai:intent Mean of scores, rounded to the nearest integer (0 for none)
 /// @ai:effects pure
-fn extract_json(response: &str) -> Result<String> {
-    // Find the first { and last }
-    let start = response
-        .find('{')
-        .ok_or_else(|| anyhow::anyhow!("No JSON object found in response"))?;
-    let end = response
-        .rfind('}')
-        .ok_or_else(|| anyhow::anyhow!("No JSON object end found in response"))?;
+fn mean_score(scores: impl ExactSizeIterator<Item = u8>) -> u8 {
+    let count = scores.len().max(1);
+    let total: u32 = scores.map(u32::from).sum();
+    (total as f64 / count as f64).round() as u8
+}
 
-    if end <= start {
-        anyhow::bail!("Invalid JSON structure in response");
+/// @ai:intent Copy an implementation directory for the judge, skipping REVIEW_EXCLUDED
+/// @ai:effects fs:read, fs:write
+fn copy_for_review(source: &Path, dest: &Path) -> Result<()> {
+    let entries = WalkDir::new(source).into_iter().filter_entry(|entry| {
+        entry.depth() == 0
+            || !REVIEW_EXCLUDED.contains(&entry.file_name().to_string_lossy().as_ref())
+    });
+    for entry in entries {
+        let entry = entry?;
+        let target = dest.join(entry.path().strip_prefix(source)?);
+        if entry.file_type().is_dir() {
+            std::fs::create_dir_all(&target)?;
+        } else if entry.file_type().is_file() {
+            std::fs::copy(entry.path(), &target)
+                .with_context(|| format!("Failed to copy {}", entry.path().display()))?;
+        }
     }
+    Ok(())
+}
 
-    Ok(response[start..=end].to_string())
+/// @ai:intent Prompt asking the judge to restate an answer that could not be parsed
+///            The answer is quoted, so the judge need not read the implementations again
+/// @ai:effects pure
+fn build_reprompt(response: &str, error: &str, format: &str) -> String {
+    format!(
+        "Your previous evaluation could not be processed: {}\n\n\
+         ## Previous Answer\n{}\n\n\
+         ## Required Output Format\n\
+         Restate the same evaluation, keeping its scores and reasons. \
+         Respond ONLY with valid JSON in this exact format:\n{}",
+        error,
+        response.trim(),
+        format
+    )
 }
 
 /// @ai:intent Mock scorer for testing
@@ -215,50 +1030,30 @@ impl MockClaudeScorer {
         Self { score }
     }
 
-    /// @ai:intent Create a mock scorer with default scores
+    /// @ai:intent Create a mock scorer with default scores for the default rubric
     pub fn with_defaults() -> Self {
+        let implementation = |overall: u8, scores: [u8; 4], reason: &str| ImplementationScore {
+            overall,
+            aspects: Rubric::default()
+                .aspects
+                .into_iter()
+                .zip(scores)
+                .map(|(aspect, score)| {
+                    let reason = reason.to_string();
+                    (aspect.name, AspectScore { score, reason })
+                })
+                .collect(),
+        };
+
         Self {
             score: ComparisonScore {
-                baseline: ImplementationScore {
-                    overall: 70,
-                    intent_match: AspectScore {
-                        score: 70,
-                        reason: "Mock baseline".to_string(),
-                    },
-                    edge_cases: AspectScore {
-                        score: 60,
-                        reason: "Mock baseline".to_string(),
-                    },
-                    code_quality: AspectScore {
-                        score: 75,
-                        reason: "Mock baseline".to_string(),
-                    },
-                    annotation_compliance: AspectScore {
-                        score: 50,
-                        reason: "Mock baseline".to_string(),
-                    },
-                },
-                aicms: ImplementationScore {
-                    overall: 85,
-                    intent_match: AspectScore {
-                        score: 90,
-                        reason: "Mock AICMS".to_string(),
-                    },
-                    edge_cases: AspectScore {
-                        score: 85,
-                        reason: "Mock AICMS".to_string(),
-                    },
-                    code_quality: AspectScore {
-                        score: 80,
-                        reason: "Mock AICMS".to_string(),
-                    },
-                    annotation_compliance: AspectScore {
-                        score: 90,
-                        reason: "Mock AICMS".to_string(),
-                    },
-                },
+                baseline: implementation(70, [70, 60, 75, 50], "Mock baseline"),
+                aicms: implementation(85, [90, 85, 80, 90], "Mock AICMS"),
                 winner: "aicms".to_string(),
                 summary: "Mock comparison".to_string(),
+                assignment: None,
+                passes: Vec::new(),
+                parse_attempts: Vec::new(),
             },
         }
     }
@@ -273,6 +1068,19 @@ impl ClaudeScorerTrait for MockClaudeScorer {
     ) -> Result<ComparisonScore> {
         Ok(self.score.clone())
     }
+
+    /// @ai:intent Rank implementations in the order given, all with the AICMS score
+    fn rank_dirs(&self, _task_spec: &str, dirs: &[&Path]) -> Result<RankedVerdict> {
+        Ok(RankedVerdict {
+            implementations: (1..=dirs.len() as u32)
+                .map(|rank| RankedImplementation {
+                    rank,
+                    score: self.score.aicms.clone(),
+                })
+                .collect(),
+            summary: self.score.summary.clone(),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -280,28 +1088,242 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_extract_json_simple() {
-        let response = r#"{"score": 85}"#;
-        let result = extract_json(response).unwrap();
-        assert_eq!(result, r#"{"score": 85}"#);
+    fn test_build_prompt_contains_paths() {
+        let scorer = ClaudeScorer::default();
+        let dir_a = Path::new("/tmp/implementation-a");
+        let dir_b = Path::new("/tmp/implementation-b");
+        let prompt = scorer.build_prompt("spec", dir_a, dir_b);
+        assert!(prompt.contains("/tmp/implementation-a"));
+        assert!(prompt.contains("/tmp/implementation-b"));
+        assert!(prompt.contains("spec"));
+        assert!(!prompt.to_lowercase().contains("baseline"));
+        assert!(prompt.contains("**Error Handling** (weight 1)"));
+        assert!(prompt.contains(r#""error_handling": {"score""#));
+        assert!(!prompt.contains("{{"));
     }
 
     #[test]
-    fn test_extract_json_with_prefix() {
-        let response = r#"Here's the score: {"score": 85} end"#;
-        let result = extract_json(response).unwrap();
-        assert_eq!(result, r#"{"score": 85}"#);
+    fn test_verdict_is_mapped_back_to_modes() {
+        let aspect = |score| format!(r#"{{"score": {}, "reason": "r"}}"#, score);
+        let implementation = |overall| {
+            format!(
+                r#"{{"intent_match": {1}, "edge_cases": {1}, "code_quality": {1}, "error_handling": {1}, "overall": {0}}}"#,
+                overall,
+                aspect(overall)
+            )
+        };
+        let response = format!(
+            r#"Verdict: {{"implementation_a": {}, "implementation_b": {}, "winner": "A", "summary": "A is simpler"}}"#,
+            implementation(90),
+            implementation(60)
+        );
+
+        let scorer = ClaudeScorer::default().with_seed(7);
+        let score = scorer.parse_response(&response, true).unwrap();
+        assert_eq!((score.aicms.overall, score.baseline.overall), (90, 60));
+        assert_eq!(score.winner, "aicms");
+        let assignment = score.assignment.unwrap();
+        assert_eq!(assignment.implementation_a, "aicms".to_string());
+        assert_eq!(assignment.seed, Some(7));
+
+        let score = scorer.parse_response(&response, false).unwrap();
+        assert_eq!((score.aicms.overall, score.baseline.overall), (60, 90));
+        assert_eq!(score.winner, "baseline");
+
+        let tie = response.replace(r#""winner": "A""#, r#""winner": "tie""#);
+        assert_eq!(scorer.parse_response(&tie, true).unwrap().winner, "tie");
+
+        let missing = response.replace(r#""error_handling""#, r#""annotation_compliance""#);
+        assert!(scorer.parse_response(&missing, true).is_err());
     }
 
     #[test]
-    fn test_build_prompt_contains_paths() {
+    fn test_seed_fixes_blind_assignments() {
+        let draws = |scorer: &ClaudeScorer| -> Vec<bool> {
+            (0..32).map(|_| scorer.draw_aicms_is_a()).collect()
+        };
+        let first = draws(&ClaudeScorer::default().with_seed(42));
+        assert_eq!(first, draws(&ClaudeScorer::default().with_seed(42)));
+        assert_ne!(first, draws(&ClaudeScorer::default().with_seed(43)));
+        // Both orders occur
+        assert!(first.contains(&true) && first.contains(&false));
+    }
+
+    #[test]
+    fn test_parse_attempts_are_recorded() {
+        let aspects = Rubric::default()
+            .aspects
+            .iter()
+            .map(|a| format!(r#""{}": {{"score": 80, "reason": "r",}}"#, a.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let response = format!(
+            "```json\n{{\"implementation_a\": {{{0}}}, \"implementation_b\": {{{0}}}, \
+             \"winner\": \"tie\", \"summary\": \"equal\"}}\n```",
+            aspects
+        );
+
+        let scorer = ClaudeScorer::default().with_parse_retries(0);
+        let parse = |response: &str| scorer.parse_response(response, true);
+        let (score, attempts) = scorer
+            .parse_with_retries(response.clone(), "{}", parse)
+            .unwrap();
+        assert_eq!(score.winner, "tie");
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts[0].repairs, ["code-fence", "trailing-comma"]);
+        assert_eq!(attempts[0].error, None);
+
+        let truncated = response.replace(r#""winner": "tie", "#, "");
+        let error = scorer
+            .parse_with_retries(truncated, "{}", parse)
+            .unwrap_err();
+        assert!(format!("{:#}", error).contains("missing field `winner`"));
+
+        let prompt = build_reprompt("{oops", "EOF while parsing", "{\"winner\": \"<a|b|tie>\"}");
+        assert!(prompt.contains("EOF while parsing") && prompt.ends_with("<a|b|tie>\"}"));
+    }
+
+    #[test]
+    fn test_ranking_is_mapped_back_to_input_order() {
+        let aspects = |score: u8| {
+            [
+                "intent_match",
+                "edge_cases",
+                "code_quality",
+                "error_handling",
+            ]
+            .map(|name| format!(r#""{}": {{"score": {}, "reason": "r"}}"#, name, score))
+            .join(", ")
+        };
+        let response = format!(
+            r#"{{"implementations": {{"1": {{{}}}, "2": {{{}}}, "3": {{{}}}}},
+                "ranking": ["2", "Implementation 3", "1"], "summary": "2 is best"}}"#,
+            aspects(50),
+            aspects(90),
+            aspects(70)
+        );
+
+        // Inputs 0, 1, 2 were shown as implementations 3, 1, 2
         let scorer = ClaudeScorer::default();
-        let baseline = Path::new("/tmp/baseline");
-        let aicms = Path::new("/tmp/aicms");
-        let prompt = scorer.build_prompt("spec", baseline, aicms);
-        assert!(prompt.contains("/tmp/baseline"));
-        assert!(prompt.contains("/tmp/aicms"));
-        assert!(prompt.contains("spec"));
+        let verdict = scorer.parse_ranking(&response, &[1, 2, 0]).unwrap();
+        let ranks: Vec<(u32, u8)> = verdict
+            .implementations
+            .iter()
+            .map(|i| (i.rank, i.score.overall))
+            .collect();
+        assert_eq!(ranks, [(2, 70), (3, 50), (1, 90)]);
+
+        let duplicate = response.replace(r#""Implementation 3""#, r#""2""#);
+        assert!(scorer.parse_ranking(&duplicate, &[1, 2, 0]).is_err());
+
+        let prompt = scorer.rubric.render_ranking(&scorer.ranking_template, 3);
+        assert!(prompt.contains(r#""3": {"#) && prompt.contains(r#""ranking": ["#));
+
+        let shuffled = shuffled_order(&mut SplitMix64::new(42), 5);
+        assert_eq!(shuffled, shuffled_order(&mut SplitMix64::new(42), 5));
+        let mut order = shuffled;
+        order.sort();
+        assert_eq!(order, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_saved_scores_keep_aspects_inline() {
+        let saved = r#"{"overall": 75, "intent_match": {"score": 80, "reason": "ok"},
+            "annotation_compliance": {"score": 70, "reason": "errors handled"}}"#;
+        let score: ImplementationScore = serde_json::from_str(saved).unwrap();
+        assert_eq!(score.overall, 75);
+        assert_eq!(score.aspects["annotation_compliance"].score, 70);
+
+        let json = serde_json::to_value(&score).unwrap();
+        assert_eq!(json["intent_match"]["score"], 80);
+    }
+
+    #[test]
+    fn test_combine_verdicts_uses_mean_and_majority() {
+        let pass = |aicms: u8, winner: &str| {
+            let MockClaudeScorer { mut score } = MockClaudeScorer::with_defaults();
+            score.aicms.overall = aicms;
+            score.aicms.aspects.get_mut("edge_cases").unwrap().score = aicms;
+            score.winner = winner.to_string();
+            score.summary = format!("{} wins", winner);
+            score
+        };
+
+        let combined =
+            combine_verdicts(vec![pass(80, "aicms"), pass(70, "tie"), pass(91, "aicms")]);
+        assert_eq!(combined.winner, "aicms");
+        assert_eq!(combined.aicms.overall, 80);
+        assert_eq!(combined.aicms.aspects["edge_cases"].score, 80);
+        assert_eq!(combined.baseline.overall, 70);
+        assert_eq!(combined.summary, "aicms wins");
+        assert_eq!(combined.passes.len(), 3);
+
+        let split = combine_verdicts(vec![pass(80, "aicms"), pass(60, "baseline")]);
+        assert_eq!(split.winner, "tie");
+
+        let single = combine_verdicts(vec![pass(80, "baseline")]);
+        assert_eq!(single.winner, "baseline");
+        assert!(single.passes.is_empty());
+    }
+
+    #[test]
+    fn test_judge_panel_rotates_models() {
+        let config = CompareConfig {
+            judge_passes: 3,
+            judge_models: vec!["opus".to_string(), "sonnet".to_string()],
+            ..Default::default()
+        };
+        let rubric = Rubric::default();
+        let api = ApiConfig::default();
+        let cli_panel = |config: &CompareConfig| {
+            JudgePanel::from_config("", "", &rubric, config, JudgeBackend::ClaudeCode, &api)
+                .unwrap()
+        };
+        let panel = cli_panel(&config);
+        let models: Vec<_> = panel.judges.iter().map(|j| j.model.as_deref()).collect();
+        assert_eq!(models, [Some("opus"), Some("sonnet"), Some("opus")]);
+
+        let panel = cli_panel(&CompareConfig::default());
+        assert_eq!(panel.judges.len(), 1);
+        assert_eq!(panel.judges[0].model, None);
+    }
+
+    #[test]
+    fn test_copy_for_review_hides_mode_files() {
+        let source = TempDir::new().unwrap();
+        std::fs::create_dir_all(source.path().join("src")).unwrap();
+        std::fs::create_dir_all(source.path().join("target/debug")).unwrap();
+        std::fs::write(source.path().join("src/lib.rs"), "fn f() {}").unwrap();
+        std::fs::write(source.path().join("target/debug/out"), "").unwrap();
+        std::fs::write(source.path().join("CLAUDE.md"), "@skill").unwrap();
+        std::fs::write(source.path().join(MANIFEST_FILE), "{}").unwrap();
+
+        let dest = TempDir::new().unwrap();
+        let copy = dest.path().join("implementation-a");
+        copy_for_review(source.path(), &copy).unwrap();
+
+        assert!(copy.join("src/lib.rs").exists());
+        assert!(!copy.join("target").exists());
+        assert!(!copy.join("CLAUDE.md").exists());
+        assert!(!copy.join(MANIFEST_FILE).exists());
+    }
+
+    #[test]
+    fn test_session_reuse_limit() {
+        let scorer = ClaudeScorer::default();
+        scorer.record_session(Some("s1".to_string()), false);
+        assert_eq!(scorer.resumable_session(), None);
+
+        let scorer = ClaudeScorer::default().with_session_reuse(2);
+        assert_eq!(scorer.resumable_session(), None);
+        scorer.record_session(Some("s1".to_string()), false);
+        assert_eq!(scorer.resumable_session().as_deref(), Some("s1"));
+        scorer.record_session(Some("s1".to_string()), true);
+        assert_eq!(scorer.resumable_session(), None);
+
+        let prompt =
+            ClaudeScorer::build_followup_prompt("spec", Path::new("/tmp/b"), Path::new("/tmp/a"));
+        assert!(prompt.contains("/tmp/b") && prompt.contains("/tmp/a"));
     }
 
     #[test]
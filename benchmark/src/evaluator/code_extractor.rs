@@ -5,6 +5,7 @@
 
 use crate::corpus::Language;
 use crate::evaluator::SourceFile;
+use crate::platform::normalize_separators;
 use regex::Regex;
 
 /// @ai:intent Extracted code from a response
@@ -63,6 +64,8 @@ impl CodeExtractor {
             "rust" | "rs" => Some(Language::Rust),
             "python" | "py" => Some(Language::Python),
             "typescript" | "ts" | "javascript" | "js" => Some(Language::TypeScript),
+            "go" | "golang" => Some(Language::Go),
+            "java" => Some(Language::Java),
             _ => None,
         }
     }
@@ -75,7 +78,7 @@ impl CodeExtractor {
         self.file_marker_regex
             .captures(first_line)
             .and_then(|cap| cap.get(1))
-            .map(|m| m.as_str().trim().to_string())
+            .map(|m| normalize_separators(m.as_str().trim()))
     }
 
     /// @ai:intent Remove file marker comment from code
@@ -156,7 +159,7 @@ impl CodeExtractorTrait for CodeExtractor {
 
             if language.is_none() || language == Some(expected_lang) {
                 files.push(ExtractedFile {
-                    path: path.to_string(),
+                    path: normalize_separators(path),
                     code: code.to_string(),
                     language,
                 });
@@ -300,7 +303,7 @@ fn main() {
 ```
 
 ```rust
-// file: src/lib.rs
+// file: src\lib.rs
 pub fn hello() {}
 ```
 "#;
@@ -308,6 +311,7 @@ pub fn hello() {}
         let files = extractor.extract_files(response, Language::Rust);
         assert_eq!(files.len(), 2);
         assert_eq!(files[0].path, "src/main.rs");
+        // Windows-style separators are normalized
         assert_eq!(files[1].path, "src/lib.rs");
         // Marker comment should be stripped
         assert!(!files[0].code.contains("// file:"));
@@ -0,0 +1,292 @@
+//! @ai:module:intent Idiomatic-lint scoring of generated projects with clippy, ruff, eslint,
+//!                   go vet and javac -Xlint
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api CodeQualityChecker, CodeQualityCheckerTrait, CodeQuality
+//! @ai:module:depends_on evaluator::sandbox, evaluator::test_runner
+//! @ai:module:stateless true
+
+use crate::corpus::Language;
+use crate::evaluator::sandbox::Sandbox;
+use crate::evaluator::test_runner::{java_sources, write_project};
+use crate::evaluator::SourceFile;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+use tempfile::TempDir;
+
+/// ESLint flat config for TypeScript (needs eslint and typescript-eslint installed)
+const ESLINT_CONFIG: &str = r#"import tseslint from "typescript-eslint";
+
+export default tseslint.config(...tseslint.configs.recommended);
+"#;
+
+/// @ai:intent Warnings reported by the language's idiomatic linter for one execution
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CodeQuality {
+    /// Linter that produced the warnings (clippy, ruff, eslint, go vet, javac -Xlint)
+    pub tool: String,
+    pub warnings: u32,
+    /// Non-blank lines in the linted source files
+    pub lines: u32,
+    /// Warning count per rule (e.g. "clippy::needless_return", "F401")
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, u32>,
+}
+
+impl CodeQuality {
+    /// @ai:intent Warnings per 100 non-blank source lines
+    /// @ai:effects pure
+    pub fn density(&self) -> f64 {
+        if self.lines == 0 {
+            0.0
+        } else {
+            self.warnings as f64 / self.lines as f64 * 100.0
+        }
+    }
+}
+
+/// @ai:intent Trait for idiomatic-lint checking
+pub trait CodeQualityCheckerTrait: Send + Sync {
+    /// @ai:intent Lint a generated project with the language's idiomatic linter
+    fn check(&self, files: &[SourceFile], language: Language) -> Result<CodeQuality>;
+}
+
+/// @ai:intent Runs clippy (Rust), ruff (Python), eslint (TypeScript), go vet (Go) or
+///            javac -Xlint (Java) on a project copy
+pub struct CodeQualityChecker {
+    sandbox: Sandbox,
+}
+
+impl CodeQualityChecker {
+    /// @ai:intent Create a checker that runs linters on the host
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            sandbox: Sandbox::default(),
+        }
+    }
+
+    /// @ai:intent Run the linters in the given sandbox
+    /// @ai:effects pure
+    pub fn with_sandbox(mut self, sandbox: Sandbox) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
+    /// @ai:intent Run cargo clippy and count warnings by lint name
+    /// @ai:effects io
+    fn clippy(&self, root: &Path) -> Result<BTreeMap<String, u32>> {
+        let output = self
+            .sandbox
+            .command("cargo", root, Language::Rust)
+            .args(["clippy", "--all-targets", "--message-format=json"])
+            .output()
+            .map_err(|e| anyhow::anyhow!("clippy not available: {}", e))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        if stdout.trim().is_empty() {
+            bail!("clippy failed: {}", String::from_utf8_lossy(&output.stderr));
+        }
+
+        // The lib is also built as a test target, reporting its warnings twice
+        let mut seen = HashSet::new();
+        let mut rules = BTreeMap::new();
+        for line in stdout.lines() {
+            let Ok(message) = serde_json::from_str::<serde_json::Value>(line) else {
+                continue;
+            };
+            if message["reason"] != "compiler-message" {
+                continue;
+            }
+            let message = &message["message"];
+            // Summary lines ("N warnings emitted") have no code
+            if message["level"] != "warning" {
+                continue;
+            }
+            if !seen.insert(message["rendered"].to_string()) {
+                continue;
+            }
+            if let Some(code) = message["code"]["code"].as_str() {
+                *rules.entry(code.to_string()).or_default() += 1;
+            }
+        }
+        Ok(rules)
+    }
+
+    /// @ai:intent Run ruff and count violations by rule code
+    /// @ai:effects io
+    fn ruff(&self, root: &Path) -> Result<BTreeMap<String, u32>> {
+        let output = self
+            .sandbox
+            .command("ruff", root, Language::Python)
+            .args(["check", "--output-format=json", "--exit-zero", "."])
+            .output()
+            .map_err(|e| anyhow::anyhow!("ruff not available: {}", e))?;
+
+        let diagnostics: Vec<serde_json::Value> =
+            serde_json::from_slice(&output.stdout).map_err(|_| {
+                anyhow::anyhow!("ruff failed: {}", String::from_utf8_lossy(&output.stderr))
+            })?;
+
+        let mut rules = BTreeMap::new();
+        for diagnostic in diagnostics {
+            let code = diagnostic["code"].as_str().unwrap_or("syntax-error");
+            *rules.entry(code.to_string()).or_default() += 1;
+        }
+        Ok(rules)
+    }
+
+    /// @ai:intent Run eslint with the typescript-eslint recommended rules, counted by rule id
+    /// @ai:effects fs:write, io
+    fn eslint(&self, root: &Path) -> Result<BTreeMap<String, u32>> {
+        std::fs::write(root.join("eslint.config.mjs"), ESLINT_CONFIG)?;
+
+        let output = self
+            .sandbox
+            .command("npx", root, Language::TypeScript)
+            .args(["--no-install", "eslint", "--format", "json", "."])
+            .output()
+            .map_err(|e| anyhow::anyhow!("eslint not available: {}", e))?;
+
+        let results: Vec<serde_json::Value> =
+            serde_json::from_slice(&output.stdout).map_err(|_| {
+                anyhow::anyhow!("eslint failed: {}", String::from_utf8_lossy(&output.stderr))
+            })?;
+
+        let mut rules = BTreeMap::new();
+        for message in results
+            .iter()
+            .filter_map(|file| file["messages"].as_array())
+            .flatten()
+        {
+            let rule = message["ruleId"].as_str().unwrap_or("parse-error");
+            *rules.entry(rule.to_string()).or_default() += 1;
+        }
+        Ok(rules)
+    }
+
+    /// @ai:intent Run go vet and count findings by analyzer
+    /// @ai:effects io
+    fn go_vet(&self, root: &Path) -> Result<BTreeMap<String, u32>> {
+        let output = self
+            .sandbox
+            .command("go", root, Language::Go)
+            .args(["vet", "-json", "./..."])
+            .output()
+            .map_err(|e| anyhow::anyhow!("go not available: {}", e))?;
+
+        // One "# package" line and one {package: {analyzer: [finding]}} object per package
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let json: String = stderr
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let packages = serde_json::Deserializer::from_str(&json)
+            .into_iter::<BTreeMap<String, BTreeMap<String, serde_json::Value>>>()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| anyhow::anyhow!("go vet failed: {}", stderr))?;
+
+        let mut rules = BTreeMap::new();
+        for (analyzer, findings) in packages.iter().flat_map(|p| p.values()).flatten() {
+            let count = findings.as_array().map_or(0, Vec::len) as u32;
+            *rules.entry(analyzer.clone()).or_default() += count;
+        }
+        Ok(rules)
+    }
+
+    /// @ai:intent Compile the non-test Java sources with every javac lint enabled and count
+    ///            warnings by lint category ("javac" for warnings without one)
+    /// @ai:effects io
+    fn javac_lint(&self, root: &Path) -> Result<BTreeMap<String, u32>> {
+        let output = self
+            .sandbox
+            .command("javac", root, Language::Java)
+            .args(["-Xlint:all", "-Xmaxwarns", "10000", "-encoding", "UTF-8"])
+            .args(["-d", "build/lint"])
+            .args(java_sources(root, false))
+            .output()
+            .map_err(|e| anyhow::anyhow!("javac not available: {}", e))?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !output.status.success() {
+            bail!("javac failed: {}", stderr);
+        }
+
+        // File.java:12: warning: [rawtypes] found raw type: List
+        let mut rules = BTreeMap::new();
+        for (_, message) in stderr
+            .lines()
+            .filter_map(|line| line.split_once(": warning: "))
+        {
+            let rule = message
+                .strip_prefix('[')
+                .and_then(|rest| rest.split_once(']'))
+                .map_or("javac", |(rule, _)| rule);
+            *rules.entry(rule.to_string()).or_default() += 1;
+        }
+        Ok(rules)
+    }
+}
+
+impl Default for CodeQualityChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CodeQualityCheckerTrait for CodeQualityChecker {
+    /// @ai:intent Lint a generated project with the language's idiomatic linter
+    ///            Fails if the linter is not installed, so a missing tool is not scored as 0
+    /// @ai:effects fs:write, io
+    fn check(&self, files: &[SourceFile], language: Language) -> Result<CodeQuality> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        write_project(root, files, language)?;
+
+        let (tool, rules) = match language {
+            Language::Rust => ("clippy", self.clippy(root)?),
+            Language::Python => ("ruff", self.ruff(root)?),
+            Language::TypeScript => ("eslint", self.eslint(root)?),
+            Language::Go => ("go vet", self.go_vet(root)?),
+            Language::Java => ("javac -Xlint", self.javac_lint(root)?),
+        };
+
+        let lines = files
+            .iter()
+            .filter(|f| f.path.ends_with(&format!(".{}", language.extension())))
+            .flat_map(|f| f.content.lines())
+            .filter(|line| !line.trim().is_empty())
+            .count() as u32;
+
+        Ok(CodeQuality {
+            tool: tool.to_string(),
+            warnings: rules.values().sum(),
+            lines,
+            rules,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clippy_counts_warnings_by_lint() {
+        let files = vec![SourceFile {
+            path: "lib.rs".to_string(),
+            content: "pub fn double(x: i32) -> i32 {\n    return x * 2;\n}\n".to_string(),
+        }];
+
+        let quality = CodeQualityChecker::new()
+            .check(&files, Language::Rust)
+            .unwrap();
+
+        assert_eq!(quality.tool, "clippy");
+        assert_eq!(quality.rules.get("clippy::needless_return"), Some(&1));
+        assert_eq!(quality.lines, 3);
+        assert!((quality.density() - quality.warnings as f64 / 3.0 * 100.0).abs() < 0.01);
+    }
+}
@@ -0,0 +1,150 @@
+//! @ai:module:intent Size of generated solutions: file count and lines of code split by tests
+//! @ai:module:layer application
+//! @ai:module:public_api CodeSize
+//! @ai:module:depends_on evaluator::reference
+//! @ai:module:stateless true
+
+use crate::corpus::Language;
+use crate::evaluator::reference::is_test_file;
+use crate::evaluator::SourceFile;
+use serde::{Deserialize, Serialize};
+
+/// @ai:intent Size of the files generated by one execution
+///            Lines are non-blank lines of the task language's source files
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CodeSize {
+    /// Generated files of any kind (including manifests)
+    pub files: u32,
+    pub lines: u32,
+    /// Lines in test files and inline test modules (`#[cfg(test)]`)
+    pub test_lines: u32,
+}
+
+impl CodeSize {
+    /// @ai:intent Measure the generated files
+    /// @ai:post result.test_lines <= result.lines
+    /// @ai:effects pure
+    pub fn measure(files: &[SourceFile], language: Language) -> Self {
+        let extension = format!(".{}", language.extension());
+        let mut size = Self {
+            files: files.len() as u32,
+            ..Default::default()
+        };
+
+        for file in files.iter().filter(|f| f.path.ends_with(&extension)) {
+            let lines = non_blank_lines(&file.content);
+            size.lines += lines;
+            size.test_lines += if is_test_file(&file.path, language) {
+                lines
+            } else if language == Language::Rust {
+                inline_test_lines(&file.content)
+            } else {
+                0
+            };
+        }
+
+        size
+    }
+
+    /// @ai:intent Lines outside tests
+    /// @ai:effects pure
+    pub fn source_lines(&self) -> u32 {
+        self.lines - self.test_lines
+    }
+}
+
+/// @ai:intent Count lines with non-whitespace content
+/// @ai:effects pure
+fn non_blank_lines(content: &str) -> u32 {
+    content.lines().filter(|l| !l.trim().is_empty()).count() as u32
+}
+
+/// @ai:intent Non-blank lines of `#[cfg(test)]` items
+/// @ai:effects pure
+fn inline_test_lines(content: &str) -> u32 {
+    inline_test_code(content).len() as u32
+}
+
+/// @ai:intent Test code of the generated files: test files, plus inline `#[cfg(test)]`
+///            items of Rust sources
+/// @ai:effects pure
+pub(crate) fn test_code(files: &[SourceFile], language: Language) -> String {
+    let extension = format!(".{}", language.extension());
+    let mut code = Vec::new();
+
+    for file in files.iter().filter(|f| f.path.ends_with(&extension)) {
+        if is_test_file(&file.path, language) {
+            code.push(file.content.clone());
+        } else if language == Language::Rust {
+            code.extend(inline_test_code(&file.content).into_iter().map(str::to_string));
+        }
+    }
+    code.join("\n")
+}
+
+/// @ai:intent Non-blank lines of `#[cfg(test)]` items, from the attribute to the closing brace
+/// @ai:effects pure
+fn inline_test_code(content: &str) -> Vec<&str> {
+    let mut lines = Vec::new();
+    let mut in_test = false;
+    let mut depth = 0i32;
+    let mut opened = false;
+
+    for line in content.lines() {
+        if !in_test && line.trim_start().starts_with("#[cfg(test)]") {
+            in_test = true;
+            depth = 0;
+            opened = false;
+        }
+        if !in_test {
+            continue;
+        }
+        if !line.trim().is_empty() {
+            lines.push(line);
+        }
+        for c in line.chars() {
+            match c {
+                '{' => {
+                    depth += 1;
+                    opened = true;
+                }
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+        // Item ends when its block closes, or at `;` for block-less items (`mod tests;`)
+        if (opened && depth <= 0) || (!opened && line.trim_end().ends_with(';')) {
+            in_test = false;
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure_splits_test_lines() {
+        let files = vec![
+            SourceFile {
+                path: "Cargo.toml".to_string(),
+                content: "[package]\nname = \"x\"\n".to_string(),
+            },
+            SourceFile {
+                path: "src/lib.rs".to_string(),
+                content: "pub fn one() -> u32 {\n    1\n}\n\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn works() {\n        assert_eq!(super::one(), 1);\n    }\n}\n".to_string(),
+            },
+            SourceFile {
+                path: "tests/api.rs".to_string(),
+                content: "#[test]\nfn api() {}\n".to_string(),
+            },
+        ];
+
+        let size = CodeSize::measure(&files, Language::Rust);
+        assert_eq!(size.files, 3);
+        assert_eq!(size.lines, 12);
+        assert_eq!(size.test_lines, 9);
+        assert_eq!(size.source_lines(), 3);
+    }
+}
@@ -1,13 +1,17 @@
 //! @ai:module:intent Compilation checking for generated code
 //! @ai:module:layer infrastructure
-//! @ai:module:public_api CompilationChecker, CompilationResult
+//! @ai:module:public_api CompilationChecker, CompilationResult, Diagnostic
 //! @ai:module:stateless true
 
 use crate::corpus::Language;
+use crate::evaluator::sandbox::Sandbox;
+use crate::evaluator::test_runner::{
+    java_file_name, java_sources, write_project, GO_MOD, JAVA_CLASSES,
+};
 use crate::evaluator::SourceFile;
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 use std::io::Write;
-use std::process::Command;
 use tempfile::TempDir;
 
 /// @ai:intent Result of compilation check
@@ -16,6 +20,47 @@ pub struct CompilationResult {
     pub success: bool,
     pub errors: Vec<String>,
     pub warnings: Vec<String>,
+    /// Structured errors and warnings (Rust only; other compilers report plain lines)
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// @ai:intent One error or warning from rustc's JSON diagnostics
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    /// "error" or "warning"
+    pub level: String,
+    /// Error code or lint name (e.g. "E0308", "unused_variables"); syntax errors have none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    pub message: String,
+    /// Position of the primary span (1-based line and column)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<u32>,
+    /// The message as the compiler prints it, with source excerpt, notes and help
+    pub rendered: String,
+}
+
+impl Diagnostic {
+    /// @ai:intent One-line form, as printed by --message-format=short
+    /// @ai:effects pure
+    /// @ai:example (E0308 at src/lib.rs:3:5) -> "src/lib.rs:3:5: error[E0308]: mismatched types"
+    pub fn short(&self) -> String {
+        let position = match (&self.file, self.line, self.column) {
+            (Some(file), Some(line), Some(column)) => format!("{}:{}:{}: ", file, line, column),
+            (Some(file), _, _) => format!("{}: ", file),
+            _ => String::new(),
+        };
+        let code = self
+            .code
+            .as_ref()
+            .map(|code| format!("[{}]", code))
+            .unwrap_or_default();
+        format!("{}{}{}: {}", position, self.level, code, self.message)
+    }
 }
 
 /// @ai:intent Trait for compilation checking
@@ -32,13 +77,24 @@ pub trait CompilationCheckerTrait: Send + Sync {
 }
 
 /// @ai:intent Checks if generated code compiles
-pub struct CompilationChecker;
+pub struct CompilationChecker {
+    sandbox: Sandbox,
+}
 
 impl CompilationChecker {
-    /// @ai:intent Create a new compilation checker
+    /// @ai:intent Create a new compilation checker (runs compilers on the host)
     /// @ai:effects pure
     pub fn new() -> Self {
-        Self
+        Self {
+            sandbox: Sandbox::default(),
+        }
+    }
+
+    /// @ai:intent Run compilers in the given sandbox
+    /// @ai:effects pure
+    pub fn with_sandbox(mut self, sandbox: Sandbox) -> Self {
+        self.sandbox = sandbox;
+        self
     }
 
     /// @ai:intent Check Rust code compilation
@@ -51,23 +107,24 @@ impl CompilationChecker {
         file.write_all(code.as_bytes())?;
         drop(file);
 
-        let output = Command::new("rustc")
+        let output = self
+            .sandbox
+            .command("rustc", temp_dir.path(), Language::Rust)
             .arg("--emit=metadata")
             .arg("--edition=2021")
+            .arg("--error-format=json")
             .arg("-o")
-            .arg(temp_dir.path().join("out"))
-            .arg(&src_path)
+            .arg("out")
+            .arg("main.rs")
             .output()?;
 
+        // rustc writes its JSON diagnostics to stderr
         let stderr = String::from_utf8_lossy(&output.stderr);
-        let errors = extract_rust_messages(&stderr, "error");
-        let warnings = extract_rust_messages(&stderr, "warning");
-
-        Ok(CompilationResult {
-            success: output.status.success(),
-            errors,
-            warnings,
-        })
+        Ok(rust_compilation_result(
+            output.status.success(),
+            &stderr,
+            &stderr,
+        ))
     }
 
     /// @ai:intent Check Python code compilation
@@ -80,10 +137,12 @@ impl CompilationChecker {
         file.write_all(code.as_bytes())?;
         drop(file);
 
-        let output = Command::new("python")
+        let output = self
+            .sandbox
+            .command("python", temp_dir.path(), Language::Python)
             .arg("-m")
             .arg("py_compile")
-            .arg(&src_path)
+            .arg("main.py")
             .output()?;
 
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -97,6 +156,7 @@ impl CompilationChecker {
             success: output.status.success(),
             errors,
             warnings: vec![],
+            diagnostics: vec![],
         })
     }
 
@@ -110,10 +170,12 @@ impl CompilationChecker {
         file.write_all(code.as_bytes())?;
         drop(file);
 
-        let output = Command::new("tsc")
+        let output = self
+            .sandbox
+            .command("tsc", temp_dir.path(), Language::TypeScript)
             .arg("--noEmit")
             .arg("--strict")
-            .arg(&src_path)
+            .arg("main.ts")
             .output()?;
 
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -130,9 +192,30 @@ impl CompilationChecker {
             success: output.status.success(),
             errors,
             warnings: vec![],
+            diagnostics: vec![],
         })
     }
 
+    /// @ai:intent Check Go code compilation
+    /// @ai:effects fs:write, io
+    fn check_go(&self, code: &str) -> Result<CompilationResult> {
+        let temp_dir = TempDir::new()?;
+        std::fs::write(temp_dir.path().join("main.go"), code)?;
+        std::fs::write(temp_dir.path().join("go.mod"), GO_MOD)?;
+
+        self.check_go_directory(temp_dir.path())
+    }
+
+    /// @ai:intent Check Java code compilation (file named after the public class)
+    /// @ai:effects fs:write, io
+    fn check_java(&self, code: &str) -> Result<CompilationResult> {
+        let temp_dir = TempDir::new()?;
+        let file_name = java_file_name(code).unwrap_or_else(|| "Main.java".to_string());
+        std::fs::write(temp_dir.path().join(file_name), code)?;
+
+        self.check_java_directory(temp_dir.path())
+    }
+
     /// @ai:intent Check multi-file Rust project compilation using Cargo
     /// @ai:effects fs:write, io
     fn check_rust_files(&self, files: &[SourceFile]) -> Result<CompilationResult> {
@@ -181,10 +264,11 @@ edition = "2021"
         std::fs::create_dir_all(&src_dir)?;
 
         // Run cargo check
-        let output = Command::new("cargo")
+        let output = self
+            .sandbox
+            .command("cargo", temp_dir.path(), Language::Rust)
             .arg("check")
-            .arg("--message-format=short")
-            .current_dir(temp_dir.path())
+            .arg("--message-format=json")
             .output()?;
 
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -193,14 +277,11 @@ edition = "2021"
         tracing::debug!("Cargo check stdout: {}", stdout);
         tracing::debug!("Cargo check stderr: {}", stderr);
 
-        let errors = extract_rust_messages(&stderr, "error");
-        let warnings = extract_rust_messages(&stderr, "warning");
-
-        Ok(CompilationResult {
-            success: output.status.success(),
-            errors,
-            warnings,
-        })
+        Ok(rust_compilation_result(
+            output.status.success(),
+            &stdout,
+            &stderr,
+        ))
     }
 
     /// @ai:intent Check multi-file Python project compilation
@@ -228,12 +309,12 @@ edition = "2021"
 
         // Check each Python file
         for source_file in files {
-            let file_path = temp_dir.path().join(&source_file.path);
-
-            let output = Command::new("python")
+            let output = self
+                .sandbox
+                .command("python", temp_dir.path(), Language::Python)
                 .arg("-m")
                 .arg("py_compile")
-                .arg(&file_path)
+                .arg(&source_file.path)
                 .output()?;
 
             if !output.status.success() {
@@ -246,6 +327,7 @@ edition = "2021"
             success: all_errors.is_empty(),
             errors: all_errors,
             warnings: vec![],
+            diagnostics: vec![],
         })
     }
 
@@ -276,10 +358,14 @@ edition = "2021"
   "include": ["**/*.ts"]
 }"#;
         std::fs::write(temp_dir.path().join("tsconfig.json"), tsconfig)?;
+        // Type declarations of the dependencies
+        self.sandbox
+            .install_dependencies(temp_dir.path(), Language::TypeScript)?;
 
-        let output = Command::new("tsc")
+        let output = self
+            .sandbox
+            .command("tsc", temp_dir.path(), Language::TypeScript)
             .arg("--noEmit")
-            .current_dir(temp_dir.path())
             .output()?;
 
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -296,28 +382,46 @@ edition = "2021"
             success: output.status.success(),
             errors,
             warnings: vec![],
+            diagnostics: vec![],
         })
     }
 
+    /// @ai:intent Check multi-file Go project compilation (go.mod added if missing)
+    /// @ai:effects fs:write, io
+    fn check_go_files(&self, files: &[SourceFile]) -> Result<CompilationResult> {
+        let temp_dir = TempDir::new()?;
+        write_project(temp_dir.path(), files, Language::Go)?;
+
+        self.check_go_directory(temp_dir.path())
+    }
+
+    /// @ai:intent Check multi-file Java project compilation
+    /// @ai:effects fs:write, io
+    fn check_java_files(&self, files: &[SourceFile]) -> Result<CompilationResult> {
+        let temp_dir = TempDir::new()?;
+        write_project(temp_dir.path(), files, Language::Java)?;
+
+        self.check_java_directory(temp_dir.path())
+    }
+
     /// @ai:intent Check Rust code compilation in an existing directory
     /// @ai:effects io
     fn check_rust_directory(&self, dir: &std::path::Path) -> Result<CompilationResult> {
         // Run cargo check in the directory
-        let output = Command::new("cargo")
+        let output = self
+            .sandbox
+            .command("cargo", dir, Language::Rust)
             .arg("check")
-            .arg("--message-format=short")
-            .current_dir(dir)
+            .arg("--message-format=json")
             .output()?;
 
+        let stdout = String::from_utf8_lossy(&output.stdout);
         let stderr = String::from_utf8_lossy(&output.stderr);
-        let errors = extract_rust_messages(&stderr, "error");
-        let warnings = extract_rust_messages(&stderr, "warning");
-
-        Ok(CompilationResult {
-            success: output.status.success(),
-            errors,
-            warnings,
-        })
+        Ok(rust_compilation_result(
+            output.status.success(),
+            &stdout,
+            &stderr,
+        ))
     }
 
     /// @ai:intent Check Python code compilation in an existing directory
@@ -326,22 +430,24 @@ edition = "2021"
         let mut all_errors = Vec::new();
 
         // Find and check all Python files
-        check_python_files_recursive(dir, dir, &mut all_errors)?;
+        check_python_files_recursive(&self.sandbox, dir, dir, &mut all_errors)?;
 
         Ok(CompilationResult {
             success: all_errors.is_empty(),
             errors: all_errors,
             warnings: vec![],
+            diagnostics: vec![],
         })
     }
 
     /// @ai:intent Check TypeScript code compilation in an existing directory
     /// @ai:effects io
     fn check_typescript_directory(&self, dir: &std::path::Path) -> Result<CompilationResult> {
-        let output = Command::new("tsc")
+        let output = self
+            .sandbox
+            .command("tsc", dir, Language::TypeScript)
             .arg("--noEmit")
             .arg("--strict")
-            .current_dir(dir)
             .output()?;
 
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -358,6 +464,89 @@ edition = "2021"
             success: output.status.success(),
             errors,
             warnings: vec![],
+            diagnostics: vec![],
+        })
+    }
+
+    /// @ai:intent Check Go code compilation in an existing module directory
+    ///            go build finds the errors; go vet (which also type-checks the tests)
+    ///            reports its findings as warnings
+    /// @ai:effects io
+    fn check_go_directory(&self, dir: &std::path::Path) -> Result<CompilationResult> {
+        let build = self
+            .sandbox
+            .command("go", dir, Language::Go)
+            .args(["build", "./..."])
+            .output()?;
+
+        if !build.status.success() {
+            let stderr = String::from_utf8_lossy(&build.stderr);
+            let mut errors = extract_go_messages(&stderr);
+            // Failures without a source position (e.g. a broken go.mod) are kept whole
+            if errors.is_empty() {
+                errors.push(stderr.trim().to_string());
+            }
+            return Ok(CompilationResult {
+                success: false,
+                errors,
+                warnings: vec![],
+                diagnostics: vec![],
+            });
+        }
+
+        let vet = self
+            .sandbox
+            .command("go", dir, Language::Go)
+            .args(["vet", "./..."])
+            .output()?;
+
+        Ok(CompilationResult {
+            success: true,
+            errors: vec![],
+            warnings: extract_go_messages(&String::from_utf8_lossy(&vet.stderr)),
+            diagnostics: vec![],
+        })
+    }
+
+    /// @ai:intent Check Java code compilation in an existing directory
+    ///            A Gradle wrapper builds the main classes; otherwise javac compiles the
+    ///            non-test sources, like cargo check (tests need JUnit on the class path)
+    /// @ai:effects io
+    fn check_java_directory(&self, dir: &std::path::Path) -> Result<CompilationResult> {
+        let output = if dir.join("gradlew").exists() {
+            self.sandbox
+                .command("./gradlew", dir, Language::Java)
+                .args(["classes", "--quiet", "--console=plain"])
+                .output()?
+        } else {
+            let sources = java_sources(dir, false);
+            if sources.is_empty() {
+                return Ok(CompilationResult {
+                    success: false,
+                    errors: vec!["No Java source files found".to_string()],
+                    warnings: vec![],
+                    diagnostics: vec![],
+                });
+            }
+            self.sandbox
+                .command("javac", dir, Language::Java)
+                .args(["-d", JAVA_CLASSES, "-encoding", "UTF-8"])
+                .args(sources)
+                .output()?
+        };
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let mut errors = extract_javac_messages(&stderr, "error");
+        // Failures without a source position (e.g. a broken build script) are kept whole
+        if !output.status.success() && errors.is_empty() {
+            errors.push(stderr.trim().to_string());
+        }
+
+        Ok(CompilationResult {
+            success: output.status.success(),
+            errors,
+            warnings: extract_javac_messages(&stderr, "warning"),
+            diagnostics: vec![],
         })
     }
 }
@@ -365,6 +554,7 @@ edition = "2021"
 /// @ai:intent Recursively check Python files in a directory
 /// @ai:effects io
 fn check_python_files_recursive(
+    sandbox: &Sandbox,
     base: &std::path::Path,
     current: &std::path::Path,
     errors: &mut Vec<String>,
@@ -377,18 +567,19 @@ fn check_python_files_recursive(
             let name = path.file_name().unwrap_or_default().to_string_lossy();
 
             if !name.starts_with('.') && name != "__pycache__" && name != "venv" {
-                check_python_files_recursive(base, &path, errors)?;
+                check_python_files_recursive(sandbox, base, &path, errors)?;
             }
-        } else if path.extension().map_or(false, |e| e == "py") {
-            let output = Command::new("python")
+        } else if path.extension().is_some_and(|e| e == "py") {
+            let relative = path.strip_prefix(base).unwrap_or(&path);
+            let output = sandbox
+                .command("python", base, Language::Python)
                 .arg("-m")
                 .arg("py_compile")
-                .arg(&path)
+                .arg(relative)
                 .output()?;
 
             if !output.status.success() {
                 let stderr = String::from_utf8_lossy(&output.stderr);
-                let relative = path.strip_prefix(base).unwrap_or(&path);
                 errors.push(format!("{}: {}", relative.display(), stderr.trim()));
             }
         }
@@ -426,6 +617,20 @@ fn detect_language_from_directory(dir: &std::path::Path) -> Option<Language> {
         return Some(Language::TypeScript);
     }
 
+    // Check for go.mod or .go files
+    if dir.join("go.mod").exists() || has_files_with_extension(dir, "go") {
+        return Some(Language::Go);
+    }
+
+    // Check for a Gradle or Maven build, or .java files
+    if ["build.gradle", "build.gradle.kts", "pom.xml"]
+        .iter()
+        .any(|build| dir.join(build).exists())
+        || has_files_with_extension(dir, "java")
+    {
+        return Some(Language::Java);
+    }
+
     None
 }
 
@@ -445,10 +650,13 @@ fn has_files_with_extension(dir: &std::path::Path, ext: &str) -> bool {
             } else if path.is_dir() {
                 let name = path.file_name().unwrap_or_default().to_string_lossy();
 
-                if !name.starts_with('.') && name != "target" && name != "__pycache__" {
-                    if has_files_with_extension(&path, ext) {
-                        return true;
-                    }
+                if !name.starts_with('.')
+                    && name != "target"
+                    && name != "build"
+                    && name != "__pycache__"
+                    && has_files_with_extension(&path, ext)
+                {
+                    return true;
                 }
             }
         }
@@ -457,13 +665,128 @@ fn has_files_with_extension(dir: &std::path::Path, ext: &str) -> bool {
     false
 }
 
-/// @ai:intent Extract error or warning messages from Rust compiler output
+/// @ai:intent Compilation result from cargo or rustc JSON diagnostics
+///            A failure without error diagnostics (a bad manifest, a dependency that cannot
+///            be resolved) is reported with cargo's `error:` lines from stderr
+/// @ai:effects pure
+fn rust_compilation_result(success: bool, json: &str, stderr: &str) -> CompilationResult {
+    let diagnostics = parse_rust_diagnostics(json);
+    let messages = |level: &str| -> Vec<String> {
+        diagnostics
+            .iter()
+            .filter(|d| d.level == level)
+            .map(Diagnostic::short)
+            .collect()
+    };
+    let mut errors = messages("error");
+    if !success && errors.is_empty() {
+        errors = stderr
+            .lines()
+            .map(str::trim)
+            .filter(|line| line.starts_with("error"))
+            .map(str::to_string)
+            .collect();
+    }
+
+    CompilationResult {
+        success,
+        errors,
+        warnings: messages("warning"),
+        diagnostics,
+    }
+}
+
+/// @ai:intent Errors and warnings in JSON compiler output, one JSON object per line
+///            Accepts cargo's compiler-message records and bare rustc diagnostics; other
+///            lines and the "aborting due to" / "N warnings emitted" summaries are skipped
 /// @ai:effects pure
-fn extract_rust_messages(output: &str, msg_type: &str) -> Vec<String> {
+fn parse_rust_diagnostics(output: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    for line in output.lines().filter(|line| line.starts_with('{')) {
+        let Ok(record) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        let message = if record["reason"] == "compiler-message" {
+            &record["message"]
+        } else if record["$message_type"] == "diagnostic" {
+            &record
+        } else {
+            continue;
+        };
+
+        // Internal compiler errors have the level "error: internal compiler error"
+        let level = match message["level"].as_str() {
+            Some(level) if level.starts_with("error") => "error",
+            Some("warning") => "warning",
+            _ => continue,
+        };
+        let text = message["message"].as_str().unwrap_or_default();
+        let spans = message["spans"].as_array().map_or(&[][..], Vec::as_slice);
+        if spans.is_empty()
+            && (text.starts_with("aborting due to")
+                || text.ends_with("warning emitted")
+                || text.ends_with("warnings emitted"))
+        {
+            continue;
+        }
+
+        let primary = spans
+            .iter()
+            .find(|span| span["is_primary"] == true)
+            .or(spans.first());
+        let position = |key: &str| {
+            primary
+                .and_then(|span| span[key].as_u64())
+                .map(|value| value as u32)
+        };
+        diagnostics.push(Diagnostic {
+            level: level.to_string(),
+            code: message["code"]["code"].as_str().map(str::to_string),
+            message: text.to_string(),
+            file: primary
+                .and_then(|span| span["file_name"].as_str())
+                .map(str::to_string),
+            line: position("line_start"),
+            column: position("column_start"),
+            rendered: message["rendered"]
+                .as_str()
+                .unwrap_or(text)
+                .trim_end()
+                .to_string(),
+        });
+    }
+    diagnostics
+}
+
+/// @ai:intent Extract positioned messages (`file.go:line:col: message`) from go output
+/// @ai:effects pure
+/// @ai:example ("# benchmark\n./main.go:5:2: undefined: x\n") -> ["./main.go:5:2: undefined: x"]
+fn extract_go_messages(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| {
+            line.split_once(".go:")
+                .is_some_and(|(_, position)| position.starts_with(|c: char| c.is_ascii_digit()))
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// @ai:intent Extract positioned javac messages of one kind (`File.java:line: kind: message`)
+/// @ai:effects pure
+/// @ai:example ("Bank.java:3: error: ';' expected\n    int x\n", "error") -> ["Bank.java:3: error: ';' expected"]
+fn extract_javac_messages(output: &str, kind: &str) -> Vec<String> {
+    let marker = format!(": {kind}:");
     output
         .lines()
-        .filter(|line| line.contains(&format!("{msg_type}[")))
-        .map(|line| line.to_string())
+        .map(str::trim)
+        .filter(|line| {
+            line.split_once(".java:").is_some_and(|(_, position)| {
+                position.starts_with(|c: char| c.is_ascii_digit()) && position.contains(&marker)
+            })
+        })
+        .map(str::to_string)
         .collect()
 }
 
@@ -487,6 +810,8 @@ impl CompilationCheckerTrait for CompilationChecker {
             Language::Rust => self.check_rust(code),
             Language::Python => self.check_python(code),
             Language::TypeScript => self.check_typescript(code),
+            Language::Go => self.check_go(code),
+            Language::Java => self.check_java(code),
         }
     }
 
@@ -501,6 +826,8 @@ impl CompilationCheckerTrait for CompilationChecker {
             Language::Rust => self.check_rust_files(files),
             Language::Python => self.check_python_files(files),
             Language::TypeScript => self.check_typescript_files(files),
+            Language::Go => self.check_go_files(files),
+            Language::Java => self.check_java_files(files),
         }
     }
 
@@ -515,6 +842,8 @@ impl CompilationCheckerTrait for CompilationChecker {
             Language::Rust => self.check_rust_directory(dir),
             Language::Python => self.check_python_directory(dir),
             Language::TypeScript => self.check_typescript_directory(dir),
+            Language::Go => self.check_go_directory(dir),
+            Language::Java => self.check_java_directory(dir),
         }
     }
 }
@@ -540,7 +869,68 @@ mod tests {
 
         let result = checker.check(code, Language::Rust).unwrap();
         assert!(!result.success);
-        assert!(!result.errors.is_empty());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.diagnostics[0].code.as_deref(), Some("E0308"));
+        assert_eq!(result.diagnostics[0].line, Some(1));
+        assert!(result.diagnostics[0].rendered.contains("expected `i32`"));
+    }
+
+    #[test]
+    fn test_parse_rust_diagnostics() {
+        let output = concat!(
+            r#"{"reason":"compiler-artifact","package_id":"x"}"#,
+            "\n",
+            r#"{"reason":"compiler-message","message":{"level":"error","code":null,"message":"expected one of `!` or `::`, found `x`","spans":[{"file_name":"src/lib.rs","line_start":3,"column_start":5,"is_primary":true}],"rendered":"error: expected one of `!` or `::`, found `x`\n --> src/lib.rs:3:5\n  |\n3 | pub x\n  |     ^ expected one of `!` or `::`\n\n"}}"#,
+            "\n",
+            r#"{"reason":"compiler-message","message":{"level":"warning","code":{"code":"unused_variables"},"message":"unused variable: `y`","spans":[{"file_name":"src/a.rs","line_start":1,"column_start":9,"is_primary":false},{"file_name":"src/lib.rs","line_start":7,"column_start":9,"is_primary":true}],"rendered":"warning: unused variable: `y`\n"}}"#,
+            "\n",
+            r#"{"reason":"compiler-message","message":{"level":"error","code":null,"message":"aborting due to 1 previous error","spans":[],"rendered":"error: aborting due to 1 previous error\n"}}"#,
+            "\n",
+        );
+
+        let result = rust_compilation_result(false, output, "");
+
+        assert_eq!(result.diagnostics.len(), 2);
+        assert_eq!(
+            result.errors,
+            ["src/lib.rs:3:5: error: expected one of `!` or `::`, found `x`"]
+        );
+        assert!(result.diagnostics[0]
+            .rendered
+            .ends_with("expected one of `!` or `::`"));
+        assert_eq!(
+            result.warnings,
+            ["src/lib.rs:7:9: warning[unused_variables]: unused variable: `y`"]
+        );
+
+        let stderr =
+            "error: failed to parse manifest at `/tmp/x/Cargo.toml`\n\nCaused by:\n  bad\n";
+        assert_eq!(
+            rust_compilation_result(false, "", stderr).errors,
+            ["error: failed to parse manifest at `/tmp/x/Cargo.toml`"]
+        );
+    }
+
+    #[test]
+    fn test_extract_go_messages() {
+        let output = "# benchmark/bank\n./bank.go:5:2: undefined: x\nvet: ./bank.go:9:3: unreachable code\n";
+        assert_eq!(
+            extract_go_messages(output),
+            [
+                "./bank.go:5:2: undefined: x",
+                "vet: ./bank.go:9:3: unreachable code"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_javac_messages() {
+        let output = "src/bank/Bank.java:3: error: ';' expected\n    int x\n         ^\nsrc/bank/Bank.java:7: warning: [removal] Integer(int) in Integer has been deprecated\n1 error\n1 warning\n";
+        assert_eq!(
+            extract_javac_messages(output, "error"),
+            ["src/bank/Bank.java:3: error: ';' expected"]
+        );
+        assert_eq!(extract_javac_messages(output, "warning").len(), 1);
     }
 
     #[test]
@@ -0,0 +1,597 @@
+//! @ai:module:intent Structural complexity of generated code: cyclomatic complexity, nesting
+//!                   depth and maintainability index per function
+//! @ai:module:layer application
+//! @ai:module:public_api ComplexityAnalyzer, ComplexityAnalyzerTrait, Complexity
+//! @ai:module:stateless true
+
+use crate::corpus::Language;
+use crate::evaluator::reference::is_test_file;
+use crate::evaluator::SourceFile;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// @ai:intent Complexity of the functions of one execution (test functions excluded)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Complexity {
+    pub functions: u32,
+    pub avg_cyclomatic: f64,
+    pub max_cyclomatic: u32,
+    /// Deepest block nesting inside a function body, averaged over functions
+    pub avg_nesting_depth: f64,
+    pub max_nesting_depth: u32,
+    /// Maintainability index (0-100, higher is easier to maintain), averaged over functions
+    pub avg_maintainability: f64,
+}
+
+/// @ai:intent Trait for complexity analysis
+pub trait ComplexityAnalyzerTrait: Send + Sync {
+    /// @ai:intent Measure the functions defined in the given files (None if there are none)
+    fn analyze(&self, files: &[SourceFile], language: Language) -> Option<Complexity>;
+}
+
+/// @ai:intent Measurements of a single function
+#[derive(Debug, Clone, PartialEq)]
+struct FunctionComplexity {
+    name: String,
+    cyclomatic: u32,
+    nesting_depth: u32,
+    maintainability: f64,
+}
+
+/// @ai:intent Lexical complexity analyzer
+///            Comments and string literals are blanked out first; Rust, TypeScript, Go and
+///            Java bodies are found by brace matching, Python bodies by indentation
+pub struct ComplexityAnalyzer {
+    rust_fn: Regex,
+    typescript_fn: Regex,
+    go_func: Regex,
+    java_method: Regex,
+    python_def: Regex,
+    token: Regex,
+}
+
+impl ComplexityAnalyzer {
+    /// @ai:intent Create a new complexity analyzer
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            rust_fn: Regex::new(r"\bfn\s+([A-Za-z_]\w*)\s*(?:<[^(]*>)?\s*\(").unwrap(),
+            typescript_fn: Regex::new(
+                r"(?m)(?:\bfunction\s*\*?\s*([A-Za-z_$][\w$]*)|^[ \t]*(?:(?:public|private|protected|static|async|readonly|override|get|set)\s+)*([A-Za-z_$][\w$]*)|\b(?:const|let|var)\s+([A-Za-z_$][\w$]*)\s*=\s*(?:async\s+)?)\s*(?:<[^>()]*>)?\s*\(",
+            )
+            .unwrap(),
+            // Functions and methods (with a receiver), optionally generic
+            go_func: Regex::new(r"\bfunc\s+(?:\([^)]*\)\s*)?([A-Za-z_]\w*)\s*(?:\[[^\]]*\])?\(")
+                .unwrap(),
+            // Methods and constructors: modifiers, type parameters and a return type
+            java_method: Regex::new(
+                r"(?m)^[ \t]*(?:(?:public|private|protected|static|final|abstract|synchronized|native|default)\s+)*(?:<[^>()]*>\s*)?(?:[\w.]+(?:<[^;{}()=]*>)?(?:\[\])*\s+)?([A-Za-z_]\w*)\s*\(",
+            )
+            .unwrap(),
+            python_def: Regex::new(r"(?m)^([ \t]*)(?:async\s+)?def\s+([A-Za-z_]\w*)\s*\(").unwrap(),
+            token: Regex::new(r"[A-Za-z_$][\w$]*|\d+|\S").unwrap(),
+        }
+    }
+
+    /// @ai:intent Functions of a brace-delimited language with their bodies
+    /// @ai:effects pure
+    fn brace_functions<'a>(&self, code: &'a str, language: Language) -> Vec<(String, &'a str)> {
+        let regex = match language {
+            Language::Rust => &self.rust_fn,
+            Language::Go => &self.go_func,
+            Language::Java => &self.java_method,
+            _ => &self.typescript_fn,
+        };
+        let keywords = [
+            "if", "for", "while", "switch", "catch", "return", "function", "try", "synchronized",
+        ];
+
+        let mut functions = Vec::new();
+        for cap in regex.captures_iter(code) {
+            let (Some(whole), Some(name)) = (
+                cap.get(0),
+                cap.get(1).or_else(|| cap.get(2)).or_else(|| cap.get(3)),
+            ) else {
+                continue;
+            };
+            if keywords.contains(&name.as_str())
+                || is_test_function(code, whole.start(), name.as_str())
+            {
+                continue;
+            }
+            // Java record headers and anonymous classes look like methods
+            if language == Language::Java
+                && code[whole.start()..name.start()]
+                    .split_whitespace()
+                    .last()
+                    .is_some_and(|word| word == "record" || word == "new")
+            {
+                continue;
+            }
+
+            let Some(params_end) = matching(code, whole.end() - 1, '(', ')') else {
+                continue;
+            };
+            // The body opens at the next brace unless a `;` ends a declaration first
+            let Some(open) = code[params_end..].find(['{', ';']).map(|i| params_end + i) else {
+                continue;
+            };
+            let tail = code[params_end + 1..open].trim();
+            let is_signature = match language {
+                // Go result types (`int`, `(T, error)`) need no separator before the body
+                Language::Go => true,
+                Language::Java => tail.is_empty() || tail.starts_with("throws"),
+                _ => is_signature_tail(tail, cap.get(3).is_some()),
+            };
+            if !code[open..].starts_with('{') || !is_signature {
+                continue;
+            }
+            if let Some(close) = matching(code, open, '{', '}') {
+                functions.push((name.as_str().to_string(), &code[open + 1..close]));
+            }
+        }
+        functions
+    }
+
+    /// @ai:intent Python functions with their (indented) bodies
+    /// @ai:effects pure
+    fn python_functions<'a>(&self, code: &'a str) -> Vec<(String, &'a str)> {
+        let mut functions = Vec::new();
+        for cap in self.python_def.captures_iter(code) {
+            let (Some(whole), Some(indent), Some(name)) = (cap.get(0), cap.get(1), cap.get(2))
+            else {
+                continue;
+            };
+            if is_test_function(code, whole.start(), name.as_str()) {
+                continue;
+            }
+
+            let body_start = code[whole.end()..]
+                .find('\n')
+                .map(|i| whole.end() + i + 1)
+                .unwrap_or(code.len());
+            let mut body_end = body_start;
+            for line in code[body_start..].split_inclusive('\n') {
+                if !line.trim().is_empty() && indentation(line) <= indent.as_str().len() {
+                    break;
+                }
+                body_end += line.len();
+            }
+            functions.push((name.as_str().to_string(), &code[body_start..body_end]));
+        }
+        functions
+    }
+
+    /// @ai:intent Measure one function body
+    /// @ai:effects pure
+    fn measure(&self, name: String, body: &str, language: Language) -> FunctionComplexity {
+        let cyclomatic = 1 + decision_points(body, language);
+        let nesting_depth = match language {
+            Language::Python => python_nesting(body),
+            _ => brace_nesting(body),
+        };
+
+        // Maintainability index (SEI variant rescaled to 0-100), Halstead volume from tokens
+        let tokens: Vec<&str> = self.token.find_iter(body).map(|m| m.as_str()).collect();
+        let distinct = tokens.iter().collect::<HashSet<_>>().len().max(2) as f64;
+        let volume = (tokens.len().max(1) as f64 * distinct.log2()).max(1.0);
+        let lines = body.lines().filter(|l| !l.trim().is_empty()).count().max(1) as f64;
+        let maintainability =
+            ((171.0 - 5.2 * volume.ln() - 0.23 * cyclomatic as f64 - 16.2 * lines.ln()) * 100.0
+                / 171.0)
+                .clamp(0.0, 100.0);
+
+        FunctionComplexity {
+            name,
+            cyclomatic,
+            nesting_depth,
+            maintainability,
+        }
+    }
+}
+
+impl Default for ComplexityAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ComplexityAnalyzerTrait for ComplexityAnalyzer {
+    /// @ai:intent Measure the functions defined in the given files
+    /// @ai:post result.is_none() || result.unwrap().functions > 0
+    /// @ai:effects pure
+    fn analyze(&self, files: &[SourceFile], language: Language) -> Option<Complexity> {
+        let extension = format!(".{}", language.extension());
+        let mut functions = Vec::new();
+
+        // Go and Java tests live in their own files
+        let separate_tests = matches!(language, Language::Go | Language::Java);
+        for file in files.iter().filter(|f| {
+            f.path.ends_with(&extension) && !(separate_tests && is_test_file(&f.path, language))
+        }) {
+            let code = strip_comments_and_strings(&file.content, language);
+            let bodies = match language {
+                Language::Python => self.python_functions(&code),
+                _ => self.brace_functions(&code, language),
+            };
+            functions.extend(
+                bodies
+                    .into_iter()
+                    .map(|(name, body)| self.measure(name, body, language)),
+            );
+        }
+
+        if functions.is_empty() {
+            return None;
+        }
+
+        tracing::debug!(
+            "Most complex function: {:?}",
+            functions
+                .iter()
+                .max_by_key(|f| f.cyclomatic)
+                .map(|f| &f.name)
+        );
+
+        let count = functions.len() as f64;
+        Some(Complexity {
+            functions: functions.len() as u32,
+            avg_cyclomatic: functions.iter().map(|f| f.cyclomatic as f64).sum::<f64>() / count,
+            max_cyclomatic: functions.iter().map(|f| f.cyclomatic).max().unwrap_or(0),
+            avg_nesting_depth: functions
+                .iter()
+                .map(|f| f.nesting_depth as f64)
+                .sum::<f64>()
+                / count,
+            max_nesting_depth: functions.iter().map(|f| f.nesting_depth).max().unwrap_or(0),
+            avg_maintainability: functions.iter().map(|f| f.maintainability).sum::<f64>() / count,
+        })
+    }
+}
+
+/// @ai:intent Replace comments and string/char literal contents with spaces, keeping line breaks
+///            so that keywords inside them are not counted and line structure is preserved
+/// @ai:effects pure
+pub(crate) fn strip_comments_and_strings(code: &str, language: Language) -> String {
+    let chars: Vec<char> = code.chars().collect();
+    let mut out = String::with_capacity(code.len());
+    let starts = |i: usize, s: &str| {
+        s.chars()
+            .enumerate()
+            .all(|(k, c)| chars.get(i + k) == Some(&c))
+    };
+    let blank = |out: &mut String, c: char| out.push(if c == '\n' { '\n' } else { ' ' });
+    let line_comment = if language == Language::Python {
+        "#"
+    } else {
+        "//"
+    };
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+
+        if starts(i, line_comment) {
+            while i < chars.len() && chars[i] != '\n' {
+                out.push(' ');
+                i += 1;
+            }
+            continue;
+        }
+
+        if language != Language::Python && starts(i, "/*") {
+            while i < chars.len() && !starts(i, "*/") {
+                blank(&mut out, chars[i]);
+                i += 1;
+            }
+            out.push_str("  ");
+            i += 2;
+            continue;
+        }
+
+        // Rust lifetimes ('a) look like char literals; only 'x' and '\..' are literals
+        let is_rust_char = language == Language::Rust
+            && c == '\''
+            && (chars.get(i + 2) == Some(&'\'') || chars.get(i + 1) == Some(&'\\'));
+        let is_rust_raw = language == Language::Rust
+            && c == 'r'
+            && matches!(chars.get(i + 1), Some('"') | Some('#'))
+            && !i
+                .checked_sub(1)
+                .and_then(|p| chars.get(p))
+                .is_some_and(|p| p.is_alphanumeric() || *p == '_');
+
+        let delimiter: Option<(String, bool)> = if is_rust_raw {
+            let hashes = chars[i + 1..].iter().take_while(|c| **c == '#').count();
+            if chars.get(i + 1 + hashes) == Some(&'"') {
+                out.push('r');
+                out.push_str(&"#".repeat(hashes));
+                i += 1 + hashes;
+                Some((format!("\"{}", "#".repeat(hashes)), false))
+            } else {
+                None
+            }
+        } else if language == Language::Python && (starts(i, "\"\"\"") || starts(i, "'''")) {
+            Some((c.to_string().repeat(3), true))
+        } else if language == Language::Java && starts(i, "\"\"\"") {
+            // Text block
+            Some((c.to_string().repeat(3), true))
+        } else if c == '"'
+            || is_rust_char
+            || (c == '\'' && language != Language::Rust)
+            || (c == '`' && language == Language::TypeScript)
+        {
+            Some((c.to_string(), true))
+        } else if c == '`' && language == Language::Go {
+            // Raw string: backslashes are literal
+            Some((c.to_string(), false))
+        } else {
+            None
+        };
+
+        let Some((close, escapes)) = delimiter else {
+            out.push(c);
+            i += 1;
+            continue;
+        };
+
+        // Opening quote(s), blanked contents, closing delimiter
+        let open_len = close
+            .chars()
+            .take_while(|ch| *ch == '"' || *ch == '\'' || *ch == '`')
+            .count();
+        for _ in 0..open_len {
+            out.push(chars[i]);
+            i += 1;
+        }
+        while i < chars.len() && !starts(i, &close) {
+            if escapes && chars[i] == '\\' && i + 1 < chars.len() {
+                out.push(' ');
+                i += 1;
+            }
+            blank(&mut out, chars[i]);
+            i += 1;
+        }
+        if i < chars.len() {
+            out.push_str(&close);
+            i += close.chars().count();
+        }
+    }
+    out
+}
+
+/// @ai:intent Index of the bracket closing the one at open (None if unbalanced)
+/// @ai:pre code[open..] starts with open_char
+/// @ai:effects pure
+pub(crate) fn matching(
+    code: &str,
+    open: usize,
+    open_char: char,
+    close_char: char,
+) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in code[open..].char_indices() {
+        if c == open_char {
+            depth += 1;
+        } else if c == close_char {
+            depth -= 1;
+            if depth == 0 {
+                return Some(open + i);
+            }
+        }
+    }
+    None
+}
+
+/// @ai:intent Whether the text between a parameter list and `{` is a return type annotation
+///            (`-> T`, `: T`, a where clause) or, for arrow functions, `=>`, rather than a call
+/// @ai:effects pure
+fn is_signature_tail(tail: &str, arrow: bool) -> bool {
+    let tail = tail.trim();
+    if arrow {
+        return tail.ends_with("=>");
+    }
+    tail.is_empty() || tail.starts_with("->") || tail.starts_with(':') || tail.starts_with("where")
+}
+
+/// @ai:intent Whether a function is a test (test_ prefix or a #[test] attribute)
+/// @ai:effects pure
+fn is_test_function(code: &str, start: usize, name: &str) -> bool {
+    if name.starts_with("test_") {
+        return true;
+    }
+    code[..start]
+        .lines()
+        .rev()
+        .skip(1)
+        .map(str::trim)
+        .take_while(|line| line.starts_with("#["))
+        .any(|line| line.contains("test]"))
+        || code[..start]
+            .lines()
+            .last()
+            .is_some_and(|line| line.contains("#[test]"))
+}
+
+/// @ai:intent Branch points that add to cyclomatic complexity
+/// @ai:effects pure
+fn decision_points(body: &str, language: Language) -> u32 {
+    let count = |pattern: &str| Regex::new(pattern).unwrap().find_iter(body).count() as i64;
+
+    let points = match language {
+        Language::Rust => {
+            // A match with n arms adds n - 1 branches
+            let arms = count(r"=>") - count(r"\bmatch\b");
+            let or = Regex::new(r"(\w+|[)\]])\s*\|\|")
+                .unwrap()
+                .captures_iter(body)
+                .filter(|cap| &cap[1] != "move")
+                .count() as i64;
+            count(r"\b(?:if|while|for)\b") + arms.max(0) + count(r"&&") + or
+        }
+        Language::TypeScript => {
+            let chars: Vec<char> = body.chars().collect();
+            let ternaries = (0..chars.len())
+                .filter(|&i| {
+                    chars[i] == '?'
+                        && !matches!(chars.get(i + 1), Some('.') | Some('?') | Some(':'))
+                        && (i == 0 || chars[i - 1] != '?')
+                })
+                .count() as i64;
+            count(r"\b(?:if|while|for|case|catch)\b") + count(r"&&|\|\||\?\?") + ternaries
+        }
+        Language::Python => count(r"\b(?:if|elif|while|for|except|and|or|case)\b"),
+        Language::Go => count(r"\b(?:if|for|case)\b") + count(r"&&|\|\|"),
+        Language::Java => {
+            // `?` after `<` or `,` is a generic wildcard (`List<?>`), not a ternary
+            count(r"\b(?:if|while|for|case|catch)\b") + count(r"&&|\|\|") + count(r"[^<,\s]\s*\?")
+        }
+    };
+    points.max(0) as u32
+}
+
+/// @ai:intent Deepest brace nesting inside a function body
+/// @ai:effects pure
+fn brace_nesting(body: &str) -> u32 {
+    let (mut depth, mut max) = (0i32, 0i32);
+    for c in body.chars() {
+        match c {
+            '{' => {
+                depth += 1;
+                max = max.max(depth);
+            }
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    max.max(0) as u32
+}
+
+/// @ai:intent Deepest indentation level inside a Python function body
+/// @ai:effects pure
+fn python_nesting(body: &str) -> u32 {
+    let mut levels: Vec<usize> = Vec::new();
+    let mut max = 0;
+    for line in body.lines().filter(|l| !l.trim().is_empty()) {
+        let indent = indentation(line);
+        while levels.last().is_some_and(|top| indent < *top) {
+            levels.pop();
+        }
+        if levels.last().is_none_or(|top| indent > *top) {
+            levels.push(indent);
+        }
+        max = max.max(levels.len() - 1);
+    }
+    max as u32
+}
+
+/// @ai:intent Width of a line's leading whitespace (tabs count as 4)
+/// @ai:effects pure
+fn indentation(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace() && *c != '\n')
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str, content: &str) -> Vec<SourceFile> {
+        vec![SourceFile {
+            path: path.to_string(),
+            content: content.to_string(),
+        }]
+    }
+
+    #[test]
+    fn test_rust_complexity_ignores_comments_strings_and_tests() {
+        let code = r#"
+/// if this comment counted, complexity would be off
+pub fn classify(n: i32) -> &'static str {
+    if n < 0 && n > -10 {
+        "if while for"
+    } else {
+        match n {
+            0 => "zero",
+            _ => {
+                if n > 100 { "big" } else { "small" }
+            }
+        }
+    }
+}
+
+pub fn id<'a>(s: &'a str) -> &'a str { s }
+
+#[test]
+fn classifies() {
+    if true {}
+}
+"#;
+        let complexity = ComplexityAnalyzer::new()
+            .analyze(&file("src/lib.rs", code), Language::Rust)
+            .unwrap();
+
+        assert_eq!(complexity.functions, 2);
+        // classify: 1 + if + && + one extra match arm + inner if = 5; id: 1
+        assert_eq!(complexity.max_cyclomatic, 5);
+        assert!((complexity.avg_cyclomatic - 3.0).abs() < 0.01);
+        assert_eq!(complexity.max_nesting_depth, 4);
+        assert!(complexity.avg_maintainability > 0.0 && complexity.avg_maintainability <= 100.0);
+    }
+
+    #[test]
+    fn test_python_and_typescript_complexity() {
+        let python = "def grade(score):\n    # if elif\n    if score > 90 and score <= 100:\n        return 'A'\n    elif score > 80:\n        for _ in range(3):\n            pass\n    return \"if\"\n\ndef test_grade():\n    assert grade(95) == 'A'\n";
+        let complexity = ComplexityAnalyzer::new()
+            .analyze(&file("grade.py", python), Language::Python)
+            .unwrap();
+        assert_eq!(complexity.functions, 1);
+        assert_eq!(complexity.max_cyclomatic, 5);
+        assert_eq!(complexity.max_nesting_depth, 2);
+
+        let typescript = "export function sign(x: number): number {\n  return x > 0 ? 1 : x < 0 ? -1 : 0;\n}\n\nclass Box {\n  get(key?: string): string {\n    if (key) { return key ?? ''; }\n    return '';\n  }\n}\n\nconst double = (x: number) => { return x * 2; };\ncall(a).then(() => { work(); });\n";
+        let complexity = ComplexityAnalyzer::new()
+            .analyze(&file("sign.ts", typescript), Language::TypeScript)
+            .unwrap();
+        assert_eq!(complexity.functions, 3);
+        assert_eq!(complexity.max_cyclomatic, 3);
+
+        assert_eq!(
+            ComplexityAnalyzer::new().analyze(&file("Cargo.toml", "[package]"), Language::Rust),
+            None
+        );
+    }
+
+    #[test]
+    fn test_go_complexity() {
+        let go = "package bank\n\n// if for\nfunc (a *Account) Withdraw(amount int) (int, error) {\n\tif amount <= 0 || amount > a.balance {\n\t\treturn 0, errors.New(`if {`)\n\t}\n\tswitch {\n\tcase amount > 100:\n\t\ta.flagged = true\n\t}\n\ta.balance -= amount\n\treturn a.balance, nil\n}\n\nfunc Max[T int | float64](a, b T) T {\n\tif a > b {\n\t\treturn a\n\t}\n\treturn b\n}\n";
+        let test = "package bank\n\nfunc TestWithdraw(t *testing.T) {\n\tif true {\n\t}\n}\n";
+        let files = [file("account.go", go), file("account_test.go", test)].concat();
+        let complexity = ComplexityAnalyzer::new()
+            .analyze(&files, Language::Go)
+            .unwrap();
+
+        assert_eq!(complexity.functions, 2);
+        // Withdraw: 1 + if + || + case = 4; Max: 1 + if = 2
+        assert_eq!(complexity.max_cyclomatic, 4);
+        assert_eq!(complexity.max_nesting_depth, 1);
+    }
+
+    #[test]
+    fn test_java_complexity() {
+        let java = "package bank;\n\npublic record Entry(int amount) {\n}\n\npublic class Account {\n    private final Map<String, ?> meta = Map.of();\n\n    public Account(int balance) {\n        this.balance = balance;\n    }\n\n    // if while\n    public <T> int withdraw(int amount) throws IllegalStateException {\n        if (amount <= 0 || amount > balance) {\n            throw new IllegalArgumentException(\"if {\");\n        }\n        String note = \"\"\"\n            for {\n            \"\"\";\n        balance -= amount > 100 ? fee(amount) : amount;\n        return balance;\n    }\n\n    abstract int fee(int amount);\n}\n";
+        let test = "class AccountTest {\n    @Test\n    void withdraw() {\n        if (true) {\n        }\n    }\n}\n";
+        let files = [file("Account.java", java), file("AccountTest.java", test)].concat();
+        let complexity = ComplexityAnalyzer::new()
+            .analyze(&files, Language::Java)
+            .unwrap();
+
+        // Account(): 1; withdraw: 1 + if + || + ternary = 4 (fee has no body)
+        assert_eq!(complexity.functions, 2);
+        assert_eq!(complexity.max_cyclomatic, 4);
+        assert_eq!(complexity.max_nesting_depth, 1);
+    }
+}
@@ -0,0 +1,344 @@
+//! @ai:module:intent Detect generated code that is a near-duplicate of a reference solution or of
+//!                   another task's output, a sign of memorized rather than written code
+//! @ai:module:layer application
+//! @ai:module:public_api ContaminationDetector, Contamination, Fingerprint
+//! @ai:module:depends_on corpus
+//! @ai:module:stateless true
+
+use crate::corpus::Task;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+/// @ai:intent Length of the token n-grams compared between outputs and references
+const DEFAULT_NGRAM: usize = 12;
+
+/// @ai:intent Share of an output's n-grams found elsewhere above which it is flagged
+const DEFAULT_THRESHOLD: f64 = 0.5;
+
+/// @ai:intent Outputs with fewer n-grams are too short to tell copying from coincidence
+const MIN_NGRAMS: usize = 20;
+
+/// @ai:intent Near-duplicate check of one execution's output
+///            Overlaps are percentages (0-100) of the output's token n-grams, starter code excluded
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Contamination {
+    /// Overlap with the task's own reference solution (None when the task has none)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_overlap: Option<f64>,
+    /// Highest overlap with another task's reference solution or output
+    pub cross_task_overlap: f64,
+    /// Where that overlap was found, e.g. "reference of impl-rust-stack"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nearest: Option<String>,
+    /// True if an overlap reached the threshold
+    pub flagged: bool,
+}
+
+impl Contamination {
+    /// @ai:intent Highest overlap and where it was found
+    /// @ai:effects pure
+    /// @ai:example (reference_overlap 80, cross_task_overlap 10) -> (80.0, "its reference solution")
+    pub fn highest(&self) -> (f64, &str) {
+        match (self.reference_overlap, &self.nearest) {
+            (Some(own), _) if own >= self.cross_task_overlap => (own, "its reference solution"),
+            (_, Some(nearest)) => (self.cross_task_overlap, nearest),
+            _ => (self.cross_task_overlap, "nothing"),
+        }
+    }
+}
+
+/// @ai:intent Hashed token n-grams of one execution's output, for comparison with other outputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fingerprint {
+    pub task_id: String,
+    /// Execution shown in reports, e.g. "impl-rust-stack (aicms, rep 0)"
+    pub label: String,
+    /// Sorted and deduplicated
+    ngrams: Vec<u64>,
+}
+
+impl Fingerprint {
+    /// @ai:intent Share (0.0-1.0) of this fingerprint's n-grams also found in other
+    /// @ai:effects pure
+    pub fn overlap(&self, other: &Fingerprint) -> f64 {
+        overlap(&self.ngrams, &other.ngrams)
+    }
+}
+
+/// @ai:intent Token n-gram near-duplicate detector
+///            Starter code is excluded from outputs, so that modify tasks do not count as copies
+pub struct ContaminationDetector {
+    ngram: usize,
+    threshold: f64,
+    token_regex: Regex,
+    /// Fingerprints of the corpus' reference solutions, by task id
+    references: Vec<(String, Vec<u64>)>,
+}
+
+impl ContaminationDetector {
+    /// @ai:intent Create a detector with default n-gram size and threshold and no references
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            ngram: DEFAULT_NGRAM,
+            threshold: DEFAULT_THRESHOLD,
+            token_regex: Regex::new(r"[A-Za-z_][A-Za-z0-9_]*|[0-9]+|[^\sA-Za-z0-9_]").unwrap(),
+            references: Vec::new(),
+        }
+    }
+
+    /// @ai:intent Override the flagging threshold
+    /// @ai:pre 0.0 <= threshold <= 1.0
+    /// @ai:effects pure
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// @ai:intent Compare outputs with the reference solutions (code and tests) of these tasks
+    /// @ai:effects pure
+    pub fn with_references(mut self, tasks: &[Task]) -> Self {
+        self.references = tasks
+            .iter()
+            .filter_map(|task| {
+                let reference = task.reference.as_ref()?;
+                let code: Vec<&str> = reference.files.iter().map(|f| f.content.as_str()).collect();
+                Some((task.id.clone(), self.ngrams(&code.join("\n"))))
+            })
+            .collect();
+        self
+    }
+
+    /// @ai:intent Hashed token n-grams of text, sorted and deduplicated
+    /// @ai:effects pure
+    fn ngrams(&self, text: &str) -> Vec<u64> {
+        let tokens: Vec<&str> = self
+            .token_regex
+            .find_iter(text)
+            .map(|m| m.as_str())
+            .collect();
+        let mut ngrams: Vec<u64> = tokens
+            .windows(self.ngram)
+            .map(|window| {
+                let mut hasher = DefaultHasher::new();
+                window.hash(&mut hasher);
+                hasher.finish()
+            })
+            .collect();
+        ngrams.sort_unstable();
+        ngrams.dedup();
+        ngrams
+    }
+
+    /// @ai:intent Fingerprint of generated code, without the n-grams of the task's starter code
+    /// @ai:effects pure
+    pub fn fingerprint(&self, task: &Task, label: String, code: &str) -> Fingerprint {
+        let starter: HashSet<u64> = task
+            .starter
+            .iter()
+            .flat_map(|f| self.ngrams(&f.content))
+            .collect();
+        let mut ngrams = self.ngrams(code);
+        ngrams.retain(|ngram| !starter.contains(ngram));
+
+        Fingerprint {
+            task_id: task.id.clone(),
+            label,
+            ngrams,
+        }
+    }
+
+    /// @ai:intent Compare an output with the task's own and the other tasks' reference solutions
+    ///            None if the output is too short to judge
+    /// @ai:effects pure
+    pub fn analyze(&self, fingerprint: &Fingerprint) -> Option<Contamination> {
+        if fingerprint.ngrams.len() < MIN_NGRAMS {
+            return None;
+        }
+
+        let mut contamination = Contamination::default();
+        for (task_id, reference) in &self.references {
+            let share = overlap(&fingerprint.ngrams, reference) * 100.0;
+            if *task_id == fingerprint.task_id {
+                contamination.reference_overlap = Some(share);
+                contamination.flagged |= share >= self.threshold * 100.0;
+            } else {
+                self.record(&mut contamination, share, || {
+                    format!("reference of {}", task_id)
+                });
+            }
+        }
+        Some(contamination)
+    }
+
+    /// @ai:intent Compare every output with the outputs of other tasks and raise the cross-task
+    ///            overlap (and flag) of each near-duplicate
+    /// @ai:effects pure
+    pub fn compare_outputs(&self, outputs: &mut [(&Fingerprint, &mut Contamination)]) {
+        for i in 0..outputs.len() {
+            for j in 0..outputs.len() {
+                let other = outputs[j].0;
+                if other.task_id == outputs[i].0.task_id {
+                    continue;
+                }
+                let share = outputs[i].0.overlap(other) * 100.0;
+                self.record(outputs[i].1, share, || format!("output of {}", other.label));
+            }
+        }
+    }
+
+    /// @ai:intent Keep the highest cross-task overlap and its source, flagging it if too high
+    /// @ai:effects pure
+    fn record(
+        &self,
+        contamination: &mut Contamination,
+        share: f64,
+        source: impl FnOnce() -> String,
+    ) {
+        if share > contamination.cross_task_overlap {
+            contamination.cross_task_overlap = share;
+            contamination.nearest = Some(source());
+            contamination.flagged |= share >= self.threshold * 100.0;
+        }
+    }
+}
+
+impl Default for ContaminationDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// @ai:intent Share (0.0-1.0) of the n-grams of ours also in theirs
+/// @ai:pre both are sorted
+/// @ai:effects pure
+fn overlap(ours: &[u64], theirs: &[u64]) -> f64 {
+    if ours.is_empty() {
+        return 0.0;
+    }
+    let shared = ours
+        .iter()
+        .filter(|ngram| theirs.binary_search(ngram).is_ok())
+        .count();
+    shared as f64 / ours.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::corpus::{ReferenceSolution, StarterFile};
+
+    const STACK: &str = "pub struct Stack { items: Vec<i32> }\n\
+        impl Stack {\n\
+            pub fn new() -> Self { Self { items: Vec::new() } }\n\
+            pub fn push(&mut self, x: i32) { self.items.push(x); }\n\
+            pub fn pop(&mut self) -> Option<i32> { self.items.pop() }\n\
+            pub fn peek(&self) -> Option<&i32> { self.items.last() }\n\
+        }\n";
+
+    const QUEUE: &str = "use std::collections::VecDeque;\n\
+        pub struct Queue { items: VecDeque<String> }\n\
+        impl Queue {\n\
+            pub fn enqueue(&mut self, value: String) { self.items.push_back(value) }\n\
+            pub fn dequeue(&mut self) -> Option<String> { self.items.pop_front() }\n\
+            pub fn len(&self) -> usize { self.items.len() }\n\
+        }\n";
+
+    fn task(id: &str, reference: Option<&str>, starter: Option<&str>) -> Task {
+        Task {
+            id: id.to_string(),
+            name: id.to_string(),
+            starter: starter
+                .map(|content| StarterFile {
+                    path: "src/lib.rs".to_string(),
+                    content: content.to_string(),
+                })
+                .into_iter()
+                .collect(),
+            reference: reference.map(|content| ReferenceSolution {
+                files: vec![StarterFile {
+                    path: "src/lib.rs".to_string(),
+                    content: content.to_string(),
+                }],
+                similarity: false,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_flags_copies_of_references() {
+        let tasks = [
+            task("stack", Some(STACK), None),
+            task("queue", Some(QUEUE), None),
+        ];
+        let detector = ContaminationDetector::new().with_references(&tasks);
+
+        let own = detector.fingerprint(&tasks[0], "stack".to_string(), STACK);
+        let result = detector.analyze(&own).unwrap();
+        assert_eq!(result.reference_overlap, Some(100.0));
+        assert!(result.flagged);
+
+        // The queue task's output is the stack reference
+        let copied = detector.analyze(&detector.fingerprint(&tasks[1], "q".to_string(), STACK));
+        let result = copied.unwrap();
+        assert_eq!(result.reference_overlap, Some(0.0));
+        assert_eq!(result.cross_task_overlap, 100.0);
+        assert_eq!(result.nearest.as_deref(), Some("reference of stack"));
+        assert!(result.flagged);
+
+        assert_eq!(
+            detector.analyze(&detector.fingerprint(&tasks[0], "s".to_string(), "fn f() {}")),
+            None
+        );
+    }
+
+    #[test]
+    fn test_starter_code_is_not_a_copy() {
+        let modify = task("modify-stack", Some(STACK), Some(STACK));
+        let detector = ContaminationDetector::new().with_references(std::slice::from_ref(&modify));
+        let code = format!("{}\npub fn extra(values: &[i32]) -> i32 {{ values.iter().copied().filter(|v| *v > 0).sum::<i32>() * 2 + 1 }}\n", STACK);
+
+        let fingerprint = detector.fingerprint(&modify, "m".to_string(), &code);
+        assert!(fingerprint.ngrams.len() >= MIN_NGRAMS);
+        let result = detector.analyze(&fingerprint).unwrap();
+        assert!(result.reference_overlap.unwrap() < 50.0);
+        assert!(!result.flagged);
+    }
+
+    #[test]
+    fn test_compare_outputs_across_tasks() {
+        let tasks = [task("stack", None, None), task("queue", None, None)];
+        let detector = ContaminationDetector::new();
+        let fingerprints = [
+            detector.fingerprint(&tasks[0], "stack (aicms, rep 0)".to_string(), STACK),
+            detector.fingerprint(&tasks[0], "stack (aicms, rep 1)".to_string(), STACK),
+            detector.fingerprint(&tasks[1], "queue (aicms, rep 0)".to_string(), QUEUE),
+        ];
+        let mut results: Vec<Contamination> = fingerprints
+            .iter()
+            .map(|f| detector.analyze(f).unwrap())
+            .collect();
+
+        let mut outputs: Vec<_> = fingerprints.iter().zip(results.iter_mut()).collect();
+        detector.compare_outputs(&mut outputs);
+        // Repetitions of the same task may agree; different tasks should not
+        assert!(results.iter().all(|r| !r.flagged));
+
+        let mut copy = detector.analyze(&fingerprints[0]).unwrap();
+        let queue_copy = detector.fingerprint(&tasks[1], "queue (aicms, rep 1)".to_string(), STACK);
+        let mut outputs = vec![
+            (&fingerprints[0], &mut copy),
+            (&queue_copy, &mut results[2]),
+        ];
+        detector.compare_outputs(&mut outputs);
+        assert!(copy.flagged);
+        assert_eq!(
+            copy.nearest.as_deref(),
+            Some("output of queue (aicms, rep 1)")
+        );
+    }
+}
@@ -0,0 +1,173 @@
+//! @ai:module:intent Check generated code against the contract traps of modify tasks
+//! @ai:module:layer application
+//! @ai:module:public_api ContractChecker, ContractCheckerTrait, ContractCheck, ContractStatus, overlay_starter
+//! @ai:module:depends_on evaluator::test_runner
+//! @ai:module:stateless true
+
+use crate::corpus::{Language, StarterFile, Task};
+use crate::evaluator::sandbox::Sandbox;
+use crate::evaluator::test_runner::TestRunner;
+use crate::evaluator::SourceFile;
+use serde::{Deserialize, Serialize};
+
+/// @ai:intent Whether the generated code kept a documented contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ContractStatus {
+    /// The hidden test passed
+    Held,
+    /// The hidden test ran and failed
+    Violated,
+    /// The hidden test could not be built or run (e.g. the API was renamed)
+    Error,
+}
+
+/// @ai:intent Outcome of one contract trap for one execution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractCheck {
+    pub trap_id: String,
+    pub contract: String,
+    pub status: ContractStatus,
+}
+
+/// @ai:intent Trait for contract trap checking
+pub trait ContractCheckerTrait: Send + Sync {
+    /// @ai:intent Run every contract trap of the task against the generated files
+    fn check(&self, task: &Task, source_files: &[SourceFile]) -> Vec<ContractCheck>;
+}
+
+/// @ai:intent Runs each trap's hidden test in its own project copy
+pub struct ContractChecker {
+    test_runner: TestRunner,
+}
+
+impl ContractChecker {
+    /// @ai:intent Create a new contract checker
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            test_runner: TestRunner::new(),
+        }
+    }
+
+    /// @ai:intent Run the hidden tests in the given sandbox
+    /// @ai:effects pure
+    pub fn with_sandbox(mut self, sandbox: Sandbox) -> Self {
+        self.test_runner = self.test_runner.with_sandbox(sandbox);
+        self
+    }
+}
+
+impl Default for ContractChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContractCheckerTrait for ContractChecker {
+    /// @ai:intent Run every contract trap of the task against the generated files
+    /// @ai:post result.len() == task.contract_traps.len()
+    /// @ai:effects fs:write, io
+    fn check(&self, task: &Task, source_files: &[SourceFile]) -> Vec<ContractCheck> {
+        task.contract_traps
+            .iter()
+            .map(|trap| {
+                let test = SourceFile {
+                    path: trap.path.clone(),
+                    content: trap.code.clone(),
+                };
+
+                let status =
+                    match self
+                        .test_runner
+                        .run_hidden_test(source_files, &test, task.language)
+                    {
+                        Ok(result) if result.total == 0 => ContractStatus::Error,
+                        Ok(result) if result.failed > 0 => ContractStatus::Violated,
+                        Ok(_) => ContractStatus::Held,
+                        Err(e) => {
+                            tracing::error!("Contract trap {} failed to run: {}", trap.id, e);
+                            ContractStatus::Error
+                        }
+                    };
+
+                ContractCheck {
+                    trap_id: trap.id.clone(),
+                    contract: trap.contract.clone(),
+                    status,
+                }
+            })
+            .collect()
+    }
+}
+
+/// @ai:intent Combine starter code with generated files; generated files replace
+///            starter files with the same path, so unchanged files need not be repeated
+/// @ai:effects pure
+pub fn overlay_starter(
+    starter: &[StarterFile],
+    generated: &[SourceFile],
+    language: Language,
+) -> Vec<SourceFile> {
+    let mut files: Vec<SourceFile> = starter
+        .iter()
+        .filter(|s| {
+            !generated
+                .iter()
+                .any(|g| same_file(&s.path, &g.path, language))
+        })
+        .map(|s| SourceFile {
+            path: s.path.clone(),
+            content: s.content.clone(),
+        })
+        .collect();
+
+    files.extend(generated.iter().cloned());
+    files
+}
+
+/// @ai:intent Check if two relative paths name the same project file
+///            Rust sources may be given with or without the src/ prefix
+/// @ai:effects pure
+pub(crate) fn same_file(a: &str, b: &str, language: Language) -> bool {
+    let normalize = |path: &str| {
+        let path = path.trim_start_matches("./").replace('\\', "/");
+        match language {
+            Language::Rust if path.ends_with(".rs") && !path.starts_with("src/") => {
+                format!("src/{}", path)
+            }
+            _ => path,
+        }
+    };
+
+    normalize(a) == normalize(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overlay_starter_replaces_changed_files() {
+        let starter = vec![
+            StarterFile {
+                path: "Cargo.toml".to_string(),
+                content: "[package]".to_string(),
+            },
+            StarterFile {
+                path: "src/lib.rs".to_string(),
+                content: "old".to_string(),
+            },
+        ];
+        let generated = vec![SourceFile {
+            path: "lib.rs".to_string(),
+            content: "new".to_string(),
+        }];
+
+        let files = overlay_starter(&starter, &generated, Language::Rust);
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path, "Cargo.toml");
+        assert_eq!(files[1].content, "new");
+    }
+}
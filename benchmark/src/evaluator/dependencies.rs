@@ -0,0 +1,301 @@
+//! @ai:module:intent Manifests declaring a task's allowed third-party packages, and the check
+//!                   that a generated project declares no others
+//! @ai:module:layer application
+//! @ai:module:public_api with_dependency_manifest, disallowed_dependencies
+//! @ai:module:depends_on corpus, evaluator::test_runner
+//! @ai:module:stateless true
+
+use crate::corpus::{Dependency, Language, Task};
+use crate::evaluator::test_runner::{is_cargo_toml, CARGO_TOML};
+use crate::evaluator::SourceFile;
+use std::collections::HashSet;
+
+/// Python requirements file installed from the vendored wheels
+const REQUIREMENTS_TXT: &str = "requirements.txt";
+
+/// npm manifest installed from the vendored npm cache
+const PACKAGE_JSON: &str = "package.json";
+
+/// @ai:intent The project files plus a manifest declaring the task's dependencies, if the
+///            task has any and the model wrote no manifest (Go and Java get none)
+/// @ai:post result contains every file of files unchanged
+/// @ai:effects pure
+pub fn with_dependency_manifest(task: &Task, files: &[SourceFile]) -> Vec<SourceFile> {
+    let mut files = files.to_vec();
+    if task.dependencies.is_empty() {
+        return files;
+    }
+
+    let manifest = match task.language {
+        Language::Rust if !files.iter().any(|f| is_cargo_toml(&f.path)) => {
+            Some(("Cargo.toml", cargo_toml(&task.dependencies)))
+        }
+        Language::Python if !files.iter().any(|f| f.path == REQUIREMENTS_TXT) => {
+            Some((REQUIREMENTS_TXT, requirements_txt(&task.dependencies)))
+        }
+        Language::TypeScript if !files.iter().any(|f| f.path == PACKAGE_JSON) => {
+            Some((PACKAGE_JSON, package_json(&task.dependencies)))
+        }
+        _ => None,
+    };
+    if let Some((path, content)) = manifest {
+        files.push(SourceFile {
+            path: path.to_string(),
+            content,
+        });
+    }
+    files
+}
+
+/// @ai:intent Runtime dependencies the project's manifests declare beyond the task's allowed
+///            ones, in manifest order
+///            Tasks without a dependency list are not restricted, and test-only dependencies
+///            (dev-dependencies, devDependencies) are not checked
+/// @ai:effects pure
+pub fn disallowed_dependencies(task: &Task, files: &[SourceFile]) -> Vec<String> {
+    if task.dependencies.is_empty() {
+        return vec![];
+    }
+    let allowed: HashSet<String> = task
+        .dependencies
+        .iter()
+        .map(|dep| normalize_name(&dep.name, task.language))
+        .collect();
+
+    let declared: Vec<String> = match task.language {
+        Language::Rust => files
+            .iter()
+            .filter(|f| is_cargo_toml(&f.path))
+            .flat_map(|f| cargo_dependencies(&f.content))
+            .collect(),
+        Language::Python => files
+            .iter()
+            .filter(|f| f.path == REQUIREMENTS_TXT)
+            .flat_map(|f| requirement_names(&f.content))
+            .collect(),
+        Language::TypeScript => files
+            .iter()
+            .filter(|f| f.path == PACKAGE_JSON)
+            .flat_map(|f| npm_dependencies(&f.content))
+            .collect(),
+        Language::Go | Language::Java => vec![],
+    };
+
+    let mut seen = HashSet::new();
+    declared
+        .into_iter()
+        .filter(|name| !allowed.contains(&normalize_name(name, task.language)))
+        .filter(|name| seen.insert(name.clone()))
+        .collect()
+}
+
+/// @ai:intent Name under which two spellings of a package are the same package
+///            PyPI names are case-insensitive and treat -, _ and . alike
+/// @ai:effects pure
+/// @ai:example ("Typing_Extensions", Python) -> "typing-extensions"
+fn normalize_name(name: &str, language: Language) -> String {
+    match language {
+        Language::Python => name.to_lowercase().replace(['_', '.'], "-"),
+        _ => name.to_string(),
+    }
+}
+
+/// @ai:intent Default Cargo.toml with the dependencies in its [dependencies] table
+/// @ai:effects pure
+fn cargo_toml(dependencies: &[Dependency]) -> String {
+    let mut manifest = CARGO_TOML.to_string();
+    for dep in dependencies {
+        let mut spec = toml::Table::new();
+        spec.insert("version".to_string(), dep.version.clone().into());
+        if !dep.features.is_empty() {
+            spec.insert("features".to_string(), dep.features.clone().into());
+        }
+        manifest.push_str(&format!("{} = {}\n", dep.name, toml::Value::Table(spec)));
+    }
+    manifest
+}
+
+/// @ai:intent requirements.txt with one requirement per dependency
+///            A bare version ("2.31") pins it; specifiers (">=2.31") are kept as written
+/// @ai:effects pure
+/// @ai:example ([requests "2.31", attrs ">=23"]) -> "requests==2.31\nattrs>=23\n"
+fn requirements_txt(dependencies: &[Dependency]) -> String {
+    dependencies
+        .iter()
+        .map(|dep| match dep.version.trim() {
+            "" | "*" => format!("{}\n", dep.name),
+            version if version.starts_with(|c: char| c.is_ascii_digit()) => {
+                format!("{}=={}\n", dep.name, version)
+            }
+            version => format!("{}{}\n", dep.name, version),
+        })
+        .collect()
+}
+
+/// @ai:intent package.json with the dependencies under "dependencies"
+/// @ai:effects pure
+fn package_json(dependencies: &[Dependency]) -> String {
+    let versions: serde_json::Map<String, serde_json::Value> = dependencies
+        .iter()
+        .map(|dep| (dep.name.clone(), dep.version.clone().into()))
+        .collect();
+    let manifest = serde_json::json!({
+        "name": "benchmark-project",
+        "private": true,
+        "dependencies": versions,
+    });
+    format!("{:#}\n", manifest)
+}
+
+/// @ai:intent Packages in a Cargo.toml's [dependencies] and [build-dependencies] tables
+///            Path dependencies (workspace members) are not packages; a renamed
+///            dependency counts under its package name
+/// @ai:effects pure
+fn cargo_dependencies(manifest: &str) -> Vec<String> {
+    let Ok(manifest) = manifest.parse::<toml::Table>() else {
+        return vec![];
+    };
+    ["dependencies", "build-dependencies"]
+        .iter()
+        .filter_map(|table| manifest.get(*table)?.as_table())
+        .flatten()
+        .filter(|(_, spec)| spec.get("path").is_none())
+        .map(|(key, spec)| {
+            spec.get("package")
+                .and_then(|package| package.as_str())
+                .unwrap_or(key)
+                .to_string()
+        })
+        .collect()
+}
+
+/// @ai:intent Package names of the requirements in a requirements.txt
+///            Options (-r, --index-url) and comments are skipped
+/// @ai:effects pure
+/// @ai:example ("requests[socks]>=2.31 ; python_version > '3'\n# dev\n-r base.txt") -> ["requests"]
+fn requirement_names(requirements: &str) -> Vec<String> {
+    requirements
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('-'))
+        .filter_map(|line| {
+            let end = line
+                .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.')))
+                .unwrap_or(line.len());
+            (end > 0).then(|| line[..end].to_string())
+        })
+        .collect()
+}
+
+/// @ai:intent Packages under "dependencies" in a package.json
+/// @ai:effects pure
+fn npm_dependencies(manifest: &str) -> Vec<String> {
+    serde_json::from_str::<serde_json::Value>(manifest)
+        .ok()
+        .and_then(|manifest| manifest["dependencies"].as_object().cloned())
+        .map(|dependencies| dependencies.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(language: Language, dependencies: &[(&str, &str, &[&str])]) -> Task {
+        Task {
+            id: "impl-deps".to_string(),
+            name: "Deps".to_string(),
+            language,
+            dependencies: dependencies
+                .iter()
+                .map(|(name, version, features)| Dependency {
+                    name: name.to_string(),
+                    version: version.to_string(),
+                    features: features.iter().map(|f| f.to_string()).collect(),
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn file(path: &str, content: &str) -> SourceFile {
+        SourceFile {
+            path: path.to_string(),
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_manifest_declares_task_dependencies() {
+        let rust = task(
+            Language::Rust,
+            &[("serde", "1", &["derive"]), ("serde_json", "1", &[])],
+        );
+        let files = with_dependency_manifest(&rust, &[file("src/lib.rs", "")]);
+        let manifest = files[1].content.parse::<toml::Table>().unwrap();
+        assert_eq!(files[1].path, "Cargo.toml");
+        assert_eq!(
+            manifest["package"]["name"].as_str(),
+            Some("benchmark_project")
+        );
+        assert_eq!(
+            manifest["dependencies"]["serde"]["features"][0].as_str(),
+            Some("derive")
+        );
+        assert_eq!(
+            manifest["dependencies"]["serde_json"]["version"].as_str(),
+            Some("1")
+        );
+
+        // The model's own manifest wins
+        let own = [file("src/lib.rs", ""), file("Cargo.toml", "[package]")];
+        assert_eq!(with_dependency_manifest(&rust, &own).len(), 2);
+
+        let python = task(
+            Language::Python,
+            &[("requests", "2.31", &[]), ("attrs", ">=23", &[])],
+        );
+        let files = with_dependency_manifest(&python, &[]);
+        assert_eq!(files[0].path, "requirements.txt");
+        assert_eq!(files[0].content, "requests==2.31\nattrs>=23\n");
+
+        let typescript = task(Language::TypeScript, &[("zod", "^3.22", &[])]);
+        let files = with_dependency_manifest(&typescript, &[]);
+        let manifest: serde_json::Value = serde_json::from_str(&files[0].content).unwrap();
+        assert_eq!(manifest["dependencies"]["zod"], "^3.22");
+
+        assert!(with_dependency_manifest(&task(Language::Rust, &[]), &[]).is_empty());
+    }
+
+    #[test]
+    fn test_disallowed_dependencies() {
+        let rust = task(Language::Rust, &[("serde", "1", &[])]);
+        let manifest = "[package]\nname = \"x\"\n\n[dependencies]\nserde = \"1\"\n\
+                        json = { package = \"serde_json\", version = \"1\" }\n\
+                        util = { path = \"../util\" }\n\n[dev-dependencies]\nproptest = \"1\"\n";
+        assert_eq!(
+            disallowed_dependencies(&rust, &[file("Cargo.toml", manifest)]),
+            ["serde_json"]
+        );
+        assert!(disallowed_dependencies(
+            &task(Language::Rust, &[]),
+            &[file("Cargo.toml", manifest)]
+        )
+        .is_empty());
+
+        let python = task(Language::Python, &[("typing_extensions", "4", &[])]);
+        let requirements = "Typing-Extensions>=4\nrequests[socks]==2.31 ; python_version > '3'\n\
+                            # pinned\n-r base.txt\n";
+        assert_eq!(
+            disallowed_dependencies(&python, &[file("requirements.txt", requirements)]),
+            ["requests"]
+        );
+
+        let typescript = task(Language::TypeScript, &[("zod", "^3", &[])]);
+        let manifest = r#"{"dependencies": {"zod": "^3", "lodash": "^4"}, "devDependencies": {"jest": "^29"}}"#;
+        assert_eq!(
+            disallowed_dependencies(&typescript, &[file("package.json", manifest)]),
+            ["lodash"]
+        );
+    }
+}
@@ -0,0 +1,324 @@
+//! @ai:module:intent Score inferred annotations against the annotated gold files of inference tasks
+//! @ai:module:layer application
+//! @ai:module:public_api InferenceScorer, InferenceScorerTrait, InferenceScore, TagScore
+//! @ai:module:depends_on aicms_parser, evaluator::contracts
+//! @ai:module:stateless true
+
+use crate::corpus::Task;
+use crate::evaluator::contracts::same_file;
+use crate::evaluator::SourceFile;
+use aicms_parser::{ModuleAnnotations, ParsedFile};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tempfile::TempDir;
+
+/// @ai:intent Matches for one annotation tag between inferred and gold annotations
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TagScore {
+    pub tag: String,
+    pub true_positives: u32,
+    pub false_positives: u32,
+    pub false_negatives: u32,
+}
+
+impl TagScore {
+    /// @ai:intent Percentage of inferred annotations that are in the gold set
+    /// @ai:effects pure
+    pub fn precision(&self) -> f64 {
+        ratio(
+            self.true_positives,
+            self.true_positives + self.false_positives,
+        )
+    }
+
+    /// @ai:intent Percentage of gold annotations that were inferred
+    /// @ai:effects pure
+    pub fn recall(&self) -> f64 {
+        ratio(
+            self.true_positives,
+            self.true_positives + self.false_negatives,
+        )
+    }
+}
+
+/// @ai:intent Inference quality of one execution: per-tag matches and micro-averaged rates
+///            Rates are percentages (0-100)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InferenceScore {
+    pub tags: Vec<TagScore>,
+    pub precision: f64,
+    pub recall: f64,
+    pub f1: f64,
+}
+
+impl InferenceScore {
+    /// @ai:intent Build a score from per-tag matches, micro-averaging over all tags
+    /// @ai:effects pure
+    pub fn from_tags(tags: Vec<TagScore>) -> Self {
+        let tp: u32 = tags.iter().map(|t| t.true_positives).sum();
+        let fp: u32 = tags.iter().map(|t| t.false_positives).sum();
+        let fn_: u32 = tags.iter().map(|t| t.false_negatives).sum();
+        let precision = ratio(tp, tp + fp);
+        let recall = ratio(tp, tp + fn_);
+        let f1 = if precision + recall == 0.0 {
+            0.0
+        } else {
+            2.0 * precision * recall / (precision + recall)
+        };
+
+        Self {
+            tags,
+            precision,
+            recall,
+            f1,
+        }
+    }
+}
+
+/// @ai:intent Trait for annotation inference scoring
+pub trait InferenceScorerTrait: Send + Sync {
+    /// @ai:intent Compare the annotations of the generated files with the task's gold files
+    ///            (None when the task has no gold files)
+    fn score(&self, task: &Task, source_files: &[SourceFile]) -> Option<InferenceScore>;
+}
+
+/// @ai:intent Parses gold and generated files with the AICMS parser and matches their tags
+///            Only functions and modules present in the gold files are scored, so helpers
+///            the model adds do not count against it. Free-text tags (intents) match on
+///            presence; all other values match after normalizing case and whitespace
+pub struct InferenceScorer;
+
+impl InferenceScorer {
+    /// @ai:intent Create a new inference scorer
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Default for InferenceScorer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InferenceScorerTrait for InferenceScorer {
+    /// @ai:intent Compare the annotations of the generated files with the task's gold files
+    /// @ai:post result.is_some() == !task.gold.is_empty()
+    /// @ai:effects fs:write
+    fn score(&self, task: &Task, source_files: &[SourceFile]) -> Option<InferenceScore> {
+        if task.gold.is_empty() {
+            return None;
+        }
+
+        let mut gold_facts = BTreeSet::new();
+        let mut inferred_facts = BTreeSet::new();
+
+        for gold in &task.gold {
+            let Some(expected) = parse_annotations(&gold.path, &gold.content) else {
+                tracing::warn!("Gold file {} of {} could not be parsed", gold.path, task.id);
+                continue;
+            };
+            let generated = source_files
+                .iter()
+                .find(|f| same_file(&f.path, &gold.path, task.language))
+                .and_then(|f| parse_annotations(&f.path, &f.content))
+                .unwrap_or_default();
+
+            let scopes = scopes(&expected);
+            gold_facts.extend(facts(&gold.path, &expected, &scopes));
+            inferred_facts.extend(facts(&gold.path, &generated, &scopes));
+        }
+
+        let mut tags: BTreeMap<&str, TagScore> = BTreeMap::new();
+        for fact in gold_facts.union(&inferred_facts) {
+            let score = tags.entry(fact.tag).or_insert_with(|| TagScore {
+                tag: fact.tag.to_string(),
+                ..Default::default()
+            });
+            match (gold_facts.contains(fact), inferred_facts.contains(fact)) {
+                (true, true) => score.true_positives += 1,
+                (false, true) => score.false_positives += 1,
+                _ => score.false_negatives += 1,
+            }
+        }
+
+        Some(InferenceScore::from_tags(tags.into_values().collect()))
+    }
+}
+
+/// @ai:intent One annotation value attached to a module or function of a file
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Fact {
+    tag: &'static str,
+    scope: String,
+    value: String,
+}
+
+/// @ai:intent Run the AICMS parser on file content (it only reads from disk)
+///            None for unsupported file types
+/// @ai:effects fs:write
+pub(crate) fn parse_source(path: &str, content: &str) -> Option<ParsedFile> {
+    let parse = || -> Result<ParsedFile> {
+        let name = Path::new(path)
+            .file_name()
+            .ok_or_else(|| anyhow::anyhow!("no file name in {}", path))?;
+        let temp_dir = TempDir::new()?;
+        let file_path = temp_dir.path().join(name);
+        std::fs::write(&file_path, content)?;
+        Ok(aicms_parser::extract_file(&file_path)?)
+    };
+    parse().ok()
+}
+
+/// @ai:intent Module annotations of file content
+/// @ai:effects fs:write
+fn parse_annotations(path: &str, content: &str) -> Option<ModuleAnnotations> {
+    parse_source(path, content).map(|parsed| parsed.module)
+}
+
+/// @ai:intent Names of the functions the gold file annotates
+/// @ai:effects pure
+fn scopes(gold: &ModuleAnnotations) -> BTreeSet<String> {
+    gold.functions
+        .iter()
+        .filter(|f| f.is_annotated())
+        .map(|f| f.name.clone())
+        .collect()
+}
+
+/// @ai:intent Flatten the annotations of a file into comparable facts
+///            Only functions named in scopes contribute
+/// @ai:effects pure
+fn facts(path: &str, module: &ModuleAnnotations, scopes: &BTreeSet<String>) -> Vec<Fact> {
+    let mut facts = Vec::new();
+    let mut push = |tag: &'static str, scope: &str, value: &str| {
+        facts.push(Fact {
+            tag,
+            scope: scope.to_string(),
+            value: normalize(value),
+        });
+    };
+
+    let module_scope = format!("{path}#module");
+    if module.intent.is_some() {
+        push("module:intent", &module_scope, "");
+    }
+    if let Some(layer) = &module.layer {
+        push("module:layer", &module_scope, layer);
+    }
+    if let Some(stateless) = module.stateless {
+        push("module:stateless", &module_scope, &stateless.to_string());
+    }
+    for item in &module.public_api {
+        push("module:public_api", &module_scope, item);
+    }
+    for item in &module.depends_on {
+        push("module:depends_on", &module_scope, item);
+    }
+
+    for function in module.functions.iter().filter(|f| scopes.contains(&f.name)) {
+        let scope = format!("{path}#{}", function.name);
+        if function.intent.is_some() {
+            push("intent", &scope, "");
+        }
+        for value in &function.pre {
+            push("pre", &scope, value);
+        }
+        for value in &function.post {
+            push("post", &scope, value);
+        }
+        if let Some(value) = &function.invariant {
+            push("invariant", &scope, value);
+        }
+        for value in &function.examples {
+            push("example", &scope, value);
+        }
+        for value in &function.effects {
+            push("effects", &scope, value);
+        }
+    }
+
+    facts
+}
+
+/// @ai:intent Lowercase and drop whitespace so formatting differences still match
+/// @ai:effects pure
+/// @ai:example ("A > 0") -> "a>0"
+fn normalize(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// @ai:intent Percentage of part in total, 0 when total is 0
+/// @ai:effects pure
+fn ratio(part: u32, total: u32) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64 * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::corpus::{StarterFile, TaskCategory};
+
+    #[test]
+    fn test_score_counts_matches_per_tag() {
+        let gold = "\
+/// @ai:intent Add two numbers
+/// @ai:pre a >= 0
+/// @ai:effects pure
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+";
+        let inferred = "\
+/// @ai:intent Sum of both arguments
+/// @ai:pre a>=0
+/// @ai:effects io
+pub fn add(a: u32, b: u32) -> u32 {
+    a + b
+}
+
+/// @ai:intent Helper the gold file does not annotate
+fn helper() {}
+";
+        let task = Task {
+            id: "inference-rust-add".to_string(),
+            name: "Add".to_string(),
+            category: TaskCategory::Inference,
+            gold: vec![StarterFile {
+                path: "src/lib.rs".to_string(),
+                content: gold.to_string(),
+            }],
+            ..Default::default()
+        };
+        let generated = vec![SourceFile {
+            path: "lib.rs".to_string(),
+            content: inferred.to_string(),
+        }];
+
+        let score = InferenceScorer::new().score(&task, &generated).unwrap();
+        let tag = |name: &str| score.tags.iter().find(|t| t.tag == name).unwrap();
+
+        assert_eq!(tag("intent").true_positives, 1);
+        assert_eq!(tag("pre").true_positives, 1);
+        assert_eq!(tag("effects").false_positives, 1);
+        assert_eq!(tag("effects").false_negatives, 1);
+        assert!((score.precision - 200.0 / 3.0).abs() < 0.01);
+        assert!((score.recall - 200.0 / 3.0).abs() < 0.01);
+
+        // Without a generated file every gold annotation is missed
+        let missing = InferenceScorer::new().score(&task, &[]).unwrap();
+        assert_eq!(missing.recall, 0.0);
+        assert_eq!(missing.f1, 0.0);
+    }
+}
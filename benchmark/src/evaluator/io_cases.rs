@@ -0,0 +1,284 @@
+//! @ai:module:intent Behavioral checks of CLI-style tasks: build the generated program, run it
+//!                   once per declared I/O case and compare exit code and stdout
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api IoRunner, IoRunnerTrait
+//! @ai:module:depends_on corpus, evaluator::sandbox, evaluator::test_runner
+//! @ai:module:stateless true
+
+use crate::corpus::{IoCase, IoSpec, Language};
+use crate::evaluator::sandbox::{Sandbox, SandboxCommand};
+use crate::evaluator::test_runner::{java_sources, write_project, TestResult, JAVA_CLASSES};
+use crate::evaluator::SourceFile;
+use anyhow::Result;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// @ai:intent Executable `go build` writes the Go program to (relative to the project root)
+const GO_BINARY: &str = "io-program";
+
+/// @ai:intent Characters of expected and actual output shown for a failed case
+const MAX_SHOWN_OUTPUT: usize = 200;
+
+/// @ai:intent Trait for I/O case execution
+pub trait IoRunnerTrait: Send + Sync {
+    /// @ai:intent Build the project and run every case; a failed build fails them all
+    fn run(&self, spec: &IoSpec, files: &[SourceFile], language: Language) -> Result<TestResult>;
+}
+
+/// @ai:intent Builds the program and runs it with each case's arguments, stdin and timeout
+pub struct IoRunner {
+    sandbox: Sandbox,
+}
+
+impl IoRunner {
+    /// @ai:intent Create a runner that runs programs on the host
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            sandbox: Sandbox::default(),
+        }
+    }
+
+    /// @ai:intent Build and run programs in the given sandbox
+    /// @ai:effects pure
+    pub fn with_sandbox(mut self, sandbox: Sandbox) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
+    /// @ai:intent Build the program where the language needs it
+    /// @ai:post Some(errors) if the build failed
+    /// @ai:effects fs:write, io
+    fn build(
+        &self,
+        root: &Path,
+        entry: Option<&str>,
+        language: Language,
+    ) -> Result<Option<String>> {
+        let output = match language {
+            Language::Rust => {
+                let mut cmd = self.sandbox.command("cargo", root, Language::Rust);
+                cmd.args(["build", "--quiet"]);
+                if let Some(bin) = entry {
+                    cmd.args(["--bin", bin]);
+                }
+                cmd.output()?
+            }
+            Language::Go => self
+                .sandbox
+                .command("go", root, Language::Go)
+                .args(["build", "-o", GO_BINARY, entry.unwrap_or(".")])
+                .output()?,
+            Language::Java => self
+                .sandbox
+                .command("javac", root, Language::Java)
+                .args(["-d", JAVA_CLASSES, "-encoding", "UTF-8"])
+                .args(java_sources(root, false))
+                .output()?,
+            Language::Python | Language::TypeScript => {
+                self.sandbox.install_dependencies(root, language)?;
+                return Ok(None);
+            }
+        };
+
+        Ok((!output.status.success())
+            .then(|| String::from_utf8_lossy(&output.stderr).trim().to_string()))
+    }
+
+    /// @ai:intent Command that runs the built program, before the case's arguments
+    /// @ai:effects env, fs:read
+    fn program(
+        &self,
+        root: &Path,
+        entry: Option<&str>,
+        language: Language,
+        timeout_secs: u64,
+    ) -> SandboxCommand {
+        let sandbox = self.sandbox.clone().with_timeout_secs(timeout_secs);
+        match language {
+            Language::Rust => {
+                let mut cmd = sandbox.command("cargo", root, Language::Rust);
+                cmd.args(["run", "--quiet"]);
+                if let Some(bin) = entry {
+                    cmd.args(["--bin", bin]);
+                }
+                cmd.arg("--");
+                cmd
+            }
+            Language::Python => {
+                let mut cmd = sandbox.command("python", root, Language::Python);
+                cmd.arg(entry.unwrap_or("main.py"));
+                cmd
+            }
+            Language::TypeScript => {
+                let mut cmd = sandbox.command("npx", root, Language::TypeScript);
+                cmd.args(["tsx", entry.unwrap_or("src/main.ts")]);
+                cmd
+            }
+            Language::Go => sandbox.command(&format!("./{}", GO_BINARY), root, Language::Go),
+            Language::Java => {
+                let mut cmd = sandbox.command("java", root, Language::Java);
+                cmd.args(["-cp", JAVA_CLASSES, entry.unwrap_or("Main")]);
+                cmd
+            }
+        }
+    }
+
+    /// @ai:intent Run one case
+    /// @ai:post Some(reason) if the exit code or stdout differ from the expected ones
+    /// @ai:effects io
+    fn run_case(
+        &self,
+        root: &Path,
+        entry: Option<&str>,
+        language: Language,
+        case: &IoCase,
+    ) -> Result<Option<String>> {
+        let output = self
+            .program(root, entry, language, case.timeout_secs)
+            .args(&case.args)
+            .stdin(case.stdin.as_bytes())
+            .output()?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if stderr.contains("[killed after timeout of") {
+            return Ok(Some(format!("timed out after {}s", case.timeout_secs)));
+        }
+        if output.status.code() != Some(case.exit_code) {
+            return Ok(Some(format!(
+                "exit code {}, expected {}: {}",
+                output
+                    .status
+                    .code()
+                    .map_or("none".to_string(), |code| code.to_string()),
+                case.exit_code,
+                shown(stderr.trim())
+            )));
+        }
+        if normalize_output(&stdout) != normalize_output(&case.stdout) {
+            return Ok(Some(format!(
+                "stdout {:?}, expected {:?}",
+                shown(&normalize_output(&stdout)),
+                shown(&normalize_output(&case.stdout))
+            )));
+        }
+        Ok(None)
+    }
+}
+
+impl Default for IoRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IoRunnerTrait for IoRunner {
+    /// @ai:intent Build the project in a temporary directory and run every case in order
+    /// @ai:post result.total == spec.cases.len()
+    /// @ai:effects fs:write, io
+    fn run(&self, spec: &IoSpec, files: &[SourceFile], language: Language) -> Result<TestResult> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        write_project(root, files, language)?;
+        let entry = spec.entry.as_deref();
+        let total = spec.cases.len() as u32;
+
+        if let Some(errors) = self.build(root, entry, language)? {
+            return Ok(TestResult {
+                passed: 0,
+                failed: total,
+                total,
+                output: format!("Build failed: {}", errors),
+            });
+        }
+
+        let mut passed = 0;
+        let mut failures = Vec::new();
+        for case in &spec.cases {
+            match self.run_case(root, entry, language, case)? {
+                None => passed += 1,
+                Some(reason) => failures.push(format!("{}: {}", case.name, reason)),
+            }
+        }
+
+        Ok(TestResult {
+            passed,
+            failed: total - passed,
+            total,
+            output: failures.join("\n"),
+        })
+    }
+}
+
+/// @ai:intent Output without trailing whitespace on each line, trailing blank lines or \r
+/// @ai:effects pure
+/// @ai:example ("3 \r\n4\n\n") -> "3\n4"
+fn normalize_output(output: &str) -> String {
+    output
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+/// @ai:intent Text cut to MAX_SHOWN_OUTPUT characters for a failure message
+/// @ai:effects pure
+fn shown(text: &str) -> String {
+    match text.char_indices().nth(MAX_SHOWN_OUTPUT) {
+        Some((cut, _)) => format!("{}...", &text[..cut]),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(name: &str, args: &[&str], stdin: &str, stdout: &str, exit_code: i32) -> IoCase {
+        IoCase {
+            name: name.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            stdin: stdin.to_string(),
+            stdout: stdout.to_string(),
+            exit_code,
+            timeout_secs: 10,
+        }
+    }
+
+    #[test]
+    fn test_normalize_output() {
+        assert_eq!(normalize_output("3 \r\n4\n\n"), "3\n4");
+        assert_eq!(normalize_output(""), "");
+    }
+
+    #[test]
+    fn test_python_program_runs_against_cases() {
+        let files = vec![SourceFile {
+            path: "main.py".to_string(),
+            content: "import sys\n\
+                      if len(sys.argv) > 1:\n    sys.exit(int(sys.argv[1]))\n\
+                      total = sum(int(line) for line in sys.stdin if line.strip())\n\
+                      print(total)\n"
+                .to_string(),
+        }];
+        let spec = IoSpec {
+            entry: None,
+            cases: vec![
+                case("sum", &[], "1\n2\n3\n", "6\n", 0),
+                case("exit", &["3"], "", "", 3),
+                case("wrong", &[], "1\n", "2", 0),
+            ],
+        };
+
+        let result = IoRunner::new()
+            .run(&spec, &files, Language::Python)
+            .unwrap();
+        assert_eq!((result.passed, result.failed, result.total), (2, 1, 3));
+        assert!(result
+            .output
+            .starts_with("wrong: stdout \"1\", expected \"2\""));
+    }
+}
@@ -0,0 +1,183 @@
+//! @ai:module:intent Recover the JSON object from a judge answer despite common slips:
+//!                   markdown code fences, surrounding prose, trailing commas, raw newlines
+//! @ai:module:layer domain
+//! @ai:module:public_api RepairedJson, repair_json
+//! @ai:module:stateless true
+
+use anyhow::{bail, Result};
+
+/// @ai:intent JSON object recovered from a response and the repairs it needed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepairedJson {
+    pub json: String,
+    /// Repairs applied: "code-fence", "trailing-comma", "control-character"
+    pub repairs: Vec<&'static str>,
+}
+
+/// @ai:intent Extract the JSON object from a response and fix common syntax slips
+///            Text around the object is dropped without counting as a repair
+/// @ai:effects pure
+/// @ai:example ("```json\n{\"a\": 1,}\n```") -> {"a": 1} with repairs ["code-fence", "trailing-comma"]
+pub fn repair_json(response: &str) -> Result<RepairedJson> {
+    let mut repairs = Vec::new();
+
+    let text = match strip_code_fence(response) {
+        Some(inner) => {
+            repairs.push("code-fence");
+            inner
+        }
+        None => response,
+    };
+    let mut json = extract_object(text)?.to_string();
+
+    if let Some(fixed) = remove_trailing_commas(&json) {
+        repairs.push("trailing-comma");
+        json = fixed;
+    }
+    if let Some(fixed) = escape_control_characters(&json) {
+        repairs.push("control-character");
+        json = fixed;
+    }
+
+    Ok(RepairedJson { json, repairs })
+}
+
+/// @ai:intent Contents of the first markdown code fence that holds a JSON object
+/// @ai:effects pure
+fn strip_code_fence(text: &str) -> Option<&str> {
+    let mut rest = text;
+    while let Some(open) = rest.find("```") {
+        // The fence's info string (e.g. "json") runs to the end of its line
+        let body = &rest[open + 3..];
+        let body = &body[body.find('\n')? + 1..];
+        let close = body.find("```")?;
+        let inner = &body[..close];
+        if inner.trim_start().starts_with('{') {
+            return Some(inner);
+        }
+        rest = &body[close + 3..];
+    }
+    None
+}
+
+/// @ai:intent Text from the first { to the last }
+/// @ai:effects pure
+fn extract_object(text: &str) -> Result<&str> {
+    let start = text
+        .find('{')
+        .ok_or_else(|| anyhow::anyhow!("No JSON object found in response"))?;
+    let end = text
+        .rfind('}')
+        .ok_or_else(|| anyhow::anyhow!("No JSON object end found in response"))?;
+
+    if end <= start {
+        bail!("Invalid JSON structure in response");
+    }
+
+    Ok(&text[start..=end])
+}
+
+/// @ai:intent Drop commas directly before a closing } or ], outside strings
+///            None if there were none
+/// @ai:effects pure
+fn remove_trailing_commas(json: &str) -> Option<String> {
+    let chars: Vec<char> = json.chars().collect();
+    let mut fixed = String::with_capacity(json.len());
+    let mut changed = false;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, &c) in chars.iter().enumerate() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ',' {
+            let next = chars[i + 1..].iter().find(|c| !c.is_whitespace());
+            if matches!(next, Some('}') | Some(']')) {
+                changed = true;
+                continue;
+            }
+        }
+        fixed.push(c);
+    }
+
+    changed.then_some(fixed)
+}
+
+/// @ai:intent Escape raw newlines, carriage returns and tabs inside strings
+///            None if there were none
+/// @ai:effects pure
+fn escape_control_characters(json: &str) -> Option<String> {
+    let mut fixed = String::with_capacity(json.len());
+    let mut changed = false;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for c in json.chars() {
+        if in_string && !escaped {
+            let replacement = match c {
+                '\n' => Some("\\n"),
+                '\r' => Some("\\r"),
+                '\t' => Some("\\t"),
+                _ => None,
+            };
+            if let Some(replacement) = replacement {
+                changed = true;
+                fixed.push_str(replacement);
+                continue;
+            }
+        }
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            _ => {}
+        }
+        fixed.push(c);
+    }
+
+    changed.then_some(fixed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repair_json_simple() {
+        let repaired = repair_json(r#"{"score": 85}"#).unwrap();
+        assert_eq!(repaired.json, r#"{"score": 85}"#);
+        assert!(repaired.repairs.is_empty());
+    }
+
+    #[test]
+    fn test_repair_json_with_prefix() {
+        let repaired = repair_json(r#"Here's the score: {"score": 85} end"#).unwrap();
+        assert_eq!(repaired.json, r#"{"score": 85}"#);
+        assert!(repair_json("no json here").is_err());
+    }
+
+    #[test]
+    fn test_repair_json_fixes_fences_commas_and_newlines() {
+        let response = "Scores for {both} implementations:\n\
+                        ```json\n\
+                        {\"scores\": [80, 70,], \"reason\": \"handles a, ] and\nnewlines\",\n}\n\
+                        ```\n\
+                        Let me know if you need {more}.";
+        let repaired = repair_json(response).unwrap();
+        assert_eq!(
+            repaired.repairs,
+            ["code-fence", "trailing-comma", "control-character"]
+        );
+
+        let value: serde_json::Value = serde_json::from_str(&repaired.json).unwrap();
+        assert_eq!(value["scores"], serde_json::json!([80, 70]));
+        assert_eq!(value["reason"], "handles a, ] and\nnewlines");
+    }
+}
@@ -0,0 +1,210 @@
+//! @ai:module:intent Detect generated code that echoes the task description instead of implementing it
+//! @ai:module:layer application
+//! @ai:module:public_api LeakageDetector, LeakageDetectorTrait, SpecLeakage
+//! @ai:module:stateless true
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// @ai:intent Length of the word n-grams compared between spec and output
+const DEFAULT_NGRAM: usize = 6;
+
+/// @ai:intent Share of echoed words above which an output is flagged
+const DEFAULT_THRESHOLD: f64 = 0.35;
+
+/// @ai:intent Verbatim overlap between a task description and generated code
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpecLeakage {
+    /// Fraction (0.0-1.0) of generated words covered by n-grams copied from the spec
+    pub overlap_ratio: f64,
+    /// Number of generated words covered by copied n-grams
+    pub echoed_words: usize,
+    /// Number of generated words considered (required identifiers excluded)
+    pub total_words: usize,
+    /// Longest run of consecutive words copied from the spec
+    pub longest_echo: usize,
+    /// True if the output mostly restates the spec
+    pub flagged: bool,
+}
+
+/// @ai:intent Trait for spec leakage detection
+pub trait LeakageDetectorTrait: Send + Sync {
+    /// @ai:intent Measure how much of the generated code is copied from the description
+    fn analyze(&self, description: &str, code: &str) -> SpecLeakage;
+}
+
+/// @ai:intent Word n-gram overlap detector
+///            Identifiers named in the spec (backticked, snake_case, CamelCase) are
+///            excluded so that required names do not count as echoing
+pub struct LeakageDetector {
+    ngram: usize,
+    threshold: f64,
+    word_regex: Regex,
+    backtick_regex: Regex,
+}
+
+impl LeakageDetector {
+    /// @ai:intent Create a detector with default n-gram size and threshold
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            ngram: DEFAULT_NGRAM,
+            threshold: DEFAULT_THRESHOLD,
+            word_regex: Regex::new(r"[A-Za-z0-9_]+").unwrap(),
+            backtick_regex: Regex::new(r"`([^`]+)`").unwrap(),
+        }
+    }
+
+    /// @ai:intent Override the flagging threshold
+    /// @ai:pre 0.0 <= threshold <= 1.0
+    /// @ai:effects pure
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// @ai:intent Collect identifiers the spec requires the implementation to use
+    /// @ai:effects pure
+    fn required_identifiers(&self, description: &str) -> HashSet<String> {
+        let mut identifiers: HashSet<String> = self
+            .backtick_regex
+            .captures_iter(description)
+            .flat_map(|cap| {
+                self.word_regex
+                    .find_iter(cap.get(1).map(|m| m.as_str()).unwrap_or(""))
+                    .map(|m| m.as_str().to_lowercase())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        identifiers.extend(
+            self.word_regex
+                .find_iter(description)
+                .map(|m| m.as_str())
+                .filter(|word| is_identifier_like(word))
+                .map(str::to_lowercase),
+        );
+
+        identifiers
+    }
+
+    /// @ai:intent Split text into lowercase words, dropping excluded identifiers
+    /// @ai:effects pure
+    fn words(&self, text: &str, excluded: &HashSet<String>) -> Vec<String> {
+        self.word_regex
+            .find_iter(text)
+            .map(|m| m.as_str().to_lowercase())
+            .filter(|word| !excluded.contains(word))
+            .collect()
+    }
+}
+
+impl Default for LeakageDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LeakageDetectorTrait for LeakageDetector {
+    /// @ai:intent Mark every generated word that lies inside an n-gram present in the spec
+    /// @ai:post result.echoed_words <= result.total_words
+    /// @ai:effects pure
+    fn analyze(&self, description: &str, code: &str) -> SpecLeakage {
+        let excluded = self.required_identifiers(description);
+        let spec_words = self.words(description, &excluded);
+        let code_words = self.words(code, &excluded);
+
+        if code_words.len() < self.ngram || spec_words.len() < self.ngram {
+            return SpecLeakage {
+                total_words: code_words.len(),
+                ..Default::default()
+            };
+        }
+
+        let spec_ngrams: HashSet<&[String]> = spec_words.windows(self.ngram).collect();
+
+        let mut covered = vec![false; code_words.len()];
+        for (start, window) in code_words.windows(self.ngram).enumerate() {
+            if spec_ngrams.contains(window) {
+                covered[start..start + self.ngram].fill(true);
+            }
+        }
+
+        let echoed_words = covered.iter().filter(|&&c| c).count();
+        let longest_echo = covered
+            .split(|&c| !c)
+            .map(|run| run.len())
+            .max()
+            .unwrap_or(0);
+        let overlap_ratio = echoed_words as f64 / code_words.len() as f64;
+
+        SpecLeakage {
+            overlap_ratio,
+            echoed_words,
+            total_words: code_words.len(),
+            longest_echo,
+            flagged: overlap_ratio >= self.threshold,
+        }
+    }
+}
+
+/// @ai:intent Check if a word looks like a code identifier (snake_case or camelCase)
+/// @ai:effects pure
+fn is_identifier_like(word: &str) -> bool {
+    if word.trim_matches('_').contains('_') {
+        return true;
+    }
+
+    word.chars()
+        .zip(word.chars().skip(1))
+        .any(|(a, b)| a.is_lowercase() && b.is_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = "Implement a rate limiter that allows at most N requests per window. \
+        Requests beyond the limit must be rejected until the window resets. \
+        Expose `try_acquire` and a RateLimiter struct.";
+
+    #[test]
+    fn test_echoed_spec_is_flagged() {
+        let code = "// Implement a rate limiter that allows at most N requests per window.\n\
+            // Requests beyond the limit must be rejected until the window resets.\n\
+            struct RateLimiter;\n\
+            fn try_acquire() { todo!() }";
+
+        let leakage = LeakageDetector::new().analyze(SPEC, code);
+
+        assert!(leakage.flagged);
+        assert!(leakage.overlap_ratio > 0.5);
+        assert!(leakage.longest_echo >= 12);
+    }
+
+    #[test]
+    fn test_implementation_with_required_identifiers_is_not_flagged() {
+        let code = "pub struct RateLimiter { capacity: u32, used: u32 }\n\
+            impl RateLimiter {\n\
+                /// Take one slot if capacity remains\n\
+                pub fn try_acquire(&mut self) -> bool {\n\
+                    if self.used < self.capacity { self.used += 1; true } else { false }\n\
+                }\n\
+            }";
+
+        let leakage = LeakageDetector::new().analyze(SPEC, code);
+
+        assert!(!leakage.flagged);
+        assert_eq!(leakage.echoed_words, 0);
+        assert!(leakage.total_words > 0);
+    }
+
+    #[test]
+    fn test_identifier_detection() {
+        assert!(is_identifier_like("try_acquire"));
+        assert!(is_identifier_like("RateLimiter"));
+        assert!(!is_identifier_like("Requests"));
+        assert!(!is_identifier_like("_private"));
+    }
+}
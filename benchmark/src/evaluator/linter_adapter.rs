@@ -1,9 +1,15 @@
 //! @ai:module:intent AICMS linter integration for annotation validation
 //! @ai:module:layer infrastructure
 //! @ai:module:public_api LinterAdapter, LintResult, LintIssue
+//! @ai:module:depends_on aicms_parser, evaluator::inference
 //! @ai:module:stateless true
 
-use regex::Regex;
+use crate::evaluator::inference::parse_source;
+use crate::evaluator::SourceFile;
+use aicms_parser::{check_annotation, lint_parsed_file, FailOn, LintConfig};
+use std::path::PathBuf;
+
+pub use aicms_parser::{LintIssue, Severity};
 
 /// @ai:intent Result of linting AICMS annotations
 #[derive(Debug, Clone)]
@@ -11,6 +17,8 @@ pub struct LintResult {
     pub issues: Vec<LintIssue>,
     pub annotation_count: u32,
     pub valid_annotation_count: u32,
+    /// Within the configured fail-on severity and warning budget
+    pub passed: bool,
 }
 
 impl LintResult {
@@ -25,156 +33,37 @@ impl LintResult {
     }
 }
 
-/// @ai:intent A linting issue found in code
-#[derive(Debug, Clone)]
-pub struct LintIssue {
-    pub severity: Severity,
-    pub message: String,
-    pub line: Option<u32>,
-}
-
-/// @ai:intent Severity level of a lint issue
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Severity {
-    Error,
-    Warning,
-    Info,
-}
-
 /// @ai:intent Trait for linting AICMS annotations
 pub trait LinterAdapterTrait: Send + Sync {
-    /// @ai:intent Lint code for AICMS annotation issues
-    fn lint(&self, code: &str) -> LintResult;
+    /// @ai:intent Lint generated files for AICMS annotation issues
+    fn lint(&self, files: &[SourceFile]) -> LintResult;
 }
 
-/// @ai:intent AICMS linter for validating annotations
+/// @ai:intent AICMS linter for validating annotations, backed by the aicms_parser linter
+///            so tags, effects and severities match the `aicms lint` CLI
 pub struct LinterAdapter {
-    annotation_regex: Regex,
-    valid_tags: Vec<&'static str>,
+    config: LintConfig,
 }
 
 impl LinterAdapter {
-    /// @ai:intent Create a new linter adapter
+    /// @ai:intent Create a new linter adapter that only validates annotations
     /// @ai:effects pure
     pub fn new() -> Self {
         Self {
-            annotation_regex: Regex::new(r"@ai:(\w+(?::\w+)*)(?:\s+(.*))?").unwrap(),
-            valid_tags: vec![
-                "intent",
-                "pre",
-                "post",
-                "invariant",
-                "example",
-                "effects",
-                "idempotent",
-                "retry_safe",
-                "confidence",
-                "needs_review",
-                "author",
-                "verified",
-                "assumes",
-                "context",
-                "related",
-                "deprecated",
-                "complexity",
-                "edge_cases",
-                "override",
-                "constraint",  // Alias for pre, commonly generated
-                "test:integration",
-                "module:intent",
-                "module:layer",
-                "module:bounded_context",
-                "module:public_api",
-                "module:depends_on",
-                "module:depended_by",
-                "module:internal",
-                "module:stateless",
-                "module:thread_safe",
-                "module:cohesion",
-                "module:stability",
-                "project:max_function_lines",
-                "project:max_file_lines",
-                "project:max_functions_per_file",
-                "project:max_structs_per_module",
-                "project:max_params",
-                "project:max_return_values",
-                "project:max_nesting_depth",
-                "project:max_cyclomatic_complexity",
-                "project:extract_repeated_code",
-                "project:require_interface_for_deps",
-                "project:single_responsibility",
-                "project:prefer_composition",
-                "project:no_god_objects",
-                "project:no_primitive_obsession",
-                "project:immutable_by_default",
-                "project:architecture",
-                "project:layers",
-                "project:dependency_rule",
-                "project:error_strategy",
-                "project:require_error_types",
-                "project:no_panic",
-                "project:min_coverage",
-                "project:unit_tests",
-                "project:integration_tests",
-                "project:integration_tests_tools",
-                "project:test_naming",
-            ],
-        }
-    }
-
-    /// @ai:intent Check if a tag is valid
-    /// @ai:effects pure
-    fn is_valid_tag(&self, tag: &str) -> bool {
-        self.valid_tags.contains(&tag) || tag.starts_with("override:")
-    }
-
-    /// @ai:intent Validate effects value
-    /// @ai:effects pure
-    fn validate_effects(&self, value: &str) -> Option<LintIssue> {
-        let valid_effects = [
-            "pure",
-            "io",
-            "db:read",
-            "db:write",
-            "network",
-            "fs:read",
-            "fs:write",
-            "env",
-            "state:read",
-            "state:write",
-            "random",
-            "time",
-        ];
-
-        for effect in value.split(',').map(|s| s.trim()) {
-            if !valid_effects.contains(&effect) {
-                return Some(LintIssue {
-                    severity: Severity::Warning,
-                    message: format!("Unknown effect: {}", effect),
-                    line: None,
-                });
-            }
+            config: LintConfig {
+                validate_annotations: true,
+                ..Default::default()
+            },
         }
-
-        None
     }
 
-    /// @ai:intent Validate confidence value
+    /// @ai:intent Fail the lint check at the given severity or above max_warnings warnings,
+    ///            like `aicms lint --fail-on` and `--max-warnings`
     /// @ai:effects pure
-    fn validate_confidence(&self, value: &str) -> Option<LintIssue> {
-        match value.parse::<f64>() {
-            Ok(v) if (0.0..=1.0).contains(&v) => None,
-            Ok(v) => Some(LintIssue {
-                severity: Severity::Error,
-                message: format!("Confidence must be between 0.0 and 1.0, got {}", v),
-                line: None,
-            }),
-            Err(_) => Some(LintIssue {
-                severity: Severity::Error,
-                message: format!("Invalid confidence value: {}", value),
-                line: None,
-            }),
-        }
+    pub fn with_thresholds(mut self, fail_on: FailOn, max_warnings: Option<usize>) -> Self {
+        self.config.fail_on = fail_on;
+        self.config.max_warnings = max_warnings;
+        self
     }
 }
 
@@ -185,79 +74,41 @@ impl Default for LinterAdapter {
 }
 
 impl LinterAdapterTrait for LinterAdapter {
-    /// @ai:intent Lint code for AICMS annotation issues
-    /// @ai:effects pure
-    fn lint(&self, code: &str) -> LintResult {
-        let mut issues = Vec::new();
-        let mut annotation_count = 0u32;
-        let mut valid_count = 0u32;
-        let mut has_intent = false;
-
-        for (line_num, line) in code.lines().enumerate() {
-            for cap in self.annotation_regex.captures_iter(line) {
-                annotation_count += 1;
-                let tag = &cap[1];
-                let value = cap.get(2).map(|m| m.as_str().trim()).unwrap_or("");
-
-                if !self.is_valid_tag(tag) {
-                    issues.push(LintIssue {
-                        severity: Severity::Error,
-                        message: format!("Unknown annotation tag: @ai:{}", tag),
-                        line: Some(line_num as u32 + 1),
-                    });
-                    continue;
-                }
-
-                if tag == "intent" || tag == "module:intent" {
-                    has_intent = true;
-
-                    if value.is_empty() {
-                        issues.push(LintIssue {
-                            severity: Severity::Error,
-                            message: "Intent annotation must have a value".to_string(),
-                            line: Some(line_num as u32 + 1),
-                        });
-                        continue;
-                    }
-                }
-
-                if tag == "effects" {
-                    if let Some(issue) = self.validate_effects(value) {
-                        issues.push(LintIssue {
-                            line: Some(line_num as u32 + 1),
-                            ..issue
-                        });
-                        continue;
-                    }
-                }
-
-                if tag == "confidence" {
-                    if let Some(issue) = self.validate_confidence(value) {
-                        issues.push(LintIssue {
-                            line: Some(line_num as u32 + 1),
-                            ..issue
-                        });
-                        continue;
-                    }
-                }
-
-                valid_count += 1;
+    /// @ai:intent Lint generated files for AICMS annotation issues
+    ///            Files the parser does not support are skipped; issue locations point
+    ///            at the generated file paths
+    /// @ai:effects fs:write
+    fn lint(&self, files: &[SourceFile]) -> LintResult {
+        let mut result = LintResult {
+            issues: Vec::new(),
+            annotation_count: 0,
+            valid_annotation_count: 0,
+            passed: true,
+        };
+        let mut totals = aicms_parser::LintResult::default();
+
+        for file in files {
+            let Some(parsed) = parse_source(&file.path, &file.content) else {
+                continue;
+            };
+
+            result.annotation_count += parsed.raw_annotations.len() as u32;
+            result.valid_annotation_count += parsed
+                .raw_annotations
+                .iter()
+                .filter(|annotation| check_annotation(annotation).is_none())
+                .count() as u32;
+
+            let mut file_result = lint_parsed_file(&parsed, &self.config);
+            for issue in &mut file_result.issues {
+                issue.location.file = PathBuf::from(&file.path);
             }
+            totals.merge(file_result);
         }
 
-        if annotation_count > 0 && !has_intent {
-            issues.push(LintIssue {
-                severity: Severity::Warning,
-                message: "Missing @ai:intent annotation (required for all functions)".to_string(),
-                line: None,
-            });
-        }
-
-        LintResult {
-            issues,
-            annotation_count,
-            valid_annotation_count: valid_count,
-        }
+        result.passed = totals.passed_with(&self.config);
+        result.issues = totals.issues;
+        result
     }
 }
 
@@ -265,6 +116,13 @@ impl LinterAdapterTrait for LinterAdapter {
 mod tests {
     use super::*;
 
+    fn file(path: &str, content: &str) -> SourceFile {
+        SourceFile {
+            path: path.to_string(),
+            content: content.to_string(),
+        }
+    }
+
     #[test]
     fn test_lint_valid_annotations() {
         let linter = LinterAdapter::new();
@@ -275,7 +133,7 @@ mod tests {
 fn factorial(n: u64) -> u64 { 1 }
 "#;
 
-        let result = linter.lint(code);
+        let result = linter.lint(&[file("src/lib.rs", code), file("Cargo.toml", "")]);
         assert_eq!(result.annotation_count, 3);
         assert_eq!(result.valid_annotation_count, 3);
         assert!(result.issues.is_empty());
@@ -284,11 +142,18 @@ fn factorial(n: u64) -> u64 { 1 }
     #[test]
     fn test_lint_invalid_tag() {
         let linter = LinterAdapter::new();
-        let code = "/// @ai:invalid_tag something";
-
-        let result = linter.lint(code);
-        assert_eq!(result.issues.len(), 2); // invalid tag + missing intent
-        assert!(result.issues[0].message.contains("Unknown annotation"));
+        let code = "# @ai:intent Greet\n# @ai:retry_safe true\ndef greet():\n    pass\n";
+
+        let result = linter.lint(&[file("app/greet.py", code)]);
+        assert_eq!(result.issues.len(), 1);
+        assert_eq!(result.issues[0].severity, Severity::Error);
+        assert!(result.issues[0].message.contains("Unknown annotation tag"));
+        assert_eq!(
+            result.issues[0].location.file,
+            PathBuf::from("app/greet.py")
+        );
+        assert_eq!(result.issues[0].location.line, 2);
+        assert_eq!(result.valid_annotation_count, 1);
     }
 
     #[test]
@@ -297,10 +162,38 @@ fn factorial(n: u64) -> u64 { 1 }
         let code = r#"
 /// @ai:intent Test
 /// @ai:confidence 1.5
+/// @ai:effects pure, clock
+fn test() {}
 "#;
 
-        let result = linter.lint(code);
-        assert!(result.issues.iter().any(|i| i.message.contains("0.0 and 1.0")));
+        let result = linter.lint(&[file("src/lib.rs", code)]);
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.severity == Severity::Error && i.message.contains("0.0 and 1.0")));
+        assert!(result
+            .issues
+            .iter()
+            .any(|i| i.severity == Severity::Warning && i.message.contains("clock")));
+        assert_eq!(result.valid_annotation_count, 1);
+        assert!(!result.passed);
+    }
+
+    #[test]
+    fn test_lint_thresholds() {
+        let code = "/// @ai:intent Test
+/// @ai:effects pure, clock
+fn test() {}
+";
+        let files = [file("src/lib.rs", code)];
+
+        assert!(LinterAdapter::new().lint(&files).passed);
+        let strict = LinterAdapter::new().with_thresholds(FailOn::Warning, None);
+        assert!(!strict.lint(&files).passed);
+        let budget = LinterAdapter::new().with_thresholds(FailOn::Error, Some(0));
+        assert!(!budget.lint(&files).passed);
+        let budget = LinterAdapter::new().with_thresholds(FailOn::Error, Some(1));
+        assert!(budget.lint(&files).passed);
     }
 
     #[test]
@@ -309,6 +202,7 @@ fn factorial(n: u64) -> u64 { 1 }
             issues: vec![],
             annotation_count: 10,
             valid_annotation_count: 8,
+            passed: true,
         };
         assert!((result.compliance_rate() - 80.0).abs() < 0.01);
     }
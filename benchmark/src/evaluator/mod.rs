@@ -1,23 +1,84 @@
 //! @ai:module:intent Evaluation components for benchmark results
 //! @ai:module:layer application
-//! @ai:module:public_api Evaluator, EvaluationResult, ClaudeScorer, ComparisonScore
+//! @ai:module:public_api Evaluator, EvaluationResult, ClaudeScorer, ComparisonScore, ContractCheck,
+//!                        ReferenceAlignment, InferenceScore, CodeQuality, Complexity, CodeSize,
+//!                        MutationScore, TestDesign, TypeCheck, Sandbox, Rubric, TaskRanking, ApiJudge,
+//!                        Contamination, IoRunner, SecurityScan, ApiConformance, EvaluationStage,
+//!                        StageInput
 
+pub mod annotation_matcher;
+pub mod api_conformance;
+pub mod api_judge;
 pub mod annotation_scorer;
 pub mod claude_scorer;
 pub mod code_extractor;
+pub mod code_quality;
+pub mod code_size;
 pub mod compiler;
+pub mod complexity;
+pub mod contamination;
+pub mod contracts;
+pub mod dependencies;
+pub mod inference;
+pub mod io_cases;
+pub mod json_repair;
+pub mod leakage;
 pub mod linter_adapter;
+pub mod mutation;
+pub mod pipeline;
+pub mod ranking;
+pub mod reference;
+pub mod repair;
+pub mod rubric;
+pub mod sandbox;
+pub mod security;
+pub mod stages;
+pub mod test_design;
 pub mod test_runner;
+pub mod type_check;
 
-pub use annotation_scorer::{AnnotationScore, AnnotationScorer, AnnotationScorerTrait};
+pub use annotation_matcher::{
+    AnnotationJudge, AnnotationMatch, AnnotationMatcher, ClaudeJudge, EmbeddingJudge, MatchMethod,
+};
+pub use api_conformance::{ApiConformance, ApiConformanceChecker, ApiConformanceCheckerTrait};
+pub use api_judge::ApiJudge;
+pub use annotation_scorer::{
+    expected_annotations, AnnotationDensity, AnnotationScore, AnnotationScorer,
+    AnnotationScorerTrait,
+};
 pub use claude_scorer::{
-    default_comparison_prompt, ClaudeScorer, ClaudeScorerTrait, ComparisonScore,
-    ImplementationScore, MockClaudeScorer,
+    default_comparison_prompt, default_ranking_prompt, BlindAssignment, ClaudeScorer,
+    ClaudeScorerTrait, ComparisonScore, ImplementationScore, JudgePanel, JudgeParseError,
+    MockClaudeScorer, ParseAttempt, RankedImplementation, RankedVerdict,
 };
 pub use code_extractor::{CodeExtractor, CodeExtractorTrait, ExtractedCode, ExtractedFile};
-pub use compiler::{CompilationChecker, CompilationCheckerTrait, CompilationResult};
+pub use code_quality::{CodeQuality, CodeQualityChecker, CodeQualityCheckerTrait};
+pub use code_size::CodeSize;
+pub use compiler::{CompilationChecker, CompilationCheckerTrait, CompilationResult, Diagnostic};
+pub use complexity::{Complexity, ComplexityAnalyzer, ComplexityAnalyzerTrait};
+pub use contamination::{Contamination, ContaminationDetector, Fingerprint};
+pub use contracts::{
+    overlay_starter, ContractCheck, ContractChecker, ContractCheckerTrait, ContractStatus,
+};
+pub use dependencies::{disallowed_dependencies, with_dependency_manifest};
+pub use inference::{InferenceScore, InferenceScorer, InferenceScorerTrait, TagScore};
+pub use io_cases::{IoRunner, IoRunnerTrait};
+pub use json_repair::{repair_json, RepairedJson};
+pub use leakage::{LeakageDetector, LeakageDetectorTrait, SpecLeakage};
 pub use linter_adapter::{LinterAdapter, LinterAdapterTrait, LintIssue, LintResult, Severity};
-pub use test_runner::{TestResult, TestRunner, TestRunnerTrait};
+pub use mutation::{MutationScore, MutationTester, MutationTesterTrait};
+pub use pipeline::{default_stages, EvaluationStage, StageInput};
+pub use ranking::{
+    discover_contestants, rank_task, Contestant, ContestantResult, PairwiseResult, TaskRanking,
+};
+pub use reference::{ReferenceAlignment, ReferenceComparer, ReferenceComparerTrait};
+pub use repair::repair_check;
+pub use rubric::{Rubric, RubricAspect};
+pub use sandbox::Sandbox;
+pub use security::{SecurityFinding, SecurityScan, SecurityScanner, SecurityScannerTrait};
+pub use test_design::{TestDesign, TestDesignAnalyzer, TestDesignAnalyzerTrait};
+pub use test_runner::{TestDeterminism, TestResult, TestRunner, TestRunnerTrait};
+pub use type_check::{TypeCheck, TypeChecker, TypeCheckerTrait};
 
 /// @ai:intent A source file with path and content (used for evaluation)
 #[derive(Debug, Clone)]
@@ -26,53 +87,205 @@ pub struct SourceFile {
     pub content: String,
 }
 
+use crate::config::EvaluationConfig;
 use crate::corpus::Task;
+use crate::evaluator::test_runner::write_project;
+use crate::platform::remove_dir_all;
 use crate::runner::ExecutionResult;
 use anyhow::Result;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 /// @ai:intent Combined evaluation result for a task execution
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct EvaluationResult {
     pub task_id: String,
     pub mode: String,
     pub repetition: u32,
     pub compilation: Option<CompilationResult>,
     pub tests: Option<TestResult>,
+    /// Agreement of two runs of the own tests (only with [evaluation] flaky_test_detection)
+    pub test_determinism: Option<TestDeterminism>,
+    /// Reference tests from the corpus (None when the task has none)
+    pub hidden_tests: Option<TestResult>,
+    /// Runs of the built program against the task's I/O cases (None when the task has none)
+    pub io_cases: Option<TestResult>,
+    /// Required public functions and types found in the code (None when the task lists none)
+    pub api_conformance: Option<ApiConformance>,
+    /// Comparison with the task's gold solution (None when the task has none)
+    pub reference: Option<ReferenceAlignment>,
+    /// Inferred annotations scored against gold files (inference tasks with gold files)
+    pub inference: Option<InferenceScore>,
+    /// Idiomatic-lint warnings (None if the linter is unavailable)
+    pub code_quality: Option<CodeQuality>,
+    /// Type errors in Python code that compiled (only with [evaluation] python_type_checker)
+    pub type_check: Option<TypeCheck>,
+    /// Dangerous code patterns and (with [evaluation] security_audit) vulnerable dependencies
+    pub security: Option<SecurityScan>,
+    /// Cyclomatic complexity and nesting of the generated functions (None if there are none)
+    pub complexity: Option<Complexity>,
+    /// File count and lines of the generated code (None if no code was extracted)
+    pub code_size: Option<CodeSize>,
+    /// Mutants caught by the generated tests (only with [evaluation] mutation_testing)
+    pub mutation: Option<MutationScore>,
+    /// Property-based tests and edge case coverage of the model's own tests (None without tests)
+    pub test_design: Option<TestDesign>,
     pub lint: Option<LintResult>,
     pub annotation_score: Option<AnnotationScore>,
+    pub spec_leakage: Option<SpecLeakage>,
+    /// Near-duplicate check against reference solutions (None if the output is too short)
+    pub contamination: Option<Contamination>,
+    /// Token n-grams of the output, compared with other tasks' outputs after the run
+    pub fingerprint: Option<Fingerprint>,
+    /// Contract trap outcomes (modify tasks only)
+    pub contract_checks: Vec<ContractCheck>,
+    /// Values custom stages record under their own names; aggregated by the rule in
+    /// [statistics] custom_metrics and reported without further code
+    pub custom: BTreeMap<String, f64>,
     pub extracted_code: Option<String>,
     pub extracted_files: Option<Vec<ExtractedFile>>,
 }
 
-/// @ai:intent Main evaluator that combines all evaluation components
+/// @ai:intent Main evaluator: extracts the code of an execution and runs the evaluation
+///            stages on it in order
 pub struct Evaluator {
     code_extractor: CodeExtractor,
-    compiler: CompilationChecker,
-    test_runner: TestRunner,
-    linter: LinterAdapter,
-    annotation_scorer: AnnotationScorer,
+    /// Built-in stages followed by those registered with with_stage
+    stages: Vec<Box<dyn EvaluationStage>>,
+    /// Names of the stages not run ([evaluation] disabled_stages)
+    disabled_stages: Vec<String>,
+    /// Compares outputs across tasks once all executions are evaluated
+    contamination_detector: ContaminationDetector,
+    /// Results directory to save generated projects under, for comparisons
+    code_dir: Option<PathBuf>,
 }
 
 impl Evaluator {
-    /// @ai:intent Create a new evaluator with all components
+    /// @ai:intent Create a new evaluator with the built-in stages
     /// @ai:effects pure
     pub fn new() -> Self {
         Self {
             code_extractor: CodeExtractor::new(),
-            compiler: CompilationChecker::new(),
-            test_runner: TestRunner::new(),
-            linter: LinterAdapter::new(),
-            annotation_scorer: AnnotationScorer::new(),
+            stages: default_stages(),
+            disabled_stages: vec![],
+            contamination_detector: ContaminationDetector::new(),
+            code_dir: None,
+        }
+    }
+
+    /// @ai:intent Append a stage to the pipeline. Register custom stages before the other
+    ///            with_* calls so they receive the sandbox, settings and corpus too
+    /// @ai:effects pure
+    pub fn with_stage(mut self, stage: impl EvaluationStage + 'static) -> Self {
+        self.stages.push(Box::new(stage));
+        self
+    }
+
+    /// @ai:intent Names of the registered stages in run order, including disabled ones
+    /// @ai:effects pure
+    pub fn stage_names(&self) -> Vec<&str> {
+        self.stages.iter().map(|stage| stage.name()).collect()
+    }
+
+    /// @ai:intent Compile and run tests in the given sandbox instead of on the host
+    /// @ai:effects pure
+    pub fn with_sandbox(mut self, sandbox: Sandbox) -> Self {
+        for stage in &mut self.stages {
+            stage.set_sandbox(&sandbox);
+        }
+        self
+    }
+
+    /// @ai:intent Enable the optional stages selected by the [evaluation] section and skip
+    ///            the disabled ones
+    /// @ai:effects pure
+    pub fn with_evaluation_config(mut self, config: &EvaluationConfig) -> Self {
+        for stage in &mut self.stages {
+            stage.configure(config);
+        }
+        self.contamination_detector = self
+            .contamination_detector
+            .with_threshold(config.contamination_threshold);
+
+        let names = self.stage_names();
+        for name in &config.disabled_stages {
+            if !names.contains(&name.as_str()) {
+                tracing::warn!("Unknown evaluation stage in disabled_stages: {}", name);
+            }
+        }
+        self.disabled_stages = config.disabled_stages.clone();
+        self
+    }
+
+    /// @ai:intent Check outputs for copies of these tasks' reference solutions
+    /// @ai:effects pure
+    pub fn with_corpus(mut self, tasks: &[Task]) -> Self {
+        for stage in &mut self.stages {
+            stage.set_corpus(tasks);
         }
+        self
+    }
+
+    /// @ai:intent Raise the contamination of outputs that near-duplicate another task's output
+    /// @ai:effects pure
+    pub fn compare_outputs(&self, outputs: &mut [(&Fingerprint, &mut Contamination)]) {
+        self.contamination_detector.compare_outputs(outputs);
+    }
+
+    /// @ai:intent Save the first repetition's project of each task and mode under
+    ///            <output_dir>/<mode>/code/<task_id>/, where the Claude Code backend leaves
+    ///            its working directories, so runs on other backends can be compared
+    /// @ai:effects pure
+    pub fn with_code_dir(mut self, output_dir: PathBuf) -> Self {
+        self.code_dir = Some(output_dir);
+        self
+    }
+
+    /// @ai:intent Write the project evaluated for an execution to the code directory
+    /// @ai:effects fs:write
+    fn save_project(&self, task: &Task, execution: &ExecutionResult, files: &[SourceFile]) {
+        let Some(output_dir) = &self.code_dir else {
+            return;
+        };
+        if execution.repetition != 0 {
+            return;
+        }
+
+        let dir = output_dir
+            .join(execution.mode.as_str())
+            .join("code")
+            .join(&task.id);
+        let save = || -> Result<()> {
+            remove_dir_all(&dir)?;
+            write_project(&dir, files, task.language)
+        };
+        if let Err(e) = save() {
+            tracing::warn!("Failed to save the project to {}: {}", dir.display(), e);
+        }
+    }
+
+    /// @ai:intent Stages to run, in order
+    /// @ai:effects pure
+    fn enabled_stages(&self) -> impl Iterator<Item = &dyn EvaluationStage> {
+        self.stages
+            .iter()
+            .map(|stage| stage.as_ref())
+            .filter(|stage| !self.disabled_stages.iter().any(|name| name == stage.name()))
     }
 
     /// @ai:intent Evaluate a single execution result
-    ///            Extracts code from response and runs Claude's own tests
+    ///            Extracts code from the response and runs every enabled stage on it
     /// @ai:effects fs:write, io
     pub fn evaluate(&self, task: &Task, execution: &ExecutionResult) -> Result<EvaluationResult> {
         let extracted_files = self
             .code_extractor
             .extract_files(&execution.response, task.language);
+        let mut result = EvaluationResult {
+            task_id: task.id.clone(),
+            mode: execution.mode.as_str().to_string(),
+            repetition: execution.repetition,
+            ..Default::default()
+        };
 
         if extracted_files.is_empty() {
             let response_preview = truncate_for_log(&execution.response, 200);
@@ -82,18 +295,18 @@ impl Evaluator {
                 execution.mode.as_str(),
                 response_preview
             );
-
-            return Ok(EvaluationResult {
-                task_id: task.id.clone(),
-                mode: execution.mode.as_str().to_string(),
-                repetition: execution.repetition,
-                compilation: None,
-                tests: None,
-                lint: None,
-                annotation_score: None,
-                extracted_code: None,
-                extracted_files: None,
-            });
+            let input = StageInput {
+                task,
+                execution,
+                extracted_files: &[],
+                generated_files: &[],
+                source_files: &[],
+                combined_code: "",
+            };
+            for stage in self.enabled_stages() {
+                stage.run_without_code(&input, &mut result);
+            }
+            return Ok(result);
         }
 
         tracing::info!(
@@ -104,73 +317,48 @@ impl Evaluator {
             extracted_files.iter().map(|f| &f.path).collect::<Vec<_>>()
         );
 
-        let source_files = self.code_extractor.to_source_files(&extracted_files);
-
-        // Compile the project
-        tracing::info!("Compiling {} files...", source_files.len());
-        let compilation = match self.compiler.check_files(&source_files, task.language) {
-            Ok(result) => {
-                tracing::info!(
-                    "Compilation {}: {} errors, {} warnings",
-                    if result.success { "succeeded" } else { "failed" },
-                    result.errors.len(),
-                    result.warnings.len()
-                );
-
-                if !result.errors.is_empty() {
-                    for err in &result.errors {
-                        tracing::warn!("Compilation error: {}", err);
-                    }
-                }
-                Some(result)
-            }
-            Err(e) => {
-                tracing::error!("Compilation check failed: {}", e);
-                None
-            }
-        };
+        let generated_files = self.code_extractor.to_source_files(&extracted_files);
 
-        // Run Claude's own tests (included in the generated code)
-        tracing::info!("Running tests...");
-        let tests = match self.test_runner.run_own_tests(&source_files, task.language) {
-            Ok(result) => {
-                tracing::info!(
-                    "Tests: {} passed, {} failed, {} total",
-                    result.passed,
-                    result.failed,
-                    result.total
-                );
-                Some(result)
-            }
-            Err(e) => {
-                tracing::error!("Test run failed: {}", e);
-                None
-            }
+        // Modify tasks: files the model left unchanged come from the starter code
+        let source_files = if task.starter.is_empty() {
+            generated_files.clone()
+        } else {
+            overlay_starter(&task.starter, &generated_files, task.language)
         };
+        // Tasks with dependencies: a manifest declaring them, if the model wrote none
+        let source_files = with_dependency_manifest(task, &source_files);
+        self.save_project(task, execution, &source_files);
 
-        // Combine all code for linting and annotation scoring
+        // Combine all code for leakage detection and the stored result
         let combined_code: String = extracted_files
             .iter()
             .map(|f| format!("// file: {}\n{}", f.path, f.code))
             .collect::<Vec<_>>()
             .join("\n\n");
 
-        let lint = Some(self.linter.lint(&combined_code));
-
-        // Score annotations (no expected list, just count what's present)
-        let annotation_score = Some(self.annotation_scorer.score(&combined_code, &[]));
+        let input = StageInput {
+            task,
+            execution,
+            extracted_files: &extracted_files,
+            generated_files: &generated_files,
+            source_files: &source_files,
+            combined_code: &combined_code,
+        };
+        for stage in self.enabled_stages() {
+            if let Err(e) = stage.run(&input, &mut result) {
+                tracing::warn!(
+                    "Evaluation stage {} failed for task {} (mode={}): {}",
+                    stage.name(),
+                    task.id,
+                    execution.mode.as_str(),
+                    e
+                );
+            }
+        }
 
-        Ok(EvaluationResult {
-            task_id: task.id.clone(),
-            mode: execution.mode.as_str().to_string(),
-            repetition: execution.repetition,
-            compilation,
-            tests,
-            lint,
-            annotation_score,
-            extracted_code: Some(combined_code),
-            extracted_files: Some(extracted_files),
-        })
+        result.extracted_code = Some(combined_code);
+        result.extracted_files = Some(extracted_files);
+        Ok(result)
     }
 }
 
@@ -200,6 +388,8 @@ fn extract_function_name(code: &str, language: crate::corpus::Language) -> Strin
         Language::Rust => r"fn\s+(\w+)",
         Language::Python => r"def\s+(\w+)",
         Language::TypeScript => r"function\s+(\w+)",
+        Language::Go => r"func\s+(?:\([^)]*\)\s*)?(\w+)",
+        Language::Java => r"\b\w+(?:<[^>]*>)?(?:\[\])*\s+(\w+)\s*\(",
     };
 
     regex::Regex::new(pattern)
@@ -231,4 +421,110 @@ mod tests {
             "calculate_sum"
         );
     }
+
+    /// Records the number of generated files as the complexity's function count
+    struct FileCount;
+
+    impl EvaluationStage for FileCount {
+        fn name(&self) -> &str {
+            "file_count"
+        }
+
+        fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+            // Runs after the built-in stages, so their results are visible
+            anyhow::ensure!(result.code_size.is_some(), "code_size has not run");
+            result.complexity = Some(Complexity {
+                functions: input.generated_files.len() as u32,
+                ..Default::default()
+            });
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_pipeline_runs_enabled_and_custom_stages() {
+        use crate::corpus::Language;
+        use crate::runner::PromptMode;
+
+        let task = Task {
+            id: "pipeline-task".to_string(),
+            name: "Pipeline Task".to_string(),
+            language: Language::Python,
+            ..Default::default()
+        };
+        let execution = ExecutionResult {
+            task_id: task.id.clone(),
+            mode: PromptMode::Baseline,
+            repetition: 0,
+            response: "```python\ndef add(a, b):\n    return a + b\n```".to_string(),
+            input_tokens: 0,
+            output_tokens: 0,
+            execution_time_ms: 0,
+            retries: 0,
+            cache_creation_input_tokens: 0,
+            cache_read_input_tokens: 0,
+            num_turns: None,
+            cost_usd: None,
+            stop_reason: "end_turn".to_string(),
+            repair_turns: vec![],
+        };
+
+        let evaluator = Evaluator::new().with_stage(FileCount);
+        // Skip everything that runs tools, and the complexity stage the custom one stands in for
+        let disabled_stages = evaluator
+            .stage_names()
+            .into_iter()
+            .filter(|name| !["code_size", "file_count"].contains(name))
+            .map(str::to_string)
+            .collect();
+        let evaluator = evaluator.with_evaluation_config(&EvaluationConfig {
+            disabled_stages,
+            ..Default::default()
+        });
+
+        let result = evaluator.evaluate(&task, &execution).unwrap();
+        assert_eq!(result.code_size.unwrap().files, 1);
+        assert_eq!(result.complexity.unwrap().functions, 1);
+        assert!(result.compilation.is_none() && result.lint.is_none());
+        assert!(result.extracted_code.unwrap().contains("def add"));
+    }
+
+    #[test]
+    fn test_missing_code_fails_hidden_tests() {
+        use crate::corpus::{HiddenTest, Language};
+        use crate::runner::PromptMode;
+
+        let hidden_test = |path: &str| HiddenTest {
+            path: path.to_string(),
+            code: "def test_add():\n    assert add(1, 2) == 3".to_string(),
+        };
+        let task = Task {
+            id: "no-code-task".to_string(),
+            language: Language::Python,
+            hidden_tests: vec![hidden_test("test_a.py"), hidden_test("test_b.py")],
+            ..Default::default()
+        };
+        let execution = ExecutionResult {
+            task_id: task.id.clone(),
+            mode: PromptMode::Baseline,
+            repetition: 0,
+            response: "I cannot write this function.".to_string(),
+            input_tokens: 0,
+            output_tokens: 0,
+            execution_time_ms: 0,
+            retries: 0,
+            cache_creation_input_tokens: 0,
+            cache_read_input_tokens: 0,
+            num_turns: None,
+            cost_usd: None,
+            stop_reason: "end_turn".to_string(),
+            repair_turns: vec![],
+        };
+
+        let result = Evaluator::new().evaluate(&task, &execution).unwrap();
+        let hidden_tests = result.hidden_tests.unwrap();
+        assert_eq!(hidden_tests.passed, 0);
+        assert_eq!(hidden_tests.failed, 2);
+        assert_eq!(hidden_tests.total, 2);
+    }
 }
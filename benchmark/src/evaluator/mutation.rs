@@ -0,0 +1,228 @@
+//! @ai:module:intent Mutation testing of generated test suites with cargo-mutants and mutmut
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api MutationTester, MutationTesterTrait, MutationScore
+//! @ai:module:depends_on evaluator::sandbox, evaluator::test_runner, evaluator::reference
+//! @ai:module:stateless true
+
+use crate::corpus::Language;
+use crate::evaluator::reference::is_test_file;
+use crate::evaluator::sandbox::Sandbox;
+use crate::evaluator::test_runner::write_project;
+use crate::evaluator::SourceFile;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tempfile::TempDir;
+
+/// @ai:intent Outcome of mutating one execution's code and re-running its own tests
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MutationScore {
+    /// Mutation tool that produced the outcome (cargo-mutants, mutmut)
+    pub tool: String,
+    /// Mutants that made a test fail
+    pub caught: u32,
+    /// Mutants every test still passed with
+    pub missed: u32,
+    /// Mutants that made the tests hang (counted as caught)
+    pub timeout: u32,
+    /// Mutants that did not build, or were skipped (not scored)
+    pub unviable: u32,
+}
+
+impl MutationScore {
+    /// @ai:intent Percentage of viable mutants the tests caught (including timeouts)
+    /// @ai:effects pure
+    pub fn score(&self) -> f64 {
+        let viable = self.caught + self.timeout + self.missed;
+        if viable == 0 {
+            0.0
+        } else {
+            (self.caught + self.timeout) as f64 / viable as f64 * 100.0
+        }
+    }
+}
+
+/// @ai:intent Trait for mutation testing
+pub trait MutationTesterTrait: Send + Sync {
+    /// @ai:intent Mutate the generated code and count the mutants its own tests catch
+    fn test(&self, files: &[SourceFile], language: Language) -> Result<MutationScore>;
+}
+
+/// @ai:intent Runs cargo-mutants (Rust) or mutmut (Python) on a project copy
+pub struct MutationTester {
+    sandbox: Sandbox,
+    timeout_secs: u64,
+}
+
+impl MutationTester {
+    /// @ai:intent Create a tester that runs on the host with a 60s timeout per mutant
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            sandbox: Sandbox::default().without_timeout(),
+            timeout_secs: 60,
+        }
+    }
+
+    /// @ai:intent Run the mutation tools in the given sandbox
+    ///            Without its wall-clock limit: a whole run takes far longer than one
+    ///            test command, and each mutant is bounded by timeout_secs instead.
+    ///            Without the build cache: cargo-mutants builds in its own copies
+    /// @ai:effects pure
+    pub fn with_sandbox(mut self, sandbox: Sandbox) -> Self {
+        self.sandbox = sandbox.without_timeout().without_build_cache();
+        self
+    }
+
+    /// @ai:intent Seconds each mutant's test run may take
+    /// @ai:effects pure
+    pub fn with_timeout_secs(mut self, timeout_secs: u64) -> Self {
+        self.timeout_secs = timeout_secs;
+        self
+    }
+
+    /// @ai:intent Run cargo-mutants and read mutants.out/outcomes.json
+    /// @ai:effects fs:read, io
+    fn cargo_mutants(&self, root: &Path) -> Result<MutationScore> {
+        let output = self
+            .sandbox
+            .command("cargo", root, Language::Rust)
+            .args(["mutants", "--no-shuffle", "--timeout"])
+            .arg(self.timeout_secs.to_string())
+            .output()
+            .map_err(|e| anyhow::anyhow!("cargo-mutants not available: {}", e))?;
+
+        let Ok(outcomes) = std::fs::read_to_string(root.join("mutants.out/outcomes.json")) else {
+            bail!(
+                "cargo-mutants failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            );
+        };
+        parse_cargo_mutants(&outcomes)
+    }
+
+    /// @ai:intent Run mutmut on the non-test modules and read its CI/CD stats
+    /// @ai:effects fs:write, fs:read, io
+    fn mutmut(&self, root: &Path, files: &[SourceFile]) -> Result<MutationScore> {
+        let (tests, sources): (Vec<_>, Vec<_>) = files
+            .iter()
+            .filter(|f| f.path.ends_with(".py"))
+            .partition(|f| is_test_file(&f.path, Language::Python));
+        if sources.is_empty() || tests.is_empty() {
+            bail!("mutmut needs both source and test modules");
+        }
+
+        let list = |files: &[&SourceFile]| {
+            files
+                .iter()
+                .map(|f| format!("    {}", f.path))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        std::fs::write(
+            root.join("setup.cfg"),
+            format!(
+                "[mutmut]\npaths_to_mutate=\n{}\ntests_dir=\n{}\n",
+                list(&sources),
+                list(&tests)
+            ),
+        )?;
+        self.sandbox.install_dependencies(root, Language::Python)?;
+
+        let run = self
+            .sandbox
+            .command("mutmut", root, Language::Python)
+            .arg("run")
+            .output()
+            .map_err(|e| anyhow::anyhow!("mutmut not available: {}", e))?;
+        self.sandbox
+            .command("mutmut", root, Language::Python)
+            .arg("export-cicd-stats")
+            .output()?;
+
+        let Ok(stats) = std::fs::read_to_string(root.join("mutants/mutmut-cicd-stats.json")) else {
+            bail!("mutmut failed: {}", String::from_utf8_lossy(&run.stderr));
+        };
+        parse_mutmut_stats(&stats)
+    }
+}
+
+impl Default for MutationTester {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MutationTesterTrait for MutationTester {
+    /// @ai:intent Mutate the generated code and count the mutants its own tests catch
+    ///            Fails if the tool is missing or no mutant could be scored
+    /// @ai:effects fs:write, io
+    fn test(&self, files: &[SourceFile], language: Language) -> Result<MutationScore> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        write_project(root, files, language)?;
+
+        let score = match language {
+            Language::Rust => self.cargo_mutants(root)?,
+            Language::Python => self.mutmut(root, files)?,
+            Language::TypeScript => bail!("mutation testing is not supported for TypeScript"),
+            Language::Go => bail!("mutation testing is not supported for Go"),
+            Language::Java => bail!("mutation testing is not supported for Java"),
+        };
+
+        if score.caught + score.timeout + score.missed == 0 {
+            bail!("{} generated no viable mutants", score.tool);
+        }
+        Ok(score)
+    }
+}
+
+/// @ai:intent Read the mutant counts of a cargo-mutants outcomes.json
+/// @ai:effects pure
+fn parse_cargo_mutants(outcomes: &str) -> Result<MutationScore> {
+    let json: serde_json::Value = serde_json::from_str(outcomes)?;
+    let count = |key: &str| json[key].as_u64().unwrap_or(0) as u32;
+
+    Ok(MutationScore {
+        tool: "cargo-mutants".to_string(),
+        caught: count("caught"),
+        missed: count("missed"),
+        timeout: count("timeout"),
+        unviable: count("unviable"),
+    })
+}
+
+/// @ai:intent Read the mutant counts of mutmut's mutmut-cicd-stats.json
+///            Mutants no test covers count as missed
+/// @ai:effects pure
+fn parse_mutmut_stats(stats: &str) -> Result<MutationScore> {
+    let json: serde_json::Value = serde_json::from_str(stats)?;
+    let count = |key: &str| json[key].as_u64().unwrap_or(0) as u32;
+
+    Ok(MutationScore {
+        tool: "mutmut".to_string(),
+        caught: count("killed") + count("segfault"),
+        missed: count("survived") + count("no_tests"),
+        timeout: count("timeout"),
+        unviable: count("skipped") + count("suspicious"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mutation_outcomes() {
+        let cargo = r#"{"outcomes": [], "total_mutants": 10, "missed": 2, "caught": 5, "timeout": 1, "unviable": 2, "success": 0, "failure": 0}"#;
+        let score = parse_cargo_mutants(cargo).unwrap();
+        assert_eq!(score.caught, 5);
+        assert_eq!(score.unviable, 2);
+        assert!((score.score() - 75.0).abs() < 0.01);
+
+        let mutmut = r#"{"killed": 6, "survived": 1, "total": 9, "no_tests": 1, "skipped": 1, "suspicious": 0, "timeout": 0, "check_was_interrupted_by_user": 0, "segfault": 0}"#;
+        let score = parse_mutmut_stats(mutmut).unwrap();
+        assert_eq!(score.missed, 2);
+        assert!((score.score() - 75.0).abs() < 0.01);
+    }
+}
@@ -0,0 +1,85 @@
+//! @ai:module:intent Extension point of the evaluator: ordered stages that each measure one
+//!                   aspect of an execution's code and record it in the evaluation result
+//! @ai:module:layer application
+//! @ai:module:public_api EvaluationStage, StageInput, default_stages
+//! @ai:module:depends_on config, corpus, runner, evaluator::stages
+//! @ai:module:stateless true
+
+use crate::config::EvaluationConfig;
+use crate::corpus::Task;
+use crate::evaluator::stages::{
+    AnnotationStage, ApiConformanceStage, CodeQualityStage, CodeSizeStage, CompilationStage,
+    ComplexityStage, ContaminationStage, ContractStage, HiddenTestStage, InferenceStage,
+    IoCaseStage, LeakageStage, LintStage, MutationStage, ReferenceStage, SecurityStage,
+    TestDesignStage, TestStage, TypeCheckStage,
+};
+use crate::evaluator::{EvaluationResult, ExtractedFile, Sandbox, SourceFile};
+use crate::runner::ExecutionResult;
+use anyhow::Result;
+
+/// @ai:intent What a stage evaluates: the task, the execution and the code extracted from it
+pub struct StageInput<'a> {
+    pub task: &'a Task,
+    pub execution: &'a ExecutionResult,
+    /// Code blocks extracted from the response (empty if there are none)
+    pub extracted_files: &'a [ExtractedFile],
+    /// Files the model wrote
+    pub generated_files: &'a [SourceFile],
+    /// The project evaluated: generated files over the task's starter code, with a manifest
+    /// declaring the task's dependencies if the model wrote none
+    pub source_files: &'a [SourceFile],
+    /// All extracted code, each file headed by a `// file:` comment
+    pub combined_code: &'a str,
+}
+
+/// @ai:intent One step of the evaluation pipeline. Stages run in order on each execution with
+///            code, so a stage can read the results of the stages before it (e.g. mutation
+///            testing reads the test results). Registered with Evaluator::with_stage and run
+///            after the built-in stages
+pub trait EvaluationStage: Send + Sync {
+    /// @ai:intent Short name used in logs and in [evaluation] disabled_stages
+    fn name(&self) -> &str;
+
+    /// @ai:intent Evaluate the code and record the outcome in result; an error is logged and
+    ///            leaves the rest of the pipeline running
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()>;
+
+    /// @ai:intent Record the outcome for an execution without code, where a missing result
+    ///            would read as "not applicable" rather than "failed" (default: nothing)
+    fn run_without_code(&self, _input: &StageInput, _result: &mut EvaluationResult) {}
+
+    /// @ai:intent Run tools in the given sandbox instead of on the host (default: ignored)
+    fn set_sandbox(&mut self, _sandbox: &Sandbox) {}
+
+    /// @ai:intent Apply the [evaluation] settings (default: ignored)
+    fn configure(&mut self, _config: &EvaluationConfig) {}
+
+    /// @ai:intent Receive every task of the corpus, not just the one evaluated (default: ignored)
+    fn set_corpus(&mut self, _tasks: &[Task]) {}
+}
+
+/// @ai:intent The built-in stages in run order
+/// @ai:effects pure
+pub fn default_stages() -> Vec<Box<dyn EvaluationStage>> {
+    vec![
+        Box::new(CodeSizeStage),
+        Box::new(ComplexityStage::default()),
+        Box::new(CompilationStage::default()),
+        Box::new(CodeQualityStage::default()),
+        Box::new(TypeCheckStage::default()),
+        Box::new(SecurityStage::default()),
+        Box::new(TestStage::default()),
+        Box::new(TestDesignStage::default()),
+        Box::new(MutationStage::default()),
+        Box::new(HiddenTestStage::default()),
+        Box::new(IoCaseStage::default()),
+        Box::new(ApiConformanceStage::default()),
+        Box::new(ReferenceStage::default()),
+        Box::new(InferenceStage::default()),
+        Box::new(ContractStage::default()),
+        Box::new(LintStage::default()),
+        Box::new(AnnotationStage::default()),
+        Box::new(LeakageStage::default()),
+        Box::new(ContaminationStage::default()),
+    ]
+}
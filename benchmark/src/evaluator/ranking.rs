@@ -0,0 +1,339 @@
+//! @ai:module:intent Comparison of more than two implementations of a task (models, skill
+//!                   variants, modes) by round-robin pairwise judgments or one ranked judgment
+//! @ai:module:layer application
+//! @ai:module:public_api Contestant, ContestantResult, PairwiseResult, TaskRanking, discover_contestants,
+//!                        rank_task
+//! @ai:module:depends_on config, evaluator::claude_scorer, platform
+//! @ai:module:stateless true
+
+use crate::config::RankingStrategy;
+use crate::evaluator::claude_scorer::{
+    ClaudeScorerTrait, ComparisonScore, ImplementationScore, RankedVerdict,
+};
+use crate::platform::portable_path;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// @ai:intent One implementation of a task taking part in a ranking
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contestant {
+    /// Run and mode that produced it, e.g. "claude-sonnet-4/aicms"
+    pub label: String,
+    pub dir: PathBuf,
+}
+
+/// @ai:intent One pairwise judgment of a round-robin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairwiseResult {
+    /// Contestant scored as `baseline` in the comparison
+    pub first: String,
+    /// Contestant scored as `aicms` in the comparison
+    pub second: String,
+    pub comparison: ComparisonScore,
+}
+
+impl PairwiseResult {
+    /// @ai:intent Label of the winning contestant, None for a tie
+    /// @ai:effects pure
+    pub fn winner(&self) -> Option<&str> {
+        match self.comparison.winner.as_str() {
+            "baseline" => Some(&self.first),
+            "aicms" => Some(&self.second),
+            _ => None,
+        }
+    }
+}
+
+/// @ai:intent Placement of one contestant in a task's ranking
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContestantResult {
+    pub label: String,
+    /// 1 = best; contestants that cannot be separated share a rank
+    pub rank: u32,
+    /// Overall score 0-100 (mean over its pairwise judgments)
+    pub score: f64,
+    /// Contestants it beat, lost to, or tied with
+    pub wins: u32,
+    pub losses: u32,
+    pub ties: u32,
+    /// Aspect scores from a ranked judgment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scores: Option<ImplementationScore>,
+}
+
+/// @ai:intent Ranking of all implementations of one task
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaskRanking {
+    pub task_id: String,
+    pub strategy: RankingStrategy,
+    /// Best first
+    pub contestants: Vec<ContestantResult>,
+    /// Judgments behind a pairwise ranking
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pairs: Vec<PairwiseResult>,
+    /// Judge's summary of a ranked judgment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+/// @ai:intent Find every implementation per task below a results directory
+///            Implementations live in <run>/<mode>/code/<task_id>/; the label is <run>/<mode>
+/// @ai:effects fs:read
+pub fn discover_contestants(root: &Path) -> Result<BTreeMap<String, Vec<Contestant>>> {
+    let mut tasks: BTreeMap<String, Vec<Contestant>> = BTreeMap::new();
+    let code_dirs = WalkDir::new(root)
+        .min_depth(2)
+        .max_depth(5)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| entry.file_type().is_dir())
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name() == "code");
+
+    for code_dir in code_dirs {
+        let Some(mode_dir) = code_dir.path().parent() else {
+            continue;
+        };
+        if !mode_dir.ends_with("baseline") && !mode_dir.ends_with("aicms") {
+            continue;
+        }
+        let label = portable_path(mode_dir.strip_prefix(root)?);
+
+        for entry in std::fs::read_dir(code_dir.path())? {
+            let entry = entry?;
+            if !entry.path().is_dir() {
+                continue;
+            }
+            let Some(task_id) = entry.file_name().to_str().map(str::to_string) else {
+                continue;
+            };
+            tasks.entry(task_id).or_default().push(Contestant {
+                label: label.clone(),
+                dir: entry.path(),
+            });
+        }
+    }
+
+    Ok(tasks)
+}
+
+/// @ai:intent Rank the implementations of a task with the given strategy
+///            Failed pairwise judgments are skipped; fails if none succeed
+/// @ai:pre contestants has at least two entries
+/// @ai:effects io, network, fs:read, fs:write
+pub fn rank_task(
+    scorer: &dyn ClaudeScorerTrait,
+    strategy: RankingStrategy,
+    task_id: &str,
+    task_spec: &str,
+    contestants: &[Contestant],
+) -> Result<TaskRanking> {
+    let labels: Vec<String> = contestants.iter().map(|c| c.label.clone()).collect();
+
+    match strategy {
+        RankingStrategy::Pairwise => {
+            let mut pairs = Vec::new();
+            for (i, first) in contestants.iter().enumerate() {
+                for second in &contestants[i + 1..] {
+                    match scorer.compare_dirs(task_spec, &first.dir, &second.dir) {
+                        Ok(comparison) => pairs.push(PairwiseResult {
+                            first: first.label.clone(),
+                            second: second.label.clone(),
+                            comparison,
+                        }),
+                        Err(e) => tracing::warn!(
+                            "Failed to compare {} with {} for task {}: {}",
+                            first.label,
+                            second.label,
+                            task_id,
+                            e
+                        ),
+                    }
+                }
+            }
+            if pairs.is_empty() {
+                bail!("every pairwise comparison failed");
+            }
+
+            Ok(TaskRanking {
+                task_id: task_id.to_string(),
+                strategy,
+                contestants: results_from_pairs(&labels, &pairs),
+                pairs,
+                summary: None,
+            })
+        }
+        RankingStrategy::Ranked => {
+            let dirs: Vec<&Path> = contestants.iter().map(|c| c.dir.as_path()).collect();
+            let verdict = scorer.rank_dirs(task_spec, &dirs)?;
+
+            Ok(TaskRanking {
+                task_id: task_id.to_string(),
+                strategy,
+                contestants: results_from_verdict(&labels, &verdict),
+                pairs: Vec::new(),
+                summary: Some(verdict.summary),
+            })
+        }
+    }
+}
+
+/// @ai:intent Rank contestants by net pairwise wins, then by mean score
+/// @ai:effects pure
+fn results_from_pairs(labels: &[String], pairs: &[PairwiseResult]) -> Vec<ContestantResult> {
+    let mut results: Vec<ContestantResult> = labels
+        .iter()
+        .map(|label| {
+            let mut result = ContestantResult {
+                label: label.clone(),
+                rank: 0,
+                score: 0.0,
+                wins: 0,
+                losses: 0,
+                ties: 0,
+                scores: None,
+            };
+            let mut scores = Vec::new();
+            for pair in pairs {
+                let score = if &pair.first == label {
+                    pair.comparison.baseline.overall
+                } else if &pair.second == label {
+                    pair.comparison.aicms.overall
+                } else {
+                    continue;
+                };
+                scores.push(score as f64);
+                match pair.winner() {
+                    Some(winner) if winner == label => result.wins += 1,
+                    Some(_) => result.losses += 1,
+                    None => result.ties += 1,
+                }
+            }
+            if !scores.is_empty() {
+                result.score = scores.iter().sum::<f64>() / scores.len() as f64;
+            }
+            result
+        })
+        .collect();
+
+    let key = |r: &ContestantResult| (r.wins as i64 - r.losses as i64, r.score);
+    let keys: Vec<_> = results.iter().map(key).collect();
+    for result in &mut results {
+        let own = key(result);
+        result.rank = 1 + keys
+            .iter()
+            .filter(|other| other.0 > own.0 || (other.0 == own.0 && other.1 > own.1))
+            .count() as u32;
+    }
+    results.sort_by(|a, b| a.rank.cmp(&b.rank).then_with(|| a.label.cmp(&b.label)));
+    results
+}
+
+/// @ai:intent Contestant results of a ranked judgment; a better rank counts as a win
+/// @ai:pre verdict ranks the contestants in the order of labels
+/// @ai:effects pure
+fn results_from_verdict(labels: &[String], verdict: &RankedVerdict) -> Vec<ContestantResult> {
+    let ranks: Vec<u32> = verdict.implementations.iter().map(|i| i.rank).collect();
+    let mut results: Vec<ContestantResult> = labels
+        .iter()
+        .zip(&verdict.implementations)
+        .map(|(label, implementation)| {
+            let rank = implementation.rank;
+            ContestantResult {
+                label: label.clone(),
+                rank,
+                score: implementation.score.overall as f64,
+                wins: ranks.iter().filter(|&&other| other > rank).count() as u32,
+                losses: ranks.iter().filter(|&&other| other < rank).count() as u32,
+                // Every contestant shares its rank with itself
+                ties: ranks.iter().filter(|&&other| other == rank).count() as u32 - 1,
+                scores: Some(implementation.score.clone()),
+            }
+        })
+        .collect();
+    results.sort_by(|a, b| a.rank.cmp(&b.rank).then_with(|| a.label.cmp(&b.label)));
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::claude_scorer::MockClaudeScorer;
+
+    fn contestants(labels: &[&str]) -> Vec<Contestant> {
+        labels
+            .iter()
+            .map(|label| Contestant {
+                label: label.to_string(),
+                dir: PathBuf::from(label),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_pairwise_round_robin() {
+        // The mock always prefers the second implementation of a pair
+        let scorer = MockClaudeScorer::with_defaults();
+        let ranking = rank_task(
+            &scorer,
+            RankingStrategy::Pairwise,
+            "t",
+            "spec",
+            &contestants(&["a", "b", "c"]),
+        )
+        .unwrap();
+
+        assert_eq!(ranking.pairs.len(), 3);
+        let order: Vec<(&str, u32, u32)> = ranking
+            .contestants
+            .iter()
+            .map(|c| (c.label.as_str(), c.rank, c.wins))
+            .collect();
+        assert_eq!(order, [("c", 1, 2), ("b", 2, 1), ("a", 3, 0)]);
+        assert_eq!(ranking.contestants[2].score, 70.0);
+        assert_eq!(ranking.contestants[1].score, 77.5);
+    }
+
+    #[test]
+    fn test_ranked_judgment_shares_ranks() {
+        let scorer = MockClaudeScorer::with_defaults();
+        let mut verdict = scorer
+            .rank_dirs("spec", &[Path::new("a"), Path::new("b"), Path::new("c")])
+            .unwrap();
+        verdict.implementations[0].rank = 2;
+        verdict.implementations[1].rank = 1;
+        verdict.implementations[2].rank = 2;
+
+        let labels: Vec<String> = ["a", "b", "c"].iter().map(|l| l.to_string()).collect();
+        let results = results_from_verdict(&labels, &verdict);
+        assert_eq!(results[0].label, "b");
+        assert_eq!((results[0].wins, results[0].losses), (2, 0));
+        assert_eq!((results[1].label.as_str(), results[1].ties), ("a", 1));
+        assert!(results[1].scores.is_some());
+    }
+
+    #[test]
+    fn test_discover_contestants() {
+        let root = tempfile::TempDir::new().unwrap();
+        for dir in [
+            "model-a/baseline/code/t1",
+            "model-a/aicms/code/t1",
+            "model-b/aicms/code/t1",
+            "model-b/aicms/code/t2",
+            "model-b/aicms/report/t2",
+        ] {
+            std::fs::create_dir_all(root.path().join(dir)).unwrap();
+        }
+
+        let tasks = discover_contestants(root.path()).unwrap();
+        let labels: Vec<&str> = tasks["t1"].iter().map(|c| c.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            ["model-a/aicms", "model-a/baseline", "model-b/aicms"]
+        );
+        assert_eq!(tasks["t2"].len(), 1);
+    }
+}
@@ -0,0 +1,276 @@
+//! @ai:module:intent Compare generated code with a task's gold reference solution
+//! @ai:module:layer application
+//! @ai:module:public_api ReferenceComparer, ReferenceComparerTrait, ReferenceAlignment
+//! @ai:module:depends_on evaluator::test_runner
+//! @ai:module:stateless true
+
+use crate::corpus::{Language, Task};
+use crate::evaluator::sandbox::Sandbox;
+use crate::evaluator::test_runner::{TestRunner, TestRunnerTrait};
+use crate::evaluator::SourceFile;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+
+/// @ai:intent How closely one execution matches the reference solution
+///            All rates are percentages (0-100)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReferenceAlignment {
+    /// Share of the reference's public items also defined by the generated code
+    pub api_match: f64,
+    /// Public items of the reference missing from the generated code
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing_api: Vec<String>,
+    /// Pass rate of the reference's test files run against the generated code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub behavior_pass_rate: Option<f64>,
+    /// Token overlap with the reference sources (only if the task opts in)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub similarity: Option<f64>,
+    /// Mean of the components above that were measured
+    pub score: f64,
+}
+
+/// @ai:intent Trait for reference solution comparison
+pub trait ReferenceComparerTrait: Send + Sync {
+    /// @ai:intent Compare the generated files with the task's reference (None without one)
+    fn compare(&self, task: &Task, source_files: &[SourceFile]) -> Option<ReferenceAlignment>;
+}
+
+/// @ai:intent Compares API surface, reference test behavior and (optionally) text
+pub struct ReferenceComparer {
+    test_runner: TestRunner,
+    token_regex: Regex,
+}
+
+impl ReferenceComparer {
+    /// @ai:intent Create a new reference comparer
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            test_runner: TestRunner::new(),
+            token_regex: Regex::new(r"[A-Za-z_][A-Za-z0-9_]*|[0-9]+|[^\sA-Za-z0-9_]").unwrap(),
+        }
+    }
+
+    /// @ai:intent Run the reference tests in the given sandbox
+    /// @ai:effects pure
+    pub fn with_sandbox(mut self, sandbox: Sandbox) -> Self {
+        self.test_runner = self.test_runner.with_sandbox(sandbox);
+        self
+    }
+
+    /// @ai:intent Occurrences of each token in text
+    /// @ai:effects pure
+    fn token_counts<'a>(&self, text: &'a str) -> HashMap<&'a str, u32> {
+        let mut counts = HashMap::new();
+        for token in self.token_regex.find_iter(text) {
+            *counts.entry(token.as_str()).or_default() += 1;
+        }
+        counts
+    }
+
+    /// @ai:intent Multiset token overlap (sum of minimum / sum of maximum counts) as a percentage
+    /// @ai:post 0.0 <= result <= 100.0
+    /// @ai:effects pure
+    fn similarity(&self, reference: &str, generated: &str) -> f64 {
+        let (reference, generated) = (self.token_counts(reference), self.token_counts(generated));
+
+        let (mut shared, mut union) = (0u32, 0u32);
+        for token in reference
+            .keys()
+            .chain(generated.keys())
+            .collect::<BTreeSet<_>>()
+        {
+            let a = reference.get(token).copied().unwrap_or(0);
+            let b = generated.get(token).copied().unwrap_or(0);
+            shared += a.min(b);
+            union += a.max(b);
+        }
+
+        if union == 0 {
+            100.0
+        } else {
+            shared as f64 / union as f64 * 100.0
+        }
+    }
+}
+
+impl Default for ReferenceComparer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReferenceComparerTrait for ReferenceComparer {
+    /// @ai:intent Compare the generated files with the task's reference solution
+    /// @ai:post result.is_some() == task.reference.is_some()
+    /// @ai:effects fs:write, io
+    fn compare(&self, task: &Task, source_files: &[SourceFile]) -> Option<ReferenceAlignment> {
+        let reference = task.reference.as_ref()?;
+        let language = task.language;
+
+        let (tests, sources): (Vec<_>, Vec<_>) = reference
+            .files
+            .iter()
+            .partition(|f| is_test_file(&f.path, language));
+        let reference_code = sources
+            .iter()
+            .map(|f| f.content.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let generated_code = source_files
+            .iter()
+            .map(|f| f.content.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let expected = public_items(&reference_code, language);
+        let defined = public_items(&generated_code, language);
+        let missing_api: Vec<String> = expected.difference(&defined).cloned().collect();
+        let api_match = if expected.is_empty() {
+            100.0
+        } else {
+            (expected.len() - missing_api.len()) as f64 / expected.len() as f64 * 100.0
+        };
+
+        let behavior_pass_rate = if tests.is_empty() {
+            None
+        } else {
+            let test_files: Vec<SourceFile> = tests
+                .iter()
+                .map(|f| SourceFile {
+                    path: f.path.clone(),
+                    content: f.content.clone(),
+                })
+                .collect();
+            match self
+                .test_runner
+                .run_files(source_files, &test_files, language)
+            {
+                Ok(result) => Some(result.pass_rate()),
+                Err(e) => {
+                    tracing::error!("Reference tests of {} failed to run: {}", task.id, e);
+                    None
+                }
+            }
+        };
+
+        let similarity = reference
+            .similarity
+            .then(|| self.similarity(&reference_code, &generated_code));
+
+        let components: Vec<f64> = [Some(api_match), behavior_pass_rate, similarity]
+            .into_iter()
+            .flatten()
+            .collect();
+        let score = components.iter().sum::<f64>() / components.len() as f64;
+
+        Some(ReferenceAlignment {
+            api_match,
+            missing_api,
+            behavior_pass_rate,
+            similarity,
+            score,
+        })
+    }
+}
+
+/// @ai:intent Whether a reference file holds tests rather than implementation
+/// @ai:effects pure
+/// @ai:example ("tests/reference.rs", Rust) -> true
+/// @ai:example ("test_stack.py", Python) -> true
+pub(crate) fn is_test_file(path: &str, language: Language) -> bool {
+    let path = path.trim_start_matches("./").replace('\\', "/");
+    let name = path.rsplit('/').next().unwrap_or(&path);
+    match language {
+        Language::Rust => path.starts_with("tests/"),
+        Language::Python => name.starts_with("test_") || name.ends_with("_test.py"),
+        Language::TypeScript => name.contains(".test.") || name.contains(".spec."),
+        Language::Go => name.ends_with("_test.go"),
+        // Maven/Gradle test tree, or the JUnit naming convention
+        Language::Java => {
+            path.starts_with("src/test/")
+                || path.contains("/src/test/")
+                || name.ends_with("Test.java")
+                || name.ends_with("Tests.java")
+        }
+    }
+}
+
+/// @ai:intent Names of the public items (functions, types, constants) defined in code
+/// @ai:effects pure
+fn public_items(code: &str, language: Language) -> BTreeSet<String> {
+    let pattern = match language {
+        Language::Rust => {
+            r"(?m)^\s*pub\s+(?:async\s+)?(?:fn|struct|enum|trait|type|const|static)\s+([A-Za-z_]\w*)"
+        }
+        Language::Python => r"(?m)^\s*(?:async\s+)?(?:def|class)\s+([A-Za-z]\w*)",
+        Language::TypeScript => {
+            r"(?m)^\s*export\s+(?:default\s+)?(?:async\s+)?(?:function|class|interface|type|enum|const|let)\s+([A-Za-z_$][\w$]*)"
+        }
+        // Exported names start with a capital letter
+        Language::Go => r"(?m)^(?:func\s+(?:\([^)]*\)\s*)?|type\s+|const\s+|var\s+)([A-Z]\w*)",
+        // Public types, methods, constructors and fields
+        Language::Java => {
+            r"(?m)^\s*public\s+(?:(?:static|final|abstract|sealed|default|synchronized)\s+)*(?:<[^>]*>\s*)?(?:(?:class|interface|enum|record|[\w.]+(?:<[^;{}()=]*>)?(?:\[\])*)\s+)?([A-Za-z_]\w*)"
+        }
+    };
+
+    Regex::new(pattern)
+        .unwrap()
+        .captures_iter(code)
+        .filter_map(|cap| cap.get(1))
+        .map(|m| m.as_str().to_string())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::corpus::{ReferenceSolution, StarterFile};
+
+    #[test]
+    fn test_api_match_and_similarity() {
+        let mut task = Task {
+            id: "impl-python-stack".to_string(),
+            name: "Stack".to_string(),
+            language: Language::Python,
+            ..Default::default()
+        };
+        task.reference = Some(ReferenceSolution {
+            files: vec![StarterFile {
+                path: "stack.py".to_string(),
+                content: "class Stack:\n    def push(self, x):\n        pass\n\ndef peek(s):\n    return s[-1]\n".to_string(),
+            }],
+            similarity: true,
+        });
+        let generated = vec![SourceFile {
+            path: "stack.py".to_string(),
+            content: "class Stack:\n    def push(self, x):\n        pass\n".to_string(),
+        }];
+
+        let alignment = ReferenceComparer::new().compare(&task, &generated).unwrap();
+        assert!((alignment.api_match - 200.0 / 3.0).abs() < 0.01);
+        assert_eq!(alignment.missing_api, vec!["peek".to_string()]);
+        assert_eq!(alignment.behavior_pass_rate, None);
+        let similarity = alignment.similarity.unwrap();
+        assert!(similarity > 0.0 && similarity < 100.0);
+        assert!((alignment.score - (alignment.api_match + similarity) / 2.0).abs() < 0.01);
+
+        task.reference = None;
+        assert_eq!(ReferenceComparer::new().compare(&task, &generated), None);
+    }
+
+    #[test]
+    fn test_reference_test_files_are_recognized() {
+        assert!(is_test_file("tests/reference.rs", Language::Rust));
+        assert!(!is_test_file("src/lib.rs", Language::Rust));
+        assert!(is_test_file("pkg/test_stack.py", Language::Python));
+        assert!(!is_test_file("stack.py", Language::Python));
+        assert!(is_test_file("stack.test.ts", Language::TypeScript));
+
+        let items = public_items("pub fn a() {}\nfn b() {}\npub struct C;", Language::Rust);
+        assert_eq!(items.into_iter().collect::<Vec<_>>(), vec!["C", "a"]);
+    }
+}
@@ -0,0 +1,171 @@
+//! @ai:module:intent Turn evaluation results into feedback for multi-turn repair
+//! @ai:module:layer application
+//! @ai:module:public_api repair_check
+//! @ai:module:depends_on evaluator, runner::repair
+//! @ai:module:stateless true
+
+use crate::corpus::Task;
+use crate::evaluator::{EvaluationResult, Evaluator};
+use crate::runner::{ExecutionResult, RepairCheck, RepairCheckerTrait};
+
+/// Compiler errors quoted back to the model per turn
+const MAX_FEEDBACK_ERRORS: usize = 20;
+
+/// Tail of the test output quoted back to the model, in bytes
+const MAX_TEST_OUTPUT: usize = 4000;
+
+impl RepairCheckerTrait for Evaluator {
+    /// @ai:intent Evaluate the turn's response and describe what to fix
+    /// @ai:effects fs:write, io
+    fn check(&self, task: &Task, execution: &ExecutionResult) -> RepairCheck {
+        match self.evaluate(task, execution) {
+            Ok(eval) => repair_check(&eval),
+            Err(e) => {
+                tracing::error!("Repair check failed for {}: {}", task.id, e);
+                RepairCheck {
+                    compiled: false,
+                    test_pass_rate: 0.0,
+                    feedback: None,
+                }
+            }
+        }
+    }
+}
+
+/// @ai:intent Summarize an evaluation as a repair check
+///            Feedback covers missing code, compile errors, and failing tests;
+///            a compiling response with no failing tests needs no repair
+/// @ai:effects pure
+pub fn repair_check(eval: &EvaluationResult) -> RepairCheck {
+    let compiled = eval.compilation.as_ref().is_some_and(|c| c.success);
+    let test_pass_rate = eval.tests.as_ref().map_or(0.0, |t| t.pass_rate());
+
+    let feedback = if eval.extracted_files.is_none() {
+        Some(
+            "No code blocks were found in your answer. Return the complete implementation \
+             in fenced code blocks."
+                .to_string(),
+        )
+    } else if let Some(compilation) = eval.compilation.as_ref().filter(|c| !c.success) {
+        let mut errors: Vec<&str> = compilation
+            .errors
+            .iter()
+            .take(MAX_FEEDBACK_ERRORS)
+            .map(String::as_str)
+            .collect();
+        if compilation.errors.len() > MAX_FEEDBACK_ERRORS {
+            errors.push("...");
+        }
+        Some(format!(
+            "The code does not compile:\n\n```\n{}\n```",
+            errors.join("\n")
+        ))
+    } else {
+        eval.tests.as_ref().filter(|t| t.failed > 0).map(|tests| {
+            format!(
+                "{} of {} tests failed:\n\n```\n{}\n```",
+                tests.failed,
+                tests.total,
+                tail(&tests.output, MAX_TEST_OUTPUT).trim()
+            )
+        })
+    };
+
+    RepairCheck {
+        compiled,
+        test_pass_rate,
+        feedback,
+    }
+}
+
+/// @ai:intent Last max_len bytes of s, cut at a character boundary
+/// @ai:effects pure
+fn tail(s: &str, max_len: usize) -> &str {
+    let mut start = s.len().saturating_sub(max_len);
+    while !s.is_char_boundary(start) {
+        start += 1;
+    }
+    &s[start..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::{CompilationResult, ExtractedFile, TestResult};
+
+    fn evaluation(compilation: CompilationResult, tests: Option<TestResult>) -> EvaluationResult {
+        EvaluationResult {
+            task_id: "t1".to_string(),
+            mode: "aicms".to_string(),
+            repetition: 0,
+            compilation: Some(compilation),
+            tests,
+            test_determinism: None,
+            hidden_tests: None,
+            io_cases: None,
+            api_conformance: None,
+            reference: None,
+            inference: None,
+            code_quality: None,
+            type_check: None,
+            security: None,
+            complexity: None,
+            code_size: None,
+            mutation: None,
+            test_design: None,
+            lint: None,
+            annotation_score: None,
+            spec_leakage: None,
+            contamination: None,
+            fingerprint: None,
+            contract_checks: vec![],
+            custom: Default::default(),
+            extracted_code: Some("fn f() {}".to_string()),
+            extracted_files: Some(vec![ExtractedFile {
+                path: "lib.rs".to_string(),
+                code: "fn f() {}".to_string(),
+                language: None,
+            }]),
+        }
+    }
+
+    #[test]
+    fn test_repair_check_feedback() {
+        let broken = evaluation(
+            CompilationResult {
+                success: false,
+                errors: vec!["error[E0425]: cannot find value `x`".to_string()],
+                warnings: vec![],
+                diagnostics: vec![],
+            },
+            None,
+        );
+        let check = repair_check(&broken);
+        assert!(!check.compiled);
+        assert!(check.feedback.unwrap().contains("E0425"));
+
+        let failing = evaluation(
+            CompilationResult {
+                success: true,
+                errors: vec![],
+                warnings: vec![],
+                diagnostics: vec![],
+            },
+            Some(TestResult {
+                passed: 1,
+                failed: 1,
+                total: 2,
+                output: "test add ... FAILED".to_string(),
+            }),
+        );
+        let check = repair_check(&failing);
+        assert!((check.test_pass_rate - 50.0).abs() < 0.01);
+        assert!(check.feedback.unwrap().starts_with("1 of 2 tests failed"));
+
+        let passing = EvaluationResult {
+            tests: None,
+            ..failing
+        };
+        assert_eq!(repair_check(&passing).feedback, None);
+    }
+}
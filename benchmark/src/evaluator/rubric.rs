@@ -0,0 +1,311 @@
+//! @ai:module:intent Data-driven rubric for Claude comparisons: aspects, descriptions and weights
+//! @ai:module:layer domain
+//! @ai:module:public_api Rubric, RubricAspect
+//! @ai:module:depends_on evaluator::claude_scorer
+//! @ai:module:stateless true
+
+use crate::evaluator::claude_scorer::ImplementationScore;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// @ai:intent One scored aspect of the comparison rubric
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RubricAspect {
+    /// JSON key of the aspect in the judge's answer (e.g. "intent_match")
+    pub name: String,
+    /// Heading shown to the judge; defaults to the name
+    #[serde(default)]
+    pub label: String,
+    /// What the judge should assess for this aspect
+    pub description: String,
+    /// Relative weight in the overall score
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+/// @ai:intent Aspects the judge scores; drives the prompt and the expected answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Rubric {
+    pub aspects: Vec<RubricAspect>,
+}
+
+impl Default for Rubric {
+    fn default() -> Self {
+        let aspect = |name: &str, label: &str, description: &str| RubricAspect {
+            name: name.to_string(),
+            label: label.to_string(),
+            description: description.to_string(),
+            weight: default_weight(),
+        };
+        Self {
+            aspects: vec![
+                aspect(
+                    "intent_match",
+                    "Intent Match",
+                    "Does the actual implementation correctly fulfill the specified task? \
+                     (Ignore @ai:intent comments)",
+                ),
+                aspect(
+                    "edge_cases",
+                    "Edge Cases",
+                    "Are edge cases handled correctly in the code? \
+                     (Ignore @ai:pre/@ai:post comments)",
+                ),
+                aspect(
+                    "code_quality",
+                    "Code Quality",
+                    "Is the code clean, readable, and well-structured?",
+                ),
+                aspect(
+                    "error_handling",
+                    "Error Handling",
+                    "Does the code properly handle errors and invalid inputs?",
+                ),
+            ],
+        }
+    }
+}
+
+impl Rubric {
+    /// @ai:intent Load a rubric from a TOML file, or JSON if the extension is .json
+    /// @ai:effects fs:read
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read rubric {}", path.display()))?;
+        let rubric: Self = if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&content)?
+        } else {
+            toml::from_str(&content)?
+        };
+        rubric
+            .validate()
+            .with_context(|| format!("Invalid rubric {}", path.display()))?;
+        Ok(rubric)
+    }
+
+    /// @ai:intent Check that aspects are present, uniquely named, and weighted sensibly
+    /// @ai:effects pure
+    pub fn validate(&self) -> Result<()> {
+        if self.aspects.is_empty() {
+            bail!("rubric has no aspects");
+        }
+        for (i, aspect) in self.aspects.iter().enumerate() {
+            let valid_name = !aspect.name.is_empty()
+                && aspect
+                    .name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+            if !valid_name || aspect.name == "overall" {
+                bail!(
+                    "aspect name {:?} must be lowercase letters, digits and '_' (and not \"overall\")",
+                    aspect.name
+                );
+            }
+            if self.aspects[..i].iter().any(|a| a.name == aspect.name) {
+                bail!("aspect {:?} is defined twice", aspect.name);
+            }
+            if !aspect.weight.is_finite() || aspect.weight < 0.0 {
+                bail!("aspect {:?} has a negative weight", aspect.name);
+            }
+        }
+        if self.aspects.iter().map(|a| a.weight).sum::<f64>() <= 0.0 {
+            bail!("aspect weights sum to zero");
+        }
+        Ok(())
+    }
+
+    /// @ai:intent Fill the {{CRITERIA}} and {{OUTPUT_FORMAT}} placeholders of a prompt template
+    /// @ai:effects pure
+    pub fn render(&self, template: &str) -> String {
+        template
+            .replace("{{CRITERIA}}", &self.criteria())
+            .replace("{{OUTPUT_FORMAT}}", &self.output_format())
+    }
+
+    /// @ai:intent Fill the placeholders of a ranking prompt for n implementations
+    /// @ai:effects pure
+    pub fn render_ranking(&self, template: &str, implementations: usize) -> String {
+        template
+            .replace("{{CRITERIA}}", &self.criteria())
+            .replace("{{OUTPUT_FORMAT}}", &self.ranking_format(implementations))
+    }
+
+    /// @ai:intent Numbered list of the aspects with their weights
+    /// @ai:effects pure
+    /// @ai:example (default rubric) -> "1. **Intent Match** (weight 1): Does the actual ..."
+    fn criteria(&self) -> String {
+        self.aspects
+            .iter()
+            .enumerate()
+            .map(|(i, aspect)| {
+                format!(
+                    "{}. **{}** (weight {}): {}",
+                    i + 1,
+                    aspect.label(),
+                    aspect.weight,
+                    aspect.description
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// @ai:intent JSON skeleton the judge must answer with
+    /// @ai:effects pure
+    fn output_format(&self) -> String {
+        let implementation = self.aspect_fields("    ");
+        format!(
+            "{{\n  \"implementation_a\": {{\n{0}\n  }},\n  \"implementation_b\": {{\n{0}\n  }},\n  \
+             \"winner\": \"<a|b|tie>\",\n  \
+             \"summary\": \"<one sentence comparing the two implementations>\"\n}}",
+            implementation
+        )
+    }
+
+    /// @ai:intent JSON skeleton for ranking n implementations numbered from 1
+    /// @ai:effects pure
+    fn ranking_format(&self, implementations: usize) -> String {
+        let fields = self.aspect_fields("      ");
+        let scores = (1..=implementations)
+            .map(|n| format!("    \"{}\": {{\n{}\n    }}", n, fields))
+            .collect::<Vec<_>>()
+            .join(",\n");
+        format!(
+            "{{\n  \"implementations\": {{\n{}\n  }},\n  \
+             \"ranking\": [\"<number of the best implementation>\", \"...\", \"<number of the worst>\"],\n  \
+             \"summary\": \"<one sentence comparing the implementations>\"\n}}",
+            scores
+        )
+    }
+
+    /// @ai:intent One "name": {score, reason} line per aspect, comma-separated
+    /// @ai:effects pure
+    fn aspect_fields(&self, indent: &str) -> String {
+        self.aspects
+            .iter()
+            .map(|aspect| {
+                format!(
+                    "{}\"{}\": {{\"score\": <0-100>, \"reason\": \"<brief reason>\"}}",
+                    indent, aspect.name
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",\n")
+    }
+
+    /// @ai:intent Check that every aspect was scored and set overall to their weighted mean
+    /// @ai:effects pure
+    pub fn apply(&self, score: &mut ImplementationScore) -> Result<()> {
+        let mut weighted = 0.0;
+        let mut total_weight = 0.0;
+        for aspect in &self.aspects {
+            let Some(aspect_score) = score.aspects.get(&aspect.name) else {
+                bail!("Judge response lacks the rubric aspect {:?}", aspect.name);
+            };
+            weighted += aspect.weight * aspect_score.score.min(100) as f64;
+            total_weight += aspect.weight;
+        }
+        score.overall = (weighted / total_weight).round() as u8;
+        Ok(())
+    }
+}
+
+impl RubricAspect {
+    /// @ai:intent Heading for the aspect, falling back to its name
+    /// @ai:effects pure
+    pub fn label(&self) -> &str {
+        if self.label.is_empty() {
+            &self.name
+        } else {
+            &self.label
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::claude_scorer::AspectScore;
+
+    #[test]
+    fn test_load_toml_rubric() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("rubric.toml");
+        std::fs::write(
+            &path,
+            r#"
+[[aspects]]
+name = "correctness"
+description = "Does it work?"
+weight = 3
+
+[[aspects]]
+name = "readability"
+label = "Readability"
+description = "Is it easy to read?"
+"#,
+        )
+        .unwrap();
+
+        let rubric = Rubric::load(&path).unwrap();
+        assert_eq!(rubric.aspects.len(), 2);
+        assert_eq!(rubric.aspects[0].label(), "correctness");
+        assert_eq!(rubric.aspects[1].weight, 1.0);
+
+        let prompt = rubric.render("{{CRITERIA}}\n{{OUTPUT_FORMAT}}");
+        assert!(prompt.contains("1. **correctness** (weight 3): Does it work?"));
+        assert!(prompt.contains("\"readability\": {\"score\""));
+        assert!(!prompt.contains("intent_match"));
+
+        let json = dir.path().join("rubric.json");
+        std::fs::write(
+            &json,
+            r#"{"aspects": [{"name": "overall", "description": "x"}]}"#,
+        )
+        .unwrap();
+        assert!(Rubric::load(&json).is_err());
+    }
+
+    #[test]
+    fn test_apply_weights_and_requires_aspects() {
+        let rubric = Rubric {
+            aspects: vec![
+                RubricAspect {
+                    name: "correctness".to_string(),
+                    label: String::new(),
+                    description: String::new(),
+                    weight: 3.0,
+                },
+                RubricAspect {
+                    name: "readability".to_string(),
+                    label: String::new(),
+                    description: String::new(),
+                    weight: 1.0,
+                },
+            ],
+        };
+        let aspect = |score| AspectScore {
+            score,
+            reason: String::new(),
+        };
+
+        let mut score = ImplementationScore {
+            overall: 0,
+            aspects: [
+                ("correctness".to_string(), aspect(80)),
+                ("readability".to_string(), aspect(40)),
+            ]
+            .into(),
+        };
+        rubric.apply(&mut score).unwrap();
+        assert_eq!(score.overall, 70);
+
+        score.aspects.remove("readability");
+        assert!(rubric.apply(&mut score).is_err());
+    }
+}
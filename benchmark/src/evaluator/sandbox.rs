@@ -0,0 +1,852 @@
+//! @ai:module:intent Run compiler and test commands on the host or in a throwaway Docker container,
+//!                   with a timeout, an output cap, CPU/memory limits, a Rust build cache, and
+//!                   offline access to vendored third-party packages
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api Sandbox, SandboxCommand
+//! @ai:module:depends_on config, corpus, runner::process
+//! @ai:module:stateless false
+
+use crate::config::{RustBuildCache, SandboxConfig, SandboxKind};
+use crate::corpus::Language;
+use crate::platform::resolve_executable;
+use crate::runner::{timeout_from_secs, ProcessTree};
+use std::ffi::OsStr;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tempfile::TempDir;
+use walkdir::WalkDir;
+
+/// Where the work directory is mounted inside the container
+const CONTAINER_WORKDIR: &str = "/work";
+
+/// Where the leased target directory is mounted inside the container
+const CONTAINER_TARGET_DIR: &str = "/cache/target";
+
+/// Where the [sandbox.vendor] directories are mounted inside the container
+const CONTAINER_VENDOR_DIR: &str = "/vendor";
+
+/// Where Python dependencies are installed in the work directory (put on PYTHONPATH)
+const PYTHON_DEPS_DIR: &str = ".deps";
+
+/// Processes a container may run; stops fork bombs in generated tests
+const CONTAINER_PIDS_LIMIT: u32 = 512;
+
+/// @ai:intent Where evaluation commands run, and the limits they run under
+///            Generated code (build.rs, tests) is arbitrary; Docker keeps it off the host.
+///            The default runs on the host with the default limits and no build cache
+#[derive(Debug, Clone, Default)]
+pub struct Sandbox {
+    config: SandboxConfig,
+    /// Shared by every clone, so all cargo commands of a run draw from one pool
+    target_dirs: Option<Arc<SharedTargetDirs>>,
+}
+
+impl Sandbox {
+    /// @ai:intent Sandbox selected by the [sandbox] section
+    ///            With rust_build_cache = "shared-target", creates the run's target directories
+    /// @ai:effects fs:write
+    pub fn from_config(config: &SandboxConfig) -> Self {
+        let target_dirs = (config.rust_build_cache == RustBuildCache::SharedTarget)
+            .then(SharedTargetDirs::new)
+            .and_then(|created| {
+                created
+                    .map_err(|e| tracing::warn!("No shared Rust target directory: {}", e))
+                    .ok()
+            })
+            .map(Arc::new);
+
+        Self {
+            config: config.clone(),
+            target_dirs,
+        }
+    }
+
+    /// @ai:intent The same sandbox without the wall-clock limit, for commands that
+    ///            enforce their own per-step timeouts (mutation testing)
+    /// @ai:effects pure
+    pub fn without_timeout(mut self) -> Self {
+        self.config.timeout_secs = 0;
+        self
+    }
+
+    /// @ai:intent The same sandbox with another wall-clock limit (0 = none), for commands
+    ///            with their own time budget (I/O cases)
+    /// @ai:effects pure
+    pub fn with_timeout_secs(mut self, secs: u64) -> Self {
+        self.config.timeout_secs = secs;
+        self
+    }
+
+    /// @ai:intent The same sandbox without the Rust build cache, for tools that manage
+    ///            their own build directories (cargo-mutants)
+    /// @ai:effects pure
+    pub fn without_build_cache(mut self) -> Self {
+        self.config.rust_build_cache = RustBuildCache::Off;
+        self.target_dirs = None;
+        self
+    }
+
+    /// @ai:intent Path of the JUnit console launcher where commands run, if configured
+    /// @ai:effects pure
+    pub fn junit_jar(&self) -> Option<&str> {
+        self.config.junit_jar.as_deref()
+    }
+
+    /// @ai:intent Install the project's third-party packages from the vendored ones, offline
+    ///            Python: requirements.txt into .deps, which Python commands have on
+    ///            PYTHONPATH; TypeScript: package.json into node_modules. Does nothing without
+    ///            the manifest or the [sandbox.vendor] directory; a failed install is logged
+    ///            and shows up as import errors when the code runs
+    /// @ai:effects fs:write, io
+    pub fn install_dependencies(&self, work_dir: &Path, language: Language) -> io::Result<()> {
+        let vendor = &self.config.vendor;
+        let (program, args) = match (language, &vendor.wheels, &vendor.npm_cache) {
+            (Language::Python, Some(wheels), _) if work_dir.join("requirements.txt").exists() => (
+                "python",
+                vec![
+                    "-m".to_string(),
+                    "pip".into(),
+                    "install".into(),
+                    "--quiet".into(),
+                    "--disable-pip-version-check".into(),
+                    "--no-index".into(),
+                    "--find-links".into(),
+                    self.vendor_path(wheels, "wheels"),
+                    "--target".into(),
+                    PYTHON_DEPS_DIR.into(),
+                    "-r".into(),
+                    "requirements.txt".into(),
+                ],
+            ),
+            (Language::TypeScript, _, Some(cache)) if work_dir.join("package.json").exists() => (
+                "npm",
+                vec![
+                    "install".to_string(),
+                    "--offline".into(),
+                    "--cache".into(),
+                    self.vendor_path(cache, "npm"),
+                    "--ignore-scripts".into(),
+                    "--no-audit".into(),
+                    "--no-fund".into(),
+                ],
+            ),
+            _ => return Ok(()),
+        };
+
+        let output = self
+            .command(program, work_dir, language)
+            .args(args)
+            .output()?;
+        if !output.status.success() {
+            tracing::warn!(
+                "{} could not install the vendored dependencies: {}",
+                program,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+        Ok(())
+    }
+
+    /// @ai:intent Command that runs program with work_dir as working directory
+    ///            program is a tool on PATH or "./name" for a file in work_dir; arguments
+    ///            added by the caller must be paths relative to work_dir, since host paths
+    ///            do not exist inside the container
+    ///            The command leads its own process group, so the first Ctrl-C of a run
+    ///            (a graceful stop) does not abort the evaluation in progress, and a
+    ///            timeout kills everything it started
+    /// @ai:effects env, fs:read
+    pub fn command(&self, program: &str, work_dir: &Path, language: Language) -> SandboxCommand {
+        let target_dir = self.lease_target_dir(program);
+        let mut cmd = self.base_command(
+            program,
+            work_dir,
+            language,
+            target_dir.as_ref().map(TargetDirLease::path),
+        );
+        ProcessTree::configure(&mut cmd);
+        SandboxCommand {
+            cmd,
+            program: program.to_string(),
+            timeout: timeout_from_secs(self.config.timeout_secs),
+            max_output_bytes: self.config.max_output_bytes,
+            target_dir,
+            cargo_config: (program == "cargo")
+                .then(|| self.cargo_vendor_config())
+                .flatten(),
+            work_dir: work_dir.to_path_buf(),
+            stdin: None,
+        }
+    }
+
+    /// @ai:intent Where a vendored directory is seen by commands: the host path, or its
+    ///            mount point in the container
+    /// @ai:effects pure
+    fn vendor_path(&self, host_path: &Path, name: &str) -> String {
+        match self.config.kind {
+            SandboxKind::Host => host_path.display().to_string(),
+            SandboxKind::Docker => format!("{}/{}", CONTAINER_VENDOR_DIR, name),
+        }
+    }
+
+    /// @ai:intent Cargo configuration that replaces crates.io with the vendored crates and
+    ///            turns off network access (None without [sandbox.vendor] cargo)
+    /// @ai:effects pure
+    fn cargo_vendor_config(&self) -> Option<String> {
+        let vendored = self.config.vendor.cargo.as_ref()?;
+        let directory = toml::Value::String(self.vendor_path(vendored, "cargo"));
+        Some(format!(
+            "[source.crates-io]\nreplace-with = \"vendored-sources\"\n\n\
+             [source.vendored-sources]\ndirectory = {}\n\n[net]\noffline = true\n",
+            directory
+        ))
+    }
+
+    /// @ai:intent Environment that puts installed Python dependencies on the import path
+    ///            (empty for other languages, and without [sandbox.vendor] wheels)
+    /// @ai:effects pure
+    fn vendor_env(&self, work_dir: &Path, language: Language) -> Vec<(&'static str, String)> {
+        if language != Language::Python || self.config.vendor.wheels.is_none() {
+            return vec![];
+        }
+        let deps = match self.config.kind {
+            SandboxKind::Host => work_dir.join(PYTHON_DEPS_DIR).display().to_string(),
+            SandboxKind::Docker => format!("{}/{}", CONTAINER_WORKDIR, PYTHON_DEPS_DIR),
+        };
+        vec![("PYTHONPATH", deps)]
+    }
+
+    /// @ai:intent A shared target directory for the command, if program is cargo and the
+    ///            shared-target cache is in use (None, with a warning, if none can be created)
+    /// @ai:effects fs:write
+    fn lease_target_dir(&self, program: &str) -> Option<TargetDirLease> {
+        let pool = self.target_dirs.as_ref().filter(|_| program == "cargo")?;
+        SharedTargetDirs::lease(pool)
+            .map_err(|e| tracing::warn!("No shared Rust target directory: {}", e))
+            .ok()
+    }
+
+    /// @ai:intent Environment that points cargo at the build cache (empty for other programs)
+    /// @ai:effects pure
+    fn build_cache_env(
+        &self,
+        program: &str,
+        target_dir: Option<&Path>,
+    ) -> Vec<(&'static str, String)> {
+        if program != "cargo" {
+            return vec![];
+        }
+        match (self.config.rust_build_cache, target_dir, self.config.kind) {
+            (RustBuildCache::Sccache, _, _) => vec![("RUSTC_WRAPPER", "sccache".to_string())],
+            (RustBuildCache::SharedTarget, Some(target_dir), SandboxKind::Host) => {
+                vec![("CARGO_TARGET_DIR", target_dir.display().to_string())]
+            }
+            (RustBuildCache::SharedTarget, Some(_), SandboxKind::Docker) => {
+                vec![("CARGO_TARGET_DIR", CONTAINER_TARGET_DIR.to_string())]
+            }
+            _ => vec![],
+        }
+    }
+
+    /// @ai:intent Command for program before process-group setup
+    /// @ai:effects env, fs:read
+    fn base_command(
+        &self,
+        program: &str,
+        work_dir: &Path,
+        language: Language,
+        target_dir: Option<&Path>,
+    ) -> Command {
+        let mut env = self.build_cache_env(program, target_dir);
+        env.extend(self.vendor_env(work_dir, language));
+        match self.config.kind {
+            SandboxKind::Host => {
+                // Windows resolves a relative program against our directory, not work_dir
+                let mut cmd = match program.strip_prefix("./") {
+                    Some(local) => Command::new(work_dir.join(local)),
+                    None => Command::new(resolve_executable(program)),
+                };
+                cmd.current_dir(work_dir).envs(env);
+                #[cfg(unix)]
+                set_rlimits(
+                    &mut cmd,
+                    self.config.cpu_limit_secs,
+                    self.config.memory_limit_mb,
+                );
+                cmd
+            }
+            SandboxKind::Docker => {
+                let mut cmd = Command::new("docker");
+                cmd.args(docker_args(
+                    &self.config,
+                    work_dir,
+                    language,
+                    target_dir,
+                    &env,
+                ))
+                .arg(program);
+                cmd
+            }
+        }
+    }
+}
+
+/// @ai:intent A compiler/test command that runs under its sandbox's limits
+///            Mirrors the parts of std::process::Command the evaluator uses
+pub struct SandboxCommand {
+    cmd: Command,
+    /// Program as requested, for log messages (the real program may be docker)
+    program: String,
+    timeout: Option<Duration>,
+    max_output_bytes: usize,
+    /// Shared target directory leased to this command until it is dropped (cargo only)
+    target_dir: Option<TargetDirLease>,
+    /// .cargo/config.toml written to the work directory before running (cargo only)
+    /// A file rather than --config flags, so cargo-mutants' own builds see it too
+    cargo_config: Option<String>,
+    work_dir: PathBuf,
+    /// Written to the command's standard input (None = no input)
+    stdin: Option<Vec<u8>>,
+}
+
+impl SandboxCommand {
+    /// @ai:intent Add an argument
+    /// @ai:effects pure
+    pub fn arg<S: AsRef<OsStr>>(&mut self, arg: S) -> &mut Self {
+        self.cmd.arg(arg);
+        self
+    }
+
+    /// @ai:intent Add arguments
+    /// @ai:effects pure
+    pub fn args<I, S>(&mut self, args: I) -> &mut Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.cmd.args(args);
+        self
+    }
+
+    /// @ai:intent Feed input to the command's standard input, which is then closed
+    /// @ai:effects pure
+    pub fn stdin<B: Into<Vec<u8>>>(&mut self, input: B) -> &mut Self {
+        self.stdin = Some(input.into());
+        self
+    }
+
+    /// @ai:intent The underlying command, for inspection
+    /// @ai:effects pure
+    pub fn as_std(&self) -> &Command {
+        &self.cmd
+    }
+
+    /// @ai:intent Run the command to completion and capture its (capped) output
+    ///            On timeout the command and its subprocesses are killed; the failing
+    ///            status and a note on stderr let callers treat it as a failed run
+    /// @ai:effects io
+    pub fn output(&mut self) -> io::Result<Output> {
+        if let Some(target_dir) = &self.target_dir {
+            target_dir.prepare(&self.work_dir)?;
+        }
+        if let Some(cargo_config) = &self.cargo_config {
+            let dir = self.work_dir.join(".cargo");
+            std::fs::create_dir_all(&dir)?;
+            std::fs::write(dir.join("config.toml"), cargo_config)?;
+        }
+
+        let stdin = match self.stdin {
+            Some(_) => Stdio::piped(),
+            None => Stdio::null(),
+        };
+        self.cmd
+            .stdin(stdin)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        let mut child = self.cmd.spawn()?;
+        let tree = ProcessTree::attach(child.id());
+
+        // Written in the background: a program that does not read its input must not block us
+        if let (Some(input), Some(mut pipe)) = (self.stdin.clone(), child.stdin.take()) {
+            std::thread::spawn(move || pipe.write_all(&input));
+        }
+
+        let limited = tree.wait_with_limited_output(child, self.timeout, self.max_output_bytes)?;
+        let mut output = limited.output;
+        if limited.timed_out {
+            let secs = self.timeout.unwrap_or_default().as_secs();
+            tracing::warn!(
+                "{} timed out after {}s; killed it and its subprocesses",
+                self.program,
+                secs
+            );
+            output
+                .stderr
+                .extend_from_slice(format!("\n[killed after timeout of {}s]\n", secs).as_bytes());
+        }
+        Ok(output)
+    }
+}
+
+/// @ai:intent Target directories shared by the cargo commands of one run
+///            Each command leases a directory no other command is using, so concurrent
+///            evaluations never build in the same one; the pool grows to the number of
+///            cargo commands running at once, and each directory compiles dependencies once
+#[derive(Debug)]
+struct SharedTargetDirs {
+    /// Parent of the target directories, deleted with the sandbox
+    root: TempDir,
+    pool: Mutex<TargetDirPool>,
+}
+
+#[derive(Debug, Default)]
+struct TargetDirPool {
+    /// Directories not leased to a command
+    idle: Vec<PathBuf>,
+    /// Directories created so far (names them)
+    created: usize,
+}
+
+impl SharedTargetDirs {
+    /// @ai:intent Create an empty pool
+    /// @ai:effects fs:write
+    fn new() -> io::Result<Self> {
+        Ok(Self {
+            root: tempfile::Builder::new()
+                .prefix("aicms-bench-target-")
+                .tempdir()?,
+            pool: Mutex::new(TargetDirPool::default()),
+        })
+    }
+
+    /// @ai:intent Lease an idle directory, or a new one if all are in use
+    /// @ai:effects fs:write
+    fn lease(pool: &Arc<Self>) -> io::Result<TargetDirLease> {
+        let mut dirs = pool.pool.lock().unwrap_or_else(PoisonError::into_inner);
+        let path = match dirs.idle.pop() {
+            Some(path) => path,
+            None => {
+                let path = pool.root.path().join(dirs.created.to_string());
+                std::fs::create_dir(&path)?;
+                dirs.created += 1;
+                path
+            }
+        };
+        Ok(TargetDirLease {
+            pool: Arc::clone(pool),
+            path,
+        })
+    }
+}
+
+/// @ai:intent A target directory used by one command; returned to the pool on drop
+#[derive(Debug)]
+struct TargetDirLease {
+    pool: Arc<SharedTargetDirs>,
+    path: PathBuf,
+}
+
+impl TargetDirLease {
+    /// @ai:intent Path of the target directory on the host
+    /// @ai:effects pure
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// @ai:intent Forget every earlier build of the packages of the project in work_dir
+    ///            Projects of different executions usually share a package name, and so
+    ///            artifact names: cargo could take another project's build for fresh
+    /// @ai:post cargo rebuilds all targets of the project's packages
+    /// @ai:effects fs:read, fs:write
+    fn prepare(&self, work_dir: &Path) -> io::Result<()> {
+        let packages = package_names(work_dir);
+
+        // <target>/<profile>/.fingerprint, or <target>/<triple>/<profile>/.fingerprint
+        let fingerprint_dirs = WalkDir::new(&self.path)
+            .max_depth(3)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|e| e.file_type().is_dir() && e.file_name() == ".fingerprint");
+        for fingerprints in fingerprint_dirs {
+            for entry in std::fs::read_dir(fingerprints.path())? {
+                let entry = entry?;
+                if is_fingerprint_of(&entry.file_name().to_string_lossy(), &packages) {
+                    std::fs::remove_dir_all(entry.path())?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Drop for TargetDirLease {
+    fn drop(&mut self) {
+        let mut dirs = self
+            .pool
+            .pool
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        dirs.idle.push(std::mem::take(&mut self.path));
+    }
+}
+
+/// @ai:intent Names of the packages in a project's manifests (root and workspace members)
+/// @ai:effects fs:read
+fn package_names(work_dir: &Path) -> Vec<String> {
+    WalkDir::new(work_dir)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != "target")
+        .filter_map(Result::ok)
+        .filter(|e| e.file_name() == "Cargo.toml")
+        .filter_map(|e| std::fs::read_to_string(e.path()).ok())
+        .filter_map(|manifest| manifest.parse::<toml::Table>().ok())
+        .filter_map(|manifest| {
+            manifest
+                .get("package")?
+                .get("name")?
+                .as_str()
+                .map(str::to_string)
+        })
+        .collect()
+}
+
+/// @ai:intent Whether a fingerprint directory (`<package>-<hash>`) belongs to one of packages
+/// @ai:effects pure
+/// @ai:example ("bank-account-9f8e1387759cac6f", ["bank-account"]) -> true
+/// @ai:example ("bank-account-utils-9f8e1387759cac6f", ["bank-account"]) -> false
+fn is_fingerprint_of(dir_name: &str, packages: &[String]) -> bool {
+    packages.iter().any(|package| {
+        dir_name
+            .strip_prefix(package.as_str())
+            .and_then(|rest| rest.strip_prefix('-'))
+            .is_some_and(|hash| !hash.is_empty() && hash.chars().all(|c| c.is_ascii_hexdigit()))
+    })
+}
+
+/// @ai:intent Limit the CPU time and address space of the command's process (and, by
+///            inheritance, of every process it starts); 0 leaves a limit unchanged
+///            Soft limits only, capped at the current hard limit, so spawning never fails
+/// @ai:effects pure
+#[cfg(unix)]
+fn set_rlimits(cmd: &mut Command, cpu_secs: u64, memory_mb: u64) {
+    use std::os::unix::process::CommandExt;
+
+    if cpu_secs == 0 && memory_mb == 0 {
+        return;
+    }
+
+    let lower = |resource, value: u64| {
+        let mut limit = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        // getrlimit and setrlimit are async-signal-safe, so they may run after fork
+        unsafe {
+            if libc::getrlimit(resource, &mut limit) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            limit.rlim_cur = (value as libc::rlim_t).min(limit.rlim_max);
+            if libc::setrlimit(resource, &limit) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+        }
+        Ok(())
+    };
+
+    unsafe {
+        cmd.pre_exec(move || {
+            if cpu_secs > 0 {
+                lower(libc::RLIMIT_CPU, cpu_secs)?;
+            }
+            if memory_mb > 0 {
+                lower(libc::RLIMIT_AS, memory_mb * 1024 * 1024)?;
+            }
+            Ok(())
+        });
+    }
+}
+
+/// @ai:intent `docker run` arguments up to (not including) the program to run
+///            target_dir is mounted for the build cache, vendored packages read-only;
+///            env is set in the container
+/// @ai:effects pure
+fn docker_args(
+    config: &SandboxConfig,
+    work_dir: &Path,
+    language: Language,
+    target_dir: Option<&Path>,
+    env: &[(&str, String)],
+) -> Vec<String> {
+    let image = match language {
+        Language::Rust => &config.rust_image,
+        Language::Python => &config.python_image,
+        Language::TypeScript => &config.typescript_image,
+        Language::Go => &config.go_image,
+        Language::Java => &config.java_image,
+    };
+
+    let mut args: Vec<String> = vec![
+        "run".into(),
+        "--rm".into(),
+        "--network".into(),
+        if config.network { "bridge" } else { "none" }.into(),
+        "--cpus".into(),
+        config.cpus.to_string(),
+        "--memory".into(),
+        config.memory.clone(),
+        "--pids-limit".into(),
+        CONTAINER_PIDS_LIMIT.to_string(),
+        "--volume".into(),
+        format!("{}:{}", work_dir.display(), CONTAINER_WORKDIR),
+        "--workdir".into(),
+        CONTAINER_WORKDIR.into(),
+        // Keep stdin open, so input fed to the command reaches the program
+        "--interactive".into(),
+        // Writable locations for tool caches when not running as root
+        "--env".into(),
+        "HOME=/tmp".into(),
+        "--env".into(),
+        "CARGO_HOME=/tmp/cargo".into(),
+    ];
+
+    if let Some(target_dir) = target_dir {
+        args.push("--volume".into());
+        args.push(format!("{}:{}", target_dir.display(), CONTAINER_TARGET_DIR));
+    }
+    let vendor = &config.vendor;
+    for (name, dir) in [
+        ("cargo", &vendor.cargo),
+        ("wheels", &vendor.wheels),
+        ("npm", &vendor.npm_cache),
+    ] {
+        if let Some(dir) = dir {
+            args.push("--volume".into());
+            args.push(format!(
+                "{}:{}/{}:ro",
+                dir.display(),
+                CONTAINER_VENDOR_DIR,
+                name
+            ));
+        }
+    }
+    for (key, value) in env {
+        args.push("--env".into());
+        args.push(format!("{}={}", key, value));
+    }
+
+    if config.cpu_limit_secs > 0 {
+        args.push("--ulimit".into());
+        args.push(format!("cpu={}", config.cpu_limit_secs));
+    }
+
+    // Run as the host user, so build output in the mounted directory can be cleaned up
+    #[cfg(unix)]
+    {
+        let (uid, gid) = unsafe { (libc::getuid(), libc::getgid()) };
+        args.push("--user".into());
+        args.push(format!("{}:{}", uid, gid));
+    }
+
+    args.push(image.clone());
+
+    // Killing the docker client on timeout leaves the container running; stop it from inside
+    if config.timeout_secs > 0 {
+        args.push("timeout".into());
+        args.push("--signal=KILL".into());
+        args.push(config.timeout_secs.to_string());
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_docker_command_is_isolated() {
+        let config = SandboxConfig {
+            kind: SandboxKind::Docker,
+            ..Default::default()
+        };
+        let sandbox = Sandbox::from_config(&config);
+        let command = sandbox.command("cargo", Path::new("/tmp/project"), Language::Rust);
+        let cmd = command.as_std();
+        let args: Vec<String> = cmd
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+
+        assert_eq!(cmd.get_program(), "docker");
+        assert!(args.windows(2).any(|w| w == ["--network", "none"]));
+        assert!(args.windows(2).any(|w| w == ["--memory", "2g"]));
+        assert!(args.contains(&"/tmp/project:/work".to_string()));
+        assert!(args.windows(2).any(|w| w == ["--ulimit", "cpu=600"]));
+        assert!(args
+            .windows(2)
+            .any(|w| w == ["--env", "CARGO_TARGET_DIR=/cache/target"]));
+        assert_eq!(
+            &args[args.len() - 5..],
+            ["rust:1-slim", "timeout", "--signal=KILL", "600", "cargo"]
+        );
+
+        let host = Sandbox::default().command("cargo", Path::new("/tmp/project"), Language::Rust);
+        let host = host.as_std();
+        assert_eq!(
+            Path::new(host.get_program()).file_stem(),
+            Some("cargo".as_ref())
+        );
+        assert_eq!(host.get_current_dir(), Some(Path::new("/tmp/project")));
+    }
+
+    #[test]
+    fn test_vendored_packages_are_used_offline() {
+        let mut config = SandboxConfig::default();
+        config.vendor.cargo = Some(PathBuf::from("/srv/vendor/cargo"));
+        config.vendor.wheels = Some(PathBuf::from("/srv/vendor/wheels"));
+        let work_dir = Path::new("/tmp/project");
+
+        let host = Sandbox::from_config(&config);
+        let cargo_config: toml::Table = host.cargo_vendor_config().unwrap().parse().unwrap();
+        assert_eq!(
+            cargo_config["source"]["crates-io"]["replace-with"].as_str(),
+            Some("vendored-sources")
+        );
+        assert_eq!(
+            cargo_config["source"]["vendored-sources"]["directory"].as_str(),
+            Some("/srv/vendor/cargo")
+        );
+        assert_eq!(cargo_config["net"]["offline"].as_bool(), Some(true));
+        let python = host.command("python", work_dir, Language::Python);
+        assert!(python
+            .as_std()
+            .get_envs()
+            .any(|(key, value)| key == "PYTHONPATH"
+                && value == Some(work_dir.join(".deps").as_os_str())));
+
+        config.kind = SandboxKind::Docker;
+        let docker = Sandbox::from_config(&config);
+        let command = docker.command("cargo", work_dir, Language::Rust);
+        let args: Vec<String> = command
+            .as_std()
+            .get_args()
+            .map(|a| a.to_string_lossy().into_owned())
+            .collect();
+        assert!(args.contains(&"/srv/vendor/cargo:/vendor/cargo:ro".to_string()));
+        assert!(args.contains(&"/srv/vendor/wheels:/vendor/wheels:ro".to_string()));
+        assert!(docker
+            .cargo_vendor_config()
+            .unwrap()
+            .contains("directory = \"/vendor/cargo\""));
+    }
+
+    #[test]
+    fn test_shared_target_dir_rebuilds_same_named_projects() {
+        let sandbox = Sandbox::from_config(&SandboxConfig::default());
+        let manifest =
+            "[package]\nname = \"bank-account\"\nversion = \"0.1.0\"\nedition = \"2021\"\n";
+        let project = |value: u32| {
+            let dir = TempDir::new().unwrap();
+            std::fs::create_dir(dir.path().join("src")).unwrap();
+            std::fs::write(dir.path().join("Cargo.toml"), manifest).unwrap();
+            let lib = dir.path().join("src/lib.rs");
+            std::fs::write(
+                &lib,
+                format!("pub fn v() -> u32 {{ {value} }}\n#[test]\nfn one() {{ assert_eq!(v(), 1); }}\n"),
+            )
+            .unwrap();
+            // Older than the first project's build: only the fingerprints force a rebuild
+            std::fs::File::options()
+                .write(true)
+                .open(&lib)
+                .unwrap()
+                .set_modified(
+                    std::time::SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000),
+                )
+                .unwrap();
+            dir
+        };
+        let cargo_test = |dir: &TempDir| {
+            sandbox
+                .command("cargo", dir.path(), Language::Rust)
+                .args(["test", "--offline"])
+                .output()
+                .unwrap()
+        };
+
+        let (passing, failing) = (project(1), project(2));
+        assert!(cargo_test(&passing).status.success());
+        assert!(!passing.path().join("target").exists());
+        assert!(!cargo_test(&failing).status.success());
+
+        assert!(is_fingerprint_of(
+            "bank-account-9f8e1387759cac6f",
+            &["bank-account".to_string()]
+        ));
+        assert!(!is_fingerprint_of(
+            "bank-account-utils-9f8e1387759cac6f",
+            &["bank-account".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_concurrent_cargo_commands_lease_separate_target_dirs() {
+        let sandbox = Sandbox::from_config(&SandboxConfig::default());
+        let target_dir = |command: &SandboxCommand| {
+            command
+                .as_std()
+                .get_envs()
+                .find(|(key, _)| *key == "CARGO_TARGET_DIR")
+                .and_then(|(_, value)| value)
+                .map(|value| value.to_os_string())
+        };
+        let work_dir = Path::new("/tmp/project");
+
+        let first = sandbox.command("cargo", work_dir, Language::Rust);
+        let second = sandbox.command("cargo", work_dir, Language::Rust);
+        assert!(target_dir(&first).is_some());
+        assert_ne!(target_dir(&first), target_dir(&second));
+        assert_eq!(
+            target_dir(&sandbox.command("rustc", work_dir, Language::Rust)),
+            None
+        );
+
+        let released = target_dir(&second);
+        drop(second);
+        let third = sandbox.command("cargo", work_dir, Language::Rust);
+        assert_eq!(target_dir(&third), released);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_times_out() {
+        let config = SandboxConfig {
+            timeout_secs: 1,
+            ..Default::default()
+        };
+        let started = std::time::Instant::now();
+        let output = Sandbox::from_config(&config)
+            .command("sh", Path::new("/tmp"), Language::Rust)
+            .args(["-c", "echo started; while :; do :; done"])
+            .output()
+            .unwrap();
+
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(!output.status.success());
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "started\n");
+        assert!(String::from_utf8_lossy(&output.stderr).contains("killed after timeout of 1s"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_command_reads_stdin() {
+        let output = Sandbox::default()
+            .command("sh", Path::new("/tmp"), Language::Rust)
+            .args(["-c", "tr a-z A-Z"])
+            .stdin("hello\n")
+            .output()
+            .unwrap();
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "HELLO\n");
+    }
+}
@@ -0,0 +1,545 @@
+//! @ai:module:intent Security scan of generated projects: known-vulnerable dependencies from
+//!                   cargo-audit, pip-audit or npm audit, and dangerous code patterns (shell
+//!                   commands, eval, unsafe deserialization, hardcoded secrets, ...)
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api SecurityScanner, SecurityScannerTrait, SecurityScan, SecurityFinding
+//! @ai:module:depends_on evaluator::sandbox, evaluator::test_runner, evaluator::reference
+//! @ai:module:stateless true
+
+use crate::corpus::Language;
+use crate::evaluator::reference::is_test_file;
+use crate::evaluator::sandbox::Sandbox;
+use crate::evaluator::test_runner::write_project;
+use crate::evaluator::SourceFile;
+use anyhow::{bail, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// @ai:intent A code pattern that is dangerous in generated code
+struct DangerousPattern {
+    rule: &'static str,
+    /// Languages the pattern applies to (empty = all)
+    languages: &'static [Language],
+    regex: &'static str,
+    message: &'static str,
+}
+
+/// @ai:intent Dangerous-pattern rules, matched line by line against non-test source files
+const DANGEROUS_PATTERNS: &[DangerousPattern] = &[
+    DangerousPattern {
+        rule: "hardcoded-secret",
+        languages: &[],
+        regex: r#"(?i)\b\w*(?:password|passwd|secret|api_?key|access_?token|private_?key)\w*["']?\s*[:=]\s*["'][^"'\s]{8,}["']"#,
+        message: "credential assigned from a string literal",
+    },
+    DangerousPattern {
+        rule: "sql-injection",
+        languages: &[],
+        regex: r#"(?i)(?:\bf"\s*(?:select|insert|update|delete)\s[^"]*\{|\bf'\s*(?:select|insert|update|delete)\s[^']*\{|"\s*(?:select|insert|update|delete)\s[^"]*"\s*\+|'\s*(?:select|insert|update|delete)\s[^']*'\s*\+)"#,
+        message: "SQL statement built from interpolated or concatenated values",
+    },
+    DangerousPattern {
+        rule: "unsafe-code",
+        languages: &[Language::Rust],
+        regex: r"\bunsafe\s*(?:\{|fn\b|impl\b)",
+        message: "unsafe block or function",
+    },
+    DangerousPattern {
+        rule: "unsafe-code",
+        languages: &[Language::Go],
+        regex: r#""unsafe""#,
+        message: "imports the unsafe package",
+    },
+    DangerousPattern {
+        rule: "shell-command",
+        languages: &[Language::Rust],
+        regex: r#"Command::new\(\s*"(?:sh|bash|cmd|powershell)""#,
+        message: "runs a command through a shell",
+    },
+    DangerousPattern {
+        rule: "shell-command",
+        languages: &[Language::Python],
+        regex: r"\bos\.(?:system|popen)\s*\(|\bshell\s*=\s*True",
+        message: "runs a command through a shell",
+    },
+    DangerousPattern {
+        rule: "shell-command",
+        languages: &[Language::TypeScript],
+        regex: r#"["'](?:node:)?child_process["']"#,
+        message: "spawns processes with child_process",
+    },
+    DangerousPattern {
+        rule: "shell-command",
+        languages: &[Language::Go],
+        regex: r#"exec\.Command\(\s*"(?:sh|bash|cmd|powershell)""#,
+        message: "runs a command through a shell",
+    },
+    DangerousPattern {
+        rule: "shell-command",
+        languages: &[Language::Java],
+        regex: r"Runtime\.getRuntime\(\)\.exec\s*\(",
+        message: "runs a command with Runtime.exec",
+    },
+    DangerousPattern {
+        rule: "dynamic-code",
+        languages: &[Language::Python],
+        regex: r"(?:^|[^\w.])(?:eval|exec)\s*\(",
+        message: "evaluates dynamically built code",
+    },
+    DangerousPattern {
+        rule: "dynamic-code",
+        languages: &[Language::TypeScript],
+        regex: r"(?:^|[^\w.])eval\s*\(|\bnew\s+Function\s*\(",
+        message: "evaluates dynamically built code",
+    },
+    DangerousPattern {
+        rule: "unsafe-deserialization",
+        languages: &[Language::Python],
+        regex: r"\b(?:pickle|marshal)\.loads?\s*\(|\byaml\.load\s*\(",
+        message: "deserializes data that can run code",
+    },
+    DangerousPattern {
+        rule: "unsafe-deserialization",
+        languages: &[Language::Java],
+        regex: r"\bnew\s+ObjectInputStream\s*\(",
+        message: "deserializes data that can run code",
+    },
+    DangerousPattern {
+        rule: "insecure-tls",
+        languages: &[Language::Python],
+        regex: r"\bverify\s*=\s*False\b",
+        message: "disables TLS certificate verification",
+    },
+    DangerousPattern {
+        rule: "insecure-tls",
+        languages: &[Language::TypeScript],
+        regex: r"\brejectUnauthorized\s*:\s*false\b",
+        message: "disables TLS certificate verification",
+    },
+    DangerousPattern {
+        rule: "insecure-tls",
+        languages: &[Language::Go],
+        regex: r"\bInsecureSkipVerify\s*:\s*true\b",
+        message: "disables TLS certificate verification",
+    },
+    DangerousPattern {
+        rule: "weak-hash",
+        languages: &[Language::Python],
+        regex: r"\bhashlib\.(?:md5|sha1)\s*\(",
+        message: "uses MD5 or SHA-1",
+    },
+    DangerousPattern {
+        rule: "weak-hash",
+        languages: &[Language::TypeScript],
+        regex: r#"\bcreateHash\(\s*["'](?:md5|sha1)["']"#,
+        message: "uses MD5 or SHA-1",
+    },
+    DangerousPattern {
+        rule: "weak-hash",
+        languages: &[Language::Go],
+        regex: r#""crypto/(?:md5|sha1)""#,
+        message: "uses MD5 or SHA-1",
+    },
+    DangerousPattern {
+        rule: "weak-hash",
+        languages: &[Language::Java],
+        regex: r#"MessageDigest\.getInstance\(\s*"(?i:md5|sha-?1)""#,
+        message: "uses MD5 or SHA-1",
+    },
+];
+
+/// @ai:intent One security finding: a vulnerable dependency or a dangerous pattern
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SecurityFinding {
+    /// Advisory id (e.g. "RUSTSEC-2020-0071", "GHSA-...") or pattern rule (e.g. "shell-command")
+    pub rule: String,
+    /// Dependency ("time 0.1.43") or source line ("src/main.rs:12")
+    pub location: String,
+    pub message: String,
+}
+
+/// @ai:intent Security findings for one execution
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SecurityScan {
+    /// Dependency auditor that ran (cargo-audit, pip-audit, npm audit); None if the audit was
+    /// disabled, is not available for the language, or failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audit_tool: Option<String>,
+    /// Advisories reported against the project's dependencies
+    pub vulnerable_dependencies: u32,
+    /// Dangerous-pattern matches in non-test source files
+    pub dangerous_patterns: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub findings: Vec<SecurityFinding>,
+}
+
+impl SecurityScan {
+    /// @ai:intent All findings, dependency advisories and dangerous patterns
+    /// @ai:effects pure
+    pub fn total(&self) -> u32 {
+        self.vulnerable_dependencies + self.dangerous_patterns
+    }
+
+    /// @ai:intent True when there are no findings of either kind
+    /// @ai:effects pure
+    pub fn is_clean(&self) -> bool {
+        self.total() == 0
+    }
+
+    /// @ai:intent Finding count per rule
+    /// @ai:effects pure
+    pub fn rules(&self) -> BTreeMap<&str, u32> {
+        let mut rules = BTreeMap::new();
+        for finding in &self.findings {
+            *rules.entry(finding.rule.as_str()).or_default() += 1;
+        }
+        rules
+    }
+}
+
+/// @ai:intent Trait for security scanning
+pub trait SecurityScannerTrait: Send + Sync {
+    /// @ai:intent Scan a generated project for vulnerable dependencies and dangerous patterns
+    fn scan(&self, files: &[SourceFile], language: Language) -> Result<SecurityScan>;
+}
+
+/// @ai:intent Matches dangerous patterns in the source and, when enabled, audits dependencies
+///            with cargo-audit (Rust), pip-audit (Python) or npm audit (TypeScript)
+pub struct SecurityScanner {
+    sandbox: Sandbox,
+    audit: bool,
+    patterns: Vec<(&'static DangerousPattern, Regex)>,
+}
+
+impl SecurityScanner {
+    /// @ai:intent Create a scanner that only matches dangerous patterns
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            sandbox: Sandbox::default(),
+            audit: false,
+            patterns: DANGEROUS_PATTERNS
+                .iter()
+                .map(|pattern| (pattern, Regex::new(pattern.regex).unwrap()))
+                .collect(),
+        }
+    }
+
+    /// @ai:intent Run the dependency auditors in the given sandbox
+    /// @ai:effects pure
+    pub fn with_sandbox(mut self, sandbox: Sandbox) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
+    /// @ai:intent Also audit dependencies (the auditors may need their advisory database or
+    ///            package registry)
+    /// @ai:effects pure
+    pub fn with_audit(mut self, audit: bool) -> Self {
+        self.audit = audit;
+        self
+    }
+
+    /// @ai:intent Dangerous-pattern findings in the non-test source files, one per rule and line
+    /// @ai:effects pure
+    fn match_patterns(&self, files: &[SourceFile], language: Language) -> Vec<SecurityFinding> {
+        let patterns: Vec<_> = self
+            .patterns
+            .iter()
+            .filter(|(p, _)| p.languages.is_empty() || p.languages.contains(&language))
+            .collect();
+
+        let mut findings = Vec::new();
+        let sources = files.iter().filter(|f| {
+            f.path.ends_with(&format!(".{}", language.extension()))
+                && !is_test_file(&f.path, language)
+        });
+        for file in sources {
+            for (number, line) in file.content.lines().enumerate() {
+                let trimmed = line.trim_start();
+                if ["//", "#", "/*", "*"]
+                    .iter()
+                    .any(|c| trimmed.starts_with(c))
+                {
+                    continue;
+                }
+                for (pattern, regex) in &patterns {
+                    if regex.is_match(line) {
+                        findings.push(SecurityFinding {
+                            rule: pattern.rule.to_string(),
+                            location: format!("{}:{}", file.path, number + 1),
+                            message: pattern.message.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+        findings
+    }
+
+    /// @ai:intent Run the language's dependency auditor
+    /// @ai:post None if the language has no auditor
+    /// @ai:effects fs:write, io
+    fn audit_dependencies(
+        &self,
+        root: &Path,
+        language: Language,
+    ) -> Result<Option<(&'static str, Vec<SecurityFinding>)>> {
+        let findings = match language {
+            Language::Rust => ("cargo-audit", self.cargo_audit(root)?),
+            Language::Python if root.join("requirements.txt").exists() => {
+                ("pip-audit", self.pip_audit(root)?)
+            }
+            Language::TypeScript if root.join("package.json").exists() => {
+                ("npm audit", self.npm_audit(root)?)
+            }
+            // No manifest to audit, or no auditor for the language
+            _ => return Ok(None),
+        };
+        Ok(Some(findings))
+    }
+
+    /// @ai:intent Lock the dependencies and run cargo-audit against the local advisory database
+    /// @ai:effects fs:write, io
+    fn cargo_audit(&self, root: &Path) -> Result<Vec<SecurityFinding>> {
+        let lock = self
+            .sandbox
+            .command("cargo", root, Language::Rust)
+            .arg("generate-lockfile")
+            .output()?;
+        if !lock.status.success() {
+            bail!(
+                "cargo generate-lockfile failed: {}",
+                String::from_utf8_lossy(&lock.stderr)
+            );
+        }
+
+        let output = self
+            .sandbox
+            .command("cargo", root, Language::Rust)
+            .args(["audit", "--json", "--no-fetch", "--stale"])
+            .output()
+            .map_err(|e| anyhow::anyhow!("cargo-audit not available: {}", e))?;
+        // Exits non-zero when vulnerabilities are found
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).map_err(|_| {
+            anyhow::anyhow!(
+                "cargo-audit failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+        })?;
+
+        Ok(report["vulnerabilities"]["list"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|vulnerability| SecurityFinding {
+                rule: json_str(&vulnerability["advisory"]["id"]),
+                location: format!(
+                    "{} {}",
+                    json_str(&vulnerability["package"]["name"]),
+                    json_str(&vulnerability["package"]["version"])
+                ),
+                message: json_str(&vulnerability["advisory"]["title"]),
+            })
+            .collect())
+    }
+
+    /// @ai:intent Run pip-audit on requirements.txt
+    /// @ai:effects io
+    fn pip_audit(&self, root: &Path) -> Result<Vec<SecurityFinding>> {
+        let output = self
+            .sandbox
+            .command("python", root, Language::Python)
+            .args(["-m", "pip_audit", "--requirement", "requirements.txt"])
+            .args(["--format", "json", "--progress-spinner", "off"])
+            .output()
+            .map_err(|e| anyhow::anyhow!("pip-audit not available: {}", e))?;
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).map_err(|_| {
+            anyhow::anyhow!(
+                "pip-audit failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+        })?;
+
+        // {"dependencies": [...]} since pip-audit 2.5, a bare list before
+        let dependencies = report["dependencies"].as_array().or(report.as_array());
+        let mut findings = Vec::new();
+        for dependency in dependencies.into_iter().flatten() {
+            for vulnerability in dependency["vulns"].as_array().into_iter().flatten() {
+                findings.push(SecurityFinding {
+                    rule: json_str(&vulnerability["id"]),
+                    location: format!(
+                        "{} {}",
+                        json_str(&dependency["name"]),
+                        json_str(&dependency["version"])
+                    ),
+                    message: json_str(&vulnerability["description"]),
+                });
+            }
+        }
+        Ok(findings)
+    }
+
+    /// @ai:intent Install the vendored packages, which writes package-lock.json, and run npm audit
+    /// @ai:effects fs:write, io
+    fn npm_audit(&self, root: &Path) -> Result<Vec<SecurityFinding>> {
+        self.sandbox
+            .install_dependencies(root, Language::TypeScript)?;
+        let output = self
+            .sandbox
+            .command("npm", root, Language::TypeScript)
+            .args(["audit", "--json"])
+            .output()
+            .map_err(|e| anyhow::anyhow!("npm not available: {}", e))?;
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).map_err(|_| {
+            anyhow::anyhow!(
+                "npm audit failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+        })?;
+        if report.get("error").is_some() {
+            bail!("npm audit failed: {}", report["error"]["summary"]);
+        }
+
+        // One entry per vulnerable package; "via" holds its advisories, or the names of the
+        // vulnerable packages it depends on
+        let mut findings = Vec::new();
+        for (name, package) in report["vulnerabilities"].as_object().into_iter().flatten() {
+            let advisory = package["via"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .find(|via| via.is_object());
+            let rule = advisory
+                .and_then(|a| a["url"].as_str())
+                .and_then(|url| url.rsplit('/').next())
+                .unwrap_or("npm-advisory");
+            let message = match advisory {
+                Some(advisory) => json_str(&advisory["title"]),
+                None => format!("depends on a vulnerable package ({})", package["severity"]),
+            };
+            findings.push(SecurityFinding {
+                rule: rule.to_string(),
+                location: format!("{} {}", name, json_str(&package["range"])),
+                message,
+            });
+        }
+        Ok(findings)
+    }
+}
+
+impl Default for SecurityScanner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SecurityScannerTrait for SecurityScanner {
+    /// @ai:intent Match dangerous patterns, then audit dependencies in a temporary project
+    ///            A failed or missing auditor is logged and leaves audit_tool unset
+    /// @ai:effects fs:write, io
+    fn scan(&self, files: &[SourceFile], language: Language) -> Result<SecurityScan> {
+        let mut findings = self.match_patterns(files, language);
+        let dangerous_patterns = findings.len() as u32;
+
+        let mut audit_tool = None;
+        let mut vulnerable_dependencies = 0;
+        if self.audit {
+            let temp_dir = TempDir::new()?;
+            write_project(temp_dir.path(), files, language)?;
+            match self.audit_dependencies(temp_dir.path(), language) {
+                Ok(Some((tool, advisories))) => {
+                    audit_tool = Some(tool.to_string());
+                    vulnerable_dependencies = advisories.len() as u32;
+                    findings.extend(advisories);
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Dependency audit skipped: {}", e),
+            }
+        }
+
+        Ok(SecurityScan {
+            audit_tool,
+            vulnerable_dependencies,
+            dangerous_patterns,
+            findings,
+        })
+    }
+}
+
+/// @ai:intent String value of a JSON field, or "?" if it is missing
+/// @ai:effects pure
+fn json_str(value: &serde_json::Value) -> String {
+    value.as_str().unwrap_or("?").to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str, content: &str) -> SourceFile {
+        SourceFile {
+            path: path.to_string(),
+            content: content.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_python_patterns() {
+        let files = vec![
+            file(
+                "app.py",
+                "import os, pickle\n\
+                 API_KEY = \"sk-live-1234567890\"\n\
+                 # eval(user_input) is never called\n\
+                 def run(cmd, blob, name):\n\
+                 \x20   os.system(cmd)\n\
+                 \x20   data = pickle.loads(blob)\n\
+                 \x20   cursor.execute(f\"SELECT * FROM users WHERE name = '{name}'\")\n\
+                 \x20   return re.compile(cmd).match(name), literal_eval(blob)\n",
+            ),
+            file("test_app.py", "eval('1 + 1')\n"),
+        ];
+
+        let scan = SecurityScanner::new()
+            .scan(&files, Language::Python)
+            .unwrap();
+        let rules: Vec<_> = scan
+            .findings
+            .iter()
+            .map(|f| (f.rule.as_str(), f.location.as_str()))
+            .collect();
+        assert_eq!(
+            rules,
+            [
+                ("hardcoded-secret", "app.py:2"),
+                ("shell-command", "app.py:5"),
+                ("unsafe-deserialization", "app.py:6"),
+                ("sql-injection", "app.py:7"),
+            ]
+        );
+        assert_eq!((scan.dangerous_patterns, scan.total()), (4, 4));
+        assert_eq!(scan.audit_tool, None);
+    }
+
+    #[test]
+    fn test_patterns_apply_to_their_language() {
+        let code =
+            "fn main() {\n    let p = unsafe { *ptr };\n    Command::new(\"sh\").arg(\"-c\");\n}\n";
+        let files = vec![file("src/main.rs", code)];
+
+        let scan = SecurityScanner::new().scan(&files, Language::Rust).unwrap();
+        assert_eq!(
+            scan.rules(),
+            BTreeMap::from([("shell-command", 1), ("unsafe-code", 1)])
+        );
+
+        // The same text in a Go project matches neither Go rule
+        let files = vec![file("main.go", code)];
+        let scan = SecurityScanner::new().scan(&files, Language::Go).unwrap();
+        assert_eq!(scan.total(), 0);
+    }
+}
@@ -0,0 +1,722 @@
+//! @ai:module:intent Built-in evaluation stages, each wrapping one evaluation component
+//! @ai:module:layer application
+//! @ai:module:public_api CodeSizeStage, ComplexityStage, CompilationStage, CodeQualityStage,
+//!                        TypeCheckStage, SecurityStage, TestStage, TestDesignStage, MutationStage,
+//!                        HiddenTestStage, IoCaseStage, ApiConformanceStage, ReferenceStage,
+//!                        InferenceStage, ContractStage, LintStage, AnnotationStage, LeakageStage,
+//!                        ContaminationStage
+//! @ai:module:depends_on config, corpus, evaluator::pipeline, evaluator components
+//! @ai:module:stateless true
+
+use crate::config::EvaluationConfig;
+use crate::corpus::{Language, Task};
+use crate::evaluator::pipeline::{EvaluationStage, StageInput};
+use crate::evaluator::{
+    disallowed_dependencies, expected_annotations, AnnotationMatcher, AnnotationScorer,
+    AnnotationScorerTrait, ApiConformanceChecker, ApiConformanceCheckerTrait, CodeQualityChecker,
+    CodeQualityCheckerTrait, CodeSize, CompilationChecker, CompilationCheckerTrait,
+    ComplexityAnalyzer, ComplexityAnalyzerTrait, ContaminationDetector, ContractChecker,
+    ContractCheckerTrait, ContractStatus, EvaluationResult, InferenceScorer, InferenceScorerTrait,
+    IoRunner, IoRunnerTrait, LeakageDetector, LeakageDetectorTrait, LinterAdapter,
+    LinterAdapterTrait, MutationTester, MutationTesterTrait, ReferenceAlignment, ReferenceComparer,
+    ReferenceComparerTrait, Sandbox, SecurityScanner, SecurityScannerTrait, SourceFile,
+    TestDesignAnalyzer, TestDesignAnalyzerTrait, TestDeterminism, TestResult, TestRunner,
+    TestRunnerTrait, TypeChecker, TypeCheckerTrait,
+};
+use anyhow::Result;
+
+/// @ai:intent File count and lines of the generated code, before unchanged starter files are added
+pub struct CodeSizeStage;
+
+impl EvaluationStage for CodeSizeStage {
+    fn name(&self) -> &str {
+        "code_size"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        let code_size = CodeSize::measure(input.generated_files, input.task.language);
+        tracing::info!(
+            "Code size: {} files, {} lines ({} in tests)",
+            code_size.files,
+            code_size.lines,
+            code_size.test_lines
+        );
+        result.code_size = Some(code_size);
+        Ok(())
+    }
+}
+
+/// @ai:intent Cyclomatic complexity and nesting of the generated functions
+#[derive(Default)]
+pub struct ComplexityStage {
+    analyzer: ComplexityAnalyzer,
+}
+
+impl EvaluationStage for ComplexityStage {
+    fn name(&self) -> &str {
+        "complexity"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        result.complexity = self
+            .analyzer
+            .analyze(input.generated_files, input.task.language);
+        if let Some(complexity) = &result.complexity {
+            tracing::info!(
+                "Complexity: {} functions, avg cyclomatic {:.1}, avg nesting {:.1}",
+                complexity.functions,
+                complexity.avg_cyclomatic,
+                complexity.avg_nesting_depth
+            );
+        }
+        Ok(())
+    }
+}
+
+/// @ai:intent Compile the project; a dependency the task does not allow fails the build
+#[derive(Default)]
+pub struct CompilationStage {
+    compiler: CompilationChecker,
+}
+
+impl EvaluationStage for CompilationStage {
+    fn name(&self) -> &str {
+        "compilation"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        tracing::info!("Compiling {} files...", input.source_files.len());
+        let disallowed = disallowed_dependencies(input.task, input.source_files);
+        let mut compilation = self
+            .compiler
+            .check_files(input.source_files, input.task.language)?;
+        // A dependency the task does not allow fails the build, even if it resolved
+        for name in &disallowed {
+            compilation.success = false;
+            compilation.errors.push(format!(
+                "Dependency `{}` is not allowed for this task",
+                name
+            ));
+        }
+        let outcome = if compilation.success {
+            "succeeded"
+        } else {
+            "failed"
+        };
+        tracing::info!(
+            "Compilation {}: {} errors, {} warnings",
+            outcome,
+            compilation.errors.len(),
+            compilation.warnings.len()
+        );
+        for err in &compilation.errors {
+            tracing::warn!("Compilation error: {}", err);
+        }
+
+        result.compilation = Some(compilation);
+        Ok(())
+    }
+
+    fn set_sandbox(&mut self, sandbox: &Sandbox) {
+        self.compiler = std::mem::take(&mut self.compiler).with_sandbox(sandbox.clone());
+    }
+}
+
+/// @ai:intent Idiomatic lints (clippy, ruff, eslint)
+#[derive(Default)]
+pub struct CodeQualityStage {
+    checker: CodeQualityChecker,
+}
+
+impl EvaluationStage for CodeQualityStage {
+    fn name(&self) -> &str {
+        "code_quality"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        let quality = self
+            .checker
+            .check(input.source_files, input.task.language)?;
+        tracing::info!(
+            "{}: {} warnings ({:.1} per 100 lines)",
+            quality.tool,
+            quality.warnings,
+            quality.density()
+        );
+        result.code_quality = Some(quality);
+        Ok(())
+    }
+
+    fn set_sandbox(&mut self, sandbox: &Sandbox) {
+        self.checker = std::mem::take(&mut self.checker).with_sandbox(sandbox.clone());
+    }
+}
+
+/// @ai:intent Static type check of Python code that compiles (only with python_type_checker)
+#[derive(Default)]
+pub struct TypeCheckStage {
+    checker: TypeChecker,
+    enabled: bool,
+}
+
+impl EvaluationStage for TypeCheckStage {
+    fn name(&self) -> &str {
+        "type_check"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        let compiled = result.compilation.as_ref().is_some_and(|c| c.success);
+        if !self.enabled || input.task.language != Language::Python || !compiled {
+            return Ok(());
+        }
+
+        let check = self.checker.check(input.source_files)?;
+        tracing::info!("{}: {} type errors", check.tool, check.errors);
+        result.type_check = Some(check);
+        Ok(())
+    }
+
+    fn set_sandbox(&mut self, sandbox: &Sandbox) {
+        self.checker = std::mem::take(&mut self.checker).with_sandbox(sandbox.clone());
+    }
+
+    fn configure(&mut self, config: &EvaluationConfig) {
+        if let Some(tool) = config.python_type_checker {
+            self.enabled = true;
+            self.checker = std::mem::take(&mut self.checker).with_tool(tool);
+        }
+    }
+}
+
+/// @ai:intent Dangerous code patterns, and vulnerable dependencies when auditing is enabled
+#[derive(Default)]
+pub struct SecurityStage {
+    scanner: SecurityScanner,
+}
+
+impl EvaluationStage for SecurityStage {
+    fn name(&self) -> &str {
+        "security"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        let scan = self.scanner.scan(input.source_files, input.task.language)?;
+        if !scan.is_clean() {
+            tracing::warn!(
+                "Task {} (mode={}) has {} security findings ({} vulnerable dependencies)",
+                input.task.id,
+                input.execution.mode.as_str(),
+                scan.total(),
+                scan.vulnerable_dependencies
+            );
+        }
+        result.security = Some(scan);
+        Ok(())
+    }
+
+    fn set_sandbox(&mut self, sandbox: &Sandbox) {
+        self.scanner = std::mem::take(&mut self.scanner).with_sandbox(sandbox.clone());
+    }
+
+    fn configure(&mut self, config: &EvaluationConfig) {
+        self.scanner = std::mem::take(&mut self.scanner).with_audit(config.security_audit);
+    }
+}
+
+/// @ai:intent Run the model's own tests (included in the generated code), twice with
+///            flaky_test_detection
+#[derive(Default)]
+pub struct TestStage {
+    runner: TestRunner,
+    flaky_test_detection: bool,
+}
+
+impl EvaluationStage for TestStage {
+    fn name(&self) -> &str {
+        "tests"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        tracing::info!("Running tests...");
+        let tests = self
+            .runner
+            .run_own_tests(input.source_files, input.task.language)?;
+        tracing::info!(
+            "Tests: {} passed, {} failed, {} total",
+            tests.passed,
+            tests.failed,
+            tests.total
+        );
+        result.tests = Some(tests.clone());
+        if !self.flaky_test_detection || tests.total == 0 {
+            return Ok(());
+        }
+
+        // Re-run the tests: a suite that disagrees with itself makes the pass rate a coin toss
+        tracing::info!("Re-running tests to detect flakiness...");
+        let second = self
+            .runner
+            .run_own_tests(input.source_files, input.task.language)?;
+        let determinism = TestDeterminism::from_runs(&[tests, second]);
+        if determinism.flaky {
+            tracing::warn!(
+                "Flaky tests in task {}: passed {:?} of {:?}",
+                input.task.id,
+                determinism.passed,
+                determinism.total
+            );
+        }
+        result.test_determinism = Some(determinism);
+        Ok(())
+    }
+
+    fn set_sandbox(&mut self, sandbox: &Sandbox) {
+        self.runner = std::mem::take(&mut self.runner).with_sandbox(sandbox.clone());
+    }
+
+    fn configure(&mut self, config: &EvaluationConfig) {
+        self.flaky_test_detection = config.flaky_test_detection;
+    }
+}
+
+/// @ai:intent Property-based tests and edge case coverage of the model's own tests
+#[derive(Default)]
+pub struct TestDesignStage {
+    analyzer: TestDesignAnalyzer,
+}
+
+impl EvaluationStage for TestDesignStage {
+    fn name(&self) -> &str {
+        "test_design"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        result.test_design = self.analyzer.analyze(input.task, input.generated_files);
+        if let Some(design) = &result.test_design {
+            tracing::info!(
+                "Test design: property framework {:?}, {}/{} edge cases tested",
+                design.property_framework,
+                design.edge_cases_tested,
+                design.edge_cases
+            );
+        }
+        Ok(())
+    }
+}
+
+/// @ai:intent Mutation testing of a green, deterministic test suite (only with mutation_testing)
+#[derive(Default)]
+pub struct MutationStage {
+    tester: MutationTester,
+    enabled: bool,
+}
+
+impl EvaluationStage for MutationStage {
+    fn name(&self) -> &str {
+        "mutation"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        // Mutation testing only means something for a green, deterministic test suite
+        let tests_green = result
+            .tests
+            .as_ref()
+            .is_some_and(|t| t.total > 0 && t.failed == 0)
+            && !result.test_determinism.as_ref().is_some_and(|d| d.flaky);
+        if !self.enabled || !tests_green {
+            return Ok(());
+        }
+
+        tracing::info!("Running mutation testing...");
+        let score = self.tester.test(input.source_files, input.task.language)?;
+        tracing::info!(
+            "{}: {} caught, {} missed, {} timed out ({:.1}%)",
+            score.tool,
+            score.caught,
+            score.missed,
+            score.timeout,
+            score.score()
+        );
+        result.mutation = Some(score);
+        Ok(())
+    }
+
+    fn set_sandbox(&mut self, sandbox: &Sandbox) {
+        self.tester = std::mem::take(&mut self.tester).with_sandbox(sandbox.clone());
+    }
+
+    fn configure(&mut self, config: &EvaluationConfig) {
+        self.enabled = config.mutation_testing;
+        self.tester =
+            std::mem::take(&mut self.tester).with_timeout_secs(config.mutation_timeout_secs);
+    }
+}
+
+/// @ai:intent Run the reference tests the model never saw
+#[derive(Default)]
+pub struct HiddenTestStage {
+    runner: TestRunner,
+}
+
+impl EvaluationStage for HiddenTestStage {
+    fn name(&self) -> &str {
+        "hidden_tests"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        let task = input.task;
+        if task.hidden_tests.is_empty() {
+            return Ok(());
+        }
+
+        tracing::info!("Running {} hidden test files...", task.hidden_tests.len());
+        let test_files: Vec<SourceFile> = task
+            .hidden_tests
+            .iter()
+            .map(|t| SourceFile {
+                path: t.path.clone(),
+                content: t.code.clone(),
+            })
+            .collect();
+        let hidden_tests = self
+            .runner
+            .run_files(input.source_files, &test_files, task.language)?;
+        tracing::info!(
+            "Hidden tests: {} passed, {} failed, {} total",
+            hidden_tests.passed,
+            hidden_tests.failed,
+            hidden_tests.total
+        );
+        result.hidden_tests = Some(hidden_tests);
+        Ok(())
+    }
+
+    fn run_without_code(&self, input: &StageInput, result: &mut EvaluationResult) {
+        // Missing code fails every reference test
+        result.hidden_tests = (!input.task.hidden_tests.is_empty()).then(|| TestResult {
+            passed: 0,
+            failed: input.task.hidden_tests.len() as u32,
+            total: input.task.hidden_tests.len() as u32,
+            output: "No code extracted".to_string(),
+        });
+    }
+
+    fn set_sandbox(&mut self, sandbox: &Sandbox) {
+        self.runner = std::mem::take(&mut self.runner).with_sandbox(sandbox.clone());
+    }
+}
+
+/// @ai:intent Run the built program against the task's I/O cases (CLI-style tasks)
+#[derive(Default)]
+pub struct IoCaseStage {
+    runner: IoRunner,
+}
+
+impl EvaluationStage for IoCaseStage {
+    fn name(&self) -> &str {
+        "io_cases"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        let Some(io) = &input.task.io else {
+            return Ok(());
+        };
+
+        tracing::info!("Running {} I/O cases...", io.cases.len());
+        let io_cases = self
+            .runner
+            .run(io, input.source_files, input.task.language)?;
+        tracing::info!(
+            "I/O cases: {} of {} passed",
+            io_cases.passed,
+            io_cases.total
+        );
+        result.io_cases = Some(io_cases);
+        Ok(())
+    }
+
+    fn run_without_code(&self, input: &StageInput, result: &mut EvaluationResult) {
+        result.io_cases = input.task.io.as_ref().map(|io| TestResult {
+            passed: 0,
+            failed: io.cases.len() as u32,
+            total: io.cases.len() as u32,
+            output: "No code extracted".to_string(),
+        });
+    }
+
+    fn set_sandbox(&mut self, sandbox: &Sandbox) {
+        self.runner = std::mem::take(&mut self.runner).with_sandbox(sandbox.clone());
+    }
+}
+
+/// @ai:intent Look for the public functions and types the task requires
+#[derive(Default)]
+pub struct ApiConformanceStage {
+    checker: ApiConformanceChecker,
+}
+
+impl EvaluationStage for ApiConformanceStage {
+    fn name(&self) -> &str {
+        "api_conformance"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        result.api_conformance = self.checker.check(input.task, input.source_files);
+        if let Some(conformance) = &result.api_conformance {
+            tracing::info!(
+                "API conformance: {} of {} required items ({} missing, {} with another signature)",
+                conformance.conforming,
+                conformance.required,
+                conformance.missing.len(),
+                conformance.mismatched.len()
+            );
+        }
+        Ok(())
+    }
+
+    fn run_without_code(&self, input: &StageInput, result: &mut EvaluationResult) {
+        // Checked against no files, so every required item is missing
+        result.api_conformance = self.checker.check(input.task, &[]);
+    }
+}
+
+/// @ai:intent Compare with the gold solution, if the task ships one
+#[derive(Default)]
+pub struct ReferenceStage {
+    comparer: ReferenceComparer,
+}
+
+impl EvaluationStage for ReferenceStage {
+    fn name(&self) -> &str {
+        "reference"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        result.reference = self.comparer.compare(input.task, input.source_files);
+        if let Some(alignment) = &result.reference {
+            tracing::info!(
+                "Reference alignment: {:.1}% (API match {:.1}%)",
+                alignment.score,
+                alignment.api_match
+            );
+        }
+        Ok(())
+    }
+
+    fn run_without_code(&self, input: &StageInput, result: &mut EvaluationResult) {
+        result.reference = input
+            .task
+            .reference
+            .as_ref()
+            .map(|_| ReferenceAlignment::default());
+    }
+
+    fn set_sandbox(&mut self, sandbox: &Sandbox) {
+        self.comparer = std::mem::take(&mut self.comparer).with_sandbox(sandbox.clone());
+    }
+}
+
+/// @ai:intent Score inferred annotations against the gold files (inference tasks)
+#[derive(Default)]
+pub struct InferenceStage {
+    scorer: InferenceScorer,
+}
+
+impl EvaluationStage for InferenceStage {
+    fn name(&self) -> &str {
+        "inference"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        result.inference = self.scorer.score(input.task, input.source_files);
+        if let Some(score) = &result.inference {
+            tracing::info!(
+                "Annotation inference: precision {:.1}%, recall {:.1}%, F1 {:.1}%",
+                score.precision,
+                score.recall,
+                score.f1
+            );
+        }
+        Ok(())
+    }
+
+    fn run_without_code(&self, input: &StageInput, result: &mut EvaluationResult) {
+        // Scored against no files, so every gold annotation is missed
+        result.inference = self.scorer.score(input.task, &[]);
+    }
+}
+
+/// @ai:intent Run the hidden contract trap tests (modify tasks)
+#[derive(Default)]
+pub struct ContractStage {
+    checker: ContractChecker,
+}
+
+impl EvaluationStage for ContractStage {
+    fn name(&self) -> &str {
+        "contracts"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        let task = input.task;
+        if task.contract_traps.is_empty() {
+            return Ok(());
+        }
+
+        tracing::info!("Checking {} contract traps...", task.contract_traps.len());
+        let checks = self.checker.check(task, input.source_files);
+        for check in checks
+            .iter()
+            .filter(|c| c.status == ContractStatus::Violated)
+        {
+            tracing::warn!(
+                "Task {} (mode={}) violates contract {}: {}",
+                task.id,
+                input.execution.mode.as_str(),
+                check.trap_id,
+                check.contract
+            );
+        }
+        result.contract_checks = checks;
+        Ok(())
+    }
+
+    fn set_sandbox(&mut self, sandbox: &Sandbox) {
+        self.checker = std::mem::take(&mut self.checker).with_sandbox(sandbox.clone());
+    }
+}
+
+/// @ai:intent AICMS annotation lints of the generated files
+#[derive(Default)]
+pub struct LintStage {
+    linter: LinterAdapter,
+}
+
+impl EvaluationStage for LintStage {
+    fn name(&self) -> &str {
+        "lint"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        result.lint = Some(self.linter.lint(input.generated_files));
+        Ok(())
+    }
+
+    fn configure(&mut self, config: &EvaluationConfig) {
+        self.linter = std::mem::take(&mut self.linter)
+            .with_thresholds(config.lint_fail_on, config.lint_max_warnings);
+    }
+}
+
+/// @ai:intent Score annotations against the gold annotations of inference tasks
+#[derive(Default)]
+pub struct AnnotationStage {
+    scorer: AnnotationScorer,
+}
+
+impl EvaluationStage for AnnotationStage {
+    fn name(&self) -> &str {
+        "annotations"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        result.annotation_score = Some(
+            self.scorer
+                .score(input.generated_files, &expected_annotations(input.task)),
+        );
+        Ok(())
+    }
+
+    fn configure(&mut self, config: &EvaluationConfig) {
+        match AnnotationMatcher::from_config(&config.annotation_matching) {
+            Ok(matcher) => self.scorer = std::mem::take(&mut self.scorer).with_matcher(matcher),
+            Err(e) => tracing::warn!(
+                "Annotation judge unavailable, matching by token overlap: {}",
+                e
+            ),
+        }
+    }
+}
+
+/// @ai:intent Quality control: flag outputs that mostly restate the task description
+#[derive(Default)]
+pub struct LeakageStage {
+    detector: LeakageDetector,
+}
+
+impl EvaluationStage for LeakageStage {
+    fn name(&self) -> &str {
+        "leakage"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        let spec_leakage = self
+            .detector
+            .analyze(&input.task.description, input.combined_code);
+        if spec_leakage.flagged {
+            tracing::warn!(
+                "Task {} (mode={}) echoes the task description: {:.0}% of words copied",
+                input.task.id,
+                input.execution.mode.as_str(),
+                spec_leakage.overlap_ratio * 100.0
+            );
+        }
+        result.spec_leakage = Some(spec_leakage);
+        Ok(())
+    }
+}
+
+/// @ai:intent Quality control: flag outputs that near-duplicate a reference solution
+#[derive(Default)]
+pub struct ContaminationStage {
+    detector: ContaminationDetector,
+}
+
+impl EvaluationStage for ContaminationStage {
+    fn name(&self) -> &str {
+        "contamination"
+    }
+
+    fn run(&self, input: &StageInput, result: &mut EvaluationResult) -> Result<()> {
+        let (task, execution) = (input.task, input.execution);
+        let generated_code: Vec<&str> = input
+            .extracted_files
+            .iter()
+            .map(|f| f.code.as_str())
+            .collect();
+        let fingerprint = self.detector.fingerprint(
+            task,
+            format!(
+                "{} ({}, rep {})",
+                task.id,
+                execution.mode.as_str(),
+                execution.repetition
+            ),
+            &generated_code.join("\n"),
+        );
+        let contamination = self.detector.analyze(&fingerprint);
+        if let Some(contamination) = contamination.as_ref().filter(|c| c.flagged) {
+            let (share, source) = contamination.highest();
+            tracing::warn!(
+                "Task {} (mode={}) looks memorized: {:.0}% of its code matches {}",
+                task.id,
+                execution.mode.as_str(),
+                share,
+                source
+            );
+        }
+
+        result.contamination = contamination;
+        result.fingerprint = Some(fingerprint);
+        Ok(())
+    }
+
+    fn configure(&mut self, config: &EvaluationConfig) {
+        self.detector =
+            std::mem::take(&mut self.detector).with_threshold(config.contamination_threshold);
+    }
+
+    fn set_corpus(&mut self, tasks: &[Task]) {
+        self.detector = std::mem::take(&mut self.detector).with_references(tasks);
+    }
+}
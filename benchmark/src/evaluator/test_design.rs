@@ -0,0 +1,313 @@
+//! @ai:module:intent Detect property-based tests and coverage of the task's edge cases in the
+//!                   model's own tests
+//! @ai:module:layer application
+//! @ai:module:public_api TestDesignAnalyzer, TestDesignAnalyzerTrait, TestDesign
+//! @ai:module:depends_on evaluator::code_size
+//! @ai:module:stateless true
+
+use crate::corpus::{EdgeCase, Language, Task};
+use crate::evaluator::code_size::test_code;
+use crate::evaluator::SourceFile;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Words of edge case names too common to identify a test
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "with", "from", "into", "onto", "that", "when", "than", "are", "not",
+];
+
+/// @ai:intent How the generated tests are designed: property-based tests and edge cases
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TestDesign {
+    /// Property-based testing library in use (proptest, quickcheck, hypothesis, fast-check)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub property_framework: Option<String>,
+    /// Property tests found (proptest! blocks, @given, fc.assert, ...)
+    pub property_tests: u32,
+    /// Edge cases listed by the task
+    pub edge_cases: u32,
+    pub edge_cases_tested: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub untested_edge_cases: Vec<String>,
+}
+
+impl TestDesign {
+    /// @ai:intent Percentage of the task's edge cases the tests cover (None without any)
+    /// @ai:effects pure
+    pub fn edge_case_coverage(&self) -> Option<f64> {
+        (self.edge_cases > 0)
+            .then(|| self.edge_cases_tested as f64 / self.edge_cases as f64 * 100.0)
+    }
+}
+
+/// @ai:intent Trait for test design analysis
+pub trait TestDesignAnalyzerTrait: Send + Sync {
+    /// @ai:intent Analyze the generated tests (None if the generated code has no tests)
+    fn analyze(&self, task: &Task, files: &[SourceFile]) -> Option<TestDesign>;
+}
+
+/// @ai:intent Lexical test design analyzer over test files and inline test modules
+pub struct TestDesignAnalyzer {
+    word_regex: Regex,
+}
+
+impl TestDesignAnalyzer {
+    /// @ai:intent Create a new test design analyzer
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            word_regex: Regex::new(r"[A-Za-z0-9]+").unwrap(),
+        }
+    }
+
+    /// @ai:intent Whether the tests cover an edge case
+    ///            Patterns are matched against all test code; names against each test unit
+    /// @ai:effects pure
+    fn is_tested(&self, edge_case: &EdgeCase, code: &str, units: &[String]) -> bool {
+        if !edge_case.patterns.is_empty() {
+            return edge_case
+                .patterns
+                .iter()
+                .any(|pattern| match Regex::new(pattern) {
+                    Ok(regex) => regex.is_match(code),
+                    Err(e) => {
+                        tracing::warn!("Invalid edge case pattern {:?}: {}", pattern, e);
+                        false
+                    }
+                });
+        }
+
+        let keywords: Vec<String> = self
+            .word_regex
+            .find_iter(&edge_case.name)
+            .map(|m| m.as_str().to_lowercase())
+            .filter(|w| w.len() >= 3 && !STOPWORDS.contains(&w.as_str()))
+            .collect();
+        !keywords.is_empty()
+            && units
+                .iter()
+                .any(|unit| keywords.iter().all(|k| unit.contains(k.as_str())))
+    }
+}
+
+impl Default for TestDesignAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestDesignAnalyzerTrait for TestDesignAnalyzer {
+    /// @ai:intent Detect property tests and check the task's edge cases against the tests
+    /// @ai:effects pure
+    fn analyze(&self, task: &Task, files: &[SourceFile]) -> Option<TestDesign> {
+        let code = test_code(files, task.language);
+        if code.trim().is_empty() {
+            return None;
+        }
+
+        let (property_framework, property_tests) = property_tests(&code, task.language);
+
+        let units = test_units(&code, task.language);
+        let untested_edge_cases: Vec<String> = task
+            .edge_cases
+            .iter()
+            .filter(|edge_case| !self.is_tested(edge_case, &code, &units))
+            .map(|edge_case| edge_case.name.clone())
+            .collect();
+
+        Some(TestDesign {
+            property_framework,
+            property_tests,
+            edge_cases: task.edge_cases.len() as u32,
+            edge_cases_tested: (task.edge_cases.len() - untested_edge_cases.len()) as u32,
+            untested_edge_cases,
+        })
+    }
+}
+
+/// @ai:intent Property-based testing library used by the tests, and how many property tests
+/// @ai:effects pure
+fn property_tests(code: &str, language: Language) -> (Option<String>, u32) {
+    let frameworks: &[(&str, &str, &str)] = match language {
+        Language::Rust => &[
+            (
+                "proptest",
+                r"\bproptest\b",
+                r"(?m)^\s*fn\s+\w+\s*\([^)]*\bin\b",
+            ),
+            (
+                "quickcheck",
+                r"\bquickcheck\b",
+                r"#\[quickcheck\]|\bquickcheck\s*\(",
+            ),
+        ],
+        Language::Python => &[("hypothesis", r"\bhypothesis\b", r"@given\s*\(")],
+        Language::TypeScript => &[("fast-check", r"fast-check", r"\bfc\.(?:assert|check)\s*\(")],
+        Language::Go => &[
+            ("rapid", r"pgregory\.net/rapid", r"\brapid\.Check\s*\("),
+            (
+                "testing/quick",
+                r#""testing/quick""#,
+                r"\bquick\.Check(?:Equal)?\s*\(",
+            ),
+            ("go fuzz", r"\*testing\.F\b", r"(?m)^func\s+Fuzz\w*\s*\("),
+        ],
+        Language::Java => &[
+            ("jqwik", r"\bnet\.jqwik\b", r"@Property\b"),
+            (
+                "junit-quickcheck",
+                r"\bcom\.pholser\.junit\.quickcheck\b",
+                r"@Property\b",
+            ),
+        ],
+    };
+
+    for (name, import, test) in frameworks {
+        if Regex::new(import).unwrap().is_match(code) {
+            let count = Regex::new(test).unwrap().find_iter(code).count() as u32;
+            return (Some(name.to_string()), count);
+        }
+    }
+    (None, 0)
+}
+
+/// @ai:intent Split test code into lowercased units, one per test function or test case
+///            Identifiers are split into words (`transfer_to_self`, `transferToSelf`)
+/// @ai:effects pure
+fn test_units(code: &str, language: Language) -> Vec<String> {
+    let start = match language {
+        Language::Rust => r"(?m)^\s*(?:pub\s+)?(?:async\s+)?fn\s",
+        Language::Python => r"(?m)^\s*(?:async\s+)?def\s",
+        Language::TypeScript => r"\b(?:it|test)\s*\(",
+        // Test functions and their subtests
+        Language::Go => r"(?m)^func\s|\bt\.Run\s*\(",
+        // Annotated test methods
+        Language::Java => r"@(?:Test|ParameterizedTest|RepeatedTest|Property)\b",
+    };
+
+    let starts: Vec<usize> = Regex::new(start)
+        .unwrap()
+        .find_iter(code)
+        .map(|m| m.start())
+        .collect();
+    let mut bounds = vec![0];
+    bounds.extend(&starts);
+    bounds.push(code.len());
+
+    bounds
+        .windows(2)
+        .map(|w| {
+            let mut unit = String::with_capacity(w[1] - w[0]);
+            for c in code[w[0]..w[1]].chars() {
+                match c {
+                    '_' => unit.push(' '),
+                    c if c.is_uppercase() => {
+                        unit.push(' ');
+                        unit.extend(c.to_lowercase());
+                    }
+                    c => unit.push(c),
+                }
+            }
+            unit
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(language: Language, edge_cases: Vec<EdgeCase>) -> Task {
+        Task {
+            id: "t".to_string(),
+            name: "T".to_string(),
+            language,
+            edge_cases,
+            ..Default::default()
+        }
+    }
+
+    fn edge_case(name: &str, patterns: &[&str]) -> EdgeCase {
+        EdgeCase {
+            name: name.to_string(),
+            patterns: patterns.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_rust_edge_cases_and_proptest() {
+        let files = vec![SourceFile {
+            path: "src/lib.rs".to_string(),
+            content: r#"
+pub fn transfer(from: u32, to: u32, amount: i64) {}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    #[test]
+    fn transfer_to_same_account_fails() {}
+
+    #[test]
+    fn zero_fee() { charge(0); }
+
+    proptest! {
+        #[test]
+        fn balance_never_negative(amount in 0i64..1000) {}
+    }
+}
+"#
+            .to_string(),
+        }];
+        let task = task(
+            Language::Rust,
+            vec![
+                edge_case("transfer to the same account", &[]),
+                edge_case("zero fee", &[r"charge\(0\)"]),
+                edge_case("unknown account", &[]),
+            ],
+        );
+
+        let design = TestDesignAnalyzer::new().analyze(&task, &files).unwrap();
+        assert_eq!(design.property_framework.as_deref(), Some("proptest"));
+        assert_eq!(design.property_tests, 1);
+        assert_eq!(design.edge_cases_tested, 2);
+        assert_eq!(
+            design.untested_edge_cases,
+            vec!["unknown account".to_string()]
+        );
+        assert!((design.edge_case_coverage().unwrap() - 200.0 / 3.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_python_hypothesis_and_no_tests() {
+        let files = vec![
+            SourceFile {
+                path: "stack.py".to_string(),
+                content: "def push(s, x):\n    s.append(x)\n".to_string(),
+            },
+            SourceFile {
+                path: "test_stack.py".to_string(),
+                content: "from hypothesis import given\n\n@given(st.lists(st.integers()))\ndef test_push(xs):\n    pass\n\ndef test_popEmptyStack():\n    pass\n".to_string(),
+            },
+        ];
+        let task = task(
+            Language::Python,
+            vec![
+                edge_case("pop from an empty stack", &[]),
+                edge_case("push onto a full stack", &[]),
+            ],
+        );
+
+        let design = TestDesignAnalyzer::new().analyze(&task, &files).unwrap();
+        assert_eq!(design.property_framework.as_deref(), Some("hypothesis"));
+        assert_eq!(design.property_tests, 1);
+        assert_eq!(design.edge_cases_tested, 1);
+        assert_eq!(
+            design.untested_edge_cases,
+            vec!["push onto a full stack".to_string()]
+        );
+
+        assert_eq!(TestDesignAnalyzer::new().analyze(&task, &files[..1]), None);
+    }
+}
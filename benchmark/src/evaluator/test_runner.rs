@@ -1,13 +1,18 @@
 //! @ai:module:intent Execute tests against generated code
 //! @ai:module:layer infrastructure
-//! @ai:module:public_api TestRunner, TestResult
+//! @ai:module:public_api TestRunner, TestResult, TestDeterminism
 //! @ai:module:stateless true
 
 use crate::corpus::Language;
+use crate::evaluator::reference::is_test_file;
+use crate::evaluator::sandbox::Sandbox;
 use crate::evaluator::SourceFile;
 use anyhow::Result;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::io::Write;
-use std::process::Command;
+use std::path::Path;
+use std::process::Output;
 use tempfile::TempDir;
 
 /// @ai:intent Result of running tests
@@ -31,12 +36,38 @@ impl TestResult {
     }
 }
 
+/// @ai:intent Agreement between repeated runs of the same test suite. Runs are compared by
+///            their counts, since the runners do not report which tests passed
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TestDeterminism {
+    /// Tests passed in each run, in run order
+    pub passed: Vec<u32>,
+    /// Tests counted in each run
+    pub total: Vec<u32>,
+    /// True if any run's passed, failed or total count differs from the first run's
+    pub flaky: bool,
+}
+
+impl TestDeterminism {
+    /// @ai:intent Compare the outcomes of runs of one test suite
+    /// @ai:effects pure
+    pub fn from_runs(runs: &[TestResult]) -> Self {
+        let counts = |r: &TestResult| (r.passed, r.failed, r.total);
+        Self {
+            passed: runs.iter().map(|r| r.passed).collect(),
+            total: runs.iter().map(|r| r.total).collect(),
+            flaky: runs.windows(2).any(|w| counts(&w[0]) != counts(&w[1])),
+        }
+    }
+}
+
 /// @ai:intent Trait for test execution
 pub trait TestRunnerTrait: Send + Sync {
     /// @ai:intent Run tests against single-file generated code
     fn run(&self, code: &str, test_code: &str, language: Language) -> Result<TestResult>;
 
-    /// @ai:intent Run tests against multi-file generated code
+    /// @ai:intent Run the given test files (paths relative to the project) against
+    ///            multi-file generated code; the project's own tests are not counted
     fn run_files(
         &self,
         source_files: &[SourceFile],
@@ -46,13 +77,24 @@ pub trait TestRunnerTrait: Send + Sync {
 }
 
 /// @ai:intent Executes tests for generated code
-pub struct TestRunner;
+pub struct TestRunner {
+    sandbox: Sandbox,
+}
 
 impl TestRunner {
-    /// @ai:intent Create a new test runner
+    /// @ai:intent Create a new test runner (runs tests on the host)
     /// @ai:effects pure
     pub fn new() -> Self {
-        Self
+        Self {
+            sandbox: Sandbox::default(),
+        }
+    }
+
+    /// @ai:intent Run tests in the given sandbox
+    /// @ai:effects pure
+    pub fn with_sandbox(mut self, sandbox: Sandbox) -> Self {
+        self.sandbox = sandbox;
+        self
     }
 
     /// @ai:intent Run Rust tests
@@ -60,6 +102,8 @@ impl TestRunner {
     fn run_rust(&self, code: &str, test_code: &str) -> Result<TestResult> {
         let temp_dir = TempDir::new()?;
         let src_path = temp_dir.path().join("main.rs");
+        // rustc -o names the binary exactly; Windows only runs it with .exe
+        let test_bin = format!("test_bin{}", std::env::consts::EXE_SUFFIX);
 
         let combined = format!("{code}\n\n#[cfg(test)]\nmod tests {{\n    use super::*;\n{test_code}\n}}");
 
@@ -67,12 +111,14 @@ impl TestRunner {
         file.write_all(combined.as_bytes())?;
         drop(file);
 
-        let output = Command::new("rustc")
+        let output = self
+            .sandbox
+            .command("rustc", temp_dir.path(), Language::Rust)
             .arg("--test")
             .arg("--edition=2021")
             .arg("-o")
-            .arg(temp_dir.path().join("test_bin"))
-            .arg(&src_path)
+            .arg(&test_bin)
+            .arg("main.rs")
             .output()?;
 
         if !output.status.success() {
@@ -85,10 +131,14 @@ impl TestRunner {
             });
         }
 
-        let test_output = Command::new(temp_dir.path().join("test_bin")).output()?;
+        let test_output = self
+            .sandbox
+            .command(&format!("./{}", test_bin), temp_dir.path(), Language::Rust)
+            .output()?;
 
         let stdout = String::from_utf8_lossy(&test_output.stdout);
-        parse_rust_test_output(&stdout)
+        let result = parse_rust_test_output(&stdout)?;
+        Ok(aborted_run(result, &test_output))
     }
 
     /// @ai:intent Run Python tests
@@ -103,13 +153,18 @@ impl TestRunner {
         file.write_all(combined.as_bytes())?;
         drop(file);
 
-        let output = Command::new("python").arg(&src_path).output()?;
+        let output = self
+            .sandbox
+            .command("python", temp_dir.path(), Language::Python)
+            .arg("test_main.py")
+            .output()?;
 
         let stderr = String::from_utf8_lossy(&output.stderr);
         parse_python_test_output(&stderr)
     }
 
-    /// @ai:intent Run TypeScript tests (using ts-node and basic assertions)
+    /// @ai:intent Run TypeScript tests: the code and the tests as one file, under vitest or
+    ///            jest if the tests are written for one, else as a ts-node script
     /// @ai:effects fs:write, io
     fn run_typescript(&self, code: &str, test_code: &str) -> Result<TestResult> {
         let temp_dir = TempDir::new()?;
@@ -121,24 +176,204 @@ impl TestRunner {
         file.write_all(combined.as_bytes())?;
         drop(file);
 
-        let output = Command::new("npx")
-            .arg("ts-node")
-            .arg(&src_path)
+        let files = [SourceFile {
+            path: "test.ts".to_string(),
+            content: combined,
+        }];
+        self.typescript_test(temp_dir.path(), &files, &["test.ts".to_string()])
+    }
+
+    /// @ai:intent Run Go tests: the code and the test functions as one package
+    /// @ai:effects fs:write, io
+    fn run_go(&self, code: &str, test_code: &str) -> Result<TestResult> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        // Test code without its own package clause is a list of test functions
+        let test_code = if declared_package(test_code).is_some() {
+            test_code.to_string()
+        } else {
+            let package = declared_package(code).unwrap_or("main");
+            format!("package {package}\n\nimport \"testing\"\n\n{test_code}")
+        };
+        std::fs::write(root.join("main.go"), code)?;
+        std::fs::write(root.join("main_test.go"), test_code)?;
+        std::fs::write(root.join("go.mod"), GO_MOD)?;
+
+        self.go_test(root)
+    }
+
+    /// @ai:intent Run `go test` on every package of a module laid out under root
+    /// @ai:effects io
+    fn go_test(&self, root: &Path) -> Result<TestResult> {
+        let output = self
+            .sandbox
+            .command("go", root, Language::Go)
+            .args(["test", "-json", "./..."])
             .output()?;
 
-        let success = output.status.success();
         let stdout = String::from_utf8_lossy(&output.stdout);
         let stderr = String::from_utf8_lossy(&output.stderr);
+        let result = parse_go_test_output(&stdout, &stderr);
+        Ok(aborted_run(result, &output))
+    }
 
-        Ok(TestResult {
-            passed: if success { 1 } else { 0 },
-            failed: if success { 0 } else { 1 },
-            total: 1,
-            output: format!("{}{}", stdout, stderr),
-        })
+    /// @ai:intent Run Java tests: the code and a JUnit 5 test class in the same package
+    /// @ai:effects fs:write, io
+    fn run_java(&self, code: &str, test_code: &str) -> Result<TestResult> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        // Test code without a class of its own is a list of @Test methods
+        let test_code = if test_code.contains("class ") {
+            test_code.to_string()
+        } else {
+            let package = declared_package(code)
+                .map(|package| format!("package {package};\n\n"))
+                .unwrap_or_default();
+            format!(
+                "{package}import org.junit.jupiter.api.Test;\nimport static org.junit.jupiter.api.Assertions.*;\n\nclass GeneratedTest {{\n{test_code}\n}}\n"
+            )
+        };
+        let code_file = java_file_name(code).unwrap_or_else(|| "Main.java".to_string());
+        let test_file =
+            java_file_name(&test_code).unwrap_or_else(|| "GeneratedTest.java".to_string());
+        std::fs::write(root.join(code_file), code)?;
+        std::fs::write(root.join(test_file), &test_code)?;
+
+        self.java_test(root)
+    }
+
+    /// @ai:intent Compile every Java source under root and run it with the JUnit console
+    ///            launcher; a build failure counts as one failed test
+    /// @ai:effects io
+    fn java_test(&self, root: &Path) -> Result<TestResult> {
+        let Some(jar) = self.sandbox.junit_jar() else {
+            return Ok(junit_not_configured());
+        };
+
+        let build = self.javac_with_junit(root, jar)?;
+        if !build.status.success() {
+            let result = TestResult {
+                passed: 0,
+                failed: 0,
+                total: 0,
+                output: String::new(),
+            };
+            return Ok(aborted_run(result, &build));
+        }
+
+        let output = self.junit_console(root, jar)?;
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let result = parse_junit_output(&stdout, &stderr);
+        Ok(aborted_run(result, &output))
+    }
+
+    /// @ai:intent Run the given TypeScript test files of the project written under root with
+    ///            the framework they are written for (see typescript_test_framework)
+    ///            vitest gets a minimal config if the project has none; ts-node scripts count
+    ///            as one test each, failed if the script exits non-zero
+    /// @ai:pre files are the project's files (tests included), already written under root
+    /// @ai:effects fs:write, io
+    fn typescript_test(
+        &self,
+        root: &Path,
+        files: &[SourceFile],
+        tests: &[String],
+    ) -> Result<TestResult> {
+        let output = match typescript_test_framework(files) {
+            TypeScriptTestFramework::Vitest => {
+                let mut command = self.sandbox.command("npx", root, Language::TypeScript);
+                command
+                    .args(["vitest", "run", "--reporter=json"])
+                    .arg(format!("--outputFile={TS_TEST_REPORT}"));
+                if files.iter().any(|f| is_config_file(&f.path, "vitest")) {
+                    command.args(tests);
+                } else {
+                    std::fs::write(root.join(VITEST_CONFIG), vitest_config(tests))?;
+                }
+                command.output()?
+            }
+            TypeScriptTestFramework::Jest => self
+                .sandbox
+                .command("npx", root, Language::TypeScript)
+                .args(["jest", "--ci", "--json"])
+                .arg(format!("--outputFile={TS_TEST_REPORT}"))
+                .arg("--runTestsByPath")
+                .args(tests)
+                .output()?,
+            TypeScriptTestFramework::TsNode => return self.ts_node_scripts(root, tests),
+        };
+
+        let report = std::fs::read_to_string(root.join(TS_TEST_REPORT)).ok();
+        let result = parse_jest_report(
+            report.as_deref(),
+            &String::from_utf8_lossy(&output.stdout),
+            &String::from_utf8_lossy(&output.stderr),
+        );
+        Ok(aborted_run(result, &output))
+    }
+
+    /// @ai:intent Run each test file as a ts-node script, one test per script
+    /// @ai:effects io
+    fn ts_node_scripts(&self, root: &Path, tests: &[String]) -> Result<TestResult> {
+        let mut result = TestResult {
+            passed: 0,
+            failed: 0,
+            total: 0,
+            output: String::new(),
+        };
+        for test in tests {
+            let output = self
+                .sandbox
+                .command("npx", root, Language::TypeScript)
+                .arg("ts-node")
+                .arg(test)
+                .output()?;
+
+            if output.status.success() {
+                result.passed += 1;
+            } else {
+                result.failed += 1;
+            }
+            result.total += 1;
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            result.output.push_str(&format!("{stdout}{stderr}"));
+        }
+        Ok(result)
+    }
+
+    /// @ai:intent Compile every Java source under root (tests included) into JAVA_CLASSES
+    /// @ai:effects io
+    fn javac_with_junit(&self, root: &Path, jar: &str) -> Result<Output> {
+        Ok(self
+            .sandbox
+            .command("javac", root, Language::Java)
+            .args(["-d", JAVA_CLASSES, "-encoding", "UTF-8", "-cp", jar])
+            .args(java_sources(root, true))
+            .output()?)
+    }
+
+    /// @ai:intent Run every test class found in JAVA_CLASSES with the JUnit console launcher
+    /// @ai:effects io
+    fn junit_console(&self, root: &Path, jar: &str) -> Result<Output> {
+        Ok(self
+            .sandbox
+            .command("java", root, Language::Java)
+            .args(["-jar", jar, "execute", "--class-path", JAVA_CLASSES])
+            .args([
+                "--scan-class-path",
+                "--disable-banner",
+                "--disable-ansi-colors",
+                "--details=tree",
+            ])
+            .output()?)
     }
 
     /// @ai:intent Run multi-file Rust tests using Cargo
+    ///            Only the given integration tests run, not the project's own unit tests
     /// @ai:effects fs:write, io
     fn run_rust_files(
         &self,
@@ -146,27 +381,14 @@ impl TestRunner {
         test_files: &[SourceFile],
     ) -> Result<TestResult> {
         let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
 
-        // Check if source files include a Cargo.toml
-        let has_cargo_toml = source_files.iter().any(|f| {
-            f.path == "Cargo.toml"
-                || f.path.ends_with("/Cargo.toml")
-                || f.path.ends_with("\\Cargo.toml")
-        });
-
-        // Create src directory and write source files
-        let src_dir = temp_dir.path().join("src");
-        std::fs::create_dir_all(&src_dir)?;
-
+        // Cargo.toml goes at root, source files under src/
         for source_file in source_files {
-            // Cargo.toml goes at root, source files under src/
-            let file_path = if source_file.path == "Cargo.toml"
-                || source_file.path.ends_with("/Cargo.toml")
-                || source_file.path.ends_with("\\Cargo.toml")
-            {
-                temp_dir.path().join("Cargo.toml")
+            let file_path = if is_cargo_toml(&source_file.path) {
+                root.join("Cargo.toml")
             } else {
-                temp_dir.path().join(&source_file.path)
+                root.join(normalize_rust_path(&source_file.path))
             };
 
             if let Some(parent) = file_path.parent() {
@@ -177,43 +399,37 @@ impl TestRunner {
         }
 
         // Only create minimal Cargo.toml if none was provided
-        if !has_cargo_toml {
-            let cargo_toml = r#"[package]
-name = "benchmark_project"
-version = "0.1.0"
-edition = "2021"
-
-[dependencies]
-
-[dev-dependencies]
-"#;
-            std::fs::write(temp_dir.path().join("Cargo.toml"), cargo_toml)?;
+        let crate_name = rust_crate_name(source_files);
+        if !source_files.iter().any(|f| is_cargo_toml(&f.path)) {
+            let cargo_toml = format!(
+                "[package]\nname = \"{crate_name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n"
+            );
+            std::fs::write(root.join("Cargo.toml"), cargo_toml)?;
         }
 
-        // Create tests directory and write test files
-        let tests_dir = temp_dir.path().join("tests");
-        std::fs::create_dir_all(&tests_dir)?;
+        let mut cmd = self.sandbox.command("cargo", root, Language::Rust);
+        cmd.arg("test");
 
+        // Test files are project-relative (tests/*.rs) and name the crate as {{crate}}
         for test_file in test_files {
-            let file_path = tests_dir.join(&test_file.path);
+            let file_path = root.join(&test_file.path);
 
             if let Some(parent) = file_path.parent() {
                 std::fs::create_dir_all(parent)?;
             }
 
-            std::fs::write(&file_path, &test_file.content)?;
+            std::fs::write(&file_path, test_file.content.replace("{{crate}}", &crate_name))?;
+
+            if let Some(name) = file_path.file_stem().and_then(|s| s.to_str()) {
+                cmd.args(["--test", name]);
+            }
         }
 
-        // Run cargo test
-        let output = Command::new("cargo")
-            .arg("test")
-            .arg("--")
-            .arg("--test-threads=1")
-            .current_dir(temp_dir.path())
-            .output()?;
+        let output = cmd.arg("--").arg("--test-threads=1").output()?;
 
         let stdout = String::from_utf8_lossy(&output.stdout);
-        parse_rust_test_output(&stdout)
+        let result = parse_rust_test_output(&stdout)?;
+        Ok(aborted_run(result, &output))
     }
 
     /// @ai:intent Run multi-file Python tests using pytest
@@ -252,17 +468,80 @@ edition = "2021"
             std::fs::write(&file_path, &test_file.content)?;
         }
 
-        // Run pytest
-        let output = Command::new("python")
+        self.sandbox
+            .install_dependencies(temp_dir.path(), Language::Python)?;
+
+        // Run pytest on the given test files only
+        let output = self
+            .sandbox
+            .command("python", temp_dir.path(), Language::Python)
             .arg("-m")
             .arg("pytest")
             .arg("-v")
-            .current_dir(temp_dir.path())
+            .args(test_files.iter().map(|f| &f.path))
             .output()?;
 
         let stdout = String::from_utf8_lossy(&output.stdout);
         let stderr = String::from_utf8_lossy(&output.stderr);
-        parse_pytest_output(&stdout, &stderr)
+        let result = parse_pytest_output(&stdout, &stderr)?;
+        // A missing pytest also fails without results; only a killed run counts as failed
+        Ok(if was_killed(&output) {
+            aborted_run(result, &output)
+        } else {
+            result
+        })
+    }
+
+    /// @ai:intent Run multi-file Go tests
+    ///            The project's own _test.go files are left out, so only the given tests count
+    /// @ai:effects fs:write, io
+    fn run_go_files(
+        &self,
+        source_files: &[SourceFile],
+        test_files: &[SourceFile],
+    ) -> Result<TestResult> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        let sources: Vec<SourceFile> = source_files
+            .iter()
+            .filter(|f| !is_test_file(&f.path, Language::Go))
+            .cloned()
+            .collect();
+        write_project(root, &sources, Language::Go)?;
+        for test_file in test_files {
+            let file_path = root.join(&test_file.path);
+
+            if let Some(parent) = file_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            std::fs::write(&file_path, &test_file.content)?;
+        }
+
+        self.go_test(root)
+    }
+
+    /// @ai:intent Run multi-file Java tests
+    ///            The project's own test classes are left out, so only the given tests count
+    /// @ai:effects fs:write, io
+    fn run_java_files(
+        &self,
+        source_files: &[SourceFile],
+        test_files: &[SourceFile],
+    ) -> Result<TestResult> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        let sources: Vec<SourceFile> = source_files
+            .iter()
+            .filter(|f| !is_test_file(&f.path, Language::Java))
+            .cloned()
+            .collect();
+        write_project(root, &sources, Language::Java)?;
+        write_project(root, test_files, Language::Java)?;
+
+        self.java_test(root)
     }
 
     /// @ai:intent Run multi-file TypeScript tests
@@ -306,29 +585,15 @@ edition = "2021"
   "include": ["**/*.ts"]
 }"#;
         std::fs::write(temp_dir.path().join("tsconfig.json"), tsconfig)?;
+        self.sandbox
+            .install_dependencies(temp_dir.path(), Language::TypeScript)?;
 
-        // Run test file with ts-node (assuming first test file is the entry)
-        let test_entry = test_files
-            .first()
-            .map(|f| f.path.clone())
-            .unwrap_or_else(|| "test.ts".to_string());
-
-        let output = Command::new("npx")
-            .arg("ts-node")
-            .arg(&test_entry)
-            .current_dir(temp_dir.path())
-            .output()?;
-
-        let success = output.status.success();
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        let stderr = String::from_utf8_lossy(&output.stderr);
-
-        Ok(TestResult {
-            passed: if success { 1 } else { 0 },
-            failed: if success { 0 } else { 1 },
-            total: 1,
-            output: format!("{}{}", stdout, stderr),
-        })
+        let mut tests: Vec<String> = test_files.iter().map(|f| f.path.clone()).collect();
+        if tests.is_empty() {
+            tests.push("test.ts".to_string());
+        }
+        let files: Vec<SourceFile> = source_files.iter().chain(test_files).cloned().collect();
+        self.typescript_test(temp_dir.path(), &files, &tests)
     }
 }
 
@@ -338,23 +603,57 @@ impl Default for TestRunner {
     }
 }
 
+/// @ai:intent Count a failed run that reported no results (build error, killed after a
+///            timeout) as one failed test, keeping its stderr
+/// @ai:effects pure
+fn aborted_run(result: TestResult, output: &Output) -> TestResult {
+    if output.status.success() || result.total > 0 {
+        return result;
+    }
+    TestResult {
+        passed: 0,
+        failed: 1,
+        total: 1,
+        output: format!(
+            "Build/test failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        ),
+    }
+}
+
+/// @ai:intent Whether the command was killed by a signal (timeout, CPU limit, crash)
+/// @ai:effects pure
+fn was_killed(output: &Output) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        output.status.signal().is_some()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = output;
+        false
+    }
+}
+
 /// @ai:intent Parse Rust test output for pass/fail counts
 /// @ai:effects pure
 fn parse_rust_test_output(output: &str) -> Result<TestResult> {
     let mut passed = 0u32;
     let mut failed = 0u32;
 
+    // One summary per test binary (unit tests, integration tests, doc-tests)
     for line in output.lines() {
         if line.contains("test result:") {
             let parts: Vec<&str> = line.split_whitespace().collect();
 
             for (i, part) in parts.iter().enumerate() {
                 if *part == "passed;" && i > 0 {
-                    passed = parts[i - 1].parse().unwrap_or(0);
+                    passed += parts[i - 1].parse::<u32>().unwrap_or(0);
                 }
 
                 if *part == "failed;" && i > 0 {
-                    failed = parts[i - 1].parse().unwrap_or(0);
+                    failed += parts[i - 1].parse::<u32>().unwrap_or(0);
                 }
             }
         }
@@ -409,9 +708,233 @@ fn parse_python_test_output(output: &str) -> Result<TestResult> {
     })
 }
 
+/// @ai:intent One event of `go test -json` (test2json)
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct GoTestEvent {
+    action: String,
+    #[serde(default)]
+    test: Option<String>,
+    #[serde(default)]
+    output: Option<String>,
+}
+
+/// @ai:intent Parse `go test -json` output for pass/fail counts
+///            Subtests are not counted separately: a failing subtest fails its test
+/// @ai:effects pure
+fn parse_go_test_output(stdout: &str, stderr: &str) -> TestResult {
+    let mut passed = 0u32;
+    let mut failed = 0u32;
+    let mut output = String::new();
+
+    for line in stdout.lines() {
+        // Build errors of older Go versions are printed as plain text
+        let Ok(event) = serde_json::from_str::<GoTestEvent>(line) else {
+            output.push_str(line);
+            output.push('\n');
+            continue;
+        };
+        if let Some(text) = &event.output {
+            output.push_str(text);
+        }
+        if event.test.is_none_or(|test| test.contains('/')) {
+            continue;
+        }
+        match event.action.as_str() {
+            "pass" => passed += 1,
+            "fail" => failed += 1,
+            _ => {}
+        }
+    }
+    output.push_str(stderr);
+
+    TestResult {
+        passed,
+        failed,
+        total: passed + failed,
+        output,
+    }
+}
+
+/// @ai:intent Parse the JUnit console launcher summary for pass/fail counts
+///            Aborted tests (failed assumptions) count as skipped, like JUnit does
+/// @ai:effects pure
+/// @ai:example ("[  2 tests successful  ]\n[  1 tests failed  ]", "") -> passed 2, failed 1
+fn parse_junit_output(stdout: &str, stderr: &str) -> TestResult {
+    let mut passed = 0u32;
+    let mut failed = 0u32;
+
+    for line in stdout.lines() {
+        let summary = line.trim().trim_start_matches('[').trim_end_matches(']');
+        let words: Vec<&str> = summary.split_whitespace().collect();
+        let [count, "tests", status] = words.as_slice() else {
+            continue;
+        };
+        let Ok(count) = count.parse() else {
+            continue;
+        };
+        match *status {
+            "successful" => passed = count,
+            "failed" => failed = count,
+            _ => {}
+        }
+    }
+
+    TestResult {
+        passed,
+        failed,
+        total: passed + failed,
+        output: format!("{stdout}{stderr}"),
+    }
+}
+
+/// @ai:intent Result of a Java test run without a configured JUnit console launcher
+/// @ai:effects pure
+fn junit_not_configured() -> TestResult {
+    TestResult {
+        passed: 0,
+        failed: 0,
+        total: 0,
+        output: "JUnit console launcher not configured (set junit_jar in [sandbox])".to_string(),
+    }
+}
+
+/// @ai:intent Test framework TypeScript tests are written for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeScriptTestFramework {
+    Vitest,
+    /// Only when the project declares or configures it: jest needs a TypeScript transform
+    /// (ts-jest, babel) that the project must set up
+    Jest,
+    /// A plain script asserting with node:assert or by throwing
+    TsNode,
+}
+
+/// @ai:intent Framework the project's TypeScript tests are written for
+///            A framework declared in package.json or configured by the project wins; then
+///            one the tests import; then tests using describe/it/test globals with expect
+///            run under vitest (whose globals are jest-compatible); anything else is a script
+/// @ai:effects pure
+/// @ai:example ([sum.test.ts "import { it, expect } from 'vitest'"]) -> Vitest
+/// @ai:example ([test.ts "assert.strictEqual(sum(1, 2), 3);"]) -> TsNode
+fn typescript_test_framework(files: &[SourceFile]) -> TypeScriptTestFramework {
+    let declared = |name: &str| {
+        files.iter().filter(|f| f.path == "package.json").any(|f| {
+            let manifest: serde_json::Value = serde_json::from_str(&f.content).unwrap_or_default();
+            ["dependencies", "devDependencies"]
+                .iter()
+                .any(|section| manifest[section].get(name).is_some())
+        })
+    };
+    let configured = |name: &str| files.iter().any(|f| is_config_file(&f.path, name));
+    let imported = |module: &str| {
+        files.iter().any(|f| {
+            f.content.contains(&format!("from '{module}'"))
+                || f.content.contains(&format!("from \"{module}\""))
+        })
+    };
+
+    if declared("vitest") || configured("vitest") {
+        TypeScriptTestFramework::Vitest
+    } else if declared("jest") || configured("jest") {
+        TypeScriptTestFramework::Jest
+    } else if imported("vitest") {
+        TypeScriptTestFramework::Vitest
+    } else if imported("@jest/globals") {
+        TypeScriptTestFramework::Jest
+    } else if uses_test_globals(files) {
+        TypeScriptTestFramework::Vitest
+    } else {
+        TypeScriptTestFramework::TsNode
+    }
+}
+
+/// @ai:intent Whether some file calls describe/it/test and expect as globals
+/// @ai:effects pure
+fn uses_test_globals(files: &[SourceFile]) -> bool {
+    let Ok(call) = Regex::new(r"(?m)^\s*(?:describe|it|test)(?:\.\w+)?\s*\(") else {
+        return false;
+    };
+    files
+        .iter()
+        .any(|f| call.is_match(&f.content) && f.content.contains("expect("))
+}
+
+/// @ai:intent Whether path is a config file of the named tool (vitest.config.ts, ...)
+/// @ai:effects pure
+fn is_config_file(path: &str, tool: &str) -> bool {
+    let name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    name.starts_with(&format!("{tool}.config."))
+}
+
+/// @ai:intent Minimal vitest config running exactly the given test files, with globals
+/// @ai:effects pure
+fn vitest_config(tests: &[String]) -> String {
+    let include = serde_json::to_string(tests).unwrap_or_else(|_| "[]".to_string());
+    format!(
+        "export default {{\n  test: {{\n    globals: true,\n    include: {include},\n  }},\n}};\n"
+    )
+}
+
+/// @ai:intent Test report written by jest --json and vitest's json reporter (same format)
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JestReport {
+    num_passed_tests: u32,
+    num_failed_tests: u32,
+    #[serde(default)]
+    test_results: Vec<JestSuiteResult>,
+}
+
+/// @ai:intent One test file of a JestReport
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JestSuiteResult {
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    assertion_results: Vec<serde_json::Value>,
+}
+
+/// @ai:intent Parse a jest/vitest JSON report for per-test pass/fail counts
+///            A test file that failed before running any test (syntax or import error)
+///            counts as one failed test; skipped and todo tests are not counted
+/// @ai:effects pure
+/// @ai:example (Some(r#"{"numPassedTests": 3, "numFailedTests": 1}"#), "", "") -> passed 3, failed 1
+fn parse_jest_report(report: Option<&str>, stdout: &str, stderr: &str) -> TestResult {
+    let mut output = format!("{stdout}{stderr}");
+    let report = report.and_then(|report| serde_json::from_str::<JestReport>(report).ok());
+    let Some(report) = report else {
+        return TestResult {
+            passed: 0,
+            failed: 0,
+            total: 0,
+            output,
+        };
+    };
+
+    let mut failed = report.num_failed_tests;
+    for suite in &report.test_results {
+        if suite.status == "failed" && suite.assertion_results.is_empty() {
+            failed += 1;
+            output.push_str(&suite.message);
+            output.push('\n');
+        }
+    }
+
+    TestResult {
+        passed: report.num_passed_tests,
+        failed,
+        total: report.num_passed_tests + failed,
+        output,
+    }
+}
+
 /// @ai:intent Normalize Rust file path to be under src/ directory
 /// @ai:effects pure
-fn normalize_rust_path(path: &str) -> String {
+pub(crate) fn normalize_rust_path(path: &str) -> String {
     // If path already starts with "src/", use as-is
     if path.starts_with("src/") || path.starts_with("src\\") {
         return path.to_string();
@@ -444,7 +967,7 @@ fn parse_pytest_output(stdout: &str, stderr: &str) -> Result<TestResult> {
             if let Some(pos) = line.find("failed") {
                 let before = &line[..pos];
 
-                if let Some(num_str) = before.split(',').last() {
+                if let Some(num_str) = before.split(',').next_back() {
                     if let Some(n) = num_str.split_whitespace().last() {
                         failed = n.parse().unwrap_or(0);
                     }
@@ -469,6 +992,8 @@ impl TestRunner {
             Language::Rust => self.run_rust_own_tests(source_files),
             Language::Python => self.run_python_own_tests(source_files),
             Language::TypeScript => self.run_typescript_own_tests(source_files),
+            Language::Go => self.run_go_own_tests(source_files),
+            Language::Java => self.run_java_own_tests(source_files),
         }
     }
 
@@ -526,11 +1051,12 @@ edition = "2021"
         std::fs::create_dir_all(&src_dir)?;
 
         // Run cargo test
-        let output = Command::new("cargo")
+        let output = self
+            .sandbox
+            .command("cargo", temp_dir.path(), Language::Rust)
             .arg("test")
             .arg("--")
             .arg("--test-threads=1")
-            .current_dir(temp_dir.path())
             .output()?;
 
         let stdout = String::from_utf8_lossy(&output.stdout);
@@ -539,16 +1065,8 @@ edition = "2021"
         tracing::debug!("Cargo test stdout: {}", stdout);
         tracing::debug!("Cargo test stderr: {}", stderr);
 
-        if !output.status.success() && stdout.is_empty() {
-            return Ok(TestResult {
-                passed: 0,
-                failed: 1,
-                total: 1,
-                output: format!("Build/test failed: {}", stderr),
-            });
-        }
-
-        parse_rust_test_output(&stdout)
+        let result = parse_rust_test_output(&stdout)?;
+        Ok(aborted_run(result, &output))
     }
 
     /// @ai:intent Run Python's pytest on generated code
@@ -573,17 +1091,43 @@ edition = "2021"
             std::fs::write(&file_path, &source_file.content)?;
         }
 
+        self.sandbox
+            .install_dependencies(temp_dir.path(), Language::Python)?;
+
         // Run pytest
-        let output = Command::new("python")
+        let output = self
+            .sandbox
+            .command("python", temp_dir.path(), Language::Python)
             .arg("-m")
             .arg("pytest")
             .arg("-v")
-            .current_dir(temp_dir.path())
             .output()?;
 
         let stdout = String::from_utf8_lossy(&output.stdout);
         let stderr = String::from_utf8_lossy(&output.stderr);
-        parse_pytest_output(&stdout, &stderr)
+        let result = parse_pytest_output(&stdout, &stderr)?;
+        // A missing pytest also fails without results; only a killed run counts as failed
+        Ok(if was_killed(&output) {
+            aborted_run(result, &output)
+        } else {
+            result
+        })
+    }
+
+    /// @ai:intent Run the generated Go tests (go test on every package)
+    /// @ai:effects fs:write, io
+    fn run_go_own_tests(&self, source_files: &[SourceFile]) -> Result<TestResult> {
+        let temp_dir = TempDir::new()?;
+        write_project(temp_dir.path(), source_files, Language::Go)?;
+        self.go_test(temp_dir.path())
+    }
+
+    /// @ai:intent Run the generated Java tests (JUnit on every compiled test class)
+    /// @ai:effects fs:write, io
+    fn run_java_own_tests(&self, source_files: &[SourceFile]) -> Result<TestResult> {
+        let temp_dir = TempDir::new()?;
+        write_project(temp_dir.path(), source_files, Language::Java)?;
+        self.java_test(temp_dir.path())
     }
 
     /// @ai:intent Run TypeScript tests
@@ -613,30 +1157,25 @@ edition = "2021"
   "include": ["**/*.ts"]
 }"#;
         std::fs::write(temp_dir.path().join("tsconfig.json"), tsconfig)?;
+        self.sandbox
+            .install_dependencies(temp_dir.path(), Language::TypeScript)?;
 
-        // Try to find and run test file
-        let test_file = source_files
+        // Every *.test.ts / *.spec.ts file, else the one file that looks like a test
+        let mut tests: Vec<String> = source_files
             .iter()
-            .find(|f| f.path.contains("test"))
-            .or_else(|| source_files.first());
-
-        if let Some(test) = test_file {
-            let output = Command::new("npx")
-                .arg("ts-node")
-                .arg(&test.path)
-                .current_dir(temp_dir.path())
-                .output()?;
-
-            let success = output.status.success();
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            let stderr = String::from_utf8_lossy(&output.stderr);
+            .filter(|f| is_test_file(&f.path, Language::TypeScript))
+            .map(|f| f.path.clone())
+            .collect();
+        if tests.is_empty() {
+            let test_file = source_files
+                .iter()
+                .find(|f| f.path.contains("test"))
+                .or_else(|| source_files.first());
+            tests.extend(test_file.map(|f| f.path.clone()));
+        }
 
-            Ok(TestResult {
-                passed: if success { 1 } else { 0 },
-                failed: if success { 0 } else { 1 },
-                total: 1,
-                output: format!("{}{}", stdout, stderr),
-            })
+        if !tests.is_empty() {
+            self.typescript_test(temp_dir.path(), source_files, &tests)
         } else {
             Ok(TestResult {
                 passed: 0,
@@ -648,6 +1187,250 @@ edition = "2021"
     }
 }
 
+impl TestRunner {
+    /// @ai:intent Run one hidden test file against the generated project
+    ///            The project is laid out as for run_own_tests; only the hidden test is run
+    /// @ai:post result.total == 0 if the project or the test did not build
+    /// @ai:effects fs:write, io
+    pub fn run_hidden_test(
+        &self,
+        source_files: &[SourceFile],
+        test: &SourceFile,
+        language: Language,
+    ) -> Result<TestResult> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+
+        for source_file in source_files {
+            let file_path = match language {
+                Language::Rust if is_cargo_toml(&source_file.path) => root.join("Cargo.toml"),
+                Language::Rust => root.join(normalize_rust_path(&source_file.path)),
+                // Only the hidden test runs: Go and the JUnit class path scan would run
+                // every generated test too
+                Language::Go | Language::Java if is_test_file(&source_file.path, language) => {
+                    continue
+                }
+                _ => root.join(&source_file.path),
+            };
+
+            if let Some(parent) = file_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            std::fs::write(&file_path, &source_file.content)?;
+        }
+
+        let test_path = root.join(&test.path);
+        if let Some(parent) = test_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        match language {
+            Language::Rust => {
+                let crate_name = rust_crate_name(source_files);
+                if !source_files.iter().any(|f| is_cargo_toml(&f.path)) {
+                    let cargo_toml = format!(
+                        "[package]\nname = \"{crate_name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n"
+                    );
+                    std::fs::write(root.join("Cargo.toml"), cargo_toml)?;
+                }
+                std::fs::write(&test_path, test.content.replace("{{crate}}", &crate_name))?;
+
+                let test_name = test_path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("hidden");
+                let output = self
+                    .sandbox
+                    .command("cargo", root, Language::Rust)
+                    .args(["test", "--test", test_name, "--", "--test-threads=1"])
+                    .output()?;
+
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                if !output.status.success() && stdout.is_empty() {
+                    return Ok(TestResult {
+                        passed: 0,
+                        failed: 0,
+                        total: 0,
+                        output: format!("Build failed: {}", String::from_utf8_lossy(&output.stderr)),
+                    });
+                }
+
+                parse_rust_test_output(&stdout)
+            }
+            Language::Python => {
+                std::fs::write(&test_path, &test.content)?;
+                self.sandbox.install_dependencies(root, Language::Python)?;
+
+                let output = self
+                    .sandbox
+                    .command("python", root, Language::Python)
+                    .args(["-m", "pytest", "-v"])
+                    .arg(&test.path)
+                    .output()?;
+
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                parse_pytest_output(&stdout, &stderr)
+            }
+            Language::TypeScript => {
+                std::fs::write(&test_path, &test.content)?;
+                self.sandbox
+                    .install_dependencies(root, Language::TypeScript)?;
+
+                let mut files = source_files.to_vec();
+                files.push(test.clone());
+                self.typescript_test(root, &files, std::slice::from_ref(&test.path))
+            }
+            Language::Go => {
+                if !root.join("go.mod").exists() {
+                    std::fs::write(root.join("go.mod"), GO_MOD)?;
+                }
+                std::fs::write(&test_path, &test.content)?;
+
+                let output = self
+                    .sandbox
+                    .command("go", root, Language::Go)
+                    .args(["test", "-json", "./..."])
+                    .output()?;
+
+                // Build failures report no test results
+                Ok(parse_go_test_output(
+                    &String::from_utf8_lossy(&output.stdout),
+                    &String::from_utf8_lossy(&output.stderr),
+                ))
+            }
+            Language::Java => {
+                std::fs::write(&test_path, &test.content)?;
+                let Some(jar) = self.sandbox.junit_jar() else {
+                    return Ok(junit_not_configured());
+                };
+
+                let build = self.javac_with_junit(root, jar)?;
+                if !build.status.success() {
+                    return Ok(TestResult {
+                        passed: 0,
+                        failed: 0,
+                        total: 0,
+                        output: format!("Build failed: {}", String::from_utf8_lossy(&build.stderr)),
+                    });
+                }
+
+                let output = self.junit_console(root, jar)?;
+                Ok(parse_junit_output(
+                    &String::from_utf8_lossy(&output.stdout),
+                    &String::from_utf8_lossy(&output.stderr),
+                ))
+            }
+        }
+    }
+}
+
+/// @ai:intent Check if a generated file is the Cargo manifest
+/// @ai:effects pure
+pub(crate) fn is_cargo_toml(path: &str) -> bool {
+    path == "Cargo.toml" || path.ends_with("/Cargo.toml") || path.ends_with("\\Cargo.toml")
+}
+
+/// @ai:intent Cargo.toml written when a generated Rust project has none
+pub(crate) const CARGO_TOML: &str =
+    "[package]\nname = \"benchmark_project\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\n";
+
+/// @ai:intent go.mod written when a generated Go project has none
+pub(crate) const GO_MOD: &str = "module benchmark\n\ngo 1.21\n";
+
+/// @ai:intent Directory (relative to the project root) javac writes class files to
+pub(crate) const JAVA_CLASSES: &str = "build/classes";
+
+/// @ai:intent vitest config written when a TypeScript project has none
+const VITEST_CONFIG: &str = "vitest.config.mjs";
+
+/// @ai:intent File (relative to the project root) vitest and jest write their JSON report to
+const TS_TEST_REPORT: &str = "test-report.json";
+
+/// @ai:intent Write a generated project under root, laid out as the language's tools expect
+///            Rust sources go under src/, with a minimal Cargo.toml if none was generated;
+///            Go projects get a go.mod if none was generated
+/// @ai:effects fs:write
+pub(crate) fn write_project(root: &Path, files: &[SourceFile], language: Language) -> Result<()> {
+    for file in files {
+        let file_path = match language {
+            Language::Rust if is_cargo_toml(&file.path) => root.join("Cargo.toml"),
+            Language::Rust => root.join(normalize_rust_path(&file.path)),
+            _ => root.join(&file.path),
+        };
+
+        if let Some(parent) = file_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(&file_path, &file.content)?;
+    }
+
+    if language == Language::Rust && !files.iter().any(|f| is_cargo_toml(&f.path)) {
+        std::fs::write(root.join("Cargo.toml"), CARGO_TOML)?;
+    }
+    if language == Language::Go && !root.join("go.mod").exists() {
+        std::fs::write(root.join("go.mod"), GO_MOD)?;
+    }
+    Ok(())
+}
+
+/// @ai:intent Package name declared by Go or Java source code
+/// @ai:effects pure
+/// @ai:example ("// Package bank\npackage bank\n") -> Some("bank")
+/// @ai:example ("package com.example.bank;\n") -> Some("com.example.bank")
+fn declared_package(code: &str) -> Option<&str> {
+    code.lines()
+        .map(str::trim)
+        .find_map(|line| line.strip_prefix("package "))
+        .map(|package| package.trim_end_matches(';').trim())
+}
+
+/// @ai:intent File name Java requires for code with a public top-level type
+/// @ai:effects pure
+/// @ai:example ("public final class Account {}") -> Some("Account.java")
+pub(crate) fn java_file_name(code: &str) -> Option<String> {
+    Regex::new(r"(?m)^public\s+(?:(?:final|abstract|sealed|non-sealed|strictfp)\s+)*(?:class|interface|enum|record|@interface)\s+([A-Za-z_$][\w$]*)")
+        .unwrap()
+        .captures(code)
+        .map(|cap| format!("{}.java", &cap[1]))
+}
+
+/// @ai:intent Java sources under root as paths relative to it, skipping build output
+///            Test sources (see is_test_file) are left out unless include_tests is set
+/// @ai:effects fs:read
+pub(crate) fn java_sources(root: &Path, include_tests: bool) -> Vec<String> {
+    walkdir::WalkDir::new(root)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| entry.depth() == 0 || entry.file_name() != "build")
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let relative = entry.path().strip_prefix(root).ok()?;
+            let relative = relative.to_string_lossy().replace('\\', "/");
+            (relative.ends_with(".java")
+                && (include_tests || !is_test_file(&relative, Language::Java)))
+            .then_some(relative)
+        })
+        .collect()
+}
+
+/// @ai:intent Library crate name of a generated Rust project (as used in `use` paths)
+/// @ai:effects pure
+/// @ai:example ([Cargo.toml with name = "bank-account"]) -> "bank_account"
+fn rust_crate_name(source_files: &[SourceFile]) -> String {
+    let name_regex = regex::Regex::new(r#"(?m)^\s*name\s*=\s*"([^"]+)""#).unwrap();
+
+    source_files
+        .iter()
+        .filter(|f| is_cargo_toml(&f.path))
+        .find_map(|f| name_regex.captures(&f.content))
+        .and_then(|cap| cap.get(1))
+        .map(|m| m.as_str().replace('-', "_"))
+        .unwrap_or_else(|| "benchmark_project".to_string())
+}
+
 impl TestRunnerTrait for TestRunner {
     /// @ai:intent Run tests against generated code
     /// @ai:effects fs:write, io
@@ -656,6 +1439,8 @@ impl TestRunnerTrait for TestRunner {
             Language::Rust => self.run_rust(code, test_code),
             Language::Python => self.run_python(code, test_code),
             Language::TypeScript => self.run_typescript(code, test_code),
+            Language::Go => self.run_go(code, test_code),
+            Language::Java => self.run_java(code, test_code),
         }
     }
 
@@ -667,15 +1452,12 @@ impl TestRunnerTrait for TestRunner {
         test_files: &[SourceFile],
         language: Language,
     ) -> Result<TestResult> {
-        // If single files, fall back to simple run
-        if source_files.len() == 1 && test_files.len() == 1 {
-            return self.run(&source_files[0].content, &test_files[0].content, language);
-        }
-
         match language {
             Language::Rust => self.run_rust_files(source_files, test_files),
             Language::Python => self.run_python_files(source_files, test_files),
             Language::TypeScript => self.run_typescript_files(source_files, test_files),
+            Language::Go => self.run_go_files(source_files, test_files),
+            Language::Java => self.run_java_files(source_files, test_files),
         }
     }
 }
@@ -690,6 +1472,128 @@ mod tests {
         let result = parse_rust_test_output(output).unwrap();
         assert_eq!(result.passed, 3);
         assert_eq!(result.failed, 1);
+
+        // Unit tests followed by an empty doc-test run
+        let output = "test result: ok. 3 passed; 0 failed; 0 ignored\n\
+                      test result: ok. 0 passed; 0 failed; 0 ignored";
+        assert_eq!(parse_rust_test_output(output).unwrap().passed, 3);
+    }
+
+    #[test]
+    fn test_parse_go_output() {
+        let stdout = r#"{"Action":"run","Package":"benchmark/bank","Test":"TestDeposit"}
+{"Action":"output","Package":"benchmark/bank","Test":"TestDeposit","Output":"=== RUN   TestDeposit\n"}
+{"Action":"pass","Package":"benchmark/bank","Test":"TestDeposit/positive"}
+{"Action":"pass","Package":"benchmark/bank","Test":"TestDeposit"}
+{"Action":"fail","Package":"benchmark/bank","Test":"TestWithdraw"}
+{"Action":"fail","Package":"benchmark/bank"}"#;
+        let result = parse_go_test_output(stdout, "");
+        assert_eq!((result.passed, result.failed, result.total), (1, 1, 2));
+        assert!(result.output.contains("=== RUN   TestDeposit"));
+
+        // A build failure reports no tests; its message is kept
+        let result = parse_go_test_output("# benchmark/bank\nbank.go:3:1: syntax error\n", "");
+        assert_eq!(result.total, 0);
+        assert!(result.output.contains("syntax error"));
+    }
+
+    #[test]
+    fn test_typescript_test_framework() {
+        let file = |path: &str, content: &str| SourceFile {
+            path: path.to_string(),
+            content: content.to_string(),
+        };
+        let script = file("test.ts", "assert.strictEqual(sum(1, 2), 3);\n");
+        let globals = file(
+            "sum.test.ts",
+            "describe('sum', () => {\n  it('adds', () => expect(sum(1, 2)).toBe(3));\n});\n",
+        );
+
+        assert_eq!(
+            typescript_test_framework(std::slice::from_ref(&script)),
+            TypeScriptTestFramework::TsNode
+        );
+        assert_eq!(
+            typescript_test_framework(std::slice::from_ref(&globals)),
+            TypeScriptTestFramework::Vitest
+        );
+        let jest_import = file("a.test.ts", "import { expect } from '@jest/globals';");
+        assert_eq!(
+            typescript_test_framework(&[jest_import]),
+            TypeScriptTestFramework::Jest
+        );
+        // A framework the project declares wins over the tests' style
+        let jest = file("package.json", r#"{"devDependencies": {"jest": "^29"}}"#);
+        assert_eq!(
+            typescript_test_framework(&[globals, jest]),
+            TypeScriptTestFramework::Jest
+        );
+        assert_eq!(
+            typescript_test_framework(&[script, file("src/vitest.config.ts", "")]),
+            TypeScriptTestFramework::Vitest
+        );
+    }
+
+    #[test]
+    fn test_parse_jest_report() {
+        let report = r#"{"numTotalTests": 5, "numPassedTests": 3, "numFailedTests": 1,
+            "numPendingTests": 1, "testResults": [
+            {"name": "/work/sum.test.ts", "status": "failed", "message": "",
+             "assertionResults": [{"status": "passed"}, {"status": "failed"}]},
+            {"name": "/work/bad.test.ts", "status": "failed",
+             "message": "SyntaxError: Unexpected token", "assertionResults": []}]}"#;
+        let result = parse_jest_report(Some(report), "", "");
+        assert_eq!((result.passed, result.failed, result.total), (3, 2, 5));
+        assert!(result.output.contains("SyntaxError"));
+
+        // No report (the runner did not start) counts nothing
+        let result = parse_jest_report(None, "", "sh: 1: vitest: not found\n");
+        assert_eq!(result.total, 0);
+        assert!(result.output.contains("not found"));
+        assert!(vitest_config(&["test.ts".to_string()]).contains(r#"include: ["test.ts"]"#));
+    }
+
+    #[test]
+    fn test_declared_package() {
+        assert_eq!(
+            declared_package("// Package bank\npackage bank\n"),
+            Some("bank")
+        );
+        assert_eq!(
+            declared_package("package com.example.bank;\n"),
+            Some("com.example.bank")
+        );
+        assert_eq!(declared_package("func TestX(t *testing.T) {}"), None);
+    }
+
+    #[test]
+    fn test_java_file_name() {
+        let code = "package bank;\n\nimport java.util.List;\n\npublic final class Account {\n}\n\nclass Ledger {}\n";
+        assert_eq!(java_file_name(code), Some("Account.java".to_string()));
+        assert_eq!(java_file_name("class AccountTest {}"), None);
+    }
+
+    #[test]
+    fn test_parse_junit_output() {
+        let stdout = "\u{2577}\n\u{251c}\u{2500} JUnit Jupiter \u{2714}\n\u{2502}  \u{2514}\u{2500} AccountTest \u{2714}\n\nTest run finished after 64 ms\n[         2 containers found      ]\n[         3 tests found           ]\n[         0 tests skipped         ]\n[         3 tests started         ]\n[         0 tests aborted         ]\n[         2 tests successful      ]\n[         1 tests failed          ]\n";
+        let result = parse_junit_output(stdout, "");
+        assert_eq!((result.passed, result.failed, result.total), (2, 1, 3));
+        assert!(result.output.contains("AccountTest"));
+    }
+
+    #[test]
+    fn test_rust_crate_name() {
+        let manifest = SourceFile {
+            path: "Cargo.toml".to_string(),
+            content: "[package]\nname = \"bank-account\"\nversion = \"0.1.0\"\n".to_string(),
+        };
+        let lib = SourceFile {
+            path: "src/lib.rs".to_string(),
+            content: String::new(),
+        };
+
+        assert_eq!(rust_crate_name(&[lib.clone(), manifest]), "bank_account");
+        assert_eq!(rust_crate_name(&[lib]), "benchmark_project");
     }
 
     #[test]
@@ -702,4 +1606,39 @@ mod tests {
         };
         assert!((result.pass_rate() - 70.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_determinism_flags_disagreeing_runs() {
+        let run = |passed, failed| TestResult {
+            passed,
+            failed,
+            total: passed + failed,
+            output: String::new(),
+        };
+
+        let stable = TestDeterminism::from_runs(&[run(4, 1), run(4, 1)]);
+        assert!(!stable.flaky);
+        assert_eq!(stable.passed, vec![4, 4]);
+
+        let flaky = TestDeterminism::from_runs(&[run(5, 0), run(4, 1)]);
+        assert!(flaky.flaky);
+        assert_eq!((flaky.passed, flaky.total), (vec![5, 4], vec![5, 5]));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_killed_run_counts_as_failure() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let killed = Output {
+            status: std::process::ExitStatus::from_raw(libc::SIGKILL),
+            stdout: b"running 1 test\n".to_vec(),
+            stderr: b"\n[killed after timeout of 600s]\n".to_vec(),
+        };
+        let result = parse_rust_test_output("running 1 test\n").unwrap();
+        assert!(was_killed(&killed));
+        let result = aborted_run(result, &killed);
+        assert_eq!((result.failed, result.total), (1, 1));
+        assert!(result.output.contains("killed after timeout"));
+    }
 }
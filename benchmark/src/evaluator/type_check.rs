@@ -0,0 +1,217 @@
+//! @ai:module:intent Static type checking of generated Python code with mypy or pyright
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api TypeChecker, TypeCheckerTrait, TypeCheck
+//! @ai:module:depends_on config, evaluator::sandbox, evaluator::test_runner
+//! @ai:module:stateless true
+
+use crate::config::PythonTypeChecker;
+use crate::corpus::Language;
+use crate::evaluator::sandbox::Sandbox;
+use crate::evaluator::test_runner::write_project;
+use crate::evaluator::SourceFile;
+use anyhow::{bail, Result};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use tempfile::TempDir;
+
+/// pyright config: unresolved imports are not type errors, as with mypy's
+/// --ignore-missing-imports
+const PYRIGHT_CONFIG: &str = r#"{
+  "reportMissingImports": "none",
+  "reportMissingModuleSource": "none"
+}
+"#;
+
+/// @ai:intent Type errors reported by the type checker for one execution
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TypeCheck {
+    /// Type checker that produced the errors (mypy, pyright)
+    pub tool: String,
+    pub errors: u32,
+    /// Error count per error code (e.g. "arg-type", "reportAttributeAccessIssue")
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, u32>,
+}
+
+impl TypeCheck {
+    /// @ai:intent Whether the code type-checks without errors
+    /// @ai:effects pure
+    pub fn is_correct(&self) -> bool {
+        self.errors == 0
+    }
+}
+
+/// @ai:intent Trait for static type checking
+pub trait TypeCheckerTrait: Send + Sync {
+    /// @ai:intent Type-check the Python files of a generated project
+    fn check(&self, files: &[SourceFile]) -> Result<TypeCheck>;
+}
+
+/// @ai:intent Runs mypy or pyright on a project copy
+pub struct TypeChecker {
+    sandbox: Sandbox,
+    tool: PythonTypeChecker,
+}
+
+impl TypeChecker {
+    /// @ai:intent Create a checker that runs mypy on the host
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self {
+            sandbox: Sandbox::default(),
+            tool: PythonTypeChecker::Mypy,
+        }
+    }
+
+    /// @ai:intent Run the type checker in the given sandbox
+    /// @ai:effects pure
+    pub fn with_sandbox(mut self, sandbox: Sandbox) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
+    /// @ai:intent Use the given type checker
+    /// @ai:effects pure
+    pub fn with_tool(mut self, tool: PythonTypeChecker) -> Self {
+        self.tool = tool;
+        self
+    }
+
+    /// @ai:intent Run mypy on the given files and count errors by error code
+    /// @ai:effects io
+    fn mypy(&self, root: &Path, paths: &[&str]) -> Result<BTreeMap<String, u32>> {
+        let output = self
+            .sandbox
+            .command("python", root, Language::Python)
+            .args(["-m", "mypy", "--ignore-missing-imports"])
+            .args(["--show-error-codes", "--no-error-summary"])
+            .args(["--no-color-output", "--no-incremental"])
+            .args(paths)
+            .output()
+            .map_err(|e| anyhow::anyhow!("python not available: {}", e))?;
+
+        // Exit code 1: type errors were found; 2: mypy crashed or is misconfigured
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let rules = parse_mypy_output(&stdout);
+        if !output.status.success() && rules.is_empty() {
+            bail!(
+                "mypy failed: {}{}",
+                stdout,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+        Ok(rules)
+    }
+
+    /// @ai:intent Run pyright on the given files and count errors by rule
+    /// @ai:effects fs:write, io
+    fn pyright(&self, root: &Path, paths: &[&str]) -> Result<BTreeMap<String, u32>> {
+        std::fs::write(root.join("pyrightconfig.json"), PYRIGHT_CONFIG)?;
+
+        let output = self
+            .sandbox
+            .command("pyright", root, Language::Python)
+            .arg("--outputjson")
+            .args(paths)
+            .output()
+            .map_err(|e| anyhow::anyhow!("pyright not available: {}", e))?;
+
+        let report: serde_json::Value = serde_json::from_slice(&output.stdout).map_err(|_| {
+            anyhow::anyhow!(
+                "pyright failed: {}",
+                String::from_utf8_lossy(&output.stderr)
+            )
+        })?;
+
+        let mut rules = BTreeMap::new();
+        for diagnostic in report["generalDiagnostics"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|d| d["severity"] == "error")
+        {
+            let rule = diagnostic["rule"].as_str().unwrap_or("pyright");
+            *rules.entry(rule.to_string()).or_default() += 1;
+        }
+        Ok(rules)
+    }
+}
+
+impl Default for TypeChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeCheckerTrait for TypeChecker {
+    /// @ai:intent Type-check the Python files of a generated project, tests included
+    ///            Fails if the type checker is not installed, so a missing tool is not
+    ///            scored as type-correct
+    /// @ai:effects fs:write, io
+    fn check(&self, files: &[SourceFile]) -> Result<TypeCheck> {
+        let paths: Vec<&str> = files
+            .iter()
+            .map(|f| f.path.as_str())
+            .filter(|path| path.ends_with(".py"))
+            .collect();
+        if paths.is_empty() {
+            bail!("no Python files to type-check");
+        }
+
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        write_project(root, files, Language::Python)?;
+
+        let (tool, rules) = match self.tool {
+            PythonTypeChecker::Mypy => ("mypy", self.mypy(root, &paths)?),
+            PythonTypeChecker::Pyright => ("pyright", self.pyright(root, &paths)?),
+        };
+
+        Ok(TypeCheck {
+            tool: tool.to_string(),
+            errors: rules.values().sum(),
+            rules,
+        })
+    }
+}
+
+/// @ai:intent Count mypy errors by error code ("mypy" for errors without one)
+///            Notes attached to an error are not counted
+/// @ai:effects pure
+/// @ai:example ("app.py:3: error: Incompatible return value type  [return-value]") -> {"return-value": 1}
+fn parse_mypy_output(output: &str) -> BTreeMap<String, u32> {
+    let mut rules = BTreeMap::new();
+    let Ok(error) = Regex::new(r"^.+?:\d+(?::\d+)?: error: .*?(?:\s+\[([\w-]+)\])?$") else {
+        return rules;
+    };
+    for captures in output.lines().filter_map(|line| error.captures(line)) {
+        let code = captures.get(1).map_or("mypy", |code| code.as_str());
+        *rules.entry(code.to_string()).or_default() += 1;
+    }
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mypy_output() {
+        let output = "\
+app.py:3: error: Incompatible return value type (got \"str\", expected \"int\")  [return-value]
+app.py:7: error: Argument 1 to \"double\" has incompatible type \"str\"; expected \"int\"  [arg-type]
+app.py:7: note: See https://mypy.rtfd.io/en/stable/_refs.html
+test_app.py:2:1: error: Name \"doubel\" is not defined  [name-defined]
+test_app.py:9: error: Unexpected keyword argument \"y\" for \"double\"  [call-arg]
+test_app.py:12: error: Invalid syntax
+";
+        let rules = parse_mypy_output(output);
+        assert_eq!(rules.values().sum::<u32>(), 5);
+        assert_eq!(rules.get("return-value"), Some(&1));
+        assert_eq!(rules.get("name-defined"), Some(&1));
+        assert_eq!(rules.get("mypy"), Some(&1));
+        assert!(parse_mypy_output("Success: no issues found in 2 source files\n").is_empty());
+    }
+}
@@ -0,0 +1,357 @@
+//! @ai:module:intent Append-only history of run summaries under results/history, and the
+//!                   per-metric time series (trends) read back from it
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api HistoryEntry, ResultsHistory, Trend, TrendPoint, trends, record_run,
+//!                        short_version, HISTORY_DIR, HISTORY_FILE
+//! @ai:module:depends_on metrics, platform
+//! @ai:module:stateless true
+
+use crate::metrics::{AggregateStats, BenchmarkResults};
+use crate::platform::portable_path;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Directory of the results tree holding the history (never a run, so never cleaned)
+pub const HISTORY_DIR: &str = "history";
+
+/// File of the history, one JSON summary per line
+pub const HISTORY_FILE: &str = "history.jsonl";
+
+/// Reads one recorded metric from a mode's stats, None when it does not apply
+type MetricValue = fn(&AggregateStats) -> Option<f64>;
+
+/// Metrics recorded for each mode, in report order
+const HISTORY_METRICS: &[(&str, MetricValue)] = &[
+    ("compilation_rate", |s| Some(s.compilation_rate)),
+    ("test_pass_rate", |s| Some(s.avg_test_pass_rate)),
+    ("lint_compliance", |s| Some(s.avg_lint_compliance)),
+    ("annotation_quality", |s| Some(s.avg_annotation_quality)),
+    ("avg_tokens", |s| {
+        let tokens = s.total_input_tokens + s.total_output_tokens;
+        Some(tokens as f64 / s.task_count as f64)
+    }),
+    ("avg_execution_time_ms", |s| Some(s.avg_execution_time_ms)),
+    ("avg_cost_usd", |s| {
+        (s.priced_count > 0).then(|| s.avg_cost_usd())
+    }),
+    ("cost_per_pass_usd", |s| s.cost_per_pass_usd),
+];
+
+/// @ai:intent Summary row of one run: where it came from and its headline metrics per mode
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub recorded_at: String,
+    /// Run directory relative to the results directory (e.g. "2026-01-20_12-00-00/opus")
+    pub run: String,
+    pub model: String,
+    #[serde(default)]
+    pub bench_version: String,
+    /// Commit of the corpus (see RunMetadata::corpus_git_sha), None if unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corpus_version: Option<String>,
+    /// Commit of the skill file, None if unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skill_version: Option<String>,
+    pub repetitions: u32,
+    /// Whether the run stopped early (its metrics cover fewer executions)
+    #[serde(default)]
+    pub truncated: bool,
+    /// Metric name to value; empty for a mode that was not run
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub baseline: BTreeMap<String, f64>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub aicms: BTreeMap<String, f64>,
+}
+
+impl HistoryEntry {
+    /// @ai:intent Summarize a run's results
+    /// @ai:effects pure
+    pub fn from_results(run: &str, results: &BenchmarkResults) -> Self {
+        let metadata = results.metadata.as_ref();
+        Self {
+            recorded_at: chrono::Utc::now().to_rfc3339(),
+            run: run.to_string(),
+            model: results.model.clone(),
+            bench_version: metadata
+                .map(|m| m.bench_version.clone())
+                .unwrap_or_default(),
+            corpus_version: metadata.and_then(|m| m.corpus_git_sha.clone()),
+            skill_version: metadata.and_then(|m| m.skill_git_sha.clone()),
+            repetitions: results.repetitions,
+            truncated: results.truncated.is_some(),
+            baseline: summarize(&results.overall.baseline),
+            aicms: summarize(&results.overall.aicms),
+        }
+    }
+
+    /// @ai:intent Recorded value of a metric for a mode ("baseline" or "aicms")
+    /// @ai:effects pure
+    pub fn value(&self, mode: &str, metric: &str) -> Option<f64> {
+        let values = if mode == "baseline" {
+            &self.baseline
+        } else {
+            &self.aicms
+        };
+        values.get(metric).copied()
+    }
+}
+
+/// @ai:intent Headline and custom metrics of one mode, empty if it has no executions
+/// @ai:effects pure
+fn summarize(stats: &AggregateStats) -> BTreeMap<String, f64> {
+    if stats.task_count == 0 {
+        return BTreeMap::new();
+    }
+    HISTORY_METRICS
+        .iter()
+        .filter_map(|(name, value)| value(stats).map(|v| (name.to_string(), v)))
+        .chain(stats.custom.clone())
+        .collect()
+}
+
+/// @ai:intent The history file of a results directory
+pub struct ResultsHistory {
+    path: PathBuf,
+}
+
+impl ResultsHistory {
+    /// @ai:intent History of the results directory (results/history/history.jsonl)
+    /// @ai:effects pure
+    pub fn new(results_dir: &Path) -> Self {
+        Self {
+            path: results_dir.join(HISTORY_DIR).join(HISTORY_FILE),
+        }
+    }
+
+    /// @ai:intent Path of the history file
+    /// @ai:effects pure
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// @ai:intent Append one summary row, creating the file on first use
+    /// @ai:effects fs:write
+    pub fn append(&self, entry: &HistoryEntry) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open {}", self.path.display()))?;
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+        Ok(())
+    }
+
+    /// @ai:intent Every recorded run in the order recorded; a run recorded again (resumed)
+    ///            keeps only its latest row. Empty if nothing was recorded yet
+    /// @ai:effects fs:read
+    pub fn load(&self) -> Result<Vec<HistoryEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&self.path)?;
+        let mut entries: Vec<HistoryEntry> = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: HistoryEntry = serde_json::from_str(line).with_context(|| {
+                format!("Failed to parse {} line {}", self.path.display(), index + 1)
+            })?;
+            entries.retain(|e| e.run != entry.run);
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+}
+
+/// @ai:intent Value of a metric in one recorded run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrendPoint {
+    pub recorded_at: String,
+    pub run: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corpus_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skill_version: Option<String>,
+    pub baseline: Option<f64>,
+    pub aicms: Option<f64>,
+}
+
+impl TrendPoint {
+    /// @ai:intent AICMS minus baseline, None unless both modes ran
+    /// @ai:effects pure
+    pub fn delta(&self) -> Option<f64> {
+        Some(self.aicms? - self.baseline?)
+    }
+}
+
+/// @ai:intent Time series of one metric for one model, oldest run first
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trend {
+    pub model: String,
+    pub metric: String,
+    pub points: Vec<TrendPoint>,
+}
+
+impl Trend {
+    /// @ai:intent Change of a mode's value from its first to its last recorded run
+    /// @ai:effects pure
+    /// @ai:example (points baseline 60.0 then 75.0, "baseline") -> Some(15.0)
+    pub fn change(&self, mode: &str) -> Option<f64> {
+        let value = |p: &TrendPoint| {
+            if mode == "baseline" {
+                p.baseline
+            } else {
+                p.aicms
+            }
+        };
+        let mut values = self.points.iter().filter_map(value);
+        let first = values.next()?;
+        Some(values.next_back()? - first)
+    }
+}
+
+/// @ai:intent Per-model, per-metric series of the history, optionally limited to one model
+///            and one metric. Metrics follow report order, then any others by name
+/// @ai:effects pure
+pub fn trends(entries: &[HistoryEntry], model: Option<&str>, metric: Option<&str>) -> Vec<Trend> {
+    let mut models: Vec<&str> = Vec::new();
+    for entry in entries {
+        if model.is_none_or(|m| m == entry.model) && !models.contains(&entry.model.as_str()) {
+            models.push(&entry.model);
+        }
+    }
+
+    let mut trends = Vec::new();
+    for model in models {
+        let runs: Vec<&HistoryEntry> = entries.iter().filter(|e| e.model == model).collect();
+        let mut metrics: Vec<&str> = HISTORY_METRICS.iter().map(|(name, _)| *name).collect();
+        for entry in &runs {
+            for name in entry.baseline.keys().chain(entry.aicms.keys()) {
+                if !metrics.contains(&name.as_str()) {
+                    metrics.push(name);
+                }
+            }
+        }
+
+        for name in metrics
+            .into_iter()
+            .filter(|n| metric.is_none_or(|m| m == *n))
+        {
+            let points: Vec<TrendPoint> = runs
+                .iter()
+                .map(|e| TrendPoint {
+                    recorded_at: e.recorded_at.clone(),
+                    run: e.run.clone(),
+                    corpus_version: e.corpus_version.clone(),
+                    skill_version: e.skill_version.clone(),
+                    baseline: e.value("baseline", name),
+                    aicms: e.value("aicms", name),
+                })
+                .filter(|p| p.baseline.is_some() || p.aicms.is_some())
+                .collect();
+            if !points.is_empty() {
+                trends.push(Trend {
+                    model: model.to_string(),
+                    metric: name.to_string(),
+                    points,
+                });
+            }
+        }
+    }
+    trends
+}
+
+/// @ai:intent Record a run in the history of the results directory it was written to
+/// @ai:effects fs:write
+pub fn record_run(results_dir: &Path, run_dir: &Path, results: &BenchmarkResults) -> Result<()> {
+    let run = run_dir.strip_prefix(results_dir).unwrap_or(run_dir);
+    let entry = HistoryEntry::from_results(&portable_path(run), results);
+    ResultsHistory::new(results_dir).append(&entry)
+}
+
+/// @ai:intent Short form of a recorded version for tables (12 characters, keeping "-dirty")
+/// @ai:effects pure
+/// @ai:example (Some("0123456789abcdef0123-dirty")) -> "0123456789ab-dirty"
+pub fn short_version(version: Option<&str>) -> String {
+    let Some(version) = version else {
+        return "-".to_string();
+    };
+    let (sha, dirty) = match version.strip_suffix("-dirty") {
+        Some(sha) => (sha, "-dirty"),
+        None => (version, ""),
+    };
+    format!("{}{}", &sha[..sha.len().min(12)], dirty)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::{MetricsAggregator, MetricsAggregatorTrait};
+
+    fn results(model: &str, baseline_pass: f64, aicms_pass: f64) -> BenchmarkResults {
+        let mut results = MetricsAggregator::new().aggregate(&[], &[], model, 1);
+        results.overall.baseline.task_count = 2;
+        results.overall.baseline.avg_test_pass_rate = baseline_pass;
+        results.overall.aicms.task_count = 2;
+        results.overall.aicms.avg_test_pass_rate = aicms_pass;
+        results
+    }
+
+    #[test]
+    fn test_history_round_trip_and_trends() {
+        let dir = tempfile::tempdir().unwrap();
+        let history = ResultsHistory::new(dir.path());
+        assert!(history.load().unwrap().is_empty());
+
+        let run_dir = dir.path().join("2026-01-01_00-00-00");
+        record_run(dir.path(), &run_dir, &results("opus", 60.0, 70.0)).unwrap();
+        let mut second =
+            HistoryEntry::from_results("2026-01-02_00-00-00", &results("opus", 65.0, 80.0));
+        second.corpus_version = Some("0123456789abcdef-dirty".to_string());
+        history.append(&second).unwrap();
+        // A resumed run is recorded again; only its latest row counts
+        let resumed =
+            HistoryEntry::from_results("2026-01-02_00-00-00", &results("opus", 75.0, 90.0));
+        history.append(&resumed).unwrap();
+        history
+            .append(&HistoryEntry::from_results(
+                "2026-01-03_00-00-00",
+                &results("haiku", 50.0, 40.0),
+            ))
+            .unwrap();
+
+        let entries = history.load().unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].run, "2026-01-01_00-00-00");
+
+        let all = trends(&entries, None, None);
+        assert_eq!(all[0].model, "opus");
+        assert_eq!(all[0].metric, "compilation_rate");
+        assert!(
+            all.iter().all(|t| t.metric != "avg_cost_usd"),
+            "unpriced runs have no cost"
+        );
+
+        let pass = trends(&entries, Some("opus"), Some("test_pass_rate"));
+        assert_eq!(pass.len(), 1);
+        assert_eq!(pass[0].points.len(), 2);
+        assert_eq!(pass[0].points[1].delta(), Some(15.0));
+        assert_eq!(pass[0].change("baseline"), Some(15.0));
+        assert_eq!(pass[0].change("aicms"), Some(20.0));
+        assert_eq!(
+            short_version(pass[0].points[0].corpus_version.as_deref()),
+            "-"
+        );
+        assert_eq!(
+            short_version(Some("0123456789abcdef-dirty")),
+            "0123456789ab-dirty"
+        );
+    }
+}
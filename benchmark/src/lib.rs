@@ -1,19 +1,26 @@
 //! @ai:module:intent AICMS Benchmark System library
 //! @ai:module:layer application
-//! @ai:module:public_api config, corpus, runner, evaluator, metrics, report, toolchain
+//! @ai:module:public_api config, corpus, runner, evaluator, history, metrics, report, retention,
+//!                        toolchain, platform, provenance
 
 pub mod config;
 pub mod corpus;
 pub mod evaluator;
+pub mod history;
 pub mod metrics;
+pub mod platform;
+pub mod provenance;
 pub mod report;
+pub mod retention;
 pub mod runner;
 pub mod toolchain;
 
 pub use config::BenchmarkConfig;
 pub use corpus::{CorpusLoader, Task};
-pub use evaluator::Evaluator;
+pub use evaluator::{EvaluationResult, EvaluationStage, Evaluator, StageInput};
 pub use metrics::{BenchmarkResults, MetricsAggregator, TaskMetrics};
-pub use report::ReportGenerator;
+pub use provenance::RunMetadata;
+pub use report::{ChartPlugin, ReportGenerator};
+pub use retention::{ResultsCleaner, RetentionPolicy};
 pub use runner::{BenchmarkExecutor, ClaudeClient, ClaudeClientTrait, ClaudeCodeClient, ExecutionResult};
 pub use toolchain::{ToolchainStatus, ToolchainValidator};
@@ -2,18 +2,36 @@
 //! @ai:module:layer presentation
 
 use aicms_bench::{
-    config::{BenchmarkConfig, FilterConfig, PathConfig},
-    corpus::{CorpusLoader, CorpusLoaderTrait},
-    evaluator::Evaluator,
-    metrics::{MetricsAggregator, MetricsAggregatorTrait, TaskMetrics},
-    report::ReportGenerator,
-    runner::{create_executor, ClaudeClient, ClaudeCodeClient, MockClaudeClient},
+    config::{
+        BackendKind, BenchmarkConfig, FilterConfig, JudgeBackend, MissingDataPolicy, ModeSelection,
+        PathConfig, SkillVariant, StatisticsConfig,
+    },
+    corpus::{analyze_parity, CorpusLoader, CorpusLoaderTrait},
+    evaluator::{Evaluator, Fingerprint, Sandbox},
+    history::{record_run, short_version, trends, ResultsHistory},
+    platform::portable_path,
+    provenance::RunMetadata,
+    metrics::{
+        diff_results, AggregateStats, Change, ConfidenceInterval, EffectSize, FailureReason,
+        MetricsAggregator, MetricsAggregatorTrait, PairedTest, RegressionThresholds,
+        Reproducibility, TaskMetrics,
+    },
+    report::{OpenMetricsExporter, ReportGenerator},
+    retention::{format_bytes, ResultsCleaner, RetentionPolicy},
+    runner::{
+        create_executor, kill_all_trees, BedrockClient, Budget, CheckpointStore, ClaudeClient,
+        ClaudeClientTrait, ClaudeCodeClient, DryRunClient, Event, EventLog, Interrupt,
+        OllamaClient, RecordingClient, ReplayClient, TaskManifest, VertexClient, EXECUTIONS_FILE,
+    },
     toolchain::ToolchainValidator,
 };
-use anyhow::Result;
-use clap::{Parser, Subcommand};
+use anyhow::{Context, Result};
+use clap::{Args, Parser, Subcommand};
 use std::path::PathBuf;
-use std::sync::Arc;
+use std::sync::{Arc, LazyLock};
+
+/// Set by the first Ctrl-C of a `run`: no new executions start and partial results are written
+static INTERRUPT: LazyLock<Arc<Interrupt>> = LazyLock::new(|| Arc::new(Interrupt::new()));
 
 #[derive(Parser)]
 #[command(name = "aicms-bench")]
@@ -27,51 +45,26 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Run benchmarks
-    Run {
+    Run(Box<RunArgs>),
+
+    /// Run comparison on existing results directory
+    Compare {
+        /// Path to results directory (e.g., results/2026-01-20_12-00-00)
+        #[arg(short, long)]
+        results_dir: PathBuf,
+
         /// Path to configuration file
         #[arg(short, long)]
         config: Option<PathBuf>,
-
-        /// Filter by categories (comma-separated)
-        #[arg(long)]
-        categories: Option<String>,
-
-        /// Filter by languages (comma-separated)
-        #[arg(long)]
-        languages: Option<String>,
-
-        /// Filter by task IDs (comma-separated)
-        #[arg(long)]
-        tasks: Option<String>,
-
-        /// Number of repetitions
-        #[arg(short, long, default_value = "1")]
-        repetitions: u32,
-
-        /// Run without making API calls
-        #[arg(long)]
-        dry_run: bool,
-
-        /// Use direct API instead of Claude Code CLI (requires ANTHROPIC_API_KEY)
-        #[arg(long)]
-        use_api: bool,
-
-        /// Enable Claude-based comparison scoring (slower, uses Claude to score both implementations)
-        #[arg(long)]
-        compare: bool,
-
-        /// Output directory for results
-        #[arg(short, long, default_value = "results")]
-        output: PathBuf,
     },
 
-    /// Run comparison on existing results directory
-    Compare {
+    /// Re-evaluate the saved executions of a results directory (no model calls)
+    Evaluate {
         /// Path to results directory (e.g., results/2026-01-20_12-00-00)
         #[arg(short, long)]
         results_dir: PathBuf,
 
-        /// Path to configuration file
+        /// Path to configuration file ([sandbox], [evaluation] and the corpus are taken from it)
         #[arg(short, long)]
         config: Option<PathBuf>,
     },
@@ -101,6 +94,92 @@ enum Commands {
     /// Validate corpus for errors
     Validate,
 
+    /// Show which task families are missing a language variant
+    Parity {
+        /// Emit the report as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Exit with an error if any family is missing a language
+        #[arg(long)]
+        strict: bool,
+    },
+
+    /// Prune old results and build artifacts
+    Clean {
+        /// Results directory to clean (defaults to paths.results_dir)
+        #[arg(short, long)]
+        results_dir: Option<PathBuf>,
+
+        /// Path to configuration file
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Keep only the N most recent runs
+        #[arg(long)]
+        keep_last: Option<usize>,
+
+        /// Remove runs older than this many days
+        #[arg(long)]
+        older_than_days: Option<u64>,
+
+        /// Delete build artifacts (target/, node_modules/, __pycache__/, ...) from kept runs
+        #[arg(long)]
+        artifacts: bool,
+
+        /// Show what would be removed without deleting anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Compare two results.json files; exits non-zero if anything regressed
+    DiffResults {
+        /// Results to compare against (e.g. the main branch)
+        before: PathBuf,
+
+        /// Results to check (e.g. the change under review)
+        after: PathBuf,
+
+        /// Percentage points a rate (compilation, tests, lint, annotations) may drop
+        #[arg(long, default_value = "2.0")]
+        rate_threshold: f64,
+
+        /// Percent tokens, execution time and cost may grow
+        #[arg(long, default_value = "10.0")]
+        resource_threshold: f64,
+
+        /// Percentage points a task's compilation or test pass rate may drop
+        #[arg(long, default_value = "10.0")]
+        task_threshold: f64,
+
+        /// Emit the comparison as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show how headline metrics changed across recorded runs (results/history)
+    Trends {
+        /// Results directory holding the history (defaults to paths.results_dir)
+        #[arg(short, long)]
+        results_dir: Option<PathBuf>,
+
+        /// Path to configuration file
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Only show this model
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Only show this metric (e.g. test_pass_rate)
+        #[arg(long)]
+        metric: Option<String>,
+
+        /// Emit the series as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Initialize default configuration
     Init {
         /// Output path for config file
@@ -120,47 +199,173 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
-    match cli.command {
-        Commands::Run {
-            config,
-            categories,
-            languages,
-            tasks,
-            repetitions,
-            dry_run,
-            use_api,
-            compare,
-            output,
-        } => run_benchmarks(RunArgs {
-            config,
-            categories,
-            languages,
-            tasks,
-            repetitions,
-            dry_run,
-            use_api,
-            compare,
-            output,
-        })
-        .await,
+    // A run stops gracefully on the first Ctrl-C so completed work is reported. Claude CLI
+    // processes run in their own process groups, out of reach of the terminal's Ctrl-C;
+    // kill them explicitly on a hard stop so nothing is left behind
+    let graceful = matches!(cli.command, Commands::Run(_));
+    tokio::spawn(async move {
+        while tokio::signal::ctrl_c().await.is_ok() {
+            if graceful && INTERRUPT.request() {
+                tracing::warn!(
+                    "Interrupted: no new executions will start; running ones finish and partial \
+                     results are written. Press Ctrl-C again to stop immediately"
+                );
+                continue;
+            }
+            kill_all_trees();
+            std::process::exit(130);
+        }
+    });
+
+    let result = match cli.command {
+        Commands::Run(args) => run_benchmarks(*args).await,
         Commands::Compare { results_dir, config } => run_comparison_only(results_dir, config),
+        Commands::Evaluate {
+            results_dir,
+            config,
+        } => reevaluate_results(results_dir, config).await,
         Commands::Report { results, output } => generate_reports(results, output),
         Commands::List { category, language } => list_tasks(category, language),
         Commands::Validate => validate(),
+        Commands::Parity { json, strict } => corpus_parity(json, strict),
+        Commands::Clean {
+            results_dir,
+            config,
+            keep_last,
+            older_than_days,
+            artifacts,
+            dry_run,
+        } => clean_results(
+            results_dir,
+            config,
+            RetentionPolicy {
+                keep_last,
+                max_age_days: older_than_days,
+                prune_artifacts: artifacts,
+            },
+            dry_run,
+        ),
+        Commands::DiffResults {
+            before,
+            after,
+            rate_threshold,
+            resource_threshold,
+            task_threshold,
+            json,
+        } => diff_result_files(
+            before,
+            after,
+            RegressionThresholds {
+                rate_points: rate_threshold,
+                resource_percent: resource_threshold,
+                task_points: task_threshold,
+            },
+            json,
+        ),
+        Commands::Trends {
+            results_dir,
+            config,
+            model,
+            metric,
+            json,
+        } => show_trends(results_dir, config, model, metric, json),
         Commands::Init { output } => init_config(output),
+    };
+
+    // Partial results have been written; exit the way an interrupted process does
+    if INTERRUPT.is_requested() {
+        result?;
+        std::process::exit(130);
     }
+    result
 }
 
+#[derive(Args)]
 struct RunArgs {
+    /// Path to configuration file
+    #[arg(short, long)]
     config: Option<PathBuf>,
+
+    /// Filter by categories (comma-separated)
+    #[arg(long)]
     categories: Option<String>,
+
+    /// Filter by languages (comma-separated)
+    #[arg(long)]
     languages: Option<String>,
+
+    /// Filter by task IDs (comma-separated)
+    #[arg(long)]
     tasks: Option<String>,
+
+    /// Number of repetitions
+    #[arg(short, long, default_value = "1")]
     repetitions: u32,
+
+    /// Maximum number of task executions to run in parallel (overrides run.concurrency)
+    #[arg(short = 'j', long)]
+    concurrency: Option<usize>,
+
+    /// Prompt modes to run: baseline, aicms, or both (overrides run.modes)
+    #[arg(long)]
+    modes: Option<ModeSelection>,
+
+    /// Run without making API calls, answering every task with a bundled fixture response
+    #[arg(long)]
     dry_run: bool,
+
+    /// Use direct API instead of Claude Code CLI (requires ANTHROPIC_API_KEY)
+    #[arg(long, conflicts_with = "backend")]
     use_api: bool,
+
+    /// Code generation backend: claude-code, api, ollama, bedrock, or vertex (overrides backend.kind)
+    #[arg(long)]
+    backend: Option<BackendKind>,
+
+    /// Enable Claude-based comparison scoring (slower, uses Claude to score both implementations)
+    #[arg(long)]
     compare: bool,
+
+    /// Output directory for results
+    #[arg(short, long, default_value = "results")]
     output: PathBuf,
+
+    /// Resume an interrupted run (e.g., results/2026-01-20_12-00-00), skipping completed executions
+    #[arg(long, conflicts_with = "output")]
+    resume: Option<PathBuf>,
+
+    /// Save every prompt/response pair as a fixture in this directory (overrides run.record_dir)
+    #[arg(long)]
+    record: Option<PathBuf>,
+
+    /// Replay fixtures from this directory instead of calling a model (overrides run.replay_dir)
+    #[arg(long, conflicts_with_all = ["record", "dry_run"])]
+    replay: Option<PathBuf>,
+
+    /// Sweep: run the matrix once per temperature, e.g. 0.0,0.3,0.7 (overrides api.temperatures)
+    #[arg(long, value_delimiter = ',')]
+    temperatures: Vec<f32>,
+
+    /// Skill files or directories of variants to A/B test; the AICMS arm runs once per
+    /// variant (overrides paths.skill_variants)
+    #[arg(long, value_delimiter = ',')]
+    skill_variants: Vec<PathBuf>,
+
+    /// Sweep: run the matrix once per seed, e.g. 1,2,3 (Ollama only; overrides api.seeds)
+    #[arg(long, value_delimiter = ',')]
+    seeds: Vec<u64>,
+
+    /// Feed compile/test failures back to the model for up to N repair turns (overrides run.repair_turns)
+    #[arg(long)]
+    repair_turns: Option<u32>,
+
+    /// Stop starting executions once the run has cost this many USD (overrides run.max_cost_usd)
+    #[arg(long)]
+    max_cost_usd: Option<f64>,
+
+    /// Stop starting executions after this many input+output tokens (overrides run.max_total_tokens)
+    #[arg(long)]
+    max_total_tokens: Option<u64>,
 }
 
 /// @ai:intent Run benchmark suite
@@ -170,7 +375,42 @@ async fn run_benchmarks(args: RunArgs) -> Result<()> {
 
     config.run.repetitions = args.repetitions;
     config.run.dry_run = args.dry_run;
+    if let Some(concurrency) = args.concurrency {
+        config.run.concurrency = concurrency;
+    }
+    if let Some(modes) = args.modes {
+        config.run.modes = modes;
+    }
     config.run.filter = build_filter(args.categories, args.languages, args.tasks);
+    if args.record.is_some() {
+        config.run.record_dir = args.record;
+    }
+    if args.replay.is_some() {
+        config.run.replay_dir = args.replay;
+    }
+    if !args.temperatures.is_empty() {
+        config.api.temperatures = args.temperatures;
+    }
+    if !args.seeds.is_empty() {
+        config.api.seeds = args.seeds;
+    }
+    if !args.skill_variants.is_empty() {
+        config.paths.skill_variants = args.skill_variants;
+    }
+    if let Some(repair_turns) = args.repair_turns {
+        config.run.repair_turns = repair_turns;
+    }
+    if args.max_cost_usd.is_some() {
+        config.run.max_cost_usd = args.max_cost_usd;
+    }
+    if args.max_total_tokens.is_some() {
+        config.run.max_total_tokens = args.max_total_tokens;
+    }
+    if args.use_api {
+        config.backend.kind = BackendKind::Api;
+    } else if let Some(backend) = args.backend {
+        config.backend.kind = backend;
+    }
 
     let toolchain_status = ToolchainValidator::validate();
     ToolchainValidator::log_warnings(&toolchain_status);
@@ -198,59 +438,589 @@ async fn run_benchmarks(args: RunArgs) -> Result<()> {
     tracing::info!("Found {} tasks to run", tasks.len());
 
     // Create output directory first so Claude runs inside it
-    let timestamp = chrono::Utc::now().format("%Y-%m-%d_%H-%M-%S");
-    let output_dir = args.output.join(timestamp.to_string());
+    let output_dir = match args.resume {
+        Some(dir) => {
+            if !dir.is_dir() {
+                anyhow::bail!("Cannot resume: {} is not a directory", dir.display());
+            }
+            dir
+        }
+        None => {
+            let timestamp = chrono::Utc::now().format("%Y-%m-%d_%H-%M-%S");
+            args.output.join(timestamp.to_string())
+        }
+    };
     std::fs::create_dir_all(&output_dir)?;
     tracing::info!("Output directory: {}", output_dir.display());
 
-    let all_metrics = if config.run.dry_run {
-        tracing::info!("Running in dry-run mode");
-        let mock_client = Arc::new(MockClaudeClient::new(
-            "Mock response with ```rust\nfn main() {}\n```".to_string(),
-        ));
-        let executor = create_executor(mock_client, &config)?;
-        execute_tasks(&executor, &tasks).await?
-    } else if args.use_api {
-        tracing::info!("Using direct API (requires ANTHROPIC_API_KEY)");
-        let client = Arc::new(ClaudeClient::new(config.api.clone())?);
-        let executor = create_executor(client, &config)?;
-        execute_tasks(&executor, &tasks).await?
+    let reporter = ReportGenerator::new();
+    let events = EventLog::open(&output_dir)?;
+    let metadata = RunMetadata::capture(&config)?;
+    metadata.write(&output_dir)?;
+
+    let budget = Budget::from_config(&config.run).map(Arc::new);
+    if budget.as_ref().is_some_and(|b| b.caps_cost()) {
+        warn_unpriced_models(&config);
+    }
+
+    if config.api.models.is_empty() && !config.is_sweep() && !config.is_skill_matrix() {
+        let results = run_model(
+            &config,
+            &tasks,
+            &output_dir,
+            args.compare,
+            budget.as_ref(),
+            &events,
+            &metadata,
+        )
+        .await?;
+        record_history(&config, &output_dir, &output_dir, &results);
+        push_metrics(&config, &results).await;
+        print_summary(&results);
+        print_resume_hint(&output_dir);
+        return Ok(());
+    }
+
+    let models = config.models();
+    if !config.api.models.is_empty() {
+        tracing::info!("Running {} models: {}", models.len(), models.join(", "));
+    }
+
+    let settings = config.sampling_settings();
+    if config.is_sweep() {
+        warn_unsupported_sampling(&config);
+        let labels: Vec<String> = settings.iter().map(|s| s.label()).collect();
+        tracing::info!("Sweeping {} sampling settings: {}", settings.len(), labels.join(", "));
+    }
+
+    // Skill matrix: one baseline arm, then one AICMS arm per variant
+    // (None is the baseline arm, or the whole configured run outside a skill matrix)
+    let mut arms: Vec<Option<SkillVariant>> = vec![None];
+    if config.is_skill_matrix() {
+        let variants = config.skill_variants()?;
+        let labels: Vec<&str> = variants.iter().map(|v| v.label.as_str()).collect();
+        tracing::info!("Comparing {} skill variants: {}", variants.len(), labels.join(", "));
+        if config.run.modes == ModeSelection::Aicms {
+            arms.clear();
+        }
+        if config.run.modes != ModeSelection::Baseline {
+            arms.extend(variants.into_iter().map(Some));
+        }
+    }
+
+    let mut runs = Vec::new();
+    let mut runs_not_started = 0;
+    for model in &models {
+        for setting in &settings {
+            for arm in &arms {
+                if INTERRUPT.is_requested() {
+                    runs_not_started += 1;
+                    continue;
+                }
+                if budget.as_ref().is_some_and(|b| b.is_exhausted()) {
+                    tracing::warn!("Budget exhausted; not starting {} ({})", model, setting.label());
+                    runs_not_started += 1;
+                    continue;
+                }
+
+                let mut run_config = config.clone();
+                let mut sub_dir = PathBuf::new();
+                if !config.api.models.is_empty() {
+                    run_config = run_config.for_model(model);
+                    sub_dir.push(model_dir_name(model));
+                }
+                if config.is_sweep() {
+                    run_config = run_config.for_sampling(*setting);
+                    sub_dir.push(setting.dir_name());
+                }
+                if config.is_skill_matrix() {
+                    match arm {
+                        Some(variant) => {
+                            run_config = run_config.for_skill(variant);
+                            sub_dir.push(variant.dir_name());
+                        }
+                        None => {
+                            run_config.run.modes = ModeSelection::Baseline;
+                            sub_dir.push("baseline");
+                        }
+                    }
+                }
+
+                let run_dir = output_dir.join(&sub_dir);
+                tracing::info!("Model {} ({}) -> {}", model, setting.label(), run_dir.display());
+                // Keep each run's fixtures apart so replays serve the right run's answers
+                for dir in [&mut run_config.run.record_dir, &mut run_config.run.replay_dir]
+                    .into_iter()
+                    .flatten()
+                {
+                    *dir = dir.join(&sub_dir);
+                }
+                let run_events = events.for_run(Some(portable_path(&sub_dir)));
+                let results = run_model(
+                    &run_config,
+                    &tasks,
+                    &run_dir,
+                    args.compare,
+                    budget.as_ref(),
+                    &run_events,
+                    &metadata,
+                )
+                .await?;
+                record_history(&run_config, &output_dir, &run_dir, &results);
+                print_summary(&results);
+                runs.push(results);
+            }
+        }
+    }
+
+    let mut combined = MetricsAggregator::from_config(&config.statistics).combine_models(
+        &runs,
+        &tasks,
+        config.run.repetitions,
+    );
+    combined.truncated = match runs_not_started {
+        0 => runs.iter().find_map(|r| r.truncated.clone()),
+        n if INTERRUPT.is_requested() => Some(format!("interrupted; {} runs not started", n)),
+        n => budget.as_ref().map(|b| {
+            format!(
+                "budget exhausted: {}; {} runs not started",
+                b.reason().unwrap_or_default(),
+                n
+            )
+        }),
+    };
+    // With several runs, rank every implementation of each task against each other
+    let has_run_dirs = config.run.replay_dir.is_none() && !config.run.dry_run;
+    if args.compare && runs.len() > 1 && has_run_dirs && !INTERRUPT.is_requested() {
+        tracing::info!("Ranking implementations across {} runs...", runs.len());
+        let rankings = run_rankings(&config, &output_dir, |task_id| {
+            tasks.iter().find(|t| t.id == task_id).map(build_task_spec)
+        })?;
+        MetricsAggregator::new().add_rankings(&mut combined, rankings);
+    }
+    combined.metadata = Some(metadata);
+    reporter.generate_all(&combined, &output_dir)?;
+    push_metrics(&config, &combined).await;
+    if let Some(reason) = &combined.truncated {
+        println!("Run truncated: {}", reason);
+    }
+    if combined.by_model.len() > 1 {
+        print_model_summary(&combined);
+    }
+    if combined.by_sampling.len() > 1 {
+        print_sampling_summary(&combined);
+    }
+    if !combined.by_skill.is_empty() {
+        print_skill_summary(&combined);
+    }
+    if !combined.ranking_stats.is_empty() {
+        print_ranking_summary(&combined.ranking_stats);
+    }
+    print_resume_hint(&output_dir);
+
+    Ok(())
+}
+
+/// @ai:intent Append a run to the history of the results directory holding it (dry runs
+///            answer from fixtures and are left out); a failure only warns
+/// @ai:effects fs:write
+fn record_history(
+    config: &BenchmarkConfig,
+    output_dir: &std::path::Path,
+    run_dir: &std::path::Path,
+    results: &aicms_bench::BenchmarkResults,
+) {
+    if config.run.dry_run {
+        return;
+    }
+    // Runs live in <results>/<timestamp>[/<model>/...]; the history sits next to them
+    let results_dir = output_dir.parent().unwrap_or(std::path::Path::new("."));
+    if let Err(e) = record_run(results_dir, run_dir, results) {
+        tracing::warn!("Failed to record the run in the history: {}", e);
+    }
+}
+
+/// @ai:intent Push a run's metrics to the configured Pushgateway (dry runs are left out, like
+///            in the history); a failure only warns
+/// @ai:effects network
+async fn push_metrics(config: &BenchmarkConfig, results: &aicms_bench::BenchmarkResults) {
+    let Some(url) = &config.export.pushgateway_url else {
+        return;
+    };
+    if config.run.dry_run {
+        return;
+    }
+    match OpenMetricsExporter::new()
+        .push(results, url, &config.export.job)
+        .await
+    {
+        Ok(()) => tracing::info!("Metrics pushed to {}", url),
+        Err(e) => tracing::warn!("{:#}", e),
+    }
+}
+
+/// @ai:intent After an interrupted run, tell the user how to finish it
+/// @ai:effects io
+fn print_resume_hint(output_dir: &std::path::Path) {
+    if INTERRUPT.is_requested() {
+        println!(
+            "Run interrupted; partial results written. Finish it with: aicms-bench run --resume {}",
+            output_dir.display()
+        );
+    }
+}
+
+/// @ai:intent Run the task x mode matrix for one model and write its reports to output_dir
+/// @ai:effects network, fs:write
+async fn run_model(
+    config: &BenchmarkConfig,
+    tasks: &[aicms_bench::corpus::Task],
+    output_dir: &std::path::Path,
+    compare: bool,
+    budget: Option<&Arc<Budget>>,
+    events: &EventLog,
+    metadata: &RunMetadata,
+) -> Result<aicms_bench::BenchmarkResults> {
+    std::fs::create_dir_all(output_dir)?;
+    let output_dir = output_dir.to_path_buf();
+    let metadata = metadata.for_config(config)?;
+    metadata.write(&output_dir)?;
+    let skipped_before = budget.map_or(0, |b| b.skipped());
+    let interrupted_before = INTERRUPT.skipped();
+    events.emit(Event::RunStarted {
+        model: config.model_name().to_string(),
+        tasks: tasks.len(),
+        repetitions: config.run.repetitions,
+    });
+
+    let checkpoint = Arc::new(CheckpointStore::open(&output_dir)?);
+    if checkpoint.completed_count() > 0 {
+        tracing::info!(
+            "Resuming: {} executions already completed in {}",
+            checkpoint.completed_count(),
+            checkpoint.path().display()
+        );
+    }
+
+    // HTTP backends return code as text: keep it where the CLI leaves its working directories
+    let code_dir = Some(output_dir.as_path());
+    let all_metrics = if let Some(dir) = &config.run.replay_dir {
+        let client = ReplayClient::load(dir)?;
+        tracing::info!("Replaying {} fixtures from {}", client.len(), dir.display());
+        let executor = create_executor(Arc::new(client), config)?.with_checkpoint(checkpoint);
+        execute_tasks(executor, config, budget.cloned(), events, tasks, None).await?
+    } else if config.run.dry_run {
+        tracing::info!("Running in dry-run mode with bundled fixture responses");
+        let client = Arc::new(DryRunClient::new(tasks));
+        let executor = create_executor(client, config)?.with_checkpoint(checkpoint);
+        execute_tasks(executor, config, budget.cloned(), events, tasks, None).await?
     } else {
-        tracing::info!("Using Claude Code CLI");
-        let client = Arc::new(ClaudeCodeClient::new(output_dir.clone()));
-        let executor = create_executor(client, &config)?;
-        execute_tasks(&executor, &tasks).await?
+        match config.backend.kind {
+            BackendKind::Api => {
+                tracing::info!("Using direct API (requires ANTHROPIC_API_KEY)");
+                let client = ClaudeClient::new(config.api.clone())?;
+                execute_with(
+                    client,
+                    config,
+                    checkpoint,
+                    budget.cloned(),
+                    events,
+                    tasks,
+                    code_dir,
+                )
+                .await?
+            }
+            BackendKind::Ollama => {
+                tracing::info!(
+                    "Using Ollama at {} (model {})",
+                    config.backend.ollama.endpoint,
+                    config.backend.ollama.model
+                );
+                let client = OllamaClient::new(config.backend.ollama.clone(), &config.api)?;
+                execute_with(
+                    client,
+                    config,
+                    checkpoint,
+                    budget.cloned(),
+                    events,
+                    tasks,
+                    code_dir,
+                )
+                .await?
+            }
+            BackendKind::Bedrock => {
+                tracing::info!("Using AWS Bedrock (model {})", config.backend.bedrock.model_id);
+                let client =
+                    BedrockClient::new(config.backend.bedrock.clone(), config.api.clone())?;
+                execute_with(
+                    client,
+                    config,
+                    checkpoint,
+                    budget.cloned(),
+                    events,
+                    tasks,
+                    code_dir,
+                )
+                .await?
+            }
+            BackendKind::Vertex => {
+                tracing::info!(
+                    "Using Vertex AI in {} (model {})",
+                    config.backend.vertex.region,
+                    config.backend.vertex.model
+                );
+                let client = VertexClient::new(config.backend.vertex.clone(), config.api.clone())?;
+                execute_with(
+                    client,
+                    config,
+                    checkpoint,
+                    budget.cloned(),
+                    events,
+                    tasks,
+                    code_dir,
+                )
+                .await?
+            }
+            BackendKind::ClaudeCode => {
+                tracing::info!("Using Claude Code CLI");
+                let client = if config.api.models.is_empty() {
+                    ClaudeCodeClient::new(output_dir.clone())
+                } else {
+                    ClaudeCodeClient::with_model(config.api.model.clone(), output_dir.clone())
+                }
+                .with_skill_file(config.paths.skill_file.clone())
+                .with_claude_md_template(load_claude_md_template(&config.paths.claude_md_template)?)
+                .with_config(config.backend.claude_code.clone());
+                execute_with(
+                    client,
+                    config,
+                    checkpoint,
+                    budget.cloned(),
+                    events,
+                    tasks,
+                    None,
+                )
+                .await?
+            }
+        }
     };
 
-    let aggregator = MetricsAggregator::new();
-    let mut results =
-        aggregator.aggregate(&all_metrics.metrics, &tasks, &config.api.model, config.run.repetitions);
+    let mut metrics = all_metrics.metrics;
+    label_and_price(config, &mut metrics);
 
-    // Load comparison prompt for saving with results
-    let comparison_prompt = load_comparison_prompt(&config.paths.comparison_prompt_file)?;
+    let aggregator = MetricsAggregator::from_config(&config.statistics);
+    let mut results =
+        aggregator.aggregate(&metrics, tasks, config.model_name(), config.run.repetitions);
+    if let Some(budget) = budget {
+        let skipped = budget.skipped() - skipped_before;
+        if skipped > 0 {
+            results.truncated = Some(format!(
+                "budget exhausted: {}; {} executions not run",
+                budget.reason().unwrap_or_default(),
+                skipped
+            ));
+        }
+    }
+    let interrupted = INTERRUPT.skipped() - interrupted_before;
+    if interrupted > 0 {
+        results.truncated = Some(format!("interrupted; {} executions not run", interrupted));
+    }
 
-    // Run Claude comparisons if enabled (only works with Claude Code CLI mode)
-    if args.compare && !config.run.dry_run && !args.use_api {
+    // Load comparison prompt for saving with results, with the rubric filled in
+    let prompt_template = load_comparison_prompt(&config.paths.comparison_prompt_file)?;
+    let rubric = load_comparison_rubric(&config.paths.comparison_rubric_file)?;
+    let comparison_prompt = rubric.render(&prompt_template);
+
+    // Run Claude comparisons if enabled (every live backend leaves code directories)
+    let has_run_dirs = config.run.replay_dir.is_none();
+    if compare && INTERRUPT.is_requested() {
+        tracing::warn!("Comparison skipped: the run was interrupted");
+    } else if compare && config.run.modes != ModeSelection::Both {
+        tracing::warn!(
+            "Comparison needs both modes; skipped for --modes {}",
+            config.run.modes.as_str()
+        );
+    } else if compare && !config.run.dry_run && has_run_dirs {
         tracing::info!("Running Claude-based comparisons...");
-        let comparisons = run_claude_comparisons(&config, &tasks, &output_dir)?;
-        aggregator.add_claude_comparisons(&mut results, comparisons);
-    } else if args.compare && args.use_api {
-        tracing::warn!("Comparison not available with --use-api (no run directories)");
+        let (comparisons, unparsed) = run_claude_comparisons(config, tasks, &output_dir)?;
+        aggregator.add_claude_comparisons(&mut results, comparisons, tasks);
+        aggregator.add_judge_failures(&mut results, &unparsed);
+    } else if compare && !has_run_dirs {
+        tracing::warn!("Comparison not available when replaying fixtures (no run directories)");
     }
 
+    results.metadata = Some(metadata);
     let reporter = ReportGenerator::new();
     reporter.generate_all(&results, &output_dir)?;
 
     // Save comparison prompt used
     reporter.save_comparison_prompt(&comparison_prompt, &output_dir)?;
 
-    print_summary(&results);
+    events.emit(Event::RunFinished {
+        model: config.model_name().to_string(),
+        executions: metrics.len(),
+        truncated: results.truncated.clone(),
+    });
 
-    if let Some(ref stats) = results.claude_stats {
-        print_claude_summary(stats, &results.claude_comparisons);
+    Ok(results)
+}
+
+/// @ai:intent Tag metrics with the run's sampling setting and skill variant, and price them
+/// @ai:effects pure
+fn label_and_price(config: &BenchmarkConfig, metrics: &mut [TaskMetrics]) {
+    if let Some(sampling) = config.sampling_label() {
+        for m in metrics.iter_mut() {
+            m.sampling = Some(sampling.clone());
+        }
+    }
+    if let Some(skill) = config.skill_label() {
+        for m in metrics.iter_mut() {
+            m.skill = Some(skill.clone());
+        }
     }
 
-    Ok(())
+    // Price executions from [pricing]; a cost reported by the backend (Claude Code) wins
+    let pricing = config.pricing_for(config.model_name());
+    match pricing {
+        Some(pricing) if !config.run.dry_run => {
+            for m in metrics.iter_mut().filter(|m| m.cost_usd.is_none()) {
+                m.cost_usd = Some(pricing.cost(
+                    m.input_tokens,
+                    m.output_tokens,
+                    m.cache_creation_input_tokens,
+                    m.cache_read_input_tokens,
+                ));
+            }
+        }
+        None if !config.run.dry_run => {
+            tracing::info!(
+                "No price known for {}; add it under [pricing] to track cost",
+                config.model_name()
+            );
+        }
+        _ => {}
+    }
+}
+
+/// @ai:intent Directory name for a model's results (path-unsafe characters replaced)
+/// @ai:effects pure
+fn model_dir_name(model: &str) -> String {
+    model
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '-' })
+        .collect()
+}
+
+/// @ai:intent Warn when a cost cap cannot see the cost of some models
+/// @ai:effects io
+fn warn_unpriced_models(config: &BenchmarkConfig) {
+    // Claude Code reports its own cost
+    if config.backend.kind == BackendKind::ClaudeCode {
+        return;
+    }
+    for model in config.models() {
+        if config.pricing_for(&model).is_none() {
+            tracing::warn!(
+                "run.max_cost_usd cannot count {} (no price; add it under [pricing])",
+                model
+            );
+        }
+    }
+}
+
+/// @ai:intent Warn about sweep dimensions the selected backend ignores
+/// @ai:effects io
+fn warn_unsupported_sampling(config: &BenchmarkConfig) {
+    if config.backend.kind == BackendKind::ClaudeCode && !config.api.temperatures.is_empty() {
+        tracing::warn!("The Claude Code CLI does not expose temperature; every setting will sample alike");
+    }
+    if config.backend.kind != BackendKind::Ollama && !config.api.seeds.is_empty() {
+        tracing::warn!(
+            "The {} backend has no seed parameter; seeds only act as extra repetitions",
+            config.backend.kind.as_str()
+        );
+    }
+}
+
+/// @ai:intent Print sampling settings side by side after a temperature/seed sweep
+/// @ai:effects io
+fn print_sampling_summary(results: &aicms_bench::BenchmarkResults) {
+    println!();
+    println!("Sampling Settings Side by Side");
+    println!("=============================");
+    println!();
+    println!(
+        "{:<40} {:>10} {:>10} {:>10} {:>10}",
+        "Setting", "Compile B", "Compile A", "Tests B", "Tests A"
+    );
+    println!("{}", "-".repeat(84));
+
+    for setting in &results.by_sampling {
+        println!(
+            "{:<40} {:>9.1}% {:>9.1}% {:>9.1}% {:>9.1}%",
+            setting.sampling,
+            setting.baseline.compilation_rate,
+            setting.aicms.compilation_rate,
+            setting.baseline.avg_test_pass_rate,
+            setting.aicms.avg_test_pass_rate
+        );
+    }
+    println!();
+}
+
+/// @ai:intent Print skill variants side by side after a skill matrix run
+/// @ai:effects io
+fn print_skill_summary(results: &aicms_bench::BenchmarkResults) {
+    println!();
+    println!("Skill Variants Side by Side");
+    println!("===========================");
+    println!();
+    println!(
+        "{:<30} {:>10} {:>10} {:>12} {:>12}",
+        "Variant", "Compile", "Tests", "Annotations", "Tests Delta"
+    );
+    println!("{}", "-".repeat(78));
+
+    for variant in &results.by_skill {
+        println!(
+            "{:<30} {:>9.1}% {:>9.1}% {:>11.1}% {:>12}",
+            variant.skill,
+            variant.aicms.compilation_rate,
+            variant.aicms.avg_test_pass_rate,
+            variant.aicms.avg_annotation_quality,
+            variant
+                .delta
+                .as_ref()
+                .map_or("n/a".to_string(), |d| format!("{:+.1}%", d.test_pass_rate))
+        );
+    }
+    println!();
+}
+
+/// @ai:intent Print models side by side after a multi-model run
+/// @ai:effects io
+fn print_model_summary(results: &aicms_bench::BenchmarkResults) {
+    println!();
+    println!("Models Side by Side");
+    println!("===================");
+    println!();
+    println!(
+        "{:<40} {:>10} {:>10} {:>10} {:>10}",
+        "Model", "Compile B", "Compile A", "Tests B", "Tests A"
+    );
+    println!("{}", "-".repeat(84));
+
+    for model in &results.by_model {
+        println!(
+            "{:<40} {:>9.1}% {:>9.1}% {:>9.1}% {:>9.1}%",
+            model.model,
+            model.baseline.compilation_rate,
+            model.aicms.compilation_rate,
+            model.baseline.avg_test_pass_rate,
+            model.aicms.avg_test_pass_rate
+        );
+    }
+    println!();
 }
 
 /// @ai:intent Run comparison only on existing results directory
@@ -262,6 +1032,20 @@ fn run_comparison_only(results_dir: PathBuf, config_path: Option<PathBuf>) -> Re
     let baseline_code_dir = results_dir.join("baseline").join("code");
     let aicms_code_dir = results_dir.join("aicms").join("code");
 
+    if !baseline_code_dir.exists() && !aicms_code_dir.exists() {
+        // Multi-run results (models, sweeps, skill variants): rank all implementations
+        let rankings = run_rankings(&config, &results_dir, |task_id| {
+            Some(format!("Task: {}\n\n(Task details from original corpus)", task_id))
+        })?;
+        if rankings.is_empty() {
+            anyhow::bail!(
+                "No tasks with two or more implementations found. Expected:\n  {0}/baseline/code/\n  {0}/aicms/code/\nor run directories such as {0}/<run>/<mode>/code/",
+                results_dir.display()
+            );
+        }
+        print_ranking_summary(&aicms_bench::metrics::RankingStats::aggregate(&rankings));
+        return save_ranking_results(&results_dir, &rankings);
+    }
     if !baseline_code_dir.exists() || !aicms_code_dir.exists() {
         anyhow::bail!(
             "Invalid results directory structure. Expected:\n  {}/baseline/code/\n  {}/aicms/code/",
@@ -280,15 +1064,12 @@ fn run_comparison_only(results_dir: PathBuf, config_path: Option<PathBuf>) -> Re
 
     tracing::info!("Found {} tasks to compare", tasks.len());
 
-    // Load comparison prompt
-    let prompt_template = load_comparison_prompt(&config.paths.comparison_prompt_file)?;
-
     // Run comparisons
-    let comparisons = run_comparison_on_discovered_tasks(&prompt_template, &tasks)?;
+    let comparisons = run_comparison_on_discovered_tasks(&config, &tasks)?;
 
     // Print results
     if !comparisons.is_empty() {
-        let stats = compute_comparison_stats(&comparisons);
+        let stats = compute_comparison_stats(&comparisons, &config.statistics);
         print_comparison_only_summary(&stats, &comparisons);
     }
 
@@ -298,6 +1079,180 @@ fn run_comparison_only(results_dir: PathBuf, config_path: Option<PathBuf>) -> Re
     Ok(())
 }
 
+/// @ai:intent Re-run evaluation over the executions saved in a results directory, without
+///            calling any model, and rewrite its reports (e.g. after improving a scorer)
+///            A matrix run is re-evaluated per model, setting and skill, then combined again
+/// @ai:effects fs:read, fs:write, io
+async fn reevaluate_results(results_dir: PathBuf, config_path: Option<PathBuf>) -> Result<()> {
+    let config = load_or_default_config(config_path)?;
+    let run_dirs = find_run_dirs(&results_dir);
+    if run_dirs.is_empty() {
+        anyhow::bail!("No {} found in {}", EXECUTIONS_FILE, results_dir.display());
+    }
+
+    tracing::info!("Loading corpus from {}", config.paths.corpus_dir.display());
+    let tasks = CorpusLoader::new().load_all(&config.paths.corpus_dir)?;
+    let events = EventLog::open(&results_dir)?;
+
+    let mut runs = Vec::new();
+    for run_dir in &run_dirs {
+        let sub_dir = run_dir.strip_prefix(&results_dir).unwrap_or(run_dir);
+        let run_events = if sub_dir.as_os_str().is_empty() {
+            events.clone()
+        } else {
+            events.for_run(Some(portable_path(sub_dir)))
+        };
+        let results = reevaluate_run(&config, &tasks, run_dir, &run_events).await?;
+        print_summary(&results);
+        runs.push(results);
+    }
+
+    if run_dirs != [results_dir.clone()] {
+        let run_tasks: Vec<_> = tasks
+            .iter()
+            .filter(|t| runs.iter().any(|r| r.task_metrics.iter().any(|m| m.task_id == t.id)))
+            .cloned()
+            .collect();
+        let saved = load_saved_results(&results_dir);
+        let repetitions = runs.iter().map(|r| r.repetitions).max().unwrap_or(1);
+        let mut combined = MetricsAggregator::from_config(&config.statistics).combine_models(
+            &runs,
+            &run_tasks,
+            repetitions,
+        );
+        combined.truncated = saved.and_then(|s| s.truncated);
+        combined.metadata = RunMetadata::load(&results_dir)?;
+        ReportGenerator::new().generate_all(&combined, &results_dir)?;
+        if combined.by_model.len() > 1 {
+            print_model_summary(&combined);
+        }
+        if combined.by_sampling.len() > 1 {
+            print_sampling_summary(&combined);
+        }
+        if !combined.by_skill.is_empty() {
+            print_skill_summary(&combined);
+        }
+    }
+
+    println!("Reports rewritten in {}", results_dir.display());
+    Ok(())
+}
+
+/// @ai:intent Re-evaluate the executions of one run directory and rewrite its reports
+///            The run's recorded config decides the model, labels and pricing;
+///            [sandbox] and [evaluation] come from the current config
+/// @ai:effects fs:read, fs:write, io
+async fn reevaluate_run(
+    config: &BenchmarkConfig,
+    tasks: &[aicms_bench::corpus::Task],
+    run_dir: &std::path::Path,
+    events: &EventLog,
+) -> Result<aicms_bench::BenchmarkResults> {
+    let metadata = RunMetadata::load(run_dir)?;
+    let mut run_config = match &metadata {
+        Some(metadata) => serde_json::from_value::<BenchmarkConfig>(metadata.config.clone())
+            .with_context(|| format!("Unreadable config in {}", run_dir.display()))?,
+        None => {
+            tracing::warn!(
+                "No run metadata in {}; using the current config",
+                run_dir.display()
+            );
+            config.clone()
+        }
+    };
+    run_config.sandbox = config.sandbox.clone();
+    run_config.evaluation = config.evaluation.clone();
+
+    let (executions, unknown): (Vec<_>, Vec<_>) =
+        CheckpointStore::load(&run_dir.join(EXECUTIONS_FILE))?
+            .into_iter()
+            .partition(|e| tasks.iter().any(|t| t.id == e.task_id));
+    for exec in &unknown {
+        tracing::warn!("Skipping execution of {}: task not in the corpus", exec.task_id);
+    }
+    let run_tasks: Vec<_> = tasks
+        .iter()
+        .filter(|t| executions.iter().any(|e| e.task_id == t.id))
+        .cloned()
+        .collect();
+    tracing::info!(
+        "Re-evaluating {} executions in {}",
+        executions.len(),
+        run_dir.display()
+    );
+
+    let evaluator = Arc::new(
+        Evaluator::new()
+            .with_sandbox(Sandbox::from_config(&run_config.sandbox))
+            .with_evaluation_config(&run_config.evaluation)
+            .with_corpus(tasks),
+    );
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+    for exec in &executions {
+        sender.send(exec.clone())?;
+    }
+    drop(sender);
+    let mut metrics = evaluate_completed(
+        evaluator,
+        Arc::new(run_tasks.clone()),
+        events.clone(),
+        receiver,
+        run_config.evaluation.worker_count(),
+    )
+    .await?;
+    sort_by_execution_order(&mut metrics, &executions);
+    label_and_price(&run_config, &mut metrics);
+
+    let aggregator = MetricsAggregator::from_config(&run_config.statistics);
+    let mut results = aggregator.aggregate(
+        &metrics,
+        &run_tasks,
+        run_config.model_name(),
+        run_config.run.repetitions,
+    );
+    // Nothing was re-run, so the saved comparisons and truncation still apply
+    if let Some(saved) = load_saved_results(run_dir) {
+        let unparsed: Vec<String> = saved
+            .task_metrics
+            .iter()
+            .filter(|m| m.failure == Some(FailureReason::JudgeParseError))
+            .map(|m| m.task_id.clone())
+            .collect();
+        results.truncated = saved.truncated;
+        aggregator.add_claude_comparisons(&mut results, saved.claude_comparisons, &run_tasks);
+        aggregator.add_judge_failures(&mut results, &unparsed);
+    }
+    results.metadata = metadata;
+    ReportGenerator::new().generate_all(&results, run_dir)?;
+
+    Ok(results)
+}
+
+/// @ai:intent Run directories holding saved executions: the directory itself, or the
+///            model / sampling / skill sub-directories of a matrix run, in name order
+/// @ai:effects fs:read
+fn find_run_dirs(results_dir: &std::path::Path) -> Vec<PathBuf> {
+    if results_dir.join(EXECUTIONS_FILE).is_file() {
+        return vec![results_dir.to_path_buf()];
+    }
+    walkdir::WalkDir::new(results_dir)
+        .min_depth(1)
+        .max_depth(3)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_dir() && entry.path().join(EXECUTIONS_FILE).is_file())
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+/// @ai:intent results.json of a directory, None if missing or unreadable
+/// @ai:effects fs:read
+fn load_saved_results(dir: &std::path::Path) -> Option<aicms_bench::BenchmarkResults> {
+    let content = std::fs::read_to_string(dir.join("results.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
 /// @ai:intent Discover task IDs from existing code directories
 /// @ai:effects fs:read
 fn discover_tasks_from_directory(
@@ -335,11 +1290,23 @@ fn discover_tasks_from_directory(
     }
 
     // Find tasks that exist in both
-    for task_id in baseline_tasks.intersection(&aicms_tasks) {
+    for dir_name in baseline_tasks.intersection(&aicms_tasks) {
+        let task_baseline_dir = baseline_dir.join(dir_name);
+
+        // Prefer the task ID recorded in TASK.json (directories may have been renamed)
+        let id = match TaskManifest::read(&task_baseline_dir) {
+            Ok(Some(manifest)) => manifest.task_id,
+            Ok(None) => dir_name.clone(),
+            Err(e) => {
+                tracing::warn!("Ignoring unreadable manifest in {}: {}", task_baseline_dir.display(), e);
+                dir_name.clone()
+            }
+        };
+
         tasks.push(DiscoveredTask {
-            id: task_id.clone(),
-            baseline_dir: baseline_dir.join(task_id),
-            aicms_dir: aicms_dir.join(task_id),
+            id,
+            baseline_dir: task_baseline_dir,
+            aicms_dir: aicms_dir.join(dir_name),
         });
     }
 
@@ -356,14 +1323,14 @@ struct DiscoveredTask {
 /// @ai:intent Run comparisons on discovered tasks
 /// @ai:effects network, fs:read
 fn run_comparison_on_discovered_tasks(
-    prompt_template: &str,
+    config: &BenchmarkConfig,
     tasks: &[DiscoveredTask],
 ) -> Result<Vec<aicms_bench::metrics::TaskComparison>> {
-    use aicms_bench::evaluator::{ClaudeScorer, ClaudeScorerTrait, CompilationChecker};
+    use aicms_bench::evaluator::{ClaudeScorerTrait, CompilationChecker};
     use aicms_bench::metrics::TaskComparison;
 
-    let scorer = ClaudeScorer::new(prompt_template.to_string());
-    let compiler = CompilationChecker::new();
+    let scorer = build_judge_panel(config)?;
+    let compiler = CompilationChecker::new().with_sandbox(Sandbox::from_config(&config.sandbox));
     let mut comparisons = Vec::new();
     let total = tasks.len();
 
@@ -424,6 +1391,7 @@ fn run_comparison_on_discovered_tasks(
 /// @ai:effects pure
 fn compute_comparison_stats(
     comparisons: &[aicms_bench::metrics::TaskComparison],
+    statistics: &StatisticsConfig,
 ) -> aicms_bench::metrics::ClaudeComparisonStats {
     let mut baseline_scores = Vec::new();
     let mut aicms_scores = Vec::new();
@@ -454,13 +1422,24 @@ fn compute_comparison_stats(
         aicms_scores.iter().sum::<f64>() / aicms_scores.len() as f64
     };
 
-    aicms_bench::metrics::ClaudeComparisonStats {
+    let mut stats = aicms_bench::metrics::ClaudeComparisonStats {
         avg_baseline_score: avg_baseline,
         avg_aicms_score: avg_aicms,
         baseline_wins,
         aicms_wins,
         ties,
-    }
+        agreement: aicms_bench::metrics::JudgeAgreement::from_comparisons(comparisons),
+        win_rate_ci: aicms_bench::metrics::ClaudeComparisonStats::win_rate_interval(
+            comparisons,
+            statistics.bootstrap_resamples,
+        ),
+        win_test: None,
+        by_category: vec![],
+        by_language: vec![],
+        by_difficulty: vec![],
+    };
+    stats.win_test = stats.win_test(statistics.ties);
+    stats
 }
 
 /// @ai:intent Print comparison-only summary
@@ -490,36 +1469,191 @@ struct ExecutionData {
     metrics: Vec<TaskMetrics>,
 }
 
+/// @ai:intent Execute tasks with a backend client, recording fixtures if run.record_dir is set
+///            Backends that leave no working directories save the evaluated code in code_dir
+/// @ai:effects network, fs:write
+async fn execute_with<C: ClaudeClientTrait + 'static>(
+    client: C,
+    config: &BenchmarkConfig,
+    checkpoint: Arc<CheckpointStore>,
+    budget: Option<Arc<Budget>>,
+    events: &EventLog,
+    tasks: &[aicms_bench::corpus::Task],
+    code_dir: Option<&std::path::Path>,
+) -> Result<ExecutionData> {
+    match &config.run.record_dir {
+        Some(dir) => {
+            tracing::info!("Recording fixtures to {}", dir.display());
+            let client = Arc::new(RecordingClient::new(client, dir)?);
+            let executor = create_executor(client, config)?.with_checkpoint(checkpoint);
+            execute_tasks(executor, config, budget, events, tasks, code_dir).await
+        }
+        None => {
+            let executor = create_executor(Arc::new(client), config)?.with_checkpoint(checkpoint);
+            execute_tasks(executor, config, budget, events, tasks, code_dir).await
+        }
+    }
+}
+
 /// @ai:intent Execute tasks and collect metrics
 /// @ai:effects network
-async fn execute_tasks<C: aicms_bench::runner::ClaudeClientTrait>(
-    executor: &aicms_bench::runner::BenchmarkExecutor<C>,
+async fn execute_tasks<C: aicms_bench::runner::ClaudeClientTrait + 'static>(
+    executor: aicms_bench::runner::BenchmarkExecutor<C>,
+    config: &BenchmarkConfig,
+    budget: Option<Arc<Budget>>,
+    events: &EventLog,
     tasks: &[aicms_bench::corpus::Task],
+    code_dir: Option<&std::path::Path>,
 ) -> Result<ExecutionData> {
-    let evaluator = Evaluator::new();
+    // The evaluator also checks responses between repair turns (run.repair_turns)
+    let mut evaluator = Evaluator::new()
+        .with_sandbox(Sandbox::from_config(&config.sandbox))
+        .with_evaluation_config(&config.evaluation)
+        .with_corpus(tasks);
+    if let Some(dir) = code_dir {
+        evaluator = evaluator.with_code_dir(dir.to_path_buf());
+    }
+    let evaluator = Arc::new(evaluator);
+    // Completed executions are evaluated on a worker pool while later ones still run
+    let (completed, receiver) = tokio::sync::mpsc::unbounded_channel();
+    let mut executor = executor
+        .with_repair_checker(evaluator.clone())
+        .with_events(events.clone())
+        .with_interrupt(INTERRUPT.clone())
+        .with_completed_sender(completed);
+    if let Some(budget) = budget {
+        executor = executor.with_budget(budget);
+    }
+    let evaluations = tokio::spawn(evaluate_completed(
+        evaluator,
+        Arc::new(tasks.to_vec()),
+        events.clone(),
+        receiver,
+        config.evaluation.worker_count(),
+    ));
+
+    // Dropping the executor closes the channel, so the pipeline drains and finishes
+    let executions = Arc::new(executor).execute_all(tasks).await;
+    let evaluated = evaluations.await.context("Evaluation pipeline panicked")?;
+    let executions = executions?;
+    let mut evaluated = evaluated?;
+    sort_by_execution_order(&mut evaluated, &executions);
+
+    Ok(ExecutionData { metrics: evaluated })
+}
+
+/// @ai:intent Put metrics in execution order (task, repetition, mode), not completion order
+/// @ai:effects pure
+fn sort_by_execution_order(
+    metrics: &mut [TaskMetrics],
+    executions: &[aicms_bench::runner::ExecutionResult],
+) {
+    let order: std::collections::HashMap<(&str, &str, u32), usize> = executions
+        .iter()
+        .enumerate()
+        .map(|(i, e)| ((e.task_id.as_str(), e.mode.as_str(), e.repetition), i))
+        .collect();
+    metrics.sort_by_key(|m| {
+        order
+            .get(&(m.task_id.as_str(), m.mode.as_str(), m.repetition))
+            .copied()
+            .unwrap_or(usize::MAX)
+    });
+}
+
+/// @ai:intent Evaluate executions as they arrive, at most `workers` at a time, then compare
+///            the outputs of different tasks for near-duplicates
+///            Evaluation is blocking (compilers, test runs), so each runs on a blocking thread
+/// @ai:post result is in completion order
+/// @ai:effects fs:write, io
+async fn evaluate_completed(
+    evaluator: Arc<Evaluator>,
+    tasks: Arc<Vec<aicms_bench::corpus::Task>>,
+    events: EventLog,
+    mut receiver: tokio::sync::mpsc::UnboundedReceiver<aicms_bench::runner::ExecutionResult>,
+    workers: usize,
+) -> Result<Vec<TaskMetrics>> {
+    let semaphore = Arc::new(tokio::sync::Semaphore::new(workers.max(1)));
+    let mut join_set = tokio::task::JoinSet::new();
+
+    while let Some(exec) = receiver.recv().await {
+        let permit = semaphore.clone().acquire_owned().await?;
+        let (evaluator, tasks, events) = (evaluator.clone(), tasks.clone(), events.clone());
+        join_set.spawn_blocking(move || {
+            let _permit = permit;
+            let task = tasks
+                .iter()
+                .find(|t| t.id == exec.task_id)
+                .context("Execution result for unknown task")?;
+            evaluate_execution(&evaluator, task, &exec, &events)
+        });
+    }
+
     let mut all_metrics = Vec::new();
-    let total_tasks = tasks.len();
-
-    for (index, task) in tasks.iter().enumerate() {
-        let current = index + 1;
-        tracing::info!("[{}/{}] Running task: {}", current, total_tasks, task.id);
-        let executions = executor.execute_task(task).await?;
-
-        for exec in &executions {
-            let eval = evaluator.evaluate(task, exec)?;
-            let metrics = TaskMetrics::from_evaluation(
-                &eval,
-                exec.input_tokens,
-                exec.output_tokens,
-                exec.execution_time_ms,
-            );
-            all_metrics.push(metrics);
-        }
+    let mut fingerprints = Vec::new();
+    while let Some(joined) = join_set.join_next().await {
+        let (metrics, fingerprint) = joined.context("Evaluation panicked")??;
+        all_metrics.push(metrics);
+        fingerprints.push(fingerprint);
     }
 
-    Ok(ExecutionData {
-        metrics: all_metrics,
-    })
+    let mut outputs: Vec<_> = all_metrics
+        .iter_mut()
+        .zip(&fingerprints)
+        .filter_map(|(m, fingerprint)| Some((fingerprint.as_ref()?, m.contamination.as_mut()?)))
+        .collect();
+    evaluator.compare_outputs(&mut outputs);
+    Ok(all_metrics)
+}
+
+/// @ai:intent Evaluate one execution and turn it into task metrics, with the fingerprint of
+///            its output
+/// @ai:effects fs:write, io
+fn evaluate_execution(
+    evaluator: &Evaluator,
+    task: &aicms_bench::corpus::Task,
+    exec: &aicms_bench::runner::ExecutionResult,
+    events: &EventLog,
+) -> Result<(TaskMetrics, Option<Fingerprint>)> {
+    let eval = match evaluator.evaluate(task, exec) {
+        Ok(eval) => eval,
+        Err(e) => {
+            events.emit(Event::EvaluationFailed {
+                task_id: exec.task_id.clone(),
+                mode: exec.mode,
+                repetition: exec.repetition,
+                error: format!("{:#}", e),
+            });
+            return Err(e);
+        }
+    };
+    events.emit(Event::Evaluated {
+        task_id: exec.task_id.clone(),
+        mode: exec.mode,
+        repetition: exec.repetition,
+        compiled: eval.compilation.as_ref().map(|c| c.success),
+        compile_errors: eval.compilation.as_ref().map_or(0, |c| c.errors.len()),
+        tests_passed: eval.tests.as_ref().map(|t| t.passed),
+        tests_total: eval.tests.as_ref().map(|t| t.total),
+    });
+    let mut metrics = TaskMetrics {
+        cache_creation_input_tokens: exec.cache_creation_input_tokens,
+        cache_read_input_tokens: exec.cache_read_input_tokens,
+        num_turns: exec.num_turns,
+        cost_usd: exec.cost_usd,
+        repair_turns: exec.repair_turns.clone(),
+        ..TaskMetrics::from_evaluation(
+            &eval,
+            exec.input_tokens,
+            exec.output_tokens,
+            exec.execution_time_ms,
+        )
+    };
+    // A timed-out or failed client explains the missing or broken code better
+    metrics.failure = metrics
+        .failure
+        .map(|failure| FailureReason::from_stop_reason(&exec.stop_reason).unwrap_or(failure));
+    Ok((metrics, eval.fingerprint))
 }
 
 /// @ai:intent Build task specification string for comparison
@@ -548,20 +1682,176 @@ fn load_comparison_prompt(path: &std::path::Path) -> Result<String> {
     }
 }
 
+/// @ai:intent Load the ranking prompt template from file, or the built-in one if it does not exist
+/// @ai:effects fs:read
+fn load_ranking_prompt(path: &std::path::Path) -> Result<String> {
+    if path.exists() {
+        std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to load ranking prompt: {}", e))
+    } else {
+        tracing::info!("Ranking prompt file not found at {:?}, using default", path);
+        Ok(aicms_bench::evaluator::default_ranking_prompt())
+    }
+}
+
+/// @ai:intent Build the comparison judge(s) from the configured prompts, rubric and [compare]
+/// @ai:effects fs:read, env
+fn build_judge_panel(config: &BenchmarkConfig) -> Result<aicms_bench::evaluator::JudgePanel> {
+    let prompt_template = load_comparison_prompt(&config.paths.comparison_prompt_file)?;
+    let ranking_template = load_ranking_prompt(&config.paths.ranking_prompt_file)?;
+    let rubric = load_comparison_rubric(&config.paths.comparison_rubric_file)?;
+    let backend = config.judge_backend();
+    if backend == JudgeBackend::Api {
+        tracing::info!("Judging comparisons over the API (requires ANTHROPIC_API_KEY)");
+        if config.compare.comparisons_per_session > 1 {
+            tracing::warn!("compare.comparisons_per_session is ignored by the API judge");
+        }
+    }
+    aicms_bench::evaluator::JudgePanel::from_config(
+        &prompt_template,
+        &ranking_template,
+        &rubric,
+        &config.compare,
+        backend,
+        &config.api,
+    )
+}
+
+/// @ai:intent Rank all implementations of each task found below root, skipping ones that do
+///            not compile; tasks with fewer than two compiling implementations are skipped
+/// @ai:effects network, fs:read, fs:write
+fn run_rankings(
+    config: &BenchmarkConfig,
+    root: &std::path::Path,
+    task_spec: impl Fn(&str) -> Option<String>,
+) -> Result<Vec<aicms_bench::evaluator::TaskRanking>> {
+    use aicms_bench::evaluator::{discover_contestants, rank_task, CompilationChecker};
+
+    let scorer = build_judge_panel(config)?;
+    let compiler = CompilationChecker::new().with_sandbox(Sandbox::from_config(&config.sandbox));
+    let candidates = discover_contestants(root)?;
+    let total = candidates.len();
+    let mut rankings = Vec::new();
+
+    for (i, (task_id, contestants)) in candidates.into_iter().enumerate() {
+        let Some(spec) = task_spec(&task_id) else {
+            continue;
+        };
+        let contestants: Vec<_> = contestants
+            .into_iter()
+            .filter(|c| check_directory_compiles(&compiler, &c.dir, &c.label, &task_id))
+            .collect();
+        if contestants.len() < 2 {
+            tracing::warn!(
+                "Skipping ranking for task {}: fewer than two implementations compile",
+                task_id
+            );
+            continue;
+        }
+
+        tracing::info!(
+            "[{}/{}] Ranking {} implementations ({}) for: {}",
+            i + 1,
+            total,
+            contestants.len(),
+            config.compare.ranking.as_str(),
+            task_id
+        );
+        match rank_task(
+            &scorer,
+            config.compare.ranking,
+            &task_id,
+            &spec,
+            &contestants,
+        ) {
+            Ok(ranking) => rankings.push(ranking),
+            Err(e) => tracing::warn!("Failed to rank task {}: {}", task_id, e),
+        }
+    }
+
+    Ok(rankings)
+}
+
+/// @ai:intent Save task rankings to JSON file
+/// @ai:effects fs:write
+fn save_ranking_results(
+    output_dir: &std::path::Path,
+    rankings: &[aicms_bench::evaluator::TaskRanking],
+) -> Result<()> {
+    let output_path = output_dir.join("ranking_results.json");
+    std::fs::write(&output_path, serde_json::to_string_pretty(rankings)?)?;
+    tracing::info!("Ranking results saved to {}", output_path.display());
+    Ok(())
+}
+
+/// @ai:intent Print how each implementation source placed across task rankings
+/// @ai:effects io
+fn print_ranking_summary(stats: &[aicms_bench::metrics::RankingStats]) {
+    println!();
+    println!("Implementation Ranking");
+    println!("======================");
+    println!();
+    println!(
+        "{:<40} {:>6} {:>9} {:>9} {:>7} {:>14} {:>7}",
+        "Implementation", "Tasks", "Avg Rank", "Avg Score", "Firsts", "W / L / T", "Rating"
+    );
+    println!("{}", "-".repeat(98));
+    for entry in stats {
+        println!(
+            "{:<40} {:>6} {:>9.2} {:>9.1} {:>7} {:>14} {:>7.0}",
+            entry.label,
+            entry.tasks,
+            entry.avg_rank,
+            entry.avg_score,
+            entry.first_places,
+            format!("{} / {} / {}", entry.wins, entry.losses, entry.ties),
+            entry.rating
+        );
+    }
+    println!();
+}
+
+/// @ai:intent Load the comparison rubric from file, or the built-in one if it does not exist
+/// @ai:effects fs:read
+fn load_comparison_rubric(path: &std::path::Path) -> Result<aicms_bench::evaluator::Rubric> {
+    if path.exists() {
+        aicms_bench::evaluator::Rubric::load(path)
+    } else {
+        tracing::info!("Comparison rubric not found at {:?}, using default", path);
+        Ok(aicms_bench::evaluator::Rubric::default())
+    }
+}
+
+/// @ai:intent Load the AICMS-mode CLAUDE.md template from file
+/// @ai:effects fs:read
+fn load_claude_md_template(path: &std::path::Path) -> Result<String> {
+    if path.exists() {
+        std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("Failed to load CLAUDE.md template: {}", e))
+    } else {
+        tracing::info!(
+            "CLAUDE.md template not found at {:?}, using default",
+            path
+        );
+        Ok(aicms_bench::runner::default_claude_md_template())
+    }
+}
+
 /// @ai:intent Run Claude comparisons for all tasks using the new directory structure
+///            Returns the comparisons and the ids of tasks whose judge answer could not be parsed
 /// @ai:effects network, fs:read
 fn run_claude_comparisons(
     config: &BenchmarkConfig,
     tasks: &[aicms_bench::corpus::Task],
     output_dir: &std::path::Path,
-) -> Result<Vec<aicms_bench::metrics::TaskComparison>> {
-    use aicms_bench::evaluator::{ClaudeScorer, ClaudeScorerTrait, CompilationChecker};
+) -> Result<(Vec<aicms_bench::metrics::TaskComparison>, Vec<String>)> {
+    use aicms_bench::evaluator::{ClaudeScorerTrait, CompilationChecker, JudgeParseError};
     use aicms_bench::metrics::TaskComparison;
 
-    let prompt_template = load_comparison_prompt(&config.paths.comparison_prompt_file)?;
-    let scorer = ClaudeScorer::new(prompt_template);
-    let compiler = CompilationChecker::new();
+    let scorer = build_judge_panel(config)?;
+    let compiler = CompilationChecker::new().with_sandbox(Sandbox::from_config(&config.sandbox));
     let mut comparisons = Vec::new();
+    let mut unparsed = Vec::new();
 
     // New directory structure: {output_dir}/{mode}/code/{task_id}/
     let baseline_code_dir = output_dir.join("baseline").join("code");
@@ -598,7 +1888,7 @@ fn run_claude_comparisons(
 
     if total == 0 {
         tracing::warn!("No tasks have both baseline and aicms directories. Skipping comparisons.");
-        return Ok(comparisons);
+        return Ok((comparisons, unparsed));
     }
 
     for (i, (task, baseline_dir, aicms_dir)) in tasks_with_both.iter().enumerate() {
@@ -646,11 +1936,14 @@ fn run_claude_comparisons(
             }
             Err(e) => {
                 tracing::warn!("Failed to compare task {}: {}", task.id, e);
+                if e.downcast_ref::<JudgeParseError>().is_some() {
+                    unparsed.push(task.id.clone());
+                }
             }
         }
     }
 
-    Ok(comparisons)
+    Ok((comparisons, unparsed))
 }
 
 /// @ai:intent Check if directory compiles and log result
@@ -716,67 +2009,110 @@ fn print_claude_summary(
         "Wins: AICMS {} | Baseline {} | Ties {}",
         stats.aicms_wins, stats.baseline_wins, stats.ties
     );
+    let interval = stats
+        .win_rate_ci
+        .map_or(String::new(), |ci| format!(" (95% CI {})", ci.format()));
+    println!(
+        "AICMS win rate: {:.1}%{} (ties count half)",
+        stats.win_rate(),
+        interval
+    );
+    if let Some(test) = &stats.win_test {
+        println!("Binomial test vs 50%: {}", test.format());
+    }
+    if let Some(agreement) = &stats.agreement {
+        let kappa = agreement
+            .fleiss_kappa
+            .map_or("n/a".to_string(), |kappa| format!("{:.2}", kappa));
+        println!(
+            "Judge agreement ({} passes, {} comparisons): Fleiss' kappa {} | unanimous {:.0}%",
+            agreement.passes,
+            agreement.comparisons,
+            kappa,
+            agreement.unanimous_rate * 100.0
+        );
+    }
+    let attempts: Vec<&[aicms_bench::evaluator::ParseAttempt]> = comparisons
+        .iter()
+        .flat_map(|c| std::iter::once(&c.comparison).chain(&c.comparison.passes))
+        .map(|score| score.parse_attempts.as_slice())
+        .collect();
+    let repaired = attempts
+        .iter()
+        .filter(|a| a.iter().any(|attempt| !attempt.repairs.is_empty()))
+        .count();
+    let reprompted = attempts.iter().filter(|a| a.len() > 1).count();
+    if repaired + reprompted > 0 {
+        println!(
+            "Judge answers: {} needed JSON repairs, {} needed a re-prompt",
+            repaired, reprompted
+        );
+    }
+    for (label, groups) in [
+        ("category", &stats.by_category),
+        ("language", &stats.by_language),
+        ("difficulty", &stats.by_difficulty),
+    ] {
+        if groups.is_empty() {
+            continue;
+        }
+        let rates: Vec<String> = groups
+            .iter()
+            .map(|g| format!("{} {:.1}% ({})", g.group, g.win_rate(), g.comparisons()))
+            .collect();
+        println!("AICMS win rate by {}: {}", label, rates.join(" | "));
+    }
 
     // Show detailed breakdown for each task
     for comp in comparisons {
         println!();
         println!("Task: {}", comp.task_id);
         println!("{}", "-".repeat(60));
-        println!("Winner: {}", comp.comparison.winner.to_uppercase());
-        println!("Summary: {}", comp.comparison.summary);
-        println!();
-
-        println!(
-            "  {:<22} {:>10} {:>10}",
-            "Aspect", "Baseline", "AICMS"
-        );
-        println!("  {}", "-".repeat(44));
-
-        println!(
-            "  {:<22} {:>10} {:>10}",
-            "Overall:",
-            comp.comparison.baseline.overall,
-            comp.comparison.aicms.overall
-        );
-        println!(
-            "  {:<22} {:>10} {:>10}",
-            "Intent Match:",
-            comp.comparison.baseline.intent_match.score,
-            comp.comparison.aicms.intent_match.score
-        );
-        println!(
-            "  {:<22} {:>10} {:>10}",
-            "Edge Cases:",
-            comp.comparison.baseline.edge_cases.score,
-            comp.comparison.aicms.edge_cases.score
-        );
+        println!("Winner: {}", comp.comparison.winner.to_uppercase());
+        println!("Summary: {}", comp.comparison.summary);
+        println!();
+
         println!(
             "  {:<22} {:>10} {:>10}",
-            "Code Quality:",
-            comp.comparison.baseline.code_quality.score,
-            comp.comparison.aicms.code_quality.score
+            "Aspect", "Baseline", "AICMS"
         );
+        println!("  {}", "-".repeat(44));
+
         println!(
             "  {:<22} {:>10} {:>10}",
-            "Annotation Compliance:",
-            comp.comparison.baseline.annotation_compliance.score,
-            comp.comparison.aicms.annotation_compliance.score
+            "Overall:",
+            comp.comparison.baseline.overall,
+            comp.comparison.aicms.overall
         );
+        let baseline = &comp.comparison.baseline.aspects;
+        let aicms = &comp.comparison.aicms.aspects;
+        let score = |aspect: Option<&aicms_bench::evaluator::claude_scorer::AspectScore>| {
+            aspect.map_or("-".to_string(), |a| a.score.to_string())
+        };
+        let mut names: Vec<&String> = baseline.keys().chain(aicms.keys()).collect();
+        names.sort();
+        names.dedup();
+        for name in &names {
+            println!(
+                "  {:<22} {:>10} {:>10}",
+                format!("{}:", name),
+                score(baseline.get(*name)),
+                score(aicms.get(*name))
+            );
+        }
 
         // Show reasons for differences
         println!();
         println!("  Baseline reasons:");
-        print_aspect_reason("Intent Match", &comp.comparison.baseline.intent_match.reason);
-        print_aspect_reason("Edge Cases", &comp.comparison.baseline.edge_cases.reason);
-        print_aspect_reason("Code Quality", &comp.comparison.baseline.code_quality.reason);
-        print_aspect_reason("Annotations", &comp.comparison.baseline.annotation_compliance.reason);
+        for (name, aspect) in baseline {
+            print_aspect_reason(name, &aspect.reason);
+        }
 
         println!();
         println!("  AICMS reasons:");
-        print_aspect_reason("Intent Match", &comp.comparison.aicms.intent_match.reason);
-        print_aspect_reason("Edge Cases", &comp.comparison.aicms.edge_cases.reason);
-        print_aspect_reason("Code Quality", &comp.comparison.aicms.code_quality.reason);
-        print_aspect_reason("Annotations", &comp.comparison.aicms.annotation_compliance.reason);
+        for (name, aspect) in aicms {
+            print_aspect_reason(name, &aspect.reason);
+        }
     }
 
     println!();
@@ -850,6 +2186,215 @@ fn validate() -> Result<()> {
     Ok(())
 }
 
+/// @ai:intent Report per-family language coverage of the corpus
+/// @ai:effects fs:read
+fn corpus_parity(json: bool, strict: bool) -> Result<()> {
+    let config = BenchmarkConfig::default();
+    let loader = CorpusLoader::new();
+    let tasks = loader.load_all(&config.paths.corpus_dir)?;
+    let report = analyze_parity(&tasks);
+    let incomplete = report.incomplete().count();
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        println!("Corpus parity ({} families):", report.families.len());
+        println!();
+        print!("{}", report.to_markdown());
+        println!();
+
+        for (language, count) in &report.task_counts {
+            println!(
+                "  {:<12} {} tasks, missing from {} families",
+                language,
+                count,
+                report.missing_counts().get(language).copied().unwrap_or(0)
+            );
+        }
+
+        if incomplete > 0 {
+            println!();
+            println!("Missing variants:");
+        }
+
+        for family in report.incomplete() {
+            let missing: Vec<_> = family.missing.iter().map(|l| l.as_str()).collect();
+            println!("  - {}: missing {}", family.family, missing.join(", "));
+        }
+    }
+
+    if strict && incomplete > 0 {
+        anyhow::bail!("{} task families are missing a language variant", incomplete);
+    }
+
+    Ok(())
+}
+
+/// @ai:intent Compare two results files and fail if any metric or task regressed beyond the
+///            thresholds
+/// @ai:effects fs:read, io
+fn diff_result_files(
+    before_path: PathBuf,
+    after_path: PathBuf,
+    thresholds: RegressionThresholds,
+    json: bool,
+) -> Result<()> {
+    let load = |path: &PathBuf| -> Result<aicms_bench::BenchmarkResults> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    };
+    let diff = diff_results(&load(&before_path)?, &load(&after_path)?, &thresholds);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&diff)?);
+    } else {
+        println!(
+            "Comparing {} -> {}",
+            before_path.display(),
+            after_path.display()
+        );
+        println!();
+        println!(
+            "  {:<10} {:<24} {:>12} {:>12} {:>10}  Change",
+            "Mode", "Metric", "Before", "After", "Delta"
+        );
+        for metric in &diff.metrics {
+            println!(
+                "  {:<10} {:<24} {:>12.2} {:>12.2} {:>+10.2}  {}",
+                metric.mode,
+                metric.metric,
+                metric.before,
+                metric.after,
+                metric.delta(),
+                metric.change.as_str()
+            );
+        }
+
+        for (change, label) in [
+            (Change::Regression, "Task regressions"),
+            (Change::Improvement, "Task improvements"),
+        ] {
+            let tasks: Vec<_> = diff.task_changes(change).collect();
+            if tasks.is_empty() {
+                continue;
+            }
+            println!("\n{} ({}):", label, tasks.len());
+            for task in tasks {
+                println!(
+                    "  {} [{}]: compiled {:.0}% -> {:.0}%, tests {:.1}% -> {:.1}%",
+                    task.task_id,
+                    task.mode,
+                    task.before.compilation_rate,
+                    task.after.compilation_rate,
+                    task.before.test_pass_rate,
+                    task.after.test_pass_rate
+                );
+            }
+        }
+        if !diff.added_tasks.is_empty() {
+            println!(
+                "\nOnly in {}: {}",
+                after_path.display(),
+                diff.added_tasks.join(", ")
+            );
+        }
+        if !diff.removed_tasks.is_empty() {
+            println!(
+                "\nOnly in {}: {}",
+                before_path.display(),
+                diff.removed_tasks.join(", ")
+            );
+        }
+    }
+
+    let regressions = diff.regression_count();
+    if regressions > 0 {
+        anyhow::bail!(
+            "{} regressions ({} metrics, {} tasks)",
+            regressions,
+            diff.metric_changes(Change::Regression).count(),
+            diff.task_changes(Change::Regression).count()
+        );
+    }
+    if !json {
+        println!("\nNo regressions");
+    }
+    Ok(())
+}
+
+/// @ai:intent Print the per-metric series of the recorded runs, per model
+/// @ai:effects fs:read, io
+fn show_trends(
+    results_dir: Option<PathBuf>,
+    config_path: Option<PathBuf>,
+    model: Option<String>,
+    metric: Option<String>,
+    json: bool,
+) -> Result<()> {
+    let results_dir = match results_dir {
+        Some(dir) => dir,
+        None => load_or_default_config(config_path)?.paths.results_dir,
+    };
+    let history = ResultsHistory::new(&results_dir);
+    let entries = history.load()?;
+    let series = trends(&entries, model.as_deref(), metric.as_deref());
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&series)?);
+        return Ok(());
+    }
+    if series.is_empty() {
+        println!("No recorded runs match in {}", history.path().display());
+        return Ok(());
+    }
+
+    let format = |metric: &str, value: Option<f64>| match value {
+        Some(v) if metric.ends_with("_usd") => format!("${:.4}", v),
+        Some(v) => format!("{:.1}", v),
+        None => "-".to_string(),
+    };
+    let format_change = |metric: &str, value: Option<f64>| match value {
+        Some(v) if metric.ends_with("_usd") => format!("{:+.4}", v),
+        Some(v) => format!("{:+.1}", v),
+        None => "-".to_string(),
+    };
+
+    let mut current_model = None;
+    for trend in &series {
+        if current_model != Some(&trend.model) {
+            println!("\n=== Trends: {} ===", trend.model);
+            current_model = Some(&trend.model);
+        }
+        println!("\n{}", trend.metric);
+        println!(
+            "  {:<28} {:<20} {:<20} {:>10} {:>10} {:>10}",
+            "Run", "Corpus", "Skill", "Baseline", "AICMS", "Delta"
+        );
+        for point in &trend.points {
+            println!(
+                "  {:<28} {:<20} {:<20} {:>10} {:>10} {:>10}",
+                point.run,
+                short_version(point.corpus_version.as_deref()),
+                short_version(point.skill_version.as_deref()),
+                format(&trend.metric, point.baseline),
+                format(&trend.metric, point.aicms),
+                format_change(&trend.metric, point.delta())
+            );
+        }
+        if trend.points.len() > 1 {
+            println!(
+                "  Change since first run: baseline {}, AICMS {}",
+                format_change(&trend.metric, trend.change("baseline")),
+                format_change(&trend.metric, trend.change("aicms"))
+            );
+        }
+    }
+
+    Ok(())
+}
+
 /// @ai:intent Initialize default configuration file
 /// @ai:effects fs:write
 fn init_config(output: PathBuf) -> Result<()> {
@@ -859,6 +2404,54 @@ fn init_config(output: PathBuf) -> Result<()> {
     Ok(())
 }
 
+/// @ai:intent Apply a retention policy to the results directory and report reclaimed space
+/// @ai:effects fs:read, fs:write
+fn clean_results(
+    results_dir: Option<PathBuf>,
+    config_path: Option<PathBuf>,
+    policy: RetentionPolicy,
+    dry_run: bool,
+) -> Result<()> {
+    if policy.is_empty() {
+        anyhow::bail!("Nothing to clean: pass --keep-last, --older-than-days, and/or --artifacts");
+    }
+
+    let results_dir = match results_dir {
+        Some(dir) => dir,
+        None => load_or_default_config(config_path)?.paths.results_dir,
+    };
+
+    let cleaner = ResultsCleaner::new();
+    let plan = cleaner.plan(&results_dir, &policy, chrono::Utc::now())?;
+
+    for (path, size) in &plan.runs {
+        println!("  run       {} ({})", path.display(), format_bytes(*size));
+    }
+    for (path, size) in &plan.artifacts {
+        println!("  artifacts {} ({})", path.display(), format_bytes(*size));
+    }
+
+    if dry_run {
+        println!(
+            "\nDry run: would remove {} runs and {} artifact directories, reclaiming {}",
+            plan.runs.len(),
+            plan.artifacts.len(),
+            format_bytes(plan.total_bytes())
+        );
+        return Ok(());
+    }
+
+    let report = cleaner.apply(&plan);
+    println!(
+        "\nRemoved {} runs and {} artifact directories, reclaimed {}",
+        report.runs_removed,
+        report.artifacts_removed,
+        format_bytes(report.bytes_reclaimed)
+    );
+
+    Ok(())
+}
+
 /// @ai:intent Load configuration or use defaults
 /// @ai:effects fs:read
 fn load_or_default_config(path: Option<PathBuf>) -> Result<BenchmarkConfig> {
@@ -876,7 +2469,11 @@ fn load_or_default_config(path: Option<PathBuf>) -> Result<BenchmarkConfig> {
                         prompts_dir: PathBuf::from("prompts"),
                         results_dir: PathBuf::from("results"),
                         skill_file: PathBuf::from("../skills/aicms/SKILL.md"),
+                        skill_variants: vec![],
                         comparison_prompt_file: PathBuf::from("prompts/comparison.md"),
+                        comparison_rubric_file: PathBuf::from("prompts/rubric.toml"),
+                        ranking_prompt_file: PathBuf::from("prompts/ranking.md"),
+                        claude_md_template: PathBuf::from("prompts/claude_md.md"),
                     },
                     ..Default::default()
                 })
@@ -900,7 +2497,8 @@ fn build_filter(
     }
 }
 
-/// @ai:intent Print summary to console
+/// @ai:intent Print the summary of one model's results to console, with the Claude
+///            comparison if there was one
 /// @ai:effects io
 fn print_summary(results: &aicms_bench::BenchmarkResults) {
     println!();
@@ -908,8 +2506,14 @@ fn print_summary(results: &aicms_bench::BenchmarkResults) {
     println!("=======================");
     println!();
 
+    if let Some(reason) = &results.truncated {
+        println!("TRUNCATED: {}", reason);
+        println!();
+    }
+
     // Check for extraction failures
-    let extraction_warnings = check_extraction_failures(&results.task_metrics);
+    let extraction_warnings =
+        check_extraction_failures(&results.task_metrics, results.missing_data.policy);
     if !extraction_warnings.is_empty() {
         println!("Warnings:");
         for warning in &extraction_warnings {
@@ -923,38 +2527,422 @@ fn print_summary(results: &aicms_bench::BenchmarkResults) {
         "", "Baseline", "AICMS", "Delta"
     );
     println!("{}", "-".repeat(60));
-    println!(
-        "{:<25} {:>9.1}% {:>9.1}% {:>+9.1}%",
-        "Compilation rate:",
-        results.overall.baseline.compilation_rate,
-        results.overall.aicms.compilation_rate,
-        results.overall.delta.compilation_rate
-    );
-    println!(
-        "{:<25} {:>9.1}% {:>9.1}% {:>+9.1}%",
-        "Test pass rate:",
-        results.overall.baseline.avg_test_pass_rate,
-        results.overall.aicms.avg_test_pass_rate,
-        results.overall.delta.test_pass_rate
-    );
-    println!(
-        "{:<25} {:>9.1}% {:>9.1}% {:>+9.1}%",
-        "Lint compliance:",
-        results.overall.baseline.avg_lint_compliance,
-        results.overall.aicms.avg_lint_compliance,
-        results.overall.delta.lint_compliance
-    );
+
+    let (baseline, aicms) = (&results.overall.baseline, &results.overall.aicms);
+    print_rate_row("Compilation rate:", baseline, aicms, |s| s.compilation_rate);
+    print_rate_row("Test pass rate:", baseline, aicms, |s| s.avg_test_pass_rate);
+    print_rate_row("Lint compliance:", baseline, aicms, |s| s.avg_lint_compliance);
+    if baseline.lint_checked_count > 0 || aicms.lint_checked_count > 0 {
+        print_rate_row("Lint passed:", baseline, aicms, |s| s.lint_pass_rate);
+    }
+    if let Some(composite) = &results.composite {
+        let score = |value: Option<f64>| value.map_or("-".to_string(), |v| format!("{:.1}", v));
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Composite score:",
+            score(composite.baseline),
+            score(composite.aicms),
+            composite
+                .delta()
+                .map_or("n/a".to_string(), |d| format!("{:+.1}", d))
+        );
+        println!("  = {}", composite.label());
+    }
+    if baseline.hidden_tested_count > 0 || aicms.hidden_tested_count > 0 {
+        print_rate_row("Hidden test pass rate:", baseline, aicms, |s| {
+            s.avg_hidden_test_pass_rate
+        });
+    }
+    if baseline.io_tested_count > 0 || aicms.io_tested_count > 0 {
+        print_rate_row("I/O cases passed:", baseline, aicms, |s| s.avg_io_pass_rate);
+    }
+    if baseline.api_checked_count > 0 || aicms.api_checked_count > 0 {
+        print_rate_row("API conformance:", baseline, aicms, |s| {
+            s.avg_api_conformance
+        });
+    }
+    if baseline.reference_compared_count > 0 || aicms.reference_compared_count > 0 {
+        print_rate_row("Reference alignment:", baseline, aicms, |s| {
+            s.avg_reference_alignment
+        });
+    }
+    if baseline.inference_scored_count > 0 || aicms.inference_scored_count > 0 {
+        print_rate_row("Inference F1:", baseline, aicms, |s| s.avg_inference_f1);
+    }
+    if baseline.quality_checked_count > 0 || aicms.quality_checked_count > 0 {
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Lint warnings/100 lines:",
+            format!("{:.2}", baseline.avg_quality_density),
+            format!("{:.2}", aicms.avg_quality_density),
+            format!("{:+.2}", aicms.avg_quality_density - baseline.avg_quality_density)
+        );
+    }
+    if baseline.security_scanned_count > 0 || aicms.security_scanned_count > 0 {
+        print_rate_row("Security clean:", baseline, aicms, |s| {
+            s.security_clean_rate
+        });
+    }
+    if baseline.test_design_count > 0 || aicms.test_design_count > 0 {
+        print_rate_row("Property-based tests:", baseline, aicms, |s| {
+            s.property_based_rate
+        });
+    }
+    if baseline.edge_case_checked_count > 0 || aicms.edge_case_checked_count > 0 {
+        print_rate_row("Edge cases tested:", baseline, aicms, |s| s.avg_edge_case_coverage);
+    }
+    if baseline.mutation_tested_count > 0 || aicms.mutation_tested_count > 0 {
+        print_rate_row("Mutation score:", baseline, aicms, |s| s.avg_mutation_score);
+    }
+    if baseline.determinism_checked_count > 0 || aicms.determinism_checked_count > 0 {
+        print_rate_row("Flaky tests:", baseline, aicms, |s| s.flaky_test_rate);
+    }
+    if baseline.density_measured_count > 0 || aicms.density_measured_count > 0 {
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Annotations/function:",
+            format!("{:.2}", baseline.avg_annotations_per_function),
+            format!("{:.2}", aicms.avg_annotations_per_function),
+            format!(
+                "{:+.2}",
+                aicms.avg_annotations_per_function - baseline.avg_annotations_per_function
+            )
+        );
+    }
+    if baseline.sized_count > 0 || aicms.sized_count > 0 {
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Lines of code:",
+            format!("{:.0}", baseline.avg_lines_of_code),
+            format!("{:.0}", aicms.avg_lines_of_code),
+            format!("{:+.0}", aicms.avg_lines_of_code - baseline.avg_lines_of_code)
+        );
+    }
+    if baseline.complexity_measured_count > 0 || aicms.complexity_measured_count > 0 {
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Cyclomatic complexity:",
+            format!("{:.2}", baseline.avg_cyclomatic_complexity),
+            format!("{:.2}", aicms.avg_cyclomatic_complexity),
+            format!(
+                "{:+.2}",
+                aicms.avg_cyclomatic_complexity - baseline.avg_cyclomatic_complexity
+            )
+        );
+    }
+    if baseline.contracts_checked > 0 || aicms.contracts_checked > 0 {
+        print_rate_row("Contract violations:", baseline, aicms, |s| {
+            s.contract_violation_rate
+        });
+    }
+    if baseline.priced_count > 0 || aicms.priced_count > 0 {
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Cost (USD):",
+            format!("${:.2}", baseline.total_cost_usd),
+            format!("${:.2}", aicms.total_cost_usd),
+            format!("{:+.2}", aicms.total_cost_usd - baseline.total_cost_usd)
+        );
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Cost per execution:",
+            format!("${:.4}", baseline.avg_cost_usd()),
+            format!("${:.4}", aicms.avg_cost_usd()),
+            format!("{:+.4}", aicms.avg_cost_usd() - baseline.avg_cost_usd())
+        );
+        let cost = |c: Option<f64>| c.map_or("-".to_string(), |c| format!("${:.4}", c));
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Cost per pass:",
+            cost(baseline.cost_per_pass_usd),
+            cost(aicms.cost_per_pass_usd),
+            baseline
+                .cost_per_pass_usd
+                .zip(aicms.cost_per_pass_usd)
+                .map_or("-".to_string(), |(b, a)| format!("{:+.4}", a - b))
+        );
+        println!();
+        println!(
+            "Total run cost: ${:.2}",
+            baseline.total_cost_usd + aicms.total_cost_usd
+        );
+    }
     println!();
 
+    if baseline.compilation_rate_ci.is_some() || aicms.compilation_rate_ci.is_some() {
+        let cell = |ci: Option<ConfidenceInterval>| ci.map_or("-".to_string(), |ci| ci.format());
+        println!("{:<25} {:>10} {:>10}", "95% CI:", "Baseline", "AICMS");
+        println!(
+            "{:<25} {:>10} {:>10}",
+            "Compilation rate:",
+            cell(baseline.compilation_rate_ci),
+            cell(aicms.compilation_rate_ci)
+        );
+        println!(
+            "{:<25} {:>10} {:>10}",
+            "Test pass rate:",
+            cell(baseline.test_pass_rate_ci),
+            cell(aicms.test_pass_rate_ci)
+        );
+        println!();
+    }
+
+    if !baseline.repetition_spread.is_empty() || !aicms.repetition_spread.is_empty() {
+        let cell = |stats: &AggregateStats, metric: &str| {
+            stats
+                .repetition_spread
+                .iter()
+                .find(|s| s.metric == metric)
+                .map_or("-".to_string(), |s| format!("{:.1}", s.std_dev))
+        };
+        // Both modes spread the same metrics, so either one names the rows
+        let rows = if baseline.repetition_spread.is_empty() {
+            &aicms.repetition_spread
+        } else {
+            &baseline.repetition_spread
+        };
+        println!(
+            "{:<25} {:>10} {:>10}",
+            "Repetition std dev:", "Baseline", "AICMS"
+        );
+        for spread in rows {
+            println!(
+                "{:<25} {:>10} {:>10}",
+                format!("{}:", spread.label()),
+                cell(baseline, &spread.metric),
+                cell(aicms, &spread.metric)
+            );
+        }
+        println!();
+    }
+
+    if baseline.reproducibility.is_some() || aicms.reproducibility.is_some() {
+        let cell = |stats: &AggregateStats, value: fn(&Reproducibility) -> f64| {
+            stats
+                .reproducibility
+                .as_ref()
+                .map_or("-".to_string(), |r| format!("{:.1}", value(r)))
+        };
+        println!(
+            "{:<25} {:>10} {:>10}",
+            "Reproducibility:", "Baseline", "AICMS"
+        );
+        println!(
+            "{:<25} {:>10} {:>10}",
+            "Consistent compilation:",
+            cell(baseline, |r| r.consistent_compilation_rate),
+            cell(aicms, |r| r.consistent_compilation_rate)
+        );
+        println!(
+            "{:<25} {:>10} {:>10}",
+            "Pass rate range:",
+            cell(baseline, |r| r.avg_pass_rate_range),
+            cell(aicms, |r| r.avg_pass_rate_range)
+        );
+        println!(
+            "{:<25} {:>10} {:>10}",
+            "Stability score:",
+            cell(baseline, |r| r.score),
+            cell(aicms, |r| r.score)
+        );
+        println!();
+    }
+
+    let delta = results.overall.delta.as_ref();
+    if let Some(delta) = delta.filter(|d| !d.effect_sizes.is_empty()) {
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Effect size:", "Cohen's d", "Cliff's δ", "Magnitude"
+        );
+        for effect in &delta.effect_sizes {
+            println!(
+                "{:<25} {:>10} {:>10} {:>10}",
+                format!("{}:", effect.label()),
+                EffectSize::format(effect.cohens_d),
+                EffectSize::format(effect.cliffs_delta),
+                effect.magnitude()
+            );
+        }
+        println!();
+    }
+
+    if !baseline.custom.is_empty() || !aicms.custom.is_empty() {
+        let cell = |stats: &AggregateStats, name: &String| {
+            stats
+                .custom
+                .get(name)
+                .map_or("-".to_string(), |v| format!("{:.2}", v))
+        };
+        let mut names: Vec<&String> = baseline.custom.keys().chain(aicms.custom.keys()).collect();
+        names.sort();
+        names.dedup();
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Custom metrics:", "Baseline", "AICMS", "Delta"
+        );
+        for name in names {
+            println!(
+                "{:<25} {:>10} {:>10} {:>10}",
+                format!("{}:", name),
+                cell(baseline, name),
+                cell(aicms, name),
+                delta
+                    .and_then(|d| d.custom.get(name))
+                    .map_or("n/a".to_string(), |d| format!("{:+.2}", d))
+            );
+        }
+        println!();
+    }
+
+    if !baseline.failures.is_empty() || !aicms.failures.is_empty() {
+        let cell = |stats: &AggregateStats, reason: &FailureReason| {
+            stats.failures.get(reason).copied().unwrap_or(0)
+        };
+        let mut reasons: Vec<&FailureReason> = baseline
+            .failures
+            .keys()
+            .chain(aicms.failures.keys())
+            .collect();
+        reasons.sort();
+        reasons.dedup();
+        println!("{:<25} {:>10} {:>10}", "Failures:", "Baseline", "AICMS");
+        for reason in reasons {
+            println!(
+                "{:<25} {:>10} {:>10}",
+                format!("{}:", reason.as_str()),
+                cell(baseline, reason),
+                cell(aicms, reason)
+            );
+        }
+        println!();
+    }
+
+    if !results.flaky_tasks.is_empty() {
+        println!(
+            "Flaky tasks (outcome flipped between repetitions): {}",
+            results.flaky_tasks.len()
+        );
+        for task in &results.flaky_tasks {
+            println!(
+                "  {} ({}): compiled {}/{}, passed {}/{}",
+                task.task_id,
+                task.mode,
+                task.compiled,
+                task.repetitions,
+                task.passed,
+                task.repetitions
+            );
+        }
+        println!();
+    }
+
+    if let Some(normalized) = &results.difficulty_normalized {
+        let rate = |value: Option<f64>| value.map_or("-".to_string(), |v| format!("{:.1}%", v));
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Difficulty-normalized:", "Baseline", "AICMS", "Delta"
+        );
+        for metric in &normalized.metrics {
+            println!(
+                "{:<25} {:>10} {:>10} {:>10}",
+                format!("{}:", metric.label()),
+                rate(metric.baseline),
+                rate(metric.aicms),
+                metric
+                    .delta()
+                    .map_or("n/a".to_string(), |d| format!("{:+.1}%", d))
+            );
+        }
+        println!();
+    }
+
+    if let Some(outliers) = &results.outliers {
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Without outliers:", "Baseline", "AICMS", "Delta"
+        );
+        let (baseline, aicms) = (&outliers.without.baseline, &outliers.without.aicms);
+        print_rate_row("Compilation rate:", baseline, aicms, |s| s.compilation_rate);
+        print_rate_row("Test pass rate:", baseline, aicms, |s| s.avg_test_pass_rate);
+        print_rate_row("Lint compliance:", baseline, aicms, |s| {
+            s.avg_lint_compliance
+        });
+        for task in &outliers.tasks {
+            println!("  excluded {}: {}", task.task_id, task.reason.as_str());
+        }
+        println!();
+    }
+
+    if !results.significance.is_empty() {
+        println!(
+            "{:<25} {:>10} {:>10} {:>10}",
+            "Significance:", "Tasks", "t-test p", "Wilcoxon p"
+        );
+        for test in &results.significance {
+            println!(
+                "{:<25} {:>10} {:>10} {:>10}",
+                format!("{}:", test.label()),
+                test.pairs,
+                PairedTest::format_p(test.t_test_p),
+                PairedTest::format_p(test.wilcoxon_p)
+            );
+        }
+        println!();
+    }
+
     // Show lint issues if any
     print_lint_issues(&results.task_metrics);
+
+    if let Some(ref stats) = results.claude_stats {
+        print_claude_summary(stats, &results.claude_comparisons);
+    }
 }
 
-/// @ai:intent Check for extraction failures and return warnings
+/// @ai:intent Print one rate per mode and their delta
+///            A mode without executions (see --modes) shows "-" and no delta
+/// @ai:effects io
+fn print_rate_row(
+    label: &str,
+    baseline: &AggregateStats,
+    aicms: &AggregateStats,
+    rate: impl Fn(&AggregateStats) -> f64,
+) {
+    let cell = |stats: &AggregateStats| {
+        if stats.task_count == 0 {
+            "-".to_string()
+        } else {
+            format!("{:.1}%", rate(stats))
+        }
+    };
+    let delta = if baseline.task_count > 0 && aicms.task_count > 0 {
+        format!("{:+.1}%", rate(aicms) - rate(baseline))
+    } else {
+        "n/a".to_string()
+    };
+
+    println!(
+        "{:<25} {:>10} {:>10} {:>10}",
+        label,
+        cell(baseline),
+        cell(aicms),
+        delta
+    );
+}
+
+/// @ai:intent Check for extraction failures and return warnings saying how the missing-data
+///            policy counted them
 /// @ai:effects pure
-fn check_extraction_failures(metrics: &[aicms_bench::metrics::TaskMetrics]) -> Vec<String> {
+fn check_extraction_failures(
+    metrics: &[aicms_bench::metrics::TaskMetrics],
+    policy: MissingDataPolicy,
+) -> Vec<String> {
     use std::collections::HashSet;
 
+    let effect = match policy {
+        MissingDataPolicy::Zero => "metrics show 0%",
+        MissingDataPolicy::Exclude => "left out of the metrics",
+        MissingDataPolicy::Impute => "imputed from its other repetitions, if any",
+    };
     let mut warnings = Vec::new();
     let mut tasks_missing_baseline = HashSet::new();
     let mut tasks_missing_aicms = HashSet::new();
@@ -971,15 +2959,15 @@ fn check_extraction_failures(metrics: &[aicms_bench::metrics::TaskMetrics]) -> V
 
     for task_id in &tasks_missing_baseline {
         warnings.push(format!(
-            "Code extraction failed for {} (baseline) - metrics show 0%",
-            task_id
+            "Code extraction failed for {} (baseline) - {}",
+            task_id, effect
         ));
     }
 
     for task_id in &tasks_missing_aicms {
         warnings.push(format!(
-            "Code extraction failed for {} (aicms) - metrics show 0%",
-            task_id
+            "Code extraction failed for {} (aicms) - {}",
+            task_id, effect
         ));
     }
 
@@ -3,16 +3,41 @@
 //! @ai:module:public_api MetricsAggregator
 //! @ai:module:stateless true
 
+use crate::config::{CustomAggregation, MissingDataPolicy, StatisticsConfig, TieHandling};
 use crate::corpus::Task;
+use crate::evaluator::TaskRanking;
 use crate::metrics::types::{
-    AggregateStats, BenchmarkResults, CategoryStats, ClaudeComparisonStats, DeltaStats,
-    DifficultyStats, LanguageStats, ModeComparison, TaskComparison, TaskMetrics,
+    AggregateStats, BenchmarkResults, CategoryStats, ClaudeComparisonStats, ComparisonGroupStats,
+    CompositeScore, ConfidenceInterval, DeltaStats, DifficultyNormalized, DifficultyStats,
+    EffectSize, FailureReason, FlakyTask, JudgeAgreement, LanguageStats, MissingData,
+    ModeComparison, ModelStats, OutlierStats, OutlierTask, PairedTest, Percentiles, RankingStats,
+    RepairTurnStats, RepetitionSpread, Reproducibility, SamplingStats, SkillStats, TagStats,
+    TaskComparison, TaskHeadToHead, TaskMetrics, TaskOutcome,
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// @ai:intent A headline metric: its DeltaStats field name and its value for one execution
+type HeadlineMetric = (&'static str, fn(&TaskMetrics) -> f64);
+
+/// @ai:intent The metrics compared in the overall results table
+const HEADLINE_METRICS: [HeadlineMetric; 4] = [
+    ("compilation_rate", |m| if m.compiled { 100.0 } else { 0.0 }),
+    ("test_pass_rate", |m| m.test_pass_rate),
+    ("lint_compliance", |m| m.lint_compliance),
+    ("annotation_quality", |m| m.annotation_quality),
+];
+
+/// @ai:intent Executions of one task in baseline and AICMS mode
+type ModeMetrics<'a> = (Vec<&'a TaskMetrics>, Vec<&'a TaskMetrics>);
+
+/// @ai:intent A task of one model, sampling setting and skill variant: (model, sampling,
+///            skill, task id)
+type TaskKey<'a> = (&'a str, Option<&'a str>, Option<&'a str>, &'a str);
 
 /// @ai:intent Trait for metrics aggregation
 pub trait MetricsAggregatorTrait: Send + Sync {
     /// @ai:intent Aggregate task metrics into benchmark results
+    ///            Metrics without a model are attributed to `model`
     fn aggregate(
         &self,
         metrics: &[TaskMetrics],
@@ -23,13 +48,78 @@ pub trait MetricsAggregatorTrait: Send + Sync {
 }
 
 /// @ai:intent Aggregates task metrics into statistical summaries
-pub struct MetricsAggregator;
+pub struct MetricsAggregator {
+    bootstrap_resamples: u32,
+    ties: TieHandling,
+    /// Aggregation rule of each custom metric (others are averaged)
+    custom_metrics: BTreeMap<String, CustomAggregation>,
+    /// Weights of the composite score's components
+    composite_weights: BTreeMap<String, f64>,
+    /// Weights of the difficulties in the difficulty-normalized rates
+    difficulty_weights: BTreeMap<String, f64>,
+    /// How executions without extracted code count
+    missing_data: MissingDataPolicy,
+}
 
 impl MetricsAggregator {
-    /// @ai:intent Create a new metrics aggregator
+    /// @ai:intent Create a new metrics aggregator with the default [statistics] settings
     /// @ai:effects pure
     pub fn new() -> Self {
-        Self
+        Self::from_config(&StatisticsConfig::default())
+    }
+
+    /// @ai:intent Create a metrics aggregator from the [statistics] settings
+    /// @ai:effects pure
+    pub fn from_config(config: &StatisticsConfig) -> Self {
+        Self {
+            bootstrap_resamples: config.bootstrap_resamples,
+            ties: config.ties,
+            custom_metrics: config.custom_metrics.clone(),
+            composite_weights: config.composite.clone(),
+            difficulty_weights: config.difficulty_weights.clone(),
+            missing_data: config.missing_data,
+        }
+    }
+
+    /// @ai:intent Declare how a custom metric combines over executions, overriding
+    ///            [statistics] custom_metrics
+    /// @ai:effects pure
+    pub fn with_custom_metric(mut self, name: impl Into<String>, rule: CustomAggregation) -> Self {
+        self.custom_metrics.insert(name.into(), rule);
+        self
+    }
+
+    /// @ai:intent Combine the custom metrics of the executions into stats, each by its rule
+    ///            over the executions that recorded it
+    /// @ai:effects pure
+    fn add_custom(&self, stats: &mut AggregateStats, metrics: &[&TaskMetrics]) {
+        let mut values: BTreeMap<&str, Vec<f64>> = BTreeMap::new();
+        for m in metrics {
+            for (name, value) in &m.custom {
+                values.entry(name).or_default().push(*value);
+            }
+        }
+        stats.custom = values
+            .into_iter()
+            .filter_map(|(name, values)| {
+                let rule = self.custom_metrics.get(name).copied().unwrap_or_default();
+                Some((name.to_string(), rule.apply(&values)?))
+            })
+            .collect();
+    }
+
+    /// @ai:intent Add bootstrap intervals of the compilation and test pass rates to stats
+    /// @ai:effects pure
+    fn add_intervals(&self, stats: &mut AggregateStats, metrics: &[&TaskMetrics]) {
+        let compiled: Vec<f64> = metrics
+            .iter()
+            .map(|m| if m.compiled { 100.0 } else { 0.0 })
+            .collect();
+        let pass_rates: Vec<f64> = metrics.iter().map(|m| m.test_pass_rate).collect();
+        stats.compilation_rate_ci =
+            ConfidenceInterval::of_mean(&compiled, self.bootstrap_resamples);
+        stats.test_pass_rate_ci =
+            ConfidenceInterval::of_mean(&pass_rates, self.bootstrap_resamples);
     }
 
     /// @ai:intent Calculate aggregate stats for a set of metrics
@@ -44,25 +134,265 @@ impl MetricsAggregator {
 
         let compilation_rate = (compiled_count as f64 / task_count as f64) * 100.0;
         let avg_test_pass_rate = average(metrics.iter().map(|m| m.test_pass_rate));
+        let hidden_rates: Vec<f64> = metrics.iter().filter_map(|m| m.hidden_test_pass_rate).collect();
+        let hidden_tested_count = hidden_rates.len() as u32;
+        let avg_hidden_test_pass_rate = average(hidden_rates.into_iter());
+        let io_rates: Vec<f64> = metrics.iter().filter_map(|m| m.io_pass_rate).collect();
+        let io_tested_count = io_rates.len() as u32;
+        let avg_io_pass_rate = average(io_rates.into_iter());
+        let api_scores: Vec<f64> = metrics.iter().filter_map(|m| m.api_conformance.as_ref()).map(|a| a.score()).collect();
+        let api_checked_count = api_scores.len() as u32;
+        let avg_api_conformance = average(api_scores.into_iter());
+        let references: Vec<_> = metrics.iter().filter_map(|m| m.reference.as_ref()).collect();
+        let reference_compared_count = references.len() as u32;
+        let avg_reference_api_match = average(references.iter().map(|r| r.api_match));
+        let avg_reference_alignment = average(references.iter().map(|r| r.score));
+        let inferences: Vec<_> = metrics.iter().filter_map(|m| m.inference.as_ref()).collect();
+        let inference_scored_count = inferences.len() as u32;
+        let avg_inference_precision = average(inferences.iter().map(|i| i.precision));
+        let avg_inference_recall = average(inferences.iter().map(|i| i.recall));
+        let avg_inference_f1 = average(inferences.iter().map(|i| i.f1));
+        let qualities: Vec<_> = metrics.iter().filter_map(|m| m.code_quality.as_ref()).collect();
+        let quality_checked_count = qualities.len() as u32;
+        let avg_quality_warnings = average(qualities.iter().map(|q| q.warnings as f64));
+        let avg_quality_density = average(qualities.iter().map(|q| q.density()));
+        let type_checks: Vec<_> = metrics
+            .iter()
+            .filter_map(|m| m.type_check.as_ref())
+            .collect();
+        let type_checked_count = type_checks.len() as u32;
+        let type_correct_rate =
+            average(type_checks.iter().map(|t| if t.is_correct() { 100.0 } else { 0.0 }));
+        let avg_type_errors = average(type_checks.iter().map(|t| t.errors as f64));
+        let scans: Vec<_> = metrics.iter().filter_map(|m| m.security.as_ref()).collect();
+        let security_scanned_count = scans.len() as u32;
+        let security_clean_rate =
+            average(scans.iter().map(|s| if s.is_clean() { 100.0 } else { 0.0 }));
+        let avg_dangerous_patterns = average(scans.iter().map(|s| s.dangerous_patterns as f64));
+        let audited: Vec<_> = scans.iter().filter(|s| s.audit_tool.is_some()).collect();
+        let audited_count = audited.len() as u32;
+        let avg_vulnerable_dependencies =
+            average(audited.iter().map(|s| s.vulnerable_dependencies as f64));
+        let complexities: Vec<_> = metrics.iter().filter_map(|m| m.complexity.as_ref()).collect();
+        let complexity_measured_count = complexities.len() as u32;
+        let avg_cyclomatic_complexity = average(complexities.iter().map(|c| c.avg_cyclomatic));
+        let avg_nesting_depth = average(complexities.iter().map(|c| c.avg_nesting_depth));
+        let avg_maintainability = average(complexities.iter().map(|c| c.avg_maintainability));
+        let sizes: Vec<_> = metrics.iter().filter_map(|m| m.code_size.as_ref()).collect();
+        let sized_count = sizes.len() as u32;
+        let avg_files = average(sizes.iter().map(|s| s.files as f64));
+        let avg_lines_of_code = average(sizes.iter().map(|s| s.lines as f64));
+        let avg_test_lines = average(sizes.iter().map(|s| s.test_lines as f64));
+        let densities: Vec<_> = metrics
+            .iter()
+            .filter_map(|m| m.annotation_density.as_ref())
+            .collect();
+        let density_measured_count = densities.len() as u32;
+        let avg_annotations_per_function = average(densities.iter().map(|d| d.per_function()));
+        let avg_annotated_function_rate = average(densities.iter().map(|d| d.annotated_rate()));
+        let mutated: Vec<_> = metrics
+            .iter()
+            .filter_map(|m| m.mutation.as_ref().map(|s| (m.test_pass_rate, s.score())))
+            .collect();
+        let mutation_tested_count = mutated.len() as u32;
+        let avg_mutation_score = average(mutated.iter().map(|(_, score)| *score));
+        let avg_mutation_weighted_pass_rate =
+            average(mutated.iter().map(|(pass_rate, score)| pass_rate * score / 100.0));
+        let determinisms: Vec<_> = metrics.iter().filter_map(|m| m.test_determinism.as_ref()).collect();
+        let determinism_checked_count = determinisms.len() as u32;
+        let flaky_test_rate =
+            average(determinisms.iter().map(|d| if d.flaky { 100.0 } else { 0.0 }));
+        let designs: Vec<_> = metrics.iter().filter_map(|m| m.test_design.as_ref()).collect();
+        let test_design_count = designs.len() as u32;
+        let property_based_rate = average(
+            designs
+                .iter()
+                .map(|d| if d.property_framework.is_some() { 100.0 } else { 0.0 }),
+        );
+        let coverages: Vec<f64> = designs.iter().filter_map(|d| d.edge_case_coverage()).collect();
+        let edge_case_checked_count = coverages.len() as u32;
+        let avg_edge_case_coverage = average(coverages.into_iter());
         let avg_lint_compliance = average(metrics.iter().map(|m| m.lint_compliance));
+        let lint_checks: Vec<bool> = metrics.iter().filter_map(|m| m.lint_passed).collect();
+        let lint_checked_count = lint_checks.len() as u32;
+        let lint_pass_rate = average(
+            lint_checks
+                .iter()
+                .map(|&passed| if passed { 100.0 } else { 0.0 }),
+        );
         let avg_annotation_quality = average(metrics.iter().map(|m| m.annotation_quality));
+        let avg_spec_overlap = average(metrics.iter().map(|m| m.spec_overlap));
+        let spec_echo_count = metrics.iter().filter(|m| m.spec_echo).count() as u32;
+        let checked: Vec<_> = metrics.iter().filter_map(|m| m.contamination.as_ref()).collect();
+        let contamination_checked_count = checked.len() as u32;
+        let contamination_count = checked.iter().filter(|c| c.flagged).count() as u32;
+
+        let contracts_checked: u32 = metrics.iter().map(|m| m.contracts_checked).sum();
+        let contracts_violated: u32 = metrics.iter().map(|m| m.contracts_violated).sum();
+        let contract_violation_rate = if contracts_checked == 0 {
+            0.0
+        } else {
+            (contracts_violated as f64 / contracts_checked as f64) * 100.0
+        };
 
         let total_input_tokens: u64 = metrics.iter().map(|m| m.input_tokens as u64).sum();
         let total_output_tokens: u64 = metrics.iter().map(|m| m.output_tokens as u64).sum();
         let avg_execution_time_ms = average(metrics.iter().map(|m| m.execution_time_ms as f64));
+        let test_pass_rate_percentiles =
+            Percentiles::from_values(metrics.iter().map(|m| m.test_pass_rate));
+        let execution_time_percentiles =
+            Percentiles::from_values(metrics.iter().map(|m| m.execution_time_ms as f64));
+        let token_percentiles = Percentiles::from_values(
+            metrics
+                .iter()
+                .map(|m| m.input_tokens as f64 + m.output_tokens as f64),
+        );
+
+        let total_cache_creation_tokens: u64 = metrics
+            .iter()
+            .map(|m| m.cache_creation_input_tokens as u64)
+            .sum();
+        let total_cache_read_tokens: u64 =
+            metrics.iter().map(|m| m.cache_read_input_tokens as u64).sum();
+        let cache_saved_tokens = cache_savings(total_cache_creation_tokens, total_cache_read_tokens);
+
+        let priced_count = metrics.iter().filter(|m| m.cost_usd.is_some()).count() as u32;
+        let total_cost_usd = metrics.iter().filter_map(|m| m.cost_usd).sum();
+        // An execution passing half of its tests counts as half a pass
+        let priced_passes: f64 = metrics
+            .iter()
+            .filter(|m| m.cost_usd.is_some())
+            .map(|m| m.test_pass_rate / 100.0)
+            .sum();
+        let cost_per_pass_usd = (priced_passes > 0.0).then(|| total_cost_usd / priced_passes);
+        let repeated = repeated_tasks(metrics);
 
         AggregateStats {
             task_count,
             compilation_rate,
             avg_test_pass_rate,
+            // Only the overall results get intervals (see add_intervals)
+            compilation_rate_ci: None,
+            test_pass_rate_ci: None,
+            repetition_spread: repetition_spread(&repeated),
+            reproducibility: Reproducibility::from_repetitions(&repeated),
+            hidden_tested_count,
+            avg_hidden_test_pass_rate,
+            io_tested_count,
+            avg_io_pass_rate,
+            api_checked_count,
+            avg_api_conformance,
+            reference_compared_count,
+            avg_reference_api_match,
+            avg_reference_alignment,
+            inference_scored_count,
+            avg_inference_precision,
+            avg_inference_recall,
+            avg_inference_f1,
+            quality_checked_count,
+            avg_quality_warnings,
+            avg_quality_density,
+            type_checked_count,
+            type_correct_rate,
+            avg_type_errors,
+            security_scanned_count,
+            security_clean_rate,
+            avg_dangerous_patterns,
+            audited_count,
+            avg_vulnerable_dependencies,
+            complexity_measured_count,
+            avg_cyclomatic_complexity,
+            avg_nesting_depth,
+            avg_maintainability,
+            sized_count,
+            avg_files,
+            avg_lines_of_code,
+            avg_test_lines,
+            density_measured_count,
+            avg_annotations_per_function,
+            avg_annotated_function_rate,
+            mutation_tested_count,
+            avg_mutation_score,
+            avg_mutation_weighted_pass_rate,
+            determinism_checked_count,
+            flaky_test_rate,
+            test_design_count,
+            property_based_rate,
+            edge_case_checked_count,
+            avg_edge_case_coverage,
             avg_lint_compliance,
+            lint_checked_count,
+            lint_pass_rate,
             avg_annotation_quality,
+            avg_spec_overlap,
+            spec_echo_count,
+            contamination_checked_count,
+            contamination_count,
+            contracts_checked,
+            contracts_violated,
+            contract_violation_rate,
             total_input_tokens,
             total_output_tokens,
             avg_execution_time_ms,
+            test_pass_rate_percentiles,
+            execution_time_percentiles,
+            token_percentiles,
+            total_cache_creation_tokens,
+            total_cache_read_tokens,
+            cache_saved_tokens,
+            total_cost_usd,
+            priced_count,
+            cost_per_pass_usd,
+            // Only the overall results get custom metrics (see add_custom)
+            custom: BTreeMap::new(),
+            // Only the overall results get failure counts (see compare_modes)
+            failures: BTreeMap::new(),
         }
     }
 
+    /// @ai:intent Overall stats of each mode, with intervals, custom metrics and failure
+    ///            counts, and their delta
+    /// @ai:effects pure
+    fn compare_modes(&self, metrics: &[TaskMetrics]) -> ModeComparison {
+        let (baseline, aicms) = Self::split_by_mode(metrics);
+
+        let mut baseline_stats = Self::calculate_aggregate(&baseline);
+        let mut aicms_stats = Self::calculate_aggregate(&aicms);
+        self.add_intervals(&mut baseline_stats, &baseline);
+        self.add_intervals(&mut aicms_stats, &aicms);
+        self.add_custom(&mut baseline_stats, &baseline);
+        self.add_custom(&mut aicms_stats, &aicms);
+        baseline_stats.failures = count_failures(&baseline);
+        aicms_stats.failures = count_failures(&aicms);
+        let delta = DeltaStats::between(&baseline_stats, &aicms_stats)
+            .map(|delta| with_effect_sizes(delta, &baseline, &aicms));
+
+        ModeComparison {
+            baseline: baseline_stats,
+            aicms: aicms_stats,
+            delta,
+        }
+    }
+
+    /// @ai:intent Outlier tasks and the overall results of the other tasks (None without
+    ///            outliers)
+    /// @ai:effects pure
+    fn outliers(&self, metrics: &[TaskMetrics]) -> Option<OutlierStats> {
+        let tasks = OutlierTask::detect(metrics);
+        if tasks.is_empty() {
+            return None;
+        }
+        let rest: Vec<TaskMetrics> = metrics
+            .iter()
+            .filter(|m| tasks.iter().all(|t| t.task_id != m.task_id))
+            .cloned()
+            .collect();
+        Some(OutlierStats {
+            without: self.compare_modes(&rest),
+            tasks,
+        })
+    }
+
     /// @ai:intent Split metrics by mode
     /// @ai:effects pure
     fn split_by_mode(metrics: &[TaskMetrics]) -> (Vec<&TaskMetrics>, Vec<&TaskMetrics>) {
@@ -78,6 +408,79 @@ impl Default for MetricsAggregator {
     }
 }
 
+/// @ai:intent Input-token equivalents saved by prompt caching
+///            Cache reads cost 10% of the base input price and cache writes 125%
+/// @ai:effects pure
+fn cache_savings(creation_tokens: u64, read_tokens: u64) -> f64 {
+    read_tokens as f64 * 0.9 - creation_tokens as f64 * 0.25
+}
+
+/// @ai:intent Number of failed executions by failure reason
+/// @ai:effects pure
+fn count_failures(metrics: &[&TaskMetrics]) -> BTreeMap<FailureReason, u32> {
+    let mut counts = BTreeMap::new();
+    for reason in metrics.iter().filter_map(|m| m.failure) {
+        *counts.entry(reason).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// @ai:intent Executions as they count in the aggregates under the missing-data policy.
+///            Those without extracted code stay at 0% (zero), are left out (exclude), or take
+///            the average rates of the repetitions of their task in the same mode, model,
+///            sampling setting and skill variant that produced code (impute; left out when
+///            none did)
+/// @ai:effects pure
+fn apply_missing_data(
+    policy: MissingDataPolicy,
+    metrics: &[TaskMetrics],
+) -> (Vec<TaskMetrics>, MissingData) {
+    let mut missing = MissingData {
+        policy,
+        ..Default::default()
+    };
+    let mut counted = Vec::with_capacity(metrics.len());
+    for m in metrics {
+        if m.code_extracted {
+            counted.push(m.clone());
+            continue;
+        }
+        missing.executions += 1;
+        match policy {
+            MissingDataPolicy::Zero => counted.push(m.clone()),
+            MissingDataPolicy::Exclude => missing.excluded += 1,
+            MissingDataPolicy::Impute => {
+                let donors: Vec<&TaskMetrics> = metrics
+                    .iter()
+                    .filter(|d| {
+                        d.code_extracted
+                            && d.task_id == m.task_id
+                            && d.mode == m.mode
+                            && d.model == m.model
+                            && d.sampling == m.sampling
+                            && d.skill == m.skill
+                    })
+                    .collect();
+                if donors.is_empty() {
+                    missing.excluded += 1;
+                    continue;
+                }
+                // Compiled when at least half of the repetitions did
+                let compiled = donors.iter().filter(|d| d.compiled).count();
+                counted.push(TaskMetrics {
+                    compiled: compiled * 2 >= donors.len(),
+                    test_pass_rate: average(donors.iter().map(|d| d.test_pass_rate)),
+                    lint_compliance: average(donors.iter().map(|d| d.lint_compliance)),
+                    annotation_quality: average(donors.iter().map(|d| d.annotation_quality)),
+                    ..m.clone()
+                });
+                missing.imputed += 1;
+            }
+        }
+    }
+    (counted, missing)
+}
+
 /// @ai:intent Calculate average of an iterator of f64
 /// @ai:effects pure
 fn average<I: Iterator<Item = f64>>(iter: I) -> f64 {
@@ -100,45 +503,132 @@ impl MetricsAggregatorTrait for MetricsAggregator {
         model: &str,
         repetitions: u32,
     ) -> BenchmarkResults {
-        let (baseline, aicms) = Self::split_by_mode(metrics);
+        let task_metrics: Vec<TaskMetrics> = metrics
+            .iter()
+            .cloned()
+            .map(|mut m| {
+                if m.model.is_empty() {
+                    m.model = model.to_string();
+                }
+                m
+            })
+            .collect();
+        // The aggregates follow the missing-data policy; task_metrics keeps every execution
+        let (counted, missing_data) = apply_missing_data(self.missing_data, &task_metrics);
+        let metrics = counted.as_slice();
 
-        let baseline_stats = Self::calculate_aggregate(&baseline);
-        let aicms_stats = Self::calculate_aggregate(&aicms);
-        let delta = DeltaStats::calculate(&baseline_stats, &aicms_stats);
+        let overall = self.compare_modes(metrics);
 
         let task_map: HashMap<_, _> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
 
         let by_category = aggregate_by_category(metrics, &task_map);
         let by_language = aggregate_by_language(metrics, &task_map);
         let by_difficulty = aggregate_by_difficulty(metrics, &task_map);
+        let by_tag = aggregate_by_tag(metrics, &task_map);
+        let by_model = aggregate_by_model(metrics);
+        let by_sampling = aggregate_by_sampling(metrics);
+        let by_skill = aggregate_by_skill(metrics);
+        let repair_curve = aggregate_repair_curve(metrics);
+        let significance = paired_significance(metrics);
+        let head_to_head = head_to_head(metrics);
+        let flaky_tasks = flaky_tasks(metrics);
+        let composite = CompositeScore::compute(
+            &self.composite_weights,
+            &overall.baseline,
+            &overall.aicms,
+            None,
+        );
+        let difficulty_normalized = DifficultyNormalized::compute(
+            &self.difficulty_weights,
+            &overall.baseline,
+            &overall.aicms,
+            &by_difficulty,
+        );
+        let outliers = self.outliers(metrics);
 
         BenchmarkResults {
             timestamp: chrono::Utc::now().to_rfc3339(),
             model: model.to_string(),
             repetitions,
-            overall: ModeComparison {
-                baseline: baseline_stats,
-                aicms: aicms_stats,
-                delta,
-            },
+            overall,
             by_category,
             by_language,
             by_difficulty,
-            task_metrics: metrics.to_vec(),
+            by_tag,
+            by_model,
+            by_sampling,
+            by_skill,
+            repair_curve,
+            significance,
+            head_to_head,
+            flaky_tasks,
+            composite,
+            difficulty_normalized,
+            outliers,
+            task_metrics,
             claude_comparisons: vec![],
             claude_stats: None,
+            rankings: vec![],
+            ranking_stats: vec![],
+            truncated: None,
+            missing_data,
+            metadata: None,
         }
     }
 
 }
 
 impl MetricsAggregator {
-    /// @ai:intent Add Claude comparisons to results and calculate stats
+    /// @ai:intent Merge per-model (and per-setting) results of a matrix run into one
+    ///            side-by-side result; Claude comparisons stay in the per-run results
+    /// @ai:effects pure
+    pub fn combine_models(
+        &self,
+        runs: &[BenchmarkResults],
+        tasks: &[Task],
+        repetitions: u32,
+    ) -> BenchmarkResults {
+        let metrics: Vec<TaskMetrics> = runs
+            .iter()
+            .flat_map(|r| r.task_metrics.iter().cloned())
+            .collect();
+        // Sweeps run each model several times; name it once
+        let mut models: Vec<&str> = Vec::new();
+        for run in runs {
+            if !models.contains(&run.model.as_str()) {
+                models.push(&run.model);
+            }
+        }
+
+        self.aggregate(&metrics, tasks, &models.join(", "), repetitions)
+    }
+
+    /// @ai:intent Mark the executions of tasks whose judge answer could not be parsed as
+    ///            judge parse errors (unless they already failed) and recount the failures
+    /// @ai:effects pure
+    pub fn add_judge_failures(&self, results: &mut BenchmarkResults, task_ids: &[String]) {
+        if task_ids.is_empty() {
+            return;
+        }
+        for m in &mut results.task_metrics {
+            if m.failure.is_none() && task_ids.contains(&m.task_id) {
+                m.failure = Some(FailureReason::JudgeParseError);
+            }
+        }
+        let (counted, _) = apply_missing_data(self.missing_data, &results.task_metrics);
+        let (baseline, aicms) = Self::split_by_mode(&counted);
+        results.overall.baseline.failures = count_failures(&baseline);
+        results.overall.aicms.failures = count_failures(&aicms);
+    }
+
+    /// @ai:intent Add Claude comparisons to results and calculate stats, overall and per
+    ///            category, language and difficulty of the tasks
     /// @ai:effects pure
     pub fn add_claude_comparisons(
         &self,
         results: &mut BenchmarkResults,
         comparisons: Vec<TaskComparison>,
+        tasks: &[Task],
     ) {
         if comparisons.is_empty() {
             return;
@@ -163,129 +653,483 @@ impl MetricsAggregator {
 
         let avg_baseline = average(baseline_scores.into_iter());
         let avg_aicms = average(aicms_scores.into_iter());
+        let task_map: HashMap<_, _> = tasks.iter().map(|t| (t.id.as_str(), t)).collect();
 
-        results.claude_comparisons = comparisons;
-        results.claude_stats = Some(ClaudeComparisonStats {
+        let mut stats = ClaudeComparisonStats {
             avg_baseline_score: avg_baseline,
             avg_aicms_score: avg_aicms,
             aicms_wins,
             baseline_wins,
             ties,
-        });
+            agreement: JudgeAgreement::from_comparisons(&comparisons),
+            win_rate_ci: ClaudeComparisonStats::win_rate_interval(
+                &comparisons,
+                self.bootstrap_resamples,
+            ),
+            win_test: None,
+            by_category: comparison_groups(&comparisons, &task_map, |t| t.category),
+            by_language: comparison_groups(&comparisons, &task_map, |t| t.language),
+            by_difficulty: comparison_groups(&comparisons, &task_map, |t| t.difficulty),
+        };
+        stats.win_test = stats.win_test(self.ties);
+        // The comparison wins can now count towards the composite
+        results.composite = CompositeScore::compute(
+            &self.composite_weights,
+            &results.overall.baseline,
+            &results.overall.aicms,
+            Some(&stats),
+        );
+        results.claude_stats = Some(stats);
+        for entry in &mut results.head_to_head {
+            entry.winner = comparisons
+                .iter()
+                .find(|c| c.task_id == entry.task_id)
+                .map(|c| c.comparison.winner.clone());
+        }
+        results.claude_comparisons = comparisons;
+    }
+
+    /// @ai:intent Add task rankings to results and aggregate them per implementation source
+    /// @ai:effects pure
+    pub fn add_rankings(&self, results: &mut BenchmarkResults, rankings: Vec<TaskRanking>) {
+        results.ranking_stats = RankingStats::aggregate(&rankings);
+        results.rankings = rankings;
     }
 }
 
+/// @ai:intent Group executions by a property of their task, split by mode. Buckets come from
+///            the tasks that ran, ordered by key; executions of unknown tasks are left out
+/// @ai:effects pure
+fn group_by_task<'a, K: Ord>(
+    metrics: &'a [TaskMetrics],
+    task_map: &HashMap<&str, &Task>,
+    key: impl Fn(&Task) -> K,
+) -> BTreeMap<K, ModeMetrics<'a>> {
+    let mut groups: BTreeMap<K, ModeMetrics<'a>> = BTreeMap::new();
+    for m in metrics {
+        let Some(task) = task_map.get(m.task_id.as_str()) else {
+            continue;
+        };
+        let (baseline, aicms) = groups.entry(key(task)).or_default();
+        if m.mode == "baseline" {
+            baseline.push(m);
+        } else {
+            aicms.push(m);
+        }
+    }
+    groups
+}
+
+/// @ai:intent Judge results of the comparisons grouped by an attribute of their task, in
+///            the attribute's order; comparisons of tasks not in the corpus are left out
+/// @ai:effects pure
+fn comparison_groups<K: Ord + std::fmt::Display>(
+    comparisons: &[TaskComparison],
+    task_map: &HashMap<&str, &Task>,
+    key: impl Fn(&Task) -> K,
+) -> Vec<ComparisonGroupStats> {
+    let mut groups: BTreeMap<K, Vec<&TaskComparison>> = BTreeMap::new();
+    for comp in comparisons {
+        if let Some(task) = task_map.get(comp.task_id.as_str()) {
+            groups.entry(key(task)).or_default().push(comp);
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(group, comparisons)| {
+            ComparisonGroupStats::from_comparisons(group.to_string(), &comparisons)
+        })
+        .collect()
+}
+
 /// @ai:intent Aggregate metrics by task category
 /// @ai:effects pure
 fn aggregate_by_category(
     metrics: &[TaskMetrics],
     task_map: &HashMap<&str, &Task>,
 ) -> Vec<CategoryStats> {
-    let categories = ["implement", "bugfix", "refactor", "inference"];
+    group_by_task(metrics, task_map, |t| t.category)
+        .into_iter()
+        .map(|(category, (baseline, aicms))| CategoryStats {
+            category: category.to_string(),
+            baseline: MetricsAggregator::calculate_aggregate(&baseline),
+            aicms: MetricsAggregator::calculate_aggregate(&aicms),
+        })
+        .collect()
+}
 
-    categories
-        .iter()
-        .map(|cat| {
-            let cat_metrics: Vec<_> = metrics
+/// @ai:intent Aggregate metrics by programming language
+/// @ai:effects pure
+fn aggregate_by_language(
+    metrics: &[TaskMetrics],
+    task_map: &HashMap<&str, &Task>,
+) -> Vec<LanguageStats> {
+    group_by_task(metrics, task_map, |t| t.language)
+        .into_iter()
+        .map(|(language, (baseline, aicms))| LanguageStats {
+            language: language.to_string(),
+            baseline: MetricsAggregator::calculate_aggregate(&baseline),
+            aicms: MetricsAggregator::calculate_aggregate(&aicms),
+        })
+        .collect()
+}
+
+/// @ai:intent Aggregate metrics by difficulty level
+/// @ai:effects pure
+fn aggregate_by_difficulty(
+    metrics: &[TaskMetrics],
+    task_map: &HashMap<&str, &Task>,
+) -> Vec<DifficultyStats> {
+    group_by_task(metrics, task_map, |t| t.difficulty)
+        .into_iter()
+        .map(|(difficulty, (baseline, aicms))| DifficultyStats {
+            difficulty: difficulty.to_string(),
+            baseline: MetricsAggregator::calculate_aggregate(&baseline),
+            aicms: MetricsAggregator::calculate_aggregate(&aicms),
+        })
+        .collect()
+}
+
+/// @ai:intent Aggregate metrics by task tag, in tag order; each execution counts towards
+///            every tag of its task
+/// @ai:effects pure
+fn aggregate_by_tag(metrics: &[TaskMetrics], task_map: &HashMap<&str, &Task>) -> Vec<TagStats> {
+    let mut groups: BTreeMap<&str, ModeMetrics> = BTreeMap::new();
+    for m in metrics {
+        let Some(task) = task_map.get(m.task_id.as_str()) else {
+            continue;
+        };
+        let tags: BTreeSet<&str> = task.tags.iter().map(String::as_str).collect();
+        for tag in tags {
+            let (baseline, aicms) = groups.entry(tag).or_default();
+            if m.mode == "baseline" {
+                baseline.push(m);
+            } else {
+                aicms.push(m);
+            }
+        }
+    }
+    groups
+        .into_iter()
+        .map(|(tag, (baseline, aicms))| TagStats {
+            tag: tag.to_string(),
+            baseline: MetricsAggregator::calculate_aggregate(&baseline),
+            aicms: MetricsAggregator::calculate_aggregate(&aicms),
+        })
+        .collect()
+}
+
+/// @ai:intent Aggregate metrics by model, in order of first appearance
+/// @ai:effects pure
+fn aggregate_by_model(metrics: &[TaskMetrics]) -> Vec<ModelStats> {
+    let mut models: Vec<&str> = Vec::new();
+    for m in metrics {
+        if !models.contains(&m.model.as_str()) {
+            models.push(&m.model);
+        }
+    }
+
+    models
+        .into_iter()
+        .map(|model| {
+            let (baseline_metrics, aicms_metrics): (Vec<_>, Vec<_>) = metrics
                 .iter()
-                .filter(|m| {
-                    task_map
-                        .get(m.task_id.as_str())
-                        .map(|t| t.category.as_str() == *cat)
-                        .unwrap_or(false)
-                })
-                .collect();
+                .filter(|m| m.model == model)
+                .partition(|m| m.mode == "baseline");
+
+            let baseline = MetricsAggregator::calculate_aggregate(&baseline_metrics);
+            let aicms = MetricsAggregator::calculate_aggregate(&aicms_metrics);
+            let delta = DeltaStats::between(&baseline, &aicms)
+                .map(|delta| with_effect_sizes(delta, &baseline_metrics, &aicms_metrics));
+
+            ModelStats {
+                model: model.to_string(),
+                baseline,
+                aicms,
+                delta,
+            }
+        })
+        .collect()
+}
+
+/// @ai:intent Aggregate metrics by sampling setting, in order of first appearance
+///            Metrics without a setting (no sweep) are left out
+/// @ai:effects pure
+fn aggregate_by_sampling(metrics: &[TaskMetrics]) -> Vec<SamplingStats> {
+    let mut settings: Vec<&str> = Vec::new();
+    for m in metrics {
+        if let Some(sampling) = m.sampling.as_deref() {
+            if !settings.contains(&sampling) {
+                settings.push(sampling);
+            }
+        }
+    }
 
-            let (baseline, aicms): (Vec<_>, Vec<_>) =
-                cat_metrics.iter().partition(|m| m.mode == "baseline");
+    settings
+        .into_iter()
+        .map(|sampling| {
+            let (baseline_metrics, aicms_metrics): (Vec<_>, Vec<_>) = metrics
+                .iter()
+                .filter(|m| m.sampling.as_deref() == Some(sampling))
+                .partition(|m| m.mode == "baseline");
 
-            let baseline_refs: Vec<_> = baseline.iter().copied().collect();
-            let aicms_refs: Vec<_> = aicms.iter().copied().collect();
+            let baseline = MetricsAggregator::calculate_aggregate(&baseline_metrics);
+            let aicms = MetricsAggregator::calculate_aggregate(&aicms_metrics);
+            let delta = DeltaStats::between(&baseline, &aicms)
+                .map(|delta| with_effect_sizes(delta, &baseline_metrics, &aicms_metrics));
 
-            CategoryStats {
-                category: cat.to_string(),
-                baseline: MetricsAggregator::calculate_aggregate(&baseline_refs),
-                aicms: MetricsAggregator::calculate_aggregate(&aicms_refs),
+            SamplingStats {
+                sampling: sampling.to_string(),
+                baseline,
+                aicms,
+                delta,
             }
         })
         .collect()
 }
 
-/// @ai:intent Aggregate metrics by programming language
+/// @ai:intent Aggregate AICMS metrics by skill variant, in order of first appearance,
+///            each against all baseline metrics (the baseline arm runs once per matrix)
 /// @ai:effects pure
-fn aggregate_by_language(
-    metrics: &[TaskMetrics],
-    task_map: &HashMap<&str, &Task>,
-) -> Vec<LanguageStats> {
-    let languages = ["rust", "python", "typescript"];
+fn aggregate_by_skill(metrics: &[TaskMetrics]) -> Vec<SkillStats> {
+    let mut skills: Vec<&str> = Vec::new();
+    for m in metrics {
+        if let Some(skill) = m.skill.as_deref() {
+            if !skills.contains(&skill) {
+                skills.push(skill);
+            }
+        }
+    }
 
-    languages
-        .iter()
-        .map(|lang| {
-            let lang_metrics: Vec<_> = metrics
+    let baseline_metrics: Vec<_> = metrics.iter().filter(|m| m.mode == "baseline").collect();
+    let baseline = MetricsAggregator::calculate_aggregate(&baseline_metrics);
+
+    skills
+        .into_iter()
+        .map(|skill| {
+            let aicms_metrics: Vec<_> = metrics
                 .iter()
-                .filter(|m| {
-                    task_map
-                        .get(m.task_id.as_str())
-                        .map(|t| t.language.as_str() == *lang)
-                        .unwrap_or(false)
-                })
+                .filter(|m| m.mode == "aicms" && m.skill.as_deref() == Some(skill))
+                .collect();
+            let aicms = MetricsAggregator::calculate_aggregate(&aicms_metrics);
+            let delta = DeltaStats::between(&baseline, &aicms)
+                .map(|delta| with_effect_sizes(delta, &baseline_metrics, &aicms_metrics));
+
+            let variant_metrics: Vec<TaskMetrics> = baseline_metrics
+                .iter()
+                .chain(&aicms_metrics)
+                .map(|m| (*m).clone())
                 .collect();
 
-            let (baseline, aicms): (Vec<_>, Vec<_>) =
-                lang_metrics.iter().partition(|m| m.mode == "baseline");
+            SkillStats {
+                skill: skill.to_string(),
+                baseline: baseline.clone(),
+                aicms,
+                delta,
+                significance: paired_significance(&variant_metrics),
+            }
+        })
+        .collect()
+}
+
+/// @ai:intent Compile and test rates per repair turn; an execution that stopped
+///            early (it passed or hit an error) keeps its last turn's result
+/// @ai:effects pure
+fn aggregate_repair_curve(metrics: &[TaskMetrics]) -> Vec<RepairTurnStats> {
+    let repaired: Vec<&TaskMetrics> = metrics
+        .iter()
+        .filter(|m| !m.repair_turns.is_empty())
+        .collect();
+    let max_turns = repaired.iter().map(|m| m.repair_turns.len()).max().unwrap_or(0);
 
-            let baseline_refs: Vec<_> = baseline.iter().copied().collect();
-            let aicms_refs: Vec<_> = aicms.iter().copied().collect();
+    let rates = |mode: &str, turn: usize| {
+        let states: Vec<_> = repaired
+            .iter()
+            .filter(|m| m.mode == mode)
+            .filter_map(|m| m.repair_turns.get(turn).or(m.repair_turns.last()))
+            .collect();
+        let compiled = states.iter().filter(|t| t.compiled).count();
+        let compilation_rate = if states.is_empty() {
+            0.0
+        } else {
+            compiled as f64 / states.len() as f64 * 100.0
+        };
+        (compilation_rate, average(states.iter().map(|t| t.test_pass_rate)))
+    };
 
-            LanguageStats {
-                language: lang.to_string(),
-                baseline: MetricsAggregator::calculate_aggregate(&baseline_refs),
-                aicms: MetricsAggregator::calculate_aggregate(&aicms_refs),
+    (0..max_turns)
+        .map(|turn| {
+            let (baseline_compilation_rate, baseline_test_pass_rate) = rates("baseline", turn);
+            let (aicms_compilation_rate, aicms_test_pass_rate) = rates("aicms", turn);
+            RepairTurnStats {
+                turn: turn as u32,
+                baseline_compilation_rate,
+                aicms_compilation_rate,
+                baseline_test_pass_rate,
+                aicms_test_pass_rate,
             }
         })
         .collect()
 }
 
-/// @ai:intent Aggregate metrics by difficulty level
+/// @ai:intent Executions grouped by task, model, sampling setting and skill variant (metrics
+///            are of one mode), leaving out groups of one execution
 /// @ai:effects pure
-fn aggregate_by_difficulty(
-    metrics: &[TaskMetrics],
-    task_map: &HashMap<&str, &Task>,
-) -> Vec<DifficultyStats> {
-    let difficulties = ["easy", "medium", "hard"];
+fn repeated_tasks<'a>(metrics: &[&'a TaskMetrics]) -> Vec<Vec<&'a TaskMetrics>> {
+    let mut tasks: BTreeMap<_, Vec<&TaskMetrics>> = BTreeMap::new();
+    for m in metrics {
+        let key = (
+            m.task_id.as_str(),
+            m.model.as_str(),
+            m.sampling.as_deref(),
+            m.skill.as_deref(),
+        );
+        tasks.entry(key).or_default().push(m);
+    }
+    tasks.into_values().filter(|runs| runs.len() > 1).collect()
+}
 
-    difficulties
-        .iter()
-        .map(|diff| {
-            let diff_metrics: Vec<_> = metrics
+/// @ai:intent Spread of each headline metric across the repetitions of each repeated task.
+///            Empty when no task was repeated
+/// @ai:effects pure
+fn repetition_spread(repeated: &[Vec<&TaskMetrics>]) -> Vec<RepetitionSpread> {
+    if repeated.is_empty() {
+        return vec![];
+    }
+
+    HEADLINE_METRICS
+        .into_iter()
+        .map(|(metric, value)| {
+            let groups: Vec<Vec<f64>> = repeated
                 .iter()
-                .filter(|m| {
-                    task_map
-                        .get(m.task_id.as_str())
-                        .map(|t| t.difficulty.as_str() == *diff)
-                        .unwrap_or(false)
-                })
+                .map(|runs| runs.iter().map(|m| value(m)).collect())
                 .collect();
+            RepetitionSpread::from_repetitions(metric, &groups)
+        })
+        .collect()
+}
 
-            let (baseline, aicms): (Vec<_>, Vec<_>) =
-                diff_metrics.iter().partition(|m| m.mode == "baseline");
-
-            let baseline_refs: Vec<_> = baseline.iter().copied().collect();
-            let aicms_refs: Vec<_> = aicms.iter().copied().collect();
+/// @ai:intent Add the effect size of each headline metric, over the executions of each mode
+/// @ai:effects pure
+fn with_effect_sizes(
+    mut delta: DeltaStats,
+    baseline: &[&TaskMetrics],
+    aicms: &[&TaskMetrics],
+) -> DeltaStats {
+    delta.effect_sizes = HEADLINE_METRICS
+        .into_iter()
+        .map(|(metric, value)| {
+            let baseline: Vec<f64> = baseline.iter().map(|m| value(m)).collect();
+            let aicms: Vec<f64> = aicms.iter().map(|m| value(m)).collect();
+            EffectSize::between(metric, &baseline, &aicms)
+        })
+        .collect();
+    delta
+}
 
-            DifficultyStats {
-                difficulty: diff.to_string(),
-                baseline: MetricsAggregator::calculate_aggregate(&baseline_refs),
-                aicms: MetricsAggregator::calculate_aggregate(&aicms_refs),
+/// @ai:intent Executions of each task of one model, sampling setting and skill variant that
+///            ran in both modes, keyed by (model, sampling, skill, task id). Each variant of a
+///            skill matrix pairs with the shared baseline arm
+/// @ai:effects pure
+fn paired_tasks(metrics: &[TaskMetrics]) -> BTreeMap<TaskKey<'_>, ModeMetrics<'_>> {
+    let mut tasks: BTreeMap<TaskKey, ModeMetrics> = BTreeMap::new();
+    let (baseline, aicms) = MetricsAggregator::split_by_mode(metrics);
+    for m in aicms {
+        let key = (
+            m.model.as_str(),
+            m.sampling.as_deref(),
+            m.skill.as_deref(),
+            m.task_id.as_str(),
+        );
+        tasks.entry(key).or_default().1.push(m);
+    }
+    // The baseline arm of a skill matrix has no skill and is shared by every variant
+    for m in baseline {
+        for ((model, sampling, skill, task_id), entry) in tasks.iter_mut() {
+            if m.model == *model
+                && m.sampling.as_deref() == *sampling
+                && m.task_id == *task_id
+                && (m.skill.is_none() || m.skill.as_deref() == *skill)
+            {
+                entry.0.push(m);
             }
+        }
+    }
+    tasks.retain(|_, (baseline, aicms)| !baseline.is_empty() && !aicms.is_empty());
+    tasks
+}
+
+/// @ai:intent Paired tests of the headline metrics (the DeltaStats fields). A task of one
+///            model, sampling setting and skill variant is a pair if it ran in both modes;
+///            repetitions are averaged. Empty when there are no pairs
+/// @ai:effects pure
+fn paired_significance(metrics: &[TaskMetrics]) -> Vec<PairedTest> {
+    let tasks = paired_tasks(metrics);
+    let paired: Vec<_> = tasks.values().collect();
+    if paired.is_empty() {
+        return vec![];
+    }
+
+    HEADLINE_METRICS
+        .into_iter()
+        .map(|(metric, value)| {
+            let pairs: Vec<(f64, f64)> = paired
+                .iter()
+                .map(|(baseline, aicms)| {
+                    (
+                        average(baseline.iter().map(|m| value(m))),
+                        average(aicms.iter().map(|m| value(m))),
+                    )
+                })
+                .collect();
+            PairedTest::from_pairs(metric, &pairs)
         })
         .collect()
 }
 
+/// @ai:intent Tasks whose outcome flipped between repetitions of the same mode, by task id
+///            then mode
+/// @ai:effects pure
+fn flaky_tasks(metrics: &[TaskMetrics]) -> Vec<FlakyTask> {
+    let (baseline, aicms) = MetricsAggregator::split_by_mode(metrics);
+    let mut tasks: Vec<FlakyTask> = [baseline, aicms]
+        .iter()
+        .flat_map(|mode| repeated_tasks(mode))
+        .filter_map(|runs| FlakyTask::from_repetitions(&runs))
+        .collect();
+    tasks.sort_by(|a, b| (&a.task_id, &a.mode).cmp(&(&b.task_id, &b.mode)));
+    tasks
+}
+
+/// @ai:intent Baseline vs AICMS outcome of each task run in both modes, by model, sampling
+///            setting and task id; the judge's winners are added with the Claude comparisons
+/// @ai:effects pure
+fn head_to_head(metrics: &[TaskMetrics]) -> Vec<TaskHeadToHead> {
+    paired_tasks(metrics)
+        .into_iter()
+        .map(
+            |((model, sampling, skill, task_id), (baseline, aicms))| TaskHeadToHead {
+                task_id: task_id.to_string(),
+                model: model.to_string(),
+                sampling: sampling.map(str::to_string),
+                skill: skill.map(str::to_string),
+                baseline: TaskOutcome::from_executions(&baseline),
+                aicms: TaskOutcome::from_executions(&aicms),
+                winner: None,
+            },
+        )
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::evaluator::{
+        AnnotationDensity, ApiConformance, CodeQuality, CodeSize, Complexity, Contamination,
+        InferenceScore, MutationScore, ReferenceAlignment, SecurityScan, TestDesign,
+        TestDeterminism, TypeCheck,
+    };
+    use crate::metrics::types::OutlierReason;
 
     #[test]
     fn test_average() {
@@ -303,32 +1147,162 @@ mod tests {
     fn test_calculate_aggregate() {
         let m1 = TaskMetrics {
             task_id: "t1".to_string(),
+            model: String::new(),
+            sampling: None,
+            skill: None,
             mode: "baseline".to_string(),
             repetition: 0,
             code_extracted: true,
             compiled: true,
+            compile_errors: vec![],
             test_pass_rate: 80.0,
+            test_determinism: Some(TestDeterminism {
+                passed: vec![4, 3],
+                total: vec![5, 5],
+                flaky: true,
+            }),
+            hidden_test_pass_rate: Some(50.0),
+            io_pass_rate: Some(75.0),
+            api_conformance: Some(ApiConformance {
+                required: 4,
+                conforming: 3,
+                ..Default::default()
+            }),
+            reference: Some(ReferenceAlignment {
+                api_match: 75.0,
+                score: 80.0,
+                ..Default::default()
+            }),
+            inference: Some(InferenceScore {
+                precision: 50.0,
+                recall: 100.0,
+                f1: 200.0 / 3.0,
+                ..Default::default()
+            }),
+            code_quality: Some(CodeQuality {
+                tool: "clippy".to_string(),
+                warnings: 2,
+                lines: 40,
+                ..Default::default()
+            }),
+            type_check: Some(TypeCheck {
+                tool: "mypy".to_string(),
+                errors: 3,
+                ..Default::default()
+            }),
+            security: Some(SecurityScan {
+                audit_tool: Some("cargo-audit".to_string()),
+                vulnerable_dependencies: 1,
+                dangerous_patterns: 2,
+                ..Default::default()
+            }),
+            complexity: Some(Complexity {
+                functions: 2,
+                avg_cyclomatic: 3.5,
+                max_cyclomatic: 5,
+                avg_nesting_depth: 1.5,
+                max_nesting_depth: 2,
+                avg_maintainability: 60.0,
+            }),
+            code_size: Some(CodeSize {
+                files: 3,
+                lines: 120,
+                test_lines: 40,
+            }),
+            mutation: Some(MutationScore {
+                tool: "cargo-mutants".to_string(),
+                caught: 3,
+                missed: 1,
+                ..Default::default()
+            }),
+            test_design: Some(TestDesign {
+                property_framework: Some("proptest".to_string()),
+                property_tests: 2,
+                edge_cases: 4,
+                edge_cases_tested: 3,
+                ..Default::default()
+            }),
             lint_compliance: 100.0,
             lint_issues: vec![],
+            lint_passed: Some(true),
             annotation_quality: 70.0,
+            annotation_density: Some(AnnotationDensity {
+                functions: 4,
+                annotated_functions: 3,
+                annotations: 6,
+                ..Default::default()
+            }),
+            spec_overlap: 4.0,
+            spec_echo: false,
+            contamination: None,
+            contracts_checked: 2,
+            contracts_violated: 1,
+            violated_contracts: vec!["no-overdraft".to_string()],
+            custom: BTreeMap::new(),
+            failure: None,
             input_tokens: 100,
             output_tokens: 200,
             execution_time_ms: 1000,
+            cache_creation_input_tokens: 20,
+            cache_read_input_tokens: 80,
+            num_turns: None,
+            cost_usd: Some(0.25),
+            repair_turns: vec![],
         };
 
         let m2 = TaskMetrics {
             task_id: "t2".to_string(),
+            model: String::new(),
+            sampling: None,
+            skill: None,
             mode: "baseline".to_string(),
             repetition: 0,
             code_extracted: true,
             compiled: false,
+            compile_errors: vec![],
             test_pass_rate: 60.0,
+            test_determinism: Some(TestDeterminism {
+                passed: vec![3, 3],
+                total: vec![5, 5],
+                flaky: false,
+            }),
+            hidden_test_pass_rate: None,
+            io_pass_rate: None,
+            api_conformance: None,
+            reference: None,
+            inference: None,
+            code_quality: None,
+            type_check: None,
+            security: Some(SecurityScan::default()),
+            complexity: None,
+            code_size: None,
+            mutation: None,
+            test_design: None,
             lint_compliance: 80.0,
             lint_issues: vec![],
+            lint_passed: Some(false),
             annotation_quality: 50.0,
+            annotation_density: None,
+            spec_overlap: 60.0,
+            spec_echo: true,
+            contamination: Some(Contamination {
+                reference_overlap: Some(90.0),
+                flagged: true,
+                ..Default::default()
+            }),
+            contracts_checked: 2,
+            contracts_violated: 0,
+            violated_contracts: vec![],
+            custom: BTreeMap::new(),
+            failure: None,
             input_tokens: 150,
             output_tokens: 250,
             execution_time_ms: 1500,
+            cache_creation_input_tokens: 0,
+            cache_read_input_tokens: 0,
+            num_turns: None,
+            cost_usd: None,
+            repair_turns: vec![],
         };
 
         let metrics: Vec<&TaskMetrics> = vec![&m1, &m2];
@@ -337,5 +1311,844 @@ mod tests {
         assert_eq!(stats.task_count, 2);
         assert!((stats.compilation_rate - 50.0).abs() < 0.01);
         assert!((stats.avg_test_pass_rate - 70.0).abs() < 0.01);
+        // Only executions of tasks with hidden tests count towards their average
+        assert_eq!(stats.hidden_tested_count, 1);
+        assert!((stats.avg_hidden_test_pass_rate - 50.0).abs() < 0.01);
+        assert_eq!(stats.io_tested_count, 1);
+        assert!((stats.avg_io_pass_rate - 75.0).abs() < 0.01);
+        assert_eq!(stats.api_checked_count, 1);
+        assert!((stats.avg_api_conformance - 75.0).abs() < 0.01);
+        assert_eq!(stats.reference_compared_count, 1);
+        assert!((stats.avg_reference_api_match - 75.0).abs() < 0.01);
+        assert!((stats.avg_reference_alignment - 80.0).abs() < 0.01);
+        assert_eq!(stats.inference_scored_count, 1);
+        assert!((stats.avg_inference_recall - 100.0).abs() < 0.01);
+        assert_eq!(stats.quality_checked_count, 1);
+        assert!((stats.avg_quality_density - 5.0).abs() < 0.01);
+        assert_eq!(stats.type_checked_count, 1);
+        assert!((stats.type_correct_rate - 0.0).abs() < 0.01);
+        assert!((stats.avg_type_errors - 3.0).abs() < 0.01);
+        assert_eq!(stats.lint_checked_count, 2);
+        assert!((stats.lint_pass_rate - 50.0).abs() < 0.01);
+        // m2 is clean; only m1's dependencies were audited
+        assert_eq!(stats.security_scanned_count, 2);
+        assert!((stats.security_clean_rate - 50.0).abs() < 0.01);
+        assert!((stats.avg_dangerous_patterns - 1.0).abs() < 0.01);
+        assert_eq!(stats.audited_count, 1);
+        assert!((stats.avg_vulnerable_dependencies - 1.0).abs() < 0.01);
+        assert_eq!(stats.complexity_measured_count, 1);
+        assert!((stats.avg_cyclomatic_complexity - 3.5).abs() < 0.01);
+        assert_eq!(stats.sized_count, 1);
+        assert!((stats.avg_lines_of_code - 120.0).abs() < 0.01);
+        assert_eq!(stats.density_measured_count, 1);
+        assert!((stats.avg_annotations_per_function - 1.5).abs() < 0.01);
+        assert!((stats.avg_annotated_function_rate - 75.0).abs() < 0.01);
+        // m1 passes 80% of its tests, which catch 75% of mutants
+        assert_eq!(stats.mutation_tested_count, 1);
+        assert!((stats.avg_mutation_score - 75.0).abs() < 0.01);
+        assert!((stats.avg_mutation_weighted_pass_rate - 60.0).abs() < 0.01);
+        assert_eq!(stats.determinism_checked_count, 2);
+        assert!((stats.flaky_test_rate - 50.0).abs() < 0.01);
+        assert_eq!(stats.test_design_count, 1);
+        assert!((stats.property_based_rate - 100.0).abs() < 0.01);
+        assert!((stats.avg_edge_case_coverage - 75.0).abs() < 0.01);
+        assert!((stats.avg_spec_overlap - 32.0).abs() < 0.01);
+        assert_eq!(stats.contamination_checked_count, 1);
+        assert_eq!(stats.contamination_count, 1);
+        assert_eq!(stats.spec_echo_count, 1);
+        assert_eq!(stats.contracts_checked, 4);
+        assert_eq!(stats.total_cache_read_tokens, 80);
+        assert!((stats.cache_saved_tokens - 67.0).abs() < 0.01);
+        assert!((stats.cache_hit_rate() - 32.0).abs() < 0.01);
+        assert!((stats.contract_violation_rate - 25.0).abs() < 0.01);
+        assert_eq!(stats.priced_count, 1);
+        assert!((stats.avg_cost_usd() - 0.25).abs() < 1e-9);
+        // Only the priced execution counts: 80% of a pass for $0.25
+        assert!((stats.cost_per_pass_usd.unwrap() - 0.3125).abs() < 1e-9);
+        let pass_rates = stats.test_pass_rate_percentiles.unwrap();
+        assert!((pass_rates.p25 - 65.0).abs() < 1e-9);
+        assert!((pass_rates.median - 70.0).abs() < 1e-9);
+        assert!((pass_rates.p95 - 79.0).abs() < 1e-9);
+        assert!((stats.execution_time_percentiles.unwrap().median - 1250.0).abs() < 1e-9);
+        assert!((stats.token_percentiles.unwrap().p75 - 375.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_combine_models_side_by_side() {
+        let metric = |mode: &str, compiled: bool| TaskMetrics {
+            task_id: "t1".to_string(),
+            model: String::new(),
+            sampling: None,
+            skill: None,
+            mode: mode.to_string(),
+            repetition: 0,
+            code_extracted: true,
+            compiled,
+            compile_errors: vec![],
+            test_pass_rate: 0.0,
+            test_determinism: None,
+            hidden_test_pass_rate: None,
+            io_pass_rate: None,
+            api_conformance: None,
+            reference: None,
+            inference: None,
+            code_quality: None,
+            type_check: None,
+            security: None,
+            complexity: None,
+            code_size: None,
+            mutation: None,
+            test_design: None,
+            lint_compliance: 0.0,
+            lint_issues: vec![],
+            lint_passed: None,
+            annotation_quality: 0.0,
+            annotation_density: None,
+            spec_overlap: 0.0,
+            spec_echo: false,
+            contamination: None,
+            contracts_checked: 0,
+            contracts_violated: 0,
+            violated_contracts: vec![],
+            custom: BTreeMap::new(),
+            failure: None,
+            input_tokens: 0,
+            output_tokens: 0,
+            execution_time_ms: 0,
+            cache_creation_input_tokens: 0,
+            cache_read_input_tokens: 0,
+            num_turns: None,
+            cost_usd: None,
+            repair_turns: vec![],
+        };
+        let aggregator = MetricsAggregator::new();
+        let sonnet = aggregator.aggregate(
+            &[metric("baseline", false), metric("aicms", true)],
+            &[],
+            "sonnet",
+            1,
+        );
+        let haiku = aggregator.aggregate(
+            &[metric("baseline", false), metric("aicms", false)],
+            &[],
+            "haiku",
+            1,
+        );
+
+        let combined = aggregator.combine_models(&[sonnet, haiku], &[], 1);
+
+        assert_eq!(combined.model, "sonnet, haiku");
+        assert_eq!(combined.task_metrics.len(), 4);
+        assert_eq!(combined.by_model.len(), 2);
+        assert_eq!(combined.by_model[0].model, "sonnet");
+        let sonnet_delta = combined.by_model[0].delta.as_ref().unwrap();
+        assert!((sonnet_delta.compilation_rate - 100.0).abs() < 0.01);
+        assert!(combined.by_model[1].delta.as_ref().unwrap().compilation_rate.abs() < 0.01);
+        assert!((combined.overall.aicms.compilation_rate - 50.0).abs() < 0.01);
+        assert!(combined.by_sampling.is_empty());
+
+        let swept = |sampling: &str, compiled: bool| TaskMetrics {
+            sampling: Some(sampling.to_string()),
+            ..metric("aicms", compiled)
+        };
+        let sweep = aggregator.aggregate(
+            &[swept("t=0", true), swept("t=0.7", false), swept("t=0.7", true)],
+            &[],
+            "sonnet",
+            1,
+        );
+        assert_eq!(sweep.by_sampling.len(), 2);
+        assert_eq!(sweep.by_sampling[1].sampling, "t=0.7");
+        assert!((sweep.by_sampling[1].aicms.compilation_rate - 50.0).abs() < 0.01);
+
+        // AICMS-only metrics: no baseline to compare against, so no deltas
+        assert_eq!(sweep.overall.baseline.task_count, 0);
+        assert!(sweep.overall.delta.is_none());
+        assert!(sweep.by_sampling[1].delta.is_none());
+        assert!(sweep.by_skill.is_empty());
+
+        // Skill matrix: every variant's AICMS arm against the one baseline arm
+        let variant = |skill: &str, compiled: bool| TaskMetrics {
+            skill: Some(skill.to_string()),
+            ..metric("aicms", compiled)
+        };
+        let baseline = aggregator.aggregate(&[metric("baseline", false)], &[], "sonnet", 1);
+        let terse = aggregator.aggregate(&[variant("terse", true)], &[], "sonnet", 1);
+        let verbose = aggregator.aggregate(&[variant("verbose", false)], &[], "sonnet", 1);
+        let matrix = aggregator.combine_models(&[baseline, terse, verbose], &[], 1);
+        let skills: Vec<&str> = matrix.by_skill.iter().map(|s| s.skill.as_str()).collect();
+        assert_eq!(skills, ["terse", "verbose"]);
+        assert_eq!(matrix.by_skill[1].baseline.task_count, 1);
+        let terse_delta = matrix.by_skill[0].delta.as_ref().unwrap();
+        assert!((terse_delta.compilation_rate - 100.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_paired_significance() {
+        let metric = |task: usize, mode: &str, test_pass_rate: f64| TaskMetrics {
+            task_id: format!("t{}", task),
+            mode: mode.to_string(),
+            test_pass_rate,
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let mut metrics: Vec<TaskMetrics> = (1..=5)
+            .flat_map(|i| [metric(i, "baseline", 50.0), metric(i, "aicms", 50.0 + 10.0 * i as f64)])
+            .collect();
+        // Repetitions are averaged per task; a task run in one mode only is not a pair
+        metrics.push(metric(1, "aicms", 60.0));
+        metrics.push(metric(6, "baseline", 0.0));
+
+        let tests = paired_significance(&metrics);
+        assert_eq!(tests.len(), 4);
+        let test_pass_rate = &tests[1];
+        assert_eq!(test_pass_rate.label(), "Test pass rate");
+        assert_eq!(test_pass_rate.pairs, 5);
+        assert!((test_pass_rate.mean_difference - 30.0).abs() < 0.01);
+        assert!((test_pass_rate.t_test_p.unwrap() - 0.01324).abs() < 1e-4);
+        assert!((test_pass_rate.wilcoxon_p.unwrap() - 0.0625).abs() < 1e-9);
+        // No task compiled in either mode
+        assert_eq!((tests[0].t_test_p, tests[0].wilcoxon_p), (None, None));
+
+        assert!(paired_significance(&metrics[..1]).is_empty());
+    }
+
+    #[test]
+    fn test_skill_variants_pair_with_shared_baseline() {
+        let metric =
+            |task: usize, mode: &str, skill: Option<&str>, test_pass_rate: f64| TaskMetrics {
+                task_id: format!("t{}", task),
+                mode: mode.to_string(),
+                skill: skill.map(str::to_string),
+                test_pass_rate,
+                ..TaskMetrics::from_evaluation(
+                    &crate::evaluator::EvaluationResult::default(),
+                    0,
+                    0,
+                    0,
+                )
+            };
+        let metrics: Vec<TaskMetrics> = (1..=3)
+            .flat_map(|i| {
+                [
+                    metric(i, "baseline", None, 50.0),
+                    metric(i, "aicms", Some("terse"), 50.0 + 10.0 * i as f64),
+                    metric(i, "aicms", Some("verbose"), 40.0),
+                ]
+            })
+            .collect();
+
+        // Each variant is its own pair against the shared baseline, not pooled with the other
+        let tests = paired_significance(&metrics);
+        assert_eq!(tests[1].pairs, 6);
+        let rows = head_to_head(&metrics);
+        assert_eq!(rows.len(), 6);
+        let terse: Vec<_> = rows
+            .iter()
+            .filter(|r| r.skill.as_deref() == Some("terse"))
+            .collect();
+        assert_eq!(terse.len(), 3);
+        assert!(terse
+            .iter()
+            .all(|r| (r.baseline.test_pass_rate - 50.0).abs() < 0.01));
+
+        let by_skill = aggregate_by_skill(&metrics);
+        assert_eq!(by_skill.len(), 2);
+        let terse = &by_skill[0].significance[1];
+        assert_eq!(terse.pairs, 3);
+        assert!((terse.mean_difference - 20.0).abs() < 0.01);
+        let verbose = &by_skill[1].significance[1];
+        assert_eq!(verbose.pairs, 3);
+        assert!((verbose.mean_difference + 10.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_overall_intervals() {
+        let metric = |task: usize, compiled: bool| TaskMetrics {
+            task_id: format!("t{}", task),
+            mode: "aicms".to_string(),
+            compiled,
+            test_pass_rate: if compiled { 80.0 } else { 0.0 },
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let metrics: Vec<TaskMetrics> = (0..10).map(|i| metric(i, i < 7)).collect();
+
+        let results = MetricsAggregator::new().aggregate(&metrics, &[], "sonnet", 1);
+        let aicms = &results.overall.aicms;
+        let ci = aicms.compilation_rate_ci.unwrap();
+        assert!(ci.lower < 70.0 && ci.upper > 70.0 && ci.upper <= 100.0);
+        assert!(aicms.test_pass_rate_ci.is_some());
+        // No executions, no interval
+        assert_eq!(results.overall.baseline.compilation_rate_ci, None);
+
+        let disabled = MetricsAggregator::from_config(&StatisticsConfig {
+            bootstrap_resamples: 0,
+            ..Default::default()
+        });
+        let results = disabled.aggregate(&metrics, &[], "sonnet", 1);
+        assert_eq!(results.overall.aicms.compilation_rate_ci, None);
+    }
+
+    #[test]
+    fn test_effect_sizes() {
+        let metric = |mode: &str, model: &str, compiled: bool| TaskMetrics {
+            task_id: "t".to_string(),
+            mode: mode.to_string(),
+            model: model.to_string(),
+            compiled,
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let metrics = vec![
+            metric("baseline", "sonnet", false),
+            metric("baseline", "sonnet", true),
+            metric("aicms", "sonnet", true),
+            metric("aicms", "sonnet", true),
+            metric("aicms", "haiku", true),
+        ];
+
+        let results = MetricsAggregator::new().aggregate(&metrics, &[], "sonnet", 1);
+        let delta = results.overall.delta.unwrap();
+        assert_eq!(delta.effect_sizes.len(), 4);
+        let compilation = delta.effect_size("compilation_rate").unwrap();
+        // Three AICMS executions tie with one baseline execution and beat the other
+        assert!((compilation.cliffs_delta.unwrap() - 0.5).abs() < 1e-9);
+        assert_eq!(compilation.magnitude(), "large");
+        assert!(compilation.cohens_d.unwrap() > 0.0);
+        // No execution differs in lint compliance
+        let lint = delta.effect_size("lint_compliance").unwrap();
+        assert_eq!((lint.cohens_d, lint.cliffs_delta), (None, Some(0.0)));
+
+        // Per model, too; haiku has no baseline executions and no delta
+        let (sonnet, haiku) = (&results.by_model[0], &results.by_model[1]);
+        assert_eq!(sonnet.model, "sonnet");
+        assert_eq!(haiku.model, "haiku");
+        assert_eq!(sonnet.delta.as_ref().unwrap().effect_sizes.len(), 4);
+        assert!(haiku.delta.is_none());
+    }
+
+    #[test]
+    fn test_repetition_spread_groups_by_task() {
+        let metric = |task: &str, model: &str, repetition: u32, test_pass_rate: f64| TaskMetrics {
+            task_id: task.to_string(),
+            mode: "aicms".to_string(),
+            model: model.to_string(),
+            repetition,
+            test_pass_rate,
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let metrics = [
+            metric("t1", "sonnet", 1, 40.0),
+            metric("t1", "sonnet", 2, 60.0),
+            metric("t2", "sonnet", 1, 100.0),
+            metric("t2", "sonnet", 2, 100.0),
+            // Another model's run of t1 is not a repetition of sonnet's
+            metric("t1", "haiku", 1, 0.0),
+        ];
+        let refs: Vec<&TaskMetrics> = metrics.iter().collect();
+
+        let repeated = repeated_tasks(&refs);
+        assert_eq!(repeated.len(), 2);
+        let spread = repetition_spread(&repeated);
+        assert_eq!(spread.len(), 4);
+        let test_pass_rate = &spread[1];
+        assert_eq!(test_pass_rate.metric, "test_pass_rate");
+        assert_eq!(test_pass_rate.tasks, 2);
+        // Sample variances 200 and 0
+        assert!((test_pass_rate.std_dev - 10.0).abs() < 1e-9);
+        assert!((test_pass_rate.min - 70.0).abs() < 1e-9);
+        assert!((test_pass_rate.max - 80.0).abs() < 1e-9);
+
+        let reproducibility = Reproducibility::from_repetitions(&repeated).unwrap();
+        assert!((reproducibility.consistent_pass_rate - 50.0).abs() < 1e-9);
+        assert!((reproducibility.avg_pass_rate_range - 10.0).abs() < 1e-9);
+
+        assert!(repeated_tasks(&refs[4..]).is_empty());
+        assert!(repetition_spread(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_flaky_tasks_flip_within_a_mode() {
+        let metric = |task: &str, mode: &str, compiled: bool, test_pass_rate: f64| TaskMetrics {
+            task_id: task.to_string(),
+            mode: mode.to_string(),
+            compiled,
+            test_pass_rate,
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let metrics = vec![
+            metric("t1", "aicms", true, 100.0),
+            metric("t1", "aicms", false, 0.0),
+            metric("t2", "baseline", true, 100.0),
+            metric("t2", "baseline", true, 50.0),
+            // Failing every time is consistent, not flaky
+            metric("t3", "baseline", false, 0.0),
+            metric("t3", "baseline", false, 0.0),
+            // A different outcome in the other mode is not a flip
+            metric("t4", "baseline", false, 0.0),
+            metric("t4", "aicms", true, 100.0),
+        ];
+
+        let results = MetricsAggregator::new().aggregate(&metrics, &[], "sonnet", 2);
+        let flaky: Vec<(&str, &str, bool, bool)> = results
+            .flaky_tasks
+            .iter()
+            .map(|t| {
+                let flips = (t.compilation_flipped(), t.tests_flipped());
+                (t.task_id.as_str(), t.mode.as_str(), flips.0, flips.1)
+            })
+            .collect();
+        assert_eq!(
+            flaky,
+            vec![("t1", "aicms", true, true), ("t2", "baseline", false, true)]
+        );
+        assert_eq!(results.flaky_tasks[1].passed, 1);
+    }
+
+    #[test]
+    fn test_breakdowns_follow_the_tasks_that_ran() {
+        use crate::corpus::{Difficulty, Language, TaskCategory};
+
+        let task = |id: &str, language: Language, difficulty: Difficulty| Task {
+            id: id.to_string(),
+            name: id.to_string(),
+            category: TaskCategory::Bugfix,
+            language,
+            difficulty,
+            ..Default::default()
+        };
+        let metric = |task: &str, mode: &str| TaskMetrics {
+            task_id: task.to_string(),
+            mode: mode.to_string(),
+            compiled: mode == "aicms",
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let mut tasks = vec![
+            task("java-1", Language::Java, Difficulty::Hard),
+            task("go-1", Language::Go, Difficulty::Easy),
+            task("rust-1", Language::Rust, Difficulty::Hard),
+        ];
+        // A repeated tag counts once; an unrun task's tag is left out
+        tasks[0].tags = vec!["parsing".into(), "api-design".into(), "parsing".into()];
+        tasks[1].tags = vec!["parsing".into()];
+        tasks[2].tags = vec!["concurrency".into()];
+        let metrics = vec![
+            metric("java-1", "baseline"),
+            metric("java-1", "aicms"),
+            metric("go-1", "aicms"),
+            // Not in the corpus: left out of the breakdowns
+            metric("gone-1", "aicms"),
+        ];
+
+        let results = MetricsAggregator::new().aggregate(&metrics, &tasks, "sonnet", 1);
+
+        // Only languages with executions, in declaration order
+        let languages: Vec<_> = results
+            .by_language
+            .iter()
+            .map(|l| l.language.as_str())
+            .collect();
+        assert_eq!(languages, ["go", "java"]);
+        let java = &results.by_language[1];
+        assert_eq!((java.baseline.task_count, java.aicms.task_count), (1, 1));
+        assert!((java.aicms.compilation_rate - 100.0).abs() < 0.01);
+
+        let difficulties: Vec<_> = results
+            .by_difficulty
+            .iter()
+            .map(|d| d.difficulty.as_str())
+            .collect();
+        assert_eq!(difficulties, ["easy", "hard"]);
+        let categories: Vec<_> = results
+            .by_category
+            .iter()
+            .map(|c| c.category.as_str())
+            .collect();
+        assert_eq!(categories, ["bugfix"]);
+        assert_eq!(results.by_category[0].aicms.task_count, 2);
+        assert!(results.difficulty_normalized.is_none());
+
+        let tags: Vec<_> = results
+            .by_tag
+            .iter()
+            .map(|t| (t.tag.as_str(), t.baseline.task_count, t.aicms.task_count))
+            .collect();
+        assert_eq!(tags, [("api-design", 1, 1), ("parsing", 1, 2)]);
+
+        let config = StatisticsConfig {
+            difficulty_weights: BTreeMap::from([
+                ("easy".to_string(), 1.0),
+                ("hard".to_string(), 3.0),
+            ]),
+            ..Default::default()
+        };
+        let results =
+            MetricsAggregator::from_config(&config).aggregate(&metrics, &tasks, "sonnet", 1);
+        // The baseline has no easy executions, so only hard ones are weighted
+        let normalized = results.difficulty_normalized.unwrap();
+        assert_eq!(normalized.missing, vec!["easy"]);
+        assert_eq!(normalized.metrics[0].aicms, Some(100.0));
+    }
+
+    #[test]
+    fn test_head_to_head_pairs_tasks_run_in_both_modes() {
+        use crate::evaluator::{ClaudeScorerTrait, MockClaudeScorer};
+
+        let metric = |task: &str, mode: &str, compiled: bool, tokens: u32| TaskMetrics {
+            task_id: task.to_string(),
+            mode: mode.to_string(),
+            compiled,
+            test_pass_rate: if compiled { 100.0 } else { 0.0 },
+            input_tokens: tokens,
+            output_tokens: tokens,
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let metrics = vec![
+            metric("t1", "baseline", true, 100),
+            metric("t1", "aicms", true, 150),
+            metric("t1", "aicms", false, 250),
+            metric("t2", "baseline", false, 100),
+            metric("t2", "aicms", true, 100),
+            // Only run in one mode: no head-to-head
+            metric("t3", "aicms", true, 100),
+        ];
+
+        let aggregator = MetricsAggregator::new();
+        let mut results = aggregator.aggregate(&metrics, &[], "sonnet", 2);
+        assert_eq!(results.head_to_head.len(), 2);
+        let t1 = &results.head_to_head[0];
+        assert_eq!((t1.task_id.as_str(), t1.model.as_str()), ("t1", "sonnet"));
+        assert_eq!(t1.aicms.executions, 2);
+        assert!((t1.aicms.compilation_rate - 50.0).abs() < 1e-9);
+        assert!((t1.aicms.avg_tokens - 400.0).abs() < 1e-9);
+        assert!(t1.regressed());
+        assert!(!results.head_to_head[1].regressed());
+
+        let score = MockClaudeScorer::with_defaults()
+            .compare_dirs("spec", std::path::Path::new("b"), std::path::Path::new("a"))
+            .unwrap();
+        let comparison = TaskComparison {
+            task_id: "t2".to_string(),
+            comparison: crate::evaluator::ComparisonScore {
+                winner: "aicms".to_string(),
+                ..score
+            },
+        };
+        aggregator.add_claude_comparisons(&mut results, vec![comparison], &[]);
+        assert_eq!(results.head_to_head[0].winner, None);
+        assert_eq!(results.head_to_head[1].winner.as_deref(), Some("aicms"));
+    }
+
+    #[test]
+    fn test_comparisons_are_broken_down_by_task() {
+        use crate::corpus::{Difficulty, Language};
+        use crate::evaluator::{ClaudeScorerTrait, MockClaudeScorer};
+
+        let task = |id: &str, language: Language, difficulty: Difficulty| Task {
+            id: id.to_string(),
+            name: id.to_string(),
+            language,
+            difficulty,
+            ..Default::default()
+        };
+        let tasks = vec![
+            task("rust-1", Language::Rust, Difficulty::Hard),
+            task("go-1", Language::Go, Difficulty::Easy),
+            task("go-2", Language::Go, Difficulty::Hard),
+        ];
+        let score = MockClaudeScorer::with_defaults()
+            .compare_dirs("spec", std::path::Path::new("b"), std::path::Path::new("a"))
+            .unwrap();
+        let comparison = |task: &str, winner: &str, aicms: u8| {
+            let mut comparison = crate::evaluator::ComparisonScore {
+                winner: winner.to_string(),
+                ..score.clone()
+            };
+            comparison.baseline.overall = 50;
+            comparison.aicms.overall = aicms;
+            TaskComparison {
+                task_id: task.to_string(),
+                comparison,
+            }
+        };
+        let comparisons = vec![
+            comparison("rust-1", "aicms", 80),
+            comparison("go-1", "baseline", 40),
+            comparison("go-2", "tie", 50),
+            // Not in the corpus: only in the overall stats
+            comparison("gone-1", "aicms", 90),
+        ];
+
+        let aggregator = MetricsAggregator::new();
+        let mut results = aggregator.aggregate(&[], &tasks, "sonnet", 1);
+        aggregator.add_claude_comparisons(&mut results, comparisons, &tasks);
+        let stats = results.claude_stats.unwrap();
+        assert_eq!(stats.aicms_wins, 2);
+
+        let groups = |groups: &[ComparisonGroupStats]| -> Vec<(String, u32, f64)> {
+            groups
+                .iter()
+                .map(|g| (g.group.clone(), g.comparisons(), g.win_rate()))
+                .collect()
+        };
+        assert_eq!(
+            groups(&stats.by_language),
+            [("rust".to_string(), 1, 100.0), ("go".to_string(), 2, 25.0)]
+        );
+        assert_eq!(
+            groups(&stats.by_difficulty),
+            [("easy".to_string(), 1, 0.0), ("hard".to_string(), 2, 75.0)]
+        );
+        assert_eq!(stats.by_category.len(), 1);
+        let go = &stats.by_language[1];
+        assert!((go.avg_baseline_score - 50.0).abs() < 1e-9);
+        assert!((go.avg_aicms_score - 45.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_custom_metrics_follow_their_rules() {
+        let metric = |mode: &str, custom: &[(&str, f64)]| TaskMetrics {
+            mode: mode.to_string(),
+            custom: custom.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let metrics = vec![
+            metric("baseline", &[("todos", 1.0), ("peak_mb", 10.0)]),
+            metric("baseline", &[("todos", 3.0), ("peak_mb", 30.0), ("score", 2.0)]),
+            metric("aicms", &[("todos", 1.0), ("peak_mb", 5.0), ("score", 4.0)]),
+            // Executions without a metric do not count towards it
+            metric("aicms", &[]),
+        ];
+
+        let config = StatisticsConfig {
+            custom_metrics: BTreeMap::from([("todos".to_string(), CustomAggregation::Sum)]),
+            ..Default::default()
+        };
+        let aggregator = MetricsAggregator::from_config(&config)
+            .with_custom_metric("peak_mb", CustomAggregation::Max);
+        let results = aggregator.aggregate(&metrics, &[], "sonnet", 1);
+
+        let baseline = &results.overall.baseline.custom;
+        assert_eq!(baseline["todos"], 4.0);
+        assert_eq!(baseline["peak_mb"], 30.0);
+        assert_eq!(baseline["score"], 2.0);
+        let aicms = &results.overall.aicms.custom;
+        assert_eq!(
+            (aicms["todos"], aicms["peak_mb"], aicms["score"]),
+            (1.0, 5.0, 4.0)
+        );
+        let delta = &results.overall.delta.as_ref().unwrap().custom;
+        assert_eq!(delta["todos"], -3.0);
+        assert_eq!(delta["score"], 2.0);
+        // Breakdowns stay on the built-in metrics
+        assert!(results.by_model[0].aicms.custom.is_empty());
+    }
+
+    #[test]
+    fn test_outliers_are_reported_and_left_out() {
+        let metric = |task: &str, mode: &str, pass_rate: f64| TaskMetrics {
+            task_id: task.to_string(),
+            mode: mode.to_string(),
+            code_extracted: true,
+            compiled: true,
+            test_pass_rate: pass_rate,
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let mut metrics = Vec::new();
+        for (task, rate) in [("t1", 90.0), ("t2", 95.0), ("t3", 100.0), ("t4", 85.0)] {
+            metrics.push(metric(task, "baseline", rate));
+            metrics.push(metric(task, "aicms", rate));
+        }
+        // Far below the others, and no code at all
+        metrics.push(metric("t5", "baseline", 10.0));
+        metrics.push(metric("t5", "aicms", 10.0));
+        for mode in ["baseline", "aicms"] {
+            metrics.push(TaskMetrics {
+                code_extracted: false,
+                compiled: false,
+                ..metric("t6", mode, 0.0)
+            });
+        }
+
+        let results = MetricsAggregator::new().aggregate(&metrics, &[], "sonnet", 1);
+        let outliers = results.outliers.unwrap();
+        let reasons: Vec<(&str, OutlierReason)> = outliers
+            .tasks
+            .iter()
+            .map(|t| (t.task_id.as_str(), t.reason))
+            .collect();
+        assert_eq!(
+            reasons,
+            vec![
+                ("t5", OutlierReason::FarBelow),
+                ("t6", OutlierReason::NoCode)
+            ]
+        );
+        assert_eq!(outliers.tasks[1].outcome.executions, 2);
+        assert_eq!(outliers.without.baseline.task_count, 4);
+        assert!((outliers.without.aicms.avg_test_pass_rate - 92.5).abs() < 1e-9);
+        assert!((results.overall.aicms.avg_test_pass_rate - 380.0 / 6.0).abs() < 1e-9);
+
+        // Without spread between the other tasks, a slightly lower one is no outlier
+        let even: Vec<TaskMetrics> = ["t1", "t2", "t3", "t4", "t5"]
+            .iter()
+            .map(|task| metric(task, "aicms", if *task == "t5" { 95.0 } else { 100.0 }))
+            .collect();
+        assert!(MetricsAggregator::new()
+            .aggregate(&even, &[], "sonnet", 1)
+            .outliers
+            .is_none());
+    }
+
+    #[test]
+    fn test_failures_are_counted_per_mode() {
+        let metric = |task: &str, mode: &str, failure: Option<FailureReason>| TaskMetrics {
+            task_id: task.to_string(),
+            mode: mode.to_string(),
+            failure,
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let metrics = vec![
+            metric("t1", "baseline", Some(FailureReason::CompileError)),
+            metric("t2", "baseline", Some(FailureReason::CompileError)),
+            metric("t3", "baseline", None),
+            metric("t1", "aicms", Some(FailureReason::Timeout)),
+            metric("t2", "aicms", None),
+            metric("t3", "aicms", None),
+        ];
+        let aggregator = MetricsAggregator::new();
+        let mut results = aggregator.aggregate(&metrics, &[], "sonnet", 1);
+        assert_eq!(
+            results.overall.baseline.failures,
+            BTreeMap::from([(FailureReason::CompileError, 2)])
+        );
+        assert!(results.by_model[0].baseline.failures.is_empty());
+
+        // Only executions that had not failed already become judge parse errors
+        aggregator.add_judge_failures(&mut results, &["t1".to_string(), "t3".to_string()]);
+        assert_eq!(
+            results.overall.baseline.failures,
+            BTreeMap::from([
+                (FailureReason::CompileError, 2),
+                (FailureReason::JudgeParseError, 1)
+            ])
+        );
+        assert_eq!(
+            results.overall.aicms.failures,
+            BTreeMap::from([
+                (FailureReason::Timeout, 1),
+                (FailureReason::JudgeParseError, 1)
+            ])
+        );
+    }
+
+    #[test]
+    fn test_missing_data_policy_applies_to_aggregates() {
+        let metric = |task: &str, code_extracted: bool, test_pass_rate: f64| TaskMetrics {
+            task_id: task.to_string(),
+            mode: "aicms".to_string(),
+            code_extracted,
+            compiled: code_extracted,
+            test_pass_rate,
+            ..TaskMetrics::from_evaluation(&crate::evaluator::EvaluationResult::default(), 0, 0, 0)
+        };
+        let metrics = vec![
+            metric("t1", true, 80.0),
+            metric("t1", true, 60.0),
+            metric("t1", false, 0.0),
+            // No repetition produced code: nothing to impute from
+            metric("t2", false, 0.0),
+        ];
+        let aggregate = |policy: MissingDataPolicy| {
+            let config = StatisticsConfig {
+                missing_data: policy,
+                ..Default::default()
+            };
+            MetricsAggregator::from_config(&config).aggregate(&metrics, &[], "sonnet", 3)
+        };
+
+        let zero = aggregate(MissingDataPolicy::Zero);
+        assert_eq!(zero.overall.aicms.task_count, 4);
+        assert!((zero.overall.aicms.avg_test_pass_rate - 35.0).abs() < 0.01);
+        assert_eq!(zero.missing_data.executions, 2);
+        assert_eq!(
+            zero.missing_data.describe(),
+            "zero (2 executions without code counted as 0%)"
+        );
+
+        let exclude = aggregate(MissingDataPolicy::Exclude);
+        assert_eq!(exclude.overall.aicms.task_count, 2);
+        assert!((exclude.overall.aicms.avg_test_pass_rate - 70.0).abs() < 0.01);
+        assert_eq!(exclude.missing_data.excluded, 2);
+
+        let impute = aggregate(MissingDataPolicy::Impute);
+        assert_eq!(impute.overall.aicms.task_count, 3);
+        assert!((impute.overall.aicms.compilation_rate - 100.0).abs() < 0.01);
+        assert!((impute.overall.aicms.avg_test_pass_rate - 70.0).abs() < 0.01);
+        assert_eq!(
+            impute.missing_data.describe(),
+            "impute (2 executions without code: 1 imputed, 1 left out)"
+        );
+
+        // The results keep every execution as it ran
+        assert_eq!(impute.task_metrics.len(), 4);
+        assert!(!impute.task_metrics[2].compiled);
+    }
+
+    #[test]
+    fn test_repair_curve_carries_final_turn() {
+        let turn = |turn: u32, compiled: bool| crate::runner::RepairTurn {
+            turn,
+            compiled,
+            test_pass_rate: if compiled { 100.0 } else { 0.0 },
+            input_tokens: 0,
+            output_tokens: 0,
+            execution_time_ms: 0,
+        };
+        let metric = |turns: Vec<crate::runner::RepairTurn>| TaskMetrics {
+            repair_turns: turns,
+            ..TaskMetrics::from_evaluation(
+                &crate::evaluator::EvaluationResult {
+                    task_id: "t1".to_string(),
+                    mode: "aicms".to_string(),
+                    repetition: 0,
+                    compilation: None,
+                    tests: None,
+                    test_determinism: None,
+                    hidden_tests: None,
+                    io_cases: None,
+                    api_conformance: None,
+                    reference: None,
+                    inference: None,
+                    code_quality: None,
+                    type_check: None,
+                    security: None,
+                    complexity: None,
+                    code_size: None,
+                    mutation: None,
+                    test_design: None,
+                    lint: None,
+                    annotation_score: None,
+                    spec_leakage: None,
+                    contamination: None,
+                    fingerprint: None,
+                    contract_checks: vec![],
+                    custom: BTreeMap::new(),
+                    extracted_code: None,
+                    extracted_files: None,
+                },
+                0,
+                0,
+                0,
+            )
+        };
+
+        let curve = aggregate_repair_curve(&[
+            metric(vec![turn(0, true)]),
+            metric(vec![turn(0, false), turn(1, false), turn(2, true)]),
+        ]);
+
+        assert_eq!(curve.len(), 3);
+        assert!((curve[0].aicms_compilation_rate - 50.0).abs() < 0.01);
+        assert!((curve[2].aicms_compilation_rate - 100.0).abs() < 0.01);
+        assert!(curve[0].baseline_compilation_rate.abs() < 0.01);
     }
 }
@@ -1,12 +1,27 @@
 //! @ai:module:intent Metrics collection and aggregation
 //! @ai:module:layer application
-//! @ai:module:public_api TaskMetrics, AggregateStats, BenchmarkResults, MetricsAggregator, TaskComparison, ClaudeComparisonStats
+//! @ai:module:public_api TaskMetrics, AggregateStats, BenchmarkResults, MetricsAggregator, TaskComparison, ClaudeComparisonStats,
+//!                        ModelStats, SamplingStats, SkillStats, JudgeAgreement, RankingStats,
+//!                        PairedTest, ConfidenceInterval, EffectSize, RepetitionSpread, Reproducibility,
+//!                        Percentiles, TaskOutcome, TaskHeadToHead, BinomialTest, CompositeScore,
+//!                        OutlierTask, OutlierStats, FailureReason, DifficultyNormalized,
+//!                        FlakyTask, TagStats, MissingData, ComparisonGroupStats, ResultsDiff,
+//!                        RegressionThresholds, diff_results
 
 pub mod aggregator;
+pub mod regression;
+pub mod statistics;
 pub mod types;
 
 pub use aggregator::{MetricsAggregator, MetricsAggregatorTrait};
+pub use regression::{
+    diff_results, Change, MetricDiff, RegressionThresholds, ResultsDiff, TaskDiff,
+};
 pub use types::{
-    AggregateStats, BenchmarkResults, CategoryStats, ClaudeComparisonStats, DeltaStats,
-    DifficultyStats, LanguageStats, ModeComparison, TaskComparison, TaskMetrics,
+    AggregateStats, BenchmarkResults, BinomialTest, CategoryStats, ClaudeComparisonStats,
+    ComparisonGroupStats, CompositeScore, ConfidenceInterval, DeltaStats, DifficultyNormalized,
+    DifficultyStats, EffectSize, FailureReason, FlakyTask, JudgeAgreement, LanguageStats,
+    MissingData, ModeComparison, ModelStats, NormalizedMetric, OutlierReason, OutlierStats,
+    OutlierTask, PairedTest, Percentiles, RankingStats, RepetitionSpread, Reproducibility,
+    SamplingStats, SkillStats, TagStats, TaskComparison, TaskHeadToHead, TaskMetrics, TaskOutcome,
 };
@@ -0,0 +1,360 @@
+//! @ai:module:intent Compare two sets of results and flag per-metric and per-task regressions
+//!                   beyond configurable thresholds (the CI gate of diff-results)
+//! @ai:module:layer domain
+//! @ai:module:public_api RegressionThresholds, Change, MetricDiff, TaskDiff, ResultsDiff,
+//!                        diff_results
+//! @ai:module:depends_on metrics::types
+//! @ai:module:stateless true
+
+use crate::metrics::types::{AggregateStats, BenchmarkResults, TaskMetrics, TaskOutcome};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Reads one compared metric from a mode's stats, None when it does not apply
+type MetricValue = fn(&AggregateStats) -> Option<f64>;
+
+/// Compared metrics in report order; rates are better higher, resources better lower
+const DIFFED_METRICS: &[(&str, MetricKind, MetricValue)] = &[
+    ("compilation_rate", MetricKind::Rate, |s| {
+        Some(s.compilation_rate)
+    }),
+    ("test_pass_rate", MetricKind::Rate, |s| {
+        Some(s.avg_test_pass_rate)
+    }),
+    ("lint_compliance", MetricKind::Rate, |s| {
+        Some(s.avg_lint_compliance)
+    }),
+    ("annotation_quality", MetricKind::Rate, |s| {
+        Some(s.avg_annotation_quality)
+    }),
+    ("avg_tokens", MetricKind::Resource, |s| {
+        let tokens = s.total_input_tokens + s.total_output_tokens;
+        Some(tokens as f64 / s.task_count as f64)
+    }),
+    ("avg_execution_time_ms", MetricKind::Resource, |s| {
+        Some(s.avg_execution_time_ms)
+    }),
+    ("avg_cost_usd", MetricKind::Resource, |s| {
+        (s.priced_count > 0).then(|| s.avg_cost_usd())
+    }),
+];
+
+/// @ai:intent How a metric is compared: percentage points up, or relative change down
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MetricKind {
+    Rate,
+    Resource,
+}
+
+/// @ai:intent How far a metric may move before it counts as a regression or improvement
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RegressionThresholds {
+    /// Percentage points a rate (compilation, test pass, lint, annotation) may change
+    pub rate_points: f64,
+    /// Percent a resource (tokens, time, cost) may change relative to the first results
+    pub resource_percent: f64,
+    /// Percentage points a task's compilation or test pass rate may change
+    pub task_points: f64,
+}
+
+impl Default for RegressionThresholds {
+    fn default() -> Self {
+        Self {
+            rate_points: 2.0,
+            resource_percent: 10.0,
+            task_points: 10.0,
+        }
+    }
+}
+
+/// @ai:intent Whether a comparison moved beyond its threshold, and which way
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Change {
+    Regression,
+    Improvement,
+    Unchanged,
+}
+
+impl Change {
+    /// @ai:intent Classify a change where positive is better, given the allowed magnitude
+    /// @ai:effects pure
+    /// @ai:example (-3.0, 2.0) -> Regression
+    fn classify(gain: f64, threshold: f64) -> Self {
+        if gain < -threshold {
+            Self::Regression
+        } else if gain > threshold {
+            Self::Improvement
+        } else {
+            Self::Unchanged
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Regression => "regression",
+            Self::Improvement => "improvement",
+            Self::Unchanged => "unchanged",
+        }
+    }
+}
+
+/// @ai:intent One overall metric of one mode in both results
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricDiff {
+    pub mode: String,
+    pub metric: String,
+    pub before: f64,
+    pub after: f64,
+    pub change: Change,
+}
+
+impl MetricDiff {
+    /// @ai:intent After minus before
+    /// @ai:effects pure
+    pub fn delta(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+/// @ai:intent One mode's results on one task in both results, pooled over models, sampling
+///            settings and repetitions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskDiff {
+    pub task_id: String,
+    pub mode: String,
+    pub before: TaskOutcome,
+    pub after: TaskOutcome,
+    pub change: Change,
+}
+
+/// @ai:intent Everything that moved between two results
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResultsDiff {
+    pub thresholds: RegressionThresholds,
+    /// Every compared metric of every mode present in both results
+    pub metrics: Vec<MetricDiff>,
+    /// Tasks run in the same mode in both results, by task then mode
+    pub tasks: Vec<TaskDiff>,
+    /// Task ids only in the second results, and only in the first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added_tasks: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed_tasks: Vec<String>,
+}
+
+impl ResultsDiff {
+    /// @ai:intent Number of metric and task regressions
+    /// @ai:effects pure
+    pub fn regression_count(&self) -> usize {
+        self.metric_changes(Change::Regression).count()
+            + self.task_changes(Change::Regression).count()
+    }
+
+    /// @ai:intent Metrics that changed the given way
+    /// @ai:effects pure
+    pub fn metric_changes(&self, change: Change) -> impl Iterator<Item = &MetricDiff> {
+        self.metrics.iter().filter(move |m| m.change == change)
+    }
+
+    /// @ai:intent Tasks that changed the given way
+    /// @ai:effects pure
+    pub fn task_changes(&self, change: Change) -> impl Iterator<Item = &TaskDiff> {
+        self.tasks.iter().filter(move |t| t.change == change)
+    }
+}
+
+/// @ai:intent Compare the overall metrics and per-task outcomes of two results
+/// @ai:effects pure
+pub fn diff_results(
+    before: &BenchmarkResults,
+    after: &BenchmarkResults,
+    thresholds: &RegressionThresholds,
+) -> ResultsDiff {
+    let mut metrics = Vec::new();
+    let modes = [
+        (
+            "baseline",
+            &before.overall.baseline,
+            &after.overall.baseline,
+        ),
+        ("aicms", &before.overall.aicms, &after.overall.aicms),
+    ];
+    for (mode, old, new) in modes {
+        if old.task_count == 0 || new.task_count == 0 {
+            continue;
+        }
+        for (metric, kind, value) in DIFFED_METRICS {
+            let (Some(old_value), Some(new_value)) = (value(old), value(new)) else {
+                continue;
+            };
+            let change = match kind {
+                MetricKind::Rate => Change::classify(new_value - old_value, thresholds.rate_points),
+                MetricKind::Resource if old_value == 0.0 => Change::Unchanged,
+                MetricKind::Resource => Change::classify(
+                    (old_value - new_value) / old_value * 100.0,
+                    thresholds.resource_percent,
+                ),
+            };
+            metrics.push(MetricDiff {
+                mode: mode.to_string(),
+                metric: metric.to_string(),
+                before: old_value,
+                after: new_value,
+                change,
+            });
+        }
+    }
+
+    let old_tasks = by_task_and_mode(&before.task_metrics);
+    let new_tasks = by_task_and_mode(&after.task_metrics);
+    let tasks = old_tasks
+        .iter()
+        .filter_map(|(&(task_id, mode), old)| {
+            let new = new_tasks.get(&(task_id, mode))?;
+            let (before, after) = (
+                TaskOutcome::from_executions(old),
+                TaskOutcome::from_executions(new),
+            );
+            // The worse of the two rates decides a regression, the better an improvement
+            let gains = [
+                after.compilation_rate - before.compilation_rate,
+                after.test_pass_rate - before.test_pass_rate,
+            ];
+            let change = match Change::classify(gains[0].min(gains[1]), thresholds.task_points) {
+                Change::Regression => Change::Regression,
+                _ => Change::classify(gains[0].max(gains[1]), thresholds.task_points),
+            };
+            Some(TaskDiff {
+                task_id: task_id.to_string(),
+                mode: mode.to_string(),
+                before,
+                after,
+                change,
+            })
+        })
+        .collect();
+
+    let task_ids = |tasks: &BTreeMap<(&str, &str), _>| -> Vec<String> {
+        let mut ids: Vec<String> = tasks.keys().map(|(id, _)| id.to_string()).collect();
+        ids.dedup();
+        ids
+    };
+    let (old_ids, new_ids) = (task_ids(&old_tasks), task_ids(&new_tasks));
+
+    ResultsDiff {
+        thresholds: *thresholds,
+        metrics,
+        tasks,
+        added_tasks: new_ids
+            .iter()
+            .filter(|id| !old_ids.contains(id))
+            .cloned()
+            .collect(),
+        removed_tasks: old_ids
+            .iter()
+            .filter(|id| !new_ids.contains(id))
+            .cloned()
+            .collect(),
+    }
+}
+
+/// @ai:intent Executions grouped by task and mode, sorted by task id
+/// @ai:effects pure
+fn by_task_and_mode(metrics: &[TaskMetrics]) -> BTreeMap<(&str, &str), Vec<&TaskMetrics>> {
+    let mut groups: BTreeMap<(&str, &str), Vec<&TaskMetrics>> = BTreeMap::new();
+    for m in metrics {
+        groups
+            .entry((m.task_id.as_str(), m.mode.as_str()))
+            .or_default()
+            .push(m);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::EvaluationResult;
+    use crate::metrics::{MetricsAggregator, MetricsAggregatorTrait};
+
+    fn metric(task: &str, mode: &str, pass_rate: f64, tokens: u32) -> TaskMetrics {
+        TaskMetrics {
+            task_id: task.to_string(),
+            mode: mode.to_string(),
+            compiled: true,
+            test_pass_rate: pass_rate,
+            input_tokens: tokens,
+            output_tokens: 0,
+            ..TaskMetrics::from_evaluation(&EvaluationResult::default(), 0, 0, 0)
+        }
+    }
+
+    #[test]
+    fn test_diff_results_flags_regressions() {
+        let aggregator = MetricsAggregator::new();
+        let before = aggregator.aggregate(
+            &[
+                metric("t1", "baseline", 100.0, 100),
+                metric("t1", "aicms", 100.0, 100),
+                metric("t2", "aicms", 50.0, 100),
+                metric("t3", "aicms", 100.0, 100),
+            ],
+            &[],
+            "sonnet",
+            1,
+        );
+        let after = aggregator.aggregate(
+            &[
+                metric("t1", "baseline", 100.0, 105),
+                metric("t1", "aicms", 20.0, 100),
+                metric("t2", "aicms", 100.0, 200),
+                metric("t4", "aicms", 100.0, 100),
+            ],
+            &[],
+            "sonnet",
+            1,
+        );
+
+        let diff = diff_results(&before, &after, &RegressionThresholds::default());
+        let find = |mode: &str, metric: &str| {
+            diff.metrics
+                .iter()
+                .find(|m| m.mode == mode && m.metric == metric)
+                .unwrap()
+        };
+        // Within 10% of the token budget, then well beyond it
+        assert_eq!(find("baseline", "avg_tokens").change, Change::Unchanged);
+        assert_eq!(find("aicms", "avg_tokens").change, Change::Regression);
+        assert_eq!(find("aicms", "test_pass_rate").change, Change::Regression);
+        assert_eq!(find("aicms", "compilation_rate").change, Change::Unchanged);
+        assert!(diff.metrics.iter().all(|m| m.metric != "avg_cost_usd"));
+
+        let tasks: Vec<(&str, &str, Change)> = diff
+            .tasks
+            .iter()
+            .map(|t| (t.task_id.as_str(), t.mode.as_str(), t.change))
+            .collect();
+        assert_eq!(
+            tasks,
+            vec![
+                ("t1", "aicms", Change::Regression),
+                ("t1", "baseline", Change::Unchanged),
+                ("t2", "aicms", Change::Improvement),
+            ]
+        );
+        assert_eq!(diff.added_tasks, vec!["t4"]);
+        assert_eq!(diff.removed_tasks, vec!["t3"]);
+        assert_eq!(diff.regression_count(), 3);
+
+        let lenient = RegressionThresholds {
+            rate_points: 50.0,
+            resource_percent: 100.0,
+            task_points: 90.0,
+        };
+        assert_eq!(
+            diff_results(&before, &after, &lenient).regression_count(),
+            0
+        );
+    }
+}
@@ -0,0 +1,614 @@
+//! @ai:module:intent Statistical tests over paired observations and win counts, effect sizes,
+//!                   pairwise ratings, bootstrap confidence intervals, and the special
+//!                   functions they need
+//! @ai:module:layer domain
+//! @ai:module:public_api paired_t_test, wilcoxon_signed_rank, cohens_d, cliffs_delta,
+//!                        binomial_test, bradley_terry, bootstrap_mean_interval, percentile
+//! @ai:module:stateless true
+
+/// @ai:intent Non-zero differences up to which the Wilcoxon p-value is exact rather than
+///            taken from the normal approximation
+const WILCOXON_EXACT_MAX: usize = 50;
+
+/// @ai:intent Differences smaller than this count as zero (and absolute values as tied)
+const EPSILON: f64 = 1e-9;
+
+/// @ai:intent Iterations after which the Bradley-Terry fit stops even if not converged
+const BRADLEY_TERRY_MAX_ITERATIONS: usize = 1000;
+
+/// @ai:intent Seed of the bootstrap resampling, fixed so that reports are reproducible
+const BOOTSTRAP_SEED: u64 = 0x5eed_b007_57a7_1571;
+
+/// @ai:intent Lanczos coefficients (g = 7, n = 9) for ln_gamma
+const LANCZOS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+/// @ai:intent Coefficients of the polynomial in t = 1 / (1 + |x| / 2) used by erfc
+const ERFC_COEFFICIENTS: [f64; 10] = [
+    -1.265_512_23,
+    1.000_023_68,
+    0.374_091_96,
+    0.096_784_18,
+    -0.186_288_06,
+    0.278_868_07,
+    -1.135_203_98,
+    1.488_515_87,
+    -0.822_152_23,
+    0.170_872_77,
+];
+
+/// @ai:intent Numerator coefficients of Acklam's normal quantile approximation (central region)
+const QUANTILE_A: [f64; 6] = [
+    -39.696_830_286_653_76,
+    220.946_098_424_520_5,
+    -275.928_510_446_968_7,
+    138.357_751_867_269,
+    -30.664_798_066_147_16,
+    2.506_628_277_459_239,
+];
+
+/// @ai:intent Denominator coefficients of the central region
+const QUANTILE_B: [f64; 5] = [
+    -54.476_098_798_224_06,
+    161.585_836_858_040_9,
+    -155.698_979_859_886_6,
+    66.801_311_887_719_72,
+    -13.280_681_552_885_72,
+];
+
+/// @ai:intent Numerator coefficients of the tails
+const QUANTILE_C: [f64; 6] = [
+    -0.007_784_894_002_430_293,
+    -0.322_396_458_041_136_4,
+    -2.400_758_277_161_838,
+    -2.549_732_539_343_734,
+    4.374_664_141_464_968,
+    2.938_163_982_698_783,
+];
+
+/// @ai:intent Denominator coefficients of the tails
+const QUANTILE_D: [f64; 4] = [
+    0.007_784_695_709_041_462,
+    0.322_467_129_070_039_8,
+    2.445_134_137_142_996,
+    3.754_408_661_907_416,
+];
+
+/// @ai:intent Two-sided p-value of the paired t-test that the mean difference is zero
+/// @ai:post None with fewer than two differences or when all differences are equal
+/// @ai:effects pure
+/// @ai:example ([1.0, 2.0, 3.0, 4.0, 5.0]) -> Some(0.0132)
+pub fn paired_t_test(differences: &[f64]) -> Option<f64> {
+    let n = differences.len();
+    if n < 2 {
+        return None;
+    }
+
+    let mean = differences.iter().sum::<f64>() / n as f64;
+    let variance = differences.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+    if variance < EPSILON {
+        return None;
+    }
+
+    let t = mean / (variance / n as f64).sqrt();
+    let df = (n - 1) as f64;
+    Some(incomplete_beta(df / 2.0, 0.5, df / (df + t * t)))
+}
+
+/// @ai:intent Two-sided p-value of the Wilcoxon signed-rank test that the differences are
+///            symmetric around zero. Zero differences are dropped and tied absolute values
+///            share their average rank; exact up to WILCOXON_EXACT_MAX differences, normal
+///            approximation with continuity correction above
+/// @ai:post None when every difference is zero
+/// @ai:effects pure
+/// @ai:example ([1.0, 2.0, 3.0, 4.0, 5.0]) -> Some(0.0625)
+pub fn wilcoxon_signed_rank(differences: &[f64]) -> Option<f64> {
+    let mut nonzero: Vec<f64> = differences
+        .iter()
+        .copied()
+        .filter(|d| d.abs() > EPSILON)
+        .collect();
+    if nonzero.is_empty() {
+        return None;
+    }
+    nonzero.sort_by(|a, b| a.abs().total_cmp(&b.abs()));
+
+    // Average ranks of ties, doubled so they stay integers
+    let n = nonzero.len();
+    let mut ranks = vec![0usize; n];
+    let mut start = 0;
+    while start < n {
+        let mut end = start;
+        while end + 1 < n && nonzero[end + 1].abs() - nonzero[start].abs() < EPSILON {
+            end += 1;
+        }
+        ranks[start..=end].fill(start + end + 2);
+        start = end + 1;
+    }
+
+    let positive: usize = (0..n).filter(|&i| nonzero[i] > 0.0).map(|i| ranks[i]).sum();
+    let total: usize = ranks.iter().sum();
+
+    if n <= WILCOXON_EXACT_MAX {
+        // Sign assignments by the sum of their positive (doubled) ranks
+        let mut counts = vec![0.0f64; total + 1];
+        counts[0] = 1.0;
+        for &rank in &ranks {
+            for sum in (rank..=total).rev() {
+                counts[sum] += counts[sum - rank];
+            }
+        }
+        let tail = positive.min(total - positive);
+        let p = 2.0 * counts[..=tail].iter().sum::<f64>() / 2f64.powi(n as i32);
+        return Some(p.min(1.0));
+    }
+
+    // In doubled units: mean total/2, variance sum(rank^2)/4, continuity correction 1
+    let sd = (ranks.iter().map(|&r| (r * r) as f64).sum::<f64>() / 4.0).sqrt();
+    let z = ((positive as f64 - total as f64 / 2.0).abs() - 1.0).max(0.0) / sd;
+    Some(erfc(z / std::f64::consts::SQRT_2).min(1.0))
+}
+
+/// @ai:intent Cohen's d of treatment against control: the difference of the means in units of
+///            the pooled standard deviation
+/// @ai:post None when the two samples hold fewer than three values together or every value
+///          within each sample is the same
+/// @ai:effects pure
+/// @ai:example ([1.0, 2.0, 3.0], [2.0, 3.0, 4.0]) -> Some(1.0)
+pub fn cohens_d(control: &[f64], treatment: &[f64]) -> Option<f64> {
+    let (n1, n2) = (control.len(), treatment.len());
+    if n1 == 0 || n2 == 0 || n1 + n2 < 3 {
+        return None;
+    }
+
+    let mean = |values: &[f64]| values.iter().sum::<f64>() / values.len() as f64;
+    let squares =
+        |values: &[f64], mean: f64| values.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+    let (m1, m2) = (mean(control), mean(treatment));
+    let pooled_variance = (squares(control, m1) + squares(treatment, m2)) / (n1 + n2 - 2) as f64;
+    if pooled_variance < EPSILON {
+        return None;
+    }
+    Some((m2 - m1) / pooled_variance.sqrt())
+}
+
+/// @ai:intent Cliff's delta of treatment against control: how often a treatment value exceeds
+///            a control value minus how often it falls below, over all pairs (-1 to 1)
+/// @ai:post None when either sample is empty
+/// @ai:effects pure
+/// @ai:example ([0.0, 100.0], [100.0, 100.0]) -> Some(0.5)
+pub fn cliffs_delta(control: &[f64], treatment: &[f64]) -> Option<f64> {
+    if control.is_empty() || treatment.is_empty() {
+        return None;
+    }
+
+    let dominance: i64 = treatment
+        .iter()
+        .flat_map(|t| control.iter().map(move |c| t - c))
+        .map(|d| match d {
+            d if d > EPSILON => 1,
+            d if d < -EPSILON => -1,
+            _ => 0,
+        })
+        .sum();
+    Some(dominance as f64 / (control.len() * treatment.len()) as f64)
+}
+
+/// @ai:intent Two-sided p-value of the exact binomial test that successes out of trials
+///            come from a success rate of 50% (the sign test)
+/// @ai:pre successes <= trials
+/// @ai:post None with no trials
+/// @ai:effects pure
+/// @ai:example (9, 10) -> Some(0.0215)
+pub fn binomial_test(successes: u32, trials: u32) -> Option<f64> {
+    if trials == 0 {
+        return None;
+    }
+
+    // The distribution is symmetric at 50%, so the p-value is twice the smaller tail
+    let k = successes.min(trials - successes);
+    if 2 * k >= trials {
+        return Some(1.0);
+    }
+    // P(X <= k) for X ~ Binomial(trials, 0.5)
+    let tail = incomplete_beta((trials - k) as f64, (k + 1) as f64, 0.5);
+    Some((2.0 * tail).min(1.0))
+}
+
+/// @ai:intent Bradley-Terry strengths of players from their pairwise results, fitted with
+///            Hunter's MM algorithm: i beats j with probability s_i / (s_i + s_j). Every
+///            player also gets one win and one loss against a virtual opponent of strength 1,
+///            so unbeaten and winless players get finite strengths
+/// @ai:pre wins is square; wins[i][j] is how often i beat j, a tie counting half for each
+/// @ai:post Positive strengths with geometric mean 1; empty for no players
+/// @ai:effects pure
+/// @ai:example ([[0.0, 1.0], [1.0, 0.0]]) -> [1.0, 1.0]
+pub fn bradley_terry(wins: &[Vec<f64>]) -> Vec<f64> {
+    let n = wins.len();
+    let mut strengths = vec![1.0; n];
+    for _ in 0..BRADLEY_TERRY_MAX_ITERATIONS {
+        let next: Vec<f64> = (0..n)
+            .map(|i| {
+                let won = 1.0 + wins[i].iter().sum::<f64>();
+                let played = 2.0 / (strengths[i] + 1.0)
+                    + (0..n)
+                        .filter(|&j| j != i)
+                        .map(|j| (wins[i][j] + wins[j][i]) / (strengths[i] + strengths[j]))
+                        .sum::<f64>();
+                won / played
+            })
+            .collect();
+        let change = next
+            .iter()
+            .zip(&strengths)
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f64::max);
+        strengths = next;
+        if change < EPSILON {
+            break;
+        }
+    }
+
+    let log_mean = strengths.iter().map(|s| s.ln()).sum::<f64>() / n.max(1) as f64;
+    strengths.iter().map(|s| s / log_mean.exp()).collect()
+}
+
+/// @ai:intent Percentile of sorted values, interpolating linearly between the closest ranks
+/// @ai:pre sorted is in ascending order, 0 <= fraction <= 1
+/// @ai:post None when there are no values
+/// @ai:effects pure
+/// @ai:example ([10.0, 20.0, 30.0, 40.0], 0.5) -> Some(25.0)
+pub fn percentile(sorted: &[f64], fraction: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = fraction * last as f64;
+    let below = rank.floor() as usize;
+    let above = (below + 1).min(last);
+    Some(sorted[below] + (sorted[above] - sorted[below]) * (rank - below as f64))
+}
+
+/// @ai:intent Bias-corrected and accelerated (BCa) bootstrap interval of the mean. The
+///            percentiles of the resampled means are shifted by the bias of the resampling and
+///            by the jackknife skewness, so skewed samples (rates near 0% or 100%) and small
+///            samples are not reported as more precise than they are
+/// @ai:pre 0 < confidence < 1
+/// @ai:post None with fewer than two values or no resamples; (mean, mean) when all values
+///          are equal
+/// @ai:effects pure
+pub fn bootstrap_mean_interval(
+    values: &[f64],
+    resamples: u32,
+    confidence: f64,
+) -> Option<(f64, f64)> {
+    let n = values.len();
+    if n < 2 || resamples == 0 {
+        return None;
+    }
+    let sum: f64 = values.iter().sum();
+    let mean = sum / n as f64;
+    if values.iter().all(|v| (v - mean).abs() < EPSILON) {
+        return Some((mean, mean));
+    }
+
+    let mut rng = SplitMix64(BOOTSTRAP_SEED);
+    let mut means: Vec<f64> = (0..resamples)
+        .map(|_| (0..n).map(|_| values[rng.below(n)]).sum::<f64>() / n as f64)
+        .collect();
+    means.sort_by(f64::total_cmp);
+
+    // Bias: share of resampled means below the observed one (ties count half), kept off 0 and 1
+    let below = means.iter().filter(|&&m| m < mean - EPSILON).count() as f64;
+    let tied = means
+        .iter()
+        .filter(|&&m| (m - mean).abs() <= EPSILON)
+        .count() as f64;
+    let margin = 0.5 / resamples as f64;
+    let share = ((below + tied / 2.0) / resamples as f64).clamp(margin, 1.0 - margin);
+    let bias = normal_quantile(share);
+
+    // Acceleration: skewness of the leave-one-out means
+    let jackknife: Vec<f64> = values.iter().map(|v| (sum - v) / (n - 1) as f64).collect();
+    let jackknife_mean = jackknife.iter().sum::<f64>() / n as f64;
+    let (cubes, squares) = jackknife.iter().fold((0.0, 0.0), |(cubes, squares), j| {
+        let d = jackknife_mean - j;
+        (cubes + d.powi(3), squares + d * d)
+    });
+    let acceleration = cubes / (6.0 * squares.powf(1.5));
+
+    let percentile = |p: f64| {
+        let z = bias + normal_quantile(p);
+        let adjusted = normal_cdf(bias + z / (1.0 - acceleration * z));
+        let index = (adjusted * (resamples - 1) as f64).round() as usize;
+        means[index.min(means.len() - 1)]
+    };
+    let alpha = (1.0 - confidence) / 2.0;
+    Some((percentile(alpha), percentile(1.0 - alpha)))
+}
+
+/// @ai:intent Small, seedable pseudo-random generator (SplitMix64) for bootstrap resampling
+///            and the judge's blind presentation orders
+#[derive(Debug, Clone)]
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// @ai:intent Generator whose sequence is fixed by the seed
+    /// @ai:effects pure
+    pub(crate) fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// @ai:intent Next 64 random bits
+    /// @ai:effects state:write
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// @ai:intent Random index below n
+    /// @ai:pre n > 0
+    /// @ai:effects state:write
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+/// @ai:intent Cumulative distribution function of the standard normal distribution
+/// @ai:effects pure
+fn normal_cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / std::f64::consts::SQRT_2)
+}
+
+/// @ai:intent Quantile function of the standard normal distribution (Acklam's algorithm,
+///            relative error below 1.2e-9)
+/// @ai:pre 0 < p < 1
+/// @ai:effects pure
+fn normal_quantile(p: f64) -> f64 {
+    const LOW: f64 = 0.024_25;
+    let polynomial =
+        |coefficients: &[f64], x: f64| coefficients.iter().fold(0.0, |acc, c| acc * x + c);
+
+    if (LOW..=1.0 - LOW).contains(&p) {
+        let q = p - 0.5;
+        let r = q * q;
+        return polynomial(&QUANTILE_A, r) * q / (polynomial(&QUANTILE_B, r) * r + 1.0);
+    }
+
+    let q = (-2.0 * p.min(1.0 - p).ln()).sqrt();
+    let tail = polynomial(&QUANTILE_C, q) / (polynomial(&QUANTILE_D, q) * q + 1.0);
+    if p < LOW {
+        tail
+    } else {
+        -tail
+    }
+}
+
+/// @ai:intent Natural logarithm of the gamma function (Lanczos approximation)
+/// @ai:pre x >= 0.5
+/// @ai:effects pure
+fn ln_gamma(x: f64) -> f64 {
+    let x = x - 1.0;
+    let sum = LANCZOS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS[0], |sum, (i, c)| sum + c / (x + i as f64 + 1.0));
+    let t = x + 7.5;
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + sum.ln()
+}
+
+/// @ai:intent Regularized incomplete beta function I_x(a, b)
+/// @ai:pre a >= 0.5, b >= 0.5
+/// @ai:effects pure
+fn incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let front =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    // The continued fraction converges quickly only below this point; use symmetry above it
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * beta_fraction(a, b, x) / a
+    } else {
+        1.0 - front * beta_fraction(b, a, 1.0 - x) / b
+    }
+}
+
+/// @ai:intent Continued fraction of the incomplete beta function (modified Lentz's method)
+/// @ai:effects pure
+fn beta_fraction(a: f64, b: f64, x: f64) -> f64 {
+    const TINY: f64 = 1e-30;
+    let nonzero = |v: f64| if v.abs() < TINY { TINY } else { v };
+
+    let mut c = 1.0;
+    let mut d = 1.0 / nonzero(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+    for m in 1..300 {
+        let m = m as f64;
+        let even = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        d = 1.0 / nonzero(1.0 + even * d);
+        c = nonzero(1.0 + even / c);
+        h *= d * c;
+
+        let odd = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        d = 1.0 / nonzero(1.0 + odd * d);
+        c = nonzero(1.0 + odd / c);
+        let step = d * c;
+        h *= step;
+        if (step - 1.0).abs() < 1e-12 {
+            break;
+        }
+    }
+    h
+}
+
+/// @ai:intent Complementary error function (Chebyshev fit, relative error below 1.2e-7)
+/// @ai:effects pure
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = ERFC_COEFFICIENTS
+        .iter()
+        .rev()
+        .fold(0.0, |acc, c| acc * t + c);
+    let result = t * (poly - z * z).exp();
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paired_t_test() {
+        // scipy.stats.ttest_1samp([1, 2, 3, 4, 5], 0): t = 4.243, p = 0.01324
+        let p = paired_t_test(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        assert!((p - 0.01324).abs() < 1e-4);
+        // Symmetric differences: no evidence of a shift
+        let p = paired_t_test(&[-2.0, -1.0, 1.0, 2.0]).unwrap();
+        assert!((p - 1.0).abs() < 1e-9);
+        assert_eq!(paired_t_test(&[3.0]), None);
+        assert_eq!(paired_t_test(&[2.0, 2.0, 2.0]), None);
+    }
+
+    #[test]
+    fn test_wilcoxon_signed_rank_exact() {
+        // All five differences positive: 1 of 32 sign assignments, doubled
+        let p = wilcoxon_signed_rank(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        assert!((p - 0.0625).abs() < 1e-12);
+        // Zeros are dropped; ties share a rank (W+ = 1.5 of 3)
+        let p = wilcoxon_signed_rank(&[0.0, 1.0, -1.0]).unwrap();
+        assert!((p - 1.0).abs() < 1e-12);
+        assert_eq!(wilcoxon_signed_rank(&[0.0, 0.0]), None);
+    }
+
+    #[test]
+    fn test_cohens_d() {
+        let d = cohens_d(&[1.0, 2.0, 3.0], &[2.0, 3.0, 4.0]).unwrap();
+        assert!((d - 1.0).abs() < 1e-12);
+        // Negative when the treatment is worse
+        let d = cohens_d(&[100.0, 100.0, 0.0], &[0.0, 0.0, 100.0]).unwrap();
+        assert!(d < 0.0);
+        assert_eq!(cohens_d(&[50.0, 50.0], &[50.0]), None);
+        assert_eq!(cohens_d(&[1.0], &[2.0]), None);
+        assert_eq!(cohens_d(&[], &[1.0, 2.0, 3.0]), None);
+    }
+
+    #[test]
+    fn test_cliffs_delta() {
+        assert_eq!(cliffs_delta(&[0.0, 100.0], &[100.0, 100.0]), Some(0.5));
+        assert_eq!(cliffs_delta(&[1.0, 2.0], &[3.0, 4.0]), Some(1.0));
+        assert_eq!(cliffs_delta(&[3.0, 4.0], &[1.0, 2.0]), Some(-1.0));
+        assert_eq!(cliffs_delta(&[1.0, 2.0], &[2.0, 1.0]), Some(0.0));
+        assert_eq!(cliffs_delta(&[], &[1.0]), None);
+    }
+
+    #[test]
+    fn test_binomial_test() {
+        // 2 * 11 / 1024
+        assert!((binomial_test(9, 10).unwrap() - 0.021_484_375).abs() < 1e-9);
+        assert!((binomial_test(1, 10).unwrap() - 0.021_484_375).abs() < 1e-9);
+        // "12 wins vs 9" is no evidence either way
+        assert!((binomial_test(12, 21).unwrap() - 0.663_6).abs() < 1e-4);
+        assert!((binomial_test(0, 20).unwrap() - 2.0 / 1_048_576.0).abs() < 1e-12);
+        assert_eq!(binomial_test(5, 10), Some(1.0));
+        assert_eq!(binomial_test(0, 0), None);
+    }
+
+    #[test]
+    fn test_bradley_terry() {
+        assert!(bradley_terry(&[]).is_empty());
+        let even = bradley_terry(&[vec![0.0, 1.0], vec![1.0, 0.0]]);
+        assert!(even.iter().all(|s| (s - 1.0).abs() < 1e-6));
+
+        // a beats b, b beats c, a beats c; a is unbeaten but finite
+        let wins = vec![
+            vec![0.0, 2.0, 2.0],
+            vec![0.0, 0.0, 2.0],
+            vec![0.0, 0.0, 0.0],
+        ];
+        let strengths = bradley_terry(&wins);
+        assert!(strengths[0] > strengths[1] && strengths[1] > strengths[2]);
+        assert!(strengths[0].is_finite() && strengths[2] > 0.0);
+        let log_sum: f64 = strengths.iter().map(|s| s.ln()).sum();
+        assert!(log_sum.abs() < 1e-9);
+
+        // A tie counts half for each side
+        let tied = bradley_terry(&[vec![0.0, 0.5], vec![0.5, 0.0]]);
+        assert!((tied[0] - tied[1]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_percentile() {
+        let sorted = [10.0, 20.0, 30.0, 40.0];
+        assert_eq!(percentile(&sorted, 0.5), Some(25.0));
+        assert_eq!(percentile(&sorted, 0.0), Some(10.0));
+        assert_eq!(percentile(&sorted, 1.0), Some(40.0));
+        assert!((percentile(&sorted, 0.95).unwrap() - 38.5).abs() < 1e-9);
+        assert_eq!(percentile(&[7.0], 0.25), Some(7.0));
+        assert_eq!(percentile(&[], 0.5), None);
+    }
+
+    #[test]
+    fn test_normal_quantile() {
+        assert!((normal_quantile(0.975) - 1.959_964).abs() < 1e-6);
+        assert!((normal_quantile(0.01) + 2.326_348).abs() < 1e-6);
+        assert!(normal_quantile(0.5).abs() < 1e-9);
+        assert!((normal_cdf(normal_quantile(0.2)) - 0.2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_bootstrap_mean_interval() {
+        let values: Vec<f64> = (1..=20).map(|i| i as f64).collect();
+        let (lower, upper) = bootstrap_mean_interval(&values, 2000, 0.95).unwrap();
+        // Normal theory: 10.5 +/- 1.96 * 5.92 / sqrt(20) = [7.9, 13.1]
+        assert!((lower - 7.9).abs() < 0.5, "lower {}", lower);
+        assert!((upper - 13.1).abs() < 0.5, "upper {}", upper);
+        assert_eq!(
+            bootstrap_mean_interval(&values, 2000, 0.95),
+            Some((lower, upper))
+        );
+
+        // Skewed 0/100 outcomes: the interval is asymmetric around the 90% rate
+        let mut rates = vec![100.0; 18];
+        rates.extend([0.0, 0.0]);
+        let (lower, upper) = bootstrap_mean_interval(&rates, 2000, 0.95).unwrap();
+        assert!(lower < 90.0 && upper <= 100.0);
+        assert!(90.0 - lower > upper - 90.0);
+
+        assert_eq!(
+            bootstrap_mean_interval(&[100.0; 5], 2000, 0.95),
+            Some((100.0, 100.0))
+        );
+        assert_eq!(bootstrap_mean_interval(&[50.0], 2000, 0.95), None);
+        assert_eq!(bootstrap_mean_interval(&values, 0, 0.95), None);
+    }
+
+    #[test]
+    fn test_wilcoxon_signed_rank_normal_approximation() {
+        let shifted: Vec<f64> = (1..=60).map(|i| i as f64).collect();
+        assert!(wilcoxon_signed_rank(&shifted).unwrap() < 1e-9);
+
+        let balanced: Vec<f64> = (1..=60)
+            .map(|i| if i % 2 == 0 { i as f64 } else { -(i as f64) })
+            .collect();
+        assert!(wilcoxon_signed_rank(&balanced).unwrap() > 0.5);
+    }
+}
@@ -1,26 +1,141 @@
 //! @ai:module:intent Metric types for benchmark results
 //! @ai:module:layer domain
-//! @ai:module:public_api TaskMetrics, AggregateStats, ModeComparison, TaskComparison
+//! @ai:module:public_api TaskMetrics, AggregateStats, ModeComparison, TaskComparison, ModelStats,
+//!                        SamplingStats, SkillStats, RepairTurnStats, JudgeAgreement, RankingStats,
+//!                        PairedTest, ConfidenceInterval, EffectSize, RepetitionSpread, Reproducibility,
+//!                        Percentiles, TaskOutcome, TaskHeadToHead, BinomialTest, CompositeScore,
+//!                        OutlierReason, OutlierTask, OutlierStats, FailureReason,
+//!                        DifficultyNormalized, NormalizedMetric, FlakyTask, TagStats, MissingData,
+//!                        ComparisonGroupStats
 //! @ai:module:stateless true
 
-use crate::evaluator::{ComparisonScore, EvaluationResult};
+use crate::config::{MissingDataPolicy, TieHandling};
+use crate::evaluator::{
+    AnnotationDensity, ApiConformance, CodeQuality, CodeSize, ComparisonScore, Complexity,
+    Contamination, ContractStatus, Diagnostic, EvaluationResult, InferenceScore, MutationScore,
+    ReferenceAlignment, SecurityScan, TaskRanking, TestDesign, TestDeterminism, TypeCheck,
+};
+use crate::metrics::statistics::{
+    binomial_test, bootstrap_mean_interval, bradley_terry, cliffs_delta, cohens_d, paired_t_test,
+    percentile, wilcoxon_signed_rank,
+};
+use crate::provenance::RunMetadata;
+use crate::runner::RepairTurn;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// @ai:intent Metrics for a single task execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskMetrics {
     pub task_id: String,
+    /// Model that generated the code (filled in during aggregation)
+    #[serde(default)]
+    pub model: String,
+    /// Sampling setting of a temperature/seed sweep (e.g. "t=0.7"), None outside sweeps
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<String>,
+    /// Skill variant of the AICMS arm in a skill matrix run, None otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skill: Option<String>,
     pub mode: String,
     pub repetition: u32,
     pub code_extracted: bool,
     pub compiled: bool,
+    /// Compiler errors with code and position (Rust only; empty if it compiled)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compile_errors: Vec<Diagnostic>,
     pub test_pass_rate: f64,
+    /// Agreement of two runs of the own tests (None unless flaky test detection ran)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_determinism: Option<TestDeterminism>,
+    /// Pass rate of the task's hidden reference tests (None when the task has none)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden_test_pass_rate: Option<f64>,
+    /// Share of the task's I/O cases the built program passed (None when the task has none)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub io_pass_rate: Option<f64>,
+    /// Required public functions and types found in the code (None when the task lists none)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_conformance: Option<ApiConformance>,
+    /// Alignment with the task's gold solution (None when the task has none)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<ReferenceAlignment>,
+    /// Inferred annotations scored against gold files (None without gold files)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inference: Option<InferenceScore>,
+    /// Idiomatic-lint warnings from clippy/ruff/eslint (None if the linter is unavailable)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_quality: Option<CodeQuality>,
+    /// Type errors from mypy/pyright (None unless Python code that compiled was type checked)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub type_check: Option<TypeCheck>,
+    /// Dangerous code patterns and vulnerable dependencies (None if no code was extracted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security: Option<SecurityScan>,
+    /// Cyclomatic complexity and nesting of the generated functions (None if there are none)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complexity: Option<Complexity>,
+    /// File count and lines of code, split by tests (None if no code was extracted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_size: Option<CodeSize>,
+    /// Mutants caught by the generated tests (None unless mutation testing ran)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mutation: Option<MutationScore>,
+    /// Property-based tests and edge case coverage of the model's own tests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_design: Option<TestDesign>,
     pub lint_compliance: f64,
     pub lint_issues: Vec<String>,
+    /// Annotation lint within [evaluation] lint_fail_on and lint_max_warnings (None if not
+    /// linted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lint_passed: Option<bool>,
     pub annotation_quality: f64,
+    /// Annotations found by the AICMS parser (None if no code was extracted)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annotation_density: Option<AnnotationDensity>,
+    /// Percentage of generated words copied verbatim from the task description
+    #[serde(default)]
+    pub spec_overlap: f64,
+    /// True if the output mostly echoed the task description
+    #[serde(default)]
+    pub spec_echo: bool,
+    /// Near-duplicate check against reference solutions and other tasks' outputs
+    /// (None if the output was too short to check)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contamination: Option<Contamination>,
+    /// Contract traps whose hidden test ran (modify tasks)
+    #[serde(default)]
+    pub contracts_checked: u32,
+    #[serde(default)]
+    pub contracts_violated: u32,
+    /// Ids of the violated contract traps
+    #[serde(default)]
+    pub violated_contracts: Vec<String>,
+    /// Values recorded by custom evaluation stages, by metric name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, f64>,
+    /// Earliest step that failed (None if the execution compiled and passed its tests)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure: Option<FailureReason>,
     pub input_tokens: u32,
     pub output_tokens: u32,
     pub execution_time_ms: u64,
+    /// Prompt tokens written to / read from the prompt cache (included in input_tokens)
+    #[serde(default)]
+    pub cache_creation_input_tokens: u32,
+    #[serde(default)]
+    pub cache_read_input_tokens: u32,
+    /// Agentic turns taken, if the backend reports them
+    #[serde(default)]
+    pub num_turns: Option<u32>,
+    /// Cost in USD: reported by the backend, else computed from [pricing]
+    /// (None when the model has no price)
+    #[serde(default)]
+    pub cost_usd: Option<f64>,
+    /// Per-turn compile/test results of a multi-turn repair execution
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repair_turns: Vec<RepairTurn>,
 }
 
 impl TaskMetrics {
@@ -40,7 +155,21 @@ impl TaskMetrics {
             .map(|c| c.success)
             .unwrap_or(false);
 
+        let compile_errors = eval
+            .compilation
+            .as_ref()
+            .map(|c| {
+                c.diagnostics
+                    .iter()
+                    .filter(|d| d.level == "error")
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default();
+
         let test_pass_rate = eval.tests.as_ref().map(|t| t.pass_rate()).unwrap_or(0.0);
+        let hidden_test_pass_rate = eval.hidden_tests.as_ref().map(|t| t.pass_rate());
+        let io_pass_rate = eval.io_cases.as_ref().map(|t| t.pass_rate());
 
         let lint_compliance = eval
             .lint
@@ -53,6 +182,7 @@ impl TaskMetrics {
             .as_ref()
             .map(|l| l.issues.iter().map(|i| i.message.clone()).collect())
             .unwrap_or_default();
+        let lint_passed = eval.lint.as_ref().map(|l| l.passed);
 
         let annotation_quality = eval
             .annotation_score
@@ -60,19 +190,129 @@ impl TaskMetrics {
             .map(|a| a.overall * 100.0)
             .unwrap_or(0.0);
 
+        let spec_overlap = eval
+            .spec_leakage
+            .as_ref()
+            .map(|l| l.overlap_ratio * 100.0)
+            .unwrap_or(0.0);
+
+        let spec_echo = eval.spec_leakage.as_ref().is_some_and(|l| l.flagged);
+
+        // Traps that could not run say nothing about the contract
+        let contracts_checked = eval
+            .contract_checks
+            .iter()
+            .filter(|c| c.status != ContractStatus::Error)
+            .count() as u32;
+
+        let violated_contracts: Vec<String> = eval
+            .contract_checks
+            .iter()
+            .filter(|c| c.status == ContractStatus::Violated)
+            .map(|c| c.trap_id.clone())
+            .collect();
+
         Self {
             task_id: eval.task_id.clone(),
+            model: String::new(),
+            sampling: None,
+            skill: None,
             mode: eval.mode.clone(),
             repetition: eval.repetition,
             code_extracted,
             compiled,
+            compile_errors,
             test_pass_rate,
+            test_determinism: eval.test_determinism.clone(),
+            hidden_test_pass_rate,
+            io_pass_rate,
+            api_conformance: eval.api_conformance.clone(),
+            reference: eval.reference.clone(),
+            inference: eval.inference.clone(),
+            code_quality: eval.code_quality.clone(),
+            type_check: eval.type_check.clone(),
+            security: eval.security.clone(),
+            complexity: eval.complexity.clone(),
+            code_size: eval.code_size.clone(),
+            mutation: eval.mutation.clone(),
+            test_design: eval.test_design.clone(),
             lint_compliance,
             lint_issues,
+            lint_passed,
             annotation_quality,
+            annotation_density: eval.annotation_score.as_ref().map(|a| a.density.clone()),
+            spec_overlap,
+            spec_echo,
+            contamination: eval.contamination.clone(),
+            contracts_checked,
+            contracts_violated: violated_contracts.len() as u32,
+            violated_contracts,
+            custom: eval.custom.clone(),
+            failure: FailureReason::classify(eval),
             input_tokens,
             output_tokens,
             execution_time_ms,
+            cache_creation_input_tokens: 0,
+            cache_read_input_tokens: 0,
+            num_turns: None,
+            cost_usd: None,
+            repair_turns: vec![],
+        }
+    }
+}
+
+/// @ai:intent Why an execution failed, by the step of the pipeline that failed first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailureReason {
+    /// The client reported an error (e.g. the Claude CLI exited non-zero)
+    ClientError,
+    /// The client was stopped after its timeout
+    Timeout,
+    /// The response held no code block and the model wrote no files
+    NoCodeExtracted,
+    CompileError,
+    /// It compiled, but some of its own tests failed
+    TestFailure,
+    /// The judge's answer on the task could not be parsed, even after re-prompting
+    JudgeParseError,
+}
+
+impl FailureReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ClientError => "client_error",
+            Self::Timeout => "timeout",
+            Self::NoCodeExtracted => "no_code_extracted",
+            Self::CompileError => "compile_error",
+            Self::TestFailure => "test_failure",
+            Self::JudgeParseError => "judge_parse_error",
+        }
+    }
+
+    /// @ai:intent What went wrong in the evaluation, if anything
+    /// @ai:effects pure
+    pub fn classify(eval: &EvaluationResult) -> Option<Self> {
+        if eval.extracted_code.is_none() {
+            Some(Self::NoCodeExtracted)
+        } else if eval.compilation.as_ref().is_some_and(|c| !c.success) {
+            Some(Self::CompileError)
+        } else if eval.tests.as_ref().is_some_and(|t| t.passed < t.total) {
+            Some(Self::TestFailure)
+        } else {
+            None
+        }
+    }
+
+    /// @ai:intent The client-side failure behind a stop reason, if any
+    /// @ai:effects pure
+    /// @ai:example ("timeout") -> Some(Timeout)
+    /// @ai:example ("end_turn") -> None
+    pub fn from_stop_reason(stop_reason: &str) -> Option<Self> {
+        match stop_reason {
+            "timeout" => Some(Self::Timeout),
+            "error" => Some(Self::ClientError),
+            _ => None,
         }
     }
 }
@@ -83,11 +323,379 @@ pub struct AggregateStats {
     pub task_count: u32,
     pub compilation_rate: f64,
     pub avg_test_pass_rate: f64,
+    /// 95% bootstrap interval of the compilation rate (overall results only; None with fewer
+    /// than two executions or bootstrap_resamples = 0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compilation_rate_ci: Option<ConfidenceInterval>,
+    /// 95% bootstrap interval of the test pass rate (as compilation_rate_ci)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_pass_rate_ci: Option<ConfidenceInterval>,
+    /// Spread of each headline metric across the repetitions of a task (empty unless some
+    /// task ran more than once)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repetition_spread: Vec<RepetitionSpread>,
+    /// Agreement of outcomes across the repetitions of a task (None unless some task ran
+    /// more than once)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reproducibility: Option<Reproducibility>,
+    /// Executions whose task has hidden reference tests
+    #[serde(default)]
+    pub hidden_tested_count: u32,
+    /// Average hidden test pass rate over those executions
+    #[serde(default)]
+    pub avg_hidden_test_pass_rate: f64,
+    /// Executions whose task has I/O cases, and the average share of cases passed
+    #[serde(default)]
+    pub io_tested_count: u32,
+    #[serde(default)]
+    pub avg_io_pass_rate: f64,
+    /// Executions whose task requires an API, and the average share of it found
+    #[serde(default)]
+    pub api_checked_count: u32,
+    #[serde(default)]
+    pub avg_api_conformance: f64,
+    /// Executions whose task has a reference solution
+    #[serde(default)]
+    pub reference_compared_count: u32,
+    /// Average API match and overall alignment with the reference over those executions
+    #[serde(default)]
+    pub avg_reference_api_match: f64,
+    #[serde(default)]
+    pub avg_reference_alignment: f64,
+    /// Executions scored against gold annotations (inference tasks)
+    #[serde(default)]
+    pub inference_scored_count: u32,
+    /// Average precision, recall and F1 of inferred annotations over those executions
+    #[serde(default)]
+    pub avg_inference_precision: f64,
+    #[serde(default)]
+    pub avg_inference_recall: f64,
+    #[serde(default)]
+    pub avg_inference_f1: f64,
+    /// Executions linted by clippy/ruff/eslint
+    #[serde(default)]
+    pub quality_checked_count: u32,
+    /// Average idiomatic-lint warnings, and warnings per 100 lines, over those executions
+    #[serde(default)]
+    pub avg_quality_warnings: f64,
+    #[serde(default)]
+    pub avg_quality_density: f64,
+    /// Executions type checked by mypy/pyright
+    #[serde(default)]
+    pub type_checked_count: u32,
+    /// Percentage of those executions without type errors, and their average type errors
+    #[serde(default)]
+    pub type_correct_rate: f64,
+    #[serde(default)]
+    pub avg_type_errors: f64,
+    /// Executions scanned for dangerous code patterns
+    #[serde(default)]
+    pub security_scanned_count: u32,
+    /// Percentage of those executions without security findings, and their average pattern
+    /// matches
+    #[serde(default)]
+    pub security_clean_rate: f64,
+    #[serde(default)]
+    pub avg_dangerous_patterns: f64,
+    /// Executions whose dependencies were audited, and their average vulnerable dependencies
+    #[serde(default)]
+    pub audited_count: u32,
+    #[serde(default)]
+    pub avg_vulnerable_dependencies: f64,
+    /// Executions with at least one measured function
+    #[serde(default)]
+    pub complexity_measured_count: u32,
+    /// Average per-execution function complexity over those executions
+    #[serde(default)]
+    pub avg_cyclomatic_complexity: f64,
+    #[serde(default)]
+    pub avg_nesting_depth: f64,
+    #[serde(default)]
+    pub avg_maintainability: f64,
+    /// Executions with extracted code
+    #[serde(default)]
+    pub sized_count: u32,
+    /// Average generated files and lines (all, and in tests) over those executions
+    #[serde(default)]
+    pub avg_files: f64,
+    #[serde(default)]
+    pub avg_lines_of_code: f64,
+    #[serde(default)]
+    pub avg_test_lines: f64,
+    /// Executions whose annotations were parsed
+    #[serde(default)]
+    pub density_measured_count: u32,
+    /// Average function-level annotations per function, and share of annotated functions
+    #[serde(default)]
+    pub avg_annotations_per_function: f64,
+    #[serde(default)]
+    pub avg_annotated_function_rate: f64,
+    /// Executions whose tests were mutation tested
+    #[serde(default)]
+    pub mutation_tested_count: u32,
+    /// Average mutation score, and test pass rate weighted by it, over those executions
+    #[serde(default)]
+    pub avg_mutation_score: f64,
+    #[serde(default)]
+    pub avg_mutation_weighted_pass_rate: f64,
+    /// Executions whose tests were run twice, and percentage of them whose runs disagreed
+    #[serde(default)]
+    pub determinism_checked_count: u32,
+    #[serde(default)]
+    pub flaky_test_rate: f64,
+    /// Executions whose generated code includes tests
+    #[serde(default)]
+    pub test_design_count: u32,
+    /// Percentage of those executions with property-based tests
+    #[serde(default)]
+    pub property_based_rate: f64,
+    /// Executions of tasks listing edge cases, and the average share their tests cover
+    #[serde(default)]
+    pub edge_case_checked_count: u32,
+    #[serde(default)]
+    pub avg_edge_case_coverage: f64,
     pub avg_lint_compliance: f64,
+    /// Executions linted, and the percentage of them within the lint thresholds
+    #[serde(default)]
+    pub lint_checked_count: u32,
+    #[serde(default)]
+    pub lint_pass_rate: f64,
     pub avg_annotation_quality: f64,
+    #[serde(default)]
+    pub avg_spec_overlap: f64,
+    /// Executions flagged as echoing the task description
+    #[serde(default)]
+    pub spec_echo_count: u32,
+    /// Executions checked for contamination, and those flagged as near-duplicates
+    #[serde(default)]
+    pub contamination_checked_count: u32,
+    #[serde(default)]
+    pub contamination_count: u32,
+    /// Contract trap checks across all executions
+    #[serde(default)]
+    pub contracts_checked: u32,
+    #[serde(default)]
+    pub contracts_violated: u32,
+    /// Percentage of checked contracts that were violated
+    #[serde(default)]
+    pub contract_violation_rate: f64,
     pub total_input_tokens: u64,
     pub total_output_tokens: u64,
     pub avg_execution_time_ms: f64,
+    /// Distribution of the test pass rate over executions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_pass_rate_percentiles: Option<Percentiles>,
+    /// Distribution of the execution time (ms) over executions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_time_percentiles: Option<Percentiles>,
+    /// Distribution of input plus output tokens over executions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_percentiles: Option<Percentiles>,
+    /// Prompt cache usage (part of total_input_tokens)
+    #[serde(default)]
+    pub total_cache_creation_tokens: u64,
+    #[serde(default)]
+    pub total_cache_read_tokens: u64,
+    /// Input-token equivalents saved by caching: reads bill at 10% of the
+    /// base price, writes at 125%
+    #[serde(default)]
+    pub cache_saved_tokens: f64,
+    /// Summed cost of the executions that have one, in USD
+    #[serde(default)]
+    pub total_cost_usd: f64,
+    /// Executions with a known cost
+    #[serde(default)]
+    pub priced_count: u32,
+    /// Cost of a fully passing execution: the priced executions' cost over their summed test
+    /// pass fractions (None when no priced execution passed a test)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_per_pass_usd: Option<f64>,
+    /// Custom metrics of the executions, each combined by its [statistics] custom_metrics
+    /// rule over the executions that recorded it (overall results only)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, f64>,
+    /// Failed executions by failure reason (overall results only)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub failures: BTreeMap<FailureReason, u32>,
+}
+
+impl AggregateStats {
+    /// @ai:intent Percentage of prompt tokens served from the prompt cache
+    /// @ai:effects pure
+    pub fn cache_hit_rate(&self) -> f64 {
+        if self.total_input_tokens == 0 {
+            0.0
+        } else {
+            (self.total_cache_read_tokens as f64 / self.total_input_tokens as f64) * 100.0
+        }
+    }
+
+    /// @ai:intent Average cost of a priced execution, in USD
+    /// @ai:effects pure
+    pub fn avg_cost_usd(&self) -> f64 {
+        if self.priced_count == 0 {
+            0.0
+        } else {
+            self.total_cost_usd / self.priced_count as f64
+        }
+    }
+}
+
+/// @ai:intent How much one metric varies between repetitions of the same task. Each task
+///            (per model, sampling setting and skill variant) run at least twice is a group
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RepetitionSpread {
+    /// DeltaStats field the spread is about (e.g. "test_pass_rate")
+    pub metric: String,
+    /// Tasks with two or more repetitions
+    pub tasks: u32,
+    /// Pooled standard deviation: square root of the tasks' average sample variance
+    pub std_dev: f64,
+    /// Average over tasks of their lowest repetition
+    pub min: f64,
+    /// Average over tasks of their highest repetition
+    pub max: f64,
+}
+
+impl RepetitionSpread {
+    /// @ai:intent Spread of one metric from each task's repetition values
+    /// @ai:pre every group has at least two values
+    /// @ai:effects pure
+    pub fn from_repetitions(metric: &str, groups: &[Vec<f64>]) -> Self {
+        let count = groups.len().max(1) as f64;
+        let variance = groups
+            .iter()
+            .map(|values| {
+                let mean = values.iter().sum::<f64>() / values.len() as f64;
+                values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (values.len() - 1) as f64
+            })
+            .sum::<f64>()
+            / count;
+        let extreme = |pick: fn(f64, f64) -> f64| {
+            groups
+                .iter()
+                .filter_map(|values| values.iter().copied().reduce(pick))
+                .sum::<f64>()
+                / count
+        };
+        Self {
+            metric: metric.to_string(),
+            tasks: groups.len() as u32,
+            std_dev: variance.sqrt(),
+            min: extreme(f64::min),
+            max: extreme(f64::max),
+        }
+    }
+
+    /// @ai:intent Metric name for display
+    /// @ai:effects pure
+    pub fn label(&self) -> String {
+        metric_label(&self.metric)
+    }
+}
+
+/// @ai:intent Whether repetitions of the same task reach the same outcome. Each task (per
+///            model, sampling setting and skill variant) run at least twice counts once
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Reproducibility {
+    /// Tasks with two or more repetitions
+    pub tasks: u32,
+    /// Percentage of those tasks that compiled in every repetition or in none
+    pub consistent_compilation_rate: f64,
+    /// Percentage of those tasks whose test pass rate was the same in every repetition
+    pub consistent_pass_rate: f64,
+    /// Average over tasks of their highest minus lowest test pass rate, in points
+    pub avg_pass_rate_range: f64,
+    /// Stability score (0-100): the average of consistent_compilation_rate and
+    /// 100 - avg_pass_rate_range
+    pub score: f64,
+}
+
+impl Reproducibility {
+    /// @ai:intent Reproducibility from each task's repetitions
+    /// @ai:pre every group has at least two executions
+    /// @ai:effects pure
+    pub fn from_repetitions(groups: &[Vec<&TaskMetrics>]) -> Option<Self> {
+        if groups.is_empty() {
+            return None;
+        }
+        let count = groups.len() as f64;
+        let mut consistent_compilation = 0;
+        let mut consistent_pass = 0;
+        let mut range_sum = 0.0;
+        for runs in groups {
+            if runs.iter().all(|m| m.compiled == runs[0].compiled) {
+                consistent_compilation += 1;
+            }
+            let rates = runs.iter().map(|m| m.test_pass_rate);
+            let range = rates.clone().fold(f64::MIN, f64::max) - rates.fold(f64::MAX, f64::min);
+            if range < 1e-9 {
+                consistent_pass += 1;
+            }
+            range_sum += range;
+        }
+        let consistent_compilation_rate = consistent_compilation as f64 / count * 100.0;
+        let avg_pass_rate_range = range_sum / count;
+        Some(Self {
+            tasks: groups.len() as u32,
+            consistent_compilation_rate,
+            consistent_pass_rate: consistent_pass as f64 / count * 100.0,
+            avg_pass_rate_range,
+            score: (consistent_compilation_rate + 100.0 - avg_pass_rate_range) / 2.0,
+        })
+    }
+}
+
+/// @ai:intent Quartiles and 95th percentile of a metric over executions, which unlike the
+///            average show whether a few tasks drag the metric down
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Percentiles {
+    pub p25: f64,
+    pub median: f64,
+    pub p75: f64,
+    pub p95: f64,
+}
+
+impl Percentiles {
+    /// @ai:intent Percentiles of the values, None when there are none
+    /// @ai:effects pure
+    pub fn from_values(values: impl Iterator<Item = f64>) -> Option<Self> {
+        let mut sorted: Vec<f64> = values.collect();
+        sorted.sort_by(f64::total_cmp);
+        Some(Self {
+            p25: percentile(&sorted, 0.25)?,
+            median: percentile(&sorted, 0.5)?,
+            p75: percentile(&sorted, 0.75)?,
+            p95: percentile(&sorted, 0.95)?,
+        })
+    }
+}
+
+/// @ai:intent Confidence interval of a percentage
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceInterval {
+    pub lower: f64,
+    pub upper: f64,
+}
+
+impl ConfidenceInterval {
+    /// @ai:intent Confidence level of the reported intervals
+    pub const LEVEL: f64 = 0.95;
+
+    /// @ai:intent BCa bootstrap interval of the mean of values
+    /// @ai:post None with fewer than two values or no resamples
+    /// @ai:effects pure
+    pub fn of_mean(values: &[f64], resamples: u32) -> Option<Self> {
+        bootstrap_mean_interval(values, resamples, Self::LEVEL)
+            .map(|(lower, upper)| Self { lower, upper })
+    }
+
+    /// @ai:intent The interval as a percentage range
+    /// @ai:effects pure
+    /// @ai:example ({ lower: 61.25, upper: 80.0 }) -> "61.3-80.0%"
+    pub fn format(&self) -> String {
+        format!("{:.1}-{:.1}%", self.lower, self.upper)
+    }
 }
 
 /// @ai:intent Comparison between baseline and AICMS modes
@@ -95,7 +703,8 @@ pub struct AggregateStats {
 pub struct ModeComparison {
     pub baseline: AggregateStats,
     pub aicms: AggregateStats,
-    pub delta: DeltaStats,
+    /// None when only one mode was run (see run.modes)
+    pub delta: Option<DeltaStats>,
 }
 
 /// @ai:intent Delta between two aggregate stats
@@ -105,19 +714,404 @@ pub struct DeltaStats {
     pub test_pass_rate: f64,
     pub lint_compliance: f64,
     pub annotation_quality: f64,
+    /// Standardized size of each delta, one per field above, computed from the executions
+    /// (empty when only aggregate stats were available)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub effect_sizes: Vec<EffectSize>,
+    /// Differences in total cost and cost per execution, in USD (None unless both modes have
+    /// priced executions)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_cost_usd: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avg_cost_usd: Option<f64>,
+    /// Difference in cost per fully passing execution (None unless both modes have one)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_per_pass_usd: Option<f64>,
+    /// Difference in each custom metric recorded in both modes
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom: BTreeMap<String, f64>,
 }
 
 impl DeltaStats {
     /// @ai:intent Calculate delta between AICMS and baseline
     /// @ai:effects pure
     pub fn calculate(baseline: &AggregateStats, aicms: &AggregateStats) -> Self {
+        let priced = baseline.priced_count > 0 && aicms.priced_count > 0;
         Self {
             compilation_rate: aicms.compilation_rate - baseline.compilation_rate,
             test_pass_rate: aicms.avg_test_pass_rate - baseline.avg_test_pass_rate,
             lint_compliance: aicms.avg_lint_compliance - baseline.avg_lint_compliance,
             annotation_quality: aicms.avg_annotation_quality - baseline.avg_annotation_quality,
+            effect_sizes: vec![],
+            total_cost_usd: priced.then_some(aicms.total_cost_usd - baseline.total_cost_usd),
+            avg_cost_usd: priced.then(|| aicms.avg_cost_usd() - baseline.avg_cost_usd()),
+            cost_per_pass_usd: baseline
+                .cost_per_pass_usd
+                .zip(aicms.cost_per_pass_usd)
+                .map(|(baseline, aicms)| aicms - baseline),
+            custom: aicms
+                .custom
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.clone(), value - baseline.custom.get(name)?))
+                })
+                .collect(),
         }
     }
+
+    /// @ai:intent Effect size of one metric, by field name (e.g. "test_pass_rate")
+    /// @ai:effects pure
+    pub fn effect_size(&self, metric: &str) -> Option<&EffectSize> {
+        self.effect_sizes.iter().find(|e| e.metric == metric)
+    }
+
+    /// @ai:intent Delta between the modes, if both have executions
+    ///            A mode that was not run would otherwise count as 0% everywhere
+    /// @ai:effects pure
+    pub fn between(baseline: &AggregateStats, aicms: &AggregateStats) -> Option<Self> {
+        (baseline.task_count > 0 && aicms.task_count > 0)
+            .then(|| Self::calculate(baseline, aicms))
+    }
+}
+
+/// @ai:intent Standardized size of one headline metric's difference between the modes, so that
+///            deltas can be compared across metrics and corpus revisions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EffectSize {
+    /// DeltaStats field the effect size is about (e.g. "test_pass_rate")
+    pub metric: String,
+    /// Mean difference (AICMS - baseline) in pooled standard deviations (None with fewer
+    /// than three executions, or when no execution differs from its mode's mean)
+    pub cohens_d: Option<f64>,
+    /// Share of (baseline, AICMS) execution pairs where AICMS is higher minus the share where
+    /// it is lower, from -1 to 1 (None when a mode has no executions)
+    pub cliffs_delta: Option<f64>,
+}
+
+impl EffectSize {
+    /// @ai:intent Effect size of one metric from its per-execution values in each mode
+    /// @ai:effects pure
+    pub fn between(metric: &str, baseline: &[f64], aicms: &[f64]) -> Self {
+        Self {
+            metric: metric.to_string(),
+            cohens_d: cohens_d(baseline, aicms),
+            cliffs_delta: cliffs_delta(baseline, aicms),
+        }
+    }
+
+    /// @ai:intent Metric name for display
+    /// @ai:effects pure
+    pub fn label(&self) -> String {
+        metric_label(&self.metric)
+    }
+
+    /// @ai:intent Conventional magnitude of Cliff's delta (Romano et al. thresholds)
+    /// @ai:effects pure
+    /// @ai:example (cliffs_delta Some(-0.4)) -> "medium"
+    pub fn magnitude(&self) -> &'static str {
+        match self.cliffs_delta.map(f64::abs) {
+            None => "n/a",
+            Some(d) if d < 0.147 => "negligible",
+            Some(d) if d < 0.33 => "small",
+            Some(d) if d < 0.474 => "medium",
+            Some(_) => "large",
+        }
+    }
+
+    /// @ai:intent An effect size with sign and two decimals, or "n/a"
+    /// @ai:effects pure
+    /// @ai:example (Some(0.456)) -> "+0.46"
+    pub fn format(value: Option<f64>) -> String {
+        value.map_or_else(|| "n/a".to_string(), |v| format!("{:+.2}", v))
+    }
+}
+
+/// @ai:intent Display name of a DeltaStats field
+/// @ai:effects pure
+/// @ai:example ("test_pass_rate") -> "Test pass rate"
+fn metric_label(metric: &str) -> String {
+    let name = metric.replace('_', " ");
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => name,
+    }
+}
+
+/// @ai:intent Paired significance of one headline metric's difference between the modes.
+///            Each pair is one task's baseline and AICMS values, averaged over repetitions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PairedTest {
+    /// DeltaStats field the test is about (e.g. "test_pass_rate")
+    pub metric: String,
+    /// Tasks run in both modes
+    pub pairs: u32,
+    /// Mean of the per-task differences (AICMS - baseline)
+    pub mean_difference: f64,
+    /// Two-sided p-value of the paired t-test (None with fewer than two pairs, or when
+    /// every task differs by the same amount)
+    pub t_test_p: Option<f64>,
+    /// Two-sided p-value of the Wilcoxon signed-rank test (None when no task differs)
+    pub wilcoxon_p: Option<f64>,
+}
+
+impl PairedTest {
+    /// @ai:intent Test the (baseline, aicms) pairs of one metric
+    /// @ai:effects pure
+    pub fn from_pairs(metric: &str, pairs: &[(f64, f64)]) -> Self {
+        let differences: Vec<f64> = pairs.iter().map(|(baseline, aicms)| aicms - baseline).collect();
+        let mean_difference = if differences.is_empty() {
+            0.0
+        } else {
+            differences.iter().sum::<f64>() / differences.len() as f64
+        };
+        Self {
+            metric: metric.to_string(),
+            pairs: pairs.len() as u32,
+            mean_difference,
+            t_test_p: paired_t_test(&differences),
+            wilcoxon_p: wilcoxon_signed_rank(&differences),
+        }
+    }
+
+    /// @ai:intent Metric name for display
+    /// @ai:effects pure
+    /// @ai:example (metric "test_pass_rate") -> "Test pass rate"
+    pub fn label(&self) -> String {
+        metric_label(&self.metric)
+    }
+
+    /// @ai:intent A p-value with three decimals, "<0.001" below that, or "n/a"
+    /// @ai:effects pure
+    /// @ai:example (Some(0.01324)) -> "0.013"
+    pub fn format_p(p: Option<f64>) -> String {
+        match p {
+            Some(p) if p < 0.001 => "<0.001".to_string(),
+            Some(p) => format!("{:.3}", p),
+            None => "n/a".to_string(),
+        }
+    }
+}
+
+/// @ai:intent One mode's results on a task, averaged over its executions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaskOutcome {
+    pub executions: u32,
+    /// Percentage of executions that compiled
+    pub compilation_rate: f64,
+    pub test_pass_rate: f64,
+    /// Input plus output tokens per execution
+    pub avg_tokens: f64,
+}
+
+impl TaskOutcome {
+    /// @ai:intent Average the executions of one mode on a task
+    /// @ai:effects pure
+    pub fn from_executions(metrics: &[&TaskMetrics]) -> Self {
+        if metrics.is_empty() {
+            return Self::default();
+        }
+        let n = metrics.len() as f64;
+        let compiled = metrics.iter().filter(|m| m.compiled).count();
+        Self {
+            executions: metrics.len() as u32,
+            compilation_rate: compiled as f64 / n * 100.0,
+            test_pass_rate: metrics.iter().map(|m| m.test_pass_rate).sum::<f64>() / n,
+            avg_tokens: metrics
+                .iter()
+                .map(|m| (m.input_tokens + m.output_tokens) as f64)
+                .sum::<f64>()
+                / n,
+        }
+    }
+}
+
+/// @ai:intent Baseline and AICMS results on one task side by side, so a regression on a
+///            single task shows instead of being averaged away
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskHeadToHead {
+    pub task_id: String,
+    pub model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<String>,
+    /// Skill variant of the AICMS executions, paired with the shared baseline
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skill: Option<String>,
+    pub baseline: TaskOutcome,
+    pub aicms: TaskOutcome,
+    /// Winner of the Claude comparison ("aicms", "baseline" or "tie"), None if not compared
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub winner: Option<String>,
+}
+
+impl TaskHeadToHead {
+    /// @ai:intent Whether AICMS compiled less often or passed fewer tests than the baseline
+    /// @ai:effects pure
+    pub fn regressed(&self) -> bool {
+        self.aicms.compilation_rate < self.baseline.compilation_rate - f64::EPSILON
+            || self.aicms.test_pass_rate < self.baseline.test_pass_rate - f64::EPSILON
+    }
+}
+
+/// @ai:intent A task that compiled in some repetitions but not others, or passed all of its
+///            tests in some but not others, within one mode, model, sampling setting and
+///            skill variant
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FlakyTask {
+    pub task_id: String,
+    pub mode: String,
+    pub model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skill: Option<String>,
+    pub repetitions: u32,
+    /// Repetitions that compiled
+    pub compiled: u32,
+    /// Repetitions that passed all of their tests
+    pub passed: u32,
+}
+
+impl FlakyTask {
+    /// @ai:intent The task of the repetitions, if its outcome flipped between them
+    /// @ai:pre runs are repetitions of one task, mode, model, sampling setting and skill
+    /// @ai:effects pure
+    pub fn from_repetitions(runs: &[&TaskMetrics]) -> Option<Self> {
+        let first = runs.first()?;
+        let task = Self {
+            task_id: first.task_id.clone(),
+            mode: first.mode.clone(),
+            model: first.model.clone(),
+            sampling: first.sampling.clone(),
+            skill: first.skill.clone(),
+            repetitions: runs.len() as u32,
+            compiled: runs.iter().filter(|m| m.compiled).count() as u32,
+            passed: runs.iter().filter(|m| m.test_pass_rate >= 100.0).count() as u32,
+        };
+        (task.compilation_flipped() || task.tests_flipped()).then_some(task)
+    }
+
+    /// @ai:intent Whether some repetitions compiled and others did not
+    /// @ai:effects pure
+    pub fn compilation_flipped(&self) -> bool {
+        self.compiled > 0 && self.compiled < self.repetitions
+    }
+
+    /// @ai:intent Whether some repetitions passed all of their tests and others did not
+    /// @ai:effects pure
+    pub fn tests_flipped(&self) -> bool {
+        self.passed > 0 && self.passed < self.repetitions
+    }
+}
+
+/// Interquartile ranges beyond the quartiles at which a task's pass rate is an extreme
+/// outlier (Tukey's far fences)
+const OUTLIER_FENCE: f64 = 3.0;
+
+/// @ai:intent Why a task is excluded from the aggregates without outliers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutlierReason {
+    /// No execution in any mode produced code (e.g. the response format broke extraction)
+    NoCode,
+    /// No execution in any mode compiled or passed a test
+    NothingPassed,
+    /// Test pass rate beyond the far fences of the other tasks' pass rates
+    FarBelow,
+    FarAbove,
+}
+
+impl OutlierReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::NoCode => "no code extracted in any execution",
+            Self::NothingPassed => "nothing compiled or passed in any execution",
+            Self::FarBelow => "test pass rate far below the other tasks",
+            Self::FarAbove => "test pass rate far above the other tasks",
+        }
+    }
+}
+
+/// @ai:intent A task whose results are extreme enough to dominate the overall rates
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutlierTask {
+    pub task_id: String,
+    pub reason: OutlierReason,
+    /// Results of all its executions, pooled over modes, models and repetitions
+    pub outcome: TaskOutcome,
+}
+
+impl OutlierTask {
+    /// @ai:intent Find the outlier tasks: tasks where every execution failed outright, and
+    ///            (with at least four other tasks) tasks whose pooled test pass rate lies
+    ///            beyond the far fences of the others. Sorted by task id
+    /// @ai:effects pure
+    pub fn detect(metrics: &[TaskMetrics]) -> Vec<Self> {
+        let mut by_task: BTreeMap<&str, Vec<&TaskMetrics>> = BTreeMap::new();
+        for m in metrics {
+            by_task.entry(m.task_id.as_str()).or_default().push(m);
+        }
+
+        let mut outliers = Vec::new();
+        let mut rest = Vec::new();
+        for (task_id, executions) in &by_task {
+            let outcome = TaskOutcome::from_executions(executions);
+            let reason = if executions.iter().all(|m| !m.code_extracted) {
+                Some(OutlierReason::NoCode)
+            } else if outcome.compilation_rate == 0.0 && outcome.test_pass_rate == 0.0 {
+                Some(OutlierReason::NothingPassed)
+            } else {
+                None
+            };
+            match reason {
+                Some(reason) => outliers.push(Self {
+                    task_id: task_id.to_string(),
+                    reason,
+                    outcome,
+                }),
+                None => rest.push((*task_id, outcome)),
+            }
+        }
+
+        let mut rates: Vec<f64> = rest.iter().map(|(_, o)| o.test_pass_rate).collect();
+        rates.sort_by(f64::total_cmp);
+        // With most tasks on the same rate there is no spread to be far from
+        let fences = match (percentile(&rates, 0.25), percentile(&rates, 0.75)) {
+            (Some(q1), Some(q3)) if rates.len() >= 4 && q3 > q1 => {
+                let iqr = q3 - q1;
+                Some((q1 - OUTLIER_FENCE * iqr, q3 + OUTLIER_FENCE * iqr))
+            }
+            _ => None,
+        };
+        if let Some((low, high)) = fences {
+            for (task_id, outcome) in rest {
+                let reason = if outcome.test_pass_rate < low {
+                    OutlierReason::FarBelow
+                } else if outcome.test_pass_rate > high {
+                    OutlierReason::FarAbove
+                } else {
+                    continue;
+                };
+                outliers.push(Self {
+                    task_id: task_id.to_string(),
+                    reason,
+                    outcome,
+                });
+            }
+        }
+
+        outliers.sort_by(|a, b| a.task_id.cmp(&b.task_id));
+        outliers
+    }
+}
+
+/// @ai:intent The outlier tasks of a run and the overall results without them, so one broken
+///            task does not decide the conclusions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutlierStats {
+    pub tasks: Vec<OutlierTask>,
+    /// Overall results over the executions of all other tasks
+    pub without: ModeComparison,
 }
 
 /// @ai:intent Statistics by category
@@ -128,6 +1122,14 @@ pub struct CategoryStats {
     pub aicms: AggregateStats,
 }
 
+/// @ai:intent Statistics by task tag; a task with several tags counts towards each
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagStats {
+    pub tag: String,
+    pub baseline: AggregateStats,
+    pub aicms: AggregateStats,
+}
+
 /// @ai:intent Statistics by language
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LanguageStats {
@@ -144,6 +1146,48 @@ pub struct DifficultyStats {
     pub aicms: AggregateStats,
 }
 
+/// @ai:intent Statistics by model (multi-model matrix runs)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelStats {
+    pub model: String,
+    pub baseline: AggregateStats,
+    pub aicms: AggregateStats,
+    pub delta: Option<DeltaStats>,
+}
+
+/// @ai:intent Statistics by sampling setting (temperature/seed sweeps)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SamplingStats {
+    pub sampling: String,
+    pub baseline: AggregateStats,
+    pub aicms: AggregateStats,
+    pub delta: Option<DeltaStats>,
+}
+
+/// @ai:intent Statistics by skill variant (skill matrix runs)
+///            Every variant is compared against the same baseline arm
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillStats {
+    pub skill: String,
+    pub baseline: AggregateStats,
+    pub aicms: AggregateStats,
+    pub delta: Option<DeltaStats>,
+    /// Paired tests of this variant against the shared baseline (empty without pairs)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub significance: Vec<PairedTest>,
+}
+
+/// @ai:intent Compile and test rates after a repair turn (multi-turn runs)
+///            Executions that stopped earlier count with their final turn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepairTurnStats {
+    pub turn: u32,
+    pub baseline_compilation_rate: f64,
+    pub aicms_compilation_rate: f64,
+    pub baseline_test_pass_rate: f64,
+    pub aicms_test_pass_rate: f64,
+}
+
 /// @ai:intent Claude-based comparison for a single task
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskComparison {
@@ -159,6 +1203,617 @@ pub struct ClaudeComparisonStats {
     pub aicms_wins: u32,
     pub baseline_wins: u32,
     pub ties: u32,
+    /// Agreement between judge passes (None with a single pass)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agreement: Option<JudgeAgreement>,
+    /// 95% bootstrap interval of the AICMS win rate (None with fewer than two comparisons)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub win_rate_ci: Option<ConfidenceInterval>,
+    /// Exact binomial test of the AICMS wins against a 50% win rate (None when no
+    /// comparison counts, e.g. all ties with ties excluded)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub win_test: Option<BinomialTest>,
+    /// Judge results per task category, language and difficulty, in declaration order
+    /// (comparisons of tasks missing from the corpus are left out)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub by_category: Vec<ComparisonGroupStats>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub by_language: Vec<ComparisonGroupStats>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub by_difficulty: Vec<ComparisonGroupStats>,
+}
+
+/// @ai:intent Judge results of the comparisons of one category, language or difficulty
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComparisonGroupStats {
+    pub group: String,
+    pub avg_baseline_score: f64,
+    pub avg_aicms_score: f64,
+    pub aicms_wins: u32,
+    pub baseline_wins: u32,
+    pub ties: u32,
+}
+
+impl ComparisonGroupStats {
+    /// @ai:intent Scores and winners of a group's comparisons
+    /// @ai:effects pure
+    pub fn from_comparisons(group: impl Into<String>, comparisons: &[&TaskComparison]) -> Self {
+        let mut stats = Self {
+            group: group.into(),
+            ..Default::default()
+        };
+        for comp in comparisons {
+            stats.avg_baseline_score += comp.comparison.baseline.overall as f64;
+            stats.avg_aicms_score += comp.comparison.aicms.overall as f64;
+            match comp.comparison.winner.as_str() {
+                "aicms" => stats.aicms_wins += 1,
+                "baseline" => stats.baseline_wins += 1,
+                _ => stats.ties += 1,
+            }
+        }
+        if !comparisons.is_empty() {
+            stats.avg_baseline_score /= comparisons.len() as f64;
+            stats.avg_aicms_score /= comparisons.len() as f64;
+        }
+        stats
+    }
+
+    /// @ai:intent Number of comparisons in the group
+    /// @ai:effects pure
+    pub fn comparisons(&self) -> u32 {
+        self.aicms_wins + self.baseline_wins + self.ties
+    }
+
+    /// @ai:intent Percentage of the group's comparisons AICMS won, counting ties as half a win
+    /// @ai:effects pure
+    pub fn win_rate(&self) -> f64 {
+        let total = self.comparisons();
+        if total == 0 {
+            0.0
+        } else {
+            (self.aicms_wins as f64 + self.ties as f64 / 2.0) / total as f64 * 100.0
+        }
+    }
+}
+
+impl ClaudeComparisonStats {
+    /// @ai:intent Percentage of comparisons AICMS won, counting ties as half a win
+    /// @ai:effects pure
+    pub fn win_rate(&self) -> f64 {
+        let total = self.aicms_wins + self.baseline_wins + self.ties;
+        if total == 0 {
+            0.0
+        } else {
+            (self.aicms_wins as f64 + self.ties as f64 / 2.0) / total as f64 * 100.0
+        }
+    }
+
+    /// @ai:intent Bootstrap interval of the win rate over the comparisons
+    /// @ai:effects pure
+    pub fn win_rate_interval(
+        comparisons: &[TaskComparison],
+        resamples: u32,
+    ) -> Option<ConfidenceInterval> {
+        let outcomes: Vec<f64> = comparisons
+            .iter()
+            .map(|c| match c.comparison.winner.as_str() {
+                "aicms" => 100.0,
+                "baseline" => 0.0,
+                _ => 50.0,
+            })
+            .collect();
+        ConfidenceInterval::of_mean(&outcomes, resamples)
+    }
+
+    /// @ai:intent Exact binomial test of the wins, with ties counted as configured
+    /// @ai:effects pure
+    pub fn win_test(&self, ties: TieHandling) -> Option<BinomialTest> {
+        let decided = self.aicms_wins + self.baseline_wins;
+        let (wins, comparisons) = match ties {
+            TieHandling::Exclude => (self.aicms_wins, decided),
+            TieHandling::Split => (self.aicms_wins + self.ties / 2, decided + self.ties / 2 * 2),
+            TieHandling::Loss => (self.aicms_wins, decided + self.ties),
+        };
+        Some(BinomialTest {
+            wins,
+            comparisons,
+            ties,
+            p_value: binomial_test(wins, comparisons)?,
+        })
+    }
+}
+
+/// @ai:intent Exact binomial test of the AICMS comparison wins against a 50% win rate, so
+///            that a lead like 12 wins to 9 is not read as AICMS being better
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BinomialTest {
+    /// AICMS wins, including the ties counted as wins
+    pub wins: u32,
+    /// Comparisons counted, after leaving out or splitting ties
+    pub comparisons: u32,
+    pub ties: TieHandling,
+    /// Two-sided p-value
+    pub p_value: f64,
+}
+
+impl BinomialTest {
+    /// @ai:intent Describe the test for reports
+    /// @ai:effects pure
+    /// @ai:example (12 of 21, ties excluded, p 0.6636) -> "12 of 21 wins, ties excluded: p = 0.664"
+    pub fn format(&self) -> String {
+        let ties = match self.ties {
+            TieHandling::Exclude => "ties excluded",
+            TieHandling::Split => "ties split",
+            TieHandling::Loss => "ties count as losses",
+        };
+        format!(
+            "{} of {} wins, {}: p = {}",
+            self.wins,
+            self.comparisons,
+            ties,
+            PairedTest::format_p(Some(self.p_value))
+        )
+    }
+}
+
+/// @ai:intent One number per mode: a weighted average of several metrics (all percentages),
+///            as configured in [statistics] composite
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompositeScore {
+    /// Weight of each scored component, normalized to sum to 1
+    pub weights: BTreeMap<String, f64>,
+    /// Configured components that could not be scored (e.g. "comparison" without --compare,
+    /// or a metric no execution measured); the other weights are scaled up in their place
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing: Vec<String>,
+    /// Score of each mode, None for a mode that was not run
+    pub baseline: Option<f64>,
+    pub aicms: Option<f64>,
+}
+
+impl CompositeScore {
+    /// @ai:intent Score both modes with the configured weights, using only the components
+    ///            available in every mode that ran (None without weights or components)
+    /// @ai:effects pure
+    pub fn compute(
+        weights: &BTreeMap<String, f64>,
+        baseline: &AggregateStats,
+        aicms: &AggregateStats,
+        comparison: Option<&ClaudeComparisonStats>,
+    ) -> Option<Self> {
+        let modes: Vec<(&str, &AggregateStats)> = [("baseline", baseline), ("aicms", aicms)]
+            .into_iter()
+            .filter(|(_, stats)| stats.task_count > 0)
+            .collect();
+        let (scored, missing): (Vec<_>, Vec<_>) = weights
+            .iter()
+            .filter(|(_, weight)| **weight > 0.0)
+            .partition(|(name, _)| {
+                !modes.is_empty()
+                    && modes.iter().all(|(mode, stats)| {
+                        composite_component(name, mode, stats, comparison).is_some()
+                    })
+            });
+        let total: f64 = scored.iter().map(|(_, weight)| **weight).sum();
+        if total == 0.0 {
+            return None;
+        }
+
+        let weights: BTreeMap<String, f64> = scored
+            .into_iter()
+            .map(|(name, weight)| (name.clone(), weight / total))
+            .collect();
+        let score = |mode: &str, stats: &AggregateStats| {
+            (stats.task_count > 0).then(|| {
+                weights
+                    .iter()
+                    .filter_map(|(name, weight)| {
+                        Some(weight * composite_component(name, mode, stats, comparison)?)
+                    })
+                    .sum()
+            })
+        };
+        Some(Self {
+            baseline: score("baseline", baseline),
+            aicms: score("aicms", aicms),
+            weights,
+            missing: missing.into_iter().map(|(name, _)| name.clone()).collect(),
+        })
+    }
+
+    /// @ai:intent AICMS minus baseline, None unless both modes ran
+    /// @ai:effects pure
+    pub fn delta(&self) -> Option<f64> {
+        Some(self.aicms? - self.baseline?)
+    }
+
+    /// @ai:intent The formula with the weights used, heaviest first
+    /// @ai:effects pure
+    /// @ai:example (test_pass_rate 0.6, compilation_rate 0.4) -> "0.60 × test_pass_rate + 0.40 × compilation_rate"
+    pub fn label(&self) -> String {
+        let mut weights: Vec<_> = self.weights.iter().collect();
+        weights.sort_by(|a, b| b.1.total_cmp(a.1));
+        weights
+            .iter()
+            .map(|(name, weight)| format!("{:.2} × {}", weight, name))
+            .collect::<Vec<_>>()
+            .join(" + ")
+    }
+}
+
+/// @ai:intent Reads one headline rate from a mode's stats
+type RateValue = fn(&AggregateStats) -> f64;
+
+/// @ai:intent Headline rates reported with difficulty weighting, by DeltaStats field name
+const NORMALIZED_METRICS: &[(&str, RateValue)] = &[
+    ("compilation_rate", |s| s.compilation_rate),
+    ("test_pass_rate", |s| s.avg_test_pass_rate),
+    ("lint_compliance", |s| s.avg_lint_compliance),
+    ("annotation_quality", |s| s.avg_annotation_quality),
+];
+
+/// @ai:intent The headline rates of each mode with every difficulty weighted as configured in
+///            [statistics] difficulty_weights, instead of by its number of executions, so a
+///            corpus of mostly easy tasks does not hide the hard ones
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DifficultyNormalized {
+    /// Weight of each difficulty used, normalized to sum to 1
+    pub weights: BTreeMap<String, f64>,
+    /// Configured difficulties missing from a mode that ran; the other weights are scaled up
+    /// in their place
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing: Vec<String>,
+    /// Raw and normalized value of each headline rate, in report order
+    pub metrics: Vec<NormalizedMetric>,
+}
+
+/// @ai:intent One headline rate of each mode, over all executions and difficulty-weighted
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NormalizedMetric {
+    /// DeltaStats field the values are about (e.g. "test_pass_rate")
+    pub metric: String,
+    /// Value over all executions, as in the overall results (None for a mode that was not run)
+    pub raw_baseline: Option<f64>,
+    pub raw_aicms: Option<f64>,
+    /// Weighted average of the value per difficulty
+    pub baseline: Option<f64>,
+    pub aicms: Option<f64>,
+}
+
+impl DifficultyNormalized {
+    /// @ai:intent Weight the per-difficulty stats of both modes, using only the configured
+    ///            difficulties every mode that ran has executions of (None without weights
+    ///            or such difficulties)
+    /// @ai:effects pure
+    pub fn compute(
+        weights: &BTreeMap<String, f64>,
+        baseline: &AggregateStats,
+        aicms: &AggregateStats,
+        by_difficulty: &[DifficultyStats],
+    ) -> Option<Self> {
+        let modes_run = [baseline.task_count > 0, aicms.task_count > 0];
+        let covered = |difficulty: &str| {
+            by_difficulty.iter().any(|d| {
+                d.difficulty == difficulty
+                    && (!modes_run[0] || d.baseline.task_count > 0)
+                    && (!modes_run[1] || d.aicms.task_count > 0)
+            })
+        };
+        let (used, missing): (Vec<_>, Vec<_>) = weights
+            .iter()
+            .filter(|(_, weight)| **weight > 0.0)
+            .partition(|(difficulty, _)| modes_run.contains(&true) && covered(difficulty));
+        let total: f64 = used.iter().map(|(_, weight)| **weight).sum();
+        if total == 0.0 {
+            return None;
+        }
+
+        let weights: BTreeMap<String, f64> = used
+            .into_iter()
+            .map(|(difficulty, weight)| (difficulty.clone(), weight / total))
+            .collect();
+        let weighted = |value: RateValue, stats: fn(&DifficultyStats) -> &AggregateStats| {
+            weights
+                .iter()
+                .filter_map(|(difficulty, weight)| {
+                    let d = by_difficulty.iter().find(|d| &d.difficulty == difficulty)?;
+                    Some(weight * value(stats(d)))
+                })
+                .sum::<f64>()
+        };
+        let metrics = NORMALIZED_METRICS
+            .iter()
+            .map(|(metric, value)| NormalizedMetric {
+                metric: metric.to_string(),
+                raw_baseline: modes_run[0].then(|| value(baseline)),
+                raw_aicms: modes_run[1].then(|| value(aicms)),
+                baseline: modes_run[0].then(|| weighted(*value, |d| &d.baseline)),
+                aicms: modes_run[1].then(|| weighted(*value, |d| &d.aicms)),
+            })
+            .collect();
+        Some(Self {
+            weights,
+            missing: missing.into_iter().map(|(name, _)| name.clone()).collect(),
+            metrics,
+        })
+    }
+}
+
+impl NormalizedMetric {
+    /// @ai:intent Metric name for display
+    /// @ai:effects pure
+    pub fn label(&self) -> String {
+        metric_label(&self.metric)
+    }
+
+    /// @ai:intent Normalized AICMS minus baseline, None unless both modes ran
+    /// @ai:effects pure
+    pub fn delta(&self) -> Option<f64> {
+        Some(self.aicms? - self.baseline?)
+    }
+
+    /// @ai:intent Raw AICMS minus baseline, None unless both modes ran
+    /// @ai:effects pure
+    pub fn raw_delta(&self) -> Option<f64> {
+        Some(self.raw_aicms? - self.raw_baseline?)
+    }
+}
+
+/// @ai:intent Value of one composite component for a mode, in percent: a rate of the stats,
+///            the mode's share of comparison wins ("comparison"), or a custom metric
+/// @ai:effects pure
+fn composite_component(
+    name: &str,
+    mode: &str,
+    stats: &AggregateStats,
+    comparison: Option<&ClaudeComparisonStats>,
+) -> Option<f64> {
+    match name {
+        "compilation_rate" => Some(stats.compilation_rate),
+        "test_pass_rate" => Some(stats.avg_test_pass_rate),
+        "lint_compliance" => Some(stats.avg_lint_compliance),
+        "annotation_quality" => Some(stats.avg_annotation_quality),
+        "hidden_test_pass_rate" => {
+            (stats.hidden_tested_count > 0).then_some(stats.avg_hidden_test_pass_rate)
+        }
+        "io_pass_rate" => (stats.io_tested_count > 0).then_some(stats.avg_io_pass_rate),
+        "comparison" => comparison.map(|c| match mode {
+            "aicms" => c.win_rate(),
+            _ => 100.0 - c.win_rate(),
+        }),
+        _ => stats.custom.get(name).copied(),
+    }
+}
+
+/// @ai:intent How consistently repeated judge passes picked the same winner
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JudgeAgreement {
+    /// Comparisons judged by at least two passes
+    pub comparisons: u32,
+    /// Most passes that succeeded for one comparison
+    pub passes: u32,
+    /// Fleiss' kappa over the winners (None when every pass chose the same winner
+    /// everywhere, where chance agreement is already perfect)
+    pub fleiss_kappa: Option<f64>,
+    /// Fraction of comparisons where all passes chose the same winner
+    pub unanimous_rate: f64,
+}
+
+impl JudgeAgreement {
+    /// @ai:intent Agreement over comparisons with two or more passes, None if there are none
+    ///            Comparisons may have fewer passes than others when some passes failed
+    /// @ai:effects pure
+    pub fn from_comparisons(comparisons: &[TaskComparison]) -> Option<Self> {
+        // Votes per winner category (aicms, baseline, tie) for each comparison
+        let votes: Vec<[u32; 3]> = comparisons
+            .iter()
+            .filter(|c| c.comparison.passes.len() >= 2)
+            .map(|c| {
+                let mut counts = [0u32; 3];
+                for pass in &c.comparison.passes {
+                    let category = match pass.winner.as_str() {
+                        "aicms" => 0,
+                        "baseline" => 1,
+                        _ => 2,
+                    };
+                    counts[category] += 1;
+                }
+                counts
+            })
+            .collect();
+        if votes.is_empty() {
+            return None;
+        }
+
+        let unanimous = votes
+            .iter()
+            .filter(|counts| counts.iter().filter(|&&n| n > 0).count() == 1)
+            .count();
+        Some(Self {
+            comparisons: votes.len() as u32,
+            passes: votes
+                .iter()
+                .map(|counts| counts.iter().sum())
+                .max()
+                .unwrap_or(0),
+            fleiss_kappa: fleiss_kappa(&votes),
+            unanimous_rate: unanimous as f64 / votes.len() as f64,
+        })
+    }
+}
+
+/// @ai:intent Fleiss' kappa for subjects rated into categories by two or more raters each
+///            Observed agreement is averaged per subject, so rater counts may differ
+/// @ai:pre every subject has at least two ratings
+/// @ai:effects pure
+/// @ai:example ([[2, 0, 0], [0, 2, 0]]) -> Some(1.0)
+fn fleiss_kappa<const K: usize>(ratings: &[[u32; K]]) -> Option<f64> {
+    let total: u32 = ratings.iter().flatten().sum();
+    if ratings.is_empty() || total == 0 {
+        return None;
+    }
+
+    let observed = ratings
+        .iter()
+        .map(|counts| {
+            let n: u32 = counts.iter().sum();
+            let agreeing: u32 = counts.iter().map(|&c| c * c.saturating_sub(1)).sum();
+            agreeing as f64 / (n * (n - 1)) as f64
+        })
+        .sum::<f64>()
+        / ratings.len() as f64;
+    let expected: f64 = (0..K)
+        .map(|category| {
+            let share = ratings.iter().map(|c| c[category]).sum::<u32>() as f64 / total as f64;
+            share * share
+        })
+        .sum();
+
+    if (1.0 - expected).abs() < f64::EPSILON {
+        None
+    } else {
+        Some((observed - expected) / (1.0 - expected))
+    }
+}
+
+/// @ai:intent How one implementation source (run and mode) placed across task rankings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RankingStats {
+    pub label: String,
+    /// Rankings it took part in
+    pub tasks: u32,
+    pub avg_rank: f64,
+    pub avg_score: f64,
+    /// Rankings where it placed first (alone or shared)
+    pub first_places: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub ties: u32,
+    /// Bradley-Terry rating on the Elo scale, fitted to every pairwise outcome of the rankings:
+    /// the implementations average 1500, and 400 points more means 10:1 odds of winning
+    #[serde(default)]
+    pub rating: f64,
+}
+
+impl RankingStats {
+    /// @ai:intent Aggregate task rankings per label, best average rank first
+    /// @ai:effects pure
+    pub fn aggregate(rankings: &[TaskRanking]) -> Vec<Self> {
+        let mut stats: Vec<Self> = Vec::new();
+        for result in rankings.iter().flat_map(|r| &r.contestants) {
+            let index = match stats.iter().position(|s| s.label == result.label) {
+                Some(index) => index,
+                None => {
+                    stats.push(Self {
+                        label: result.label.clone(),
+                        ..Default::default()
+                    });
+                    stats.len() - 1
+                }
+            };
+            let entry = &mut stats[index];
+            entry.tasks += 1;
+            entry.avg_rank += result.rank as f64;
+            entry.avg_score += result.score;
+            entry.first_places += u32::from(result.rank == 1);
+            entry.wins += result.wins;
+            entry.losses += result.losses;
+            entry.ties += result.ties;
+        }
+
+        let labels: Vec<&str> = stats.iter().map(|s| s.label.as_str()).collect();
+        let strengths = bradley_terry(&pairwise_wins(rankings, &labels));
+        for (entry, strength) in stats.iter_mut().zip(strengths) {
+            entry.avg_rank /= entry.tasks as f64;
+            entry.avg_score /= entry.tasks as f64;
+            entry.rating = 1500.0 + 400.0 * strength.log10();
+        }
+        stats.sort_by(|a, b| {
+            a.avg_rank
+                .total_cmp(&b.avg_rank)
+                .then_with(|| b.avg_score.total_cmp(&a.avg_score))
+        });
+        stats
+    }
+}
+
+/// @ai:intent How often each label beat each other label across the rankings: wins[i][j] for
+///            labels[i] over labels[j], a tie counting half for each. Pairwise rankings
+///            count their judgments; a ranked judgment counts each pair by rank
+/// @ai:pre labels holds every contestant's label
+/// @ai:effects pure
+fn pairwise_wins(rankings: &[TaskRanking], labels: &[&str]) -> Vec<Vec<f64>> {
+    let index = |label: &str| labels.iter().position(|l| *l == label);
+    let mut wins = vec![vec![0.0; labels.len()]; labels.len()];
+    let mut record = |first: &str, second: &str, outcome: f64| {
+        if let (Some(i), Some(j)) = (index(first), index(second)) {
+            wins[i][j] += outcome;
+            wins[j][i] += 1.0 - outcome;
+        }
+    };
+
+    for ranking in rankings {
+        if ranking.pairs.is_empty() {
+            for (i, first) in ranking.contestants.iter().enumerate() {
+                for second in &ranking.contestants[i + 1..] {
+                    let outcome = match first.rank.cmp(&second.rank) {
+                        std::cmp::Ordering::Less => 1.0,
+                        std::cmp::Ordering::Greater => 0.0,
+                        std::cmp::Ordering::Equal => 0.5,
+                    };
+                    record(&first.label, &second.label, outcome);
+                }
+            }
+        } else {
+            for pair in &ranking.pairs {
+                let outcome = match pair.winner() {
+                    Some(winner) if winner == pair.first => 1.0,
+                    Some(_) => 0.0,
+                    None => 0.5,
+                };
+                record(&pair.first, &pair.second, outcome);
+            }
+        }
+    }
+    wins
+}
+
+/// @ai:intent The missing-data policy behind the aggregates, and the executions without
+///            extracted code it applied to
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MissingData {
+    pub policy: MissingDataPolicy,
+    /// Executions whose code could not be extracted
+    pub executions: u32,
+    /// Of those, executions left out of the aggregates
+    pub excluded: u32,
+    /// Of those, executions given the average rates of their task's other repetitions
+    pub imputed: u32,
+}
+
+impl MissingData {
+    /// @ai:intent The policy and what it did, for reports
+    /// @ai:effects pure
+    /// @ai:example (Impute, 3 executions, 1 excluded, 2 imputed)
+    ///             -> "impute (3 executions without code: 2 imputed, 1 left out)"
+    pub fn describe(&self) -> String {
+        let policy = self.policy.as_str();
+        if self.executions == 0 {
+            return policy.to_string();
+        }
+        let plural = if self.executions == 1 { "" } else { "s" };
+        let effect = match self.policy {
+            MissingDataPolicy::Zero => " counted as 0%".to_string(),
+            MissingDataPolicy::Exclude => " left out".to_string(),
+            MissingDataPolicy::Impute => {
+                format!(": {} imputed, {} left out", self.imputed, self.excluded)
+            }
+        };
+        format!(
+            "{} ({} execution{} without code{})",
+            policy, self.executions, plural, effect
+        )
+    }
 }
 
 /// @ai:intent Complete benchmark results
@@ -171,6 +1826,40 @@ pub struct BenchmarkResults {
     pub by_category: Vec<CategoryStats>,
     pub by_language: Vec<LanguageStats>,
     pub by_difficulty: Vec<DifficultyStats>,
+    /// One entry per task tag found in the corpus, by tag (empty when no task has tags)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub by_tag: Vec<TagStats>,
+    /// One entry per model, in run order
+    #[serde(default)]
+    pub by_model: Vec<ModelStats>,
+    /// One entry per sampling setting of a sweep, in run order
+    #[serde(default)]
+    pub by_sampling: Vec<SamplingStats>,
+    /// One entry per skill variant of a skill matrix, in run order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub by_skill: Vec<SkillStats>,
+    /// Rates after each repair turn, starting with the first attempt (empty when single-shot)
+    #[serde(default)]
+    pub repair_curve: Vec<RepairTurnStats>,
+    /// Paired significance tests of the headline deltas (empty when no task ran in both modes)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub significance: Vec<PairedTest>,
+    /// Baseline vs AICMS results per task run in both modes, by model and sampling setting
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub head_to_head: Vec<TaskHeadToHead>,
+    /// Tasks whose outcome changed between repetitions of the same mode, by task then mode
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub flaky_tasks: Vec<FlakyTask>,
+    /// Weighted composite score per mode (None without [statistics] composite weights)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub composite: Option<CompositeScore>,
+    /// Headline rates with the difficulties weighted (None without [statistics]
+    /// difficulty_weights)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty_normalized: Option<DifficultyNormalized>,
+    /// Outlier tasks and the overall results without them (None when no task is an outlier)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outliers: Option<OutlierStats>,
     pub task_metrics: Vec<TaskMetrics>,
     /// Claude-based comparisons for each task (optional)
     #[serde(default)]
@@ -178,11 +1867,28 @@ pub struct BenchmarkResults {
     /// Aggregate stats from Claude comparisons
     #[serde(default)]
     pub claude_stats: Option<ClaudeComparisonStats>,
+    /// Rankings of all implementations per task when more than two were compared
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rankings: Vec<TaskRanking>,
+    /// Placement of each implementation source across rankings, best first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ranking_stats: Vec<RankingStats>,
+    /// Why the run stopped early (budget cap), None for complete runs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub truncated: Option<String>,
+    /// How executions without extracted code entered the aggregates
+    #[serde(default)]
+    pub missing_data: MissingData,
+    /// Versions, git state, and effective config of the run (also in run_metadata.json)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<RunMetadata>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::evaluator::{ClaudeScorerTrait, MockClaudeScorer};
+    use std::path::Path;
 
     #[test]
     fn test_delta_calculation() {
@@ -201,5 +1907,349 @@ mod tests {
         let delta = DeltaStats::calculate(&baseline, &aicms);
         assert!((delta.compilation_rate - 12.0).abs() < 0.01);
         assert!((delta.test_pass_rate - 25.0).abs() < 0.01);
+        // Unpriced runs have no cost delta
+        assert_eq!(delta.avg_cost_usd, None);
+
+        let priced = |total_cost_usd: f64, cost_per_pass_usd: Option<f64>| AggregateStats {
+            total_cost_usd,
+            priced_count: 4,
+            cost_per_pass_usd,
+            ..Default::default()
+        };
+        let delta = DeltaStats::calculate(&priced(2.0, Some(0.8)), &priced(3.0, None));
+        assert!((delta.total_cost_usd.unwrap() - 1.0).abs() < 1e-9);
+        assert!((delta.avg_cost_usd.unwrap() - 0.25).abs() < 1e-9);
+        assert_eq!(delta.cost_per_pass_usd, None);
+    }
+
+    #[test]
+    fn test_fleiss_kappa() {
+        assert_eq!(fleiss_kappa(&[[2, 0, 0], [0, 2, 0]]), Some(1.0));
+        assert_eq!(fleiss_kappa(&[[3, 0, 0], [3, 0, 0]]), None);
+
+        // Fleiss (1971) style check: P = 0.5, Pe = 0.5 -> kappa 0
+        let kappa = fleiss_kappa(&[[1, 1], [1, 1], [2, 0], [0, 2]]).unwrap();
+        assert!(kappa.abs() < 1e-9);
+
+        let kappa = fleiss_kappa(&[[3, 0, 0], [0, 3, 0], [2, 1, 0]]).unwrap();
+        assert!(kappa > 0.0 && kappa < 1.0);
+    }
+
+    #[test]
+    fn test_judge_agreement() {
+        let comparison = |winners: &[&str]| {
+            let score = MockClaudeScorer::with_defaults()
+                .compare_dirs("spec", Path::new("b"), Path::new("a"))
+                .unwrap();
+            TaskComparison {
+                task_id: "t".to_string(),
+                comparison: ComparisonScore {
+                    passes: winners
+                        .iter()
+                        .map(|winner| ComparisonScore {
+                            winner: winner.to_string(),
+                            ..score.clone()
+                        })
+                        .collect(),
+                    ..score.clone()
+                },
+            }
+        };
+
+        assert_eq!(JudgeAgreement::from_comparisons(&[comparison(&[])]), None);
+
+        let agreement = JudgeAgreement::from_comparisons(&[
+            comparison(&["aicms", "aicms", "aicms"]),
+            comparison(&["baseline", "baseline", "tie"]),
+            comparison(&[]),
+        ])
+        .unwrap();
+        assert_eq!(agreement.comparisons, 2);
+        assert_eq!(agreement.passes, 3);
+        assert!((agreement.unanimous_rate - 0.5).abs() < 1e-9);
+        assert!(agreement.fleiss_kappa.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_repetition_spread() {
+        let groups = [vec![0.0, 100.0], vec![50.0, 50.0, 50.0]];
+        let spread = RepetitionSpread::from_repetitions("test_pass_rate", &groups);
+        assert_eq!(spread.tasks, 2);
+        // Variances 5000 and 0
+        assert!((spread.std_dev - 50.0).abs() < 1e-9);
+        assert!((spread.min - 25.0).abs() < 1e-9);
+        assert!((spread.max - 75.0).abs() < 1e-9);
+        assert_eq!(spread.label(), "Test pass rate");
+    }
+
+    #[test]
+    fn test_reproducibility() {
+        let run = |compiled: bool, test_pass_rate: f64| TaskMetrics {
+            compiled,
+            test_pass_rate,
+            ..TaskMetrics::from_evaluation(&EvaluationResult::default(), 0, 0, 0)
+        };
+        let (stable, flaky) = (
+            [run(true, 100.0), run(true, 100.0)],
+            [run(true, 80.0), run(false, 0.0), run(true, 100.0)],
+        );
+        let groups = vec![stable.iter().collect(), flaky.iter().collect()];
+        let reproducibility = Reproducibility::from_repetitions(&groups).unwrap();
+        assert_eq!(reproducibility.tasks, 2);
+        assert!((reproducibility.consistent_compilation_rate - 50.0).abs() < 1e-9);
+        assert!((reproducibility.consistent_pass_rate - 50.0).abs() < 1e-9);
+        assert!((reproducibility.avg_pass_rate_range - 50.0).abs() < 1e-9);
+        assert!((reproducibility.score - 50.0).abs() < 1e-9);
+        assert_eq!(Reproducibility::from_repetitions(&[]), None);
+    }
+
+    #[test]
+    fn test_ranking_ratings() {
+        use crate::config::RankingStrategy;
+        use crate::evaluator::ContestantResult;
+
+        let contestant = |label: &str, rank: u32| ContestantResult {
+            label: label.to_string(),
+            rank,
+            score: 0.0,
+            wins: 0,
+            losses: 0,
+            ties: 0,
+            scores: None,
+        };
+        let ranking = |ranks: [(&str, u32); 3]| TaskRanking {
+            task_id: "t".to_string(),
+            strategy: RankingStrategy::Ranked,
+            contestants: ranks.map(|(label, rank)| contestant(label, rank)).to_vec(),
+            pairs: vec![],
+            summary: None,
+        };
+        let rankings = vec![
+            ranking([("a", 1), ("b", 2), ("c", 3)]),
+            ranking([("b", 1), ("a", 2), ("c", 3)]),
+            // a and c share first place
+            ranking([("a", 1), ("c", 1), ("b", 3)]),
+        ];
+
+        let stats = RankingStats::aggregate(&rankings);
+        let rating = |label: &str| stats.iter().find(|s| s.label == label).unwrap().rating;
+        assert!(rating("a") > rating("b") && rating("b") > rating("c"));
+        let mean = stats.iter().map(|s| s.rating).sum::<f64>() / stats.len() as f64;
+        assert!((mean - 1500.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_win_test_tie_handling() {
+        let stats = ClaudeComparisonStats {
+            aicms_wins: 3,
+            baseline_wins: 1,
+            ties: 2,
+            ..Default::default()
+        };
+        let test = |ties| {
+            let test = stats.win_test(ties).unwrap();
+            (test.wins, test.comparisons, test.p_value)
+        };
+        let (wins, comparisons, p) = test(TieHandling::Exclude);
+        assert_eq!((wins, comparisons), (3, 4));
+        assert!((p - 0.625).abs() < 1e-9);
+        let (wins, comparisons, p) = test(TieHandling::Split);
+        assert_eq!((wins, comparisons), (4, 6));
+        assert!((p - 0.6875).abs() < 1e-9);
+        let (wins, comparisons, p) = test(TieHandling::Loss);
+        assert_eq!((wins, comparisons), (3, 6));
+        assert!((p - 1.0).abs() < 1e-9);
+
+        let all_ties = ClaudeComparisonStats {
+            ties: 2,
+            ..Default::default()
+        };
+        assert!(all_ties.win_test(TieHandling::Exclude).is_none());
+    }
+
+    #[test]
+    fn test_difficulty_normalized() {
+        let stats = |task_count: u32, compilation_rate: f64| AggregateStats {
+            task_count,
+            compilation_rate,
+            ..Default::default()
+        };
+        let difficulty =
+            |name: &str, baseline: AggregateStats, aicms: AggregateStats| DifficultyStats {
+                difficulty: name.to_string(),
+                baseline,
+                aicms,
+            };
+        // Nine easy tasks where AICMS changes nothing hide one hard task where it helps
+        let by_difficulty = [
+            difficulty("easy", stats(9, 100.0), stats(9, 100.0)),
+            difficulty("hard", stats(1, 0.0), stats(1, 100.0)),
+        ];
+        let weights = BTreeMap::from([
+            ("easy".to_string(), 1.0),
+            ("medium".to_string(), 1.0),
+            ("hard".to_string(), 1.0),
+        ]);
+
+        let normalized = DifficultyNormalized::compute(
+            &weights,
+            &stats(10, 90.0),
+            &stats(10, 100.0),
+            &by_difficulty,
+        )
+        .unwrap();
+        assert_eq!(normalized.missing, vec!["medium"]);
+        assert!((normalized.weights["hard"] - 0.5).abs() < 1e-9);
+        let compilation = &normalized.metrics[0];
+        assert_eq!(compilation.metric, "compilation_rate");
+        assert!((compilation.raw_delta().unwrap() - 10.0).abs() < 1e-9);
+        assert!((compilation.delta().unwrap() - 50.0).abs() < 1e-9);
+
+        // A mode that was not run has no values
+        let only_aicms = DifficultyNormalized::compute(
+            &weights,
+            &stats(0, 0.0),
+            &stats(10, 100.0),
+            &by_difficulty,
+        )
+        .unwrap();
+        assert_eq!(only_aicms.metrics[0].baseline, None);
+        assert_eq!(only_aicms.metrics[0].delta(), None);
+        assert_eq!(
+            DifficultyNormalized::compute(
+                &BTreeMap::new(),
+                &stats(10, 90.0),
+                &stats(10, 100.0),
+                &by_difficulty
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_composite_score() {
+        let baseline = AggregateStats {
+            task_count: 4,
+            compilation_rate: 80.0,
+            avg_test_pass_rate: 60.0,
+            ..Default::default()
+        };
+        let aicms = AggregateStats {
+            task_count: 4,
+            compilation_rate: 100.0,
+            avg_test_pass_rate: 70.0,
+            ..Default::default()
+        };
+        let weights = BTreeMap::from([
+            ("test_pass_rate".to_string(), 0.4),
+            ("compilation_rate".to_string(), 0.3),
+            ("comparison".to_string(), 0.2),
+            ("hidden_test_pass_rate".to_string(), 0.1),
+        ]);
+
+        // Without comparisons or hidden tests, tests and compilation carry all the weight
+        let composite = CompositeScore::compute(&weights, &baseline, &aicms, None).unwrap();
+        assert_eq!(
+            composite.missing,
+            vec!["comparison", "hidden_test_pass_rate"]
+        );
+        assert!((composite.weights["test_pass_rate"] - 4.0 / 7.0).abs() < 1e-9);
+        assert!((composite.baseline.unwrap() - (0.4 * 60.0 + 0.3 * 80.0) / 0.7).abs() < 1e-9);
+        assert_eq!(
+            composite.label(),
+            "0.57 × test_pass_rate + 0.43 × compilation_rate"
+        );
+
+        // AICMS won 3 of 4 comparisons
+        let comparison = ClaudeComparisonStats {
+            aicms_wins: 3,
+            baseline_wins: 1,
+            ..Default::default()
+        };
+        let composite =
+            CompositeScore::compute(&weights, &baseline, &aicms, Some(&comparison)).unwrap();
+        assert!((composite.aicms.unwrap() - (28.0 + 30.0 + 15.0) / 0.9).abs() < 1e-9);
+        assert!((composite.delta().unwrap() - (4.0 + 6.0 + 10.0) / 0.9).abs() < 1e-9);
+
+        // Only AICMS ran: no baseline score and no delta
+        let composite =
+            CompositeScore::compute(&weights, &AggregateStats::default(), &aicms, None).unwrap();
+        assert_eq!((composite.baseline, composite.delta()), (None, None));
+        assert!(CompositeScore::compute(&BTreeMap::new(), &baseline, &aicms, None).is_none());
+    }
+
+    #[test]
+    fn test_win_rate_interval() {
+        let score = MockClaudeScorer::with_defaults()
+            .compare_dirs("spec", Path::new("b"), Path::new("a"))
+            .unwrap();
+        let comparisons: Vec<TaskComparison> = ["aicms", "aicms", "aicms", "tie", "baseline"]
+            .iter()
+            .map(|winner| TaskComparison {
+                task_id: "t".to_string(),
+                comparison: ComparisonScore {
+                    winner: winner.to_string(),
+                    ..score.clone()
+                },
+            })
+            .collect();
+        let stats = ClaudeComparisonStats {
+            aicms_wins: 3,
+            baseline_wins: 1,
+            ties: 1,
+            ..Default::default()
+        };
+        assert!((stats.win_rate() - 70.0).abs() < 1e-9);
+
+        let ci = ClaudeComparisonStats::win_rate_interval(&comparisons, 2000).unwrap();
+        assert!(ci.lower < 70.0 && ci.upper > 70.0);
+        assert!(ci.lower >= 0.0 && ci.upper <= 100.0);
+        assert_eq!(
+            ClaudeComparisonStats::win_rate_interval(&comparisons[..1], 2000),
+            None
+        );
+    }
+
+    #[test]
+    fn test_failure_classification() {
+        use crate::evaluator::{CompilationResult, TestResult};
+
+        let mut eval = EvaluationResult::default();
+        assert_eq!(
+            FailureReason::classify(&eval),
+            Some(FailureReason::NoCodeExtracted)
+        );
+
+        eval.extracted_code = Some("fn main() {}".to_string());
+        eval.compilation = Some(CompilationResult {
+            success: false,
+            errors: vec!["error[E0425]".to_string()],
+            warnings: Vec::new(),
+            diagnostics: Vec::new(),
+        });
+        assert_eq!(
+            FailureReason::classify(&eval),
+            Some(FailureReason::CompileError)
+        );
+
+        eval.compilation.as_mut().unwrap().success = true;
+        eval.tests = Some(TestResult {
+            passed: 3,
+            failed: 1,
+            total: 4,
+            output: String::new(),
+        });
+        assert_eq!(
+            FailureReason::classify(&eval),
+            Some(FailureReason::TestFailure)
+        );
+
+        eval.tests.as_mut().unwrap().passed = 4;
+        assert_eq!(FailureReason::classify(&eval), None);
+        assert_eq!(
+            FailureReason::from_stop_reason("timeout"),
+            Some(FailureReason::Timeout)
+        );
+        assert_eq!(FailureReason::from_stop_reason("end_turn"), None);
     }
 }
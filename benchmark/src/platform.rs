@@ -0,0 +1,134 @@
+//! @ai:module:intent Portability helpers: executable lookup, directory removal, and
+//!                   '/'-separated relative paths on Unix and Windows alike
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api resolve_executable, remove_dir_all, portable_path, normalize_separators
+//! @ai:module:stateless true
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Attempts at deleting a directory before giving up
+const REMOVE_ATTEMPTS: u32 = 5;
+
+/// Pause between deletion attempts; Windows releases file handles of killed processes lazily
+const REMOVE_RETRY_DELAY: Duration = Duration::from_millis(200);
+
+/// @ai:intent Path of a program found on PATH, for spawning it with std/tokio Command
+///            On Windows, npm-installed tools (claude, npx, tsc) are .cmd shims that
+///            Command only finds with their extension, so PATHEXT is tried as well
+/// @ai:post returns name unchanged when it contains a separator or is not found
+/// @ai:effects env, fs:read
+pub fn resolve_executable(name: &str) -> PathBuf {
+    if !cfg!(windows) || name.contains(['/', '\\']) {
+        return PathBuf::from(name);
+    }
+
+    let extensions = std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".into());
+    let Some(path) = std::env::var_os("PATH") else {
+        return PathBuf::from(name);
+    };
+
+    std::env::split_paths(&path)
+        .flat_map(|dir| {
+            extensions
+                .split(';')
+                .filter(|ext| !ext.is_empty())
+                .map(move |ext| dir.join(format!("{}{}", name, ext.to_ascii_lowercase())))
+        })
+        .find(|candidate| candidate.is_file())
+        .unwrap_or_else(|| PathBuf::from(name))
+}
+
+/// @ai:intent Delete a directory tree; a missing directory is not an error
+///            Retries briefly, and on Windows clears read-only flags (e.g. in .git/objects),
+///            which std::fs::remove_dir_all refuses to delete
+/// @ai:effects fs:write
+pub fn remove_dir_all(path: &Path) -> io::Result<()> {
+    let mut attempt = 1;
+    loop {
+        match std::fs::remove_dir_all(path) {
+            Ok(()) => return Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(e) if attempt >= REMOVE_ATTEMPTS => return Err(e),
+            Err(e) => {
+                tracing::debug!("Retrying removal of {}: {}", path.display(), e);
+                #[cfg(windows)]
+                clear_readonly(path);
+                std::thread::sleep(REMOVE_RETRY_DELAY * attempt);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// @ai:intent Make every file below path writable so it can be deleted
+/// @ai:effects fs:write
+#[cfg(windows)]
+fn clear_readonly(path: &Path) {
+    for entry in walkdir::WalkDir::new(path).into_iter().flatten() {
+        if let Ok(metadata) = entry.metadata() {
+            let mut permissions = metadata.permissions();
+            if permissions.readonly() {
+                #[allow(clippy::permissions_set_readonly_false)]
+                permissions.set_readonly(false);
+                std::fs::set_permissions(entry.path(), permissions).ok();
+            }
+        }
+    }
+}
+
+/// @ai:intent A path with '/' separators, as used in prompts, reports, and code fences
+///            Drops the \\?\ prefix of Windows verbatim paths
+/// @ai:effects pure
+/// @ai:example ("src\\lib.rs") -> "src/lib.rs" (on Windows)
+pub fn portable_path(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    let path = path.strip_prefix(r"\\?\").unwrap_or(&path);
+    normalize_separators(path)
+}
+
+/// @ai:intent Replace Windows separators in a path string taken from model output
+/// @ai:effects pure
+/// @ai:example ("src\\utils\\mod.rs") -> "src/utils/mod.rs"
+pub fn normalize_separators(path: &str) -> String {
+    path.replace('\\', "/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_portable_paths() {
+        assert_eq!(
+            normalize_separators(r"src\utils\mod.rs"),
+            "src/utils/mod.rs"
+        );
+        assert_eq!(
+            portable_path(Path::new(r"\\?\C:\skills\SKILL.md")),
+            "C:/skills/SKILL.md"
+        );
+        assert_eq!(
+            portable_path(&Path::new("src").join("lib.rs")),
+            "src/lib.rs"
+        );
+    }
+
+    #[test]
+    fn test_remove_dir_all_tolerates_missing_and_readonly() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("code");
+        std::fs::create_dir_all(dir.join(".git")).unwrap();
+        let object = dir.join(".git").join("object");
+        std::fs::write(&object, "blob").unwrap();
+        let mut permissions = std::fs::metadata(&object).unwrap().permissions();
+        permissions.set_readonly(true);
+        std::fs::set_permissions(&object, permissions).unwrap();
+
+        remove_dir_all(&dir).unwrap();
+        assert!(!dir.exists());
+        remove_dir_all(&dir).unwrap();
+    }
+}
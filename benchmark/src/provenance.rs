@@ -0,0 +1,207 @@
+//! @ai:module:intent Record where a run came from (versions, git state, effective config)
+//!                   so results can be reproduced and compared across machines
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api RunMetadata, METADATA_FILE
+//! @ai:module:depends_on config, platform
+//! @ai:module:stateless true
+
+use crate::config::{BackendKind, BenchmarkConfig};
+use crate::platform::resolve_executable;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Command;
+
+/// File written to every output directory
+pub const METADATA_FILE: &str = "run_metadata.json";
+
+/// Tools whose versions are recorded (missing ones are left out)
+const VERSIONED_TOOLS: &[&str] = &["rustc", "cargo", "python", "node", "tsc", "docker"];
+
+/// Placeholder for secrets left out of the recorded config
+const REDACTED: &str = "<redacted>";
+
+/// @ai:intent Provenance of a run: tool versions, git state, and the effective config
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunMetadata {
+    /// Version of aicms-bench that produced the results
+    pub bench_version: String,
+    pub created_at: String,
+    /// Commit of the repository holding the corpus ("-dirty" if it has local changes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub corpus_git_sha: Option<String>,
+    /// Commit of the repository holding the skill file ("-dirty" if it has local changes)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skill_git_sha: Option<String>,
+    /// `claude --version`, recorded for the Claude Code backend
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub claude_cli_version: Option<String>,
+    /// First line of `<tool> --version` for every installed tool
+    #[serde(default)]
+    pub toolchains: BTreeMap<String, String>,
+    /// Effective configuration after CLI overrides, with MCP server env values redacted
+    pub config: serde_json::Value,
+}
+
+impl RunMetadata {
+    /// @ai:intent Capture versions and git state for a run with this config
+    /// @ai:effects io
+    pub fn capture(config: &BenchmarkConfig) -> Result<Self> {
+        let skill_dir = config.paths.skill_file.parent().unwrap_or(Path::new("."));
+        let claude_cli_version = match config.backend.kind {
+            BackendKind::ClaudeCode if !config.run.dry_run => command_version("claude"),
+            _ => None,
+        };
+        let toolchains = VERSIONED_TOOLS
+            .iter()
+            .filter_map(|tool| command_version(tool).map(|v| (tool.to_string(), v)))
+            .collect();
+
+        Ok(Self {
+            bench_version: env!("CARGO_PKG_VERSION").to_string(),
+            created_at: chrono::Utc::now().to_rfc3339(),
+            corpus_git_sha: git_sha(&config.paths.corpus_dir),
+            skill_git_sha: git_sha(skill_dir),
+            claude_cli_version,
+            toolchains,
+            config: redacted_config(config)?,
+        })
+    }
+
+    /// @ai:intent Same provenance with the config of one run of a matrix
+    /// @ai:effects pure
+    pub fn for_config(&self, config: &BenchmarkConfig) -> Result<Self> {
+        Ok(Self {
+            config: redacted_config(config)?,
+            ..self.clone()
+        })
+    }
+
+    /// @ai:intent Write run_metadata.json into a directory
+    /// @ai:effects fs:write
+    pub fn write(&self, dir: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(dir.join(METADATA_FILE), json)?;
+        Ok(())
+    }
+
+    /// @ai:intent Read run_metadata.json from a directory, None if it has none
+    /// @ai:effects fs:read
+    pub fn load(dir: &Path) -> Result<Option<Self>> {
+        let path = dir.join(METADATA_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path)?;
+        let metadata = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(metadata))
+    }
+}
+
+/// @ai:intent Config as JSON with the values of MCP server env vars (often tokens) replaced
+/// @ai:effects pure
+fn redacted_config(config: &BenchmarkConfig) -> Result<serde_json::Value> {
+    let mut config = config.clone();
+    for server in config.backend.claude_code.mcp_servers.values_mut() {
+        for value in server.env.values_mut() {
+            *value = REDACTED.to_string();
+        }
+    }
+    Ok(serde_json::to_value(&config)?)
+}
+
+/// @ai:intent First line of `<tool> --version`, None if the tool is missing or fails
+/// @ai:effects io
+fn command_version(tool: &str) -> Option<String> {
+    let output = Command::new(resolve_executable(tool))
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    // Some tools (older Python) print their version to stderr
+    let text = if output.stdout.is_empty() {
+        output.stderr
+    } else {
+        output.stdout
+    };
+    String::from_utf8_lossy(&text)
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+}
+
+/// @ai:intent HEAD commit of the repository containing dir, suffixed "-dirty" when
+///            files under dir have uncommitted changes; None outside a git repository
+/// @ai:effects io
+fn git_sha(dir: &Path) -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new(resolve_executable("git"))
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let sha = git(&["rev-parse", "HEAD"]).filter(|sha| !sha.is_empty())?;
+    match git(&["status", "--porcelain", "--", "."]) {
+        Some(status) if !status.is_empty() => Some(format!("{}-dirty", sha)),
+        _ => Some(sha),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::McpServerConfig;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_config_secrets_are_redacted() {
+        let mut config = BenchmarkConfig::default();
+        config.backend.claude_code.mcp_servers.insert(
+            "docs".to_string(),
+            McpServerConfig {
+                command: Some("npx".to_string()),
+                env: BTreeMap::from([("API_TOKEN".to_string(), "secret".to_string())]),
+                ..Default::default()
+            },
+        );
+
+        let value = redacted_config(&config).unwrap();
+        let server = &value["backend"]["claude_code"]["mcp_servers"]["docs"];
+        assert_eq!(server["env"]["API_TOKEN"], REDACTED);
+        assert_eq!(server["command"], "npx");
+        assert!(!value.to_string().contains("secret"));
+    }
+
+    #[test]
+    fn test_metadata_round_trips_and_git_sha_outside_repo() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(git_sha(temp.path()), None);
+        assert_eq!(command_version("nonexistent_tool_xyz"), None);
+
+        let metadata = RunMetadata {
+            bench_version: "0.1.0".to_string(),
+            created_at: "2026-01-19T12:00:00Z".to_string(),
+            corpus_git_sha: Some("abc123-dirty".to_string()),
+            skill_git_sha: None,
+            claude_cli_version: None,
+            toolchains: BTreeMap::from([("rustc".to_string(), "rustc 1.80.0".to_string())]),
+            config: redacted_config(&BenchmarkConfig::default()).unwrap(),
+        };
+        metadata.write(temp.path()).unwrap();
+
+        let content = std::fs::read_to_string(temp.path().join(METADATA_FILE)).unwrap();
+        let loaded: RunMetadata = serde_json::from_str(&content).unwrap();
+        assert_eq!(loaded, metadata);
+        assert!(!content.contains("skill_git_sha"));
+        assert_eq!(RunMetadata::load(temp.path()).unwrap(), Some(metadata));
+        assert_eq!(RunMetadata::load(&temp.path().join("missing")).unwrap(), None);
+    }
+}
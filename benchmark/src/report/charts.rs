@@ -1,19 +1,46 @@
 //! @ai:module:intent Chart generation for benchmark results
 //! @ai:module:layer infrastructure
-//! @ai:module:public_api ChartGenerator
+//! @ai:module:public_api ChartGenerator, ChartPlugin
 //! @ai:module:stateless true
 
 use crate::metrics::BenchmarkResults;
 use anyhow::Result;
 use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
 use std::path::Path;
 
+/// @ai:intent One group of a bar chart: its label and the baseline and AICMS values
+type BarGroup<'a> = (&'a str, f64, f64);
+
+/// @ai:intent Reads one mode's value from a group
+type BarValue = fn(&BarGroup) -> f64;
+
+/// @ai:intent The bars of each group: legend label, color, span around the group's center
+///            (in groups) and value
+const SERIES: [(&str, RGBColor, (f64, f64), BarValue); 2] = [
+    ("Baseline", BLUE, (-0.4, -0.02), |g| g.1),
+    ("AICMS", GREEN, (0.02, 0.4), |g| g.2),
+];
+
+/// Top of the value axis: above 100% leaves room for the values over full bars and the legend
+const VALUE_AXIS_MAX: f64 = 125.0;
+
 /// @ai:intent Trait for chart generation
 pub trait ChartGeneratorTrait: Send + Sync {
     /// @ai:intent Generate all charts from results
     fn generate_all(&self, results: &BenchmarkResults, output_dir: &Path) -> Result<Vec<String>>;
 }
 
+/// @ai:intent Extension point for additional charts (e.g. cost vs. quality scatter)
+///            Registered with ReportGenerator::with_chart_plugin and run after the built-in charts
+pub trait ChartPlugin: Send + Sync {
+    /// @ai:intent Short name used in logs
+    fn name(&self) -> &str;
+
+    /// @ai:intent Render charts into output_dir, returning the file names written
+    fn generate(&self, results: &BenchmarkResults, output_dir: &Path) -> Result<Vec<String>>;
+}
+
 /// @ai:intent Generates charts from benchmark results
 pub struct ChartGenerator;
 
@@ -24,74 +51,86 @@ impl ChartGenerator {
         Self
     }
 
-    /// @ai:intent Generate comparison bar chart
+    /// @ai:intent Draw the baseline and AICMS bars of each group side by side, with a legend
+    ///            and each bar's value above it
     /// @ai:effects fs:write
-    fn generate_comparison_chart(
+    fn draw_grouped_bars(
         &self,
-        results: &BenchmarkResults,
         output_path: &Path,
+        size: (u32, u32),
+        caption: (&str, u32),
+        y_desc: &str,
+        x_desc: Option<&str>,
+        groups: &[BarGroup],
     ) -> Result<()> {
-        let root = BitMapBackend::new(output_path, (800, 600)).into_drawing_area();
+        let root = BitMapBackend::new(output_path, size).into_drawing_area();
         root.fill(&WHITE)?;
 
-        let metrics = [
-            ("Compilation", results.overall.baseline.compilation_rate, results.overall.aicms.compilation_rate),
-            ("Test Pass", results.overall.baseline.avg_test_pass_rate, results.overall.aicms.avg_test_pass_rate),
-            ("Lint", results.overall.baseline.avg_lint_compliance, results.overall.aicms.avg_lint_compliance),
-            ("Annotations", results.overall.baseline.avg_annotation_quality, results.overall.aicms.avg_annotation_quality),
-        ];
-
+        // One unit per group, centered on the group's index
+        let count = groups.len().max(1);
         let mut chart = ChartBuilder::on(&root)
-            .caption("AICMS vs Baseline Comparison", ("sans-serif", 30))
+            .caption(caption.0, ("sans-serif", caption.1))
             .margin(20)
             .x_label_area_size(40)
             .y_label_area_size(50)
-            .build_cartesian_2d(0..4i32, 0f64..100f64)?;
-
-        chart
-            .configure_mesh()
-            .x_labels(4)
-            .y_desc("Rate (%)")
-            .x_desc("Metric")
-            .x_label_formatter(&|x| {
-                metrics
-                    .get(*x as usize)
-                    .map(|(name, _, _)| name.to_string())
-                    .unwrap_or_default()
-            })
-            .draw()?;
-
-        chart.draw_series(
-            metrics
-                .iter()
-                .enumerate()
-                .map(|(i, (_, baseline, _))| {
-                    Rectangle::new(
-                        [(i as i32, 0.0), (i as i32, *baseline)],
-                        BLUE.mix(0.7).filled(),
-                    )
-                }),
-        )?
-        .label("Baseline")
-        .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], BLUE.mix(0.7).filled()));
-
-        chart.draw_series(
-            metrics
-                .iter()
-                .enumerate()
-                .map(|(i, (_, _, aicms))| {
-                    Rectangle::new(
-                        [(i as i32, 0.0), (i as i32, *aicms)],
-                        GREEN.mix(0.7).filled(),
-                    )
-                }),
-        )?
-        .label("AICMS")
-        .legend(|(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], GREEN.mix(0.7).filled()));
+            .build_cartesian_2d(-0.5..count as f64 - 0.5, 0f64..VALUE_AXIS_MAX)?;
+
+        // Ticks fall on whole numbers at most once per group; only those name a group
+        let group_label = |x: &f64| {
+            if x.fract() != 0.0 || *x < 0.0 {
+                return String::new();
+            }
+            groups
+                .get(*x as usize)
+                .map(|(name, _, _)| name.to_string())
+                .unwrap_or_default()
+        };
+        let rate_label = |y: &f64| {
+            if *y > 100.0 {
+                String::new()
+            } else {
+                format!("{:.0}", y)
+            }
+        };
+        let mut mesh = chart.configure_mesh();
+        mesh.disable_x_mesh()
+            .x_labels(count)
+            .y_desc(y_desc)
+            .x_label_formatter(&group_label)
+            .y_label_formatter(&rate_label);
+        if let Some(x_desc) = x_desc {
+            mesh.x_desc(x_desc);
+        }
+        mesh.draw()?;
+
+        let value_style = TextStyle::from(("sans-serif", 14).into_font())
+            .pos(Pos::new(HPos::Center, VPos::Bottom));
+        for (name, color, (left, right), value) in SERIES {
+            let color = color.mix(0.7);
+            chart
+                .draw_series(groups.iter().enumerate().map(|(i, group)| {
+                    let x = i as f64;
+                    Rectangle::new([(x + left, 0.0), (x + right, value(group))], color.filled())
+                }))?
+                .label(name)
+                .legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 5), (x + 20, y + 5)], color.filled())
+                });
+
+            chart.draw_series(groups.iter().enumerate().map(|(i, group)| {
+                let center = i as f64 + (left + right) / 2.0;
+                Text::new(
+                    format!("{:.1}", value(group)),
+                    (center, value(group)),
+                    value_style.clone(),
+                )
+            }))?;
+        }
 
         chart
             .configure_series_labels()
             .position(SeriesLabelPosition::UpperRight)
+            .background_style(WHITE.mix(0.8))
             .border_style(BLACK)
             .draw()?;
 
@@ -99,6 +138,47 @@ impl ChartGenerator {
         Ok(())
     }
 
+    /// @ai:intent Generate comparison bar chart
+    /// @ai:effects fs:write
+    fn generate_comparison_chart(
+        &self,
+        results: &BenchmarkResults,
+        output_path: &Path,
+    ) -> Result<()> {
+        let (baseline, aicms) = (&results.overall.baseline, &results.overall.aicms);
+        let metrics = [
+            (
+                "Compilation",
+                baseline.compilation_rate,
+                aicms.compilation_rate,
+            ),
+            (
+                "Test Pass",
+                baseline.avg_test_pass_rate,
+                aicms.avg_test_pass_rate,
+            ),
+            (
+                "Lint",
+                baseline.avg_lint_compliance,
+                aicms.avg_lint_compliance,
+            ),
+            (
+                "Annotations",
+                baseline.avg_annotation_quality,
+                aicms.avg_annotation_quality,
+            ),
+        ];
+
+        self.draw_grouped_bars(
+            output_path,
+            (800, 600),
+            ("AICMS vs Baseline Comparison", 30),
+            "Rate (%)",
+            Some("Metric"),
+            &metrics,
+        )
+    }
+
     /// @ai:intent Generate language breakdown chart
     /// @ai:effects fs:write
     fn generate_language_chart(
@@ -106,9 +186,6 @@ impl ChartGenerator {
         results: &BenchmarkResults,
         output_path: &Path,
     ) -> Result<()> {
-        let root = BitMapBackend::new(output_path, (800, 500)).into_drawing_area();
-        root.fill(&WHITE)?;
-
         let data: Vec<_> = results
             .by_language
             .iter()
@@ -121,39 +198,14 @@ impl ChartGenerator {
             })
             .collect();
 
-        let mut chart = ChartBuilder::on(&root)
-            .caption("Compilation Rate by Language", ("sans-serif", 25))
-            .margin(20)
-            .x_label_area_size(40)
-            .y_label_area_size(50)
-            .build_cartesian_2d(0..data.len() as i32, 0f64..100f64)?;
-
-        chart
-            .configure_mesh()
-            .y_desc("Compilation Rate (%)")
-            .x_label_formatter(&|x| {
-                data.get(*x as usize)
-                    .map(|(name, _, _)| name.to_string())
-                    .unwrap_or_default()
-            })
-            .draw()?;
-
-        chart.draw_series(data.iter().enumerate().map(|(i, (_, baseline, _))| {
-            Rectangle::new(
-                [(i as i32, 0.0), (i as i32, *baseline)],
-                BLUE.mix(0.7).filled(),
-            )
-        }))?;
-
-        chart.draw_series(data.iter().enumerate().map(|(i, (_, _, aicms))| {
-            Rectangle::new(
-                [(i as i32, 0.0), (i as i32, *aicms)],
-                GREEN.mix(0.7).filled(),
-            )
-        }))?;
-
-        root.present()?;
-        Ok(())
+        self.draw_grouped_bars(
+            output_path,
+            (800, 500),
+            ("Compilation Rate by Language", 25),
+            "Compilation Rate (%)",
+            None,
+            &data,
+        )
     }
 
     /// @ai:intent Generate difficulty breakdown chart
@@ -163,9 +215,6 @@ impl ChartGenerator {
         results: &BenchmarkResults,
         output_path: &Path,
     ) -> Result<()> {
-        let root = BitMapBackend::new(output_path, (800, 500)).into_drawing_area();
-        root.fill(&WHITE)?;
-
         let data: Vec<_> = results
             .by_difficulty
             .iter()
@@ -178,39 +227,14 @@ impl ChartGenerator {
             })
             .collect();
 
-        let mut chart = ChartBuilder::on(&root)
-            .caption("Test Pass Rate by Difficulty", ("sans-serif", 25))
-            .margin(20)
-            .x_label_area_size(40)
-            .y_label_area_size(50)
-            .build_cartesian_2d(0..data.len() as i32, 0f64..100f64)?;
-
-        chart
-            .configure_mesh()
-            .y_desc("Test Pass Rate (%)")
-            .x_label_formatter(&|x| {
-                data.get(*x as usize)
-                    .map(|(name, _, _)| name.to_string())
-                    .unwrap_or_default()
-            })
-            .draw()?;
-
-        chart.draw_series(data.iter().enumerate().map(|(i, (_, baseline, _))| {
-            Rectangle::new(
-                [(i as i32, 0.0), (i as i32, *baseline)],
-                BLUE.mix(0.7).filled(),
-            )
-        }))?;
-
-        chart.draw_series(data.iter().enumerate().map(|(i, (_, _, aicms))| {
-            Rectangle::new(
-                [(i as i32, 0.0), (i as i32, *aicms)],
-                GREEN.mix(0.7).filled(),
-            )
-        }))?;
-
-        root.present()?;
-        Ok(())
+        self.draw_grouped_bars(
+            output_path,
+            (800, 500),
+            ("Test Pass Rate by Difficulty", 25),
+            "Test Pass Rate (%)",
+            None,
+            &data,
+        )
     }
 }
 
@@ -268,12 +292,17 @@ mod tests {
                     avg_lint_compliance: 88.0,
                     ..Default::default()
                 },
-                delta: DeltaStats {
+                delta: Some(DeltaStats {
                     compilation_rate: 12.0,
                     test_pass_rate: 15.0,
                     lint_compliance: 28.0,
                     annotation_quality: 0.0,
-                },
+                    effect_sizes: vec![],
+                    total_cost_usd: None,
+                    avg_cost_usd: None,
+                    cost_per_pass_usd: None,
+                    custom: Default::default(),
+                }),
             },
             by_category: vec![],
             by_language: vec![
@@ -288,6 +317,7 @@ mod tests {
                     aicms: AggregateStats { compilation_rate: 95.0, ..Default::default() },
                 },
             ],
+            by_tag: vec![],
             by_difficulty: vec![
                 DifficultyStats {
                     difficulty: "easy".to_string(),
@@ -300,9 +330,24 @@ mod tests {
                     aicms: AggregateStats { avg_test_pass_rate: 70.0, ..Default::default() },
                 },
             ],
+            by_model: vec![],
+            by_sampling: vec![],
+            by_skill: vec![],
+            repair_curve: vec![],
+            significance: vec![],
+            head_to_head: vec![],
+            flaky_tasks: vec![],
+            composite: None,
+            difficulty_normalized: None,
+            outliers: None,
             task_metrics: vec![],
             claude_comparisons: vec![],
             claude_stats: None,
+            rankings: vec![],
+            ranking_stats: vec![],
+            truncated: None,
+            missing_data: Default::default(),
+            metadata: None,
         }
     }
 
@@ -59,19 +59,40 @@ mod tests {
             overall: ModeComparison {
                 baseline: AggregateStats::default(),
                 aicms: AggregateStats::default(),
-                delta: DeltaStats {
+                delta: Some(DeltaStats {
                     compilation_rate: 0.0,
                     test_pass_rate: 0.0,
                     lint_compliance: 0.0,
                     annotation_quality: 0.0,
-                },
+                    effect_sizes: vec![],
+                    total_cost_usd: None,
+                    avg_cost_usd: None,
+                    cost_per_pass_usd: None,
+                    custom: Default::default(),
+                }),
             },
             by_category: vec![],
             by_language: vec![],
+            by_tag: vec![],
             by_difficulty: vec![],
+            by_model: vec![],
+            by_sampling: vec![],
+            by_skill: vec![],
+            repair_curve: vec![],
+            significance: vec![],
+            head_to_head: vec![],
+            flaky_tasks: vec![],
+            composite: None,
+            difficulty_normalized: None,
+            outliers: None,
             task_metrics: vec![],
             claude_comparisons: vec![],
             claude_stats: None,
+            rankings: vec![],
+            ranking_stats: vec![],
+            truncated: None,
+            missing_data: Default::default(),
+            metadata: None,
         };
 
         reporter.generate(&results, &output).unwrap();
@@ -3,8 +3,13 @@
 //! @ai:module:public_api MarkdownReporter
 //! @ai:module:stateless true
 
-use crate::metrics::{AggregateStats, BenchmarkResults, DeltaStats};
+use crate::evaluator::TagScore;
+use crate::metrics::{
+    AggregateStats, BenchmarkResults, ConfidenceInterval, DeltaStats, EffectSize, FailureReason,
+    PairedTest,
+};
 use anyhow::Result;
+use std::collections::BTreeMap;
 use std::fmt::Write as FmtWrite;
 use std::path::Path;
 
@@ -34,102 +39,2106 @@ impl MarkdownReporter {
         }
     }
 
+    /// @ai:intent Format a delta that is missing when only one mode was run
+    /// @ai:effects pure
+    fn format_optional_delta(value: Option<f64>) -> String {
+        value.map_or_else(|| "n/a".to_string(), Self::format_delta)
+    }
+
+    /// @ai:intent Format a metric's effect sizes as "d / Cliff's delta (magnitude)", or "n/a"
+    ///            when only one mode was run or the effect sizes were not computed
+    /// @ai:effects pure
+    fn format_effect_size(delta: Option<&DeltaStats>, metric: &str) -> String {
+        match delta.and_then(|d| d.effect_size(metric)) {
+            Some(effect) => format!(
+                "{} / {} ({})",
+                EffectSize::format(effect.cohens_d),
+                EffectSize::format(effect.cliffs_delta),
+                effect.magnitude()
+            ),
+            None => "n/a".to_string(),
+        }
+    }
+
+    /// @ai:intent Format a cost in USD, or a dash if there is none
+    /// @ai:effects pure
+    fn format_cost(cost: Option<f64>) -> String {
+        cost.map_or_else(|| "-".to_string(), |cost| format!("${:.4}", cost))
+    }
+
+    /// @ai:intent Format a rate of a mode, or a dash if the mode has no executions
+    /// @ai:effects pure
+    fn format_rate(stats: &AggregateStats, value: f64) -> String {
+        if stats.task_count == 0 {
+            "-".to_string()
+        } else {
+            format!("{:.1}%", value)
+        }
+    }
+
+    /// @ai:intent Modes with at least one execution, for per-mode tables
+    /// @ai:effects pure
+    fn modes_run<'a>(
+        baseline: &'a AggregateStats,
+        aicms: &'a AggregateStats,
+    ) -> Vec<(&'static str, &'a AggregateStats)> {
+        [("Baseline", baseline), ("AICMS", aicms)]
+            .into_iter()
+            .filter(|(_, stats)| stats.task_count > 0)
+            .collect()
+    }
+
     /// @ai:intent Generate overall summary section
     /// @ai:effects pure
-    fn generate_summary(results: &BenchmarkResults) -> String {
+    fn generate_summary(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "# AICMS Benchmark Results").unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "**Date:** {}", results.timestamp).unwrap();
+        writeln!(output, "**Model:** {}", results.model).unwrap();
+        writeln!(output, "**Repetitions:** {}", results.repetitions).unwrap();
+        writeln!(
+            output,
+            "**Missing data:** {}",
+            results.missing_data.describe()
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+
+        if let Some(reason) = &results.truncated {
+            writeln!(
+                output,
+                "> **Truncated run:** {}. Results cover only the executions that finished.",
+                reason
+            )
+            .unwrap();
+            writeln!(output).unwrap();
+        }
+
+        output
+    }
+
+    /// @ai:intent Generate comparison table
+    /// @ai:effects pure
+    fn generate_comparison_table(
+        baseline: &AggregateStats,
+        aicms: &AggregateStats,
+        delta: Option<&DeltaStats>,
+    ) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "## Overall Results").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Metric | Baseline | AICMS | Delta | Effect Size (d / Cliff's delta) |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|--------|----------|-------|-------|---------------------------------|"
+        )
+        .unwrap();
+
+        writeln!(
+            output,
+            "| Compilation Rate | {} | {} | {} | {} |",
+            Self::format_rate(baseline, baseline.compilation_rate),
+            Self::format_rate(aicms, aicms.compilation_rate),
+            Self::format_optional_delta(delta.map(|d| d.compilation_rate)),
+            Self::format_effect_size(delta, "compilation_rate")
+        )
+        .unwrap();
+
+        writeln!(
+            output,
+            "| Test Pass Rate | {} | {} | {} | {} |",
+            Self::format_rate(baseline, baseline.avg_test_pass_rate),
+            Self::format_rate(aicms, aicms.avg_test_pass_rate),
+            Self::format_optional_delta(delta.map(|d| d.test_pass_rate)),
+            Self::format_effect_size(delta, "test_pass_rate")
+        )
+        .unwrap();
+
+        writeln!(
+            output,
+            "| Lint Compliance | {} | {} | {} | {} |",
+            Self::format_rate(baseline, baseline.avg_lint_compliance),
+            Self::format_rate(aicms, aicms.avg_lint_compliance),
+            Self::format_optional_delta(delta.map(|d| d.lint_compliance)),
+            Self::format_effect_size(delta, "lint_compliance")
+        )
+        .unwrap();
+
+        if baseline.lint_checked_count > 0 || aicms.lint_checked_count > 0 {
+            writeln!(
+                output,
+                "| Lint Passed | {} | {} | {} | - |",
+                Self::format_rate(baseline, baseline.lint_pass_rate),
+                Self::format_rate(aicms, aicms.lint_pass_rate),
+                Self::format_optional_delta(
+                    delta.map(|_| aicms.lint_pass_rate - baseline.lint_pass_rate)
+                )
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            output,
+            "| Annotation Quality | {} | {} | {} | {} |",
+            Self::format_rate(baseline, baseline.avg_annotation_quality),
+            Self::format_rate(aicms, aicms.avg_annotation_quality),
+            Self::format_optional_delta(delta.map(|d| d.annotation_quality)),
+            Self::format_effect_size(delta, "annotation_quality")
+        )
+        .unwrap();
+
+        if delta.is_none() {
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "Only one mode was run, so no deltas are reported."
+            )
+            .unwrap();
+        } else if delta.is_some_and(|d| !d.effect_sizes.is_empty()) {
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "Effect sizes compare the executions of both modes: Cohen's d is the delta in \
+                 pooled standard deviations, Cliff's delta the share of execution pairs AICMS \
+                 wins minus the share it loses (magnitude by |delta|: below 0.147 negligible, \
+                 0.33 small, 0.474 medium, else large)."
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate composite score section: the weighted score of each mode, labeled
+    ///            with the weights used
+    /// @ai:effects pure
+    fn generate_composite_section(results: &BenchmarkResults) -> String {
+        let Some(composite) = &results.composite else {
+            return String::new();
+        };
+        let score =
+            |value: Option<f64>| value.map_or_else(|| "-".to_string(), |v| format!("{:.1}", v));
+
+        let mut output = String::new();
+
+        writeln!(output, "## Composite Score").unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "**Composite** = {}", composite.label()).unwrap();
+        if !composite.missing.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "Not scored (no data in this run; the other weights are scaled up): {}",
+                composite.missing.join(", ")
+            )
+            .unwrap();
+        }
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Score |").unwrap();
+        writeln!(output, "|------|-------|").unwrap();
+        writeln!(output, "| Baseline | {} |", score(composite.baseline)).unwrap();
+        writeln!(output, "| AICMS | {} |", score(composite.aicms)).unwrap();
+        if let Some(delta) = composite.delta() {
+            writeln!(output, "| Delta | {:+.1} |", delta).unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate difficulty-normalized section: the headline rates of each mode with
+    ///            the difficulties weighted, next to the raw rates
+    /// @ai:effects pure
+    fn generate_difficulty_normalized_section(results: &BenchmarkResults) -> String {
+        let Some(normalized) = &results.difficulty_normalized else {
+            return String::new();
+        };
+        let rate =
+            |value: Option<f64>| value.map_or_else(|| "-".to_string(), |v| format!("{:.1}%", v));
+        let delta =
+            |value: Option<f64>| value.map_or_else(|| "n/a".to_string(), |v| format!("{:+.1}%", v));
+        let weights: Vec<String> = normalized
+            .weights
+            .iter()
+            .map(|(difficulty, weight)| format!("{} {:.2}", difficulty, weight))
+            .collect();
+
+        let mut output = String::new();
+
+        writeln!(output, "## Difficulty-Normalized Results").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Each difficulty counts by its weight instead of its number of executions: {}",
+            weights.join(", ")
+        )
+        .unwrap();
+        if !normalized.missing.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "Not weighted (no executions in a mode; the other weights are scaled up): {}",
+                normalized.missing.join(", ")
+            )
+            .unwrap();
+        }
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Metric | Baseline | AICMS | Delta | Raw Baseline | Raw AICMS | Raw Delta |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|--------|----------|-------|-------|--------------|-----------|-----------|"
+        )
+        .unwrap();
+
+        for metric in &normalized.metrics {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} | {} | {} | {} |",
+                metric.label(),
+                rate(metric.baseline),
+                rate(metric.aicms),
+                delta(metric.delta()),
+                rate(metric.raw_baseline),
+                rate(metric.raw_aicms),
+                delta(metric.raw_delta())
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate outlier section: the outlier tasks and the overall results without
+    ///            them next to the deltas with them
+    /// @ai:effects pure
+    fn generate_outlier_section(results: &BenchmarkResults) -> String {
+        let Some(outliers) = &results.outliers else {
+            return String::new();
+        };
+        let (baseline, aicms) = (&outliers.without.baseline, &outliers.without.aicms);
+        let (delta, delta_with) = (
+            outliers.without.delta.as_ref(),
+            results.overall.delta.as_ref(),
+        );
+
+        let mut output = String::new();
+
+        writeln!(output, "## Outliers").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Tasks with extreme results, pooled over modes and repetitions:"
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Task | Reason | Compilation Rate | Test Pass Rate |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|------|--------|------------------|----------------|"
+        )
+        .unwrap();
+        for task in &outliers.tasks {
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% | {:.1}% |",
+                task.task_id,
+                task.reason.as_str(),
+                task.outcome.compilation_rate,
+                task.outcome.test_pass_rate
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        writeln!(output, "Overall results without them:").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Metric | Baseline | AICMS | Delta | Delta With Outliers |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|--------|----------|-------|-------|---------------------|"
+        )
+        .unwrap();
+        /// A table row: its label, the mode's value and the delta's value
+        type Row = (
+            &'static str,
+            fn(&AggregateStats) -> f64,
+            fn(&DeltaStats) -> f64,
+        );
+        let rows: [Row; 4] = [
+            (
+                "Compilation Rate",
+                |s| s.compilation_rate,
+                |d| d.compilation_rate,
+            ),
+            (
+                "Test Pass Rate",
+                |s| s.avg_test_pass_rate,
+                |d| d.test_pass_rate,
+            ),
+            (
+                "Lint Compliance",
+                |s| s.avg_lint_compliance,
+                |d| d.lint_compliance,
+            ),
+            (
+                "Annotation Quality",
+                |s| s.avg_annotation_quality,
+                |d| d.annotation_quality,
+            ),
+        ];
+        for (name, value, change) in rows {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} | {} |",
+                name,
+                Self::format_rate(baseline, value(baseline)),
+                Self::format_rate(aicms, value(aicms)),
+                Self::format_optional_delta(delta.map(change)),
+                Self::format_optional_delta(delta_with.map(change))
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+    /// @ai:intent Generate confidence interval section: bootstrap intervals of the overall
+    ///            rates and of the comparison win rate
+    /// @ai:effects pure
+    fn generate_interval_section(results: &BenchmarkResults) -> String {
+        let (baseline, aicms) = (&results.overall.baseline, &results.overall.aicms);
+        let win_rate = results
+            .claude_stats
+            .as_ref()
+            .and_then(|stats| stats.win_rate_ci.map(|ci| (stats.win_rate(), ci)));
+        let modes: Vec<_> = Self::modes_run(baseline, aicms)
+            .into_iter()
+            .filter(|(_, stats)| stats.compilation_rate_ci.is_some())
+            .collect();
+        if modes.is_empty() && win_rate.is_none() {
+            return String::new();
+        }
+
+        let mut output = String::new();
+        let cell = |value: f64, ci: Option<ConfidenceInterval>| match ci {
+            Some(ci) => format!("{:.1}% ({})", value, ci.format()),
+            None => format!("{:.1}%", value),
+        };
+
+        writeln!(output, "## Confidence Intervals").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "95% BCa bootstrap intervals over executions. Wide intervals mean the corpus is \
+             too small to pin the rate down; overlapping intervals alone do not show that the \
+             modes perform the same."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+
+        if !modes.is_empty() {
+            writeln!(output, "| Mode | Compilation Rate | Test Pass Rate |").unwrap();
+            writeln!(output, "|------|------------------|----------------|").unwrap();
+            for (mode, stats) in &modes {
+                writeln!(
+                    output,
+                    "| {} | {} | {} |",
+                    mode,
+                    cell(stats.compilation_rate, stats.compilation_rate_ci),
+                    cell(stats.avg_test_pass_rate, stats.test_pass_rate_ci)
+                )
+                .unwrap();
+            }
+            writeln!(output).unwrap();
+        }
+
+        if let Some((rate, ci)) = win_rate {
+            writeln!(
+                output,
+                "**AICMS comparison win rate:** {} (ties count half)",
+                cell(rate, Some(ci))
+            )
+            .unwrap();
+            writeln!(output).unwrap();
+        }
+
+        output
+    }
+
+    /// @ai:intent Generate repetition variance section: how much each headline metric varies
+    ///            between repetitions of a task (only when tasks ran more than once)
+    /// @ai:effects pure
+    fn generate_repetition_section(results: &BenchmarkResults) -> String {
+        let modes: Vec<_> = Self::modes_run(&results.overall.baseline, &results.overall.aicms)
+            .into_iter()
+            .filter(|(_, stats)| !stats.repetition_spread.is_empty())
+            .collect();
+        if modes.is_empty() {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Repetition Variance").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Spread between repetitions of the same task, over tasks that ran more than once: \
+             the pooled standard deviation (in points), and the average of each task's lowest \
+             and highest repetition."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Metric | Tasks | Std Dev | Min | Max |").unwrap();
+        writeln!(output, "|------|--------|-------|---------|-----|-----|").unwrap();
+
+        for (mode, stats) in modes {
+            for spread in &stats.repetition_spread {
+                writeln!(
+                    output,
+                    "| {} | {} | {} | {:.1} | {:.1}% | {:.1}% |",
+                    mode,
+                    spread.label(),
+                    spread.tasks,
+                    spread.std_dev,
+                    spread.min,
+                    spread.max
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate reproducibility section: how often repetitions of a task agree on
+    ///            the outcome, and the stability score, per mode (only when tasks ran more than once)
+    /// @ai:effects pure
+    fn generate_reproducibility_section(results: &BenchmarkResults) -> String {
+        let modes: Vec<_> = Self::modes_run(&results.overall.baseline, &results.overall.aicms)
+            .into_iter()
+            .filter_map(|(mode, stats)| Some((mode, stats.reproducibility.as_ref()?)))
+            .collect();
+        if modes.is_empty() {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Reproducibility").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Share of repeated tasks that compiled in every repetition or in none, and that \
+             passed the same share of tests every time, with the average range of the test pass \
+             rate. The stability score averages the first and 100 minus the range; a low score \
+             means a single run of a task says little."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Mode | Tasks | Consistent Compilation | Consistent Pass Rate | Pass Rate Range | Stability |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|------|-------|------------------------|----------------------|-----------------|-----------|"
+        )
+        .unwrap();
+
+        for (mode, reproducibility) in modes {
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% | {:.1}% | {:.1} | {:.1} |",
+                mode,
+                reproducibility.tasks,
+                reproducibility.consistent_compilation_rate,
+                reproducibility.consistent_pass_rate,
+                reproducibility.avg_pass_rate_range,
+                reproducibility.score
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate distribution section: quartiles and 95th percentile of the test
+    ///            pass rate, execution time and tokens per execution
+    /// @ai:effects pure
+    fn generate_distribution_section(results: &BenchmarkResults) -> String {
+        let modes = Self::modes_run(&results.overall.baseline, &results.overall.aicms);
+        if modes.is_empty() {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Distributions").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Percentiles over executions. A median well above the average means a few tasks \
+             pull the average down."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Metric | P25 | Median | P75 | P95 |").unwrap();
+        writeln!(output, "|------|--------|-----|--------|-----|-----|").unwrap();
+
+        for (mode, stats) in modes {
+            // Metric, percentiles, decimals and unit
+            let rows = [
+                ("Test pass rate", stats.test_pass_rate_percentiles, 1, "%"),
+                ("Execution time", stats.execution_time_percentiles, 0, "ms"),
+                ("Tokens", stats.token_percentiles, 0, ""),
+            ];
+            for (metric, percentiles, decimals, unit) in rows {
+                let Some(p) = percentiles else {
+                    continue;
+                };
+                let format = |value: f64| format!("{:.*}{}", decimals, value, unit);
+                writeln!(
+                    output,
+                    "| {} | {} | {} | {} | {} | {} |",
+                    mode,
+                    metric,
+                    format(p.p25),
+                    format(p.median),
+                    format(p.p75),
+                    format(p.p95)
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate significance section: paired tests of the headline deltas
+    /// @ai:effects pure
+    fn generate_significance_section(results: &BenchmarkResults) -> String {
+        let win_test = results.claude_stats.as_ref().and_then(|s| s.win_test);
+        if results.significance.is_empty() && win_test.is_none() {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Statistical Significance").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Two-sided p-values for the difference between the modes, pairing each task's \
+             baseline and AICMS results (averaged over repetitions). A p-value below 0.05 means \
+             a difference this large would be unlikely if the modes performed the same."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+
+        if !results.significance.is_empty() {
+            writeln!(
+                output,
+                "| Metric | Tasks | Mean Difference | Paired t-test p | Wilcoxon p |"
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "|--------|-------|-----------------|-----------------|------------|"
+            )
+            .unwrap();
+
+            for test in &results.significance {
+                writeln!(
+                    output,
+                    "| {} | {} | {} | {} | {} |",
+                    test.label(),
+                    test.pairs,
+                    Self::format_delta(test.mean_difference),
+                    PairedTest::format_p(test.t_test_p),
+                    PairedTest::format_p(test.wilcoxon_p)
+                )
+                .unwrap();
+            }
+            writeln!(output).unwrap();
+        }
+
+        if let Some(test) = win_test {
+            writeln!(
+                output,
+                "**Comparison wins (exact binomial test vs 50%):** {}",
+                test.format()
+            )
+            .unwrap();
+            writeln!(output).unwrap();
+        }
+
+        output
+    }
+
+    /// @ai:intent Generate category breakdown section
+    /// @ai:effects pure
+    fn generate_category_section(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "## Results by Category").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Category | Baseline Compile | AICMS Compile | Baseline Tests | AICMS Tests |"
+        )
+        .unwrap();
+        writeln!(output, "|----------|-----------------|---------------|----------------|-------------|").unwrap();
+
+        for cat in &results.by_category {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} | {} |",
+                cat.category,
+                Self::format_rate(&cat.baseline, cat.baseline.compilation_rate),
+                Self::format_rate(&cat.aicms, cat.aicms.compilation_rate),
+                Self::format_rate(&cat.baseline, cat.baseline.avg_test_pass_rate),
+                Self::format_rate(&cat.aicms, cat.aicms.avg_test_pass_rate)
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate language breakdown section
+    /// @ai:effects pure
+    fn generate_language_section(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "## Results by Language").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Language | Baseline Compile | AICMS Compile | Baseline Tests | AICMS Tests |"
+        )
+        .unwrap();
+        writeln!(output, "|----------|-----------------|---------------|----------------|-------------|").unwrap();
+
+        for lang in &results.by_language {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} | {} |",
+                lang.language,
+                Self::format_rate(&lang.baseline, lang.baseline.compilation_rate),
+                Self::format_rate(&lang.aicms, lang.aicms.compilation_rate),
+                Self::format_rate(&lang.baseline, lang.baseline.avg_test_pass_rate),
+                Self::format_rate(&lang.aicms, lang.aicms.avg_test_pass_rate)
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate difficulty breakdown section
+    /// @ai:effects pure
+    fn generate_difficulty_section(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "## Results by Difficulty").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Difficulty | Baseline Compile | AICMS Compile | Baseline Tests | AICMS Tests |"
+        )
+        .unwrap();
+        writeln!(output, "|------------|-----------------|---------------|----------------|-------------|").unwrap();
+
+        for diff in &results.by_difficulty {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} | {} |",
+                diff.difficulty,
+                Self::format_rate(&diff.baseline, diff.baseline.compilation_rate),
+                Self::format_rate(&diff.aicms, diff.aicms.compilation_rate),
+                Self::format_rate(&diff.baseline, diff.baseline.avg_test_pass_rate),
+                Self::format_rate(&diff.aicms, diff.aicms.avg_test_pass_rate)
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate results by task tag (only when tasks have tags)
+    /// @ai:effects pure
+    fn generate_tag_section(results: &BenchmarkResults) -> String {
+        if results.by_tag.is_empty() {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Results by Tag").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Tag | Baseline Compile | AICMS Compile | Baseline Tests | AICMS Tests |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|-----|-----------------|---------------|----------------|-------------|"
+        )
+        .unwrap();
+
+        for tag in &results.by_tag {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} | {} |",
+                tag.tag,
+                Self::format_rate(&tag.baseline, tag.baseline.compilation_rate),
+                Self::format_rate(&tag.aicms, tag.aicms.compilation_rate),
+                Self::format_rate(&tag.baseline, tag.baseline.avg_test_pass_rate),
+                Self::format_rate(&tag.aicms, tag.aicms.avg_test_pass_rate)
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate the judge's results per task category, language and difficulty
+    ///            (only when comparisons ran on tasks of the corpus)
+    /// @ai:effects pure
+    fn generate_comparison_breakdown_section(results: &BenchmarkResults) -> String {
+        let Some(stats) = &results.claude_stats else {
+            return String::new();
+        };
+        let breakdowns = [
+            ("Category", &stats.by_category),
+            ("Language", &stats.by_language),
+            ("Difficulty", &stats.by_difficulty),
+        ];
+        if breakdowns.iter().all(|(_, groups)| groups.is_empty()) {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Comparison Breakdown").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Judge verdicts grouped by task. The win rate counts ties as half a win."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+
+        for (label, groups) in breakdowns {
+            if groups.is_empty() {
+                continue;
+            }
+            writeln!(output, "### By {}", label).unwrap();
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "| {} | Comparisons | AICMS Wins | Baseline Wins | Ties | AICMS Win Rate | Baseline Score | AICMS Score |",
+                label
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "|{}|-------------|------------|---------------|------|----------------|----------------|-------------|",
+                "-".repeat(label.len() + 2)
+            )
+            .unwrap();
+            for group in groups {
+                writeln!(
+                    output,
+                    "| {} | {} | {} | {} | {} | {:.1}% | {:.1} | {:.1} |",
+                    group.group,
+                    group.comparisons(),
+                    group.aicms_wins,
+                    group.baseline_wins,
+                    group.ties,
+                    group.win_rate(),
+                    group.avg_baseline_score,
+                    group.avg_aicms_score
+                )
+                .unwrap();
+            }
+            writeln!(output).unwrap();
+        }
+
+        output
+    }
+
+    /// @ai:intent Generate the baseline vs AICMS results of each task run in both modes,
+    ///            flagging the tasks where AICMS did worse
+    /// @ai:effects pure
+    fn generate_head_to_head_section(results: &BenchmarkResults) -> String {
+        if results.head_to_head.is_empty() {
+            return String::new();
+        }
+
+        let mut output = String::new();
+        let regressed = results
+            .head_to_head
+            .iter()
+            .filter(|t| t.regressed())
+            .count();
+
+        writeln!(output, "## Per-Task Results").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Baseline / AICMS results of each task run in both modes, averaged over repetitions. \
+             {} of {} tasks regressed: AICMS compiled less often or passed fewer tests.",
+            regressed,
+            results.head_to_head.len()
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Task | Compiled | Test Pass Rate | Tokens | Judge | |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|------|----------|----------------|--------|-------|-|"
+        )
+        .unwrap();
+
+        for task in &results.head_to_head {
+            let mut name = task.task_id.clone();
+            if results.by_model.len() > 1 {
+                write!(name, " ({})", task.model).unwrap();
+            }
+            if let Some(sampling) = &task.sampling {
+                write!(name, " ({})", sampling).unwrap();
+            }
+            if let Some(skill) = &task.skill {
+                write!(name, " ({})", skill).unwrap();
+            }
+            writeln!(
+                output,
+                "| {} | {:.0}% / {:.0}% | {:.1}% / {:.1}% | {:.0} / {:.0} | {} | {} |",
+                name,
+                task.baseline.compilation_rate,
+                task.aicms.compilation_rate,
+                task.baseline.test_pass_rate,
+                task.aicms.test_pass_rate,
+                task.baseline.avg_tokens,
+                task.aicms.avg_tokens,
+                task.winner.as_deref().unwrap_or("-"),
+                if task.regressed() {
+                    "**regressed**"
+                } else {
+                    ""
+                }
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate the most frequent compiler errors by code (Rust diagnostics)
+    /// @ai:effects pure
+    fn generate_compile_error_section(results: &BenchmarkResults) -> String {
+        // Per code: the first message seen, and the error counts of each mode
+        let mut by_code: BTreeMap<&str, (&str, u32, u32)> = BTreeMap::new();
+        for m in &results.task_metrics {
+            for error in &m.compile_errors {
+                let code = error.code.as_deref().unwrap_or("(no code)");
+                let entry = by_code.entry(code).or_insert((&error.message, 0, 0));
+                match m.mode.as_str() {
+                    "baseline" => entry.1 += 1,
+                    _ => entry.2 += 1,
+                }
+            }
+        }
+
+        if by_code.is_empty() {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Compiler Errors").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Most frequent error codes in executions that did not compile (Rust). \
+             Syntax errors have no code."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Code | Example | Baseline | AICMS |").unwrap();
+        writeln!(output, "|------|---------|----------|-------|").unwrap();
+
+        let mut codes: Vec<_> = by_code.into_iter().collect();
+        codes.sort_by_key(|(_, (_, baseline, aicms))| std::cmp::Reverse(baseline + aicms));
+        for (code, (message, baseline, aicms)) in codes.into_iter().take(10) {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} |",
+                code,
+                message.replace('|', "\\|"),
+                baseline,
+                aicms
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate side-by-side model section (multi-model runs only)
+    /// @ai:effects pure
+    fn generate_model_section(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        if results.by_model.len() < 2 {
+            return output;
+        }
+
+        writeln!(output, "## Results by Model").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Model | Baseline Compile | AICMS Compile | Baseline Tests | AICMS Tests | Tests Delta |"
+        )
+        .unwrap();
+        writeln!(output, "|-------|-----------------|---------------|----------------|-------------|-------------|").unwrap();
+
+        for model in &results.by_model {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} | {} | {} |",
+                model.model,
+                Self::format_rate(&model.baseline, model.baseline.compilation_rate),
+                Self::format_rate(&model.aicms, model.aicms.compilation_rate),
+                Self::format_rate(&model.baseline, model.baseline.avg_test_pass_rate),
+                Self::format_rate(&model.aicms, model.aicms.avg_test_pass_rate),
+                Self::format_optional_delta(model.delta.as_ref().map(|d| d.test_pass_rate))
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate per-setting section (temperature/seed sweeps only)
+    /// @ai:effects pure
+    fn generate_sampling_section(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        if results.by_sampling.len() < 2 {
+            return output;
+        }
+
+        writeln!(output, "## Results by Sampling Setting").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Setting | Baseline Compile | AICMS Compile | Baseline Tests | AICMS Tests | Tests Delta |"
+        )
+        .unwrap();
+        writeln!(output, "|---------|-----------------|---------------|----------------|-------------|-------------|").unwrap();
+
+        for setting in &results.by_sampling {
+            writeln!(
+                output,
+                "| {} | {} | {} | {} | {} | {} |",
+                setting.sampling,
+                Self::format_rate(&setting.baseline, setting.baseline.compilation_rate),
+                Self::format_rate(&setting.aicms, setting.aicms.compilation_rate),
+                Self::format_rate(&setting.baseline, setting.baseline.avg_test_pass_rate),
+                Self::format_rate(&setting.aicms, setting.aicms.avg_test_pass_rate),
+                Self::format_optional_delta(setting.delta.as_ref().map(|d| d.test_pass_rate))
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate per-variant section (skill matrix runs only)
+    /// @ai:effects pure
+    fn generate_skill_section(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        if results.by_skill.is_empty() {
+            return output;
+        }
+
+        writeln!(output, "## Results by Skill Variant").unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "Every variant is compared against the same baseline run.").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Variant | AICMS Compile | AICMS Tests | AICMS Annotations | Compile Delta | Tests Delta | Tests Wilcoxon p |"
+        )
+        .unwrap();
+        writeln!(output, "|---------|---------------|-------------|-------------------|---------------|-------------|------------------|").unwrap();
+
+        for variant in &results.by_skill {
+            let tests_p = variant
+                .significance
+                .iter()
+                .find(|t| t.metric == "test_pass_rate")
+                .and_then(|t| t.wilcoxon_p);
+            writeln!(
+                output,
+                "| {} | {} | {} | {} | {} | {} | {} |",
+                variant.skill,
+                Self::format_rate(&variant.aicms, variant.aicms.compilation_rate),
+                Self::format_rate(&variant.aicms, variant.aicms.avg_test_pass_rate),
+                Self::format_rate(&variant.aicms, variant.aicms.avg_annotation_quality),
+                Self::format_optional_delta(variant.delta.as_ref().map(|d| d.compilation_rate)),
+                Self::format_optional_delta(variant.delta.as_ref().map(|d| d.test_pass_rate)),
+                PairedTest::format_p(tests_p)
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate implementation ranking section (runs compared across models,
+    ///            settings or skill variants only)
+    /// @ai:effects pure
+    fn generate_ranking_section(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        if results.ranking_stats.is_empty() {
+            return output;
+        }
+
+        writeln!(output, "## Implementation Ranking").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Every implementation of a task judged against the others. The rating is a \
+             Bradley-Terry fit to all pairwise outcomes on the Elo scale: the implementations \
+             average 1500, and 400 points more means 10:1 odds of winning a judgment."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Implementation | Rating | Tasks | Avg Rank | Avg Score | Firsts | W / L / T |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|----------------|--------|-------|----------|-----------|--------|-----------|"
+        )
+        .unwrap();
+
+        for entry in &results.ranking_stats {
+            writeln!(
+                output,
+                "| {} | {:.0} | {} | {:.2} | {:.1} | {} | {} / {} / {} |",
+                entry.label,
+                entry.rating,
+                entry.tasks,
+                entry.avg_rank,
+                entry.avg_score,
+                entry.first_places,
+                entry.wins,
+                entry.losses,
+                entry.ties
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate per-turn section (multi-turn repair runs only)
+    /// @ai:effects pure
+    fn generate_repair_section(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        if results.repair_curve.is_empty() {
+            return output;
+        }
+
+        writeln!(output, "## Repair Turns").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Rates after each turn; turn 0 is the first attempt and later turns feed compile/test failures back."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Turn | Baseline Compile | AICMS Compile | Baseline Tests | AICMS Tests |"
+        )
+        .unwrap();
+        writeln!(output, "|------|-----------------|---------------|----------------|-------------|").unwrap();
+
+        for turn in &results.repair_curve {
+            writeln!(
+                output,
+                "| {} | {:.1}% | {:.1}% | {:.1}% | {:.1}% |",
+                turn.turn,
+                turn.baseline_compilation_rate,
+                turn.aicms_compilation_rate,
+                turn.baseline_test_pass_rate,
+                turn.aicms_test_pass_rate
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate token usage section
+    /// @ai:effects pure
+    fn generate_token_section(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "## Token Usage").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Mode | Total Input Tokens | Total Output Tokens | Avg Execution Time |"
+        )
+        .unwrap();
+        writeln!(output, "|------|-------------------|--------------------|--------------------|").unwrap();
+
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+        let modes = Self::modes_run(baseline, aicms);
+
+        for (mode, stats) in &modes {
+            writeln!(
+                output,
+                "| {} | {} | {} | {:.0}ms |",
+                mode,
+                stats.total_input_tokens,
+                stats.total_output_tokens,
+                stats.avg_execution_time_ms
+            )
+            .unwrap();
+        }
+        let cached = [baseline, aicms]
+            .iter()
+            .any(|s| s.total_cache_creation_tokens + s.total_cache_read_tokens > 0);
+
+        if cached {
+            writeln!(output).unwrap();
+            writeln!(output, "### Prompt Caching").unwrap();
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "| Mode | Cache Writes | Cache Reads | Hit Rate | Saved (input-token equiv.) |"
+            )
+            .unwrap();
+            writeln!(output, "|------|--------------|-------------|----------|----------------------------|").unwrap();
+
+            for (mode, stats) in &modes {
+                writeln!(
+                    output,
+                    "| {} | {} | {} | {:.1}% | {:.0} |",
+                    mode,
+                    stats.total_cache_creation_tokens,
+                    stats.total_cache_read_tokens,
+                    stats.cache_hit_rate(),
+                    stats.cache_saved_tokens
+                )
+                .unwrap();
+            }
+        }
+
+        if baseline.priced_count > 0 || aicms.priced_count > 0 {
+            writeln!(output).unwrap();
+            writeln!(output, "### Cost").unwrap();
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "| Mode | Priced Executions | Total Cost | Avg per Execution | Cost per Pass |"
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "|------|-------------------|------------|-------------------|---------------|"
+            )
+            .unwrap();
+
+            for (mode, stats) in &modes {
+                writeln!(
+                    output,
+                    "| {} | {} | ${:.2} | ${:.4} | {} |",
+                    mode,
+                    stats.priced_count,
+                    stats.total_cost_usd,
+                    stats.avg_cost_usd(),
+                    Self::format_cost(stats.cost_per_pass_usd)
+                )
+                .unwrap();
+            }
+            let delta = results.overall.delta.as_ref();
+            if let Some((total, avg)) = delta.and_then(|d| d.total_cost_usd.zip(d.avg_cost_usd)) {
+                writeln!(
+                    output,
+                    "| Delta | | {:+.2} | {:+.4} | {} |",
+                    total,
+                    avg,
+                    delta
+                        .and_then(|d| d.cost_per_pass_usd)
+                        .map_or("-".to_string(), |c| format!("{:+.4}", c))
+                )
+                .unwrap();
+            }
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "A pass is an execution passing all of its tests; one passing half of them \
+                 counts as half a pass."
+            )
+            .unwrap();
+
+            let priced_categories: Vec<_> = results
+                .by_category
+                .iter()
+                .filter(|c| c.baseline.priced_count > 0 || c.aicms.priced_count > 0)
+                .collect();
+            if !priced_categories.is_empty() {
+                writeln!(output).unwrap();
+                writeln!(output, "#### Cost by Category").unwrap();
+                writeln!(output).unwrap();
+                writeln!(
+                    output,
+                    "| Category | Baseline Total | AICMS Total | Baseline Avg | AICMS Avg | Baseline per Pass | AICMS per Pass |"
+                )
+                .unwrap();
+                writeln!(output, "|----------|----------------|-------------|--------------|-----------|-------------------|----------------|").unwrap();
+                for cat in priced_categories {
+                    writeln!(
+                        output,
+                        "| {} | ${:.2} | ${:.2} | ${:.4} | ${:.4} | {} | {} |",
+                        cat.category,
+                        cat.baseline.total_cost_usd,
+                        cat.aicms.total_cost_usd,
+                        cat.baseline.avg_cost_usd(),
+                        cat.aicms.avg_cost_usd(),
+                        Self::format_cost(cat.baseline.cost_per_pass_usd),
+                        Self::format_cost(cat.aicms.cost_per_pass_usd)
+                    )
+                    .unwrap();
+                }
+            }
+
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "**Total run cost:** ${:.2}",
+                baseline.total_cost_usd + aicms.total_cost_usd
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate spec leakage section (quality control)
+    /// @ai:effects pure
+    fn generate_leakage_section(results: &BenchmarkResults) -> String {
+        let mut output = String::new();
+
+        writeln!(output, "## Spec Leakage").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Share of generated words copied verbatim from the task description \
+             (required identifiers excluded). Flagged executions mostly restate the spec."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Avg Overlap | Flagged |").unwrap();
+        writeln!(output, "|------|-------------|---------|").unwrap();
+
+        for (mode, stats) in Self::modes_run(&results.overall.baseline, &results.overall.aicms) {
+            writeln!(
+                output,
+                "| {} | {:.1}% | {}/{} |",
+                mode, stats.avg_spec_overlap, stats.spec_echo_count, stats.task_count
+            )
+            .unwrap();
+        }
+
+        let flagged: Vec<_> = results.task_metrics.iter().filter(|m| m.spec_echo).collect();
+
+        if !flagged.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(output, "Flagged executions:").unwrap();
+            writeln!(output).unwrap();
+
+            for m in flagged {
+                writeln!(
+                    output,
+                    "- `{}` ({}, rep {}): {:.1}% overlap",
+                    m.task_id, m.mode, m.repetition, m.spec_overlap
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate contamination section (quality control)
+    /// @ai:effects pure
+    fn generate_contamination_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.contamination_checked_count == 0 && aicms.contamination_checked_count == 0 {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Contamination").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Near-duplicates of a reference solution or of another task's output, by shared \
+             12-token sequences (starter code excluded). Flagged executions may be memorized."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Checked | Flagged |").unwrap();
+        writeln!(output, "|------|---------|---------|").unwrap();
+
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {} |",
+                mode, stats.contamination_checked_count, stats.contamination_count
+            )
+            .unwrap();
+        }
+
+        let flagged: Vec<_> = results
+            .task_metrics
+            .iter()
+            .filter_map(|m| m.contamination.as_ref().map(|c| (m, c)))
+            .filter(|(_, c)| c.flagged)
+            .collect();
+
+        if !flagged.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(output, "Flagged executions:").unwrap();
+            writeln!(output).unwrap();
+
+            for (m, c) in flagged {
+                let (share, source) = c.highest();
+                writeln!(
+                    output,
+                    "- `{}` ({}, rep {}): {:.1}% matches {}",
+                    m.task_id, m.mode, m.repetition, share, source
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate contract trap section (modify tasks only)
+    /// @ai:effects pure
+    fn generate_contract_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.contracts_checked == 0 && aicms.contracts_checked == 0 {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Contract Traps").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Hidden tests for constraints documented only in the starter code of modify tasks."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Checked | Violated | Violation Rate |").unwrap();
+        writeln!(output, "|------|---------|----------|----------------|").unwrap();
+
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {} | {:.1}% |",
+                mode,
+                stats.contracts_checked,
+                stats.contracts_violated,
+                stats.contract_violation_rate
+            )
+            .unwrap();
+        }
+
+        let mut by_trap: BTreeMap<(&str, &str), (u32, u32)> = BTreeMap::new();
+        for m in &results.task_metrics {
+            for trap in &m.violated_contracts {
+                let counts = by_trap.entry((&m.task_id, trap)).or_default();
+                match m.mode.as_str() {
+                    "baseline" => counts.0 += 1,
+                    _ => counts.1 += 1,
+                }
+            }
+        }
+
+        if !by_trap.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(output, "| Task | Trap | Baseline Violations | AICMS Violations |").unwrap();
+            writeln!(output, "|------|------|---------------------|------------------|").unwrap();
+
+            for ((task_id, trap), (baseline, aicms)) in by_trap {
+                writeln!(output, "| {} | {} | {} | {} |", task_id, trap, baseline, aicms).unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate hidden reference test section (tasks with hidden tests only)
+    /// @ai:effects pure
+    fn generate_hidden_test_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.hidden_tested_count == 0 && aicms.hidden_tested_count == 0 {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Hidden Tests").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Reference tests the model never sees, run against the generated code."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Executions | Own Test Pass Rate | Hidden Test Pass Rate |").unwrap();
+        writeln!(output, "|------|------------|--------------------|-----------------------|").unwrap();
+
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% | {:.1}% |",
+                mode,
+                stats.hidden_tested_count,
+                stats.avg_test_pass_rate,
+                stats.avg_hidden_test_pass_rate
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate I/O case section (tasks with I/O cases only)
+    /// @ai:effects pure
+    fn generate_io_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.io_tested_count == 0 && aicms.io_tested_count == 0 {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## I/O Cases").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "The built program run with each case's arguments and stdin; exit code and stdout \
+             must match."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Executions | Cases Passed |").unwrap();
+        writeln!(output, "|------|------------|--------------|").unwrap();
+
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% |",
+                mode, stats.io_tested_count, stats.avg_io_pass_rate
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate API conformance section (tasks that list a required API only)
+    /// @ai:effects pure
+    fn generate_api_conformance_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.api_checked_count == 0 && aicms.api_checked_count == 0 {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## API Conformance").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Required public functions and types declared with the required signatures, \
+             independent of any test."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Executions | Conformance |").unwrap();
+        writeln!(output, "|------|------------|-------------|").unwrap();
+
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% |",
+                mode, stats.api_checked_count, stats.avg_api_conformance
+            )
+            .unwrap();
+        }
+
+        // Items missed per task, counting wrong signatures as missed
+        let mut by_item: BTreeMap<String, (u32, u32)> = BTreeMap::new();
+        for m in &results.task_metrics {
+            let Some(conformance) = &m.api_conformance else {
+                continue;
+            };
+            let missing = conformance.missing.iter().map(String::as_str);
+            let mismatched = conformance
+                .mismatched
+                .iter()
+                .map(|item| item.split(':').next().unwrap_or(item));
+            for item in missing.chain(mismatched) {
+                let counts = by_item
+                    .entry(format!("{}: `{}`", m.task_id, item))
+                    .or_default();
+                match m.mode.as_str() {
+                    "baseline" => counts.0 += 1,
+                    _ => counts.1 += 1,
+                }
+            }
+        }
+
+        if !by_item.is_empty() {
+            let mut items: Vec<_> = by_item.into_iter().collect();
+            items.sort_by_key(|(_, (baseline, aicms))| std::cmp::Reverse(baseline + aicms));
+
+            writeln!(output).unwrap();
+            writeln!(output, "| Most Often Missed | Baseline | AICMS |").unwrap();
+            writeln!(output, "|-------------------|----------|-------|").unwrap();
+
+            for (item, (baseline, aicms)) in items.into_iter().take(10) {
+                writeln!(output, "| {} | {} | {} |", item, baseline, aicms).unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate reference alignment section (tasks with a gold solution only)
+    /// @ai:effects pure
+    fn generate_reference_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.reference_compared_count == 0 && aicms.reference_compared_count == 0 {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Reference Alignment").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Agreement with each task's gold solution: public API present, reference tests \
+             passed and, where enabled, textual similarity."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Executions | API Match | Alignment |").unwrap();
+        writeln!(output, "|------|------------|-----------|-----------|").unwrap();
+
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% | {:.1}% |",
+                mode,
+                stats.reference_compared_count,
+                stats.avg_reference_api_match,
+                stats.avg_reference_alignment
+            )
+            .unwrap();
+        }
+
+        let missing: Vec<_> = results
+            .task_metrics
+            .iter()
+            .filter_map(|m| m.reference.as_ref().map(|r| (m, r)))
+            .filter(|(_, r)| !r.missing_api.is_empty())
+            .collect();
+
+        if !missing.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(output, "Executions missing reference API items:").unwrap();
+            writeln!(output).unwrap();
+            for (m, r) in missing {
+                writeln!(
+                    output,
+                    "- `{}` ({}, rep {}): {}",
+                    m.task_id,
+                    m.mode,
+                    m.repetition,
+                    r.missing_api.join(", ")
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate annotation inference section (tasks with gold files only)
+    /// @ai:effects pure
+    fn generate_inference_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.inference_scored_count == 0 && aicms.inference_scored_count == 0 {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Annotation Inference").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Inferred annotations compared with the annotated gold files of inference tasks."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Executions | Precision | Recall | F1 |").unwrap();
+        writeln!(output, "|------|------------|-----------|--------|----|").unwrap();
+
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% | {:.1}% | {:.1}% |",
+                mode,
+                stats.inference_scored_count,
+                stats.avg_inference_precision,
+                stats.avg_inference_recall,
+                stats.avg_inference_f1
+            )
+            .unwrap();
+        }
+
+        // Per-tag rates pooled over all executions of each mode
+        let mut by_tag: BTreeMap<&str, [TagScore; 2]> = BTreeMap::new();
+        for m in &results.task_metrics {
+            let Some(inference) = &m.inference else {
+                continue;
+            };
+            let index = usize::from(m.mode != "baseline");
+            for tag in &inference.tags {
+                let pooled = &mut by_tag.entry(&tag.tag).or_default()[index];
+                pooled.true_positives += tag.true_positives;
+                pooled.false_positives += tag.false_positives;
+                pooled.false_negatives += tag.false_negatives;
+            }
+        }
+
+        if !by_tag.is_empty() {
+            let rates = |score: &TagScore| {
+                if score.true_positives + score.false_positives + score.false_negatives == 0 {
+                    "- | -".to_string()
+                } else {
+                    format!("{:.1}% | {:.1}%", score.precision(), score.recall())
+                }
+            };
+
+            writeln!(output).unwrap();
+            writeln!(
+                output,
+                "| Tag | Baseline Precision | Baseline Recall | AICMS Precision | AICMS Recall |"
+            )
+            .unwrap();
+            writeln!(
+                output,
+                "|-----|--------------------|-----------------|-----------------|--------------|"
+            )
+            .unwrap();
+
+            for (tag, [baseline, aicms]) in by_tag {
+                writeln!(output, "| {} | {} | {} |", tag, rates(&baseline), rates(&aicms)).unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate idiomatic-lint section (clippy, ruff, eslint)
+    /// @ai:effects pure
+    fn generate_code_quality_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.quality_checked_count == 0 && aicms.quality_checked_count == 0 {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Code Quality").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "Idiomatic-lint warnings from clippy (Rust), ruff (Python) and eslint (TypeScript)."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Executions | Avg Warnings | Warnings / 100 Lines |").unwrap();
+        writeln!(output, "|------|------------|--------------|----------------------|").unwrap();
+
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {:.1} | {:.2} |",
+                mode,
+                stats.quality_checked_count,
+                stats.avg_quality_warnings,
+                stats.avg_quality_density
+            )
+            .unwrap();
+        }
+
+        let mut by_rule: BTreeMap<&str, (u32, u32)> = BTreeMap::new();
+        for m in &results.task_metrics {
+            let Some(quality) = &m.code_quality else {
+                continue;
+            };
+            for (rule, count) in &quality.rules {
+                let counts = by_rule.entry(rule).or_default();
+                match m.mode.as_str() {
+                    "baseline" => counts.0 += count,
+                    _ => counts.1 += count,
+                }
+            }
+        }
+
+        if !by_rule.is_empty() {
+            let mut rules: Vec<_> = by_rule.into_iter().collect();
+            rules.sort_by_key(|(_, (baseline, aicms))| std::cmp::Reverse(baseline + aicms));
+
+            writeln!(output).unwrap();
+            writeln!(output, "| Most Frequent Rule | Baseline | AICMS |").unwrap();
+            writeln!(output, "|--------------------|----------|-------|").unwrap();
+
+            for (rule, (baseline, aicms)) in rules.into_iter().take(10) {
+                writeln!(output, "| {} | {} | {} |", rule, baseline, aicms).unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate Python type checking section (mypy, pyright)
+    /// @ai:effects pure
+    fn generate_type_check_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.type_checked_count == 0 && aicms.type_checked_count == 0 {
+            return String::new();
+        }
+
         let mut output = String::new();
 
-        writeln!(output, "# AICMS Benchmark Results").unwrap();
+        writeln!(output, "## Type Checking").unwrap();
         writeln!(output).unwrap();
-        writeln!(output, "**Date:** {}", results.timestamp).unwrap();
-        writeln!(output, "**Model:** {}", results.model).unwrap();
-        writeln!(output, "**Repetitions:** {}", results.repetitions).unwrap();
+        writeln!(
+            output,
+            "Static type errors in Python code that compiled. Type-correct executions have none."
+        )
+        .unwrap();
         writeln!(output).unwrap();
+        writeln!(output, "| Mode | Executions | Type-Correct | Avg Errors |").unwrap();
+        writeln!(output, "|------|------------|--------------|------------|").unwrap();
+
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% | {:.1} |",
+                mode, stats.type_checked_count, stats.type_correct_rate, stats.avg_type_errors
+            )
+            .unwrap();
+        }
+
+        let mut by_code: BTreeMap<&str, (u32, u32)> = BTreeMap::new();
+        for m in &results.task_metrics {
+            let Some(check) = &m.type_check else {
+                continue;
+            };
+            for (code, count) in &check.rules {
+                let counts = by_code.entry(code).or_default();
+                match m.mode.as_str() {
+                    "baseline" => counts.0 += count,
+                    _ => counts.1 += count,
+                }
+            }
+        }
+
+        if !by_code.is_empty() {
+            let mut codes: Vec<_> = by_code.into_iter().collect();
+            codes.sort_by_key(|(_, (baseline, aicms))| std::cmp::Reverse(baseline + aicms));
+
+            writeln!(output).unwrap();
+            writeln!(output, "| Most Frequent Error | Baseline | AICMS |").unwrap();
+            writeln!(output, "|---------------------|----------|-------|").unwrap();
+
+            for (code, (baseline, aicms)) in codes.into_iter().take(10) {
+                writeln!(output, "| {} | {} | {} |", code, baseline, aicms).unwrap();
+            }
+        }
 
+        writeln!(output).unwrap();
         output
     }
 
-    /// @ai:intent Generate comparison table
+    /// @ai:intent Generate security section (dangerous patterns, dependency audits)
     /// @ai:effects pure
-    fn generate_comparison_table(
-        baseline: &AggregateStats,
-        aicms: &AggregateStats,
-        delta: &DeltaStats,
-    ) -> String {
+    fn generate_security_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.security_scanned_count == 0 && aicms.security_scanned_count == 0 {
+            return String::new();
+        }
+
         let mut output = String::new();
 
-        writeln!(output, "## Overall Results").unwrap();
+        writeln!(output, "## Security").unwrap();
         writeln!(output).unwrap();
         writeln!(
             output,
-            "| Metric | Baseline | AICMS | Delta |"
+            "Dangerous code patterns in non-test source files and, where audited, dependencies \
+             with known vulnerabilities. Clean executions have neither."
         )
         .unwrap();
-        writeln!(output, "|--------|----------|-------|-------|").unwrap();
-
+        writeln!(output).unwrap();
         writeln!(
             output,
-            "| Compilation Rate | {:.1}% | {:.1}% | {} |",
-            baseline.compilation_rate,
-            aicms.compilation_rate,
-            Self::format_delta(delta.compilation_rate)
+            "| Mode | Executions | Clean | Avg Patterns | Audited | Avg Vulnerable Deps |"
         )
         .unwrap();
-
         writeln!(
             output,
-            "| Test Pass Rate | {:.1}% | {:.1}% | {} |",
-            baseline.avg_test_pass_rate,
-            aicms.avg_test_pass_rate,
-            Self::format_delta(delta.test_pass_rate)
+            "|------|------------|-------|--------------|---------|---------------------|"
         )
         .unwrap();
 
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% | {:.2} | {} | {:.2} |",
+                mode,
+                stats.security_scanned_count,
+                stats.security_clean_rate,
+                stats.avg_dangerous_patterns,
+                stats.audited_count,
+                stats.avg_vulnerable_dependencies
+            )
+            .unwrap();
+        }
+
+        let mut by_rule: BTreeMap<&str, (u32, u32)> = BTreeMap::new();
+        for m in &results.task_metrics {
+            let Some(scan) = &m.security else {
+                continue;
+            };
+            for (rule, count) in scan.rules() {
+                let counts = by_rule.entry(rule).or_default();
+                match m.mode.as_str() {
+                    "baseline" => counts.0 += count,
+                    _ => counts.1 += count,
+                }
+            }
+        }
+
+        if !by_rule.is_empty() {
+            let mut rules: Vec<_> = by_rule.into_iter().collect();
+            rules.sort_by_key(|(_, (baseline, aicms))| std::cmp::Reverse(baseline + aicms));
+
+            writeln!(output).unwrap();
+            writeln!(output, "| Most Frequent Finding | Baseline | AICMS |").unwrap();
+            writeln!(output, "|-----------------------|----------|-------|").unwrap();
+
+            for (rule, (baseline, aicms)) in rules.into_iter().take(10) {
+                writeln!(output, "| {} | {} | {} |", rule, baseline, aicms).unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate structural complexity section
+    /// @ai:effects pure
+    fn generate_complexity_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.complexity_measured_count == 0 && aicms.complexity_measured_count == 0 {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Complexity").unwrap();
+        writeln!(output).unwrap();
         writeln!(
             output,
-            "| Lint Compliance | {:.1}% | {:.1}% | {} |",
-            baseline.avg_lint_compliance,
-            aicms.avg_lint_compliance,
-            Self::format_delta(delta.lint_compliance)
+            "Per-function averages over generated code (test functions excluded). \
+             Lower complexity and nesting, and a higher maintainability index (0-100), \
+             indicate structurally simpler code."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Mode | Executions | Cyclomatic | Nesting Depth | Maintainability |"
         )
         .unwrap();
-
         writeln!(
             output,
-            "| Annotation Quality | {:.1}% | {:.1}% | {} |",
-            baseline.avg_annotation_quality,
-            aicms.avg_annotation_quality,
-            Self::format_delta(delta.annotation_quality)
+            "|------|------------|------------|---------------|-----------------|"
         )
         .unwrap();
 
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {:.2} | {:.2} | {:.1} |",
+                mode,
+                stats.complexity_measured_count,
+                stats.avg_cyclomatic_complexity,
+                stats.avg_nesting_depth,
+                stats.avg_maintainability
+            )
+            .unwrap();
+        }
+
         writeln!(output).unwrap();
         output
     }
 
-    /// @ai:intent Generate category breakdown section
+    /// @ai:intent Generate code size section (files and lines per execution)
     /// @ai:effects pure
-    fn generate_category_section(results: &BenchmarkResults) -> String {
+    fn generate_code_size_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.sized_count == 0 && aicms.sized_count == 0 {
+            return String::new();
+        }
+
         let mut output = String::new();
 
-        writeln!(output, "## Results by Category").unwrap();
+        writeln!(output, "## Code Size").unwrap();
         writeln!(output).unwrap();
         writeln!(
             output,
-            "| Category | Baseline Compile | AICMS Compile | Baseline Tests | AICMS Tests |"
+            "Average generated files and non-blank source lines per execution."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Mode | Executions | Files | Lines | Non-Test Lines | Test Lines |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|------|------------|-------|-------|----------------|------------|"
         )
         .unwrap();
-        writeln!(output, "|----------|-----------------|---------------|----------------|-------------|").unwrap();
 
-        for cat in &results.by_category {
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
             writeln!(
                 output,
-                "| {} | {:.1}% | {:.1}% | {:.1}% | {:.1}% |",
-                cat.category,
-                cat.baseline.compilation_rate,
-                cat.aicms.compilation_rate,
-                cat.baseline.avg_test_pass_rate,
-                cat.aicms.avg_test_pass_rate
+                "| {} | {} | {:.1} | {:.0} | {:.0} | {:.0} |",
+                mode,
+                stats.sized_count,
+                stats.avg_files,
+                stats.avg_lines_of_code,
+                stats.avg_lines_of_code - stats.avg_test_lines,
+                stats.avg_test_lines
             )
             .unwrap();
         }
@@ -138,60 +2147,117 @@ impl MarkdownReporter {
         output
     }
 
-    /// @ai:intent Generate language breakdown section
+    /// @ai:intent Generate annotation density section (adoption, separate from validity)
     /// @ai:effects pure
-    fn generate_language_section(results: &BenchmarkResults) -> String {
+    fn generate_annotation_density_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.density_measured_count == 0 && aicms.density_measured_count == 0 {
+            return String::new();
+        }
+
         let mut output = String::new();
 
-        writeln!(output, "## Results by Language").unwrap();
+        writeln!(output, "## Annotation Density").unwrap();
         writeln!(output).unwrap();
         writeln!(
             output,
-            "| Language | Baseline Compile | AICMS Compile | Baseline Tests | AICMS Tests |"
+            "Annotations found by the AICMS parser in the generated code, whether or not they are valid."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Mode | Executions | Annotations / Function | Annotated Functions |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|------|------------|------------------------|---------------------|"
         )
         .unwrap();
-        writeln!(output, "|----------|-----------------|---------------|----------------|-------------|").unwrap();
 
-        for lang in &results.by_language {
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
             writeln!(
                 output,
-                "| {} | {:.1}% | {:.1}% | {:.1}% | {:.1}% |",
-                lang.language,
-                lang.baseline.compilation_rate,
-                lang.aicms.compilation_rate,
-                lang.baseline.avg_test_pass_rate,
-                lang.aicms.avg_test_pass_rate
+                "| {} | {} | {:.2} | {:.1}% |",
+                mode,
+                stats.density_measured_count,
+                stats.avg_annotations_per_function,
+                stats.avg_annotated_function_rate
             )
             .unwrap();
         }
 
+        let mut by_tag: BTreeMap<&str, (u32, u32)> = BTreeMap::new();
+        for m in &results.task_metrics {
+            let Some(density) = &m.annotation_density else {
+                continue;
+            };
+            for (tag, count) in &density.tags {
+                let counts = by_tag.entry(tag).or_default();
+                match m.mode.as_str() {
+                    "baseline" => counts.0 += count,
+                    _ => counts.1 += count,
+                }
+            }
+        }
+
+        if !by_tag.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(output, "| Tag | Baseline | AICMS |").unwrap();
+            writeln!(output, "|-----|----------|-------|").unwrap();
+
+            for (tag, (baseline, aicms)) in by_tag {
+                writeln!(output, "| {} | {} | {} |", tag, baseline, aicms).unwrap();
+            }
+        }
+
         writeln!(output).unwrap();
         output
     }
 
-    /// @ai:intent Generate difficulty breakdown section
+    /// @ai:intent Generate mutation testing section
     /// @ai:effects pure
-    fn generate_difficulty_section(results: &BenchmarkResults) -> String {
+    fn generate_mutation_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.mutation_tested_count == 0 && aicms.mutation_tested_count == 0 {
+            return String::new();
+        }
+
         let mut output = String::new();
 
-        writeln!(output, "## Results by Difficulty").unwrap();
+        writeln!(output, "## Mutation Testing").unwrap();
         writeln!(output).unwrap();
         writeln!(
             output,
-            "| Difficulty | Baseline Compile | AICMS Compile | Baseline Tests | AICMS Tests |"
+            "Share of mutants caught by the generated tests, for executions whose tests all pass. \
+             The weighted pass rate multiplies the test pass rate by the mutation score."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Mode | Executions | Mutation Score | Weighted Pass Rate |"
+        )
+        .unwrap();
+        writeln!(
+            output,
+            "|------|------------|----------------|--------------------|"
         )
         .unwrap();
-        writeln!(output, "|------------|-----------------|---------------|----------------|-------------|").unwrap();
 
-        for diff in &results.by_difficulty {
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
             writeln!(
                 output,
-                "| {} | {:.1}% | {:.1}% | {:.1}% | {:.1}% |",
-                diff.difficulty,
-                diff.baseline.compilation_rate,
-                diff.aicms.compilation_rate,
-                diff.baseline.avg_test_pass_rate,
-                diff.aicms.avg_test_pass_rate
+                "| {} | {} | {:.1}% | {:.1}% |",
+                mode,
+                stats.mutation_tested_count,
+                stats.avg_mutation_score,
+                stats.avg_mutation_weighted_pass_rate
             )
             .unwrap();
         }
@@ -200,37 +2266,294 @@ impl MarkdownReporter {
         output
     }
 
-    /// @ai:intent Generate token usage section
+    /// @ai:intent Generate flaky test section from executions whose tests were run twice
     /// @ai:effects pure
-    fn generate_token_section(results: &BenchmarkResults) -> String {
+    fn generate_flaky_test_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.determinism_checked_count == 0 && aicms.determinism_checked_count == 0 {
+            return String::new();
+        }
+
         let mut output = String::new();
 
-        writeln!(output, "## Token Usage").unwrap();
+        writeln!(output, "## Flaky Tests").unwrap();
         writeln!(output).unwrap();
         writeln!(
             output,
-            "| Mode | Total Input Tokens | Total Output Tokens | Avg Execution Time |"
+            "Executions whose own tests were run twice. An execution is flaky when its runs pass, \
+             fail or count a different number of tests; its test pass rate is the first run's."
         )
         .unwrap();
-        writeln!(output, "|------|-------------------|--------------------|--------------------|").unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Mode | Executions | Flaky |").unwrap();
+        writeln!(output, "|------|------------|-------|").unwrap();
+
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% |",
+                mode, stats.determinism_checked_count, stats.flaky_test_rate
+            )
+            .unwrap();
+        }
+
+        let flaky: Vec<_> = results
+            .task_metrics
+            .iter()
+            .filter_map(|m| Some((m, m.test_determinism.as_ref().filter(|d| d.flaky)?)))
+            .collect();
+        if !flaky.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(output, "| Task | Mode | Repetition | Passed per Run |").unwrap();
+            writeln!(output, "|------|------|------------|----------------|").unwrap();
+
+            for (m, determinism) in flaky {
+                let runs: Vec<String> = determinism
+                    .passed
+                    .iter()
+                    .zip(&determinism.total)
+                    .map(|(passed, total)| format!("{}/{}", passed, total))
+                    .collect();
+                writeln!(
+                    output,
+                    "| {} | {} | {} | {} |",
+                    m.task_id,
+                    m.mode,
+                    m.repetition,
+                    runs.join(", ")
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
 
+    /// @ai:intent Generate test design section (property-based tests, edge cases)
+    /// @ai:effects pure
+    fn generate_test_design_section(results: &BenchmarkResults) -> String {
+        let baseline = &results.overall.baseline;
+        let aicms = &results.overall.aicms;
+
+        if baseline.test_design_count == 0 && aicms.test_design_count == 0 {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Test Design").unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "How the generated tests are written, for executions that include tests. \
+             Edge cases are the boundary cases listed by each task."
+        )
+        .unwrap();
+        writeln!(output).unwrap();
+        writeln!(
+            output,
+            "| Mode | Executions | Property-Based | Edge Cases Tested |"
+        )
+        .unwrap();
         writeln!(
             output,
-            "| Baseline | {} | {} | {:.0}ms |",
-            results.overall.baseline.total_input_tokens,
-            results.overall.baseline.total_output_tokens,
-            results.overall.baseline.avg_execution_time_ms
+            "|------|------------|----------------|-------------------|"
         )
         .unwrap();
 
+        for (mode, stats) in Self::modes_run(baseline, aicms) {
+            let edge_cases = if stats.edge_case_checked_count == 0 {
+                "-".to_string()
+            } else {
+                format!("{:.1}%", stats.avg_edge_case_coverage)
+            };
+            writeln!(
+                output,
+                "| {} | {} | {:.1}% | {} |",
+                mode, stats.test_design_count, stats.property_based_rate, edge_cases
+            )
+            .unwrap();
+        }
+
+        let untested: Vec<_> = results
+            .task_metrics
+            .iter()
+            .filter_map(|m| m.test_design.as_ref().map(|d| (m, d)))
+            .filter(|(_, d)| !d.untested_edge_cases.is_empty())
+            .collect();
+
+        if !untested.is_empty() {
+            writeln!(output).unwrap();
+            writeln!(output, "| Task | Mode | Rep | Untested Edge Cases |").unwrap();
+            writeln!(output, "|------|------|-----|---------------------|").unwrap();
+
+            for (m, design) in untested {
+                writeln!(
+                    output,
+                    "| {} | {} | {} | {} |",
+                    m.task_id,
+                    m.mode,
+                    m.repetition,
+                    design.untested_edge_cases.join(", ")
+                )
+                .unwrap();
+            }
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate custom metrics section: each metric recorded by a custom stage,
+    ///            combined by its [statistics] custom_metrics rule, per mode
+    /// @ai:effects pure
+    fn generate_custom_section(results: &BenchmarkResults) -> String {
+        let (baseline, aicms) = (&results.overall.baseline, &results.overall.aicms);
+        let mut names: Vec<&String> = baseline.custom.keys().chain(aicms.custom.keys()).collect();
+        names.sort();
+        names.dedup();
+        if names.is_empty() {
+            return String::new();
+        }
+
+        let value = |stats: &AggregateStats, name: &String| {
+            stats
+                .custom
+                .get(name)
+                .map_or_else(|| "-".to_string(), |v| format!("{:.2}", v))
+        };
+        let mut output = String::new();
+
+        writeln!(output, "## Custom Metrics").unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Metric | Baseline | AICMS | Delta |").unwrap();
+        writeln!(output, "|--------|----------|-------|-------|").unwrap();
+
+        for name in names {
+            let delta = results
+                .overall
+                .delta
+                .as_ref()
+                .and_then(|d| d.custom.get(name));
+            writeln!(
+                output,
+                "| {} | {} | {} | {} |",
+                name,
+                value(baseline, name),
+                value(aicms, name),
+                delta.map_or_else(|| "n/a".to_string(), |d| format!("{:+.2}", d))
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate flaky tasks section: tasks whose compilation or test outcome
+    ///            flipped between repetitions of the same mode
+    /// @ai:effects pure
+    fn generate_flaky_task_section(results: &BenchmarkResults) -> String {
+        if results.flaky_tasks.is_empty() {
+            return String::new();
+        }
+
+        let mut output = String::new();
+
+        writeln!(output, "## Flaky Tasks").unwrap();
+        writeln!(output).unwrap();
         writeln!(
             output,
-            "| AICMS | {} | {} | {:.0}ms |",
-            results.overall.aicms.total_input_tokens,
-            results.overall.aicms.total_output_tokens,
-            results.overall.aicms.avg_execution_time_ms
+            "Tasks that compiled, or passed all of their tests, in some repetitions of a mode but \
+             not in others. Their results depend on luck more than on the mode; check the task \
+             for nondeterministic tests or an ambiguous description."
         )
         .unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Task | Mode | Compiled | Passed | Flipped |").unwrap();
+        writeln!(output, "|------|------|----------|--------|---------|").unwrap();
+
+        for task in &results.flaky_tasks {
+            let mut name = task.task_id.clone();
+            if results.by_model.len() > 1 {
+                write!(name, " ({})", task.model).unwrap();
+            }
+            for label in [&task.sampling, &task.skill].into_iter().flatten() {
+                write!(name, " ({})", label).unwrap();
+            }
+            let flipped: Vec<&str> = [
+                (task.compilation_flipped(), "compilation"),
+                (task.tests_flipped(), "tests"),
+            ]
+            .into_iter()
+            .filter_map(|(flipped, label)| flipped.then_some(label))
+            .collect();
+            writeln!(
+                output,
+                "| {} | {} | {}/{} | {}/{} | {} |",
+                name,
+                task.mode,
+                task.compiled,
+                task.repetitions,
+                task.passed,
+                task.repetitions,
+                flipped.join(", ")
+            )
+            .unwrap();
+        }
+
+        writeln!(output).unwrap();
+        output
+    }
+
+    /// @ai:intent Generate failure reasons section: failed executions per mode by the step
+    ///            that failed first
+    /// @ai:effects pure
+    fn generate_failure_section(results: &BenchmarkResults) -> String {
+        let (baseline, aicms) = (&results.overall.baseline, &results.overall.aicms);
+        let mut reasons: Vec<&FailureReason> = baseline
+            .failures
+            .keys()
+            .chain(aicms.failures.keys())
+            .collect();
+        reasons.sort();
+        reasons.dedup();
+        if reasons.is_empty() {
+            return String::new();
+        }
+
+        let count = |stats: &AggregateStats, reason: &FailureReason| {
+            let failed = stats.failures.get(reason).copied().unwrap_or(0);
+            if stats.task_count == 0 {
+                "-".to_string()
+            } else {
+                format!(
+                    "{} ({:.1}%)",
+                    failed,
+                    failed as f64 / stats.task_count as f64 * 100.0
+                )
+            }
+        };
+        let mut output = String::new();
+
+        writeln!(output, "## Failure Reasons").unwrap();
+        writeln!(output).unwrap();
+        writeln!(output, "| Reason | Baseline | AICMS |").unwrap();
+        writeln!(output, "|--------|----------|-------|").unwrap();
+
+        for reason in reasons {
+            writeln!(
+                output,
+                "| {} | {} | {} |",
+                reason.as_str(),
+                count(baseline, reason),
+                count(aicms, reason)
+            )
+            .unwrap();
+        }
 
         writeln!(output).unwrap();
         output
@@ -253,12 +2576,49 @@ impl MarkdownReporterTrait for MarkdownReporter {
         content.push_str(&Self::generate_comparison_table(
             &results.overall.baseline,
             &results.overall.aicms,
-            &results.overall.delta,
+            results.overall.delta.as_ref(),
         ));
+        content.push_str(&Self::generate_composite_section(results));
+        content.push_str(&Self::generate_difficulty_normalized_section(results));
+        content.push_str(&Self::generate_outlier_section(results));
+        content.push_str(&Self::generate_interval_section(results));
+        content.push_str(&Self::generate_repetition_section(results));
+        content.push_str(&Self::generate_reproducibility_section(results));
+        content.push_str(&Self::generate_distribution_section(results));
+        content.push_str(&Self::generate_significance_section(results));
+        content.push_str(&Self::generate_model_section(results));
+        content.push_str(&Self::generate_sampling_section(results));
+        content.push_str(&Self::generate_skill_section(results));
+        content.push_str(&Self::generate_ranking_section(results));
+        content.push_str(&Self::generate_repair_section(results));
         content.push_str(&Self::generate_category_section(results));
         content.push_str(&Self::generate_language_section(results));
         content.push_str(&Self::generate_difficulty_section(results));
+        content.push_str(&Self::generate_tag_section(results));
+        content.push_str(&Self::generate_comparison_breakdown_section(results));
+        content.push_str(&Self::generate_head_to_head_section(results));
+        content.push_str(&Self::generate_flaky_task_section(results));
+        content.push_str(&Self::generate_failure_section(results));
+        content.push_str(&Self::generate_compile_error_section(results));
         content.push_str(&Self::generate_token_section(results));
+        content.push_str(&Self::generate_leakage_section(results));
+        content.push_str(&Self::generate_contamination_section(results));
+        content.push_str(&Self::generate_contract_section(results));
+        content.push_str(&Self::generate_hidden_test_section(results));
+        content.push_str(&Self::generate_io_section(results));
+        content.push_str(&Self::generate_api_conformance_section(results));
+        content.push_str(&Self::generate_reference_section(results));
+        content.push_str(&Self::generate_inference_section(results));
+        content.push_str(&Self::generate_code_quality_section(results));
+        content.push_str(&Self::generate_type_check_section(results));
+        content.push_str(&Self::generate_security_section(results));
+        content.push_str(&Self::generate_complexity_section(results));
+        content.push_str(&Self::generate_code_size_section(results));
+        content.push_str(&Self::generate_annotation_density_section(results));
+        content.push_str(&Self::generate_mutation_section(results));
+        content.push_str(&Self::generate_flaky_test_section(results));
+        content.push_str(&Self::generate_test_design_section(results));
+        content.push_str(&Self::generate_custom_section(results));
 
         std::fs::write(output_path, content)?;
         Ok(())
@@ -268,7 +2628,13 @@ impl MarkdownReporterTrait for MarkdownReporter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::metrics::ModeComparison;
+    use crate::config::{MissingDataPolicy, TieHandling};
+    use crate::metrics::{
+        BinomialTest, CategoryStats, ClaudeComparisonStats, ComparisonGroupStats, CompositeScore,
+        DifficultyNormalized, FlakyTask, MissingData, ModeComparison, ModelStats, NormalizedMetric,
+        OutlierReason, OutlierStats, OutlierTask, Percentiles, RankingStats, RepetitionSpread,
+        Reproducibility, SamplingStats, SkillStats, TagStats, TaskHeadToHead, TaskOutcome,
+    };
     use tempfile::TempDir;
 
     #[test]
@@ -286,6 +2652,20 @@ mod tests {
         let reporter = MarkdownReporter::new();
         let temp = TempDir::new().unwrap();
         let output = temp.path().join("results.md");
+        let (baseline_without, aicms_without) = (
+            AggregateStats {
+                task_count: 2,
+                compilation_rate: 100.0,
+                avg_test_pass_rate: 90.0,
+                ..Default::default()
+            },
+            AggregateStats {
+                task_count: 2,
+                compilation_rate: 100.0,
+                avg_test_pass_rate: 95.0,
+                ..Default::default()
+            },
+        );
 
         let results = BenchmarkResults {
             timestamp: "2026-01-19T00:00:00Z".to_string(),
@@ -293,28 +2673,270 @@ mod tests {
             repetitions: 1,
             overall: ModeComparison {
                 baseline: AggregateStats {
+                    task_count: 3,
                     compilation_rate: 80.0,
                     avg_test_pass_rate: 70.0,
+                    compilation_rate_ci: Some(ConfidenceInterval {
+                        lower: 60.0,
+                        upper: 95.0,
+                    }),
+                    test_pass_rate_ci: Some(ConfidenceInterval {
+                        lower: 50.0,
+                        upper: 90.0,
+                    }),
+                    token_percentiles: Some(Percentiles {
+                        p25: 300.0,
+                        median: 350.0,
+                        p75: 400.0,
+                        p95: 2400.0,
+                    }),
+                    repetition_spread: vec![RepetitionSpread {
+                        metric: "test_pass_rate".to_string(),
+                        tasks: 3,
+                        std_dev: 12.5,
+                        min: 60.0,
+                        max: 80.0,
+                    }],
+                    total_cost_usd: 1.5,
+                    priced_count: 3,
+                    cost_per_pass_usd: Some(0.75),
+                    reproducibility: Some(Reproducibility {
+                        tasks: 3,
+                        consistent_compilation_rate: 100.0,
+                        consistent_pass_rate: 200.0 / 3.0,
+                        avg_pass_rate_range: 10.0,
+                        score: 95.0,
+                    }),
+                    custom: BTreeMap::from([("todo_count".to_string(), 4.0)]),
+                    failures: BTreeMap::from([
+                        (FailureReason::CompileError, 1),
+                        (FailureReason::TestFailure, 2),
+                    ]),
                     ..Default::default()
                 },
                 aicms: AggregateStats {
+                    task_count: 3,
                     compilation_rate: 92.0,
                     avg_test_pass_rate: 85.0,
+                    hidden_tested_count: 3,
+                    avg_hidden_test_pass_rate: 60.0,
+                    reference_compared_count: 3,
+                    avg_reference_api_match: 100.0,
+                    avg_reference_alignment: 90.0,
+                    inference_scored_count: 2,
+                    avg_inference_precision: 80.0,
+                    avg_inference_recall: 60.0,
+                    avg_inference_f1: 68.0,
+                    quality_checked_count: 3,
+                    avg_quality_warnings: 1.5,
+                    avg_quality_density: 2.25,
+                    type_checked_count: 2,
+                    type_correct_rate: 50.0,
+                    avg_type_errors: 1.5,
+                    complexity_measured_count: 3,
+                    avg_cyclomatic_complexity: 2.5,
+                    avg_nesting_depth: 1.25,
+                    avg_maintainability: 64.0,
+                    sized_count: 3,
+                    avg_files: 2.0,
+                    avg_lines_of_code: 150.0,
+                    avg_test_lines: 50.0,
+                    density_measured_count: 3,
+                    avg_annotations_per_function: 2.5,
+                    avg_annotated_function_rate: 80.0,
+                    mutation_tested_count: 2,
+                    avg_mutation_score: 70.0,
+                    avg_mutation_weighted_pass_rate: 65.0,
+                    test_design_count: 3,
+                    property_based_rate: 100.0 / 3.0,
+                    edge_case_checked_count: 3,
+                    avg_edge_case_coverage: 50.0,
+                    custom: BTreeMap::from([
+                        ("todo_count".to_string(), 2.5),
+                        ("peak_memory_mb".to_string(), 12.0),
+                    ]),
+                    failures: BTreeMap::from([(FailureReason::TestFailure, 1)]),
                     ..Default::default()
                 },
-                delta: DeltaStats {
+                delta: Some(DeltaStats {
                     compilation_rate: 12.0,
                     test_pass_rate: 15.0,
                     lint_compliance: 0.0,
                     annotation_quality: 0.0,
-                },
+                    effect_sizes: vec![EffectSize {
+                        metric: "test_pass_rate".to_string(),
+                        cohens_d: Some(0.512),
+                        cliffs_delta: Some(0.3),
+                    }],
+                    total_cost_usd: Some(0.5),
+                    avg_cost_usd: Some(0.1667),
+                    cost_per_pass_usd: Some(-0.05),
+                    custom: BTreeMap::from([("todo_count".to_string(), -1.5)]),
+                }),
             },
-            by_category: vec![],
+            by_category: vec![CategoryStats {
+                category: "bugfix".to_string(),
+                baseline: AggregateStats {
+                    task_count: 2,
+                    total_cost_usd: 1.0,
+                    priced_count: 2,
+                    cost_per_pass_usd: Some(0.8),
+                    ..Default::default()
+                },
+                aicms: AggregateStats::default(),
+            }],
             by_language: vec![],
             by_difficulty: vec![],
+            by_tag: vec![TagStats {
+                tag: "parsing".to_string(),
+                baseline: AggregateStats {
+                    task_count: 2,
+                    compilation_rate: 50.0,
+                    avg_test_pass_rate: 40.0,
+                    ..Default::default()
+                },
+                aicms: AggregateStats::default(),
+            }],
+            by_model: ["sonnet", "haiku"]
+                .iter()
+                .map(|model| ModelStats {
+                    model: model.to_string(),
+                    baseline: AggregateStats::default(),
+                    aicms: AggregateStats::default(),
+                    delta: None,
+                })
+                .collect(),
+            by_sampling: ["t=0", "t=0.7"]
+                .iter()
+                .map(|sampling| SamplingStats {
+                    sampling: sampling.to_string(),
+                    baseline: AggregateStats::default(),
+                    aicms: AggregateStats::default(),
+                    delta: None,
+                })
+                .collect(),
+            by_skill: vec![SkillStats {
+                skill: "terse".to_string(),
+                baseline: AggregateStats::default(),
+                aicms: AggregateStats::default(),
+                delta: None,
+                significance: vec![PairedTest {
+                    metric: "test_pass_rate".to_string(),
+                    pairs: 6,
+                    mean_difference: 12.5,
+                    t_test_p: Some(0.0312),
+                    wilcoxon_p: Some(0.0469),
+                }],
+            }],
+            repair_curve: vec![],
+            significance: vec![],
+            head_to_head: vec![TaskHeadToHead {
+                task_id: "impl-rust-cache".to_string(),
+                model: "haiku".to_string(),
+                sampling: None,
+                skill: None,
+                baseline: TaskOutcome {
+                    executions: 2,
+                    compilation_rate: 100.0,
+                    test_pass_rate: 75.0,
+                    avg_tokens: 1200.0,
+                },
+                aicms: TaskOutcome {
+                    executions: 2,
+                    compilation_rate: 50.0,
+                    test_pass_rate: 40.0,
+                    avg_tokens: 1500.0,
+                },
+                winner: Some("baseline".to_string()),
+            }],
+            flaky_tasks: vec![FlakyTask {
+                task_id: "impl-rust-cache".to_string(),
+                mode: "aicms".to_string(),
+                model: "haiku".to_string(),
+                sampling: None,
+                skill: None,
+                repetitions: 2,
+                compiled: 1,
+                passed: 0,
+            }],
+            composite: Some(CompositeScore {
+                weights: BTreeMap::from([
+                    ("test_pass_rate".to_string(), 0.5),
+                    ("compilation_rate".to_string(), 0.375),
+                    ("lint_compliance".to_string(), 0.125),
+                ]),
+                missing: vec!["comparison".to_string()],
+                baseline: Some(72.5),
+                aicms: Some(80.0),
+            }),
+            difficulty_normalized: Some(DifficultyNormalized {
+                weights: BTreeMap::from([("easy".to_string(), 0.5), ("hard".to_string(), 0.5)]),
+                missing: vec!["medium".to_string()],
+                metrics: vec![NormalizedMetric {
+                    metric: "test_pass_rate".to_string(),
+                    raw_baseline: Some(70.0),
+                    raw_aicms: Some(85.0),
+                    baseline: Some(60.0),
+                    aicms: Some(80.0),
+                }],
+            }),
+            outliers: Some(OutlierStats {
+                tasks: vec![OutlierTask {
+                    task_id: "parse_json".to_string(),
+                    reason: OutlierReason::NoCode,
+                    outcome: TaskOutcome {
+                        executions: 2,
+                        ..Default::default()
+                    },
+                }],
+                without: ModeComparison {
+                    delta: Some(DeltaStats::calculate(&baseline_without, &aicms_without)),
+                    baseline: baseline_without,
+                    aicms: aicms_without,
+                },
+            }),
             task_metrics: vec![],
             claude_comparisons: vec![],
-            claude_stats: None,
+            claude_stats: Some(ClaudeComparisonStats {
+                aicms_wins: 12,
+                baseline_wins: 9,
+                ties: 3,
+                win_test: Some(BinomialTest {
+                    wins: 12,
+                    comparisons: 21,
+                    ties: TieHandling::Exclude,
+                    p_value: 0.6636,
+                }),
+                by_language: vec![ComparisonGroupStats {
+                    group: "rust".to_string(),
+                    avg_baseline_score: 62.0,
+                    avg_aicms_score: 71.5,
+                    aicms_wins: 12,
+                    baseline_wins: 9,
+                    ties: 3,
+                }],
+                ..Default::default()
+            }),
+            rankings: vec![],
+            ranking_stats: vec![RankingStats {
+                label: "sonnet/aicms".to_string(),
+                tasks: 4,
+                avg_rank: 1.25,
+                avg_score: 82.5,
+                first_places: 3,
+                wins: 7,
+                losses: 1,
+                ties: 0,
+                rating: 1712.4,
+            }],
+            truncated: None,
+            missing_data: MissingData {
+                policy: MissingDataPolicy::Exclude,
+                executions: 2,
+                excluded: 2,
+                imputed: 0,
+            },
+            metadata: None,
         };
 
         reporter.generate(&results, &output).unwrap();
@@ -323,5 +2945,68 @@ mod tests {
         let content = std::fs::read_to_string(&output).unwrap();
         assert!(content.contains("# AICMS Benchmark Results"));
         assert!(content.contains("+12.0%"));
+        assert!(
+            content.contains("| Test Pass Rate | 70.0% | 85.0% | +15.0% | +0.51 / +0.30 (small) |")
+        );
+        assert!(content.contains("| Baseline | 80.0% (60.0-95.0%) | 70.0% (50.0-90.0%) |"));
+        assert!(content.contains("| Baseline | Test pass rate | 3 | 12.5 | 60.0% | 80.0% |"));
+        assert!(content.contains("| Baseline | Tokens | 300 | 350 | 400 | 2400 |"));
+        // Models without executions show dashes instead of 0%
+        assert!(content.contains("| haiku | - | - | - | - | n/a |"));
+        assert!(content.contains("| sonnet/aicms | 1712 | 4 | 1.25 | 82.5 | 3 | 7 / 1 / 0 |"));
+        assert!(content.contains("1 of 1 tasks regressed"));
+        assert!(content.contains("12 of 21 wins, ties excluded: p = 0.664"));
+        assert!(content.contains(
+            "| impl-rust-cache (haiku) | 100% / 50% | 75.0% / 40.0% | 1200 / 1500 | baseline | **regressed** |"
+        ));
+        assert!(content.contains("## Spec Leakage"));
+        assert!(content.contains("## Results by Model"));
+        assert!(content.contains("| haiku |"));
+        assert!(content.contains("## Results by Sampling Setting"));
+        assert!(content.contains("| t=0.7 |"));
+        assert!(content.contains("## Results by Skill Variant"));
+        assert!(content.contains("| terse | - | - | - | n/a | n/a | 0.047 |"));
+        assert!(content.contains("| Baseline | 3 | $1.50 | $0.5000 | $0.7500 |"));
+        assert!(content.contains("| Delta | | +0.50 | +0.1667 | -0.0500 |"));
+        assert!(content.contains("| bugfix | $1.00 | $0.00 | $0.5000 | $0.0000 | $0.8000 | - |"));
+        assert!(content.contains("| AICMS | 3 | 85.0% | 60.0% |"));
+        assert!(content.contains("## Reference Alignment"));
+        assert!(content.contains("| AICMS | 3 | 100.0% | 90.0% |"));
+        assert!(content.contains("| AICMS | 2 | 80.0% | 60.0% | 68.0% |"));
+        assert!(content.contains("| AICMS | 3 | 1.5 | 2.25 |"));
+        assert!(content.contains("| AICMS | 2 | 50.0% | 1.5 |"));
+        assert!(content.contains("| AICMS | 3 | 2.50 | 1.25 | 64.0 |"));
+        assert!(content.contains("| AICMS | 3 | 2.0 | 150 | 100 | 50 |"));
+        assert!(content.contains("| AICMS | 3 | 2.50 | 80.0% |"));
+        assert!(content.contains("| AICMS | 2 | 70.0% | 65.0% |"));
+        assert!(content.contains("| AICMS | 3 | 33.3% | 50.0% |"));
+        assert!(content.contains(
+            "**Composite** = 0.50 × test_pass_rate + 0.38 × compilation_rate + 0.12 × lint_compliance"
+        ));
+        assert!(content.contains(
+            "Not scored (no data in this run; the other weights are scaled up): comparison"
+        ));
+        assert!(content.contains("| Delta | +7.5 |"));
+        assert!(
+            content.contains("| parse_json | no code extracted in any execution | 0.0% | 0.0% |")
+        );
+        assert!(content.contains("| Test Pass Rate | 90.0% | 95.0% | +5.0% | +"));
+        assert!(content.contains("## Custom Metrics"));
+        assert!(content.contains("| peak_memory_mb | - | 12.00 | n/a |"));
+        assert!(content.contains("| todo_count | 4.00 | 2.50 | -1.50 |"));
+        assert!(content.contains("| Baseline | 3 | 100.0% | 66.7% | 10.0 | 95.0 |"));
+        assert!(content.contains("number of executions: easy 0.50, hard 0.50"));
+        assert!(content
+            .contains("| Test pass rate | 60.0% | 80.0% | +20.0% | 70.0% | 85.0% | +15.0% |"));
+        assert!(content.contains("| parsing | 50.0% | - | 40.0% | - |"));
+        assert!(content.contains("### By Language"));
+        assert!(content.contains("| rust | 24 | 12 | 9 | 3 | 56.2% | 62.0 | 71.5 |"));
+        assert!(!content.contains("### By Category"));
+        assert!(content.contains("**Missing data:** exclude (2 executions without code left out)"));
+        assert!(content.contains("## Flaky Tasks"));
+        assert!(content.contains("| impl-rust-cache (haiku) | aicms | 1/2 | 0/2 | compilation |"));
+        assert!(content.contains("## Failure Reasons"));
+        assert!(content.contains("| compile_error | 1 (33.3%) | 0 (0.0%) |"));
+        assert!(content.contains("| test_failure | 2 (66.7%) | 1 (33.3%) |"));
     }
 }
@@ -1,14 +1,17 @@
 //! @ai:module:intent Report generation for benchmark results
 //! @ai:module:layer infrastructure
-//! @ai:module:public_api ReportGenerator, JsonReporter, MarkdownReporter, ChartGenerator
+//! @ai:module:public_api ReportGenerator, JsonReporter, MarkdownReporter, ChartGenerator, ChartPlugin,
+//!                        OpenMetricsExporter
 
 pub mod charts;
 pub mod json_report;
 pub mod markdown_report;
+pub mod openmetrics;
 
-pub use charts::{ChartGenerator, ChartGeneratorTrait};
+pub use charts::{ChartGenerator, ChartGeneratorTrait, ChartPlugin};
 pub use json_report::{JsonReporter, JsonReporterTrait};
 pub use markdown_report::{MarkdownReporter, MarkdownReporterTrait};
+pub use openmetrics::{OpenMetricsExporter, OpenMetricsExporterTrait, METRICS_FILE};
 
 use crate::metrics::BenchmarkResults;
 use anyhow::Result;
@@ -18,7 +21,9 @@ use std::path::Path;
 pub struct ReportGenerator {
     json: JsonReporter,
     markdown: MarkdownReporter,
+    openmetrics: OpenMetricsExporter,
     charts: ChartGenerator,
+    plugins: Vec<Box<dyn ChartPlugin>>,
 }
 
 impl ReportGenerator {
@@ -28,11 +33,21 @@ impl ReportGenerator {
         Self {
             json: JsonReporter::new(),
             markdown: MarkdownReporter::new(),
+            openmetrics: OpenMetricsExporter::new(),
             charts: ChartGenerator::new(),
+            plugins: Vec::new(),
         }
     }
 
+    /// @ai:intent Register an additional chart to render alongside the built-in ones
+    /// @ai:effects pure
+    pub fn with_chart_plugin(mut self, plugin: impl ChartPlugin + 'static) -> Self {
+        self.plugins.push(Box::new(plugin));
+        self
+    }
+
     /// @ai:intent Generate all reports
+    /// @ai:post a failing chart plugin is logged and does not prevent the other reports
     /// @ai:effects fs:write
     pub fn generate_all(&self, results: &BenchmarkResults, output_dir: &Path) -> Result<()> {
         std::fs::create_dir_all(output_dir)?;
@@ -40,8 +55,17 @@ impl ReportGenerator {
         self.json.generate(results, &output_dir.join("results.json"))?;
         self.markdown
             .generate(results, &output_dir.join("results.md"))?;
+        self.openmetrics
+            .generate(results, &output_dir.join(METRICS_FILE))?;
         self.charts.generate_all(results, output_dir)?;
 
+        for plugin in &self.plugins {
+            match plugin.generate(results, output_dir) {
+                Ok(files) => tracing::debug!("Chart plugin {} wrote {:?}", plugin.name(), files),
+                Err(e) => tracing::warn!("Chart plugin {} failed: {}", plugin.name(), e),
+            }
+        }
+
         tracing::info!("Reports generated in {}", output_dir.display());
         Ok(())
     }
@@ -66,3 +90,90 @@ impl Default for ReportGenerator {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::{AggregateStats, DeltaStats, ModeComparison};
+    use tempfile::TempDir;
+
+    struct TokenChart;
+
+    impl ChartPlugin for TokenChart {
+        fn name(&self) -> &str {
+            "tokens"
+        }
+
+        fn generate(&self, results: &BenchmarkResults, output_dir: &Path) -> Result<Vec<String>> {
+            let total = results.overall.aicms.total_output_tokens;
+            std::fs::write(output_dir.join("tokens.txt"), total.to_string())?;
+            Ok(vec!["tokens.txt".to_string()])
+        }
+    }
+
+    struct BrokenChart;
+
+    impl ChartPlugin for BrokenChart {
+        fn name(&self) -> &str {
+            "broken"
+        }
+
+        fn generate(&self, _results: &BenchmarkResults, _output_dir: &Path) -> Result<Vec<String>> {
+            anyhow::bail!("no data")
+        }
+    }
+
+    #[test]
+    fn test_generate_all_runs_chart_plugins() {
+        let temp = TempDir::new().unwrap();
+        let stats = AggregateStats {
+            total_output_tokens: 1234,
+            ..Default::default()
+        };
+        let results = BenchmarkResults {
+            timestamp: "2026-01-19T00:00:00Z".to_string(),
+            model: "claude-sonnet-4-20250514".to_string(),
+            repetitions: 1,
+            overall: ModeComparison {
+                delta: Some(DeltaStats::calculate(&stats, &stats)),
+                baseline: stats.clone(),
+                aicms: stats,
+            },
+            by_category: vec![],
+            by_language: vec![],
+            by_tag: vec![],
+            by_difficulty: vec![],
+            by_model: vec![],
+            by_sampling: vec![],
+            by_skill: vec![],
+            repair_curve: vec![],
+            significance: vec![],
+            head_to_head: vec![],
+            flaky_tasks: vec![],
+            composite: None,
+            difficulty_normalized: None,
+            outliers: None,
+            task_metrics: vec![],
+            claude_comparisons: vec![],
+            claude_stats: None,
+            rankings: vec![],
+            ranking_stats: vec![],
+            truncated: None,
+            missing_data: Default::default(),
+            metadata: None,
+        };
+
+        ReportGenerator::new()
+            .with_chart_plugin(BrokenChart)
+            .with_chart_plugin(TokenChart)
+            .generate_all(&results, temp.path())
+            .unwrap();
+
+        assert!(temp.path().join("comparison.png").exists());
+        assert!(temp.path().join(METRICS_FILE).exists());
+        assert_eq!(
+            std::fs::read_to_string(temp.path().join("tokens.txt")).unwrap(),
+            "1234"
+        );
+    }
+}
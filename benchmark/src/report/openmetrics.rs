@@ -0,0 +1,285 @@
+//! @ai:module:intent OpenMetrics export of run metrics, as a file next to the reports and
+//!                   optionally pushed to a Prometheus Pushgateway, for dashboards that track
+//!                   annotation effectiveness over time
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api OpenMetricsExporter, OpenMetricsExporterTrait, METRICS_FILE
+//! @ai:module:stateless true
+
+use crate::metrics::{AggregateStats, BenchmarkResults, DeltaStats};
+use anyhow::{Context, Result};
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// File the metrics are written to in a run's output directory
+pub const METRICS_FILE: &str = "metrics.prom";
+
+/// Prefix of every exported metric name
+const PREFIX: &str = "aicms_bench";
+
+/// @ai:intent Reads one per-mode gauge from a mode's stats, None when it does not apply
+type ModeValue = fn(&AggregateStats) -> Option<f64>;
+
+/// @ai:intent Per-mode gauges: name suffix, help text and value
+const MODE_METRICS: &[(&str, &str, ModeValue)] = &[
+    ("executions", "Executions of the mode", |s| {
+        Some(s.task_count as f64)
+    }),
+    (
+        "compilation_rate_percent",
+        "Percentage of executions that compiled",
+        |s| Some(s.compilation_rate),
+    ),
+    (
+        "test_pass_rate_percent",
+        "Average percentage of tests passed",
+        |s| Some(s.avg_test_pass_rate),
+    ),
+    (
+        "lint_compliance_percent",
+        "Average percentage of valid AICMS annotations",
+        |s| Some(s.avg_lint_compliance),
+    ),
+    (
+        "annotation_quality_percent",
+        "Average quality score of inferred annotations",
+        |s| Some(s.avg_annotation_quality),
+    ),
+    (
+        "tokens_per_execution",
+        "Input plus output tokens per execution",
+        |s| Some((s.total_input_tokens + s.total_output_tokens) as f64 / s.task_count as f64),
+    ),
+    (
+        "execution_time_seconds",
+        "Average generation time per execution",
+        |s| Some(s.avg_execution_time_ms / 1000.0),
+    ),
+    (
+        "cost_per_execution_usd",
+        "Average cost per priced execution",
+        |s| (s.priced_count > 0).then(|| s.avg_cost_usd()),
+    ),
+];
+
+/// @ai:intent Reads one headline delta
+type DeltaValue = fn(&DeltaStats) -> f64;
+
+/// @ai:intent Headline deltas: metric name and value
+const DELTA_METRICS: &[(&str, DeltaValue)] = &[
+    ("compilation_rate", |d| d.compilation_rate),
+    ("test_pass_rate", |d| d.test_pass_rate),
+    ("lint_compliance", |d| d.lint_compliance),
+    ("annotation_quality", |d| d.annotation_quality),
+];
+
+/// @ai:intent Trait for OpenMetrics export
+pub trait OpenMetricsExporterTrait: Send + Sync {
+    /// @ai:intent Write the metrics of results to a file
+    fn generate(&self, results: &BenchmarkResults, output_path: &Path) -> Result<()>;
+}
+
+/// @ai:intent Renders benchmark results as OpenMetrics gauges, one series per model and mode
+pub struct OpenMetricsExporter;
+
+impl OpenMetricsExporter {
+    /// @ai:intent Create a new OpenMetrics exporter
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// @ai:intent The metrics of results in the OpenMetrics text format: per model and mode
+    ///            rates and resources, AICMS - baseline deltas, the comparison win rate,
+    ///            the composite score and the run's timestamp
+    /// @ai:effects pure
+    pub fn render(&self, results: &BenchmarkResults) -> String {
+        let mut families = Vec::new();
+
+        for (suffix, help, value) in MODE_METRICS {
+            let mut samples = Vec::new();
+            for model in &results.by_model {
+                for (mode, stats) in [("baseline", &model.baseline), ("aicms", &model.aicms)] {
+                    if stats.task_count == 0 {
+                        continue;
+                    }
+                    if let Some(value) = value(stats) {
+                        samples
+                            .push((vec![("model", model.model.as_str()), ("mode", mode)], value));
+                    }
+                }
+            }
+            families.push((suffix.to_string(), *help, samples));
+        }
+
+        for (metric, value) in DELTA_METRICS {
+            let samples = results
+                .by_model
+                .iter()
+                .filter_map(|model| {
+                    let delta = model.delta.as_ref()?;
+                    Some((vec![("model", model.model.as_str())], value(delta)))
+                })
+                .collect();
+            families.push((
+                format!("{}_delta_percent", metric),
+                "AICMS minus baseline, in percentage points",
+                samples,
+            ));
+        }
+
+        let model = vec![("model", results.model.as_str())];
+        if let Some(stats) = &results.claude_stats {
+            families.push((
+                "comparison_win_rate_percent".to_string(),
+                "Share of judged comparisons AICMS won, ties counting half",
+                vec![(model.clone(), stats.win_rate())],
+            ));
+        }
+        if let Some(composite) = &results.composite {
+            let samples = [("baseline", composite.baseline), ("aicms", composite.aicms)]
+                .into_iter()
+                .filter_map(|(mode, score)| {
+                    Some((
+                        vec![("model", results.model.as_str()), ("mode", mode)],
+                        score?,
+                    ))
+                })
+                .collect();
+            families.push((
+                "composite_score".to_string(),
+                "Weighted composite score of [statistics.composite]",
+                samples,
+            ));
+        }
+        let timestamp = chrono::DateTime::parse_from_rfc3339(&results.timestamp)
+            .map_or(0.0, |t| t.timestamp() as f64);
+        let truncated = if results.truncated.is_some() {
+            1.0
+        } else {
+            0.0
+        };
+        families.push((
+            "run_timestamp_seconds".to_string(),
+            "Time the results were aggregated",
+            vec![(model.clone(), timestamp)],
+        ));
+        families.push((
+            "run_truncated".to_string(),
+            "1 when the run stopped early (budget cap or interrupt)",
+            vec![(model, truncated)],
+        ));
+
+        let mut output = String::new();
+        for (suffix, help, samples) in families {
+            if samples.is_empty() {
+                continue;
+            }
+            let name = format!("{}_{}", PREFIX, suffix);
+            writeln!(output, "# TYPE {} gauge", name).unwrap();
+            writeln!(output, "# HELP {} {}", name, help).unwrap();
+            for (labels, value) in samples {
+                let labels: Vec<String> = labels
+                    .iter()
+                    .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+                    .collect();
+                writeln!(output, "{}{{{}}} {}", name, labels.join(","), value).unwrap();
+            }
+        }
+        writeln!(output, "# EOF").unwrap();
+        output
+    }
+
+    /// @ai:intent Replace the job's metrics on a Prometheus Pushgateway with those of results
+    /// @ai:effects network
+    pub async fn push(
+        &self,
+        results: &BenchmarkResults,
+        gateway_url: &str,
+        job: &str,
+    ) -> Result<()> {
+        let url = format!("{}/metrics/job/{}", gateway_url.trim_end_matches('/'), job);
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()?;
+        client
+            .put(&url)
+            .header("Content-Type", "text/plain; version=0.0.4; charset=utf-8")
+            .body(self.render(results))
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .with_context(|| format!("Failed to push metrics to {}", url))?;
+        Ok(())
+    }
+}
+
+impl Default for OpenMetricsExporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OpenMetricsExporterTrait for OpenMetricsExporter {
+    /// @ai:intent Write the OpenMetrics text to a file
+    /// @ai:effects fs:write
+    fn generate(&self, results: &BenchmarkResults, output_path: &Path) -> Result<()> {
+        std::fs::write(output_path, self.render(results))?;
+        Ok(())
+    }
+}
+
+/// @ai:intent Escape a label value for the text format
+/// @ai:effects pure
+/// @ai:example ("say \"hi\"") -> "say \\\"hi\\\""
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evaluator::EvaluationResult;
+    use crate::metrics::{MetricsAggregator, MetricsAggregatorTrait, TaskMetrics};
+
+    #[test]
+    fn test_render_openmetrics() {
+        let metric = |mode: &str, compiled: bool| TaskMetrics {
+            task_id: "t1".to_string(),
+            mode: mode.to_string(),
+            compiled,
+            input_tokens: 300,
+            output_tokens: 100,
+            ..TaskMetrics::from_evaluation(&EvaluationResult::default(), 0, 0, 0)
+        };
+        let mut results = MetricsAggregator::new().aggregate(
+            &[metric("baseline", false), metric("aicms", true)],
+            &[],
+            "claude-\"sonnet\"",
+            1,
+        );
+        results.timestamp = "2026-01-19T00:00:00Z".to_string();
+
+        let text = OpenMetricsExporter::new().render(&results);
+        assert!(text.contains("# TYPE aicms_bench_compilation_rate_percent gauge\n"));
+        assert!(text.contains(
+            "aicms_bench_compilation_rate_percent{model=\"claude-\\\"sonnet\\\"\",mode=\"aicms\"} 100\n"
+        ));
+        assert!(text.contains(
+            "aicms_bench_tokens_per_execution{model=\"claude-\\\"sonnet\\\"\",mode=\"baseline\"} 400\n"
+        ));
+        assert!(text.contains(
+            "aicms_bench_compilation_rate_delta_percent{model=\"claude-\\\"sonnet\\\"\"} 100\n"
+        ));
+        assert!(text.contains(
+            "aicms_bench_run_timestamp_seconds{model=\"claude-\\\"sonnet\\\"\"} 1768780800\n"
+        ));
+        // Unpriced runs, runs without comparisons or composite weights leave those out
+        assert!(!text.contains("cost_per_execution_usd"));
+        assert!(!text.contains("comparison_win_rate_percent"));
+        assert!(text.ends_with("# EOF\n"));
+    }
+}
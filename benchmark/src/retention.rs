@@ -0,0 +1,351 @@
+//! @ai:module:intent Prune old benchmark runs and build artifacts from the results tree
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api RetentionPolicy, ResultsCleaner, CleanupPlan, CleanupReport, format_bytes
+//! @ai:module:depends_on runner::checkpoint
+//! @ai:module:stateless true
+
+use crate::platform::remove_dir_all;
+use crate::runner::EXECUTIONS_FILE;
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// @ai:intent Format of run directory names (results/{timestamp}/)
+const RUN_DIR_FORMAT: &str = "%Y-%m-%d_%H-%M-%S";
+
+/// @ai:intent Directories produced by building or testing generated code
+///            Safe to delete: they are recreated by the evaluator and hold no results
+pub const ARTIFACT_DIRS: &[&str] = &[
+    "target",
+    "node_modules",
+    "__pycache__",
+    ".pytest_cache",
+    ".mypy_cache",
+    ".venv",
+];
+
+/// @ai:intent Which runs to remove and whether to strip artifacts from the rest
+#[derive(Debug, Clone, Default)]
+pub struct RetentionPolicy {
+    /// Keep only the N most recent runs
+    pub keep_last: Option<usize>,
+    /// Remove runs older than this many days
+    pub max_age_days: Option<u64>,
+    /// Delete build artifacts from the runs that are kept
+    pub prune_artifacts: bool,
+}
+
+impl RetentionPolicy {
+    /// @ai:intent Check if the policy would remove anything at all
+    /// @ai:effects pure
+    pub fn is_empty(&self) -> bool {
+        self.keep_last.is_none() && self.max_age_days.is_none() && !self.prune_artifacts
+    }
+}
+
+/// @ai:intent A benchmark run directory and when it started
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunDir {
+    pub path: PathBuf,
+    pub started_at: DateTime<Utc>,
+}
+
+/// @ai:intent Paths selected for deletion, with their sizes
+#[derive(Debug, Clone, Default)]
+pub struct CleanupPlan {
+    pub runs: Vec<(PathBuf, u64)>,
+    pub artifacts: Vec<(PathBuf, u64)>,
+}
+
+impl CleanupPlan {
+    /// @ai:intent Total bytes the plan would free
+    /// @ai:effects pure
+    pub fn total_bytes(&self) -> u64 {
+        self.runs
+            .iter()
+            .chain(&self.artifacts)
+            .map(|(_, size)| size)
+            .sum()
+    }
+}
+
+/// @ai:intent Outcome of applying a cleanup plan
+#[derive(Debug, Clone, Default)]
+pub struct CleanupReport {
+    pub runs_removed: usize,
+    pub artifacts_removed: usize,
+    pub bytes_reclaimed: u64,
+}
+
+/// @ai:intent Plans and applies retention policies to a results directory
+pub struct ResultsCleaner;
+
+impl ResultsCleaner {
+    /// @ai:intent Create a new results cleaner
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// @ai:intent Find benchmark run directories, newest first
+    ///            A run is a timestamp-named directory, or any directory holding executions.jsonl
+    /// @ai:effects fs:read
+    pub fn discover_runs(&self, results_dir: &Path) -> Result<Vec<RunDir>> {
+        let entries = std::fs::read_dir(results_dir)
+            .with_context(|| format!("Failed to read {}", results_dir.display()))?;
+
+        let mut runs: Vec<RunDir> = entries
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+            .filter_map(|e| run_dir(&e.path()))
+            .collect();
+
+        runs.sort_by_key(|run| std::cmp::Reverse(run.started_at));
+        Ok(runs)
+    }
+
+    /// @ai:intent Decide what to delete without touching the filesystem
+    /// @ai:effects fs:read
+    pub fn plan(
+        &self,
+        results_dir: &Path,
+        policy: &RetentionPolicy,
+        now: DateTime<Utc>,
+    ) -> Result<CleanupPlan> {
+        let runs = self.discover_runs(results_dir)?;
+        let mut plan = CleanupPlan::default();
+
+        for (index, run) in runs.into_iter().enumerate() {
+            let beyond_count = policy.keep_last.is_some_and(|keep| index >= keep);
+            let too_old = policy
+                .max_age_days
+                .is_some_and(|days| (now - run.started_at).num_days() >= days as i64);
+
+            if beyond_count || too_old {
+                let size = dir_size(&run.path);
+                plan.runs.push((run.path, size));
+            } else if policy.prune_artifacts {
+                plan.artifacts
+                    .extend(find_artifact_dirs(&run.path).into_iter().map(|dir| {
+                        let size = dir_size(&dir);
+                        (dir, size)
+                    }));
+            }
+        }
+
+        Ok(plan)
+    }
+
+    /// @ai:intent Delete everything in the plan
+    /// @ai:post failed deletions are logged and excluded from the report
+    /// @ai:effects fs:write
+    pub fn apply(&self, plan: &CleanupPlan) -> CleanupReport {
+        let mut report = CleanupReport::default();
+
+        for (path, size) in &plan.runs {
+            match remove_dir_all(path) {
+                Ok(()) => {
+                    report.runs_removed += 1;
+                    report.bytes_reclaimed += size;
+                }
+                Err(e) => tracing::warn!("Failed to remove {}: {}", path.display(), e),
+            }
+        }
+
+        for (path, size) in &plan.artifacts {
+            match remove_dir_all(path) {
+                Ok(()) => {
+                    report.artifacts_removed += 1;
+                    report.bytes_reclaimed += size;
+                }
+                Err(e) => tracing::warn!("Failed to remove {}: {}", path.display(), e),
+            }
+        }
+
+        report
+    }
+}
+
+impl Default for ResultsCleaner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// @ai:intent Recognize a run directory and determine its start time
+///            Uses the timestamp in the name, falling back to the modification time
+/// @ai:effects fs:read
+fn run_dir(path: &Path) -> Option<RunDir> {
+    let name = path.file_name()?.to_str()?;
+
+    if let Ok(naive) = NaiveDateTime::parse_from_str(name, RUN_DIR_FORMAT) {
+        return Some(RunDir {
+            path: path.to_path_buf(),
+            started_at: naive.and_utc(),
+        });
+    }
+
+    if !path.join(EXECUTIONS_FILE).exists() {
+        return None;
+    }
+
+    let modified = std::fs::metadata(path).and_then(|m| m.modified()).ok()?;
+    Some(RunDir {
+        path: path.to_path_buf(),
+        started_at: modified.into(),
+    })
+}
+
+/// @ai:intent Find top-most build artifact directories inside a run
+/// @ai:effects fs:read
+fn find_artifact_dirs(run: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    let mut walker = WalkDir::new(run).min_depth(1).into_iter();
+
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else { continue };
+
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+
+        let is_artifact = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|name| ARTIFACT_DIRS.contains(&name));
+
+        if is_artifact {
+            found.push(entry.path().to_path_buf());
+            walker.skip_current_dir();
+        }
+    }
+
+    found
+}
+
+/// @ai:intent Total size of regular files under a directory (symlinks not followed)
+/// @ai:effects fs:read
+fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// @ai:intent Format a byte count for humans
+/// @ai:effects pure
+/// @ai:example (1536) -> "1.5 KiB"
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use tempfile::TempDir;
+
+    fn make_run(root: &Path, name: &str) -> PathBuf {
+        let run = root.join(name);
+        let task = run.join("aicms").join("code").join("impl-rust-factorial");
+        std::fs::create_dir_all(task.join("target").join("debug")).unwrap();
+        std::fs::write(
+            task.join("target").join("debug").join("lib.rlib"),
+            vec![0u8; 2048],
+        )
+        .unwrap();
+        std::fs::write(task.join("lib.rs"), "fn main() {}").unwrap();
+        std::fs::write(run.join("results.json"), "{}").unwrap();
+        run
+    }
+
+    #[test]
+    fn test_plan_keeps_newest_and_prunes_artifacts() {
+        let temp = TempDir::new().unwrap();
+        let old = make_run(temp.path(), "2026-01-01_00-00-00");
+        let recent = make_run(temp.path(), "2026-01-10_00-00-00");
+        let newest = make_run(temp.path(), "2026-01-20_00-00-00");
+        std::fs::create_dir_all(temp.path().join("notes")).unwrap();
+
+        let cleaner = ResultsCleaner::new();
+        let now = "2026-01-21T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        let policy = RetentionPolicy {
+            keep_last: Some(2),
+            prune_artifacts: true,
+            ..Default::default()
+        };
+
+        let plan = cleaner.plan(temp.path(), &policy, now).unwrap();
+
+        assert_eq!(plan.runs.len(), 1);
+        assert_eq!(plan.runs[0].0, old);
+        assert_eq!(plan.artifacts.len(), 2);
+        assert!(plan
+            .artifacts
+            .iter()
+            .all(|(p, size)| p.ends_with("target") && *size == 2048));
+
+        let report = cleaner.apply(&plan);
+
+        assert_eq!(report.runs_removed, 1);
+        assert_eq!(report.bytes_reclaimed, plan.total_bytes());
+        assert!(!old.exists());
+        assert!(newest.join("results.json").exists());
+        assert!(recent
+            .join("aicms/code/impl-rust-factorial/lib.rs")
+            .exists());
+        assert!(!recent
+            .join("aicms/code/impl-rust-factorial/target")
+            .exists());
+        assert!(temp.path().join("notes").exists());
+    }
+
+    #[test]
+    fn test_plan_by_age() {
+        let temp = TempDir::new().unwrap();
+        let now = Utc::now();
+        let old_name = (now - Duration::days(40))
+            .format(RUN_DIR_FORMAT)
+            .to_string();
+        let new_name = (now - Duration::days(1)).format(RUN_DIR_FORMAT).to_string();
+        make_run(temp.path(), &old_name);
+        make_run(temp.path(), &new_name);
+
+        let policy = RetentionPolicy {
+            max_age_days: Some(30),
+            ..Default::default()
+        };
+        let plan = ResultsCleaner::new()
+            .plan(temp.path(), &policy, now)
+            .unwrap();
+
+        assert_eq!(plan.runs.len(), 1);
+        assert!(plan.runs[0].0.ends_with(&old_name));
+        assert!(plan.artifacts.is_empty());
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024 * 1024), "3.0 GiB");
+    }
+}
@@ -0,0 +1,187 @@
+//! @ai:module:intent Claude client for AWS Bedrock (InvokeModel with SigV4 auth)
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api BedrockClient
+//! @ai:module:depends_on runner::sigv4
+//! @ai:module:stateless false
+
+use crate::config::{ApiConfig, BedrockConfig};
+use crate::runner::client::{ApiResponse, ClaudeClientTrait, ClaudeResponse, TaskContext};
+use crate::runner::sigv4::{self, AwsCredentials, SigV4Request};
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+/// @ai:intent Anthropic API version string required by Bedrock
+const BEDROCK_ANTHROPIC_VERSION: &str = "bedrock-2023-05-31";
+
+/// @ai:intent Bedrock InvokeModel body for Anthropic models
+#[derive(Debug, Serialize)]
+struct InvokeRequest<'a> {
+    anthropic_version: &'static str,
+    max_tokens: u32,
+    temperature: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<&'a str>,
+    messages: Vec<Message<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct Message<'a> {
+    role: &'static str,
+    content: &'a str,
+}
+
+/// @ai:intent Claude client that calls Bedrock instead of the Anthropic API
+pub struct BedrockClient {
+    client: reqwest::Client,
+    api: ApiConfig,
+    model_id: String,
+    region: String,
+    credentials: AwsCredentials,
+}
+
+impl BedrockClient {
+    /// @ai:intent Create a Bedrock client from config and AWS environment credentials
+    /// @ai:pre AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY are set;
+    ///         region is configured or AWS_REGION is set
+    /// @ai:effects env
+    pub fn new(config: BedrockConfig, api: ApiConfig) -> Result<Self> {
+        let credentials = AwsCredentials::from_env()?;
+        let region = match config.region {
+            Some(region) => region,
+            None => std::env::var("AWS_REGION")
+                .context("No Bedrock region: set backend.bedrock.region or AWS_REGION")?,
+        };
+
+        Self::with_credentials(config.model_id, region, api, credentials)
+    }
+
+    /// @ai:intent Create a Bedrock client with explicit credentials
+    /// @ai:effects pure
+    pub fn with_credentials(
+        model_id: String,
+        region: String,
+        api: ApiConfig,
+        credentials: AwsCredentials,
+    ) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(120))
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        Ok(Self {
+            client,
+            api,
+            model_id,
+            region,
+            credentials,
+        })
+    }
+
+    /// @ai:intent Host name of the Bedrock runtime endpoint for the region
+    /// @ai:effects pure
+    fn host(&self) -> String {
+        format!("bedrock-runtime.{}.amazonaws.com", self.region)
+    }
+
+    /// @ai:intent InvokeModel path for the configured model (model ID URI-encoded)
+    /// @ai:effects pure
+    fn invoke_path(&self) -> String {
+        format!("/model/{}/invoke", sigv4::uri_encode(&self.model_id))
+    }
+}
+
+impl ClaudeClientTrait for BedrockClient {
+    /// @ai:intent Send a message to Claude via Bedrock InvokeModel
+    /// @ai:effects network, time
+    async fn send_message(
+        &self,
+        prompt: &str,
+        system: Option<&str>,
+        _context: &TaskContext,
+    ) -> Result<ClaudeResponse> {
+        let request = InvokeRequest {
+            anthropic_version: BEDROCK_ANTHROPIC_VERSION,
+            max_tokens: self.api.max_tokens,
+            temperature: self.api.temperature,
+            system,
+            messages: vec![Message {
+                role: "user",
+                content: prompt,
+            }],
+        };
+        let body = serde_json::to_vec(&request)?;
+
+        let host = self.host();
+        let path = self.invoke_path();
+        let signed = sigv4::sign(
+            &SigV4Request {
+                method: "POST",
+                host: &host,
+                path: &path,
+                query: "",
+                headers: &[("content-type", "application/json")],
+                payload: &body,
+            },
+            &self.credentials,
+            &self.region,
+            "bedrock",
+            chrono::Utc::now(),
+        );
+
+        let mut http_request = self
+            .client
+            .post(format!("https://{}{}", host, path))
+            .header("content-type", "application/json")
+            .header("accept", "application/json")
+            .body(body);
+
+        for (name, value) in signed {
+            http_request = http_request.header(name, value);
+        }
+
+        let response = http_request
+            .send()
+            .await
+            .context("Failed to send request to Bedrock")?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Bedrock error ({}): {}", status, error_text);
+        }
+
+        let api_response: ApiResponse = response
+            .json()
+            .await
+            .context("Failed to parse Bedrock response")?;
+
+        Ok(api_response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_and_path() {
+        let client = BedrockClient::with_credentials(
+            "anthropic.claude-sonnet-4-20250514-v1:0".to_string(),
+            "us-west-2".to_string(),
+            ApiConfig::default(),
+            AwsCredentials {
+                access_key_id: "AKID".to_string(),
+                secret_access_key: "secret".to_string(),
+                session_token: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(client.host(), "bedrock-runtime.us-west-2.amazonaws.com");
+        assert_eq!(
+            client.invoke_path(),
+            "/model/anthropic.claude-sonnet-4-20250514-v1%3A0/invoke"
+        );
+    }
+}
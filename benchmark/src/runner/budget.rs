@@ -0,0 +1,142 @@
+//! @ai:module:intent Run-wide spending caps (cost and tokens) shared by all executions
+//! @ai:module:layer application
+//! @ai:module:public_api Budget
+//! @ai:module:depends_on config
+//! @ai:module:stateless false
+
+use crate::config::RunConfig;
+use std::sync::Mutex;
+
+/// @ai:intent Tracks spending against run.max_cost_usd / run.max_total_tokens
+///            Once a cap is reached no new executions start; in-flight ones finish
+pub struct Budget {
+    max_cost_usd: Option<f64>,
+    max_total_tokens: Option<u64>,
+    state: Mutex<BudgetState>,
+}
+
+#[derive(Default)]
+struct BudgetState {
+    cost_usd: f64,
+    total_tokens: u64,
+    /// Executions not started because the budget was exhausted
+    skipped: u32,
+}
+
+impl Budget {
+    /// @ai:intent Create a budget with the given caps (None = uncapped)
+    /// @ai:effects pure
+    pub fn new(max_cost_usd: Option<f64>, max_total_tokens: Option<u64>) -> Self {
+        Self {
+            max_cost_usd,
+            max_total_tokens,
+            state: Mutex::new(BudgetState::default()),
+        }
+    }
+
+    /// @ai:intent Budget for a run, or None when no cap is configured
+    /// @ai:effects pure
+    pub fn from_config(run: &RunConfig) -> Option<Self> {
+        (run.max_cost_usd.is_some() || run.max_total_tokens.is_some())
+            .then(|| Self::new(run.max_cost_usd, run.max_total_tokens))
+    }
+
+    /// @ai:intent Whether a cost cap is configured
+    /// @ai:effects pure
+    pub fn caps_cost(&self) -> bool {
+        self.max_cost_usd.is_some()
+    }
+
+    /// @ai:intent Add the usage of a finished (or resumed) execution
+    /// @ai:effects state:write
+    pub fn record(&self, tokens: u64, cost_usd: Option<f64>) {
+        let mut state = self.state.lock().unwrap();
+        let was_exhausted = self.exhausted_reason(&state).is_some();
+
+        state.total_tokens += tokens;
+        state.cost_usd += cost_usd.unwrap_or(0.0);
+
+        if !was_exhausted {
+            if let Some(reason) = self.exhausted_reason(&state) {
+                tracing::warn!("Budget exhausted: {}; no new executions will start", reason);
+            }
+        }
+    }
+
+    /// @ai:intent Check before starting an execution; counts it as skipped when exhausted
+    /// @ai:post returns false once any cap is reached
+    /// @ai:effects state:write
+    pub fn try_start(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        if self.exhausted_reason(&state).is_some() {
+            state.skipped += 1;
+            return false;
+        }
+        true
+    }
+
+    /// @ai:intent Whether any cap has been reached
+    /// @ai:effects pure
+    pub fn is_exhausted(&self) -> bool {
+        self.exhausted_reason(&self.state.lock().unwrap()).is_some()
+    }
+
+    /// @ai:intent Executions skipped so far because of the budget
+    /// @ai:effects pure
+    pub fn skipped(&self) -> u32 {
+        self.state.lock().unwrap().skipped
+    }
+
+    /// @ai:intent Human-readable truncation note (None while within budget)
+    /// @ai:effects pure
+    /// @ai:example (max_total_tokens 1000, 1200 used) -> "max_total_tokens 1000 reached (1200 used)"
+    pub fn reason(&self) -> Option<String> {
+        self.exhausted_reason(&self.state.lock().unwrap())
+    }
+
+    /// @ai:intent Which cap is reached, if any
+    /// @ai:effects pure
+    fn exhausted_reason(&self, state: &BudgetState) -> Option<String> {
+        if let Some(max) = self.max_cost_usd.filter(|&max| state.cost_usd >= max) {
+            return Some(format!(
+                "max_cost_usd ${:.2} reached (${:.2} spent)",
+                max, state.cost_usd
+            ));
+        }
+        self.max_total_tokens
+            .filter(|&max| state.total_tokens >= max)
+            .map(|max| {
+                format!(
+                    "max_total_tokens {} reached ({} used)",
+                    max, state.total_tokens
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_budget_stops_new_executions() {
+        let budget = Budget::new(Some(1.0), Some(1000));
+
+        assert!(budget.try_start());
+        budget.record(600, Some(0.4));
+        assert!(budget.try_start());
+        budget.record(600, Some(0.4));
+
+        assert!(!budget.try_start());
+        assert!(!budget.try_start());
+        assert_eq!(budget.skipped(), 2);
+        assert_eq!(
+            budget.reason().as_deref(),
+            Some("max_total_tokens 1000 reached (1200 used)")
+        );
+
+        let unpriced = Budget::new(Some(1.0), None);
+        unpriced.record(1_000_000, None);
+        assert!(!unpriced.is_exhausted());
+    }
+}
@@ -0,0 +1,176 @@
+//! @ai:module:intent Incremental persistence of execution results for resumable runs
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api CheckpointStore, ExecutionKey
+//! @ai:module:stateless false
+
+use crate::runner::executor::{ExecutionResult, PromptMode};
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// @ai:intent File name of the execution log inside a run directory
+pub const EXECUTIONS_FILE: &str = "executions.jsonl";
+
+/// @ai:intent Identifies a single execution (task, mode, repetition)
+pub type ExecutionKey = (String, PromptMode, u32);
+
+/// @ai:intent Append-only JSONL log of completed executions
+pub struct CheckpointStore {
+    path: PathBuf,
+    file: Mutex<File>,
+    completed: HashMap<ExecutionKey, ExecutionResult>,
+}
+
+impl CheckpointStore {
+    /// @ai:intent Open (or create) the execution log in a run directory
+    ///            Previously completed executions are loaded so they can be skipped
+    /// @ai:effects fs:read, fs:write
+    pub fn open(output_dir: &Path) -> Result<Self> {
+        let path = output_dir.join(EXECUTIONS_FILE);
+        let completed = Self::load(&path)?
+            .into_iter()
+            .map(|r| ((r.task_id.clone(), r.mode, r.repetition), r))
+            .collect();
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+
+        // Terminate a partially written last line so new records start cleanly
+        let content = std::fs::read(&path)?;
+        if content.last().is_some_and(|b| *b != b'\n') {
+            writeln!(file)?;
+        }
+
+        Ok(Self {
+            path,
+            file: Mutex::new(file),
+            completed,
+        })
+    }
+
+    /// @ai:intent Read all execution results from a JSONL file
+    ///            A truncated trailing line (from an interrupted write) is ignored
+    /// @ai:effects fs:read
+    pub fn load(path: &Path) -> Result<Vec<ExecutionResult>> {
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let reader = BufReader::new(File::open(path)?);
+        let mut results = Vec::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            match serde_json::from_str(&line) {
+                Ok(result) => results.push(result),
+                Err(e) => tracing::warn!(
+                    "Skipping unreadable line {} in {}: {}",
+                    index + 1,
+                    path.display(),
+                    e
+                ),
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// @ai:intent Path of the underlying JSONL file
+    /// @ai:effects pure
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// @ai:intent Get a previously completed execution, if any
+    /// @ai:effects pure
+    pub fn completed(&self, task_id: &str, mode: PromptMode, repetition: u32) -> Option<&ExecutionResult> {
+        self.completed.get(&(task_id.to_string(), mode, repetition))
+    }
+
+    /// @ai:intent Number of executions loaded from a previous run
+    /// @ai:effects pure
+    pub fn completed_count(&self) -> usize {
+        self.completed.len()
+    }
+
+    /// @ai:intent Append a completed execution to the log and flush it to disk
+    /// @ai:effects fs:write
+    pub fn record(&self, result: &ExecutionResult) -> Result<()> {
+        let line = serde_json::to_string(result)?;
+        let mut file = self
+            .file
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Checkpoint file lock poisoned"))?;
+
+        writeln!(file, "{}", line)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn create_result(task_id: &str, mode: PromptMode, repetition: u32) -> ExecutionResult {
+        ExecutionResult {
+            task_id: task_id.to_string(),
+            mode,
+            repetition,
+            response: "```rust\nfn main() {}\n```".to_string(),
+            input_tokens: 10,
+            output_tokens: 20,
+            execution_time_ms: 5,
+            retries: 0,
+            cache_creation_input_tokens: 0,
+            cache_read_input_tokens: 0,
+            num_turns: None,
+            cost_usd: None,
+            stop_reason: "end_turn".to_string(),
+            repair_turns: vec![],
+        }
+    }
+
+    #[test]
+    fn test_record_and_reopen() {
+        let temp = TempDir::new().unwrap();
+
+        let store = CheckpointStore::open(temp.path()).unwrap();
+        assert_eq!(store.completed_count(), 0);
+        store.record(&create_result("task-a", PromptMode::Baseline, 0)).unwrap();
+        store.record(&create_result("task-a", PromptMode::Aicms, 0)).unwrap();
+        drop(store);
+
+        let reopened = CheckpointStore::open(temp.path()).unwrap();
+        assert_eq!(reopened.completed_count(), 2);
+        assert!(reopened.completed("task-a", PromptMode::Aicms, 0).is_some());
+        assert!(reopened.completed("task-a", PromptMode::Aicms, 1).is_none());
+    }
+
+    #[test]
+    fn test_load_ignores_truncated_line() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join(EXECUTIONS_FILE);
+        let valid = serde_json::to_string(&create_result("task-a", PromptMode::Baseline, 0)).unwrap();
+        std::fs::write(&path, format!("{}\n{{\"task_id\":\"tas", valid)).unwrap();
+
+        let results = CheckpointStore::load(&path).unwrap();
+        assert_eq!(results.len(), 1);
+
+        let store = CheckpointStore::open(temp.path()).unwrap();
+        store.record(&create_result("task-a", PromptMode::Aicms, 0)).unwrap();
+        assert_eq!(CheckpointStore::load(&path).unwrap().len(), 2);
+    }
+}
@@ -1,13 +1,62 @@
 //! @ai:module:intent Claude Code CLI client for benchmark execution
 //! @ai:module:layer infrastructure
-//! @ai:module:public_api ClaudeCodeClient
+//! @ai:module:public_api ClaudeCodeClient, default_claude_md_template
 //! @ai:module:stateless true
 
+use crate::config::{ClaudeCodeConfig, PermissionProfile};
+use crate::platform::{portable_path, remove_dir_all, resolve_executable};
 use crate::runner::client::{ClaudeClientTrait, ClaudeResponse, TaskContext};
+use crate::runner::manifest::{hash_prompt, TaskManifest, MANIFEST_FILE};
+use crate::runner::process::{timeout_from_secs, ProcessTree, POLL_INTERVAL};
 use anyhow::{Context, Result};
-use std::path::PathBuf;
-use std::process::{Command, Stdio};
+use serde::Deserialize;
 use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWriteExt, BufReader};
+use tokio::process::Command;
+
+/// Longest progress line written to the log, in bytes
+const MAX_PROGRESS_LEN: usize = 160;
+
+/// @ai:intent Final `result` message of `claude --print --output-format json`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub(crate) struct CliResult {
+    #[serde(default)]
+    pub(crate) result: String,
+    #[serde(default)]
+    pub(crate) is_error: bool,
+    /// Session to continue with --resume
+    #[serde(default)]
+    pub(crate) session_id: Option<String>,
+    #[serde(default)]
+    num_turns: Option<u32>,
+    #[serde(default)]
+    total_cost_usd: Option<f64>,
+    #[serde(default)]
+    usage: Option<CliUsage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CliUsage {
+    #[serde(default)]
+    input_tokens: u32,
+    #[serde(default)]
+    output_tokens: u32,
+    #[serde(default)]
+    cache_creation_input_tokens: u32,
+    #[serde(default)]
+    cache_read_input_tokens: u32,
+}
+
+impl CliUsage {
+    /// @ai:intent All prompt tokens processed, whether cached or not
+    /// @ai:effects pure
+    fn total_input_tokens(&self) -> u32 {
+        self.input_tokens + self.cache_creation_input_tokens + self.cache_read_input_tokens
+    }
+}
 
 /// @ai:intent Client that uses Claude Code CLI instead of direct API
 pub struct ClaudeCodeClient {
@@ -16,6 +65,9 @@ pub struct ClaudeCodeClient {
     output_dir: PathBuf,
     /// Path to the AICMS skill file
     skill_file: PathBuf,
+    /// CLAUDE.md written in AICMS mode; {{SKILL_PATH}} is replaced with the skill file
+    claude_md_template: String,
+    config: ClaudeCodeConfig,
 }
 
 impl ClaudeCodeClient {
@@ -26,6 +78,8 @@ impl ClaudeCodeClient {
             model: None,
             output_dir,
             skill_file: PathBuf::from("../skills/aicms/SKILL.md"),
+            claude_md_template: default_claude_md_template(),
+            config: ClaudeCodeConfig::default(),
         }
     }
 
@@ -36,6 +90,8 @@ impl ClaudeCodeClient {
             model: Some(model),
             output_dir,
             skill_file: PathBuf::from("../skills/aicms/SKILL.md"),
+            claude_md_template: default_claude_md_template(),
+            config: ClaudeCodeConfig::default(),
         }
     }
 
@@ -46,6 +102,60 @@ impl ClaudeCodeClient {
         self
     }
 
+    /// @ai:intent Set the CLAUDE.md template used in AICMS mode
+    /// @ai:effects pure
+    pub fn with_claude_md_template(mut self, template: String) -> Self {
+        self.claude_md_template = template;
+        self
+    }
+
+    /// @ai:intent Set tool permissions and MCP servers ([backend.claude_code])
+    /// @ai:effects pure
+    pub fn with_config(mut self, config: ClaudeCodeConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// @ai:intent CLI arguments for the configured tool permissions
+    /// @ai:effects pure
+    fn permission_args(&self) -> Vec<String> {
+        let mut args: Vec<String> = match self.config.permission_profile {
+            PermissionProfile::Bypass => vec!["--dangerously-skip-permissions".into()],
+            PermissionProfile::AcceptEdits => {
+                vec!["--permission-mode".into(), "acceptEdits".into()]
+            }
+            PermissionProfile::Restricted => vec!["--permission-mode".into(), "default".into()],
+        };
+
+        for (flag, tools) in [
+            ("--allowedTools", &self.config.allowed_tools),
+            ("--disallowedTools", &self.config.disallowed_tools),
+        ] {
+            if !tools.is_empty() {
+                args.push(flag.to_string());
+                args.extend(tools.iter().cloned());
+            }
+        }
+
+        args
+    }
+
+    /// @ai:intent CLI arguments for the configured MCP servers (none if there are none)
+    ///            Strict mode keeps MCP servers from user or project settings out of the run
+    /// @ai:effects pure
+    fn mcp_args(&self) -> Result<Vec<String>> {
+        if self.config.mcp_servers.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let config = serde_json::json!({ "mcpServers": self.config.mcp_servers });
+        Ok(vec![
+            "--mcp-config".to_string(),
+            serde_json::to_string(&config)?,
+            "--strict-mcp-config".to_string(),
+        ])
+    }
+
     /// @ai:intent Get the code directory for a mode (baseline/aicms)
     /// @ai:effects pure
     fn get_code_dir(&self, mode: &str) -> PathBuf {
@@ -65,14 +175,12 @@ impl ClaudeCodeClient {
         let report_dir = self.get_report_dir(mode).join(task_id);
 
         // Clean up if exists from previous run
-        if code_dir.exists() {
-            std::fs::remove_dir_all(&code_dir)?;
-        }
+        remove_dir_all(&code_dir)
+            .with_context(|| format!("Failed to remove {}", code_dir.display()))?;
         std::fs::create_dir_all(&code_dir)?;
 
-        if report_dir.exists() {
-            std::fs::remove_dir_all(&report_dir)?;
-        }
+        remove_dir_all(&report_dir)
+            .with_context(|| format!("Failed to remove {}", report_dir.display()))?;
         std::fs::create_dir_all(&report_dir)?;
 
         Ok((code_dir, report_dir))
@@ -80,7 +188,7 @@ impl ClaudeCodeClient {
 
     /// @ai:intent Create CLAUDE.md file for AICMS mode that imports the skill
     /// @ai:effects fs:write
-    fn create_aicms_claude_md(&self, code_dir: &PathBuf) -> Result<()> {
+    fn create_aicms_claude_md(&self, code_dir: &Path) -> Result<()> {
         // Get absolute path to skill file
         let skill_path = if self.skill_file.is_absolute() {
             self.skill_file.clone()
@@ -88,23 +196,45 @@ impl ClaudeCodeClient {
             std::env::current_dir()?.join(&self.skill_file)
         };
 
-        let skill_path_str = skill_path.to_string_lossy().replace('\\', "/");
-
-        let claude_md_content = format!(
-            "# AICMS Benchmark\n\n\
-             @{}\n\n\
-             When working in this codebase:\n\
-             - Read and respect existing @ai:* annotations\n\
-             - Add annotations to new functions you create\n\
-             - Validate implementations against their specs\n\
-             - Flag discrepancies between intent and implementation\n",
-            skill_path_str
-        );
+        // CLAUDE.md imports take '/' separators, also on Windows
+        let skill_path_str = portable_path(&skill_path);
+
+        let claude_md_content = self
+            .claude_md_template
+            .replace("{{SKILL_PATH}}", &skill_path_str);
 
         std::fs::write(code_dir.join("CLAUDE.md"), claude_md_content)?;
         Ok(())
     }
 
+    /// @ai:intent Write TASK.json describing how the code directory was generated
+    /// @ai:effects fs:write
+    fn write_manifest(
+        &self,
+        code_dir: &Path,
+        context: &TaskContext,
+        prompt: &str,
+        started_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<()> {
+        let run_id = self
+            .output_dir
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        TaskManifest {
+            task_id: context.task_id.clone(),
+            mode: context.mode.clone(),
+            repetition: context.repetition,
+            run_id,
+            model: self.model.clone(),
+            prompt_hash: hash_prompt(prompt),
+            started_at,
+            finished_at: chrono::Utc::now(),
+        }
+        .write(code_dir)
+    }
+
     /// @ai:intent Read all source files from directory recursively
     /// @ai:effects fs:read
     fn collect_generated_files(&self, dir: &PathBuf) -> Result<Vec<(String, String)>> {
@@ -130,25 +260,29 @@ impl ClaudeCodeClient {
             let path = entry.path();
 
             if path.is_dir() {
-                // Skip hidden directories and target/
+                // Skip hidden directories, target/ and build/
                 let name = path.file_name().unwrap_or_default().to_string_lossy();
 
-                if !name.starts_with('.') && name != "target" {
+                if !name.starts_with('.') && name != "target" && name != "build" {
                     self.collect_files_recursive(base, &path, files)?;
                 }
             } else {
-                // Include source files, exclude CLAUDE.md
+                // Include source files, exclude CLAUDE.md and TASK.json
                 let name = path.file_name().unwrap_or_default().to_string_lossy();
                 let ext = path.extension().unwrap_or_default().to_string_lossy();
 
-                if name == "CLAUDE.md" {
+                if name == "CLAUDE.md" || name == MANIFEST_FILE {
                     continue;
                 }
 
-                if matches!(ext.as_ref(), "rs" | "py" | "ts" | "js" | "toml" | "json") {
+                if matches!(
+                    ext.as_ref(),
+                    "rs" | "py" | "ts" | "js" | "go" | "java" | "toml" | "json" | "mod" | "gradle"
+                        | "kts" | "xml"
+                ) {
                     let relative = path.strip_prefix(base).unwrap_or(&path);
                     let content = std::fs::read_to_string(&path)?;
-                    files.push((relative.to_string_lossy().to_string(), content));
+                    files.push((portable_path(relative), content));
                 }
             }
         }
@@ -178,6 +312,8 @@ impl ClaudeClientTrait for ClaudeCodeClient {
         _system: Option<&str>,
         context: &TaskContext,
     ) -> Result<ClaudeResponse> {
+        let started_at = chrono::Utc::now();
+
         // Create fresh directories for code and reports
         let (code_dir, report_dir) = self.create_run_dirs(&context.task_id, &context.mode)?;
 
@@ -193,14 +329,21 @@ impl ClaudeClientTrait for ClaudeCodeClient {
         // Build the prompt (SAME for both modes - no system prompt difference)
         let full_prompt = build_prompt(prompt);
 
-        let mut cmd = Command::new("claude");
+        let mut cmd = Command::new(resolve_executable("claude"));
 
         // Run in agentic mode with stdin prompt
         cmd.arg("--print");
         cmd.arg("--verbose");
 
-        // Bypass all permissions so Claude can run cargo test, etc.
-        cmd.arg("--dangerously-skip-permissions");
+        // One JSON message per line, so progress can be shown while Claude works;
+        // the final result message carries the real token usage, turn count, and cost
+        cmd.arg("--output-format").arg("stream-json");
+
+        // Tool permissions from [backend.claude_code] (default: bypass, so Claude can run cargo test, etc.)
+        cmd.args(self.permission_args());
+
+        // MCP servers from [backend.claude_code.mcp_servers]
+        cmd.args(self.mcp_args()?);
 
         // Skip user's home settings to avoid influencing generation
         cmd.arg("--setting-sources").arg("project,local");
@@ -218,6 +361,9 @@ impl ClaudeClientTrait for ClaudeCodeClient {
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
+        // Own process group, so a timeout also kills the cargo/npm processes Claude started
+        ProcessTree::configure(cmd.as_std_mut());
+
         tracing::info!(
             "Running Claude in {} mode (skill={}) in {}",
             context.mode,
@@ -225,43 +371,108 @@ impl ClaudeClientTrait for ClaudeCodeClient {
             code_dir.display()
         );
 
+        let log_path = report_dir.join("_claude_interaction.log");
+        let log_header = format!(
+            "=== MODE ===\n{} (use_aicms_skill={})\n\n=== PROMPT ===\n{}\n\n",
+            context.mode, context.use_aicms_skill, full_prompt
+        );
+        let live_log = if self.config.stream_log {
+            let mut file = std::fs::File::create(&log_path)
+                .with_context(|| format!("Failed to create {}", log_path.display()))?;
+            writeln!(file, "{}=== OUTPUT (live) ===", log_header)?;
+            Some(Arc::new(Mutex::new(file)))
+        } else {
+            None
+        };
+
         let mut child = cmd
             .spawn()
             .context("Failed to execute claude CLI. Is Claude Code installed?")?;
+        let tree = child.id().map(ProcessTree::attach);
 
-        // Write prompt to stdin
+        // Write prompt to stdin (dropped afterwards, closing it)
         if let Some(mut stdin) = child.stdin.take() {
             stdin
                 .write_all(full_prompt.as_bytes())
+                .await
                 .context("Failed to write prompt to claude stdin")?;
         }
 
-        let output = child
-            .wait_with_output()
-            .context("Failed to wait for claude process")?;
-
-        let stdout = String::from_utf8_lossy(&output.stdout).to_string();
-        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        // Read both pipes while the process runs, so progress shows up as it happens
+        let label = format!("{} (mode={})", context.task_id, context.mode);
+        let child_stdout = child.stdout.take().context("claude stdout not captured")?;
+        let child_stderr = child.stderr.take().context("claude stderr not captured")?;
+        let timeout = timeout_from_secs(self.config.timeout_secs);
+        let wait = async {
+            let exited = wait_for_exit(&mut child, tree.as_ref());
+            let timed_out = match timeout {
+                None => {
+                    exited.await?;
+                    false
+                }
+                Some(limit) => match tokio::time::timeout(limit, exited).await {
+                    Ok(exited) => {
+                        exited?;
+                        false
+                    }
+                    Err(_) => true,
+                },
+            };
+            // Killing the whole tree also closes the pipes, which ends the pumps; on exit it
+            // takes the subprocesses the CLI left behind
+            if let Some(tree) = &tree {
+                tree.kill_before_reap();
+            }
+            if timed_out {
+                child.start_kill().ok();
+            }
+            child
+                .wait()
+                .await
+                .map(|status| (!timed_out).then_some(status))
+        };
+        let (stdout, stderr, status) = tokio::join!(
+            pump_output(child_stdout, "stdout", &label, live_log.clone()),
+            pump_output(child_stderr, "stderr", &label, live_log.clone()),
+            wait
+        );
+        let stdout = stdout.context("Failed to read claude stdout")?;
+        let stderr = stderr.context("Failed to read claude stderr")?;
+        // None: killed after the timeout
+        let status = status.context("Failed to wait for claude process")?;
+        let exit = match status {
+            Some(status) => format!("{:?}", status.code()),
+            None => format!("killed after timeout of {}s", self.config.timeout_secs),
+        };
 
         // Save interaction log in report directory
-        let log_path = report_dir.join("_claude_interaction.log");
-        let log_content = format!(
-            "=== MODE ===\n{} (use_aicms_skill={})\n\n\
-             === PROMPT ===\n{}\n\n\
-             === STDOUT ===\n{}\n\n\
-             === STDERR ===\n{}\n\n\
-             === EXIT CODE ===\n{:?}",
-            context.mode, context.use_aicms_skill, full_prompt, stdout, stderr, output.status.code()
-        );
-        std::fs::write(&log_path, &log_content).ok();
+        match &live_log {
+            Some(file) => {
+                let mut file = file.lock().unwrap();
+                write!(file, "\n=== EXIT CODE ===\n{}", exit).ok();
+            }
+            None => {
+                let log_content = format!(
+                    "{}=== STDOUT ===\n{}\n\n=== STDERR ===\n{}\n\n=== EXIT CODE ===\n{}",
+                    log_header, stdout, stderr, exit
+                );
+                std::fs::write(&log_path, &log_content).ok();
+            }
+        }
         tracing::info!("Saved interaction log to {}", log_path.display());
 
-        if !output.status.success() {
-            tracing::warn!(
-                "Claude CLI returned non-zero exit code: {:?}",
-                output.status.code()
-            );
-            tracing::warn!("stderr: {}", stderr);
+        match status {
+            Some(status) if !status.success() => {
+                tracing::warn!("Claude CLI returned non-zero exit code: {:?}", status.code());
+                tracing::warn!("stderr: {}", stderr);
+            }
+            None => tracing::warn!(
+                "Claude CLI timed out after {}s on {}; killed it and its subprocesses, \
+                 evaluating the files written so far",
+                self.config.timeout_secs,
+                context.task_id
+            ),
+            _ => {}
         }
 
         // Collect all generated files from the code directory
@@ -274,29 +485,56 @@ impl ClaudeClientTrait for ClaudeCodeClient {
             generated_files.iter().map(|(p, _)| p).collect::<Vec<_>>()
         );
 
+        self.write_manifest(&code_dir, context, &full_prompt, started_at)?;
+
         // Also log stdout preview if no files generated
         if generated_files.is_empty() {
             let preview = truncate_string(&stdout, 500);
             tracing::warn!("No files generated. stdout preview:\n{}", preview);
         }
 
+        let cli_result = parse_cli_output(&stdout);
+        if cli_result.as_ref().is_some_and(|r| r.is_error) {
+            tracing::warn!("Claude CLI reported an error result for {}", context.task_id);
+        }
+
         // Format as markdown code blocks for the evaluator
         let content = if generated_files.is_empty() {
-            // If no files were generated, return Claude's stdout (might contain code blocks)
-            stdout
+            // If no files were generated, return Claude's answer (might contain code blocks)
+            match &cli_result {
+                Some(result) => result.result.clone(),
+                None => stdout,
+            }
         } else {
             self.format_files_as_markdown(&generated_files, language)
         };
 
-        // Estimate tokens
-        let estimated_input_tokens = (full_prompt.len() / 4) as u32;
-        let estimated_output_tokens = (content.len() / 4) as u32;
+        let usage = cli_result.as_ref().and_then(|r| r.usage.clone());
+        let stop_reason = match status {
+            None => "timeout",
+            Some(status) if !status.success() => "error",
+            _ if cli_result.as_ref().is_some_and(|r| r.is_error) => "error",
+            _ => "end_turn",
+        };
+        let (input_tokens, output_tokens) = match &usage {
+            Some(usage) => (usage.total_input_tokens(), usage.output_tokens),
+            None => {
+                tracing::warn!("No usage in Claude CLI output; estimating tokens from text length");
+                ((full_prompt.len() / 4) as u32, (content.len() / 4) as u32)
+            }
+        };
 
         Ok(ClaudeResponse {
             content,
-            input_tokens: estimated_input_tokens,
-            output_tokens: estimated_output_tokens,
-            stop_reason: "end_turn".to_string(),
+            input_tokens,
+            output_tokens,
+            stop_reason: stop_reason.to_string(),
+            cache_creation_input_tokens: usage
+                .as_ref()
+                .map_or(0, |u| u.cache_creation_input_tokens),
+            cache_read_input_tokens: usage.as_ref().map_or(0, |u| u.cache_read_input_tokens),
+            num_turns: cli_result.as_ref().and_then(|r| r.num_turns),
+            cost_usd: cli_result.as_ref().and_then(|r| r.total_cost_usd),
         })
     }
 }
@@ -310,6 +548,13 @@ fn detect_language(prompt: &str) -> &'static str {
         "python"
     } else if prompt.contains("TypeScript") || prompt.contains("typescript") {
         "typescript"
+    } else if regex::Regex::new(r"\b(?:Go|[Gg]olang)\b")
+        .unwrap()
+        .is_match(prompt)
+    {
+        "go"
+    } else if regex::Regex::new(r"\bJava\b").unwrap().is_match(prompt) {
+        "java"
     } else {
         "rust" // default
     }
@@ -327,16 +572,166 @@ fn build_prompt(prompt: &str) -> String {
     )
 }
 
-/// @ai:intent Truncate string with ellipsis if too long
+/// @ai:intent Find the result message in JSON CLI output
+///            A single object normally; an array of all session messages with --verbose;
+///            one message per line with --output-format stream-json
+/// @ai:effects pure
+pub(crate) fn parse_cli_output(stdout: &str) -> Option<CliResult> {
+    let is_result = |m: &serde_json::Value| m.get("type").and_then(|t| t.as_str()) == Some("result");
+
+    let result = match serde_json::from_str(stdout.trim()) {
+        Ok(serde_json::Value::Array(messages)) => messages.into_iter().rev().find(is_result)?,
+        Ok(object) => object,
+        Err(_) => stdout
+            .lines()
+            .rev()
+            .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+            .find(is_result)?,
+    };
+
+    serde_json::from_value(result).ok()
+}
+
+/// @ai:intent Wait until the CLI exits, leaving it unreaped on Unix so that the
+///            subprocesses it left behind can still be killed by process group
+/// @ai:effects io
+async fn wait_for_exit(
+    child: &mut tokio::process::Child,
+    tree: Option<&ProcessTree>,
+) -> std::io::Result<()> {
+    #[cfg(unix)]
+    if let Some(tree) = tree {
+        while !tree.has_exited()? {
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+        return Ok(());
+    }
+    #[cfg(not(unix))]
+    let _ = tree;
+    child.wait().await.map(|_| ())
+}
+
+/// @ai:intent Read a child pipe line by line until it closes, logging progress as it goes
+///            and appending each line to the live interaction log, if any
+/// @ai:post returns everything read, one line per line
+/// @ai:effects io, fs:write
+async fn pump_output<R: AsyncRead + Unpin>(
+    pipe: R,
+    stream: &str,
+    label: &str,
+    live_log: Option<Arc<Mutex<std::fs::File>>>,
+) -> std::io::Result<String> {
+    let mut lines = BufReader::new(pipe).lines();
+    let mut output = String::new();
+
+    while let Some(line) = lines.next_line().await? {
+        if let Some(progress) = summarize_cli_line(&line) {
+            tracing::info!("{} {}: {}", label, stream, progress);
+        }
+        if let Some(file) = &live_log {
+            let mut file = file.lock().unwrap();
+            writeln!(file, "[{}] {}", stream, line).ok();
+            file.flush().ok();
+        }
+        output.push_str(&line);
+        output.push('\n');
+    }
+
+    Ok(output)
+}
+
+/// @ai:intent One-line progress summary of a CLI output line (None for noise)
+///            stream-json messages are condensed to text and tool calls; tool results
+///            are skipped since they can be whole files; other lines pass through
+/// @ai:effects pure
+/// @ai:example ({"type":"assistant",..."name":"Bash","input":{"command":"cargo test"}}) -> "Bash: cargo test"
+fn summarize_cli_line(line: &str) -> Option<String> {
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+
+    let message: serde_json::Value = match serde_json::from_str(line) {
+        Ok(message) => message,
+        Err(_) => return Some(truncate_string(line, MAX_PROGRESS_LEN)),
+    };
+
+    let summary = match message.get("type").and_then(|t| t.as_str())? {
+        "system" => format!(
+            "session started ({})",
+            message.get("model").and_then(|m| m.as_str()).unwrap_or("default model")
+        ),
+        "assistant" => {
+            let parts: Vec<String> = message
+                .pointer("/message/content")?
+                .as_array()?
+                .iter()
+                .filter_map(|block| match block.get("type").and_then(|t| t.as_str())? {
+                    "text" => block
+                        .get("text")
+                        .and_then(|t| t.as_str())
+                        .and_then(|t| t.lines().find(|l| !l.trim().is_empty()))
+                        .map(str::to_string),
+                    "tool_use" => {
+                        let name = block.get("name").and_then(|n| n.as_str())?;
+                        let input = block.get("input");
+                        let detail = ["command", "file_path", "pattern", "path"]
+                            .iter()
+                            .find_map(|key| input?.get(*key)?.as_str());
+                        Some(match detail {
+                            Some(detail) => format!("{}: {}", name, detail),
+                            None => name.to_string(),
+                        })
+                    }
+                    _ => None,
+                })
+                .collect();
+            if parts.is_empty() {
+                return None;
+            }
+            parts.join("; ")
+        }
+        "result" => format!(
+            "finished ({}, {} turns)",
+            message.get("subtype").and_then(|s| s.as_str()).unwrap_or("done"),
+            message.get("num_turns").and_then(|n| n.as_u64()).unwrap_or(0)
+        ),
+        _ => return None,
+    };
+
+    Some(truncate_string(&summary, MAX_PROGRESS_LEN))
+}
+
+/// @ai:intent Truncate string with ellipsis if too long (cut at a character boundary)
 /// @ai:effects pure
 fn truncate_string(s: &str, max_len: usize) -> String {
     if s.len() > max_len {
-        format!("{}...", &s[..max_len])
+        let mut end = max_len;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}...", &s[..end])
     } else {
         s.to_string()
     }
 }
 
+/// @ai:intent Default CLAUDE.md template for AICMS mode
+/// @ai:effects pure
+pub fn default_claude_md_template() -> String {
+    r#"# AICMS Benchmark
+
+@{{SKILL_PATH}}
+
+When working in this codebase:
+- Read and respect existing @ai:* annotations
+- Add annotations to new functions you create
+- Validate implementations against their specs
+- Flag discrepancies between intent and implementation
+"#
+    .to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,6 +748,57 @@ mod tests {
         assert_eq!(client.model, Some("sonnet".to_string()));
     }
 
+    #[test]
+    fn test_permission_args() {
+        let temp = TempDir::new().unwrap();
+        let client = ClaudeCodeClient::new(temp.path().to_path_buf());
+        assert_eq!(client.permission_args(), vec!["--dangerously-skip-permissions"]);
+
+        let client = client.with_config(ClaudeCodeConfig {
+            permission_profile: PermissionProfile::AcceptEdits,
+            allowed_tools: vec!["Bash(cargo test:*)".to_string(), "Read".to_string()],
+            disallowed_tools: vec!["WebFetch".to_string()],
+            ..Default::default()
+        });
+        assert_eq!(
+            client.permission_args(),
+            vec![
+                "--permission-mode",
+                "acceptEdits",
+                "--allowedTools",
+                "Bash(cargo test:*)",
+                "Read",
+                "--disallowedTools",
+                "WebFetch"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mcp_args() {
+        let temp = TempDir::new().unwrap();
+        let client = ClaudeCodeClient::new(temp.path().to_path_buf());
+        assert!(client.mcp_args().unwrap().is_empty());
+
+        let mut permissions = ClaudeCodeConfig::default();
+        permissions.mcp_servers.insert(
+            "docs".to_string(),
+            crate::config::McpServerConfig {
+                transport: Some("http".to_string()),
+                url: Some("http://localhost:3000/mcp".to_string()),
+                ..Default::default()
+            },
+        );
+        let args = client.with_config(permissions).mcp_args().unwrap();
+
+        assert_eq!(args[0], "--mcp-config");
+        assert_eq!(
+            args[1],
+            r#"{"mcpServers":{"docs":{"type":"http","url":"http://localhost:3000/mcp"}}}"#
+        );
+        assert_eq!(args[2], "--strict-mcp-config");
+    }
+
     #[test]
     fn test_format_files_as_markdown() {
         let temp = TempDir::new().unwrap();
@@ -378,13 +824,79 @@ mod tests {
         assert_eq!(detect_language("Write a python function"), "python");
         assert_eq!(detect_language("Write a TypeScript function"), "typescript");
         assert_eq!(detect_language("Write a typescript function"), "typescript");
+        assert_eq!(detect_language("Write a Go function"), "go");
+        assert_eq!(detect_language("Write a Good function"), "rust");
+        assert_eq!(detect_language("Write a Java class"), "java");
+        assert_eq!(detect_language("Write a JavaScript function"), "rust");
         assert_eq!(detect_language("Write a function"), "rust"); // default
     }
 
+    #[test]
+    fn test_parse_cli_output() {
+        let result = r#"{"type":"result","subtype":"success","is_error":false,"num_turns":7,
+            "result":"Done","total_cost_usd":0.042,
+            "usage":{"input_tokens":12,"cache_creation_input_tokens":3000,
+                     "cache_read_input_tokens":20000,"output_tokens":1500}}"#;
+        let verbose = format!(r#"[{{"type":"system","subtype":"init"}}, {}]"#, result);
+        let stream = format!(
+            "{{\"type\":\"system\",\"subtype\":\"init\"}}\n{}\n",
+            result.replace('\n', "")
+        );
+
+        for output in [result.to_string(), verbose, stream] {
+            let parsed = parse_cli_output(&output).unwrap();
+            assert_eq!(parsed.num_turns, Some(7));
+            assert_eq!(parsed.total_cost_usd, Some(0.042));
+            assert_eq!(parsed.usage.unwrap().total_input_tokens(), 23012);
+        }
+        assert!(parse_cli_output("plain text output").is_none());
+    }
+
     #[test]
     fn test_truncate_string() {
         assert_eq!(truncate_string("short", 10), "short");
         assert_eq!(truncate_string("this is a long string", 10), "this is a ...");
+        assert_eq!(truncate_string("héllo", 2), "h...");
+    }
+
+    #[test]
+    fn test_summarize_cli_line() {
+        let tool_use = r#"{"type":"assistant","message":{"content":[
+            {"type":"text","text":"Running the tests now.\nThen fixing."},
+            {"type":"tool_use","name":"Bash","input":{"command":"cargo test"}}]}}"#
+            .replace('\n', "");
+        assert_eq!(
+            summarize_cli_line(&tool_use).as_deref(),
+            Some("Running the tests now.; Bash: cargo test")
+        );
+
+        let tool_result =
+            r#"{"type":"user","message":{"content":[{"type":"tool_result","content":"..."}]}}"#;
+        assert_eq!(summarize_cli_line(tool_result), None);
+        assert_eq!(
+            summarize_cli_line(r#"{"type":"result","subtype":"success","num_turns":4}"#)
+                .as_deref(),
+            Some("finished (success, 4 turns)")
+        );
+        assert_eq!(summarize_cli_line("error: rate limited").as_deref(), Some("error: rate limited"));
+        assert_eq!(summarize_cli_line("  "), None);
+    }
+
+    #[test]
+    fn test_claude_md_template_substitutes_skill_path() {
+        let temp = TempDir::new().unwrap();
+        let skill = temp.path().join("SKILL.md");
+        let client = ClaudeCodeClient::new(temp.path().to_path_buf()).with_skill_file(skill.clone());
+
+        client.create_aicms_claude_md(temp.path()).unwrap();
+        let content = std::fs::read_to_string(temp.path().join("CLAUDE.md")).unwrap();
+        assert!(content.starts_with("# AICMS Benchmark"));
+        assert!(content.contains(&format!("@{}\n", portable_path(&skill))));
+
+        let client = client.with_claude_md_template("Follow {{SKILL_PATH}} strictly.".to_string());
+        client.create_aicms_claude_md(temp.path()).unwrap();
+        let content = std::fs::read_to_string(temp.path().join("CLAUDE.md")).unwrap();
+        assert_eq!(content, format!("Follow {} strictly.", portable_path(&skill)));
     }
 
     #[test]
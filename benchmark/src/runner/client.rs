@@ -4,10 +4,9 @@
 //! @ai:module:stateless false
 
 use crate::config::ApiConfig;
-use crate::runner::rate_limiter::{RateLimiter, RateLimiterTrait};
+use crate::runner::streaming::{SseParser, StreamAccumulator};
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
 
 /// @ai:intent Context for task execution
 #[derive(Debug, Clone)]
@@ -18,18 +17,19 @@ pub struct TaskContext {
     pub mode: String,
     /// Whether this is AICMS mode (uses skill file)
     pub use_aicms_skill: bool,
+    /// Repetition index (0-based)
+    pub repetition: u32,
 }
 
 /// @ai:intent Trait for Claude API client
-#[allow(async_fn_in_trait)]
 pub trait ClaudeClientTrait: Send + Sync {
     /// @ai:intent Send a message to Claude and get a response
-    async fn send_message(
+    fn send_message(
         &self,
         prompt: &str,
         system: Option<&str>,
         context: &TaskContext,
-    ) -> Result<ClaudeResponse>;
+    ) -> impl std::future::Future<Output = Result<ClaudeResponse>> + Send;
 }
 
 /// @ai:intent Response from Claude API
@@ -39,6 +39,18 @@ pub struct ClaudeResponse {
     pub input_tokens: u32,
     pub output_tokens: u32,
     pub stop_reason: String,
+    /// Prompt tokens written to the prompt cache (included in input_tokens)
+    #[serde(default)]
+    pub cache_creation_input_tokens: u32,
+    /// Prompt tokens served from the prompt cache (included in input_tokens)
+    #[serde(default)]
+    pub cache_read_input_tokens: u32,
+    /// Agentic turns taken (Claude Code only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_turns: Option<u32>,
+    /// Cost reported by the backend (Claude Code only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
 }
 
 /// @ai:intent Claude API request body
@@ -48,24 +60,95 @@ struct ApiRequest<'a> {
     max_tokens: u32,
     temperature: f32,
     #[serde(skip_serializing_if = "Option::is_none")]
-    system: Option<&'a str>,
+    system: Option<SystemPrompt<'a>>,
     messages: Vec<Message<'a>>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
+}
+
+/// @ai:intent System prompt as plain text, or as a content block marked for prompt caching
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum SystemPrompt<'a> {
+    Text(&'a str),
+    Cached([SystemBlock<'a>; 1]),
+}
+
+#[derive(Debug, Serialize)]
+struct SystemBlock<'a> {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    text: &'a str,
+    cache_control: CacheControl,
+}
+
+#[derive(Debug, Serialize)]
+struct CacheControl {
+    #[serde(rename = "type")]
+    kind: &'static str,
+}
+
+impl<'a> SystemPrompt<'a> {
+    /// @ai:intent Wrap the system prompt, marking it cacheable if caching is enabled
+    ///            The system prompt is identical for every task, so later requests read it from cache
+    /// @ai:effects pure
+    fn new(system: &'a str, cache: bool) -> Self {
+        if !cache {
+            return SystemPrompt::Text(system);
+        }
+
+        SystemPrompt::Cached([SystemBlock {
+            kind: "text",
+            text: system,
+            cache_control: CacheControl { kind: "ephemeral" },
+        }])
+    }
 }
 
+/// @ai:intent How often streaming progress is logged
+const PROGRESS_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
 #[derive(Debug, Serialize)]
 struct Message<'a> {
     role: &'static str,
     content: &'a str,
 }
 
-/// @ai:intent Claude API response body
+/// @ai:intent Claude API response body (also returned by Bedrock)
 #[derive(Debug, Deserialize)]
-struct ApiResponse {
+pub(crate) struct ApiResponse {
     content: Vec<ContentBlock>,
     usage: Usage,
     stop_reason: String,
 }
 
+impl From<ApiResponse> for ClaudeResponse {
+    fn from(response: ApiResponse) -> Self {
+        let content = response
+            .content
+            .into_iter()
+            .map(|c| c.text)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let usage = response.usage;
+
+        ClaudeResponse {
+            content,
+            // input_tokens from the API excludes cached tokens; report all prompt tokens
+            input_tokens: usage.input_tokens
+                + usage.cache_creation_input_tokens
+                + usage.cache_read_input_tokens,
+            output_tokens: usage.output_tokens,
+            stop_reason: response.stop_reason,
+            cache_creation_input_tokens: usage.cache_creation_input_tokens,
+            cache_read_input_tokens: usage.cache_read_input_tokens,
+            num_turns: None,
+            cost_usd: None,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 struct ContentBlock {
     text: String,
@@ -75,13 +158,30 @@ struct ContentBlock {
 struct Usage {
     input_tokens: u32,
     output_tokens: u32,
+    #[serde(default)]
+    cache_creation_input_tokens: u32,
+    #[serde(default)]
+    cache_read_input_tokens: u32,
 }
 
-/// @ai:intent Claude API client with rate limiting
+/// @ai:intent HTTP client for the Messages API
+///            Streams may run longer than any fixed limit, so they only time out when idle
+/// @ai:effects pure
+fn http_client(config: &ApiConfig) -> reqwest::Result<reqwest::Client> {
+    let timeout = std::time::Duration::from_secs(120);
+    let builder = reqwest::Client::builder();
+    let builder = if config.stream {
+        builder.read_timeout(timeout)
+    } else {
+        builder.timeout(timeout)
+    };
+    builder.build()
+}
+
+/// @ai:intent Claude API client (rate limited by the executor's shared limiter)
 pub struct ClaudeClient {
     client: reqwest::Client,
     config: ApiConfig,
-    rate_limiter: Arc<RateLimiter>,
     api_key: String,
 }
 
@@ -93,57 +193,71 @@ impl ClaudeClient {
         let api_key =
             std::env::var("ANTHROPIC_API_KEY").context("ANTHROPIC_API_KEY not set in environment")?;
 
-        let rate_limiter = Arc::new(RateLimiter::new(config.requests_per_minute));
-
-        let client = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(120))
-            .build()?;
+        let client = http_client(&config)?;
 
         Ok(Self {
             client,
             config,
-            rate_limiter,
             api_key,
         })
     }
 
-    /// @ai:intent Create a client with a custom rate limiter (for testing)
-    /// @ai:effects pure
-    pub fn with_rate_limiter(config: ApiConfig, api_key: String, rate_limiter: Arc<RateLimiter>) -> Self {
-        let client = reqwest::Client::builder()
-            .timeout(std::time::Duration::from_secs(120))
-            .build()
-            .expect("Failed to create HTTP client");
+    /// @ai:intent Read an SSE response to the end, logging progress as text arrives
+    /// @ai:effects network, time
+    async fn read_stream(
+        mut response: reqwest::Response,
+        context: &TaskContext,
+    ) -> Result<ClaudeResponse> {
+        let mut parser = SseParser::new();
+        let mut accumulator = StreamAccumulator::new();
+        let started = std::time::Instant::now();
+        let mut last_progress = started;
 
-        Self {
-            client,
-            config,
-            rate_limiter,
-            api_key,
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .context("Claude API stream interrupted")?
+        {
+            for event in parser.feed(&chunk) {
+                accumulator.apply(&event)?;
+            }
+
+            if last_progress.elapsed() >= PROGRESS_INTERVAL {
+                last_progress = std::time::Instant::now();
+                // ~4 bytes per token; exact usage arrives with the final event
+                tracing::info!(
+                    "{} (mode={}): ~{} tokens streamed in {}s",
+                    context.task_id,
+                    context.mode,
+                    accumulator.content().len() / 4,
+                    started.elapsed().as_secs()
+                );
+            }
         }
+
+        accumulator.finish()
     }
 }
 
 impl ClaudeClientTrait for ClaudeClient {
-    /// @ai:intent Send a message to Claude and get a response
+    /// @ai:intent Send a message to Claude and get a response (streamed if configured)
     /// @ai:effects network
     async fn send_message(
         &self,
         prompt: &str,
         system: Option<&str>,
-        _context: &TaskContext,
+        context: &TaskContext,
     ) -> Result<ClaudeResponse> {
-        self.rate_limiter.wait().await;
-
         let request = ApiRequest {
             model: &self.config.model,
             max_tokens: self.config.max_tokens,
             temperature: self.config.temperature,
-            system,
+            system: system.map(|s| SystemPrompt::new(s, self.config.prompt_caching)),
             messages: vec![Message {
                 role: "user",
                 content: prompt,
             }],
+            stream: self.config.stream,
         };
 
         let response = self
@@ -164,24 +278,16 @@ impl ClaudeClientTrait for ClaudeClient {
             anyhow::bail!("Claude API error ({}): {}", status, error_text);
         }
 
+        if self.config.stream {
+            return Self::read_stream(response, context).await;
+        }
+
         let api_response: ApiResponse = response
             .json()
             .await
             .context("Failed to parse Claude API response")?;
 
-        let content = api_response
-            .content
-            .into_iter()
-            .map(|c| c.text)
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        Ok(ClaudeResponse {
-            content,
-            input_tokens: api_response.usage.input_tokens,
-            output_tokens: api_response.usage.output_tokens,
-            stop_reason: api_response.stop_reason,
-        })
+        Ok(api_response.into())
     }
 }
 
@@ -212,6 +318,10 @@ impl ClaudeClientTrait for MockClaudeClient {
             input_tokens: 100,
             output_tokens: 200,
             stop_reason: "end_turn".to_string(),
+            cache_creation_input_tokens: 0,
+            cache_read_input_tokens: 0,
+            num_turns: None,
+            cost_usd: None,
         })
     }
 }
@@ -227,8 +337,25 @@ mod tests {
             task_id: "test-task".to_string(),
             mode: "baseline".to_string(),
             use_aicms_skill: false,
+            repetition: 0,
         };
         let response = client.send_message("test", None, &context).await.unwrap();
         assert!(response.content.contains("factorial"));
     }
+
+    #[test]
+    fn test_system_prompt_cache_control() {
+        let cached = serde_json::to_value(SystemPrompt::new("You are a coder.", true)).unwrap();
+        assert_eq!(
+            cached,
+            serde_json::json!([{
+                "type": "text",
+                "text": "You are a coder.",
+                "cache_control": {"type": "ephemeral"}
+            }])
+        );
+
+        let plain = serde_json::to_value(SystemPrompt::new("You are a coder.", false)).unwrap();
+        assert_eq!(plain, serde_json::json!("You are a coder."));
+    }
 }
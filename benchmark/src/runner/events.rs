@@ -0,0 +1,213 @@
+//! @ai:module:intent Machine-readable event stream of a benchmark run (events.jsonl)
+//!                   for dashboards that tail progress and for post-mortems
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api EventLog, Event, EventRecord, EVENTS_FILE
+//! @ai:module:stateless false
+
+use crate::runner::executor::{ExecutionResult, PromptMode};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// @ai:intent File name of the event stream inside the output directory
+pub const EVENTS_FILE: &str = "events.jsonl";
+
+/// @ai:intent Something that happened during a run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    RunStarted {
+        model: String,
+        tasks: usize,
+        repetitions: u32,
+    },
+    ExecutionStarted {
+        task_id: String,
+        mode: PromptMode,
+        repetition: u32,
+    },
+    ExecutionFinished {
+        task_id: String,
+        mode: PromptMode,
+        repetition: u32,
+        input_tokens: u32,
+        output_tokens: u32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cost_usd: Option<f64>,
+        execution_time_ms: u64,
+        retries: u32,
+        /// Loaded from the checkpoint of an earlier, resumed run
+        resumed: bool,
+    },
+    /// Not started because the run was interrupted or the budget was exhausted
+    ExecutionSkipped {
+        task_id: String,
+        mode: PromptMode,
+        repetition: u32,
+        reason: String,
+    },
+    ExecutionFailed {
+        task_id: String,
+        mode: PromptMode,
+        repetition: u32,
+        error: String,
+    },
+    Evaluated {
+        task_id: String,
+        mode: PromptMode,
+        repetition: u32,
+        /// None when no code could be extracted
+        compiled: Option<bool>,
+        compile_errors: usize,
+        tests_passed: Option<u32>,
+        tests_total: Option<u32>,
+    },
+    EvaluationFailed {
+        task_id: String,
+        mode: PromptMode,
+        repetition: u32,
+        error: String,
+    },
+    RunFinished {
+        model: String,
+        executions: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        truncated: Option<String>,
+    },
+}
+
+impl Event {
+    /// @ai:intent Finished event for an execution result
+    /// @ai:effects pure
+    pub fn finished(result: &ExecutionResult, resumed: bool) -> Self {
+        Event::ExecutionFinished {
+            task_id: result.task_id.clone(),
+            mode: result.mode,
+            repetition: result.repetition,
+            input_tokens: result.input_tokens,
+            output_tokens: result.output_tokens,
+            cost_usd: result.cost_usd,
+            execution_time_ms: result.execution_time_ms,
+            retries: result.retries,
+            resumed,
+        }
+    }
+}
+
+/// @ai:intent One line of events.jsonl
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventRecord {
+    pub timestamp: DateTime<Utc>,
+    /// Model / sampling run of a matrix run (e.g. "claude-sonnet-4/t0.7-seed1")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<String>,
+    #[serde(flatten)]
+    pub event: Event,
+}
+
+/// @ai:intent Append-only event stream, flushed after every event so it can be tailed
+///            Write errors are logged and never fail the run
+#[derive(Clone)]
+pub struct EventLog {
+    file: Arc<Mutex<File>>,
+    run: Option<String>,
+}
+
+impl EventLog {
+    /// @ai:intent Open (or append to, when resuming) events.jsonl in the output directory
+    /// @ai:effects fs:write
+    pub fn open(output_dir: &Path) -> Result<Self> {
+        let path = output_dir.join(EVENTS_FILE);
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+
+        Ok(Self {
+            file: Arc::new(Mutex::new(file)),
+            run: None,
+        })
+    }
+
+    /// @ai:intent Same stream, with every event tagged with a matrix run label
+    /// @ai:effects pure
+    pub fn for_run(&self, run: Option<String>) -> Self {
+        Self {
+            file: Arc::clone(&self.file),
+            run,
+        }
+    }
+
+    /// @ai:intent Append an event with the current time
+    /// @ai:effects fs:write, time
+    pub fn emit(&self, event: Event) {
+        let record = EventRecord {
+            timestamp: Utc::now(),
+            run: self.run.clone(),
+            event,
+        };
+
+        let written = serde_json::to_string(&record)
+            .map_err(std::io::Error::from)
+            .and_then(|line| {
+                let mut file = self.file.lock().unwrap();
+                writeln!(file, "{}", line)?;
+                file.flush()
+            });
+        if let Err(e) = written {
+            tracing::warn!("Failed to write to {}: {}", EVENTS_FILE, e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_events_are_appended_as_jsonl() {
+        let temp = TempDir::new().unwrap();
+        let events = EventLog::open(temp.path()).unwrap();
+
+        events.emit(Event::RunStarted {
+            model: "claude-sonnet-4".to_string(),
+            tasks: 2,
+            repetitions: 1,
+        });
+        events
+            .for_run(Some("t0.7-seed1".to_string()))
+            .emit(Event::ExecutionFailed {
+                task_id: "impl-rust-factorial".to_string(),
+                mode: PromptMode::Aicms,
+                repetition: 0,
+                error: "claude exited".to_string(),
+            });
+
+        let content = std::fs::read_to_string(temp.path().join(EVENTS_FILE)).unwrap();
+        let records: Vec<EventRecord> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(records.len(), 2);
+        assert!(content
+            .lines()
+            .next()
+            .unwrap()
+            .contains(r#""event":"run_started""#));
+        assert_eq!(records[1].run.as_deref(), Some("t0.7-seed1"));
+        assert!(matches!(
+            &records[1].event,
+            Event::ExecutionFailed {
+                mode: PromptMode::Aicms,
+                ..
+            }
+        ));
+    }
+}
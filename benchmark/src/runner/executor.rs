@@ -3,12 +3,24 @@
 //! @ai:module:public_api BenchmarkExecutor, ExecutionResult, PromptMode
 //! @ai:module:stateless false
 
-use crate::config::{BenchmarkConfig, RunConfig};
+use crate::config::{
+    BenchmarkConfig, ModeSelection, ModelPricing, PathConfig, PromptConfig, RunConfig,
+    SystemPromptSource,
+};
 use crate::corpus::Task;
-use crate::runner::client::{ClaudeClientTrait, TaskContext};
+use crate::runner::budget::Budget;
+use crate::runner::interrupt::Interrupt;
+use crate::runner::checkpoint::CheckpointStore;
+use crate::runner::client::{ClaudeClientTrait, ClaudeResponse, TaskContext};
+use crate::runner::events::{Event, EventLog};
+use crate::runner::rate_limiter::{RateLimiter, RateLimiterTrait};
+use crate::runner::repair::{build_repair_prompt, RepairCheckerTrait, RepairTurn};
+use crate::runner::retry::retry_with_backoff;
 use anyhow::{Context, Result};
-use std::path::Path;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use tokio::sync::{mpsc, Semaphore};
+use tokio::task::JoinSet;
 
 /// @ai:intent Strip AICMS annotations from code for baseline mode
 /// @ai:effects pure
@@ -32,7 +44,8 @@ fn strip_aicms_annotations(code: &str) -> String {
 }
 
 /// @ai:intent Mode for benchmark prompt
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum PromptMode {
     Baseline,
     Aicms,
@@ -47,10 +60,20 @@ impl PromptMode {
             PromptMode::Aicms => "aicms",
         }
     }
+
+    /// @ai:intent Modes executed for a run.modes selection, in execution order
+    /// @ai:effects pure
+    pub fn selected(selection: ModeSelection) -> &'static [PromptMode] {
+        match selection {
+            ModeSelection::Both => &[PromptMode::Baseline, PromptMode::Aicms],
+            ModeSelection::Baseline => &[PromptMode::Baseline],
+            ModeSelection::Aicms => &[PromptMode::Aicms],
+        }
+    }
 }
 
 /// @ai:intent Result of executing a single task
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionResult {
     pub task_id: String,
     pub mode: PromptMode,
@@ -59,29 +82,160 @@ pub struct ExecutionResult {
     pub input_tokens: u32,
     pub output_tokens: u32,
     pub execution_time_ms: u64,
+    /// Number of failed attempts that were retried before this result
+    #[serde(default)]
+    pub retries: u32,
+    /// Prompt tokens written to / read from the prompt cache (included in input_tokens)
+    #[serde(default)]
+    pub cache_creation_input_tokens: u32,
+    #[serde(default)]
+    pub cache_read_input_tokens: u32,
+    /// Agentic turns taken, if the backend reports them
+    #[serde(default)]
+    pub num_turns: Option<u32>,
+    /// Cost reported by the backend, in USD
+    #[serde(default)]
+    pub cost_usd: Option<f64>,
+    /// Why the model stopped, as reported by the client (e.g. "end_turn", "timeout", "error")
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub stop_reason: String,
+    /// Per-turn metrics of a multi-turn repair execution (empty when single-shot)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub repair_turns: Vec<RepairTurn>,
+}
+
+impl ExecutionResult {
+    /// @ai:intent Fold a follow-up turn into this result: its response replaces ours,
+    ///            usage, time, and retries are summed
+    /// @ai:effects pure
+    fn add_turn(&mut self, next: ExecutionResult) {
+        fn sum<T: std::ops::Add<Output = T> + Default>(a: Option<T>, b: Option<T>) -> Option<T> {
+            match (a, b) {
+                (None, None) => None,
+                (a, b) => Some(a.unwrap_or_default() + b.unwrap_or_default()),
+            }
+        }
+
+        self.response = next.response;
+        self.stop_reason = next.stop_reason;
+        self.input_tokens += next.input_tokens;
+        self.output_tokens += next.output_tokens;
+        self.execution_time_ms += next.execution_time_ms;
+        self.retries += next.retries;
+        self.cache_creation_input_tokens += next.cache_creation_input_tokens;
+        self.cache_read_input_tokens += next.cache_read_input_tokens;
+        self.num_turns = sum(self.num_turns, next.num_turns);
+        self.cost_usd = sum(self.cost_usd, next.cost_usd);
+    }
 }
 
 /// @ai:intent Prompt templates loaded from files
 pub struct PromptTemplates {
-    pub baseline: String,
+    /// System prompt for baseline mode (None sends no system prompt)
+    pub baseline: Option<String>,
+    /// System prompt for AICMS mode (None sends no system prompt)
+    pub aicms: Option<String>,
     pub aicms_skill: String,
 }
 
 impl PromptTemplates {
-    /// @ai:intent Load prompt templates from directory
+    /// @ai:intent Load the per-mode system prompts and the skill file
     /// @ai:effects fs:read
-    pub fn load(prompts_dir: &Path, skill_file: &Path) -> Result<Self> {
-        let baseline = std::fs::read_to_string(prompts_dir.join("baseline.md"))
-            .context("Failed to read baseline.md")?;
+    pub fn load(paths: &PathConfig, prompts: &PromptConfig) -> Result<Self> {
+        let read = |source: &SystemPromptSource| -> Result<Option<String>> {
+            source
+                .path(paths)
+                .map(|path| {
+                    std::fs::read_to_string(&path).with_context(|| {
+                        format!("Failed to read system prompt {}", path.display())
+                    })
+                })
+                .transpose()
+        };
 
         let aicms_skill =
-            std::fs::read_to_string(skill_file).context("Failed to read SKILL.md")?;
+            std::fs::read_to_string(&paths.skill_file).context("Failed to read SKILL.md")?;
 
         Ok(Self {
-            baseline,
+            baseline: read(&prompts.baseline_system)?,
+            aicms: read(&prompts.aicms_system)?,
             aicms_skill,
         })
     }
+
+    /// @ai:intent System prompt for a mode
+    /// @ai:effects pure
+    pub fn system_prompt(&self, mode: PromptMode) -> Option<&str> {
+        match mode {
+            PromptMode::Baseline => self.baseline.as_deref(),
+            PromptMode::Aicms => self.aicms.as_deref(),
+        }
+    }
+
+    /// @ai:intent Fill a task's own prompt template
+    ///            Variables: {{TASK_NAME}}, {{LANGUAGE}}, {{DESCRIPTION}}, {{STARTER_CODE}},
+    ///            {{DEPENDENCIES}}
+    /// @ai:effects pure
+    /// @ai:example ("Implement {{TASK_NAME}}", task named "Factorial") -> "Implement Factorial"
+    pub fn render_task(template: &str, task: &Task) -> String {
+        template
+            .replace("{{TASK_NAME}}", &task.name)
+            .replace("{{LANGUAGE}}", task.language.as_str())
+            .replace("{{DESCRIPTION}}", task.description.trim_end())
+            .replace("{{STARTER_CODE}}", format_starter(task).trim_end())
+            .replace("{{DEPENDENCIES}}", &format_dependencies(task))
+    }
+}
+
+/// @ai:intent The task's allowed third-party packages as a comma-separated list
+///            ("none" if the task allows only the standard library)
+/// @ai:effects pure
+/// @ai:example (serde "1" with feature derive, anyhow "1") -> "serde 1 (features: derive), anyhow 1"
+fn format_dependencies(task: &Task) -> String {
+    if task.dependencies.is_empty() {
+        return "none".to_string();
+    }
+    task.dependencies
+        .iter()
+        .map(|dep| match dep.features.as_slice() {
+            [] => format!("{} {}", dep.name, dep.version),
+            features => format!(
+                "{} {} (features: {})",
+                dep.name,
+                dep.version,
+                features.join(", ")
+            ),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// @ai:intent Prompt paragraph listing the allowed third-party packages (empty if none)
+/// @ai:effects pure
+fn dependencies_paragraph(task: &Task) -> String {
+    if task.dependencies.is_empty() {
+        return String::new();
+    }
+    format!(
+        "**Allowed dependencies:** {} (no other third-party packages are available)\n\n",
+        format_dependencies(task)
+    )
+}
+
+/// @ai:intent Starter files as path-labelled code blocks (empty if the task has none)
+/// @ai:effects pure
+fn format_starter(task: &Task) -> String {
+    task.starter
+        .iter()
+        .map(|file| {
+            // Non-source files (Cargo.toml, package.json) keep their own fence label
+            let label = match file.path.rsplit_once('.') {
+                Some((_, ext)) if ext != task.language.extension() => ext,
+                _ => task.language.as_str(),
+            };
+            format!("```{}:{}\n{}\n```\n\n", label, file.path, file.content.trim_end())
+        })
+        .collect()
 }
 
 /// @ai:intent Executes benchmark tasks against Claude
@@ -89,6 +243,16 @@ pub struct BenchmarkExecutor<C: ClaudeClientTrait> {
     client: Arc<C>,
     templates: PromptTemplates,
     run_config: RunConfig,
+    checkpoint: Option<Arc<CheckpointStore>>,
+    repair_checker: Option<Arc<dyn RepairCheckerTrait>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    budget: Option<Arc<Budget>>,
+    interrupt: Option<Arc<Interrupt>>,
+    /// Prices executions whose backend reports no cost (for the budget)
+    pricing: Option<ModelPricing>,
+    events: Option<EventLog>,
+    /// Receives each execution as soon as it completes (or is resumed)
+    completed: Option<mpsc::UnboundedSender<ExecutionResult>>,
 }
 
 impl<C: ClaudeClientTrait> BenchmarkExecutor<C> {
@@ -99,35 +263,179 @@ impl<C: ClaudeClientTrait> BenchmarkExecutor<C> {
             client,
             templates,
             run_config,
+            checkpoint: None,
+            repair_checker: None,
+            rate_limiter: None,
+            budget: None,
+            interrupt: None,
+            pricing: None,
+            events: None,
+            completed: None,
         }
     }
 
+    /// @ai:intent Persist every completed execution and skip those already recorded
+    /// @ai:effects pure
+    pub fn with_checkpoint(mut self, checkpoint: Arc<CheckpointStore>) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    /// @ai:intent Check responses between turns so run.repair_turns can feed failures back
+    /// @ai:effects pure
+    pub fn with_repair_checker(mut self, checker: Arc<dyn RepairCheckerTrait>) -> Self {
+        self.repair_checker = Some(checker);
+        self
+    }
+
+    /// @ai:intent Pace every request (all workers, retries, and repair turns) through one limiter
+    /// @ai:effects pure
+    pub fn with_rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// @ai:intent Charge executions to a run-wide budget; none start once it is exhausted
+    /// @ai:effects pure
+    pub fn with_budget(mut self, budget: Arc<Budget>) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    /// @ai:intent Stop starting executions once a stop is requested (Ctrl-C)
+    /// @ai:effects pure
+    pub fn with_interrupt(mut self, interrupt: Arc<Interrupt>) -> Self {
+        self.interrupt = Some(interrupt);
+        self
+    }
+
+    /// @ai:intent Price used for executions without a backend-reported cost
+    /// @ai:effects pure
+    pub fn with_pricing(mut self, pricing: Option<ModelPricing>) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    /// @ai:intent Report execution start, finish, skips, and failures to an event stream
+    /// @ai:effects pure
+    pub fn with_events(mut self, events: EventLog) -> Self {
+        self.events = Some(events);
+        self
+    }
+
+    /// @ai:intent Hand every completed execution to a consumer while the rest still run
+    ///            (e.g. to evaluate it); the channel closes when the executor is dropped
+    /// @ai:effects pure
+    pub fn with_completed_sender(mut self, sender: mpsc::UnboundedSender<ExecutionResult>) -> Self {
+        self.completed = Some(sender);
+        self
+    }
+
+    /// @ai:intent Send a completed execution to the consumer, if any
+    /// @ai:effects state:write
+    fn complete(&self, result: &ExecutionResult) {
+        if let Some(sender) = &self.completed {
+            // A consumer that stopped listening has failed on its own; the run goes on
+            sender.send(result.clone()).ok();
+        }
+    }
+
+    /// @ai:intent Append to the event stream, if any
+    /// @ai:effects fs:write
+    fn emit(&self, event: Event) {
+        if let Some(events) = &self.events {
+            events.emit(event);
+        }
+    }
+
+    /// @ai:intent Charge an execution to the budget, if any
+    /// @ai:effects state:write
+    fn charge(&self, result: &ExecutionResult) {
+        if let Some(budget) = &self.budget {
+            let cost = result.cost_usd.or_else(|| {
+                self.pricing.map(|p| {
+                    p.cost(
+                        result.input_tokens,
+                        result.output_tokens,
+                        result.cache_creation_input_tokens,
+                        result.cache_read_input_tokens,
+                    )
+                })
+            });
+            budget.record(result.input_tokens as u64 + result.output_tokens as u64, cost);
+        }
+    }
+
+    /// @ai:intent Send one request, waiting for the shared rate limiter first
+    /// @ai:effects network, time
+    async fn send_limited(
+        &self,
+        prompt: &str,
+        system: Option<&str>,
+        context: &TaskContext,
+    ) -> Result<ClaudeResponse> {
+        let Some(limiter) = &self.rate_limiter else {
+            return self.client.send_message(prompt, system, context).await;
+        };
+
+        let estimated_tokens = limiter.estimate_tokens(prompt, system);
+        limiter.acquire(estimated_tokens).await;
+        // Failed requests keep their reservation
+        let response = self.client.send_message(prompt, system, context).await?;
+        limiter
+            .record_usage(estimated_tokens, response.input_tokens + response.output_tokens)
+            .await;
+
+        Ok(response)
+    }
+
     /// @ai:intent Build the prompt for a task (SAME for both modes)
-    ///            Only includes task name and description - tests are hidden
+    ///            Only includes task name, description, and starter code - tests are hidden
     /// @ai:effects pure
     fn build_prompt(&self, task: &Task) -> String {
+        if let Some(template) = &task.prompt_template {
+            return PromptTemplates::render_task(template, task);
+        }
+
+        if task.starter.is_empty() {
+            return format!(
+                "## Task: {}\n\n**Language:** {}\n\n{}{}\n\n\
+                 Please provide a complete implementation with all necessary types, \
+                 traits, and functions. Use proper error handling and include \
+                 appropriate documentation.",
+                task.name,
+                task.language.as_str(),
+                dependencies_paragraph(task),
+                task.description
+            );
+        }
+
         format!(
-            "## Task: {}\n\n**Language:** {}\n\n{}\n\n\
-             Please provide a complete implementation with all necessary types, \
-             traits, and functions. Use proper error handling and include \
-             appropriate documentation.",
+            "## Task: {}\n\n**Language:** {}\n\n{}{}\n\n### Existing code\n\n{}\
+             Modify the existing code to complete the task. Return every file you change \
+             in full, each in a code block labelled with its path (e.g. ```{}:{}).",
             task.name,
             task.language.as_str(),
-            task.description
+            dependencies_paragraph(task),
+            task.description,
+            format_starter(task),
+            task.language.as_str(),
+            task.starter[0].path
         )
     }
 
     /// @ai:intent Create task context for execution
     /// @ai:effects pure
-    fn create_task_context(&self, task: &Task, mode: PromptMode) -> TaskContext {
+    fn create_task_context(&self, task: &Task, mode: PromptMode, repetition: u32) -> TaskContext {
         TaskContext {
             task_id: task.id.clone(),
             mode: mode.as_str().to_string(),
             use_aicms_skill: mode == PromptMode::Aicms,
+            repetition,
         }
     }
 
-    /// @ai:intent Execute a single task once
+    /// @ai:intent Execute a single task once, with repair turns if enabled
     /// @ai:effects network
     async fn execute_once(
         &self,
@@ -136,25 +444,38 @@ impl<C: ClaudeClientTrait> BenchmarkExecutor<C> {
         repetition: u32,
     ) -> Result<ExecutionResult> {
         let prompt = self.build_prompt(task);
-        let context = self.create_task_context(task, mode);
+        let context = self.create_task_context(task, mode, repetition);
 
-        let start = std::time::Instant::now();
+        let first = self.send_turn(task, mode, repetition, &prompt, &context).await?;
 
-        if self.run_config.dry_run {
-            return Ok(ExecutionResult {
-                task_id: task.id.clone(),
-                mode,
-                repetition,
-                response: "[DRY RUN] No actual API call made".to_string(),
-                input_tokens: 0,
-                output_tokens: 0,
-                execution_time_ms: 0,
-            });
+        match &self.repair_checker {
+            Some(checker) if self.run_config.repair_turns > 0 => {
+                self.repair(task, &prompt, &context, checker, first).await
+            }
+            _ => Ok(first),
         }
+    }
+
+    /// @ai:intent Send one prompt (with retries) and wrap the response
+    /// @ai:effects network
+    async fn send_turn(
+        &self,
+        task: &Task,
+        mode: PromptMode,
+        repetition: u32,
+        prompt: &str,
+        context: &TaskContext,
+    ) -> Result<ExecutionResult> {
+        let start = std::time::Instant::now();
 
-        // Use baseline template as system prompt (same for both modes)
-        // The difference is in the CLAUDE.md file for AICMS mode
-        let response = self.client.send_message(&prompt, Some(&self.templates.baseline), &context).await?;
+        // System prompts come from [prompts] (both default to baseline.md);
+        // Claude Code ignores them and injects the skill via CLAUDE.md instead
+        let system = self.templates.system_prompt(mode);
+        let label = format!("{} (mode={}, rep={})", task.id, mode.as_str(), repetition);
+        let (response, retries) = retry_with_backoff(&self.run_config.retry, &label, || {
+            self.send_limited(prompt, system, context)
+        })
+        .await?;
         let elapsed = start.elapsed();
 
         Ok(ExecutionResult {
@@ -165,42 +486,241 @@ impl<C: ClaudeClientTrait> BenchmarkExecutor<C> {
             input_tokens: response.input_tokens,
             output_tokens: response.output_tokens,
             execution_time_ms: elapsed.as_millis() as u64,
+            retries,
+            cache_creation_input_tokens: response.cache_creation_input_tokens,
+            cache_read_input_tokens: response.cache_read_input_tokens,
+            num_turns: response.num_turns,
+            cost_usd: response.cost_usd,
+            stop_reason: response.stop_reason,
+            repair_turns: vec![],
         })
     }
 
+    /// @ai:intent Feed compile/test failures back to the model for up to run.repair_turns turns
+    /// @ai:post result.repair_turns has one entry per turn taken, starting with turn 0
+    /// @ai:effects network, fs:write, io
+    async fn repair(
+        &self,
+        task: &Task,
+        prompt: &str,
+        context: &TaskContext,
+        checker: &Arc<dyn RepairCheckerTrait>,
+        first: ExecutionResult,
+    ) -> Result<ExecutionResult> {
+        let max_turns = self.run_config.repair_turns;
+        let mut result = first.clone();
+        let mut last = first;
+        let mut turns = Vec::new();
+
+        for turn in 0..=max_turns {
+            // Compiling and testing block, so keep them off the async workers
+            let check = {
+                let checker = Arc::clone(checker);
+                let task = task.clone();
+                let execution = last.clone();
+                tokio::task::spawn_blocking(move || checker.check(&task, &execution))
+                    .await
+                    .context("Repair check panicked")?
+            };
+
+            turns.push(RepairTurn {
+                turn,
+                compiled: check.compiled,
+                test_pass_rate: check.test_pass_rate,
+                input_tokens: last.input_tokens,
+                output_tokens: last.output_tokens,
+                execution_time_ms: last.execution_time_ms,
+            });
+
+            let feedback = match check.feedback {
+                Some(feedback) if turn < max_turns => feedback,
+                _ => break,
+            };
+
+            tracing::info!(
+                "Repair turn {}/{} for {} (mode={}, rep={})",
+                turn + 1,
+                max_turns,
+                task.id,
+                last.mode.as_str(),
+                last.repetition
+            );
+            let repair_prompt = build_repair_prompt(prompt, &last.response, &feedback);
+            last = self
+                .send_turn(task, last.mode, last.repetition, &repair_prompt, context)
+                .await?;
+            result.add_turn(last.clone());
+        }
+
+        result.repair_turns = turns;
+        Ok(result)
+    }
+
+    /// @ai:intent Execute a task once unless the checkpoint already holds its result
+    /// @ai:post None when the run was interrupted or the budget is exhausted, and the
+    ///          execution was not started
+    /// @ai:effects network, fs:write
+    async fn execute_or_resume(
+        &self,
+        task: &Task,
+        mode: PromptMode,
+        repetition: u32,
+    ) -> Result<Option<ExecutionResult>> {
+        if let Some(checkpoint) = &self.checkpoint {
+            if let Some(done) = checkpoint.completed(&task.id, mode, repetition) {
+                tracing::info!(
+                    "Skipping {} (mode={}, rep={}): already completed",
+                    task.id,
+                    mode.as_str(),
+                    repetition
+                );
+                // Resumed executions were paid for by this run too
+                self.charge(done);
+                self.emit(Event::finished(done, true));
+                return Ok(Some(done.clone()));
+            }
+        }
+
+        if self.interrupt.as_ref().is_some_and(|i| !i.try_start()) {
+            tracing::info!(
+                "Not starting {} (mode={}, rep={}): interrupted",
+                task.id,
+                mode.as_str(),
+                repetition
+            );
+            self.emit(Event::ExecutionSkipped {
+                task_id: task.id.clone(),
+                mode,
+                repetition,
+                reason: "interrupted".to_string(),
+            });
+            return Ok(None);
+        }
+
+        if let Some(budget) = self.budget.as_ref().filter(|b| !b.try_start()) {
+            tracing::info!(
+                "Not starting {} (mode={}, rep={}): budget exhausted",
+                task.id,
+                mode.as_str(),
+                repetition
+            );
+            self.emit(Event::ExecutionSkipped {
+                task_id: task.id.clone(),
+                mode,
+                repetition,
+                reason: budget.reason().unwrap_or_default(),
+            });
+            return Ok(None);
+        }
+
+        tracing::info!(
+            "Executing {} (mode={}, rep={})",
+            task.id,
+            mode.as_str(),
+            repetition
+        );
+        self.emit(Event::ExecutionStarted {
+            task_id: task.id.clone(),
+            mode,
+            repetition,
+        });
+
+        let result = match self.execute_once(task, mode, repetition).await {
+            Ok(result) => result,
+            Err(e) => {
+                self.emit(Event::ExecutionFailed {
+                    task_id: task.id.clone(),
+                    mode,
+                    repetition,
+                    error: format!("{:#}", e),
+                });
+                return Err(e);
+            }
+        };
+        self.charge(&result);
+        self.emit(Event::finished(&result, false));
+
+        if let Some(checkpoint) = &self.checkpoint {
+            checkpoint.record(&result)?;
+        }
+
+        Ok(Some(result))
+    }
+
     /// @ai:intent Execute a task with all repetitions and modes
     /// @ai:effects network
     pub async fn execute_task(&self, task: &Task) -> Result<Vec<ExecutionResult>> {
         let mut results = Vec::new();
 
         for rep in 0..self.run_config.repetitions {
-            for mode in [PromptMode::Baseline, PromptMode::Aicms] {
-                tracing::info!(
-                    "Executing {} (mode={}, rep={})",
-                    task.id,
-                    mode.as_str(),
-                    rep
-                );
+            for &mode in PromptMode::selected(self.run_config.modes) {
+                if let Some(result) = self.execute_or_resume(task, mode, rep).await? {
+                    self.complete(&result);
+                    results.push(result);
+                }
+            }
+        }
 
-                let result = self.execute_once(task, mode, rep).await?;
+        Ok(results)
+    }
+
+    /// @ai:intent Execute all repetitions of a task for a single mode
+    ///            Repetitions run sequentially since they share the task's output directory
+    /// @ai:effects network
+    async fn execute_mode(&self, task: &Task, mode: PromptMode) -> Result<Vec<ExecutionResult>> {
+        let mut results = Vec::new();
+
+        for rep in 0..self.run_config.repetitions {
+            if let Some(result) = self.execute_or_resume(task, mode, rep).await? {
+                self.complete(&result);
                 results.push(result);
             }
         }
 
         Ok(results)
     }
+}
 
-    /// @ai:intent Execute all tasks
+impl<C: ClaudeClientTrait + 'static> BenchmarkExecutor<C> {
+    /// @ai:intent Execute all tasks with at most `run.concurrency` executions in flight
+    /// @ai:post results are ordered by task, then repetition, then mode
     /// @ai:effects network
-    pub async fn execute_all(&self, tasks: &[Task]) -> Result<Vec<ExecutionResult>> {
-        let mut all_results = Vec::new();
+    pub async fn execute_all(self: Arc<Self>, tasks: &[Task]) -> Result<Vec<ExecutionResult>> {
+        let semaphore = Arc::new(Semaphore::new(self.run_config.concurrency.max(1)));
+        let total_tasks = tasks.len();
+        let mut join_set = JoinSet::new();
 
-        for task in tasks {
-            let results = self.execute_task(task).await?;
-            all_results.extend(results);
+        for (index, task) in tasks.iter().enumerate() {
+            for &mode in PromptMode::selected(self.run_config.modes) {
+                let executor = Arc::clone(&self);
+                let semaphore = Arc::clone(&semaphore);
+                let task = task.clone();
+
+                join_set.spawn(async move {
+                    let _permit = semaphore.acquire_owned().await?;
+                    tracing::info!(
+                        "[{}/{}] Running task: {} (mode={})",
+                        index + 1,
+                        total_tasks,
+                        task.id,
+                        mode.as_str()
+                    );
+                    let results = executor.execute_mode(&task, mode).await?;
+                    Ok::<_, anyhow::Error>((index, results))
+                });
+            }
+        }
+
+        let mut indexed_results = Vec::new();
+
+        while let Some(joined) = join_set.join_next().await {
+            let (index, results) = joined.context("Task execution panicked")??;
+            indexed_results.extend(results.into_iter().map(|r| (index, r)));
         }
 
-        Ok(all_results)
+        indexed_results.sort_by_key(|(index, r)| (*index, r.repetition, r.mode));
+
+        Ok(indexed_results.into_iter().map(|(_, r)| r).collect())
     }
 }
 
@@ -210,32 +730,44 @@ pub fn create_executor<C: ClaudeClientTrait>(
     client: Arc<C>,
     config: &BenchmarkConfig,
 ) -> Result<BenchmarkExecutor<C>> {
-    let templates = PromptTemplates::load(&config.paths.prompts_dir, &config.paths.skill_file)?;
-    Ok(BenchmarkExecutor::new(client, templates, config.run.clone()))
+    let templates = PromptTemplates::load(&config.paths, &config.prompts)?;
+    let executor = BenchmarkExecutor::new(client, templates, config.run.clone())
+        .with_pricing(config.pricing_for(config.model_name()));
+
+    // One limiter for the whole run, so parallel executions share the API limits
+    // (replayed fixtures and dry runs make no requests)
+    if config.backend.kind.is_rate_limited()
+        && config.run.replay_dir.is_none()
+        && !config.run.dry_run
+    {
+        let limiter = Arc::new(RateLimiter::from_config(&config.api));
+        return Ok(executor.with_rate_limiter(limiter));
+    }
+
+    Ok(executor)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::corpus::{Difficulty, Language, TaskCategory};
     use crate::runner::client::MockClaudeClient;
 
     fn create_test_task() -> Task {
         Task {
             id: "test-task".to_string(),
             name: "Test Task".to_string(),
-            category: TaskCategory::Implement,
-            language: Language::Rust,
-            difficulty: Difficulty::Easy,
             description: "Implement a test function".to_string(),
+            ..Default::default()
         }
     }
 
     #[tokio::test]
     async fn test_dry_run_execution() {
+        // Dry runs go through the (offline) client like any other run
         let client = Arc::new(MockClaudeClient::new("response".to_string()));
         let templates = PromptTemplates {
-            baseline: "You are a coding assistant.".to_string(),
+            baseline: Some("You are a coding assistant.".to_string()),
+            aicms: Some("You are a coding assistant.".to_string()),
             aicms_skill: "skill".to_string(),
         };
         let run_config = RunConfig {
@@ -249,7 +781,404 @@ mod tests {
 
         let results = executor.execute_task(&task).await.unwrap();
         assert_eq!(results.len(), 2);
-        assert!(results[0].response.contains("DRY RUN"));
+        assert_eq!(results[0].response, "response");
+    }
+
+    #[test]
+    fn test_system_prompt_per_mode() {
+        let templates = PromptTemplates {
+            baseline: None,
+            aicms: Some("skill as system prompt".to_string()),
+            aicms_skill: "skill".to_string(),
+        };
+
+        assert_eq!(templates.system_prompt(PromptMode::Baseline), None);
+        assert_eq!(
+            templates.system_prompt(PromptMode::Aicms),
+            Some("skill as system prompt")
+        );
+    }
+
+    #[test]
+    fn test_build_prompt_includes_starter_code() {
+        let client = Arc::new(MockClaudeClient::new("response".to_string()));
+        let templates = PromptTemplates {
+            baseline: Some("You are a coding assistant.".to_string()),
+            aicms: Some("You are a coding assistant.".to_string()),
+            aicms_skill: "skill".to_string(),
+        };
+        let executor = BenchmarkExecutor::new(client, templates, RunConfig::default());
+        let mut task = create_test_task();
+        task.starter = vec![
+            crate::corpus::StarterFile {
+                path: "Cargo.toml".to_string(),
+                content: "[package]\nname = \"bank\"".to_string(),
+            },
+            crate::corpus::StarterFile {
+                path: "src/lib.rs".to_string(),
+                content: "/// @ai:invariant balance >= 0\npub struct Account;".to_string(),
+            },
+        ];
+
+        let prompt = executor.build_prompt(&task);
+
+        assert!(prompt.contains("```toml:Cargo.toml\n[package]"));
+        assert!(prompt.contains("```rust:src/lib.rs\n/// @ai:invariant balance >= 0"));
+        assert!(prompt.contains("Modify the existing code"));
+        assert!(executor.build_prompt(&create_test_task()).contains("complete implementation"));
+    }
+
+    #[test]
+    fn test_build_prompt_uses_task_template() {
+        let client = Arc::new(MockClaudeClient::new("response".to_string()));
+        let templates = PromptTemplates {
+            baseline: None,
+            aicms: None,
+            aicms_skill: "skill".to_string(),
+        };
+        let executor = BenchmarkExecutor::new(client, templates, RunConfig::default());
+        let mut task = create_test_task();
+        task.starter = vec![crate::corpus::StarterFile {
+            path: "src/lib.rs".to_string(),
+            content: "pub struct Report;".to_string(),
+        }];
+        task.prompt_template =
+            Some("# {{TASK_NAME}} ({{LANGUAGE}})\n{{DESCRIPTION}}\n\n{{STARTER_CODE}}".to_string());
+
+        let prompt = executor.build_prompt(&task);
+
+        assert!(prompt.starts_with(&format!("# {} (rust)\n{}", task.name, task.description)));
+        assert!(prompt.ends_with("```rust:src/lib.rs\npub struct Report;\n```"));
+    }
+
+    #[test]
+    fn test_build_prompt_lists_allowed_dependencies() {
+        let client = Arc::new(MockClaudeClient::new("response".to_string()));
+        let templates = PromptTemplates {
+            baseline: None,
+            aicms: None,
+            aicms_skill: "skill".to_string(),
+        };
+        let executor = BenchmarkExecutor::new(client, templates, RunConfig::default());
+        let mut task = create_test_task();
+        assert!(!executor
+            .build_prompt(&task)
+            .contains("Allowed dependencies"));
+
+        task.dependencies = vec![
+            crate::corpus::Dependency {
+                name: "serde".to_string(),
+                version: "1".to_string(),
+                features: vec!["derive".to_string()],
+            },
+            crate::corpus::Dependency {
+                name: "serde_json".to_string(),
+                version: "1".to_string(),
+                features: vec![],
+            },
+        ];
+
+        assert!(executor.build_prompt(&task).contains(
+            "**Allowed dependencies:** serde 1 (features: derive), serde_json 1 (no other"
+        ));
+        assert_eq!(
+            PromptTemplates::render_task("Use: {{DEPENDENCIES}}", &task),
+            "Use: serde 1 (features: derive), serde_json 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_execute_all_concurrent_is_ordered() {
+        let client = Arc::new(MockClaudeClient::new("response".to_string()));
+        let templates = PromptTemplates {
+            baseline: Some("You are a coding assistant.".to_string()),
+            aicms: Some("You are a coding assistant.".to_string()),
+            aicms_skill: "skill".to_string(),
+        };
+        let run_config = RunConfig {
+            repetitions: 2,
+            concurrency: 4,
+            ..Default::default()
+        };
+
+        let executor = Arc::new(BenchmarkExecutor::new(client, templates, run_config));
+        let tasks: Vec<Task> = ["task-b", "task-a", "task-c"]
+            .iter()
+            .map(|id| Task {
+                id: id.to_string(),
+                ..create_test_task()
+            })
+            .collect();
+
+        let results = executor.execute_all(&tasks).await.unwrap();
+        assert_eq!(results.len(), 12);
+
+        let order: Vec<_> = results
+            .iter()
+            .map(|r| (r.task_id.as_str(), r.repetition, r.mode))
+            .collect();
+        assert_eq!(order[0], ("task-b", 0, PromptMode::Baseline));
+        assert_eq!(order[1], ("task-b", 0, PromptMode::Aicms));
+        assert_eq!(order[2], ("task-b", 1, PromptMode::Baseline));
+        assert_eq!(order[4], ("task-a", 0, PromptMode::Baseline));
+        assert_eq!(order[11], ("task-c", 1, PromptMode::Aicms));
+    }
+
+    #[tokio::test]
+    async fn test_execute_all_streams_completed_executions() {
+        let client = Arc::new(MockClaudeClient::new("response".to_string()));
+        let templates = PromptTemplates {
+            baseline: None,
+            aicms: None,
+            aicms_skill: "skill".to_string(),
+        };
+        let run_config = RunConfig {
+            concurrency: 2,
+            ..Default::default()
+        };
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+
+        let executor = Arc::new(
+            BenchmarkExecutor::new(client, templates, run_config).with_completed_sender(sender),
+        );
+        let results = executor.execute_all(&[create_test_task()]).await.unwrap();
+
+        let mut streamed = Vec::new();
+        while let Some(result) = receiver.recv().await {
+            streamed.push(result.mode);
+        }
+        streamed.sort();
+        assert_eq!(streamed, results.iter().map(|r| r.mode).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn test_execute_all_runs_only_selected_modes() {
+        let client = Arc::new(MockClaudeClient::new("response".to_string()));
+        let templates = PromptTemplates {
+            baseline: None,
+            aicms: None,
+            aicms_skill: "skill".to_string(),
+        };
+        let run_config = RunConfig {
+            repetitions: 2,
+            modes: ModeSelection::Aicms,
+            ..Default::default()
+        };
+
+        let executor = Arc::new(BenchmarkExecutor::new(client, templates, run_config));
+        let results = executor.execute_all(&[create_test_task()]).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.mode == PromptMode::Aicms));
+    }
+
+    #[tokio::test]
+    async fn test_budget_stops_launching_executions() {
+        let client = Arc::new(MockClaudeClient::new("response".to_string()));
+        let templates = PromptTemplates {
+            baseline: None,
+            aicms: None,
+            aicms_skill: "skill".to_string(),
+        };
+        let run_config = RunConfig {
+            repetitions: 3,
+            ..Default::default()
+        };
+        // The mock uses 300 tokens per execution
+        let budget = Arc::new(Budget::new(None, Some(600)));
+        let temp = tempfile::TempDir::new().unwrap();
+
+        let executor = Arc::new(
+            BenchmarkExecutor::new(client, templates, run_config)
+                .with_budget(budget.clone())
+                .with_events(EventLog::open(temp.path()).unwrap()),
+        );
+        let results = executor.execute_all(&[create_test_task()]).await.unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(budget.skipped(), 4);
+        assert!(budget.reason().unwrap().contains("max_total_tokens 600"));
+
+        // Every execution shows up in the event stream, skipped ones included
+        let events =
+            std::fs::read_to_string(temp.path().join(crate::runner::EVENTS_FILE)).unwrap();
+        let count = |name: &str| events.matches(&format!(r#""event":"{}""#, name)).count();
+        assert_eq!(count("execution_started"), 2);
+        assert_eq!(count("execution_finished"), 2);
+        assert_eq!(count("execution_skipped"), 4);
+    }
+
+    #[tokio::test]
+    async fn test_interrupt_stops_launching_executions() {
+        let client = Arc::new(MockClaudeClient::new("response".to_string()));
+        let templates = PromptTemplates {
+            baseline: None,
+            aicms: None,
+            aicms_skill: "skill".to_string(),
+        };
+        let interrupt = Arc::new(Interrupt::new());
+        interrupt.request();
+
+        let executor = Arc::new(
+            BenchmarkExecutor::new(client, templates, RunConfig::default())
+                .with_interrupt(interrupt.clone()),
+        );
+        let results = executor.execute_all(&[create_test_task()]).await.unwrap();
+
+        assert!(results.is_empty());
+        assert_eq!(interrupt.skipped(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_skips_completed_executions() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let checkpoint = Arc::new(CheckpointStore::open(temp.path()).unwrap());
+        checkpoint
+            .record(&ExecutionResult {
+                task_id: "test-task".to_string(),
+                mode: PromptMode::Baseline,
+                repetition: 0,
+                response: "resumed".to_string(),
+                input_tokens: 1,
+                output_tokens: 2,
+                execution_time_ms: 3,
+                retries: 0,
+                cache_creation_input_tokens: 0,
+                cache_read_input_tokens: 0,
+                num_turns: None,
+                cost_usd: None,
+                stop_reason: "end_turn".to_string(),
+                repair_turns: vec![],
+            })
+            .unwrap();
+        let checkpoint = Arc::new(CheckpointStore::open(temp.path()).unwrap());
+
+        let client = Arc::new(MockClaudeClient::new("fresh".to_string()));
+        let templates = PromptTemplates {
+            baseline: Some("You are a coding assistant.".to_string()),
+            aicms: Some("You are a coding assistant.".to_string()),
+            aicms_skill: "skill".to_string(),
+        };
+        let executor = BenchmarkExecutor::new(client, templates, RunConfig::default())
+            .with_checkpoint(Arc::clone(&checkpoint));
+
+        let results = executor.execute_task(&create_test_task()).await.unwrap();
+        assert_eq!(results[0].response, "resumed");
+        assert_eq!(results[1].response, "fresh");
+
+        let persisted = CheckpointStore::load(checkpoint.path()).unwrap();
+        assert_eq!(persisted.len(), 2);
+    }
+
+    struct FlakyClient {
+        failures_left: std::sync::atomic::AtomicU32,
+    }
+
+    impl ClaudeClientTrait for FlakyClient {
+        async fn send_message(
+            &self,
+            _prompt: &str,
+            _system: Option<&str>,
+            _context: &TaskContext,
+        ) -> Result<crate::runner::ClaudeResponse> {
+            use std::sync::atomic::Ordering;
+
+            if self
+                .failures_left
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok()
+            {
+                anyhow::bail!("Claude API error (529): overloaded");
+            }
+
+            Ok(crate::runner::ClaudeResponse {
+                content: "ok".to_string(),
+                input_tokens: 1,
+                output_tokens: 1,
+                stop_reason: "end_turn".to_string(),
+                cache_creation_input_tokens: 0,
+                cache_read_input_tokens: 0,
+                num_turns: None,
+                cost_usd: None,
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_retries_transient_failures() {
+        let client = Arc::new(FlakyClient {
+            failures_left: std::sync::atomic::AtomicU32::new(2),
+        });
+        let templates = PromptTemplates {
+            baseline: Some("You are a coding assistant.".to_string()),
+            aicms: Some("You are a coding assistant.".to_string()),
+            aicms_skill: "skill".to_string(),
+        };
+        let run_config = RunConfig {
+            retry: crate::config::RetryConfig {
+                max_attempts: 3,
+                initial_backoff_ms: 1,
+                max_backoff_ms: 1,
+                jitter: 0.0,
+            },
+            ..Default::default()
+        };
+
+        let executor = BenchmarkExecutor::new(client, templates, run_config);
+        let results = executor.execute_task(&create_test_task()).await.unwrap();
+
+        assert_eq!(results[0].retries, 2);
+        assert_eq!(results[1].retries, 0);
+    }
+
+    /// Fails the first `failing_turns` checks, then passes
+    struct ScriptedChecker {
+        failing_turns: std::sync::atomic::AtomicU32,
+    }
+
+    impl RepairCheckerTrait for ScriptedChecker {
+        fn check(&self, _task: &Task, _execution: &ExecutionResult) -> crate::runner::RepairCheck {
+            use std::sync::atomic::Ordering;
+
+            let failing = self
+                .failing_turns
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            crate::runner::RepairCheck {
+                compiled: !failing,
+                test_pass_rate: if failing { 0.0 } else { 100.0 },
+                feedback: failing.then(|| "error[E0425]".to_string()),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_repair_turns_feed_back_failures() {
+        let client = Arc::new(MockClaudeClient::new("```rust\nfn f() {}\n```".to_string()));
+        let templates = PromptTemplates {
+            baseline: None,
+            aicms: None,
+            aicms_skill: "skill".to_string(),
+        };
+        let run_config = RunConfig {
+            repair_turns: 3,
+            ..Default::default()
+        };
+        let executor = BenchmarkExecutor::new(client, templates, run_config).with_repair_checker(
+            Arc::new(ScriptedChecker {
+                failing_turns: std::sync::atomic::AtomicU32::new(1),
+            }),
+        );
+
+        let result = executor
+            .execute_once(&create_test_task(), PromptMode::Aicms, 0)
+            .await
+            .unwrap();
+
+        assert_eq!(result.repair_turns.len(), 2);
+        assert!(!result.repair_turns[0].compiled);
+        assert!(result.repair_turns[1].compiled);
+        assert_eq!(result.output_tokens, 2 * result.repair_turns[0].output_tokens);
     }
 
     #[test]
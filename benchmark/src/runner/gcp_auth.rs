@@ -0,0 +1,318 @@
+//! @ai:module:intent Google Application Default Credentials (ADC) access tokens (used by the Vertex client)
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api GcpCredentials, GcpTokenProvider
+//! @ai:module:stateless false
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+use std::path::PathBuf;
+use tokio::sync::Mutex;
+
+/// @ai:intent OAuth token endpoint for refreshing user credentials
+const TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+
+/// @ai:intent GCE/GKE/Cloud Run metadata server token endpoint
+const METADATA_TOKEN_URL: &str =
+    "http://metadata.google.internal/computeMetadata/v1/instance/service-accounts/default/token";
+
+/// @ai:intent Tokens are refreshed this long before they expire
+const EXPIRY_MARGIN_SECS: i64 = 60;
+
+/// @ai:intent Lifetime assumed for tokens printed by gcloud (which omits expiry)
+const GCLOUD_TOKEN_LIFETIME_SECS: i64 = 30 * 60;
+
+/// @ai:intent How long gcloud may take to print a token before it is killed
+const GCLOUD_TIMEOUT_SECS: u64 = 30;
+
+/// @ai:intent Where access tokens come from, resolved in ADC order
+#[derive(Debug, Clone, PartialEq)]
+pub enum GcpCredentials {
+    /// Fixed token (GOOGLE_OAUTH_ACCESS_TOKEN), never refreshed
+    AccessToken(String),
+    /// `gcloud auth application-default login` credentials, refreshed via OAuth
+    AuthorizedUser {
+        client_id: String,
+        client_secret: String,
+        refresh_token: String,
+    },
+    /// Service account keys and other credential types, delegated to gcloud
+    Gcloud { credentials_file: PathBuf },
+    /// Attached service account on Google Cloud compute
+    MetadataServer,
+}
+
+/// @ai:intent ADC JSON file (only the fields needed to pick a token source)
+#[derive(Debug, Deserialize)]
+struct CredentialsFile {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    client_id: Option<String>,
+    #[serde(default)]
+    client_secret: Option<String>,
+    #[serde(default)]
+    refresh_token: Option<String>,
+}
+
+/// @ai:intent OAuth / metadata server token response
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<i64>,
+}
+
+impl GcpCredentials {
+    /// @ai:intent Resolve credentials the way Google client libraries do:
+    ///            GOOGLE_OAUTH_ACCESS_TOKEN, GOOGLE_APPLICATION_CREDENTIALS,
+    ///            the gcloud well-known file, then the metadata server
+    /// @ai:effects env, fs:read
+    pub fn from_env() -> Result<Self> {
+        if let Ok(token) = std::env::var("GOOGLE_OAUTH_ACCESS_TOKEN") {
+            return Ok(GcpCredentials::AccessToken(token));
+        }
+
+        let path = match std::env::var("GOOGLE_APPLICATION_CREDENTIALS") {
+            Ok(path) => Some(PathBuf::from(path)),
+            Err(_) => well_known_file().filter(|p| p.exists()),
+        };
+
+        match path {
+            Some(path) => {
+                let content = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                Self::from_file_contents(&content, path)
+            }
+            None => Ok(GcpCredentials::MetadataServer),
+        }
+    }
+
+    /// @ai:intent Pick a token source for the contents of an ADC JSON file
+    /// @ai:effects pure
+    fn from_file_contents(content: &str, path: PathBuf) -> Result<Self> {
+        let file: CredentialsFile = serde_json::from_str(content)
+            .with_context(|| format!("Invalid credentials file {}", path.display()))?;
+
+        match (
+            file.kind.as_str(),
+            file.client_id,
+            file.client_secret,
+            file.refresh_token,
+        ) {
+            ("authorized_user", Some(client_id), Some(client_secret), Some(refresh_token)) => {
+                Ok(GcpCredentials::AuthorizedUser {
+                    client_id,
+                    client_secret,
+                    refresh_token,
+                })
+            }
+            _ => Ok(GcpCredentials::Gcloud {
+                credentials_file: path,
+            }),
+        }
+    }
+}
+
+/// @ai:intent Location gcloud writes application default credentials to
+/// @ai:effects env
+fn well_known_file() -> Option<PathBuf> {
+    let config_dir = if cfg!(windows) {
+        PathBuf::from(std::env::var("APPDATA").ok()?).join("gcloud")
+    } else {
+        PathBuf::from(std::env::var("HOME").ok()?)
+            .join(".config")
+            .join("gcloud")
+    };
+    Some(config_dir.join("application_default_credentials.json"))
+}
+
+/// @ai:intent Cached access token and when it stops being valid
+#[derive(Debug, Clone)]
+struct CachedToken {
+    token: String,
+    expires_at: DateTime<Utc>,
+}
+
+/// @ai:intent Hands out access tokens, refreshing them shortly before expiry
+pub struct GcpTokenProvider {
+    credentials: GcpCredentials,
+    client: reqwest::Client,
+    cached: Mutex<Option<CachedToken>>,
+}
+
+impl GcpTokenProvider {
+    /// @ai:intent Create a token provider for the given credentials
+    /// @ai:effects pure
+    pub fn new(credentials: GcpCredentials) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .context("Failed to create HTTP client for GCP token requests")?;
+
+        Ok(Self {
+            credentials,
+            client,
+            cached: Mutex::new(None),
+        })
+    }
+
+    /// @ai:intent Get a valid access token, fetching a new one if needed
+    /// @ai:effects network, io, time
+    pub async fn access_token(&self) -> Result<String> {
+        if let GcpCredentials::AccessToken(token) = &self.credentials {
+            return Ok(token.clone());
+        }
+
+        let mut cached = self.cached.lock().await;
+        let now = Utc::now();
+
+        if let Some(token) = cached.as_ref() {
+            if token.expires_at - Duration::seconds(EXPIRY_MARGIN_SECS) > now {
+                return Ok(token.token.clone());
+            }
+        }
+
+        let fresh = self.fetch_token(now).await?;
+        let token = fresh.token.clone();
+        *cached = Some(fresh);
+        Ok(token)
+    }
+
+    /// @ai:intent Obtain a new token from the configured source
+    /// @ai:effects network, io
+    async fn fetch_token(&self, now: DateTime<Utc>) -> Result<CachedToken> {
+        let response = match &self.credentials {
+            GcpCredentials::AccessToken(token) => TokenResponse {
+                access_token: token.clone(),
+                expires_in: None,
+            },
+            GcpCredentials::AuthorizedUser {
+                client_id,
+                client_secret,
+                refresh_token,
+            } => {
+                let response = self
+                    .client
+                    .post(TOKEN_URL)
+                    .form(&[
+                        ("grant_type", "refresh_token"),
+                        ("client_id", client_id.as_str()),
+                        ("client_secret", client_secret.as_str()),
+                        ("refresh_token", refresh_token.as_str()),
+                    ])
+                    .send()
+                    .await
+                    .context("Failed to refresh Google OAuth token")?;
+                parse_token_response(response).await?
+            }
+            GcpCredentials::MetadataServer => {
+                let response = self
+                    .client
+                    .get(METADATA_TOKEN_URL)
+                    .header("Metadata-Flavor", "Google")
+                    .send()
+                    .await
+                    .context(
+                        "No Google credentials found: set GOOGLE_APPLICATION_CREDENTIALS \
+                         or run `gcloud auth application-default login`",
+                    )?;
+                parse_token_response(response).await?
+            }
+            GcpCredentials::Gcloud { credentials_file } => gcloud_token(credentials_file).await?,
+        };
+
+        let lifetime = response.expires_in.unwrap_or(GCLOUD_TOKEN_LIFETIME_SECS);
+        Ok(CachedToken {
+            token: response.access_token,
+            expires_at: now + Duration::seconds(lifetime),
+        })
+    }
+}
+
+/// @ai:intent Check the status of a token endpoint response and decode it
+/// @ai:effects network
+async fn parse_token_response(response: reqwest::Response) -> Result<TokenResponse> {
+    let status = response.status();
+
+    if !status.is_success() {
+        let error_text = response.text().await.unwrap_or_default();
+        anyhow::bail!("Google token endpoint error ({}): {}", status, error_text);
+    }
+
+    response
+        .json()
+        .await
+        .context("Failed to parse Google token response")
+}
+
+/// @ai:intent Ask gcloud for a token (handles service account keys without a JWT signer)
+/// @ai:effects io
+async fn gcloud_token(credentials_file: &std::path::Path) -> Result<TokenResponse> {
+    let output = tokio::process::Command::new("gcloud")
+        .args(["auth", "application-default", "print-access-token"])
+        .env("GOOGLE_APPLICATION_CREDENTIALS", credentials_file)
+        .kill_on_drop(true)
+        .output();
+
+    // A hung gcloud (e.g. waiting on a reauth prompt) would otherwise stall every request
+    let output = tokio::time::timeout(std::time::Duration::from_secs(GCLOUD_TIMEOUT_SECS), output)
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "gcloud auth application-default print-access-token timed out after {}s",
+                GCLOUD_TIMEOUT_SECS
+            )
+        })?
+        .context("Failed to run gcloud (required for service account credentials)")?;
+
+    if !output.status.success() {
+        anyhow::bail!(
+            "gcloud auth application-default print-access-token failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(TokenResponse {
+        access_token: String::from_utf8_lossy(&output.stdout).trim().to_string(),
+        expires_in: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_credentials_from_file_contents() {
+        let user = r#"{
+            "type": "authorized_user",
+            "client_id": "id.apps.googleusercontent.com",
+            "client_secret": "secret",
+            "refresh_token": "1//refresh"
+        }"#;
+        let account = r#"{"type": "service_account", "private_key": "..."}"#;
+
+        assert_eq!(
+            GcpCredentials::from_file_contents(user, PathBuf::from("adc.json")).unwrap(),
+            GcpCredentials::AuthorizedUser {
+                client_id: "id.apps.googleusercontent.com".to_string(),
+                client_secret: "secret".to_string(),
+                refresh_token: "1//refresh".to_string(),
+            }
+        );
+        assert_eq!(
+            GcpCredentials::from_file_contents(account, PathBuf::from("sa.json")).unwrap(),
+            GcpCredentials::Gcloud {
+                credentials_file: PathBuf::from("sa.json")
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_static_token_is_returned_as_is() {
+        let provider =
+            GcpTokenProvider::new(GcpCredentials::AccessToken("ya29.token".to_string())).unwrap();
+        assert_eq!(provider.access_token().await.unwrap(), "ya29.token");
+    }
+}
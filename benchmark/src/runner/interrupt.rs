@@ -0,0 +1,72 @@
+//! @ai:module:intent Cooperative stop of a run (Ctrl-C): no new executions start, in-flight
+//!                   ones finish, so the completed work can still be aggregated and reported
+//! @ai:module:layer application
+//! @ai:module:public_api Interrupt
+//! @ai:module:stateless false
+
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+/// @ai:intent Stop request shared by the signal handler and all executions of a run
+#[derive(Debug, Default)]
+pub struct Interrupt {
+    requested: AtomicBool,
+    /// Executions not started because a stop was requested
+    skipped: AtomicU32,
+}
+
+impl Interrupt {
+    /// @ai:intent Create an interrupt that has not been requested
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// @ai:intent Request a stop
+    /// @ai:post returns true only for the first request
+    /// @ai:effects state:write
+    pub fn request(&self) -> bool {
+        !self.requested.swap(true, Ordering::SeqCst)
+    }
+
+    /// @ai:intent Whether a stop has been requested
+    /// @ai:effects pure
+    pub fn is_requested(&self) -> bool {
+        self.requested.load(Ordering::SeqCst)
+    }
+
+    /// @ai:intent Check before starting an execution; counts it as skipped once stopped
+    /// @ai:post returns false after a stop was requested
+    /// @ai:effects state:write
+    pub fn try_start(&self) -> bool {
+        if self.is_requested() {
+            self.skipped.fetch_add(1, Ordering::SeqCst);
+            return false;
+        }
+        true
+    }
+
+    /// @ai:intent Executions skipped so far because of the stop request
+    /// @ai:effects pure
+    pub fn skipped(&self) -> u32 {
+        self.skipped.load(Ordering::SeqCst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_stops_new_executions() {
+        let interrupt = Interrupt::new();
+        assert!(interrupt.try_start());
+        assert_eq!(interrupt.skipped(), 0);
+
+        assert!(interrupt.request());
+        assert!(!interrupt.request());
+        assert!(interrupt.is_requested());
+        assert!(!interrupt.try_start());
+        assert!(!interrupt.try_start());
+        assert_eq!(interrupt.skipped(), 2);
+    }
+}
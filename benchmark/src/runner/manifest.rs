@@ -0,0 +1,94 @@
+//! @ai:module:intent Self-describing TASK.json manifests for generated code directories
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api TaskManifest, hash_prompt, MANIFEST_FILE
+//! @ai:module:stateless true
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// @ai:intent File name of the manifest written into each code directory
+pub const MANIFEST_FILE: &str = "TASK.json";
+
+/// @ai:intent Metadata describing how a code directory was generated
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskManifest {
+    pub task_id: String,
+    pub mode: String,
+    pub repetition: u32,
+    /// Name of the run directory (results/{run_id}/)
+    pub run_id: String,
+    pub model: Option<String>,
+    /// SHA-256 of the prompt sent to Claude, hex encoded
+    pub prompt_hash: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+}
+
+impl TaskManifest {
+    /// @ai:intent Write the manifest as pretty JSON into a directory
+    /// @ai:effects fs:write
+    pub fn write(&self, dir: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(dir.join(MANIFEST_FILE), content)
+            .with_context(|| format!("Failed to write {} in {}", MANIFEST_FILE, dir.display()))
+    }
+
+    /// @ai:intent Read the manifest from a directory, if present
+    /// @ai:effects fs:read
+    pub fn read(dir: &Path) -> Result<Option<Self>> {
+        let path = dir.join(MANIFEST_FILE);
+
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&path)?;
+        let manifest = serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        Ok(Some(manifest))
+    }
+}
+
+/// @ai:intent Compute a stable hash of a prompt
+/// @ai:effects pure
+pub fn hash_prompt(prompt: &str) -> String {
+    hex::encode(Sha256::digest(prompt.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_write_and_read_manifest() {
+        let temp = TempDir::new().unwrap();
+        let now = Utc::now();
+        let manifest = TaskManifest {
+            task_id: "impl-rust-user-crud".to_string(),
+            mode: "aicms".to_string(),
+            repetition: 2,
+            run_id: "2026-01-20_12-00-00".to_string(),
+            model: Some("claude-sonnet-4-20250514".to_string()),
+            prompt_hash: hash_prompt("prompt"),
+            started_at: now,
+            finished_at: now,
+        };
+
+        manifest.write(temp.path()).unwrap();
+
+        let loaded = TaskManifest::read(temp.path()).unwrap();
+        assert_eq!(loaded, Some(manifest));
+        assert!(TaskManifest::read(&temp.path().join("missing")).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_hash_prompt_is_stable() {
+        assert_eq!(hash_prompt("abc"), hash_prompt("abc"));
+        assert_ne!(hash_prompt("abc"), hash_prompt("abd"));
+        assert_eq!(hash_prompt("").len(), 64);
+    }
+}
@@ -1,15 +1,47 @@
 //! @ai:module:intent Task execution and API client
 //! @ai:module:layer infrastructure
-//! @ai:module:public_api ClaudeClient, ClaudeCodeClient, BenchmarkExecutor, RateLimiter, PromptMode
+//! @ai:module:public_api ClaudeClient, ClaudeCodeClient, OllamaClient, BedrockClient, VertexClient,
+//!                        BenchmarkExecutor,
+//!                        RateLimiter, PromptMode, CheckpointStore, RecordingClient, ReplayClient,
+//!                        RepairCheckerTrait, Budget, Interrupt, ProcessTree, EventLog, DryRunClient
 
+pub mod bedrock_client;
+pub mod budget;
+pub mod checkpoint;
 pub mod client;
 pub mod claude_code_client;
+pub mod dry_run;
+pub mod events;
 pub mod executor;
+pub mod gcp_auth;
+pub mod interrupt;
+pub mod manifest;
+pub mod ollama_client;
+pub mod process;
 pub mod rate_limiter;
+pub mod repair;
+pub mod replay;
+pub mod retry;
+pub mod sigv4;
+pub mod streaming;
+pub mod vertex_client;
 
+pub use bedrock_client::BedrockClient;
+pub use budget::Budget;
+pub use checkpoint::{CheckpointStore, ExecutionKey, EXECUTIONS_FILE};
 pub use client::{ClaudeClient, ClaudeClientTrait, ClaudeResponse, MockClaudeClient, TaskContext};
-pub use claude_code_client::ClaudeCodeClient;
+pub use claude_code_client::{default_claude_md_template, ClaudeCodeClient};
+pub use dry_run::DryRunClient;
+pub use events::{Event, EventLog, EventRecord, EVENTS_FILE};
 pub use executor::{
     create_executor, BenchmarkExecutor, ExecutionResult, PromptMode, PromptTemplates,
 };
-pub use rate_limiter::{RateLimiter, RateLimiterTrait};
+pub use interrupt::Interrupt;
+pub use manifest::{hash_prompt, TaskManifest, MANIFEST_FILE};
+pub use ollama_client::OllamaClient;
+pub use process::{kill_all_trees, timeout_from_secs, ProcessTree};
+pub use rate_limiter::{estimate_request_tokens, RateLimiter, RateLimiterTrait};
+pub use repair::{build_repair_prompt, RepairCheck, RepairCheckerTrait, RepairTurn};
+pub use replay::{fixture_key, Fixture, RecordingClient, ReplayClient};
+pub use retry::{backoff_delay, retry_with_backoff};
+pub use vertex_client::VertexClient;
@@ -0,0 +1,209 @@
+//! @ai:module:intent Ollama client for benchmarking open-weight models on a local server
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api OllamaClient
+//! @ai:module:stateless true
+
+use crate::config::{ApiConfig, OllamaConfig};
+use crate::runner::client::{ClaudeClientTrait, ClaudeResponse, TaskContext};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// @ai:intent Ollama /api/chat request body
+#[derive(Debug, Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage<'a>>,
+    stream: bool,
+    options: ChatOptions,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatMessage<'a> {
+    role: &'static str,
+    content: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatOptions {
+    temperature: f32,
+    num_predict: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<u64>,
+}
+
+/// @ai:intent Ollama /api/chat response body (non-streaming)
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    message: ResponseMessage,
+    #[serde(default)]
+    done_reason: Option<String>,
+    #[serde(default)]
+    prompt_eval_count: u32,
+    #[serde(default)]
+    eval_count: u32,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseMessage {
+    content: String,
+}
+
+/// @ai:intent Client for a local Ollama server, usable in place of the Claude clients
+pub struct OllamaClient {
+    client: reqwest::Client,
+    config: OllamaConfig,
+    max_tokens: u32,
+    temperature: f32,
+    seed: Option<u64>,
+}
+
+impl OllamaClient {
+    /// @ai:intent Create a new Ollama client
+    ///            Sampling settings (max_tokens, temperature, seed) come from the API config
+    /// @ai:effects pure
+    pub fn new(config: OllamaConfig, api: &ApiConfig) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(config.timeout_secs))
+            .build()?;
+
+        Ok(Self {
+            client,
+            config,
+            max_tokens: api.max_tokens,
+            temperature: api.temperature,
+            seed: api.seed,
+        })
+    }
+
+    /// @ai:intent Build the chat request for a prompt
+    /// @ai:effects pure
+    fn build_request<'a>(&'a self, prompt: &'a str, system: Option<&'a str>) -> ChatRequest<'a> {
+        let mut messages = Vec::new();
+
+        if let Some(system) = system {
+            messages.push(ChatMessage {
+                role: "system",
+                content: system,
+            });
+        }
+
+        messages.push(ChatMessage {
+            role: "user",
+            content: prompt,
+        });
+
+        ChatRequest {
+            model: &self.config.model,
+            messages,
+            stream: false,
+            options: ChatOptions {
+                temperature: self.temperature,
+                num_predict: self.max_tokens,
+                seed: self.seed,
+            },
+        }
+    }
+
+    /// @ai:intent Full URL of the chat endpoint
+    /// @ai:effects pure
+    fn chat_url(&self) -> String {
+        format!("{}/api/chat", self.config.endpoint.trim_end_matches('/'))
+    }
+}
+
+/// @ai:intent Convert an Ollama response into the common response type
+/// @ai:effects pure
+fn into_response(response: ChatResponse) -> ClaudeResponse {
+    ClaudeResponse {
+        content: response.message.content,
+        input_tokens: response.prompt_eval_count,
+        output_tokens: response.eval_count,
+        stop_reason: response.done_reason.unwrap_or_else(|| "stop".to_string()),
+        cache_creation_input_tokens: 0,
+        cache_read_input_tokens: 0,
+        num_turns: None,
+        cost_usd: None,
+    }
+}
+
+impl ClaudeClientTrait for OllamaClient {
+    /// @ai:intent Send a chat request to the Ollama server
+    /// @ai:effects network
+    async fn send_message(
+        &self,
+        prompt: &str,
+        system: Option<&str>,
+        _context: &TaskContext,
+    ) -> Result<ClaudeResponse> {
+        let request = self.build_request(prompt, system);
+
+        let response = self
+            .client
+            .post(self.chat_url())
+            .json(&request)
+            .send()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to reach Ollama at {} (is `ollama serve` running?)",
+                    self.config.endpoint
+                )
+            })?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Ollama error ({}): {}", status, error_text);
+        }
+
+        let chat_response: ChatResponse = response
+            .json()
+            .await
+            .context("Failed to parse Ollama response")?;
+
+        Ok(into_response(chat_response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_request() {
+        let config = OllamaConfig {
+            endpoint: "http://localhost:11434/".to_string(),
+            ..Default::default()
+        };
+        let client = OllamaClient::new(config, &ApiConfig::default()).unwrap();
+
+        let request =
+            serde_json::to_value(client.build_request("Write code", Some("Be brief"))).unwrap();
+
+        assert_eq!(client.chat_url(), "http://localhost:11434/api/chat");
+        assert_eq!(request["stream"], false);
+        assert_eq!(request["messages"][0]["role"], "system");
+        assert_eq!(request["messages"][1]["content"], "Write code");
+        assert_eq!(request["options"]["num_predict"], 4096);
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let body = r#"{
+            "model": "qwen2.5-coder:7b",
+            "message": {"role": "assistant", "content": "fn main() {}"},
+            "done": true,
+            "done_reason": "stop",
+            "prompt_eval_count": 42,
+            "eval_count": 7
+        }"#;
+
+        let response = into_response(serde_json::from_str(body).unwrap());
+
+        assert_eq!(response.content, "fn main() {}");
+        assert_eq!(response.input_tokens, 42);
+        assert_eq!(response.output_tokens, 7);
+        assert_eq!(response.stop_reason, "stop");
+    }
+}
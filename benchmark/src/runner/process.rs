@@ -0,0 +1,395 @@
+//! @ai:module:intent Run the Claude CLI and evaluation commands as killable process trees,
+//!                   with a timeout
+//!                   Unix: the child leads its own process group; Windows: it is put in a job object
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api ProcessTree, LimitedOutput, kill_all_trees, timeout_from_secs
+//! @ai:module:stateless false
+
+use std::io::{self, Read};
+use std::process::{Child, Command, Output};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How often a wait checks whether the child has exited
+pub(crate) const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Process ids of the trees that are still alive, killed by kill_all_trees
+static LIVE_TREES: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+/// @ai:intent Output of a child waited for with a timeout and an output cap
+#[derive(Debug)]
+pub struct LimitedOutput {
+    /// Exit status and captured pipes; a killed child has a failing status
+    pub output: Output,
+    /// The timeout expired and the tree was killed
+    pub timed_out: bool,
+}
+
+/// @ai:intent A child process and everything it spawns, killed as one unit
+///            Descendants left behind when the child exits are killed before it is reaped;
+///            dropping a tree whose child was never reaped kills all of it
+pub struct ProcessTree {
+    pid: u32,
+    /// Leftovers were killed and the child is (about to be) reaped: its process group id
+    /// may be reused, so the group must not be signalled again
+    released: AtomicBool,
+    /// Job object holding the tree; closing it terminates every process in it
+    #[cfg(windows)]
+    job: Option<std::os::windows::io::OwnedHandle>,
+}
+
+impl ProcessTree {
+    /// @ai:intent Make the command's child lead a new process group (Unix), so that
+    ///            its descendants can be killed with it; call before spawning
+    /// @ai:effects pure
+    pub fn configure(cmd: &mut Command) {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            cmd.process_group(0);
+        }
+        #[cfg(not(unix))]
+        let _ = cmd;
+    }
+
+    /// @ai:intent Track a spawned child (configured with ProcessTree::configure) as a tree
+    ///            On Windows, processes the child started before this call are not in the job
+    /// @ai:effects state:write
+    pub fn attach(pid: u32) -> Self {
+        LIVE_TREES.lock().unwrap().push(pid);
+        Self {
+            pid,
+            released: AtomicBool::new(false),
+            #[cfg(windows)]
+            job: windows_job::assign(pid)
+                .map_err(|e| tracing::warn!("No job object for process {}: {}", pid, e))
+                .ok(),
+        }
+    }
+
+    /// @ai:intent Kill the child and all of its descendants
+    /// @ai:effects io
+    pub fn kill(&self) {
+        kill_tree(self.pid);
+        #[cfg(windows)]
+        if let Some(job) = &self.job {
+            windows_job::terminate(job);
+        }
+    }
+
+    /// @ai:intent Kill what is left of the tree once the child has exited, before the caller
+    ///            reaps it; until then its zombie keeps the process group id reserved
+    /// @ai:post Dropping the tree no longer signals the process group
+    /// @ai:effects io
+    pub fn kill_before_reap(&self) {
+        self.kill();
+        self.released.store(true, Ordering::SeqCst);
+    }
+
+    /// @ai:intent Whether the child has exited, without reaping it
+    /// @ai:effects io
+    #[cfg(unix)]
+    pub fn has_exited(&self) -> io::Result<bool> {
+        let mut info: libc::siginfo_t = unsafe { std::mem::zeroed() };
+        let flags = libc::WEXITED | libc::WNOHANG | libc::WNOWAIT;
+        if unsafe { libc::waitid(libc::P_PID, self.pid as libc::id_t, &mut info, flags) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        // WNOHANG leaves si_pid zero while the child is running
+        Ok(unsafe { info.si_pid() } != 0)
+    }
+
+    /// @ai:intent Whether the child has exited; reaping it early is harmless on Windows,
+    ///            where the tree is killed through its job object rather than by process id
+    /// @ai:effects io
+    #[cfg(not(unix))]
+    fn child_exited(&self, child: &mut Child) -> io::Result<bool> {
+        Ok(child.try_wait()?.is_some())
+    }
+
+    /// @ai:intent Whether the child has exited, leaving it unreaped
+    /// @ai:effects io
+    #[cfg(unix)]
+    fn child_exited(&self, _child: &mut Child) -> io::Result<bool> {
+        self.has_exited()
+    }
+
+    /// @ai:intent Wait for the child and collect its output, killing the tree on timeout
+    /// @ai:post Ok(None) when the timeout expired and the tree was killed
+    /// @ai:effects io
+    pub fn wait_with_output(
+        &self,
+        child: Child,
+        timeout: Option<Duration>,
+    ) -> io::Result<Option<Output>> {
+        let limited = self.wait_with_limited_output(child, timeout, usize::MAX)?;
+        Ok((!limited.timed_out).then_some(limited.output))
+    }
+
+    /// @ai:intent Wait for the child, killing the tree on timeout, and keep at most
+    ///            max_output bytes of each pipe (a truncation note is appended)
+    /// @ai:post result.timed_out when the timeout expired and the tree was killed
+    /// @ai:effects io
+    pub fn wait_with_limited_output(
+        &self,
+        mut child: Child,
+        timeout: Option<Duration>,
+        max_output: usize,
+    ) -> io::Result<LimitedOutput> {
+        // Close stdin so a child reading it sees the end of its input
+        drop(child.stdin.take());
+        // Drain both pipes in the background so a chatty child cannot block on a full pipe
+        let stdout = child
+            .stdout
+            .take()
+            .map(|pipe| read_in_background(pipe, max_output));
+        let stderr = child
+            .stderr
+            .take()
+            .map(|pipe| read_in_background(pipe, max_output));
+
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let timed_out = loop {
+            if self.child_exited(&mut child)? {
+                break false;
+            }
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                break true;
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        };
+        self.kill_before_reap();
+        let status = child.wait()?;
+
+        // Killing the whole tree closes every copy of the pipes, so the readers finish
+        let stdout = join_reader(stdout, max_output)?;
+        let stderr = join_reader(stderr, max_output)?;
+
+        Ok(LimitedOutput {
+            output: Output {
+                status,
+                stdout,
+                stderr,
+            },
+            timed_out,
+        })
+    }
+}
+
+impl Drop for ProcessTree {
+    fn drop(&mut self) {
+        LIVE_TREES.lock().unwrap().retain(|&pid| pid != self.pid);
+        // A child that was never reaped still holds its group id, so killing the group cannot
+        // hit another process; on Windows, closing the job handle kills the tree
+        #[cfg(unix)]
+        if !self.released.load(Ordering::SeqCst) {
+            kill_tree(self.pid);
+        }
+    }
+}
+
+/// @ai:intent Kill every tree that is still running (e.g. when the benchmark is interrupted)
+/// @ai:effects io
+pub fn kill_all_trees() {
+    for &pid in LIVE_TREES.lock().unwrap().iter() {
+        kill_tree(pid);
+    }
+}
+
+/// @ai:intent Timeout from a config value in seconds (0 = no timeout)
+/// @ai:effects pure
+/// @ai:example (0) -> None
+/// @ai:example (600) -> Some(600s)
+pub fn timeout_from_secs(secs: u64) -> Option<Duration> {
+    (secs > 0).then(|| Duration::from_secs(secs))
+}
+
+/// @ai:intent Send SIGKILL to the process group led by pid
+/// @ai:effects io
+#[cfg(unix)]
+fn kill_tree(pid: u32) {
+    // A group id stays reserved while any member is alive, so this cannot hit a reused pid;
+    // ESRCH just means the whole tree has already exited
+    let group = -(pid as libc::pid_t);
+    if unsafe { libc::kill(group, libc::SIGKILL) } != 0 {
+        let err = io::Error::last_os_error();
+        if err.raw_os_error() != Some(libc::ESRCH) {
+            tracing::warn!("Failed to kill process group {}: {}", pid, err);
+        }
+    }
+}
+
+/// @ai:intent Processes are killed through their job object on Windows
+/// @ai:effects pure
+#[cfg(not(unix))]
+fn kill_tree(_pid: u32) {}
+
+/// Bytes kept by a reader thread, and whether more were dropped
+type PipeReader = std::thread::JoinHandle<io::Result<(Vec<u8>, bool)>>;
+
+/// @ai:intent Read a pipe to the end on a separate thread, keeping the first limit bytes
+/// @ai:effects io
+fn read_in_background<R: Read + Send + 'static>(mut pipe: R, limit: usize) -> PipeReader {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        (&mut pipe).take(limit as u64).read_to_end(&mut buf)?;
+        let dropped = io::copy(&mut pipe, &mut io::sink())?;
+        Ok((buf, dropped > 0))
+    })
+}
+
+/// @ai:intent Output collected by a reader thread (empty if the pipe was not captured)
+/// @ai:effects pure
+fn join_reader(reader: Option<PipeReader>, limit: usize) -> io::Result<Vec<u8>> {
+    let Some(handle) = reader else {
+        return Ok(Vec::new());
+    };
+    let (mut buf, truncated) = handle
+        .join()
+        .map_err(|_| io::Error::other("pipe reader thread panicked"))??;
+    if truncated {
+        buf.extend_from_slice(format!("\n[output truncated after {} bytes]\n", limit).as_bytes());
+    }
+    Ok(buf)
+}
+
+#[cfg(windows)]
+mod windows_job {
+    use std::io;
+    use std::os::windows::io::{AsRawHandle, FromRawHandle, OwnedHandle};
+    use windows_sys::Win32::Foundation::CloseHandle;
+    use windows_sys::Win32::System::JobObjects::{
+        AssignProcessToJobObject, CreateJobObjectW, JobObjectExtendedLimitInformation,
+        SetInformationJobObject, TerminateJobObject, JOBOBJECT_EXTENDED_LIMIT_INFORMATION,
+        JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE,
+    };
+    use windows_sys::Win32::System::Threading::{
+        OpenProcess, PROCESS_SET_QUOTA, PROCESS_TERMINATE,
+    };
+
+    /// @ai:intent Create a kill-on-close job object and put the process in it
+    /// @ai:effects io
+    pub fn assign(pid: u32) -> io::Result<OwnedHandle> {
+        unsafe {
+            let raw = CreateJobObjectW(std::ptr::null(), std::ptr::null());
+            if raw.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            let job = OwnedHandle::from_raw_handle(raw);
+
+            let mut info: JOBOBJECT_EXTENDED_LIMIT_INFORMATION = std::mem::zeroed();
+            info.BasicLimitInformation.LimitFlags = JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE;
+            if SetInformationJobObject(
+                raw,
+                JobObjectExtendedLimitInformation,
+                &info as *const _ as *const _,
+                std::mem::size_of::<JOBOBJECT_EXTENDED_LIMIT_INFORMATION>() as u32,
+            ) == 0
+            {
+                return Err(io::Error::last_os_error());
+            }
+
+            let process = OpenProcess(PROCESS_SET_QUOTA | PROCESS_TERMINATE, 0, pid);
+            if process.is_null() {
+                return Err(io::Error::last_os_error());
+            }
+            let assigned = AssignProcessToJobObject(raw, process);
+            CloseHandle(process);
+            if assigned == 0 {
+                return Err(io::Error::last_os_error());
+            }
+
+            Ok(job)
+        }
+    }
+
+    /// @ai:intent Terminate every process in the job
+    /// @ai:effects io
+    pub fn terminate(job: &OwnedHandle) {
+        if unsafe { TerminateJobObject(job.as_raw_handle(), 1) } == 0 {
+            tracing::warn!(
+                "Failed to terminate job object: {}",
+                io::Error::last_os_error()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Stdio;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_timeout_kills_whole_tree() {
+        // The background sleep keeps stdout open: if only sh were killed, reading would block
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c")
+            .arg("sleep 30 & sleep 30")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        ProcessTree::configure(&mut cmd);
+        let child = cmd.spawn().unwrap();
+        let tree = ProcessTree::attach(child.id());
+
+        let started = Instant::now();
+        let output = tree
+            .wait_with_output(child, Some(Duration::from_millis(300)))
+            .unwrap();
+
+        assert!(output.is_none());
+        assert!(started.elapsed() < Duration::from_secs(10));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_exit_kills_leftover_descendants() {
+        // The background sleep keeps stdout open after sh exits, until the tree is killed
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c")
+            .arg("sleep 30 & echo started")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        ProcessTree::configure(&mut cmd);
+        let child = cmd.spawn().unwrap();
+        let tree = ProcessTree::attach(child.id());
+
+        let started = Instant::now();
+        let output = tree.wait_with_output(child, None).unwrap().unwrap();
+
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"started\n");
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(tree.released.load(Ordering::SeqCst));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_limited_output_is_truncated() {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c")
+            .arg("yes | head -c 100000")
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        ProcessTree::configure(&mut cmd);
+        let child = cmd.spawn().unwrap();
+        let tree = ProcessTree::attach(child.id());
+
+        let limited = tree.wait_with_limited_output(child, None, 10).unwrap();
+
+        assert!(!limited.timed_out);
+        assert!(limited.output.status.success());
+        assert!(limited
+            .output
+            .stdout
+            .starts_with(b"y\ny\ny\ny\ny\n\n[output truncated after 10 bytes]"));
+    }
+
+    #[test]
+    fn test_timeout_from_secs() {
+        assert_eq!(timeout_from_secs(0), None);
+        assert_eq!(timeout_from_secs(600), Some(Duration::from_secs(600)));
+    }
+}
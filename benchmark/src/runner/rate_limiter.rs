@@ -1,26 +1,48 @@
 //! @ai:module:intent Rate limiting for API requests
 //! @ai:module:layer infrastructure
-//! @ai:module:public_api RateLimiter
+//! @ai:module:public_api RateLimiter, estimate_request_tokens
+//! @ai:module:depends_on config
 //! @ai:module:stateless false
 
-use std::sync::Arc;
+use crate::config::ApiConfig;
 use std::time::{Duration, Instant};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Semaphore};
 
 /// @ai:intent Trait for rate limiting functionality
 pub trait RateLimiterTrait: Send + Sync {
     /// @ai:intent Wait until a request is allowed
     fn wait(&self) -> impl std::future::Future<Output = ()> + Send;
+
+    /// @ai:intent Wait until a request is allowed and the token budget covers its estimate,
+    ///            then reserve both
+    fn acquire(&self, estimated_tokens: u32) -> impl std::future::Future<Output = ()> + Send;
+
+    /// @ai:intent Correct a reservation once the real usage is known
+    fn record_usage(
+        &self,
+        estimated_tokens: u32,
+        actual_tokens: u32,
+    ) -> impl std::future::Future<Output = ()> + Send;
 }
 
-/// @ai:intent Token bucket rate limiter for API requests
+/// @ai:intent Token bucket rate limiter for API requests, optionally also budgeting
+///            input+output tokens per minute
+///            Shared by all concurrent executions; waiters are served first come, first served
 pub struct RateLimiter {
-    state: Arc<Mutex<RateLimiterState>>,
+    state: Mutex<RateLimiterState>,
+    /// Single-permit FIFO queue: only the caller at the head waits for capacity, so a
+    /// large request is not starved by small ones arriving after it
+    queue: Semaphore,
     requests_per_minute: u32,
+    tokens_per_minute: Option<u32>,
+    /// Response tokens assumed when estimating a request (api.max_tokens)
+    max_tokens: u32,
 }
 
 struct RateLimiterState {
     tokens: f64,
+    /// Remaining API tokens (input+output) in the per-minute budget; negative when overdrawn
+    token_budget: f64,
     last_update: Instant,
 }
 
@@ -29,48 +51,133 @@ impl RateLimiter {
     /// @ai:pre requests_per_minute > 0
     /// @ai:effects pure
     pub fn new(requests_per_minute: u32) -> Self {
+        Self::with_limits(requests_per_minute, None)
+    }
+
+    /// @ai:intent Create a rate limiter that also budgets tokens per minute (None or 0 = unlimited)
+    /// @ai:pre requests_per_minute > 0
+    /// @ai:effects pure
+    pub fn with_limits(requests_per_minute: u32, tokens_per_minute: Option<u32>) -> Self {
+        let tokens_per_minute = tokens_per_minute.filter(|&tpm| tpm > 0);
+
         Self {
-            state: Arc::new(Mutex::new(RateLimiterState {
+            state: Mutex::new(RateLimiterState {
                 tokens: requests_per_minute as f64,
+                token_budget: tokens_per_minute.unwrap_or(0) as f64,
                 last_update: Instant::now(),
-            })),
+            }),
+            queue: Semaphore::new(1),
             requests_per_minute,
+            tokens_per_minute,
+            max_tokens: 0,
         }
     }
 
-    /// @ai:intent Refill tokens based on elapsed time
+    /// @ai:intent Create the limiter for [api] (requests_per_minute, tokens_per_minute, max_tokens)
+    /// @ai:effects pure
+    pub fn from_config(api: &ApiConfig) -> Self {
+        Self {
+            max_tokens: api.max_tokens,
+            ..Self::with_limits(api.requests_per_minute, api.tokens_per_minute)
+        }
+    }
+
+    /// @ai:intent Estimated usage of a request, for acquire
+    /// @ai:effects pure
+    pub fn estimate_tokens(&self, prompt: &str, system: Option<&str>) -> u32 {
+        estimate_request_tokens(prompt, system, self.max_tokens)
+    }
+
+    /// @ai:intent Refill request slots and token budget based on elapsed time
     /// @ai:effects state:write
-    fn refill_tokens(state: &mut RateLimiterState, rpm: u32) {
+    fn refill_tokens(&self, state: &mut RateLimiterState) {
         let now = Instant::now();
-        let elapsed = now.duration_since(state.last_update);
-        let tokens_to_add = elapsed.as_secs_f64() * (rpm as f64 / 60.0);
-        state.tokens = (state.tokens + tokens_to_add).min(rpm as f64);
+        let elapsed = now.duration_since(state.last_update).as_secs_f64();
+        let rpm = self.requests_per_minute as f64;
+        state.tokens = (state.tokens + elapsed * (rpm / 60.0)).min(rpm);
+
+        if let Some(tpm) = self.tokens_per_minute {
+            let tpm = tpm as f64;
+            state.token_budget = (state.token_budget + elapsed * (tpm / 60.0)).min(tpm);
+        }
         state.last_update = now;
     }
+
+    /// @ai:intent Time until a request with this estimate fits (zero if it fits now)
+    ///            A request larger than the whole token budget waits for a full bucket
+    /// @ai:effects pure
+    fn time_until_available(&self, state: &RateLimiterState, estimated_tokens: u32) -> Duration {
+        let request_wait = (1.0 - state.tokens).max(0.0) / (self.requests_per_minute as f64 / 60.0);
+
+        let token_wait = match self.tokens_per_minute {
+            Some(tpm) => {
+                let needed = estimated_tokens.min(tpm) as f64;
+                (needed - state.token_budget).max(0.0) / (tpm as f64 / 60.0)
+            }
+            None => 0.0,
+        };
+
+        Duration::from_secs_f64(request_wait.max(token_wait))
+    }
+}
+
+/// @ai:intent Estimate the tokens a request may use: ~4 bytes per prompt token plus
+///            the full max_tokens for the response (settled against real usage later)
+/// @ai:effects pure
+/// @ai:example ("x" * 400, None, 1000) -> 1100
+pub fn estimate_request_tokens(prompt: &str, system: Option<&str>, max_tokens: u32) -> u32 {
+    let prompt_bytes = prompt.len() + system.map_or(0, str::len);
+    (prompt_bytes / 4) as u32 + max_tokens
 }
 
 impl RateLimiterTrait for RateLimiter {
     /// @ai:intent Wait until a request is allowed
     /// @ai:effects state:write, time
     async fn wait(&self) {
+        self.acquire(0).await;
+    }
+
+    /// @ai:intent Wait for a request slot and token budget in arrival order, then reserve them
+    /// @ai:effects state:write, time
+    async fn acquire(&self, estimated_tokens: u32) {
+        let _turn = self
+            .queue
+            .acquire()
+            .await
+            .expect("rate limiter queue is never closed");
+
         loop {
             let sleep_duration = {
                 let mut state = self.state.lock().await;
-                Self::refill_tokens(&mut state, self.requests_per_minute);
+                self.refill_tokens(&mut state);
 
-                if state.tokens >= 1.0 {
+                let wait = self.time_until_available(&state, estimated_tokens);
+                if wait.is_zero() {
                     state.tokens -= 1.0;
+                    if self.tokens_per_minute.is_some() {
+                        state.token_budget -= estimated_tokens as f64;
+                    }
                     return;
                 }
-
-                let tokens_needed = 1.0 - state.tokens;
-                let seconds_to_wait = tokens_needed / (self.requests_per_minute as f64 / 60.0);
-                Duration::from_secs_f64(seconds_to_wait)
+                wait
             };
 
             tokio::time::sleep(sleep_duration).await;
         }
     }
+
+    /// @ai:intent Refund an overestimate or charge an underestimate
+    /// @ai:effects state:write
+    async fn record_usage(&self, estimated_tokens: u32, actual_tokens: u32) {
+        let Some(tpm) = self.tokens_per_minute else {
+            return;
+        };
+
+        let mut state = self.state.lock().await;
+        state.token_budget = (state.token_budget + estimated_tokens as f64
+            - actual_tokens as f64)
+            .min(tpm as f64);
+    }
 }
 
 #[cfg(test)]
@@ -102,4 +209,56 @@ mod tests {
 
         assert!(elapsed >= Duration::from_millis(900));
     }
+
+    #[tokio::test]
+    async fn test_token_budget_throttles_and_refunds() {
+        // 6000 tokens/minute = 100 tokens/second
+        let limiter = RateLimiter::with_limits(1000, Some(6000));
+
+        limiter.acquire(6000).await;
+        limiter.record_usage(6000, 1000).await;
+
+        let start = Instant::now();
+        limiter.acquire(5000).await;
+        assert!(start.elapsed() < Duration::from_millis(100));
+
+        limiter.acquire(50).await;
+        assert!(start.elapsed() >= Duration::from_millis(450));
+    }
+
+    #[tokio::test]
+    async fn test_shared_limiter_serves_tasks_in_order() {
+        use std::sync::Arc;
+
+        // 600 requests/minute = one every 100ms once the initial burst is spent
+        let limiter = Arc::new(RateLimiter::new(600));
+        for _ in 0..600 {
+            limiter.wait().await;
+        }
+
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let start = Instant::now();
+        let mut handles = Vec::new();
+        for i in 0..3 {
+            let limiter = Arc::clone(&limiter);
+            let order = Arc::clone(&order);
+            handles.push(tokio::spawn(async move {
+                limiter.wait().await;
+                order.lock().unwrap().push(i);
+            }));
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(*order.lock().unwrap(), vec![0, 1, 2]);
+        assert!(start.elapsed() >= Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_estimate_request_tokens() {
+        assert_eq!(estimate_request_tokens(&"x".repeat(400), None, 1000), 1100);
+        assert_eq!(estimate_request_tokens("abcd", Some("abcd"), 0), 2);
+    }
 }
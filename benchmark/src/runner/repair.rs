@@ -0,0 +1,62 @@
+//! @ai:module:intent Multi-turn repair: feed compile/test failures back to the model
+//! @ai:module:layer application
+//! @ai:module:public_api RepairCheckerTrait, RepairCheck, RepairTurn, build_repair_prompt
+//! @ai:module:stateless true
+
+use crate::corpus::Task;
+use crate::runner::executor::ExecutionResult;
+use serde::{Deserialize, Serialize};
+
+/// @ai:intent Outcome of checking one turn's response
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepairCheck {
+    pub compiled: bool,
+    pub test_pass_rate: f64,
+    /// Errors to send back to the model; None when there is nothing to repair
+    pub feedback: Option<String>,
+}
+
+/// @ai:intent Checks a response between repair turns (implemented by the Evaluator)
+pub trait RepairCheckerTrait: Send + Sync {
+    /// @ai:intent Compile and test the response of one turn
+    fn check(&self, task: &Task, execution: &ExecutionResult) -> RepairCheck;
+}
+
+/// @ai:intent Metrics of one turn of a multi-turn execution (turn 0 is the first attempt)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepairTurn {
+    pub turn: u32,
+    pub compiled: bool,
+    pub test_pass_rate: f64,
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub execution_time_ms: u64,
+}
+
+/// @ai:intent Prompt for a repair turn
+///            Clients are single-shot, so the original prompt and previous answer are repeated
+/// @ai:effects pure
+pub fn build_repair_prompt(prompt: &str, previous_response: &str, feedback: &str) -> String {
+    format!(
+        "{}\n\n## Your previous answer\n\n{}\n\n## Feedback\n\n{}\n\n\
+         Fix the problems above. Return every file in full, each in a code block \
+         labelled with its path.",
+        prompt,
+        previous_response.trim_end(),
+        feedback.trim_end()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_repair_prompt_keeps_context() {
+        let prompt = build_repair_prompt("## Task: Add", "fn add() {}", "error[E0061]");
+
+        assert!(prompt.starts_with("## Task: Add"));
+        assert!(prompt.contains("## Your previous answer\n\nfn add() {}"));
+        assert!(prompt.contains("## Feedback\n\nerror[E0061]"));
+    }
+}
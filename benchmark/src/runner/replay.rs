@@ -0,0 +1,298 @@
+//! @ai:module:intent Record model responses to fixtures and replay them without API calls
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api RecordingClient, ReplayClient, Fixture
+//! @ai:module:depends_on runner::client, runner::manifest
+//! @ai:module:stateless false
+
+use crate::runner::client::{ClaudeClientTrait, ClaudeResponse, TaskContext};
+use crate::runner::manifest::hash_prompt;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// @ai:intent One recorded prompt/response pair
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fixture {
+    pub task_id: String,
+    pub mode: String,
+    pub repetition: u32,
+    /// Hash of the system prompt and prompt (see fixture_key)
+    pub key: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    pub prompt: String,
+    pub response: ClaudeResponse,
+    pub recorded_at: DateTime<Utc>,
+}
+
+impl Fixture {
+    /// @ai:intent File name of the fixture inside the fixtures directory
+    /// @ai:effects pure
+    fn file_name(&self) -> String {
+        format!(
+            "{}.{}.{}.{}.json",
+            self.task_id,
+            self.mode,
+            self.repetition,
+            &self.key[..12]
+        )
+    }
+}
+
+/// @ai:intent Stable key for a request: the hash of its system prompt and prompt. Arms and
+///            repetitions usually send identical prompts, so it tells the requests of one
+///            execution apart and detects prompt drift, but does not identify an execution
+/// @ai:effects pure
+pub fn fixture_key(prompt: &str, system: Option<&str>) -> String {
+    hash_prompt(&format!("{}\n\n{}", system.unwrap_or_default(), prompt))
+}
+
+/// @ai:intent Client wrapper that saves every successful response as a fixture file
+pub struct RecordingClient<C: ClaudeClientTrait> {
+    inner: C,
+    dir: PathBuf,
+}
+
+impl<C: ClaudeClientTrait> RecordingClient<C> {
+    /// @ai:intent Wrap a client, recording into dir (created if missing)
+    /// @ai:effects fs:write
+    pub fn new(inner: C, dir: impl Into<PathBuf>) -> Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create fixtures directory {}", dir.display()))?;
+        Ok(Self { inner, dir })
+    }
+}
+
+impl<C: ClaudeClientTrait> ClaudeClientTrait for RecordingClient<C> {
+    /// @ai:intent Forward the request and record the response
+    /// @ai:effects network, fs:write
+    async fn send_message(
+        &self,
+        prompt: &str,
+        system: Option<&str>,
+        context: &TaskContext,
+    ) -> Result<ClaudeResponse> {
+        let response = self.inner.send_message(prompt, system, context).await?;
+
+        let fixture = Fixture {
+            task_id: context.task_id.clone(),
+            mode: context.mode.clone(),
+            repetition: context.repetition,
+            key: fixture_key(prompt, system),
+            system: system.map(str::to_string),
+            prompt: prompt.to_string(),
+            response: response.clone(),
+            recorded_at: Utc::now(),
+        };
+
+        let path = self.dir.join(fixture.file_name());
+        std::fs::write(&path, serde_json::to_string_pretty(&fixture)?)
+            .with_context(|| format!("Failed to write fixture {}", path.display()))?;
+
+        Ok(response)
+    }
+}
+
+/// @ai:intent Client that answers from recorded fixtures instead of calling a model
+pub struct ReplayClient {
+    /// Fixtures by task, mode and repetition (several for multi-turn executions)
+    by_execution: HashMap<(String, String, u32), Vec<Fixture>>,
+}
+
+impl ReplayClient {
+    /// @ai:intent Load every fixture in dir
+    /// @ai:pre dir was written by RecordingClient
+    /// @ai:effects fs:read
+    pub fn load(dir: &Path) -> Result<Self> {
+        let entries = std::fs::read_dir(dir)
+            .with_context(|| format!("Failed to read fixtures directory {}", dir.display()))?;
+
+        let mut fixtures = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("json") {
+                continue;
+            }
+            let content = std::fs::read_to_string(&path)?;
+            let fixture: Fixture = serde_json::from_str(&content)
+                .with_context(|| format!("Invalid fixture {}", path.display()))?;
+            fixtures.push(fixture);
+        }
+
+        Ok(Self::from_fixtures(fixtures))
+    }
+
+    /// @ai:intent Build a replay client from in-memory fixtures
+    /// @ai:effects pure
+    pub fn from_fixtures(fixtures: Vec<Fixture>) -> Self {
+        let mut by_execution: HashMap<_, Vec<Fixture>> = HashMap::new();
+
+        for fixture in fixtures {
+            by_execution
+                .entry((
+                    fixture.task_id.clone(),
+                    fixture.mode.clone(),
+                    fixture.repetition,
+                ))
+                .or_default()
+                .push(fixture);
+        }
+
+        Self { by_execution }
+    }
+
+    /// @ai:intent Number of loaded fixtures
+    /// @ai:effects pure
+    pub fn len(&self) -> usize {
+        self.by_execution.values().map(Vec::len).sum()
+    }
+
+    /// @ai:intent Check if no fixtures were loaded
+    /// @ai:effects pure
+    pub fn is_empty(&self) -> bool {
+        self.by_execution.is_empty()
+    }
+
+    /// @ai:intent Find the fixture for a request among those of its task, mode, and
+    ///            repetition: the one with the same prompt, else the single one recorded
+    ///            (the prompt changed since recording)
+    /// @ai:effects pure
+    fn find(&self, prompt: &str, system: Option<&str>, context: &TaskContext) -> Option<&Fixture> {
+        let execution = (
+            context.task_id.clone(),
+            context.mode.clone(),
+            context.repetition,
+        );
+        let fixtures = self.by_execution.get(&execution)?;
+
+        let key = fixture_key(prompt, system);
+        if let Some(fixture) = fixtures.iter().find(|f| f.key == key) {
+            return Some(fixture);
+        }
+
+        match fixtures.as_slice() {
+            [fixture] => {
+                tracing::warn!(
+                    "Prompt for {} (mode={}, rep={}) changed since recording; replaying anyway",
+                    context.task_id,
+                    context.mode,
+                    context.repetition
+                );
+                Some(fixture)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ClaudeClientTrait for ReplayClient {
+    /// @ai:intent Return the recorded response for the request
+    /// @ai:effects pure
+    async fn send_message(
+        &self,
+        prompt: &str,
+        system: Option<&str>,
+        context: &TaskContext,
+    ) -> Result<ClaudeResponse> {
+        self.find(prompt, system, context)
+            .map(|f| f.response.clone())
+            .with_context(|| {
+                format!(
+                    "No fixture recorded for {} (mode={}, rep={})",
+                    context.task_id, context.mode, context.repetition
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::client::MockClaudeClient;
+    use tempfile::TempDir;
+
+    fn context(repetition: u32) -> TaskContext {
+        mode_context("aicms", repetition)
+    }
+
+    fn mode_context(mode: &str, repetition: u32) -> TaskContext {
+        TaskContext {
+            task_id: "impl-rust-factorial".to_string(),
+            mode: mode.to_string(),
+            use_aicms_skill: mode == "aicms",
+            repetition,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let temp = TempDir::new().unwrap();
+        let recorder =
+            RecordingClient::new(MockClaudeClient::new("fn f() {}".to_string()), temp.path())
+                .unwrap();
+        recorder
+            .send_message("prompt", Some("system"), &context(0))
+            .await
+            .unwrap();
+
+        let replay = ReplayClient::load(temp.path()).unwrap();
+        assert_eq!(replay.len(), 1);
+
+        let response = replay
+            .send_message("prompt", Some("system"), &context(0))
+            .await
+            .unwrap();
+        assert_eq!(response.content, "fn f() {}");
+        assert_eq!(response.output_tokens, 200);
+    }
+
+    #[tokio::test]
+    async fn test_replay_falls_back_to_execution_and_fails_when_missing() {
+        let temp = TempDir::new().unwrap();
+        let recorder =
+            RecordingClient::new(MockClaudeClient::new("answer".to_string()), temp.path()).unwrap();
+        recorder
+            .send_message("old prompt", None, &context(0))
+            .await
+            .unwrap();
+        let replay = ReplayClient::load(temp.path()).unwrap();
+
+        let changed = replay
+            .send_message("new prompt", None, &context(0))
+            .await
+            .unwrap();
+        assert_eq!(changed.content, "answer");
+        assert!(replay
+            .send_message("new prompt", None, &context(1))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_identical_prompts_replay_their_own_execution() {
+        let temp = TempDir::new().unwrap();
+        let executions = [("baseline", 0), ("baseline", 1), ("aicms", 0), ("aicms", 1)];
+        for (mode, repetition) in executions {
+            let answer = format!("{} {}", mode, repetition);
+            let recorder =
+                RecordingClient::new(MockClaudeClient::new(answer), temp.path()).unwrap();
+            recorder
+                .send_message("prompt", None, &mode_context(mode, repetition))
+                .await
+                .unwrap();
+        }
+        let replay = ReplayClient::load(temp.path()).unwrap();
+        assert_eq!(replay.len(), 4);
+
+        for (mode, repetition) in executions {
+            let response = replay
+                .send_message("prompt", None, &mode_context(mode, repetition))
+                .await
+                .unwrap();
+            assert_eq!(response.content, format!("{} {}", mode, repetition));
+        }
+    }
+}
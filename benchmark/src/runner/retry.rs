@@ -0,0 +1,162 @@
+//! @ai:module:intent Exponential backoff with jitter for retrying client requests
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api backoff_delay, retry_with_backoff
+//! @ai:module:stateless true
+
+use crate::config::RetryConfig;
+use anyhow::Result;
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// @ai:intent Compute the delay before the given retry (1-based)
+///            Doubles from initial_backoff_ms, capped at max_backoff_ms, then
+///            scaled by a random factor in [1 - jitter, 1 + jitter]
+/// @ai:pre retry >= 1
+/// @ai:effects time
+pub fn backoff_delay(config: &RetryConfig, retry: u32) -> Duration {
+    let exponent = retry.saturating_sub(1).min(31);
+    let base = config
+        .initial_backoff_ms
+        .saturating_mul(1u64 << exponent)
+        .min(config.max_backoff_ms);
+
+    let jitter = config.jitter.clamp(0.0, 1.0);
+    let factor = 1.0 + jitter * (2.0 * random_unit() - 1.0);
+
+    Duration::from_millis((base as f64 * factor).round() as u64)
+}
+
+/// @ai:intent Run an async operation, retrying failures with exponential backoff
+/// @ai:post returns the first success together with the number of retries used,
+///          or the last error once max_attempts is exhausted
+/// @ai:effects time
+pub async fn retry_with_backoff<T, F, Fut>(
+    config: &RetryConfig,
+    label: &str,
+    mut operation: F,
+) -> Result<(T, u32)>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let max_attempts = config.max_attempts.max(1);
+    let mut retries = 0;
+
+    loop {
+        match operation().await {
+            Ok(value) => return Ok((value, retries)),
+            Err(e) if retries + 1 < max_attempts => {
+                retries += 1;
+                let delay = backoff_delay(config, retries);
+                tracing::warn!(
+                    "{} failed (attempt {}/{}): {}. Retrying in {:?}",
+                    label,
+                    retries,
+                    max_attempts,
+                    e,
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+            }
+            Err(e) => {
+                return Err(e.context(format!("{} failed after {} attempts", label, retries + 1)))
+            }
+        }
+    }
+}
+
+/// @ai:intent Seed for a run's pseudo-random generators, taken from the clock
+/// @ai:effects time
+pub(crate) fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+/// @ai:intent Cheap pseudo-random number in [0, 1) for jitter
+/// @ai:effects time
+fn random_unit() -> f64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+
+    // Scramble the low-entropy clock value (SplitMix64 finalizer)
+    let mut x = nanos as u64;
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^= x >> 31;
+
+    (x >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn no_jitter(max_attempts: u32) -> RetryConfig {
+        RetryConfig {
+            max_attempts,
+            initial_backoff_ms: 1,
+            max_backoff_ms: 4,
+            jitter: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        let config = no_jitter(5);
+        assert_eq!(backoff_delay(&config, 1), Duration::from_millis(1));
+        assert_eq!(backoff_delay(&config, 2), Duration::from_millis(2));
+        assert_eq!(backoff_delay(&config, 3), Duration::from_millis(4));
+        assert_eq!(backoff_delay(&config, 10), Duration::from_millis(4));
+    }
+
+    #[test]
+    fn test_backoff_jitter_bounds() {
+        let config = RetryConfig {
+            initial_backoff_ms: 1000,
+            max_backoff_ms: 1000,
+            jitter: 0.5,
+            ..no_jitter(3)
+        };
+
+        for _ in 0..20 {
+            let delay = backoff_delay(&config, 1).as_millis();
+            assert!((500..=1500).contains(&delay));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_until_success() {
+        let calls = AtomicU32::new(0);
+
+        let (value, retries) = retry_with_backoff(&no_jitter(3), "op", || async {
+            if calls.fetch_add(1, Ordering::SeqCst) < 2 {
+                anyhow::bail!("overloaded")
+            }
+            Ok(42)
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(value, 42);
+        assert_eq!(retries, 2);
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up() {
+        let calls = AtomicU32::new(0);
+
+        let result: Result<((), u32)> = retry_with_backoff(&no_jitter(2), "op", || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            anyhow::bail!("overloaded")
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
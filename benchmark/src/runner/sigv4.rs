@@ -0,0 +1,235 @@
+//! @ai:module:intent AWS Signature Version 4 request signing (used by the Bedrock client)
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api AwsCredentials, SigV4Request, sign
+//! @ai:module:stateless true
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// @ai:intent AWS credentials used to sign requests
+#[derive(Debug, Clone)]
+pub struct AwsCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+}
+
+impl AwsCredentials {
+    /// @ai:intent Read credentials from the standard AWS environment variables
+    /// @ai:pre AWS_ACCESS_KEY_ID and AWS_SECRET_ACCESS_KEY are set
+    /// @ai:effects env
+    pub fn from_env() -> Result<Self> {
+        Ok(Self {
+            access_key_id: std::env::var("AWS_ACCESS_KEY_ID")
+                .context("AWS_ACCESS_KEY_ID not set in environment")?,
+            secret_access_key: std::env::var("AWS_SECRET_ACCESS_KEY")
+                .context("AWS_SECRET_ACCESS_KEY not set in environment")?,
+            session_token: std::env::var("AWS_SESSION_TOKEN").ok(),
+        })
+    }
+}
+
+/// @ai:intent The parts of an HTTP request covered by the signature
+#[derive(Debug, Clone)]
+pub struct SigV4Request<'a> {
+    pub method: &'a str,
+    pub host: &'a str,
+    /// Path as sent on the wire (segments already URI-encoded once)
+    pub path: &'a str,
+    /// Canonical (sorted, encoded) query string, empty if none
+    pub query: &'a str,
+    /// Extra headers to sign besides host and x-amz-date (lowercase names)
+    pub headers: &'a [(&'a str, &'a str)],
+    pub payload: &'a [u8],
+}
+
+/// @ai:intent Sign a request, returning the headers to add to it
+/// @ai:post result contains authorization and x-amz-date
+///          (plus x-amz-security-token for temporary credentials)
+/// @ai:effects pure
+pub fn sign(
+    request: &SigV4Request,
+    credentials: &AwsCredentials,
+    region: &str,
+    service: &str,
+    now: DateTime<Utc>,
+) -> Vec<(String, String)> {
+    let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let date = now.format("%Y%m%d").to_string();
+
+    let mut headers: Vec<(String, String)> = request
+        .headers
+        .iter()
+        .map(|(name, value)| (name.to_lowercase(), value.trim().to_string()))
+        .collect();
+    headers.push(("host".to_string(), request.host.to_string()));
+    headers.push(("x-amz-date".to_string(), amz_date.clone()));
+    if let Some(token) = &credentials.session_token {
+        headers.push(("x-amz-security-token".to_string(), token.clone()));
+    }
+    headers.sort();
+
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value))
+        .collect();
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method,
+        canonical_uri(request.path),
+        request.query,
+        canonical_headers,
+        signed_headers,
+        hex::encode(Sha256::digest(request.payload))
+    );
+
+    let scope = format!("{}/{}/{}/aws4_request", date, region, service);
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        amz_date,
+        scope,
+        hex::encode(Sha256::digest(canonical_request.as_bytes()))
+    );
+
+    let key = signing_key(&credentials.secret_access_key, &date, region, service);
+    let signature = hex::encode(hmac(&key, string_to_sign.as_bytes()));
+
+    let mut result = vec![
+        (
+            "authorization".to_string(),
+            format!(
+                "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+                credentials.access_key_id, scope, signed_headers, signature
+            ),
+        ),
+        ("x-amz-date".to_string(), amz_date),
+    ];
+
+    if let Some(token) = &credentials.session_token {
+        result.push(("x-amz-security-token".to_string(), token.clone()));
+    }
+
+    result
+}
+
+/// @ai:intent URI-encode a path segment per RFC 3986 (unreserved characters kept)
+/// @ai:effects pure
+/// @ai:example ("anthropic.claude-v2:1") -> "anthropic.claude-v2%3A1"
+pub fn uri_encode(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// @ai:intent Canonical URI: every segment of the wire path encoded once more
+///            (all services except S3 double-encode)
+/// @ai:effects pure
+fn canonical_uri(path: &str) -> String {
+    if path.is_empty() {
+        return "/".to_string();
+    }
+
+    path.split('/')
+        .map(uri_encode)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// @ai:intent Derive the request signing key for a date, region, and service
+/// @ai:effects pure
+fn signing_key(secret: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let k_date = hmac(format!("AWS4{}", secret).as_bytes(), date.as_bytes());
+    let k_region = hmac(&k_date, region.as_bytes());
+    let k_service = hmac(&k_region, service.as_bytes());
+    hmac(&k_service, b"aws4_request")
+}
+
+/// @ai:intent HMAC-SHA256 of data under key
+/// @ai:effects pure
+fn hmac(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_credentials() -> AwsCredentials {
+        AwsCredentials {
+            access_key_id: "AKIDEXAMPLE".to_string(),
+            secret_access_key: "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_string(),
+            session_token: None,
+        }
+    }
+
+    #[test]
+    fn test_signing_key_matches_aws_example() {
+        let key = signing_key(
+            "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY",
+            "20150830",
+            "us-east-1",
+            "iam",
+        );
+        assert_eq!(
+            hex::encode(key),
+            "c4afb1cc5771d871763a393e44b703571b55cc28424d1a5e86da6ed3c154a4b9"
+        );
+    }
+
+    #[test]
+    fn test_sign_matches_aws_example() {
+        let request = SigV4Request {
+            method: "GET",
+            host: "iam.amazonaws.com",
+            path: "/",
+            query: "Action=ListUsers&Version=2010-05-08",
+            headers: &[(
+                "content-type",
+                "application/x-www-form-urlencoded; charset=utf-8",
+            )],
+            payload: b"",
+        };
+        let now = "2015-08-30T12:36:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let headers = sign(&request, &example_credentials(), "us-east-1", "iam", now);
+
+        assert_eq!(
+            headers[0].1,
+            "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/iam/aws4_request, \
+             SignedHeaders=content-type;host;x-amz-date, \
+             Signature=5d672d79c15b13162d9279b0855cfba6789a8edb4c82c400e06b5924a6f2b5d7"
+        );
+        assert_eq!(
+            headers[1],
+            ("x-amz-date".to_string(), "20150830T123600Z".to_string())
+        );
+    }
+
+    #[test]
+    fn test_canonical_uri_double_encodes() {
+        let path = format!("/model/{}/invoke", uri_encode("anthropic.claude-v2:1"));
+        assert_eq!(path, "/model/anthropic.claude-v2%3A1/invoke");
+        assert_eq!(
+            canonical_uri(&path),
+            "/model/anthropic.claude-v2%253A1/invoke"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! @ai:module:intent Server-sent event parsing for streamed Claude API responses
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api SseParser, SseEvent, StreamAccumulator
+//! @ai:module:stateless false
+
+use crate::runner::client::ClaudeResponse;
+use anyhow::Result;
+use serde::Deserialize;
+
+/// @ai:intent One server-sent event
+#[derive(Debug, Clone, PartialEq)]
+pub struct SseEvent {
+    pub event: String,
+    pub data: String,
+}
+
+/// @ai:intent Incremental SSE parser; bytes may arrive split at any point
+#[derive(Debug, Default)]
+pub struct SseParser {
+    /// Raw bytes, so multi-byte characters split across chunks stay intact
+    buffer: Vec<u8>,
+}
+
+impl SseParser {
+    /// @ai:intent Create an empty parser
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// @ai:intent Add received bytes and return every event completed by them
+    /// @ai:effects pure
+    pub fn feed(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        self.buffer.extend(chunk.iter().filter(|&&b| b != b'\r'));
+
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.windows(2).position(|w| w == b"\n\n") {
+            let block: Vec<u8> = self.buffer.drain(..end + 2).collect();
+            if let Some(event) = parse_block(&String::from_utf8_lossy(&block)) {
+                events.push(event);
+            }
+        }
+        events
+    }
+}
+
+/// @ai:intent Parse one blank-line-terminated SSE block (comments and ids ignored)
+/// @ai:effects pure
+fn parse_block(block: &str) -> Option<SseEvent> {
+    let mut event = String::from("message");
+    let mut data = Vec::new();
+
+    for line in block.lines() {
+        if let Some(value) = line.strip_prefix("event:") {
+            event = value.trim().to_string();
+        } else if let Some(value) = line.strip_prefix("data:") {
+            data.push(value.strip_prefix(' ').unwrap_or(value));
+        }
+    }
+
+    (!data.is_empty()).then(|| SseEvent {
+        event,
+        data: data.join("\n"),
+    })
+}
+
+/// @ai:intent Stream event payloads of the Messages API (only the fields used here)
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum StreamPayload {
+    MessageStart {
+        message: StartMessage,
+    },
+    ContentBlockDelta {
+        delta: Delta,
+    },
+    MessageDelta {
+        delta: MessageDeltaBody,
+        usage: StreamUsage,
+    },
+    Error {
+        error: StreamError,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+struct StartMessage {
+    usage: StreamUsage,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Delta {
+    TextDelta {
+        text: String,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessageDeltaBody {
+    #[serde(default)]
+    stop_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamUsage {
+    #[serde(default)]
+    input_tokens: Option<u32>,
+    #[serde(default)]
+    output_tokens: Option<u32>,
+    #[serde(default)]
+    cache_creation_input_tokens: Option<u32>,
+    #[serde(default)]
+    cache_read_input_tokens: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamError {
+    #[serde(rename = "type")]
+    kind: String,
+    message: String,
+}
+
+/// @ai:intent Builds a ClaudeResponse from stream events
+///            Usage comes from message_start and is overwritten by the final message_delta
+#[derive(Debug, Default)]
+pub struct StreamAccumulator {
+    content: String,
+    input_tokens: u32,
+    output_tokens: u32,
+    cache_creation_input_tokens: u32,
+    cache_read_input_tokens: u32,
+    stop_reason: Option<String>,
+}
+
+impl StreamAccumulator {
+    /// @ai:intent Create an empty accumulator
+    /// @ai:effects pure
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// @ai:intent Apply one event; returns the number of text bytes it added
+    /// @ai:post error events are returned as Err
+    /// @ai:effects pure
+    pub fn apply(&mut self, event: &SseEvent) -> Result<usize> {
+        let payload: StreamPayload = match serde_json::from_str(&event.data) {
+            Ok(payload) => payload,
+            // Unknown or non-JSON events (e.g. future event types) are skipped
+            Err(_) => return Ok(0),
+        };
+
+        match payload {
+            StreamPayload::MessageStart { message } => {
+                self.input_tokens = message.usage.input_tokens.unwrap_or(0);
+                self.output_tokens = message.usage.output_tokens.unwrap_or(0);
+                self.cache_creation_input_tokens =
+                    message.usage.cache_creation_input_tokens.unwrap_or(0);
+                self.cache_read_input_tokens = message.usage.cache_read_input_tokens.unwrap_or(0);
+            }
+            StreamPayload::ContentBlockDelta {
+                delta: Delta::TextDelta { text },
+            } => {
+                self.content.push_str(&text);
+                return Ok(text.len());
+            }
+            StreamPayload::MessageDelta { delta, usage } => {
+                if let Some(input_tokens) = usage.input_tokens {
+                    self.input_tokens = input_tokens;
+                }
+                if let Some(output_tokens) = usage.output_tokens {
+                    self.output_tokens = output_tokens;
+                }
+                if let Some(tokens) = usage.cache_creation_input_tokens {
+                    self.cache_creation_input_tokens = tokens;
+                }
+                if let Some(tokens) = usage.cache_read_input_tokens {
+                    self.cache_read_input_tokens = tokens;
+                }
+                if delta.stop_reason.is_some() {
+                    self.stop_reason = delta.stop_reason;
+                }
+            }
+            StreamPayload::Error { error } => {
+                anyhow::bail!(
+                    "Claude API stream error ({}): {}",
+                    error.kind,
+                    error.message
+                );
+            }
+            StreamPayload::ContentBlockDelta { .. } | StreamPayload::Other => {}
+        }
+
+        Ok(0)
+    }
+
+    /// @ai:intent Text received so far
+    /// @ai:effects pure
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// @ai:intent Finish the stream
+    /// @ai:pre the stream ended (message_stop or connection closed)
+    /// @ai:effects pure
+    pub fn finish(self) -> Result<ClaudeResponse> {
+        let stop_reason = match self.stop_reason {
+            Some(stop_reason) => stop_reason,
+            None => anyhow::bail!(
+                "Claude API stream ended before the final message_delta ({} bytes received)",
+                self.content.len()
+            ),
+        };
+
+        Ok(ClaudeResponse {
+            content: self.content,
+            // Report all prompt tokens, like non-streamed responses
+            input_tokens: self.input_tokens
+                + self.cache_creation_input_tokens
+                + self.cache_read_input_tokens,
+            output_tokens: self.output_tokens,
+            stop_reason,
+            cache_creation_input_tokens: self.cache_creation_input_tokens,
+            cache_read_input_tokens: self.cache_read_input_tokens,
+            num_turns: None,
+            cost_usd: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STREAM: &str = "event: message_start\n\
+data: {\"type\":\"message_start\",\"message\":{\"id\":\"msg_1\",\"usage\":{\"input_tokens\":25,\"cache_read_input_tokens\":1000,\"output_tokens\":1}}}\n\
+\n\
+event: ping\n\
+data: {\"type\": \"ping\"}\n\
+\n\
+event: content_block_delta\n\
+data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"fn main\"}}\n\
+\n\
+event: content_block_delta\n\
+data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"() {}\"}}\n\
+\n\
+event: message_delta\n\
+data: {\"type\":\"message_delta\",\"delta\":{\"stop_reason\":\"end_turn\"},\"usage\":{\"output_tokens\":15}}\n\
+\n\
+event: message_stop\n\
+data: {\"type\":\"message_stop\"}\n\
+\n";
+
+    #[test]
+    fn test_parser_handles_split_chunks() {
+        let mut parser = SseParser::new();
+        let bytes = STREAM.as_bytes();
+
+        let mut events = parser.feed(&bytes[..40]);
+        events.extend(parser.feed(&bytes[40..200]));
+        events.extend(parser.feed(&bytes[200..]));
+
+        assert_eq!(events.len(), 6);
+        assert_eq!(events[0].event, "message_start");
+        assert_eq!(events[5].event, "message_stop");
+    }
+
+    #[test]
+    fn test_accumulator_uses_final_usage() {
+        let mut accumulator = StreamAccumulator::new();
+        for event in SseParser::new().feed(STREAM.as_bytes()) {
+            accumulator.apply(&event).unwrap();
+        }
+
+        let response = accumulator.finish().unwrap();
+        assert_eq!(response.content, "fn main() {}");
+        assert_eq!(response.input_tokens, 1025);
+        assert_eq!(response.cache_read_input_tokens, 1000);
+        assert_eq!(response.output_tokens, 15);
+        assert_eq!(response.stop_reason, "end_turn");
+    }
+
+    #[test]
+    fn test_accumulator_reports_errors_and_truncation() {
+        let error = SseEvent {
+            event: "error".to_string(),
+            data: r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#
+                .to_string(),
+        };
+        let mut accumulator = StreamAccumulator::new();
+        assert!(accumulator
+            .apply(&error)
+            .unwrap_err()
+            .to_string()
+            .contains("overloaded_error"));
+        assert!(accumulator.finish().is_err());
+    }
+}
@@ -0,0 +1,173 @@
+//! @ai:module:intent Claude client for Google Cloud Vertex AI (rawPredict with ADC auth)
+//! @ai:module:layer infrastructure
+//! @ai:module:public_api VertexClient
+//! @ai:module:depends_on runner::gcp_auth
+//! @ai:module:stateless false
+
+use crate::config::{ApiConfig, VertexConfig};
+use crate::runner::client::{ApiResponse, ClaudeClientTrait, ClaudeResponse, TaskContext};
+use crate::runner::gcp_auth::{GcpCredentials, GcpTokenProvider};
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+/// @ai:intent Anthropic API version string required by Vertex AI
+const VERTEX_ANTHROPIC_VERSION: &str = "vertex-2023-10-16";
+
+/// @ai:intent Vertex rawPredict body for Anthropic models (model is in the URL)
+#[derive(Debug, Serialize)]
+struct PredictRequest<'a> {
+    anthropic_version: &'static str,
+    max_tokens: u32,
+    temperature: f32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<&'a str>,
+    messages: Vec<Message<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct Message<'a> {
+    role: &'static str,
+    content: &'a str,
+}
+
+/// @ai:intent Claude client that calls Vertex AI instead of the Anthropic API
+pub struct VertexClient {
+    client: reqwest::Client,
+    api: ApiConfig,
+    project_id: String,
+    region: String,
+    model: String,
+    tokens: GcpTokenProvider,
+}
+
+impl VertexClient {
+    /// @ai:intent Create a Vertex client from config and Application Default Credentials
+    /// @ai:pre project is configured or GOOGLE_CLOUD_PROJECT is set
+    /// @ai:effects env, fs:read
+    pub fn new(config: VertexConfig, api: ApiConfig) -> Result<Self> {
+        let credentials = GcpCredentials::from_env()?;
+        let project_id = match config.project_id {
+            Some(project_id) => project_id,
+            None => std::env::var("GOOGLE_CLOUD_PROJECT").context(
+                "No Vertex project: set backend.vertex.project_id or GOOGLE_CLOUD_PROJECT",
+            )?,
+        };
+
+        Self::with_credentials(project_id, config.region, config.model, api, credentials)
+    }
+
+    /// @ai:intent Create a Vertex client with explicit credentials
+    /// @ai:effects pure
+    pub fn with_credentials(
+        project_id: String,
+        region: String,
+        model: String,
+        api: ApiConfig,
+        credentials: GcpCredentials,
+    ) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(120))
+            .build()
+            .context("Failed to create HTTP client")?;
+
+        Ok(Self {
+            client,
+            api,
+            project_id,
+            region,
+            model,
+            tokens: GcpTokenProvider::new(credentials)?,
+        })
+    }
+
+    /// @ai:intent rawPredict URL for the configured project, region, and model
+    ///            The "global" region uses the non-regional host
+    /// @ai:effects pure
+    fn predict_url(&self) -> String {
+        let host = if self.region == "global" {
+            "aiplatform.googleapis.com".to_string()
+        } else {
+            format!("{}-aiplatform.googleapis.com", self.region)
+        };
+
+        format!(
+            "https://{}/v1/projects/{}/locations/{}/publishers/anthropic/models/{}:rawPredict",
+            host, self.project_id, self.region, self.model
+        )
+    }
+}
+
+impl ClaudeClientTrait for VertexClient {
+    /// @ai:intent Send a message to Claude via Vertex AI rawPredict
+    /// @ai:effects network, io, time
+    async fn send_message(
+        &self,
+        prompt: &str,
+        system: Option<&str>,
+        _context: &TaskContext,
+    ) -> Result<ClaudeResponse> {
+        let request = PredictRequest {
+            anthropic_version: VERTEX_ANTHROPIC_VERSION,
+            max_tokens: self.api.max_tokens,
+            temperature: self.api.temperature,
+            system,
+            messages: vec![Message {
+                role: "user",
+                content: prompt,
+            }],
+        };
+
+        let token = self.tokens.access_token().await?;
+
+        let response = self
+            .client
+            .post(self.predict_url())
+            .bearer_auth(token)
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to send request to Vertex AI")?;
+
+        let status = response.status();
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            anyhow::bail!("Vertex AI error ({}): {}", status, error_text);
+        }
+
+        let api_response: ApiResponse = response
+            .json()
+            .await
+            .context("Failed to parse Vertex AI response")?;
+
+        Ok(api_response.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(region: &str) -> VertexClient {
+        VertexClient::with_credentials(
+            "my-project".to_string(),
+            region.to_string(),
+            "claude-sonnet-4@20250514".to_string(),
+            ApiConfig::default(),
+            GcpCredentials::AccessToken("token".to_string()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_predict_url() {
+        assert_eq!(
+            client("us-east5").predict_url(),
+            "https://us-east5-aiplatform.googleapis.com/v1/projects/my-project/locations/us-east5/\
+             publishers/anthropic/models/claude-sonnet-4@20250514:rawPredict"
+        );
+        assert!(client("global").predict_url().starts_with(
+            "https://aiplatform.googleapis.com/v1/projects/my-project/locations/global/"
+        ));
+    }
+}
@@ -4,6 +4,7 @@
 //! @ai:module:stateless true
 
 use crate::corpus::Language;
+use crate::platform::resolve_executable;
 use std::collections::HashSet;
 use std::process::Command;
 
@@ -53,6 +54,16 @@ impl ToolchainValidator {
                 compiler: "tsc",
                 test_args: &["--version"],
             },
+            LanguageTools {
+                language: Language::Go,
+                compiler: "go",
+                test_args: &["version"],
+            },
+            LanguageTools {
+                language: Language::Java,
+                compiler: "javac",
+                test_args: &["-version"],
+            },
         ]
     }
 
@@ -63,6 +74,8 @@ impl ToolchainValidator {
             "rustc" => "Install Rust: https://rustup.rs/",
             "python" => "Install Python: https://www.python.org/downloads/",
             "tsc" => "Install TypeScript: npm install -g typescript",
+            "go" => "Install Go: https://go.dev/dl/",
+            "javac" => "Install a JDK: https://adoptium.net/",
             _ => "Check tool documentation for installation instructions",
         }
     }
@@ -70,7 +83,7 @@ impl ToolchainValidator {
     /// @ai:intent Check if a command is available on the system
     /// @ai:effects io
     fn is_tool_available(tool: &str, args: &[&str]) -> bool {
-        Command::new(tool)
+        Command::new(resolve_executable(tool))
             .args(args)
             .output()
             .map(|output| output.status.success())
@@ -122,7 +135,7 @@ mod tests {
     #[test]
     fn test_get_language_tools_returns_all_languages() {
         let tools = ToolchainValidator::get_language_tools();
-        assert_eq!(tools.len(), 3);
+        assert_eq!(tools.len(), 5);
     }
 
     #[test]
@@ -130,6 +143,8 @@ mod tests {
         assert!(ToolchainValidator::get_install_hint("rustc").contains("rustup"));
         assert!(ToolchainValidator::get_install_hint("python").contains("python.org"));
         assert!(ToolchainValidator::get_install_hint("tsc").contains("npm"));
+        assert!(ToolchainValidator::get_install_hint("go").contains("go.dev"));
+        assert!(ToolchainValidator::get_install_hint("javac").contains("JDK"));
     }
 
     #[test]
@@ -1,11 +1,57 @@
 //! @ai:module:intent Define data structures for AICMS annotations
 //! @ai:module:layer domain
-//! @ai:module:public_api Annotation, AnnotationType, FunctionAnnotations, ModuleAnnotations, Location
+//! @ai:module:public_api Annotation, AnnotationType, FunctionAnnotations, DeclarationAnnotations, ModuleAnnotations, Location
 //! @ai:module:stateless true
 
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+/// @ai:intent Effect values defined by the AICMS specification
+pub const STANDARD_EFFECTS: &[&str] = &[
+    "pure",
+    "io",
+    "db:read",
+    "db:write",
+    "network",
+    "fs:read",
+    "fs:write",
+    "env",
+    "state:read",
+    "state:write",
+    "random",
+    "time",
+];
+
+/// @ai:intent Project-level settings defined by the AICMS specification (`project:<tag>`)
+pub const PROJECT_TAGS: &[&str] = &[
+    "max_function_lines",
+    "max_file_lines",
+    "max_functions_per_file",
+    "max_structs_per_module",
+    "max_params",
+    "max_return_values",
+    "max_nesting_depth",
+    "max_cyclomatic_complexity",
+    "extract_repeated_code",
+    "require_interface_for_deps",
+    "single_responsibility",
+    "prefer_composition",
+    "no_god_objects",
+    "no_primitive_obsession",
+    "immutable_by_default",
+    "architecture",
+    "layers",
+    "dependency_rule",
+    "error_strategy",
+    "require_error_types",
+    "no_panic",
+    "min_coverage",
+    "unit_tests",
+    "integration_tests",
+    "integration_tests_tools",
+    "test_naming",
+];
+
 /// @ai:intent Represents a source code location
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Location {
@@ -31,9 +77,29 @@ pub enum AnnotationLevel {
     Project,
     Module,
     Function,
+    Declaration,
     Test,
 }
 
+/// @ai:intent Kind of annotated declaration (`var:` or `const:` tags)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DeclarationKind {
+    Variable,
+    Constant,
+}
+
+impl DeclarationKind {
+    /// @ai:intent Tag namespace for this kind
+    /// @ai:effects pure
+    pub fn namespace(&self) -> &'static str {
+        match self {
+            DeclarationKind::Variable => "var",
+            DeclarationKind::Constant => "const",
+        }
+    }
+}
+
 /// @ai:intent Represents a single parsed annotation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Annotation {
@@ -41,6 +107,13 @@ pub struct Annotation {
     pub tag: String,
     pub value: String,
     pub location: Location,
+    /// Sigil the annotation was written with (e.g. `@ai:`)
+    #[serde(default = "default_prefix")]
+    pub prefix: String,
+}
+
+fn default_prefix() -> String {
+    crate::prefix::DEFAULT_PREFIX.to_string()
 }
 
 /// @ai:intent Collection of annotations for a function
@@ -69,6 +142,21 @@ pub struct FunctionAnnotations {
     pub test_integration: Option<String>,
 }
 
+/// @ai:intent Annotations attached to a constant or variable declaration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeclarationAnnotations {
+    pub name: String,
+    pub kind: DeclarationKind,
+    pub location: Location,
+    /// Whether the declaration is exported/public
+    pub public: bool,
+    pub intent: Option<String>,
+    /// Unit of measure (e.g. `ms`, `bytes`, `EUR`)
+    pub unit: Option<String>,
+    /// Allowed values (e.g. `0..=100`)
+    pub range: Option<String>,
+}
+
 /// @ai:intent Collection of annotations for a module/file
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ModuleAnnotations {
@@ -84,6 +172,8 @@ pub struct ModuleAnnotations {
     pub cohesion: Option<String>,
     pub stability: Option<String>,
     pub functions: Vec<FunctionAnnotations>,
+    #[serde(default)]
+    pub declarations: Vec<DeclarationAnnotations>,
 }
 
 /// @ai:intent Complete parsed result for a file
@@ -129,6 +219,26 @@ impl FunctionAnnotations {
     }
 }
 
+impl DeclarationAnnotations {
+    /// @ai:intent Create a new DeclarationAnnotations with no tags
+    pub fn new(name: String, kind: DeclarationKind, public: bool, location: Location) -> Self {
+        Self {
+            name,
+            kind,
+            location,
+            public,
+            intent: None,
+            unit: None,
+            range: None,
+        }
+    }
+
+    /// @ai:intent Check if the declaration has any annotations at all
+    pub fn is_annotated(&self) -> bool {
+        self.intent.is_some() || self.unit.is_some() || self.range.is_some()
+    }
+}
+
 impl Location {
     /// @ai:intent Create a new Location
     pub fn new(file: PathBuf, line: usize) -> Self {
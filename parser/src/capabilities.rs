@@ -0,0 +1,110 @@
+//! @ai:module:intent Machine-readable listing of what this parser build supports
+//! @ai:module:layer application
+//! @ai:module:public_api Capabilities, capabilities
+//! @ai:module:depends_on language, extractor, annotation, linter, output, prefix
+//! @ai:module:stateless true
+
+use crate::annotation::{PROJECT_TAGS, STANDARD_EFFECTS};
+use crate::extractor::{DECLARATION_TAGS, FUNCTION_TAGS, MODULE_TAGS, TAG_FAMILIES};
+use crate::language::Language;
+use crate::linter::{LintRule, LINT_RULES};
+use crate::output::OutputFormat;
+use crate::prefix::DEFAULT_PREFIX;
+use serde::Serialize;
+
+/// @ai:intent Capabilities of the installed parser, built from its registries
+#[derive(Debug, Clone, Serialize)]
+pub struct Capabilities {
+    pub version: &'static str,
+    pub default_prefix: &'static str,
+    pub languages: Vec<LanguageCapability>,
+    pub tags: TagCapabilities,
+    pub effects: Vec<&'static str>,
+    pub lint_rules: Vec<LintRule>,
+    pub output_formats: Vec<&'static str>,
+    pub commands: Vec<CommandCapability>,
+}
+
+/// @ai:intent A supported language and the file extensions mapped to it
+#[derive(Debug, Clone, Serialize)]
+pub struct LanguageCapability {
+    pub name: &'static str,
+    pub extensions: Vec<&'static str>,
+}
+
+/// @ai:intent Annotation tags recognized by the extractor
+#[derive(Debug, Clone, Serialize)]
+pub struct TagCapabilities {
+    pub module: Vec<&'static str>,
+    pub function: Vec<&'static str>,
+    pub declaration: Vec<&'static str>,
+    pub project: Vec<&'static str>,
+    pub families: Vec<&'static str>,
+}
+
+/// @ai:intent A CLI command and its options
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandCapability {
+    pub name: String,
+    pub about: Option<String>,
+    pub options: Vec<String>,
+}
+
+impl Capabilities {
+    /// @ai:intent Attach the CLI command listing (only known to the binary)
+    /// @ai:effects pure
+    pub fn with_commands(mut self, commands: Vec<CommandCapability>) -> Self {
+        self.commands = commands;
+        self
+    }
+}
+
+/// @ai:intent Collect the capabilities of this library build
+/// @ai:effects pure
+pub fn capabilities() -> Capabilities {
+    Capabilities {
+        version: env!("CARGO_PKG_VERSION"),
+        default_prefix: DEFAULT_PREFIX,
+        languages: Language::ALL
+            .iter()
+            .map(|lang| LanguageCapability {
+                name: lang.name(),
+                extensions: lang.extensions().to_vec(),
+            })
+            .collect(),
+        tags: TagCapabilities {
+            module: MODULE_TAGS.to_vec(),
+            function: FUNCTION_TAGS.to_vec(),
+            declaration: DECLARATION_TAGS.to_vec(),
+            project: PROJECT_TAGS.to_vec(),
+            families: TAG_FAMILIES.to_vec(),
+        },
+        effects: STANDARD_EFFECTS.to_vec(),
+        lint_rules: LINT_RULES.to_vec(),
+        output_formats: OutputFormat::ALL.iter().map(|f| f.name()).collect(),
+        commands: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_capabilities_reflect_registries() {
+        let caps = capabilities();
+
+        assert_eq!(caps.languages.len(), Language::ALL.len());
+        assert!(caps
+            .languages
+            .iter()
+            .any(|l| l.name == "rust" && l.extensions == ["rs"]));
+        assert!(caps.tags.function.contains(&"intent"));
+        assert!(caps.effects.contains(&"pure"));
+        assert!(caps.lint_rules.iter().any(|r| r.code == "E001"));
+        assert!(caps.output_formats.contains(&"json-pretty"));
+
+        let json = serde_json::to_value(&caps).unwrap();
+        assert_eq!(json["default_prefix"], "@ai:");
+    }
+}
@@ -5,7 +5,8 @@
 //! @ai:module:stateless true
 
 use crate::annotation::{FunctionAnnotations, ParsedFile};
-use crate::extractor::extract_file;
+use crate::extractor::extract_file_with;
+use crate::prefix::PrefixConfig;
 use crate::error::Result;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
@@ -60,15 +61,27 @@ impl DiffResult {
 /// @ai:intent Compare two files and detect contract changes
 /// @ai:effects fs:read
 pub fn diff_files(old_path: &Path, new_path: &Path) -> Result<DiffResult> {
-    let old_parsed = extract_file(old_path)?;
-    let new_parsed = extract_file(new_path)?;
+    diff_files_with(old_path, new_path, &PrefixConfig::default())
+}
+
+/// @ai:intent Compare two files using configured annotation prefixes
+/// @ai:effects fs:read
+pub fn diff_files_with(old_path: &Path, new_path: &Path, prefixes: &PrefixConfig) -> Result<DiffResult> {
+    let old_parsed = extract_file_with(old_path, prefixes)?;
+    let new_parsed = extract_file_with(new_path, prefixes)?;
 
-    Ok(diff_parsed(&old_parsed, &new_parsed))
+    Ok(diff_parsed_with(&old_parsed, &new_parsed, prefixes))
 }
 
 /// @ai:intent Compare two parsed files
 /// @ai:effects pure
 pub fn diff_parsed(old: &ParsedFile, new: &ParsedFile) -> DiffResult {
+    diff_parsed_with(old, new, &PrefixConfig::default())
+}
+
+/// @ai:intent Compare two parsed files, reporting tags with the configured prefix
+/// @ai:effects pure
+pub fn diff_parsed_with(old: &ParsedFile, new: &ParsedFile, prefixes: &PrefixConfig) -> DiffResult {
     let mut result = DiffResult {
         file_path: new.path.display().to_string(),
         ..Default::default()
@@ -94,6 +107,10 @@ pub fn diff_parsed(old: &ParsedFile, new: &ParsedFile) -> DiffResult {
         }
     }
 
+    for change in &mut result.changes {
+        change.tag = prefixes.tag(&change.tag);
+    }
+
     result
 }
 
@@ -121,7 +138,7 @@ fn compare_functions(
             result.add_change(ContractChange {
                 function_name: func_name.clone(),
                 change_type: ChangeType::Breaking,
-                tag: "@ai:idempotent".to_string(),
+                tag: "idempotent".to_string(),
                 description: "Function is no longer idempotent".to_string(),
                 old_value: Some("true".to_string()),
                 new_value: new.idempotent.map(|v| v.to_string()),
@@ -130,7 +147,7 @@ fn compare_functions(
             result.add_change(ContractChange {
                 function_name: func_name.clone(),
                 change_type: ChangeType::NonBreaking,
-                tag: "@ai:idempotent".to_string(),
+                tag: "idempotent".to_string(),
                 description: "Function is now idempotent".to_string(),
                 old_value: old.idempotent.map(|v| v.to_string()),
                 new_value: Some("true".to_string()),
@@ -143,7 +160,7 @@ fn compare_functions(
         result.add_change(ContractChange {
             function_name: func_name.clone(),
             change_type: ChangeType::Notable,
-            tag: "@ai:intent".to_string(),
+            tag: "intent".to_string(),
             description: "Intent description changed".to_string(),
             old_value: old.intent.clone(),
             new_value: new.intent.clone(),
@@ -164,7 +181,7 @@ fn compare_functions(
             result.add_change(ContractChange {
                 function_name: func_name.clone(),
                 change_type,
-                tag: "@ai:confidence".to_string(),
+                tag: "confidence".to_string(),
                 description: format!(
                     "Confidence {} from {:.2} to {:.2}",
                     if new_conf < old_conf {
@@ -182,30 +199,24 @@ fn compare_functions(
     }
 
     // @ai:needs_review added (notable)
-    if old.needs_review.is_none() && new.needs_review.is_some() {
+    if let (None, Some(reason)) = (&old.needs_review, &new.needs_review) {
         result.add_change(ContractChange {
             function_name: func_name.clone(),
             change_type: ChangeType::Notable,
-            tag: "@ai:needs_review".to_string(),
-            description: format!(
-                "Review flag added: {}",
-                new.needs_review.as_ref().unwrap()
-            ),
+            tag: "needs_review".to_string(),
+            description: format!("Review flag added: {}", reason),
             old_value: None,
             new_value: new.needs_review.clone(),
         });
     }
 
     // @ai:deprecated added (notable)
-    if old.deprecated.is_none() && new.deprecated.is_some() {
+    if let (None, Some(reason)) = (&old.deprecated, &new.deprecated) {
         result.add_change(ContractChange {
             function_name: func_name.clone(),
             change_type: ChangeType::Notable,
-            tag: "@ai:deprecated".to_string(),
-            description: format!(
-                "Function deprecated: {}",
-                new.deprecated.as_ref().unwrap()
-            ),
+            tag: "deprecated".to_string(),
+            description: format!("Function deprecated: {}", reason),
             old_value: None,
             new_value: new.deprecated.clone(),
         });
@@ -225,7 +236,7 @@ fn compare_preconditions(
             result.add_change(ContractChange {
                 function_name: func_name.to_string(),
                 change_type: ChangeType::Breaking,
-                tag: "@ai:pre".to_string(),
+                tag: "pre".to_string(),
                 description: "Precondition strengthened (new requirement added)".to_string(),
                 old_value: None,
                 new_value: Some(new_cond.clone()),
@@ -239,7 +250,7 @@ fn compare_preconditions(
             result.add_change(ContractChange {
                 function_name: func_name.to_string(),
                 change_type: ChangeType::NonBreaking,
-                tag: "@ai:pre".to_string(),
+                tag: "pre".to_string(),
                 description: "Precondition weakened (requirement removed)".to_string(),
                 old_value: Some(old_cond.clone()),
                 new_value: None,
@@ -261,7 +272,7 @@ fn compare_postconditions(
             result.add_change(ContractChange {
                 function_name: func_name.to_string(),
                 change_type: ChangeType::Breaking,
-                tag: "@ai:post".to_string(),
+                tag: "post".to_string(),
                 description: "Postcondition weakened (guarantee removed)".to_string(),
                 old_value: Some(old_cond.clone()),
                 new_value: None,
@@ -275,7 +286,7 @@ fn compare_postconditions(
             result.add_change(ContractChange {
                 function_name: func_name.to_string(),
                 change_type: ChangeType::NonBreaking,
-                tag: "@ai:post".to_string(),
+                tag: "post".to_string(),
                 description: "Postcondition strengthened (new guarantee added)".to_string(),
                 old_value: None,
                 new_value: Some(new_cond.clone()),
@@ -299,7 +310,7 @@ fn compare_effects(
         result.add_change(ContractChange {
             function_name: func_name.to_string(),
             change_type: ChangeType::Breaking,
-            tag: "@ai:effects".to_string(),
+            tag: "effects".to_string(),
             description: "Function is no longer pure (side effects added)".to_string(),
             old_value: Some("pure".to_string()),
             new_value: Some(new_effects.join(", ")),
@@ -312,7 +323,7 @@ fn compare_effects(
         result.add_change(ContractChange {
             function_name: func_name.to_string(),
             change_type: ChangeType::NonBreaking,
-            tag: "@ai:effects".to_string(),
+            tag: "effects".to_string(),
             description: "Function is now pure (side effects removed)".to_string(),
             old_value: Some(old_effects.join(", ")),
             new_value: Some("pure".to_string()),
@@ -326,7 +337,7 @@ fn compare_effects(
             result.add_change(ContractChange {
                 function_name: func_name.to_string(),
                 change_type: ChangeType::Breaking,
-                tag: "@ai:effects".to_string(),
+                tag: "effects".to_string(),
                 description: format!("New side effect added: {}", new_effect),
                 old_value: None,
                 new_value: Some(new_effect.clone()),
@@ -340,7 +351,7 @@ fn compare_effects(
             result.add_change(ContractChange {
                 function_name: func_name.to_string(),
                 change_type: ChangeType::NonBreaking,
-                tag: "@ai:effects".to_string(),
+                tag: "effects".to_string(),
                 description: format!("Side effect removed: {}", old_effect),
                 old_value: Some(old_effect.clone()),
                 new_value: None,
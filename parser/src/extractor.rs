@@ -1,23 +1,89 @@
 //! @ai:module:intent Extract structured annotations from parsed comments
 //! @ai:module:layer application
-//! @ai:module:public_api extract_annotations, extract_file
+//! @ai:module:public_api extract_annotations, extract_file, is_known_tag, MODULE_TAGS, FUNCTION_TAGS,
+//!                        DECLARATION_TAGS
 //! @ai:module:depends_on annotation, parser, error
 //! @ai:module:stateless true
 
 use crate::annotation::{
-    Annotation, AnnotationLevel, FunctionAnnotations, Location, ModuleAnnotations, ParsedFile,
+    Annotation, AnnotationLevel, DeclarationAnnotations, FunctionAnnotations, Location,
+    ModuleAnnotations, ParsedFile, PROJECT_TAGS,
 };
 use crate::error::Result;
 use crate::parser::{parse_file, CommentBlock, ParsedSource};
-use regex::Regex;
+use crate::prefix::PrefixConfig;
 use std::path::Path;
 
+/// @ai:intent Module-level tags recognized by the extractor (`module:<tag>`)
+pub const MODULE_TAGS: &[&str] = &[
+    "intent",
+    "layer",
+    "public_api",
+    "depends_on",
+    "depended_by",
+    "internal",
+    "stateless",
+    "thread_safe",
+    "cohesion",
+    "stability",
+];
+
+/// @ai:intent Function-level tags recognized by the extractor
+pub const FUNCTION_TAGS: &[&str] = &[
+    "intent",
+    "pre",
+    "post",
+    "invariant",
+    "example",
+    "effects",
+    "idempotent",
+    "confidence",
+    "needs_review",
+    "author",
+    "verified",
+    "assumes",
+    "context",
+    "related",
+    "deprecated",
+    "complexity",
+    "edge_cases",
+];
+
+/// @ai:intent Declaration-level tags (`var:<tag>` / `const:<tag>`, or bare on a declaration)
+pub const DECLARATION_TAGS: &[&str] = &["intent", "unit", "range"];
+
+/// @ai:intent Namespaced tag families recognized by the extractor (`<family>:<name>`)
+pub const TAG_FAMILIES: &[&str] = &["override", "test", "var", "const"];
+
+/// @ai:intent Check if a tag (without prefix) is defined by the specification
+///            `override:` and `test:` accept any name; `var:` / `const:` only declaration tags
+/// @ai:effects pure
+/// @ai:example ("module:layer") -> true
+/// @ai:example ("retry_safe") -> false
+pub fn is_known_tag(tag: &str) -> bool {
+    match tag.split_once(':') {
+        Some(("module", name)) => MODULE_TAGS.contains(&name),
+        Some(("project", name)) => PROJECT_TAGS.contains(&name),
+        Some(("var" | "const", name)) => DECLARATION_TAGS.contains(&name),
+        Some(("override" | "test", name)) => !name.is_empty(),
+        Some(_) => false,
+        None => FUNCTION_TAGS.contains(&tag) || DECLARATION_TAGS.contains(&tag),
+    }
+}
+
 /// @ai:intent Extract all annotations from a source file
 /// @ai:pre path exists and is a supported file type
 /// @ai:effects fs:read
 pub fn extract_file(path: &Path) -> Result<ParsedFile> {
+    extract_file_with(path, &PrefixConfig::default())
+}
+
+/// @ai:intent Extract all annotations from a source file using configured prefixes
+/// @ai:pre path exists and is a supported file type
+/// @ai:effects fs:read
+pub fn extract_file_with(path: &Path, prefixes: &PrefixConfig) -> Result<ParsedFile> {
     let parsed = parse_file(path)?;
-    let (module, raw_annotations) = extract_from_parsed(&parsed, path);
+    let (module, raw_annotations) = extract_from_parsed(&parsed, path, prefixes);
 
     Ok(ParsedFile {
         path: path.to_path_buf(),
@@ -29,7 +95,11 @@ pub fn extract_file(path: &Path) -> Result<ParsedFile> {
 
 /// @ai:intent Extract annotations from parsed source
 /// @ai:effects pure
-fn extract_from_parsed(parsed: &ParsedSource, path: &Path) -> (ModuleAnnotations, Vec<Annotation>) {
+fn extract_from_parsed(
+    parsed: &ParsedSource,
+    path: &Path,
+    prefixes: &PrefixConfig,
+) -> (ModuleAnnotations, Vec<Annotation>) {
     let mut module = ModuleAnnotations {
         file: path.to_path_buf(),
         ..Default::default()
@@ -38,8 +108,8 @@ fn extract_from_parsed(parsed: &ParsedSource, path: &Path) -> (ModuleAnnotations
 
     // Extract module-level annotations from the first comment block
     if let Some(first_block) = parsed.comment_blocks.first() {
-        if first_block.has_ai_annotations() {
-            extract_module_annotations(first_block, path, &mut module, &mut raw_annotations);
+        if first_block.has_annotations(prefixes) {
+            extract_module_annotations(first_block, path, prefixes, &mut module, &mut raw_annotations);
         }
     }
 
@@ -52,13 +122,32 @@ fn extract_from_parsed(parsed: &ParsedSource, path: &Path) -> (ModuleAnnotations
 
         if let Some(block_idx) = func_loc.preceding_comment_block {
             if let Some(block) = parsed.comment_blocks.get(block_idx) {
-                extract_function_annotations(block, path, &mut func_annot, &mut raw_annotations);
+                extract_function_annotations(block, path, prefixes, &mut func_annot, &mut raw_annotations);
             }
         }
 
         module.functions.push(func_annot);
     }
 
+    // Extract declaration-level annotations
+    for decl_loc in &parsed.declaration_locations {
+        let mut decl_annot = DeclarationAnnotations::new(
+            decl_loc.name.clone(),
+            decl_loc.kind,
+            decl_loc.public,
+            Location::new(path.to_path_buf(), decl_loc.line),
+        );
+
+        if let Some(block) = decl_loc
+            .preceding_comment_block
+            .and_then(|idx| parsed.comment_blocks.get(idx))
+        {
+            extract_declaration_annotations(block, path, prefixes, &mut decl_annot, &mut raw_annotations);
+        }
+
+        module.declarations.push(decl_annot);
+    }
+
     (module, raw_annotations)
 }
 
@@ -67,21 +156,36 @@ fn extract_from_parsed(parsed: &ParsedSource, path: &Path) -> (ModuleAnnotations
 fn extract_module_annotations(
     block: &CommentBlock,
     path: &Path,
+    prefixes: &PrefixConfig,
     module: &mut ModuleAnnotations,
     raw: &mut Vec<Annotation>,
 ) {
-    let re = Regex::new(r"@ai:module:(\w+)\s+(.*)").expect("Invalid regex");
+    let re = prefixes.regex(r"module:(\w+)\s+(.*)");
+    let re_project = prefixes.regex(r"project:(\w+)\s+(.*)");
 
     for line in &block.lines {
+        // Project settings live in the entrypoint's header; they are kept as raw annotations
+        if let Some(captures) = re_project.captures(&line.content) {
+            raw.push(Annotation {
+                level: AnnotationLevel::Project,
+                tag: format!("project:{}", &captures[2]),
+                value: captures[3].trim().to_string(),
+                location: Location::new(path.to_path_buf(), line.line_number),
+                prefix: captures["prefix"].to_string(),
+            });
+            continue;
+        }
+
         if let Some(captures) = re.captures(&line.content) {
-            let tag = captures.get(1).unwrap().as_str();
-            let value = captures.get(2).unwrap().as_str().trim();
+            let tag = captures.get(2).unwrap().as_str();
+            let value = captures.get(3).unwrap().as_str().trim();
 
             let annotation = Annotation {
                 level: AnnotationLevel::Module,
                 tag: format!("module:{}", tag),
                 value: value.to_string(),
                 location: Location::new(path.to_path_buf(), line.line_number),
+                prefix: captures["prefix"].to_string(),
             };
             raw.push(annotation);
 
@@ -119,24 +223,26 @@ fn apply_module_annotation(module: &mut ModuleAnnotations, tag: &str, value: &st
 fn extract_function_annotations(
     block: &CommentBlock,
     path: &Path,
+    prefixes: &PrefixConfig,
     func: &mut FunctionAnnotations,
     raw: &mut Vec<Annotation>,
 ) {
-    let re_standard = Regex::new(r"@ai:(\w+)\s*(.*)").expect("Invalid regex");
-    let re_override = Regex::new(r"@ai:override:(\w+)\s+(.*)").expect("Invalid regex");
-    let re_test = Regex::new(r"@ai:test:(\w+)\s*(.*)").expect("Invalid regex");
+    let re_standard = prefixes.regex(r"(\w+(?::\w+)*)\s*(.*)");
+    let re_override = prefixes.regex(r"override:(\w+)\s+(.*)");
+    let re_test = prefixes.regex(r"test:(\w+)\s*(.*)");
 
     for line in &block.lines {
         // Check for override annotations first
         if let Some(captures) = re_override.captures(&line.content) {
-            let constraint = captures.get(1).unwrap().as_str();
-            let value = captures.get(2).unwrap().as_str().trim();
+            let constraint = captures.get(2).unwrap().as_str();
+            let value = captures.get(3).unwrap().as_str().trim();
 
             raw.push(Annotation {
                 level: AnnotationLevel::Function,
                 tag: format!("override:{}", constraint),
                 value: value.to_string(),
                 location: Location::new(path.to_path_buf(), line.line_number),
+                prefix: captures["prefix"].to_string(),
             });
 
             func.overrides.push((constraint.to_string(), value.to_string()));
@@ -145,14 +251,15 @@ fn extract_function_annotations(
 
         // Check for test annotations
         if let Some(captures) = re_test.captures(&line.content) {
-            let test_type = captures.get(1).unwrap().as_str();
-            let value = captures.get(2).unwrap().as_str().trim();
+            let test_type = captures.get(2).unwrap().as_str();
+            let value = captures.get(3).unwrap().as_str().trim();
 
             raw.push(Annotation {
                 level: AnnotationLevel::Test,
                 tag: format!("test:{}", test_type),
                 value: value.to_string(),
                 location: Location::new(path.to_path_buf(), line.line_number),
+                prefix: captures["prefix"].to_string(),
             });
 
             if test_type == "integration" {
@@ -163,20 +270,21 @@ fn extract_function_annotations(
 
         // Check for standard annotations
         if let Some(captures) = re_standard.captures(&line.content) {
-            let tag = captures.get(1).unwrap().as_str();
+            let tag = captures.get(2).unwrap().as_str();
 
-            // Skip if this is a module annotation
-            if tag.starts_with("module:") {
+            // Skip module and project annotations (extracted from the module block)
+            if tag.starts_with("module:") || tag.starts_with("project:") {
                 continue;
             }
 
-            let value = captures.get(2).unwrap().as_str().trim();
+            let value = captures.get(3).unwrap().as_str().trim();
 
             raw.push(Annotation {
                 level: AnnotationLevel::Function,
                 tag: tag.to_string(),
                 value: value.to_string(),
                 location: Location::new(path.to_path_buf(), line.line_number),
+                prefix: captures["prefix"].to_string(),
             });
 
             apply_function_annotation(func, tag, value);
@@ -184,6 +292,47 @@ fn extract_function_annotations(
     }
 }
 
+/// @ai:intent Extract declaration-level annotations from a comment block
+///            Accepts `var:unit ms`, `const:unit ms`, or a bare `unit ms`
+/// @ai:effects pure
+fn extract_declaration_annotations(
+    block: &CommentBlock,
+    path: &Path,
+    prefixes: &PrefixConfig,
+    decl: &mut DeclarationAnnotations,
+    raw: &mut Vec<Annotation>,
+) {
+    let re = prefixes.regex(r"(?:(?:var|const):)?(intent|unit|range)\s+(.*)");
+
+    for line in &block.lines {
+        if let Some(captures) = re.captures(&line.content) {
+            let tag = captures.get(2).unwrap().as_str();
+            let value = captures.get(3).unwrap().as_str().trim();
+
+            raw.push(Annotation {
+                level: AnnotationLevel::Declaration,
+                tag: format!("{}:{}", decl.kind.namespace(), tag),
+                value: value.to_string(),
+                location: Location::new(path.to_path_buf(), line.line_number),
+                prefix: captures["prefix"].to_string(),
+            });
+
+            apply_declaration_annotation(decl, tag, value);
+        }
+    }
+}
+
+/// @ai:intent Apply a parsed annotation to the declaration struct
+/// @ai:effects pure
+fn apply_declaration_annotation(decl: &mut DeclarationAnnotations, tag: &str, value: &str) {
+    match tag {
+        "intent" => decl.intent = Some(value.to_string()),
+        "unit" => decl.unit = Some(value.to_string()),
+        "range" => decl.range = Some(value.to_string()),
+        _ => {}
+    }
+}
+
 /// @ai:intent Apply a parsed annotation to the function struct
 /// @ai:effects pure
 fn apply_function_annotation(func: &mut FunctionAnnotations, tag: &str, value: &str) {
@@ -252,4 +401,66 @@ fn test_func(x: i32) -> i32 {{
         assert_eq!(func.pre, vec!["x > 0".to_string()]);
         assert_eq!(func.effects, vec!["pure".to_string()]);
     }
+
+    #[test]
+    fn test_extract_declaration_annotations() {
+        let mut file = NamedTempFile::with_suffix(".rs").unwrap();
+        writeln!(
+            file,
+            r#"/// @ai:const:intent Upper bound for request retries
+/// @ai:const:range 0..=10
+pub const MAX_RETRIES: u32 = 5;
+
+/// @ai:intent Delay between retries
+/// @ai:unit ms
+pub const RETRY_DELAY: u64 = 250;
+pub const UNDOCUMENTED: u8 = 1;"#
+        )
+        .unwrap();
+
+        let result = extract_file(file.path()).unwrap();
+        let decls = &result.module.declarations;
+
+        assert_eq!(decls.len(), 3);
+        assert_eq!(decls[0].intent.as_deref(), Some("Upper bound for request retries"));
+        assert_eq!(decls[0].range.as_deref(), Some("0..=10"));
+        assert_eq!(decls[1].unit.as_deref(), Some("ms"));
+        assert!(decls[1].public);
+        assert!(!decls[2].is_annotated());
+        assert!(result.raw_annotations.iter().any(|a| a.tag == "const:unit"));
+    }
+
+    #[test]
+    fn test_tag_registries_match_apply_functions() {
+        let empty_func = serde_json::to_value(FunctionAnnotations::default()).unwrap();
+        for tag in FUNCTION_TAGS {
+            let mut func = FunctionAnnotations::default();
+            apply_function_annotation(&mut func, tag, "0.5");
+            assert_ne!(serde_json::to_value(&func).unwrap(), empty_func, "unhandled tag {}", tag);
+        }
+
+        let empty_module = serde_json::to_value(ModuleAnnotations::default()).unwrap();
+        for tag in MODULE_TAGS {
+            let mut module = ModuleAnnotations::default();
+            apply_module_annotation(&mut module, tag, "0.5");
+            assert_ne!(serde_json::to_value(&module).unwrap(), empty_module, "unhandled tag {}", tag);
+        }
+
+        for tag in DECLARATION_TAGS {
+            let mut decl = DeclarationAnnotations::new(
+                "X".to_string(),
+                crate::annotation::DeclarationKind::Constant,
+                true,
+                Location::default(),
+            );
+            apply_declaration_annotation(&mut decl, tag, "0.5");
+            assert!(decl.is_annotated(), "unhandled tag {}", tag);
+        }
+
+        for family in TAG_FAMILIES {
+            let tag = format!("{}:intent", family);
+            assert!(is_known_tag(&tag), "unknown family {}", family);
+        }
+        assert!(!is_known_tag("unlisted:intent"));
+    }
 }
@@ -29,6 +29,18 @@ pub struct CommentStyle {
 }
 
 impl Language {
+    /// @ai:intent All supported languages
+    pub const ALL: [Language; 8] = [
+        Language::Rust,
+        Language::Python,
+        Language::TypeScript,
+        Language::JavaScript,
+        Language::Go,
+        Language::Java,
+        Language::C,
+        Language::Cpp,
+    ];
+
     /// @ai:intent Get the comment style for this language
     /// @ai:effects pure
     pub fn comment_style(&self) -> CommentStyle {
@@ -119,24 +131,9 @@ impl Language {
 pub fn detect_language(path: &Path) -> Option<Language> {
     let ext = path.extension()?.to_str()?;
 
-    let all_languages = [
-        Language::Rust,
-        Language::Python,
-        Language::TypeScript,
-        Language::JavaScript,
-        Language::Go,
-        Language::Java,
-        Language::C,
-        Language::Cpp,
-    ];
-
-    for lang in all_languages {
-        if lang.extensions().contains(&ext) {
-            return Some(lang);
-        }
-    }
-
-    None
+    Language::ALL
+        .into_iter()
+        .find(|lang| lang.extensions().contains(&ext))
 }
 
 /// @ai:intent Check if a file should be parsed based on extension
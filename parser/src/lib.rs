@@ -1,6 +1,6 @@
 //! @ai:module:intent AICMS parser library for extracting and validating annotations
 //! @ai:module:layer infrastructure
-//! @ai:module:public_api annotation, extractor, linter, parser, language, output, error
+//! @ai:module:public_api annotation, capabilities, extractor, linter, parser, prefix, language, output, error
 //! @ai:module:stateless true
 //!
 //! # AICMS Parser
@@ -25,6 +25,7 @@
 //! ```
 
 pub mod annotation;
+pub mod capabilities;
 pub mod diff;
 pub mod error;
 pub mod extractor;
@@ -32,14 +33,26 @@ pub mod language;
 pub mod linter;
 pub mod output;
 pub mod parser;
+pub mod prefix;
 
 pub use annotation::{
-    Annotation, AnnotationLevel, FunctionAnnotations, Location, ModuleAnnotations, ParsedFile,
-    ParsedProject,
+    Annotation, AnnotationLevel, DeclarationAnnotations, DeclarationKind, FunctionAnnotations,
+    Location, ModuleAnnotations, ParsedFile, ParsedProject,
+};
+pub use capabilities::{capabilities, Capabilities};
+pub use diff::{
+    diff_files, diff_files_with, diff_parsed, diff_parsed_with, ChangeType, ContractChange,
+    DiffResult,
 };
-pub use diff::{diff_files, diff_parsed, ChangeType, ContractChange, DiffResult};
 pub use error::{Error, Result};
-pub use extractor::extract_file;
+pub use extractor::{extract_file, extract_file_with, is_known_tag};
 pub use language::{detect_language, is_supported_file, Language};
-pub use linter::{lint_directory, lint_file, LintConfig, LintIssue, LintResult, Severity};
-pub use output::{format_diff_result, format_lint_result, format_parsed_file, to_json, OutputFormat};
+pub use linter::{
+    check_annotation, lint_directory, lint_file, lint_parsed_file, FailOn, LintConfig, LintIssue,
+    LintResult, LintRule, Severity, LINT_RULES,
+};
+pub use prefix::{PrefixConfig, DEFAULT_PREFIX};
+pub use output::{
+    format_capabilities, format_diff_result, format_lint_result, format_parsed_file, to_json,
+    OutputFormat,
+};
@@ -1,25 +1,151 @@
 //! @ai:module:intent Lint source files for AICMS compliance
 //! @ai:module:layer application
-//! @ai:module:public_api lint_file, lint_directory, LintResult, LintIssue, Severity
+//! @ai:module:public_api lint_file, lint_directory, lint_parsed_file, check_annotation, LintResult,
+//!                        LintIssue, Severity
 //! @ai:module:depends_on extractor, annotation, error
 //! @ai:module:stateless true
 
-use crate::annotation::{Location, ParsedFile};
+use crate::annotation::{Annotation, DeclarationKind, Location, ParsedFile, STANDARD_EFFECTS};
 use crate::error::Result;
-use crate::extractor::extract_file;
+use crate::extractor::{extract_file_with, is_known_tag};
+use crate::prefix::PrefixConfig;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 use walkdir::WalkDir;
 
 /// @ai:intent Severity level for lint issues
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
     Error,
+    #[default]
     Warning,
     Info,
 }
 
+/// @ai:intent Lowest severity that makes a lint run fail
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FailOn {
+    #[default]
+    Error,
+    Warning,
+}
+
+/// @ai:intent Description of a lint rule emitted by the linter
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct LintRule {
+    pub code: &'static str,
+    pub severity: Severity,
+    pub description: &'static str,
+}
+
+impl LintRule {
+    /// @ai:intent Issue of this rule at a location, without a suggestion
+    /// @ai:effects pure
+    fn issue(&self, message: String, location: Location) -> LintIssue {
+        LintIssue {
+            severity: self.severity,
+            code: self.code.to_string(),
+            message,
+            location,
+            suggestion: None,
+        }
+    }
+}
+
+/// @ai:intent E000: File could not be parsed
+const PARSE_FAILED: LintRule = LintRule {
+    code: "E000",
+    severity: Severity::Error,
+    description: "File could not be parsed",
+};
+
+/// @ai:intent E001: Function missing intent annotation
+const MISSING_INTENT: LintRule = LintRule {
+    code: "E001",
+    severity: Severity::Error,
+    description: "Function missing intent annotation",
+};
+
+/// @ai:intent E002: Unknown annotation tag
+const UNKNOWN_TAG: LintRule = LintRule {
+    code: "E002",
+    severity: Severity::Error,
+    description: "Unknown annotation tag",
+};
+
+/// @ai:intent E003: Confidence is not a number between 0.0 and 1.0
+const INVALID_CONFIDENCE: LintRule = LintRule {
+    code: "E003",
+    severity: Severity::Error,
+    description: "Confidence is not a number between 0.0 and 1.0",
+};
+
+/// @ai:intent W001: Module missing module:intent annotation
+const MISSING_MODULE_INTENT: LintRule = LintRule {
+    code: "W001",
+    severity: Severity::Warning,
+    description: "Module missing module:intent annotation",
+};
+
+/// @ai:intent W002: Function confidence below threshold
+const LOW_CONFIDENCE: LintRule = LintRule {
+    code: "W002",
+    severity: Severity::Warning,
+    description: "Function confidence below threshold",
+};
+
+/// @ai:intent W003: Annotations use a legacy prefix
+const LEGACY_PREFIX: LintRule = LintRule {
+    code: "W003",
+    severity: Severity::Warning,
+    description: "Annotations use a legacy prefix",
+};
+
+/// @ai:intent W004: Public constant missing intent annotation (opt-in)
+const MISSING_CONST_INTENT: LintRule = LintRule {
+    code: "W004",
+    severity: Severity::Warning,
+    description: "Public constant missing intent annotation (opt-in)",
+};
+
+/// @ai:intent W005: Effect not defined by the specification
+const UNKNOWN_EFFECT: LintRule = LintRule {
+    code: "W005",
+    severity: Severity::Warning,
+    description: "Effect not defined by the specification",
+};
+
+/// @ai:intent I001: Function flagged for review
+const NEEDS_REVIEW: LintRule = LintRule {
+    code: "I001",
+    severity: Severity::Info,
+    description: "Function flagged for review",
+};
+
+/// @ai:intent I002: Function requires an integration test
+const NEEDS_INTEGRATION_TEST: LintRule = LintRule {
+    code: "I002",
+    severity: Severity::Info,
+    description: "Function requires an integration test",
+};
+
+/// @ai:intent All lint rules the linter can emit; emit sites build issues from these
+pub const LINT_RULES: &[LintRule] = &[
+    PARSE_FAILED,
+    MISSING_INTENT,
+    UNKNOWN_TAG,
+    INVALID_CONFIDENCE,
+    MISSING_MODULE_INTENT,
+    LOW_CONFIDENCE,
+    LEGACY_PREFIX,
+    MISSING_CONST_INTENT,
+    UNKNOWN_EFFECT,
+    NEEDS_REVIEW,
+    NEEDS_INTEGRATION_TEST,
+];
+
 /// @ai:intent A single lint issue found in the code
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LintIssue {
@@ -38,12 +164,13 @@ pub struct LintConfig {
     pub require_effects_for_impure: bool,
     pub warn_low_confidence: bool,
     pub confidence_threshold: f32,
-}
-
-impl Default for Severity {
-    fn default() -> Self {
-        Self::Warning
-    }
+    /// Opt-in: require an intent on public constants
+    pub require_const_intent: bool,
+    /// Check tags, effects and confidence values against the specification
+    pub validate_annotations: bool,
+    pub max_warnings: Option<usize>,
+    pub fail_on: FailOn,
+    pub prefixes: PrefixConfig,
 }
 
 impl LintConfig {
@@ -55,6 +182,11 @@ impl LintConfig {
             require_effects_for_impure: true,
             warn_low_confidence: true,
             confidence_threshold: 0.7,
+            require_const_intent: false,
+            validate_annotations: true,
+            max_warnings: None,
+            fail_on: FailOn::Error,
+            prefixes: PrefixConfig::default(),
         }
     }
 }
@@ -75,6 +207,26 @@ impl LintResult {
         self.errors == 0
     }
 
+    /// @ai:intent Check if linting passed the thresholds of a configuration
+    /// @ai:post returns false on any error, on any warning with fail_on=warning,
+    ///          or when warnings exceed max_warnings
+    pub fn passed_with(&self, config: &LintConfig) -> bool {
+        if !self.passed() {
+            return false;
+        }
+
+        if config.fail_on == FailOn::Warning && self.warnings > 0 {
+            return false;
+        }
+
+        !self.exceeds_warning_budget(config)
+    }
+
+    /// @ai:intent Check if the warning count is above the configured budget
+    pub fn exceeds_warning_budget(&self, config: &LintConfig) -> bool {
+        config.max_warnings.is_some_and(|max| self.warnings > max)
+    }
+
     /// @ai:intent Merge another lint result into this one
     pub fn merge(&mut self, other: LintResult) {
         self.files_checked += other.files_checked;
@@ -88,7 +240,7 @@ impl LintResult {
 /// @ai:intent Lint a single file
 /// @ai:effects fs:read
 pub fn lint_file(path: &Path, config: &LintConfig) -> Result<LintResult> {
-    let parsed = extract_file(path)?;
+    let parsed = extract_file_with(path, &config.prefixes)?;
     Ok(lint_parsed_file(&parsed, config))
 }
 
@@ -108,13 +260,10 @@ pub fn lint_directory(path: &Path, config: &LintConfig) -> Result<LintResult> {
             match lint_file(file_path, config) {
                 Ok(file_result) => result.merge(file_result),
                 Err(e) => {
-                    result.issues.push(LintIssue {
-                        severity: Severity::Error,
-                        code: "E000".to_string(),
-                        message: format!("Failed to parse file: {}", e),
-                        location: Location::new(file_path.to_path_buf(), 0),
-                        suggestion: None,
-                    });
+                    result.issues.push(PARSE_FAILED.issue(
+                        format!("Failed to parse file: {}", e),
+                        Location::new(file_path.to_path_buf(), 0),
+                    ));
                     result.errors += 1;
                 }
             }
@@ -124,9 +273,54 @@ pub fn lint_directory(path: &Path, config: &LintConfig) -> Result<LintResult> {
     Ok(result)
 }
 
+/// @ai:intent Check one annotation's tag and value against the specification
+///            Unknown tags (E002) and invalid confidence values (E003) are errors;
+///            effects outside the standard list (W005) are warnings
+/// @ai:effects pure
+pub fn check_annotation(annotation: &Annotation) -> Option<LintIssue> {
+    let issue = |rule: LintRule, message| rule.issue(message, annotation.location.clone());
+    let tag = format!("{}{}", annotation.prefix, annotation.tag);
+
+    if !is_known_tag(&annotation.tag) {
+        return Some(issue(
+            UNKNOWN_TAG,
+            format!("Unknown annotation tag {}", tag),
+        ));
+    }
+
+    match annotation.tag.as_str() {
+        "confidence" => match annotation.value.parse::<f32>() {
+            Ok(conf) if (0.0..=1.0).contains(&conf) => None,
+            _ => Some(issue(
+                INVALID_CONFIDENCE,
+                format!(
+                    "{} must be between 0.0 and 1.0, got `{}`",
+                    tag, annotation.value
+                ),
+            )),
+        },
+        "effects" => {
+            let unknown: Vec<&str> = annotation
+                .value
+                .split(',')
+                .map(str::trim)
+                .filter(|effect| !STANDARD_EFFECTS.contains(effect))
+                .collect();
+            (!unknown.is_empty()).then(|| LintIssue {
+                suggestion: Some(format!("Use one of: {}", STANDARD_EFFECTS.join(", "))),
+                ..issue(
+                    UNKNOWN_EFFECT,
+                    format!("Unknown effect {} in {}", unknown.join(", "), tag),
+                )
+            })
+        }
+        _ => None,
+    }
+}
+
 /// @ai:intent Lint a parsed file
 /// @ai:effects pure
-fn lint_parsed_file(parsed: &ParsedFile, config: &LintConfig) -> LintResult {
+pub fn lint_parsed_file(parsed: &ParsedFile, config: &LintConfig) -> LintResult {
     let mut result = LintResult {
         files_checked: 1,
         functions_checked: parsed.module.functions.len(),
@@ -136,28 +330,113 @@ fn lint_parsed_file(parsed: &ParsedFile, config: &LintConfig) -> LintResult {
     // Check module-level annotations
     if config.require_module_intent && parsed.module.intent.is_none() {
         result.issues.push(LintIssue {
-            severity: Severity::Warning,
-            code: "W001".to_string(),
-            message: "Module missing @ai:module:intent annotation".to_string(),
-            location: Location::new(parsed.path.clone(), 1),
-            suggestion: Some("Add //! @ai:module:intent <description>".to_string()),
+            suggestion: Some(format!(
+                "Add //! {} <description>",
+                config.prefixes.tag("module:intent")
+            )),
+            ..MISSING_MODULE_INTENT.issue(
+                format!(
+                    "Module missing {} annotation",
+                    config.prefixes.tag("module:intent")
+                ),
+                Location::new(parsed.path.clone(), 1),
+            )
         });
         result.warnings += 1;
     }
 
+    // Check tags and values of every annotation
+    if config.validate_annotations {
+        for issue in parsed.raw_annotations.iter().filter_map(check_annotation) {
+            match issue.severity {
+                Severity::Error => result.errors += 1,
+                Severity::Warning => result.warnings += 1,
+                Severity::Info => {}
+            }
+            result.issues.push(issue);
+        }
+    }
+
+    // Check for annotations still using a legacy prefix
+    let legacy: Vec<_> = parsed
+        .raw_annotations
+        .iter()
+        .filter(|a| config.prefixes.is_legacy(&a.prefix))
+        .collect();
+
+    if let Some(first) = legacy.first() {
+        let current = parsed.raw_annotations.len() - legacy.len();
+        let message = if current > 0 {
+            format!(
+                "File mixes annotation prefixes: {} use a legacy prefix, {} use {}",
+                legacy.len(),
+                current,
+                config.prefixes.prefix
+            )
+        } else {
+            format!(
+                "File uses legacy annotation prefix {} ({} annotations)",
+                first.prefix,
+                legacy.len()
+            )
+        };
+
+        result.issues.push(LintIssue {
+            suggestion: Some(format!(
+                "Replace {} with {}",
+                first.prefix, config.prefixes.prefix
+            )),
+            ..LEGACY_PREFIX.issue(message, first.location.clone())
+        });
+        result.warnings += 1;
+    }
+
+    // Check public constants (opt-in)
+    if config.require_const_intent {
+        for decl in &parsed.module.declarations {
+            if decl.kind != DeclarationKind::Constant || !decl.public || decl.intent.is_some() {
+                continue;
+            }
+
+            result.issues.push(LintIssue {
+                suggestion: Some(format!(
+                    "Add /// {} <meaning> (and optionally {} / {}) before `{}`",
+                    config.prefixes.tag("const:intent"),
+                    config.prefixes.tag("const:unit"),
+                    config.prefixes.tag("const:range"),
+                    decl.name
+                )),
+                ..MISSING_CONST_INTENT.issue(
+                    format!(
+                        "Public constant `{}` missing {} annotation",
+                        decl.name,
+                        config.prefixes.tag("const:intent")
+                    ),
+                    decl.location.clone(),
+                )
+            });
+            result.warnings += 1;
+        }
+    }
+
     // Check function-level annotations
     for func in &parsed.module.functions {
         // Check for required intent
         if config.require_intent && func.intent.is_none() {
             result.issues.push(LintIssue {
-                severity: Severity::Error,
-                code: "E001".to_string(),
-                message: format!("Function `{}` missing @ai:intent annotation", func.name),
-                location: func.location.clone(),
                 suggestion: Some(format!(
-                    "Add /// @ai:intent <description> before `{}`",
+                    "Add /// {} <description> before `{}`",
+                    config.prefixes.tag("intent"),
                     func.name
                 )),
+                ..MISSING_INTENT.issue(
+                    format!(
+                        "Function `{}` missing {} annotation",
+                        func.name,
+                        config.prefixes.tag("intent")
+                    ),
+                    func.location.clone(),
+                )
             });
             result.errors += 1;
         }
@@ -167,14 +446,14 @@ fn lint_parsed_file(parsed: &ParsedFile, config: &LintConfig) -> LintResult {
             if let Some(conf) = func.confidence {
                 if conf < config.confidence_threshold {
                     result.issues.push(LintIssue {
-                        severity: Severity::Warning,
-                        code: "W002".to_string(),
-                        message: format!(
-                            "Function `{}` has low confidence ({:.2} < {:.2})",
-                            func.name, conf, config.confidence_threshold
-                        ),
-                        location: func.location.clone(),
                         suggestion: Some("Consider reviewing and improving confidence".to_string()),
+                        ..LOW_CONFIDENCE.issue(
+                            format!(
+                                "Function `{}` has low confidence ({:.2} < {:.2})",
+                                func.name, conf, config.confidence_threshold
+                            ),
+                            func.location.clone(),
+                        )
                     });
                     result.warnings += 1;
                 }
@@ -182,33 +461,22 @@ fn lint_parsed_file(parsed: &ParsedFile, config: &LintConfig) -> LintResult {
         }
 
         // Check for needs_review flag
-        if func.needs_review.is_some() {
-            result.issues.push(LintIssue {
-                severity: Severity::Info,
-                code: "I001".to_string(),
-                message: format!(
-                    "Function `{}` flagged for review: {}",
-                    func.name,
-                    func.needs_review.as_ref().unwrap()
-                ),
-                location: func.location.clone(),
-                suggestion: None,
-            });
+        if let Some(reason) = &func.needs_review {
+            result.issues.push(NEEDS_REVIEW.issue(
+                format!("Function `{}` flagged for review: {}", func.name, reason),
+                func.location.clone(),
+            ));
         }
 
         // Check for integration test requirement
-        if func.test_integration.is_some() {
-            result.issues.push(LintIssue {
-                severity: Severity::Info,
-                code: "I002".to_string(),
-                message: format!(
+        if let Some(requirement) = &func.test_integration {
+            result.issues.push(NEEDS_INTEGRATION_TEST.issue(
+                format!(
                     "Function `{}` requires integration test: {}",
-                    func.name,
-                    func.test_integration.as_ref().unwrap()
+                    func.name, requirement
                 ),
-                location: func.location.clone(),
-                suggestion: None,
-            });
+                func.location.clone(),
+            ));
         }
     }
 
@@ -264,4 +532,140 @@ fn with_annotation() {{
 
         assert_eq!(result.errors, 0);
     }
+
+    #[test]
+    fn test_lint_public_const_intent_is_opt_in() {
+        let mut file = NamedTempFile::with_suffix(".rs").unwrap();
+        writeln!(
+            file,
+            r#"/// @ai:const:intent Requests allowed per minute
+pub const RATE_LIMIT: u32 = 60;
+pub const TIMEOUT_MS: u64 = 500;
+const PRIVATE: u8 = 1;"#
+        )
+        .unwrap();
+
+        let default_result = lint_file(file.path(), &LintConfig::default()).unwrap();
+        assert!(default_result.issues.is_empty());
+
+        let config = LintConfig {
+            require_const_intent: true,
+            ..Default::default()
+        };
+        let result = lint_file(file.path(), &config).unwrap();
+
+        assert_eq!(result.warnings, 1);
+        assert_eq!(result.issues[0].code, "W004");
+        assert!(result.issues[0].message.contains("TIMEOUT_MS"));
+    }
+
+    #[test]
+    fn test_lint_validates_annotations() {
+        let mut file = NamedTempFile::with_suffix(".rs").unwrap();
+        writeln!(
+            file,
+            r#"//! @ai:module:intent Shipping
+//! @ai:module:owner logistics
+//! @ai:project:max_params 4
+
+/// @ai:intent Compute the shipping cost
+/// @ai:effects pure, clock
+/// @ai:confidence 1.5
+/// @ai:retry_safe true
+/// @ai:override:max_params 6
+fn cost() {{}}"#
+        )
+        .unwrap();
+
+        let default_result = lint_file(file.path(), &LintConfig::default()).unwrap();
+        assert!(default_result.issues.is_empty());
+
+        let config = LintConfig {
+            validate_annotations: true,
+            ..Default::default()
+        };
+        let result = lint_file(file.path(), &config).unwrap();
+        let codes: Vec<&str> = result.issues.iter().map(|i| i.code.as_str()).collect();
+
+        assert_eq!(codes, ["E002", "W005", "E003", "E002"]);
+        assert_eq!((result.errors, result.warnings), (3, 1));
+        assert!(result.issues[0].message.contains("@ai:module:owner"));
+        assert!(result.issues[1].message.contains("clock"));
+    }
+
+    #[test]
+    fn test_warning_thresholds() {
+        let result = LintResult {
+            warnings: 3,
+            ..Default::default()
+        };
+
+        assert!(result.passed_with(&LintConfig::default()));
+
+        let fail_on_warning = LintConfig {
+            fail_on: FailOn::Warning,
+            ..Default::default()
+        };
+        assert!(!result.passed_with(&fail_on_warning));
+
+        let within_budget = LintConfig {
+            max_warnings: Some(3),
+            ..Default::default()
+        };
+        assert!(result.passed_with(&within_budget));
+
+        let over_budget = LintConfig {
+            max_warnings: Some(2),
+            ..Default::default()
+        };
+        assert!(result.exceeds_warning_budget(&over_budget));
+        assert!(!result.passed_with(&over_budget));
+    }
+
+    #[test]
+    fn test_lint_custom_prefix_with_legacy_warning() {
+        let mut file = NamedTempFile::with_suffix(".rs").unwrap();
+        writeln!(
+            file,
+            r#"/// @meta:intent Print hello
+fn migrated() {{}}
+
+/// @ai:intent Print goodbye
+fn legacy() {{}}"#
+        )
+        .unwrap();
+
+        let config = LintConfig {
+            require_intent: true,
+            prefixes: PrefixConfig::new("meta").with_accepted("ai"),
+            ..Default::default()
+        };
+
+        let result = lint_file(file.path(), &config).unwrap();
+
+        assert_eq!(result.errors, 0);
+        assert_eq!(result.warnings, 1);
+        assert_eq!(result.issues[0].code, "W003");
+    }
+
+    #[test]
+    fn test_lint_rules_are_consistent() {
+        let mut codes: Vec<&str> = LINT_RULES.iter().map(|rule| rule.code).collect();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), LINT_RULES.len(), "duplicate lint code");
+
+        for rule in LINT_RULES {
+            let letter = match rule.severity {
+                Severity::Error => 'E',
+                Severity::Warning => 'W',
+                Severity::Info => 'I',
+            };
+            assert!(
+                rule.code.starts_with(letter),
+                "{} has the wrong severity",
+                rule.code
+            );
+        }
+    }
 }
@@ -4,9 +4,10 @@
 //! @ai:module:depends_on linter, extractor, output
 
 use aicms_parser::{
-    diff, extractor, linter, output, LintConfig, OutputFormat,
+    diff, extractor, linter, output, FailOn, LintConfig, OutputFormat, PrefixConfig,
 };
-use clap::{Parser, Subcommand, ValueEnum};
+use aicms_parser::capabilities::CommandCapability;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 use std::process::ExitCode;
 
@@ -16,6 +17,24 @@ use std::process::ExitCode;
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Annotation prefix (e.g. @meta: or llm)
+    #[arg(long, global = true, default_value = aicms_parser::DEFAULT_PREFIX)]
+    prefix: String,
+
+    /// Additional legacy prefix still accepted during migration (repeatable)
+    #[arg(long, global = true)]
+    accept_prefix: Vec<String>,
+}
+
+impl Cli {
+    /// @ai:intent Build the prefix configuration from CLI flags
+    /// @ai:effects pure
+    fn prefixes(&self) -> PrefixConfig {
+        self.accept_prefix
+            .iter()
+            .fold(PrefixConfig::new(&self.prefix), |config, p| config.with_accepted(p))
+    }
 }
 
 #[derive(Subcommand)]
@@ -42,6 +61,22 @@ enum Commands {
         #[arg(long, default_value = "0.7")]
         confidence_threshold: f32,
 
+        /// Require @ai:const:intent on public constants (opt-in, W004)
+        #[arg(long, default_value = "false")]
+        require_const_intent: bool,
+
+        /// Check tags, effects and confidence values against the specification (E002, E003, W005)
+        #[arg(long, default_value = "true")]
+        validate_annotations: bool,
+
+        /// Fail if more than N warnings are found
+        #[arg(long)]
+        max_warnings: Option<usize>,
+
+        /// Lowest severity that fails the lint run
+        #[arg(long, value_enum, default_value = "error")]
+        fail_on: FailOnLevel,
+
         /// Output format
         #[arg(long, short, value_enum, default_value = "text")]
         format: Format,
@@ -83,6 +118,33 @@ enum Commands {
         #[arg(long, default_value = "false")]
         fail_on_breaking: bool,
     },
+
+    /// List supported languages, tags, effects, lint rules, formats, and commands
+    Capabilities {
+        /// Output as JSON (for feature detection by other tools)
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// @ai:intent Describe CLI subcommands from the clap definition
+/// @ai:effects pure
+fn command_capabilities() -> Vec<CommandCapability> {
+    Cli::command()
+        .get_subcommands()
+        .map(|cmd| CommandCapability {
+            name: cmd.get_name().to_string(),
+            about: cmd.get_about().map(|a| a.to_string()),
+            options: cmd
+                .get_arguments()
+                .filter(|arg| !arg.is_global_set())
+                .map(|arg| match arg.get_long() {
+                    Some(long) => format!("--{}", long),
+                    None => arg.get_id().to_string(),
+                })
+                .collect(),
+        })
+        .collect()
 }
 
 #[derive(Clone, Copy, ValueEnum)]
@@ -92,6 +154,21 @@ enum Format {
     JsonPretty,
 }
 
+#[derive(Clone, Copy, ValueEnum)]
+enum FailOnLevel {
+    Warning,
+    Error,
+}
+
+impl From<FailOnLevel> for FailOn {
+    fn from(f: FailOnLevel) -> Self {
+        match f {
+            FailOnLevel::Warning => FailOn::Warning,
+            FailOnLevel::Error => FailOn::Error,
+        }
+    }
+}
+
 impl From<Format> for OutputFormat {
     fn from(f: Format) -> Self {
         match f {
@@ -104,6 +181,7 @@ impl From<Format> for OutputFormat {
 
 fn main() -> ExitCode {
     let cli = Cli::parse();
+    let prefixes = cli.prefixes();
 
     match cli.command {
         Commands::Lint {
@@ -112,6 +190,10 @@ fn main() -> ExitCode {
             require_module_intent,
             warn_low_confidence,
             confidence_threshold,
+            require_const_intent,
+            validate_annotations,
+            max_warnings,
+            fail_on,
             format,
         } => {
             let config = LintConfig {
@@ -120,6 +202,11 @@ fn main() -> ExitCode {
                 require_effects_for_impure: false,
                 warn_low_confidence,
                 confidence_threshold,
+                require_const_intent,
+                validate_annotations,
+                max_warnings,
+                fail_on: fail_on.into(),
+                prefixes,
             };
 
             let result = if path.is_file() {
@@ -132,7 +219,15 @@ fn main() -> ExitCode {
                 Ok(lint_result) => {
                     println!("{}", output::format_lint_result(&lint_result, format.into()));
 
-                    if lint_result.passed() {
+                    if lint_result.exceeds_warning_budget(&config) {
+                        eprintln!(
+                            "Warning budget exceeded: {} warnings (max {})",
+                            lint_result.warnings,
+                            config.max_warnings.unwrap_or_default()
+                        );
+                    }
+
+                    if lint_result.passed_with(&config) {
                         ExitCode::SUCCESS
                     } else {
                         ExitCode::from(1)
@@ -147,7 +242,7 @@ fn main() -> ExitCode {
 
         Commands::Extract { path, format } => {
             if path.is_file() {
-                match extractor::extract_file(&path) {
+                match extractor::extract_file_with(&path, &prefixes) {
                     Ok(parsed) => {
                         println!("{}", output::format_parsed_file(&parsed, format.into()));
                         ExitCode::SUCCESS
@@ -164,7 +259,7 @@ fn main() -> ExitCode {
         }
 
         Commands::Parse { path, format } => {
-            match extractor::extract_file(&path) {
+            match extractor::extract_file_with(&path, &prefixes) {
                 Ok(parsed) => {
                     println!("{}", output::format_parsed_file(&parsed, format.into()));
                     ExitCode::SUCCESS
@@ -182,7 +277,7 @@ fn main() -> ExitCode {
             format,
             fail_on_breaking,
         } => {
-            match diff::diff_files(&old_file, &new_file) {
+            match diff::diff_files_with(&old_file, &new_file, &prefixes) {
                 Ok(diff_result) => {
                     println!("{}", output::format_diff_result(&diff_result, format.into()));
 
@@ -198,5 +293,17 @@ fn main() -> ExitCode {
                 }
             }
         }
+
+        Commands::Capabilities { json } => {
+            let caps = aicms_parser::capabilities().with_commands(command_capabilities());
+            let format = if json {
+                OutputFormat::JsonPretty
+            } else {
+                OutputFormat::Text
+            };
+
+            println!("{}", output::format_capabilities(&caps, format));
+            ExitCode::SUCCESS
+        }
     }
 }
@@ -1,10 +1,11 @@
 //! @ai:module:intent Format output for different formats (JSON, text)
 //! @ai:module:layer infrastructure
-//! @ai:module:public_api OutputFormat, format_lint_result, format_parsed_file
+//! @ai:module:public_api OutputFormat, format_lint_result, format_parsed_file, format_capabilities
 //! @ai:module:depends_on linter, annotation
 //! @ai:module:stateless true
 
 use crate::annotation::ParsedFile;
+use crate::capabilities::Capabilities;
 use crate::diff::{ChangeType, DiffResult};
 use crate::linter::{LintResult, Severity};
 use colored::Colorize;
@@ -19,6 +20,21 @@ pub enum OutputFormat {
     JsonPretty,
 }
 
+impl OutputFormat {
+    /// @ai:intent All output formats
+    pub const ALL: [OutputFormat; 3] = [OutputFormat::Text, OutputFormat::Json, OutputFormat::JsonPretty];
+
+    /// @ai:intent Get the format name as used on the command line
+    /// @ai:effects pure
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Json => "json",
+            OutputFormat::JsonPretty => "json-pretty",
+        }
+    }
+}
+
 /// @ai:intent Format lint results as a string
 /// @ai:effects pure
 pub fn format_lint_result(result: &LintResult, format: OutputFormat) -> String {
@@ -134,6 +150,38 @@ fn format_parsed_file_text(file: &ParsedFile) -> String {
         }
     }
 
+    let annotated: Vec<_> = file
+        .module
+        .declarations
+        .iter()
+        .filter(|d| d.is_annotated())
+        .collect();
+
+    if !annotated.is_empty() {
+        output.push_str(&format!("\n  Declarations ({}):\n", annotated.len()));
+
+        for decl in annotated {
+            output.push_str(&format!(
+                "    {} {} (line {})\n",
+                decl.kind.namespace(),
+                decl.name.cyan(),
+                decl.location.line
+            ));
+
+            if let Some(intent) = &decl.intent {
+                output.push_str(&format!("      intent: {}\n", intent));
+            }
+
+            if let Some(unit) = &decl.unit {
+                output.push_str(&format!("      unit: {}\n", unit));
+            }
+
+            if let Some(range) = &decl.range {
+                output.push_str(&format!("      range: {}\n", range));
+            }
+        }
+    }
+
     output
 }
 
@@ -269,3 +317,58 @@ fn format_diff_result_text(result: &DiffResult) -> String {
 
     output
 }
+
+/// @ai:intent Format parser capabilities as a string
+/// @ai:effects pure
+pub fn format_capabilities(caps: &Capabilities, format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Json => serde_json::to_string(caps).unwrap_or_default(),
+        OutputFormat::JsonPretty => serde_json::to_string_pretty(caps).unwrap_or_default(),
+        OutputFormat::Text => format_capabilities_text(caps),
+    }
+}
+
+/// @ai:intent Format parser capabilities as human-readable text
+/// @ai:effects pure
+fn format_capabilities_text(caps: &Capabilities) -> String {
+    let mut output = String::new();
+
+    output.push_str(&format!("{} {}\n", "aicms".bold(), caps.version));
+    output.push_str(&format!("  default prefix: {}\n\n", caps.default_prefix));
+
+    output.push_str(&format!("{}\n", "Languages:".bold()));
+    for lang in &caps.languages {
+        output.push_str(&format!("  {} ({})\n", lang.name, lang.extensions.join(", ")));
+    }
+
+    output.push_str(&format!("\n{}\n", "Tags:".bold()));
+    output.push_str(&format!("  module: {}\n", caps.tags.module.join(", ")));
+    output.push_str(&format!("  function: {}\n", caps.tags.function.join(", ")));
+    output.push_str(&format!("  declaration: {}\n", caps.tags.declaration.join(", ")));
+    output.push_str(&format!("  project: {}\n", caps.tags.project.join(", ")));
+    output.push_str(&format!("  families: {}\n", caps.tags.families.join(", ")));
+
+    output.push_str(&format!("\n{}\n", "Effects:".bold()));
+    output.push_str(&format!("  {}\n", caps.effects.join(", ")));
+
+    output.push_str(&format!("\n{}\n", "Lint rules:".bold()));
+    for rule in &caps.lint_rules {
+        output.push_str(&format!("  {} {:?} - {}\n", rule.code, rule.severity, rule.description));
+    }
+
+    output.push_str(&format!("\n{}\n", "Output formats:".bold()));
+    output.push_str(&format!("  {}\n", caps.output_formats.join(", ")));
+
+    if !caps.commands.is_empty() {
+        output.push_str(&format!("\n{}\n", "Commands:".bold()));
+        for cmd in &caps.commands {
+            output.push_str(&format!(
+                "  {} - {}\n",
+                cmd.name,
+                cmd.about.as_deref().unwrap_or("")
+            ));
+        }
+    }
+
+    output
+}
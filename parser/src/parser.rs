@@ -4,8 +4,10 @@
 //! @ai:module:depends_on language, error
 //! @ai:module:stateless true
 
+use crate::annotation::DeclarationKind;
 use crate::error::{Error, Result};
 use crate::language::{detect_language, Language};
+use crate::prefix::PrefixConfig;
 use regex::Regex;
 use std::path::Path;
 
@@ -31,6 +33,7 @@ pub struct ParsedSource {
     pub language: Language,
     pub comment_blocks: Vec<CommentBlock>,
     pub function_locations: Vec<FunctionLocation>,
+    pub declaration_locations: Vec<DeclarationLocation>,
 }
 
 /// @ai:intent Location of a function definition in source
@@ -41,6 +44,17 @@ pub struct FunctionLocation {
     pub preceding_comment_block: Option<usize>,
 }
 
+/// @ai:intent Location of a constant or variable declaration in source
+///            Constants are always recorded (for linting); variables only when annotated
+#[derive(Debug, Clone)]
+pub struct DeclarationLocation {
+    pub name: String,
+    pub kind: DeclarationKind,
+    pub public: bool,
+    pub line: usize,
+    pub preceding_comment_block: Option<usize>,
+}
+
 /// @ai:intent Parse a source file and extract comment blocks
 /// @ai:pre path exists and is readable
 /// @ai:post result contains all comment blocks and function locations
@@ -56,11 +70,13 @@ pub fn parse_file(path: &Path) -> Result<ParsedSource> {
 
     let comment_blocks = extract_comment_blocks(&content, language);
     let function_locations = extract_function_locations(&content, language, &comment_blocks);
+    let declaration_locations = extract_declaration_locations(&content, language, &comment_blocks);
 
     Ok(ParsedSource {
         language,
         comment_blocks,
         function_locations,
+        declaration_locations,
     })
 }
 
@@ -115,9 +131,8 @@ fn extract_comment_blocks(content: &str, language: Language) -> Vec<CommentBlock
 /// @ai:effects pure
 fn extract_single_line_comment(line: &str, style: &crate::language::CommentStyle) -> Option<String> {
     for prefix in style.doc_line.iter().chain(style.single_line.iter()) {
-        if line.starts_with(prefix) {
-            let content = line[prefix.len()..].trim();
-            return Some(content.to_string());
+        if let Some(content) = line.strip_prefix(prefix) {
+            return Some(content.trim().to_string());
         }
     }
 
@@ -129,8 +144,8 @@ fn extract_single_line_comment(line: &str, style: &crate::language::CommentStyle
 
         if let Some(prefix) = style.block_line_prefix {
             let trimmed = line.trim_start();
-            if trimmed.starts_with(prefix) {
-                return Some(trimmed[prefix.len()..].trim().to_string());
+            if let Some(content) = trimmed.strip_prefix(prefix) {
+                return Some(content.trim().to_string());
             }
         }
     }
@@ -195,6 +210,102 @@ fn get_function_pattern(language: Language) -> String {
     }
 }
 
+/// @ai:intent Extract constant and variable declarations from source content
+/// @ai:effects pure
+fn extract_declaration_locations(
+    content: &str,
+    language: Language,
+    comment_blocks: &[CommentBlock],
+) -> Vec<DeclarationLocation> {
+    let patterns: Vec<(Regex, DeclarationKind)> = get_declaration_patterns(language)
+        .into_iter()
+        .map(|(pattern, kind)| (Regex::new(pattern).expect("Invalid regex pattern"), kind))
+        .collect();
+
+    let mut locations = Vec::new();
+
+    for (line_idx, line) in content.lines().enumerate() {
+        let line_number = line_idx + 1;
+
+        let Some((captures, kind)) = patterns
+            .iter()
+            .find_map(|(re, kind)| re.captures(line).map(|c| (c, *kind)))
+        else {
+            continue;
+        };
+
+        // Declarations sit in tight groups, so only a directly adjacent block is attached
+        let preceding_block = comment_blocks
+            .iter()
+            .position(|block| block.end_line + 1 == line_number);
+
+        // Local variables are only interesting when someone annotated them
+        if kind == DeclarationKind::Variable && preceding_block.is_none() {
+            continue;
+        }
+
+        let name = captures["name"].to_string();
+        let public = is_public_declaration(language, &name, captures.name("vis").is_some());
+
+        locations.push(DeclarationLocation {
+            name,
+            kind,
+            public,
+            line: line_number,
+            preceding_comment_block: preceding_block,
+        });
+    }
+
+    locations
+}
+
+/// @ai:intent Get regex patterns for declarations in a language, most specific first
+///            Each pattern captures `name` and, where visibility is explicit, `vis`
+/// @ai:effects pure
+fn get_declaration_patterns(language: Language) -> Vec<(&'static str, DeclarationKind)> {
+    match language {
+        Language::Rust => vec![
+            (r"^\s*(?:(?P<vis>pub)(?:\([^)]*\))?\s+)?static\s+mut\s+(?P<name>\w+)", DeclarationKind::Variable),
+            (r"^\s*(?:(?P<vis>pub)(?:\([^)]*\))?\s+)?(?:const|static)\s+(?P<name>[A-Za-z_]\w*)\s*:", DeclarationKind::Constant),
+            (r"^\s*let\s+(?:mut\s+)?(?P<name>\w+)", DeclarationKind::Variable),
+        ],
+        Language::Python => vec![
+            (r"^(?P<name>[A-Z][A-Z0-9_]*)\s*(?::[^=]+)?=[^=]", DeclarationKind::Constant),
+            (r"^\s*(?P<name>[A-Za-z_]\w*)\s*(?::[^=]+)?=[^=]", DeclarationKind::Variable),
+        ],
+        Language::TypeScript | Language::JavaScript => vec![
+            (r"^\s*(?:(?P<vis>export)\s+)?const\s+(?P<name>\w+)", DeclarationKind::Constant),
+            (r"^\s*(?:(?P<vis>export)\s+)?(?:let|var)\s+(?P<name>\w+)", DeclarationKind::Variable),
+        ],
+        Language::Go => vec![
+            (r"^\s*const\s+(?P<name>\w+)", DeclarationKind::Constant),
+            (r"^\s*var\s+(?P<name>\w+)", DeclarationKind::Variable),
+        ],
+        Language::Java => vec![(
+            r"^\s*(?:(?P<vis>public)\s+)?(?:(?:private|protected)\s+)?static\s+final\s+[\w<>\[\], ]+\s+(?P<name>\w+)\s*=",
+            DeclarationKind::Constant,
+        )],
+        Language::C | Language::Cpp => vec![
+            (r"^\s*#define\s+(?P<name>\w+)\s+\S", DeclarationKind::Constant),
+            (r"^\s*(?:constexpr|const)\s+[\w:<>]+\s+(?P<name>\w+)\s*=", DeclarationKind::Constant),
+        ],
+    }
+}
+
+/// @ai:intent Decide if a declaration is part of the public surface of its file
+///            Uses explicit visibility where the language has it, naming conventions otherwise
+/// @ai:effects pure
+fn is_public_declaration(language: Language, name: &str, has_visibility: bool) -> bool {
+    match language {
+        Language::Rust | Language::TypeScript | Language::JavaScript | Language::Java => {
+            has_visibility
+        }
+        Language::Python => !name.starts_with('_'),
+        Language::Go => name.chars().next().is_some_and(|c| c.is_uppercase()),
+        Language::C | Language::Cpp => true,
+    }
+}
+
 /// @ai:intent Find the comment block immediately preceding a line
 /// @ai:effects pure
 fn find_preceding_comment_block(line: usize, blocks: &[CommentBlock]) -> Option<usize> {
@@ -207,20 +318,32 @@ fn find_preceding_comment_block(line: usize, blocks: &[CommentBlock]) -> Option<
 }
 
 impl CommentBlock {
-    /// @ai:intent Check if this block contains any @ai: annotations
+    /// @ai:intent Check if this block contains any annotations with a recognized prefix
     /// @ai:effects pure
-    pub fn has_ai_annotations(&self) -> bool {
-        self.lines.iter().any(|l| l.content.contains("@ai:"))
+    pub fn has_annotations(&self, prefixes: &PrefixConfig) -> bool {
+        self.lines.iter().any(|l| prefixes.matches(&l.content))
     }
 
-    /// @ai:intent Get all lines containing @ai: annotations
+    /// @ai:intent Get all lines containing annotations with a recognized prefix
     /// @ai:effects pure
-    pub fn ai_annotation_lines(&self) -> Vec<&CommentLine> {
+    pub fn annotation_lines(&self, prefixes: &PrefixConfig) -> Vec<&CommentLine> {
         self.lines
             .iter()
-            .filter(|l| l.content.contains("@ai:"))
+            .filter(|l| prefixes.matches(&l.content))
             .collect()
     }
+
+    /// @ai:intent Check if this block contains any @ai: annotations (default prefix)
+    /// @ai:effects pure
+    pub fn has_ai_annotations(&self) -> bool {
+        self.has_annotations(&PrefixConfig::default())
+    }
+
+    /// @ai:intent Get all lines containing @ai: annotations (default prefix)
+    /// @ai:effects pure
+    pub fn ai_annotation_lines(&self) -> Vec<&CommentLine> {
+        self.annotation_lines(&PrefixConfig::default())
+    }
 }
 
 #[cfg(test)]
@@ -236,6 +359,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extract_declarations() {
+        let content = "/// @ai:const:unit ms\npub const TIMEOUT_MS: u64 = 500;\nconst LIMIT: usize = 3;\n\nfn f() {\n    let x = 1;\n    // @ai:var:range 0..=10\n    let mut retries = 0;\n}\n";
+        let blocks = extract_comment_blocks(content, Language::Rust);
+        let decls = extract_declaration_locations(content, Language::Rust, &blocks);
+
+        let names: Vec<_> = decls.iter().map(|d| (d.name.as_str(), d.kind, d.public)).collect();
+        assert_eq!(
+            names,
+            vec![
+                ("TIMEOUT_MS", DeclarationKind::Constant, true),
+                ("LIMIT", DeclarationKind::Constant, false),
+                ("retries", DeclarationKind::Variable, false),
+            ]
+        );
+        assert_eq!(decls[0].preceding_comment_block, Some(0));
+        assert_eq!(decls[1].preceding_comment_block, None);
+    }
+
+    #[test]
+    fn test_default_prefix_wrappers() {
+        let content = "/// @ai:intent Add
+/// Adds two numbers
+/// @meta:pre a > 0
+fn add() {}
+";
+        let blocks = extract_comment_blocks(content, Language::Rust);
+
+        assert!(blocks[0].has_ai_annotations());
+        assert_eq!(blocks[0].ai_annotation_lines().len(), 1);
+        let meta = PrefixConfig::new("meta");
+        assert_eq!(blocks[0].annotation_lines(&meta).len(), 1);
+    }
+
     #[test]
     fn test_extract_python_comment() {
         let style = Language::Python.comment_style();
@@ -0,0 +1,130 @@
+//! @ai:module:intent Configure the annotation sigil (default `@ai:`) and legacy prefixes
+//! @ai:module:layer domain
+//! @ai:module:public_api PrefixConfig, DEFAULT_PREFIX
+//! @ai:module:stateless true
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// @ai:intent Default annotation prefix
+pub const DEFAULT_PREFIX: &str = "@ai:";
+
+/// @ai:intent Annotation prefix in use, plus legacy prefixes still accepted during migration
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrefixConfig {
+    pub prefix: String,
+    pub accepted: Vec<String>,
+}
+
+impl Default for PrefixConfig {
+    fn default() -> Self {
+        Self {
+            prefix: DEFAULT_PREFIX.to_string(),
+            accepted: Vec::new(),
+        }
+    }
+}
+
+impl PrefixConfig {
+    /// @ai:intent Create a config with a single prefix
+    /// @ai:effects pure
+    /// @ai:example ("meta") -> PrefixConfig { prefix: "@meta:", accepted: [] }
+    pub fn new(prefix: &str) -> Self {
+        Self {
+            prefix: normalize_prefix(prefix),
+            accepted: Vec::new(),
+        }
+    }
+
+    /// @ai:intent Also recognize a legacy prefix (reported as a migration warning)
+    /// @ai:effects pure
+    pub fn with_accepted(mut self, prefix: &str) -> Self {
+        let prefix = normalize_prefix(prefix);
+
+        if prefix != self.prefix && !self.accepted.contains(&prefix) {
+            self.accepted.push(prefix);
+        }
+        self
+    }
+
+    /// @ai:intent All recognized prefixes, primary first
+    /// @ai:effects pure
+    pub fn all(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.prefix.as_str()).chain(self.accepted.iter().map(String::as_str))
+    }
+
+    /// @ai:intent Check if text contains any recognized prefix
+    /// @ai:effects pure
+    pub fn matches(&self, text: &str) -> bool {
+        self.all().any(|p| text.contains(p))
+    }
+
+    /// @ai:intent Check if a prefix is a legacy (non-primary) one
+    /// @ai:effects pure
+    pub fn is_legacy(&self, prefix: &str) -> bool {
+        prefix != self.prefix
+    }
+
+    /// @ai:intent Render a tag with the primary prefix
+    /// @ai:effects pure
+    /// @ai:example ("intent") -> "@ai:intent"
+    pub fn tag(&self, tag: &str) -> String {
+        format!("{}{}", self.prefix, tag)
+    }
+
+    /// @ai:intent Build a regex matching any recognized prefix followed by a pattern
+    ///            The prefix is captured in the named group `prefix`
+    /// @ai:effects pure
+    pub fn regex(&self, pattern: &str) -> Regex {
+        let alternatives = self
+            .all()
+            .map(regex::escape)
+            .collect::<Vec<_>>()
+            .join("|");
+
+        Regex::new(&format!("(?P<prefix>{}){}", alternatives, pattern)).expect("Invalid regex")
+    }
+}
+
+/// @ai:intent Normalize user input into a full sigil (`meta` -> `@meta:`)
+/// @ai:effects pure
+fn normalize_prefix(prefix: &str) -> String {
+    let trimmed = prefix.trim();
+    let with_at = if trimmed.starts_with('@') {
+        trimmed.to_string()
+    } else {
+        format!("@{}", trimmed)
+    };
+
+    if with_at.ends_with(':') {
+        with_at
+    } else {
+        format!("{}:", with_at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_prefix() {
+        assert_eq!(PrefixConfig::new("meta").prefix, "@meta:");
+        assert_eq!(PrefixConfig::new("@llm:").prefix, "@llm:");
+        assert_eq!(PrefixConfig::new("@llm").prefix, "@llm:");
+    }
+
+    #[test]
+    fn test_regex_matches_all_prefixes() {
+        let config = PrefixConfig::new("meta").with_accepted("ai");
+        let re = config.regex(r"(\w+)\s*(.*)");
+
+        let caps = re.captures("@ai:intent Do things").unwrap();
+        assert_eq!(&caps["prefix"], "@ai:");
+        assert!(config.is_legacy(&caps["prefix"]));
+
+        let caps = re.captures("@meta:intent Do things").unwrap();
+        assert!(!config.is_legacy(&caps["prefix"]));
+        assert!(re.captures("@other:intent").is_none());
+    }
+}